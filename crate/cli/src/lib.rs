@@ -2,4 +2,9 @@
 //!
 //! This is enabled though the `"cli"` feature on the `peace` crate.
 
+// Re-exports
+#[cfg(feature = "output_tui")]
+pub use ratatui;
+
 pub mod output;
+pub mod report;
@@ -2,7 +2,8 @@ pub use self::{
     cli_colorize::CliColorize, cli_colorize_opt::CliColorizeOpt,
     cli_colorize_parse_error::CliColorizeOptParseError, cli_md_presenter::CliMdPresenter,
     cli_output::CliOutput, cli_output_builder::CliOutputBuilder,
-    cli_output_target::CliOutputTarget,
+    cli_output_target::CliOutputTarget, json_lines_event::JsonLinesEvent,
+    json_lines_event_kind::JsonLinesEventKind, json_lines_output::JsonLinesOutput,
 };
 
 mod cli_colorize;
@@ -12,6 +13,9 @@ mod cli_md_presenter;
 mod cli_output;
 mod cli_output_builder;
 mod cli_output_target;
+mod json_lines_event;
+mod json_lines_event_kind;
+mod json_lines_output;
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "output_progress")] {
@@ -26,3 +30,12 @@ cfg_if::cfg_if! {
         mod cli_progress_format_opt_parse_error;
     }
 }
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "output_tui")] {
+        pub use self::{tui_item_state::TuiItemState, tui_output::TuiOutput};
+
+        mod tui_item_state;
+        mod tui_output;
+    }
+}
@@ -1,13 +1,21 @@
 use console::Style;
 use futures::stream::{self, TryStreamExt};
-use peace_fmt::{async_trait, presentable::HeadingLevel, Presentable, Presenter};
+use peace_fmt::{
+    async_trait,
+    presentable::{DiffKind, HeadingLevel, Table, TableAlignment},
+    Presentable, Presenter,
+};
 use tokio::io::{AsyncWrite, AsyncWriteExt};
 
-use crate::output::{CliColorize, CliOutput};
+use crate::output::{CliColorize, CliColorizeOpt, CliOutput, CliOutputBuilder};
 
 /// Command line markdown presenter.
 ///
 /// Formats `Presentable` data as markdown on the CLI.
+///
+/// Tables are drawn with box drawing characters when output is colorized, and
+/// as markdown tables otherwise, so that piped output can be pasted into
+/// markdown documents.
 #[derive(Debug)]
 pub struct CliMdPresenter<'output, W> {
     /// The CLI output to write to.
@@ -239,6 +247,126 @@ where
 
         Ok(())
     }
+
+    /// Writes a horizontal border of a box drawn table.
+    async fn table_border_write(
+        &mut self,
+        column_widths: &[usize],
+        (left, middle, right): (&str, &str, &str),
+    ) -> Result<(), std::io::Error> {
+        let style_grey = &console::Style::new().color256(243); // grey
+        let border = column_widths
+            .iter()
+            .map(|column_width| "─".repeat(column_width + 2))
+            .collect::<Vec<String>>()
+            .join(middle);
+        self.colorize_maybe(format!("{left}{border}{right}").as_str(), style_grey)
+            .await?;
+        self.output.writer.write_all(b"\n").await?;
+
+        Ok(())
+    }
+
+    /// Writes the line between the header and rows of a markdown table.
+    async fn table_md_separator_write(
+        &mut self,
+        column_widths: &[usize],
+        alignments: &[TableAlignment],
+    ) -> Result<(), std::io::Error> {
+        let style_grey = &console::Style::new().color256(243); // grey
+        let separator = column_widths
+            .iter()
+            .zip(alignments.iter())
+            .map(|(column_width, alignment)| match alignment {
+                TableAlignment::Left => {
+                    format!(" :{} ", "-".repeat(column_width.saturating_sub(1)))
+                }
+                TableAlignment::Center => {
+                    format!(" :{}: ", "-".repeat(column_width.saturating_sub(2)))
+                }
+                TableAlignment::Right => {
+                    format!(" {}: ", "-".repeat(column_width.saturating_sub(1)))
+                }
+            })
+            .collect::<Vec<String>>()
+            .join("|");
+        self.colorize_maybe(format!("|{separator}|").as_str(), style_grey)
+            .await?;
+        self.output.writer.write_all(b"\n").await?;
+
+        Ok(())
+    }
+
+    /// Writes a row of a table, padding each cell to its column's width.
+    ///
+    /// `cells` are the cell presentables and their rendered widths. Columns
+    /// without a cell are written as empty cells.
+    /// Returns the cell with `|` escaped and line breaks replaced with spaces,
+    /// so that it stays within its markdown table cell.
+    fn table_cell_md_escape(cell: &str) -> String {
+        cell.replace('|', "\\|").replace(['\r', '\n'], " ")
+    }
+
+    async fn table_row_write<P>(
+        &mut self,
+        table_style: TableStyle,
+        column_widths: &[usize],
+        alignments: &[TableAlignment],
+        cells: &[(TableCell<'_, P>, usize)],
+    ) -> Result<(), std::io::Error>
+    where
+        P: Presentable,
+    {
+        let style_grey = &console::Style::new().color256(243); // grey
+        let delimiter = match table_style {
+            TableStyle::BoxDrawing => "│",
+            TableStyle::Markdown => "|",
+        };
+
+        for (index, (column_width, alignment)) in
+            column_widths.iter().zip(alignments.iter()).enumerate()
+        {
+            self.colorize_maybe(delimiter, style_grey).await?;
+            self.output.writer.write_all(b" ").await?;
+
+            let (cell, cell_width) = match cells.get(index) {
+                Some((cell, cell_width)) => (Some(cell), *cell_width),
+                None => (None, 0),
+            };
+            let padding = column_width.saturating_sub(cell_width);
+            let (padding_left, padding_right) = match alignment {
+                TableAlignment::Left => (0, padding),
+                TableAlignment::Center => (padding / 2, padding - padding / 2),
+                TableAlignment::Right => (padding, 0),
+            };
+
+            self.output
+                .writer
+                .write_all(" ".repeat(padding_left).as_bytes())
+                .await?;
+            match cell {
+                Some(TableCell::Header(header)) => {
+                    let style_header = &console::Style::new();
+                    self.cli_bold.increment();
+                    self.colorize_maybe(header, style_header).await?;
+                    self.cli_bold.decrement();
+                }
+                Some(TableCell::Value(presentable)) => presentable.present(self).await?,
+                Some(TableCell::Rendered(rendered)) => {
+                    self.output.writer.write_all(rendered.as_bytes()).await?
+                }
+                None => {}
+            }
+            self.output
+                .writer
+                .write_all(" ".repeat(padding_right + 1).as_bytes())
+                .await?;
+        }
+        self.colorize_maybe(delimiter, style_grey).await?;
+        self.output.writer.write_all(b"\n").await?;
+
+        Ok(())
+    }
 }
 
 #[async_trait(?Send)]
@@ -328,6 +456,19 @@ where
         Ok(())
     }
 
+    async fn diff(&mut self, diff_kind: DiffKind, text: &str) -> Result<(), Self::Error> {
+        let style = &match diff_kind {
+            DiffKind::Added => console::Style::new().color256(77), // green
+            DiffKind::Removed => console::Style::new().color256(203), // red
+            DiffKind::Modified => console::Style::new().color256(220), // yellow
+            DiffKind::Unchanged => console::Style::new().color256(243), // grey
+        };
+        let symbol = diff_kind.symbol();
+        self.colorize_maybe(format!("{symbol} {text}").as_str(), style)
+            .await?;
+        Ok(())
+    }
+
     async fn list_numbered<'f, P, I>(&mut self, iter: I) -> Result<(), Self::Error>
     where
         P: Presentable + ?Sized + 'f,
@@ -437,6 +578,120 @@ where
     {
         self.list_aligned_with(ListType::Bulleted, iter, f).await
     }
+
+    async fn table<'s, P>(&mut self, table: &Table<'s, P>) -> Result<(), Self::Error>
+    where
+        P: Presentable,
+    {
+        let columns = table.columns();
+        let alignments = columns
+            .iter()
+            .map(|column| column.alignment())
+            .collect::<Vec<TableAlignment>>();
+        let table_style = match self.output.colorize {
+            CliColorize::Colored => TableStyle::BoxDrawing,
+            CliColorize::Uncolored => TableStyle::Markdown,
+        };
+        let header_cells = columns
+            .iter()
+            .map(|column| {
+                let header = column.header();
+                match table_style {
+                    TableStyle::BoxDrawing => (
+                        TableCell::Header(header),
+                        console::measure_text_width(header),
+                    ),
+                    TableStyle::Markdown => {
+                        let header = Self::table_cell_md_escape(header);
+                        let width = console::measure_text_width(&header);
+                        (TableCell::Rendered(header), width)
+                    }
+                }
+            })
+            .collect::<Vec<(TableCell<'_, P>, usize)>>();
+
+        // Render each value so we can determine the width of each column.
+        //
+        // Markdown cells are kept rendered, so that they can be escaped.
+        let mut buffer = Vec::<u8>::with_capacity(256);
+        let mut cli_output_in_memory = match table_style {
+            TableStyle::BoxDrawing => CliOutput::new_with_writer(&mut buffer),
+            TableStyle::Markdown => CliOutputBuilder::new_with_writer(&mut buffer)
+                .with_colorize(CliColorizeOpt::Never)
+                .build(),
+        };
+        let mut width_buffer_presenter = CliMdPresenter::new(&mut cli_output_in_memory);
+        let mut rows_cells = Vec::with_capacity(table.rows().len());
+        for row in table.rows() {
+            let mut row_cells = Vec::with_capacity(columns.len());
+            for presentable in row.iter().take(columns.len()) {
+                presentable.present(&mut width_buffer_presenter).await?;
+                let rendered = String::from_utf8_lossy(width_buffer_presenter.output.writer);
+                let table_cell = match table_style {
+                    TableStyle::BoxDrawing => {
+                        let width = console::measure_text_width(&rendered);
+                        (TableCell::Value(presentable), width)
+                    }
+                    TableStyle::Markdown => {
+                        let rendered = Self::table_cell_md_escape(&rendered);
+                        let width = console::measure_text_width(&rendered);
+                        (TableCell::Rendered(rendered), width)
+                    }
+                };
+                width_buffer_presenter.output.writer.clear();
+
+                row_cells.push(table_cell);
+            }
+            rows_cells.push(row_cells);
+        }
+
+        let column_widths = (0..columns.len())
+            .map(|index| {
+                std::iter::once(&header_cells)
+                    .chain(rows_cells.iter())
+                    .filter_map(|cells| cells.get(index).map(|(_cell, width)| *width))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect::<Vec<usize>>();
+
+        let column_widths = match table_style {
+            TableStyle::BoxDrawing => column_widths,
+            // Markdown alignment rows need at least three characters, e.g. `:-:`.
+            TableStyle::Markdown => column_widths
+                .into_iter()
+                .map(|column_width| column_width.max(3))
+                .collect::<Vec<usize>>(),
+        };
+        match table_style {
+            TableStyle::BoxDrawing => {
+                self.table_border_write(&column_widths, ("┌", "┬", "┐"))
+                    .await?;
+                self.table_row_write(table_style, &column_widths, &alignments, &header_cells)
+                    .await?;
+                self.table_border_write(&column_widths, ("├", "┼", "┤"))
+                    .await?;
+                for row_cells in rows_cells.iter() {
+                    self.table_row_write(table_style, &column_widths, &alignments, row_cells)
+                        .await?;
+                }
+                self.table_border_write(&column_widths, ("└", "┴", "┘"))
+                    .await?;
+            }
+            TableStyle::Markdown => {
+                self.table_row_write(table_style, &column_widths, &alignments, &header_cells)
+                    .await?;
+                self.table_md_separator_write(&column_widths, &alignments)
+                    .await?;
+                for row_cells in rows_cells.iter() {
+                    self.table_row_write(table_style, &column_widths, &alignments, row_cells)
+                        .await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
 
 /// Whether to render text in ANSI bold.
@@ -463,3 +718,23 @@ enum ListType {
     Numbered,
     Bulleted,
 }
+
+/// How to draw a table.
+#[derive(Clone, Copy, Debug)]
+enum TableStyle {
+    /// Draw borders with box drawing characters.
+    BoxDrawing,
+    /// Draw a markdown table.
+    Markdown,
+}
+
+/// Cell in a table row.
+#[derive(Debug)]
+enum TableCell<'t, P> {
+    /// Column header.
+    Header(&'t str),
+    /// Value in a row.
+    Value(&'t P),
+    /// Header or value already rendered, e.g. escaped for a markdown table.
+    Rendered(String),
+}
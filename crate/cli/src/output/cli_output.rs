@@ -4,7 +4,9 @@ use std::fmt::Debug;
 
 use peace_cli_model::OutputFormat;
 use peace_fmt::Presentable;
-use peace_rt_model_core::{async_trait, output::OutputWrite, Error, NativeError};
+use peace_rt_model_core::{
+    async_trait, output::OutputWrite, Error, NativeError, SerializationError,
+};
 use serde::Serialize;
 use tokio::io::{AsyncWrite, AsyncWriteExt, Stdout};
 
@@ -24,6 +26,9 @@ cfg_if::cfg_if! {
             },
             ItemId,
         };
+        use std::collections::HashMap;
+
+        use chrono::SecondsFormat;
         use peace_item_model::ItemLocationState;
         use peace_rt_model_core::{
             indicatif::{ProgressDrawTarget, ProgressStyle},
@@ -34,6 +39,9 @@ cfg_if::cfg_if! {
     }
 }
 
+#[cfg(all(feature = "output_progress", feature = "output_in_memory"))]
+use peace_rt_model_core::indicatif::TermLike;
+
 /// An `OutputWrite` implementation that writes to the command line.
 ///
 /// # Features
@@ -46,9 +54,10 @@ cfg_if::cfg_if! {
 /// another process, or redirected to a file), then the progress output format
 /// is a progress bar.
 ///
-/// If it is piped to another process or redirected to a file, then the progress
-/// output format defaults to the same format at the outcome output format --
-/// text, YAML, or JSON.
+/// If it is piped to another process or redirected to a file, such as in CI
+/// logs, then the progress output format defaults to timestamped lines for
+/// text output, or the same format as the outcome output format for YAML or
+/// JSON.
 ///
 /// These defaults may be overridden through the [`with_progress_target`] and
 /// [`with_progress_format`] methods.
@@ -82,6 +91,11 @@ pub struct CliOutput<W> {
     /// Width of the item ID column for progress bars
     #[cfg(feature = "output_progress")]
     pub(crate) pb_item_id_width: Option<usize>,
+    /// Last status written for each item, for the `Lines` progress format.
+    ///
+    /// This is used to only write a line when an item's status changes.
+    #[cfg(feature = "output_progress")]
+    pub(crate) progress_lines_last: HashMap<ItemId, String>,
     /// The TTY guard that restores the terminal mode when `CliOutput` is
     /// dropped.
     ///
@@ -108,7 +122,8 @@ where
             debug_struct
                 .field("progress_target", &self.progress_target)
                 .field("progress_format", &self.progress_format)
-                .field("pb_item_id_width", &self.pb_item_id_width);
+                .field("pb_item_id_width", &self.pb_item_id_width)
+                .field("progress_lines_last", &self.progress_lines_last);
         }
 
         debug_struct.field(
@@ -494,6 +509,67 @@ where
 
         format_str
     }
+
+    /// Returns the status to write for an item in the `Lines` progress format,
+    /// or `None` if nothing should be written.
+    ///
+    /// Progress deltas are rounded down to the nearest 10%, so that a line is
+    /// not written for every delta.
+    #[cfg(feature = "output_progress")]
+    fn progress_line_status(
+        progress_tracker: &ProgressTracker,
+        progress_update: &ProgressUpdate,
+    ) -> Option<String> {
+        let status = match progress_update {
+            ProgressUpdate::Reset => return None,
+            ProgressUpdate::ResetToPending => "pending".to_string(),
+            ProgressUpdate::Queued => "queued".to_string(),
+            ProgressUpdate::Interrupt => "interrupted".to_string(),
+            ProgressUpdate::Limit(_progress_limit) => "started".to_string(),
+            ProgressUpdate::Delta(_delta) => {
+                return Some(match progress_tracker.units_total() {
+                    Some(units_total) if units_total > 0 => {
+                        let percent = progress_tracker.units_current().min(units_total) * 100
+                            / units_total
+                            / 10
+                            * 10;
+                        format!("progress {percent}%")
+                    }
+                    _ => "running".to_string(),
+                });
+            }
+            ProgressUpdate::Complete(ProgressComplete::Success) => "complete".to_string(),
+            ProgressUpdate::Complete(ProgressComplete::Fail) => "failed".to_string(),
+        };
+
+        match progress_tracker.message() {
+            Some(message) => Some(format!("{status}: {message}")),
+            None => Some(status),
+        }
+    }
+
+    /// Writes a line of progress to the progress target.
+    ///
+    /// This is infallible as progress information is considered transient.
+    #[cfg(feature = "output_progress")]
+    async fn progress_line_write(&self, line: &str) {
+        match &self.progress_target {
+            CliOutputTarget::Stdout => {
+                let (Ok(()) | Err(_)) = tokio::io::stdout()
+                    .write_all(format!("{line}\n").as_bytes())
+                    .await;
+            }
+            CliOutputTarget::Stderr => {
+                let (Ok(()) | Err(_)) = tokio::io::stderr()
+                    .write_all(format!("{line}\n").as_bytes())
+                    .await;
+            }
+            #[cfg(feature = "output_in_memory")]
+            CliOutputTarget::InMemory(in_memory_term) => {
+                let (Ok(()) | Err(_)) = in_memory_term.write_line(line);
+            }
+        }
+    }
 }
 
 impl Default for CliOutput<Stdout> {
@@ -608,6 +684,13 @@ where
                     },
                 );
             }
+            CliProgressFormat::Lines => {
+                // Progress bars are not rendered, lines are written directly.
+                cmd_progress_tracker
+                    .multi_progress()
+                    .set_draw_target(ProgressDrawTarget::hidden());
+                self.progress_lines_last.clear();
+            }
             CliProgressFormat::None => {}
         }
     }
@@ -711,6 +794,33 @@ where
                     OutputFormat::None => {}
                 }
             }
+            CliProgressFormat::Lines => {
+                let item_id = &progress_update_and_id.item_id;
+                let Some(status) = Self::progress_line_status(
+                    progress_tracker,
+                    &progress_update_and_id.progress_update,
+                ) else {
+                    return;
+                };
+                if self.progress_lines_last.get(item_id) == Some(&status) {
+                    return;
+                }
+
+                let timestamp = progress_tracker
+                    .last_update_dt()
+                    .to_rfc3339_opts(SecondsFormat::Secs, true);
+                let line = match self.colorize {
+                    CliColorize::Colored => {
+                        let item_id_colorized = console::Style::new()
+                            .color256(75)
+                            .apply_to(format!("{item_id}"));
+                        format!("{timestamp} {item_id_colorized} {status}")
+                    }
+                    CliColorize::Uncolored => format!("{timestamp} {item_id} {status}"),
+                };
+                self.progress_line_write(&line).await;
+                self.progress_lines_last.insert(item_id.clone(), status);
+            }
             CliProgressFormat::None => {}
         }
     }
@@ -742,6 +852,9 @@ where
                 // moves the cursor up a line.
                 let (Ok(()) | Err(_)) = self.writer.write_all(b"\n\n").await;
             }
+            CliProgressFormat::Lines => {
+                self.progress_lines_last.clear();
+            }
             CliProgressFormat::Outcome | CliProgressFormat::None => {}
         }
     }
@@ -752,7 +865,12 @@ where
     {
         match self.outcome_format {
             OutputFormat::Text => self.output_presentable(presentable).await,
-            OutputFormat::Yaml => self.output_yaml(&presentable, Error::StatesSerialize).await,
+            OutputFormat::Yaml => {
+                self.output_yaml(&presentable, |error| {
+                    Error::StatesSerialize(SerializationError::Yaml(error))
+                })
+                .await
+            }
             OutputFormat::Json => {
                 self.output_json(&presentable, Error::StatesSerializeJson)
                     .await
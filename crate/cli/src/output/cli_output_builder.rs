@@ -1,3 +1,5 @@
+#[cfg(feature = "output_progress")]
+use std::collections::HashMap;
 use std::io::IsTerminal;
 
 use peace_cli_model::OutputFormat;
@@ -23,9 +25,10 @@ cfg_if::cfg_if! {
 /// another process, or redirected to a file), then the progress output format
 /// is a progress bar.
 ///
-/// If it is piped to another process or redirected to a file, then the progress
-/// output format defaults to the same format at the outcome output format --
-/// text, YAML, or JSON.
+/// If it is piped to another process or redirected to a file, such as in CI
+/// logs, then the progress output format defaults to timestamped lines for
+/// text output, or the same format as the outcome output format for YAML or
+/// JSON.
 ///
 /// These defaults may be overridden through the [`with_progress_target`] and
 /// [`with_progress_format`] methods.
@@ -215,27 +218,28 @@ where
                 CliProgressFormatOpt::Auto => {
                     // Even though we're using `tokio::io::stdout` / `stderr`, `IsTerminal` is only
                     // implemented on `std::io::stdout` / `stderr`.
-                    match &progress_target {
-                        CliOutputTarget::Stdout => {
-                            if std::io::stdout().is_terminal() {
-                                CliProgressFormat::ProgressBar
-                            } else {
-                                CliProgressFormat::Outcome
-                            }
-                        }
-                        CliOutputTarget::Stderr => {
-                            if std::io::stderr().is_terminal() {
-                                CliProgressFormat::ProgressBar
-                            } else {
+                    let progress_target_is_terminal = match &progress_target {
+                        CliOutputTarget::Stdout => std::io::stdout().is_terminal(),
+                        CliOutputTarget::Stderr => std::io::stderr().is_terminal(),
+                        #[cfg(feature = "output_in_memory")]
+                        CliOutputTarget::InMemory(_) => true,
+                    };
+                    if progress_target_is_terminal {
+                        CliProgressFormat::ProgressBar
+                    } else {
+                        // CI logs cannot render progress bars, so we render lines for human
+                        // readable output, and keep machine parsable output as is.
+                        match outcome_format {
+                            OutputFormat::Text => CliProgressFormat::Lines,
+                            OutputFormat::Yaml | OutputFormat::Json | OutputFormat::None => {
                                 CliProgressFormat::Outcome
                             }
                         }
-                        #[cfg(feature = "output_in_memory")]
-                        CliOutputTarget::InMemory(_) => CliProgressFormat::ProgressBar,
                     }
                 }
                 CliProgressFormatOpt::Outcome => CliProgressFormat::Outcome,
                 CliProgressFormatOpt::ProgressBar => CliProgressFormat::ProgressBar,
+                CliProgressFormatOpt::Lines => CliProgressFormat::Lines,
                 CliProgressFormatOpt::None => CliProgressFormat::None,
             };
 
@@ -297,6 +301,8 @@ where
             progress_format,
             #[cfg(feature = "output_progress")]
             pb_item_id_width: None,
+            #[cfg(feature = "output_progress")]
+            progress_lines_last: HashMap::new(),
             #[cfg(unix)]
             stdin_tty_with_guard,
         }
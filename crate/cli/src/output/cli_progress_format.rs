@@ -5,6 +5,10 @@ pub enum CliProgressFormat {
     Outcome,
     /// Always render progress as a progress bar.
     ProgressBar,
+    /// Render timestamped lines when each item's progress status changes.
+    ///
+    /// This does not move the cursor, so it is suitable for CI logs.
+    Lines,
     /// Don't render progress.
     None,
 }
@@ -5,13 +5,15 @@ use crate::output::CliProgressFormatOptParseError;
 /// How to format progress on the CLI.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum CliProgressFormatOpt {
-    /// Automatically detect whether to render a progress bar or the outcome
-    /// format.
+    /// Automatically detect whether to render a progress bar, lines, or the
+    /// outcome format.
     Auto,
     /// Render progress in the same format as the outcome.
     Outcome,
     /// Always render progress as a progress bar.
     ProgressBar,
+    /// Render timestamped lines when each item's progress status changes.
+    Lines,
     /// Don't render progress.
     None,
 }
@@ -24,6 +26,7 @@ impl FromStr for CliProgressFormatOpt {
             "auto" => Ok(Self::Auto),
             "outcome" => Ok(Self::Outcome),
             "pb" | "progress_bar" => Ok(Self::ProgressBar),
+            "lines" => Ok(Self::Lines),
             "none" => Ok(Self::None),
             _ => Err(CliProgressFormatOptParseError(s.to_string())),
         }
@@ -0,0 +1,46 @@
+use chrono::{DateTime, Utc};
+use peace_cmd_model::CmdExecutionId;
+use serde::{Deserialize, Serialize};
+
+use crate::output::JsonLinesEventKind;
+
+/// An event written by [`JsonLinesOutput`], as one JSON object per line.
+///
+/// Every event carries the schema version, the command execution it belongs
+/// to, and when it was written. The remaining fields depend on the `"event"`
+/// field -- see [`JsonLinesEventKind`].
+///
+/// ```json
+/// {"schema_version":1,"cmd_execution_id":0,"timestamp":"2024-01-01T00:00:00Z","event":"cmd_block_start","cmd_block_item_interaction_type":"Local"}
+/// ```
+///
+/// [`JsonLinesOutput`]: crate::output::JsonLinesOutput
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct JsonLinesEvent {
+    /// Version of the event schema.
+    ///
+    /// This is incremented whenever an existing field is changed or removed.
+    pub schema_version: u32,
+    /// ID of the command execution that this event belongs to.
+    pub cmd_execution_id: CmdExecutionId,
+    /// When the event was written.
+    pub timestamp: DateTime<Utc>,
+    /// The kind of event and its data.
+    #[serde(flatten)]
+    pub kind: JsonLinesEventKind,
+}
+
+impl JsonLinesEvent {
+    /// Version of the event schema written by this version of `peace`.
+    pub const SCHEMA_VERSION: u32 = 1;
+
+    /// Returns a new `JsonLinesEvent` timestamped with the current time.
+    pub fn new(cmd_execution_id: CmdExecutionId, kind: JsonLinesEventKind) -> Self {
+        Self {
+            schema_version: Self::SCHEMA_VERSION,
+            cmd_execution_id,
+            timestamp: Utc::now(),
+            kind,
+        }
+    }
+}
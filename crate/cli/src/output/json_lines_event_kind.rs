@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "output_progress")]
+use peace_core::{
+    progress::{CmdBlockItemInteractionType, ProgressUpdateAndId},
+    ItemId,
+};
+#[cfg(feature = "output_progress")]
+use peace_item_model::ItemLocationState;
+
+/// The kind of a [`JsonLinesEvent`], and its data.
+///
+/// This is serialized with an `"event"` field holding the `snake_case` name
+/// of the variant, alongside the variant's fields.
+///
+/// [`JsonLinesEvent`]: crate::output::JsonLinesEvent
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum JsonLinesEventKind {
+    /// Progress rendering has begun for a command execution.
+    #[cfg(feature = "output_progress")]
+    ProgressBegin {
+        /// IDs of the items in the flow, in order.
+        item_ids: Vec<ItemId>,
+    },
+    /// A `CmdBlock` has begun.
+    #[cfg(feature = "output_progress")]
+    CmdBlockStart {
+        /// How the `CmdBlock` interacts with each item's locations.
+        cmd_block_item_interaction_type: CmdBlockItemInteractionType,
+    },
+    /// An item's `ItemLocationState` was updated.
+    #[cfg(feature = "output_progress")]
+    ItemLocationState {
+        /// ID of the item.
+        item_id: ItemId,
+        /// The item's new location state.
+        item_location_state: ItemLocationState,
+    },
+    /// An item's progress was updated.
+    #[cfg(feature = "output_progress")]
+    ProgressUpdate(ProgressUpdateAndId),
+    /// Progress rendering has ended for a command execution.
+    #[cfg(feature = "output_progress")]
+    ProgressEnd,
+    /// A value was presented, e.g. the outcome of a command.
+    Presentation {
+        /// The presented value.
+        value: serde_json::Value,
+    },
+    /// An error was written.
+    Error {
+        /// The error's display message.
+        message: String,
+        /// Display messages of the error's sources, from outermost to
+        /// innermost.
+        causes: Vec<String>,
+    },
+}
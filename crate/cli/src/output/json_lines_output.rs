@@ -0,0 +1,208 @@
+use peace_cmd_model::CmdExecutionId;
+use peace_fmt::Presentable;
+use peace_rt_model_core::{async_trait, output::OutputWrite, Error, NativeError};
+use tokio::io::{AsyncWrite, AsyncWriteExt, Stdout};
+
+use crate::output::{JsonLinesEvent, JsonLinesEventKind};
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "output_progress")] {
+        use peace_core::{
+            progress::{CmdBlockItemInteractionType, ProgressTracker, ProgressUpdateAndId},
+            ItemId,
+        };
+        use peace_item_model::ItemLocationState;
+        use peace_rt_model_core::CmdProgressTracker;
+    }
+}
+
+/// An `OutputWrite` implementation that writes one JSON object per line.
+///
+/// This is intended for tools that wrap a `peace` application, and follow
+/// a command's progress while it runs. Each line is a [`JsonLinesEvent`],
+/// which carries the [`CmdExecutionId`], a timestamp, and a versioned schema.
+///
+/// ```json
+/// {"schema_version":1,"cmd_execution_id":0,"timestamp":"..","event":"progress_begin","item_ids":["a","b"]}
+/// {"schema_version":1,"cmd_execution_id":0,"timestamp":"..","event":"cmd_block_start","cmd_block_item_interaction_type":"Local"}
+/// {"schema_version":1,"cmd_execution_id":0,"timestamp":"..","event":"progress_update","item_id":"a","progress_update":{"Limit":{"Steps":100}},"msg_update":"NoChange"}
+/// {"schema_version":1,"cmd_execution_id":0,"timestamp":"..","event":"progress_end"}
+/// {"schema_version":1,"cmd_execution_id":0,"timestamp":"..","event":"presentation","value":{"a":"exists"}}
+/// ```
+///
+/// # Command Execution IDs
+///
+/// Events are tagged with the `CmdExecutionId` passed to
+/// [`with_cmd_execution_id`], or `0` by default. When the same output is used
+/// for multiple command executions, call [`cmd_execution_next`] between them
+/// to tag each execution's events with the next ID.
+///
+/// [`with_cmd_execution_id`]: Self::with_cmd_execution_id
+/// [`cmd_execution_next`]: Self::cmd_execution_next
+#[derive(Debug)]
+pub struct JsonLinesOutput<W> {
+    /// Output stream to write events to.
+    writer: W,
+    /// ID of the current command execution.
+    cmd_execution_id: CmdExecutionId,
+}
+
+impl JsonLinesOutput<Stdout> {
+    /// Returns a new `JsonLinesOutput` that writes to `stdout`.
+    pub fn new() -> Self {
+        Self::new_with_writer(tokio::io::stdout())
+    }
+}
+
+impl Default for JsonLinesOutput<Stdout> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<W> JsonLinesOutput<W>
+where
+    W: AsyncWrite + std::marker::Unpin,
+{
+    /// Returns a new `JsonLinesOutput` using the given writer.
+    pub fn new_with_writer(writer: W) -> Self {
+        Self {
+            writer,
+            cmd_execution_id: CmdExecutionId::new(0),
+        }
+    }
+
+    /// Sets the `CmdExecutionId` that events are tagged with.
+    pub fn with_cmd_execution_id(mut self, cmd_execution_id: CmdExecutionId) -> Self {
+        self.cmd_execution_id = cmd_execution_id;
+        self
+    }
+
+    /// Returns the `CmdExecutionId` that events are currently tagged with.
+    pub fn cmd_execution_id(&self) -> CmdExecutionId {
+        self.cmd_execution_id
+    }
+
+    /// Tags subsequent events with the next `CmdExecutionId`, and returns it.
+    pub fn cmd_execution_next(&mut self) -> CmdExecutionId {
+        self.cmd_execution_id = CmdExecutionId::new(*self.cmd_execution_id + 1);
+        self.cmd_execution_id
+    }
+
+    /// Returns the inner writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// Writes an event as a single line.
+    async fn event_write(&mut self, kind: JsonLinesEventKind) -> Result<(), Error> {
+        let event = JsonLinesEvent::new(self.cmd_execution_id, kind);
+        let mut event_serialized =
+            serde_json::to_string(&event).map_err(Error::JsonLinesSerialize)?;
+        event_serialized.push('\n');
+
+        self.writer
+            .write_all(event_serialized.as_bytes())
+            .await
+            .map_err(NativeError::JsonLinesWrite)
+            .map_err(Error::Native)?;
+        self.writer
+            .flush()
+            .await
+            .map_err(NativeError::JsonLinesWrite)
+            .map_err(Error::Native)?;
+
+        Ok(())
+    }
+
+    /// Writes a progress event as a single line, ignoring errors.
+    ///
+    /// Progress information is transient, so failing to write it does not
+    /// stop the command.
+    #[cfg(feature = "output_progress")]
+    async fn progress_event_write(&mut self, kind: JsonLinesEventKind) {
+        let (Ok(()) | Err(_)) = self.event_write(kind).await;
+    }
+}
+
+#[async_trait(?Send)]
+impl<E, W> OutputWrite<E> for JsonLinesOutput<W>
+where
+    E: std::error::Error + From<Error>,
+    W: AsyncWrite + std::fmt::Debug + std::marker::Unpin,
+{
+    #[cfg(feature = "output_progress")]
+    async fn progress_begin(&mut self, cmd_progress_tracker: &CmdProgressTracker) {
+        let item_ids = cmd_progress_tracker
+            .progress_trackers()
+            .keys()
+            .cloned()
+            .collect::<Vec<ItemId>>();
+        self.progress_event_write(JsonLinesEventKind::ProgressBegin { item_ids })
+            .await;
+    }
+
+    #[cfg(feature = "output_progress")]
+    async fn cmd_block_start(
+        &mut self,
+        cmd_block_item_interaction_type: CmdBlockItemInteractionType,
+    ) {
+        self.progress_event_write(JsonLinesEventKind::CmdBlockStart {
+            cmd_block_item_interaction_type,
+        })
+        .await;
+    }
+
+    #[cfg(feature = "output_progress")]
+    async fn item_location_state(
+        &mut self,
+        item_id: ItemId,
+        item_location_state: ItemLocationState,
+    ) {
+        self.progress_event_write(JsonLinesEventKind::ItemLocationState {
+            item_id,
+            item_location_state,
+        })
+        .await;
+    }
+
+    #[cfg(feature = "output_progress")]
+    async fn progress_update(
+        &mut self,
+        _progress_tracker: &ProgressTracker,
+        progress_update_and_id: &ProgressUpdateAndId,
+    ) {
+        self.progress_event_write(JsonLinesEventKind::ProgressUpdate(
+            progress_update_and_id.clone(),
+        ))
+        .await;
+    }
+
+    #[cfg(feature = "output_progress")]
+    async fn progress_end(&mut self, _cmd_progress_tracker: &CmdProgressTracker) {
+        self.progress_event_write(JsonLinesEventKind::ProgressEnd)
+            .await;
+    }
+
+    async fn present<P>(&mut self, presentable: P) -> Result<(), E>
+    where
+        P: Presentable,
+    {
+        let value = serde_json::to_value(&presentable).map_err(Error::JsonLinesSerialize)?;
+        self.event_write(JsonLinesEventKind::Presentation { value })
+            .await?;
+
+        Ok(())
+    }
+
+    async fn write_err(&mut self, error: &E) -> Result<(), E> {
+        let message = format!("{error}");
+        let causes = std::iter::successors(error.source(), |source| source.source())
+            .map(|source| format!("{source}"))
+            .collect::<Vec<String>>();
+        self.event_write(JsonLinesEventKind::Error { message, causes })
+            .await?;
+
+        Ok(())
+    }
+}
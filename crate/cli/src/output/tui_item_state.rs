@@ -0,0 +1,135 @@
+use peace_core::progress::{
+    ProgressComplete, ProgressMsgUpdate, ProgressStatus, ProgressTracker, ProgressUpdate,
+    ProgressUpdateAndId,
+};
+use peace_item_model::ItemLocationState;
+
+/// Maximum number of progress messages kept for each item.
+const MESSAGES_MAX: usize = 50;
+
+/// Live status of an item shown in the [`TuiOutput`].
+///
+/// [`TuiOutput`]: crate::output::TuiOutput
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TuiItemState {
+    /// Progress status of the item.
+    progress_status: ProgressStatus,
+    /// Number of progress units already completed.
+    units_current: u64,
+    /// Total number of progress units, if known.
+    units_total: Option<u64>,
+    /// Whether the item's resource exists.
+    item_location_state: Option<ItemLocationState>,
+    /// Progress messages received for the item, oldest first.
+    messages: Vec<String>,
+    /// Error message, if the item failed.
+    error: Option<String>,
+    /// The item's state diff, presented as text, if computed.
+    state_diff: Option<String>,
+}
+
+impl TuiItemState {
+    /// Returns a new `TuiItemState` for an item that has not begun.
+    pub fn new() -> Self {
+        Self {
+            progress_status: ProgressStatus::Initialized,
+            units_current: 0,
+            units_total: None,
+            item_location_state: None,
+            messages: Vec::new(),
+            error: None,
+            state_diff: None,
+        }
+    }
+
+    /// Returns the progress status of the item.
+    pub fn progress_status(&self) -> &ProgressStatus {
+        &self.progress_status
+    }
+
+    /// Returns the number of progress units already completed.
+    pub fn units_current(&self) -> u64 {
+        self.units_current
+    }
+
+    /// Returns the total number of progress units, if known.
+    pub fn units_total(&self) -> Option<u64> {
+        self.units_total
+    }
+
+    /// Returns whether the item's resource exists, if known.
+    pub fn item_location_state(&self) -> Option<ItemLocationState> {
+        self.item_location_state
+    }
+
+    /// Returns the progress messages received for the item, oldest first.
+    ///
+    /// Only the last 50 messages are kept.
+    pub fn messages(&self) -> &[String] {
+        &self.messages
+    }
+
+    /// Returns the error message, if the item failed.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Returns the item's state diff, presented as text, if computed.
+    pub fn state_diff(&self) -> Option<&str> {
+        self.state_diff.as_deref()
+    }
+
+    /// Sets whether the item's resource exists.
+    pub(crate) fn item_location_state_set(&mut self, item_location_state: ItemLocationState) {
+        self.item_location_state = Some(item_location_state);
+    }
+
+    /// Sets the item's state diff, presented as text.
+    pub(crate) fn state_diff_set(&mut self, state_diff: String) {
+        self.state_diff = Some(state_diff);
+    }
+
+    /// Updates this state from the item's `ProgressTracker`.
+    pub(crate) fn progress_tracker_sync(&mut self, progress_tracker: &ProgressTracker) {
+        self.progress_status = progress_tracker.progress_status().clone();
+        self.units_current = progress_tracker.units_current();
+        self.units_total = progress_tracker.units_total();
+    }
+
+    /// Applies a progress update to this state.
+    pub(crate) fn progress_update_apply(
+        &mut self,
+        progress_tracker: &ProgressTracker,
+        progress_update_and_id: &ProgressUpdateAndId,
+    ) {
+        self.progress_tracker_sync(progress_tracker);
+
+        if let ProgressMsgUpdate::Set(message) = &progress_update_and_id.msg_update {
+            if self.messages.last() != Some(message) {
+                if self.messages.len() == MESSAGES_MAX {
+                    self.messages.remove(0);
+                }
+                self.messages.push(message.clone());
+            }
+        }
+
+        match &progress_update_and_id.progress_update {
+            ProgressUpdate::Reset | ProgressUpdate::ResetToPending => self.error = None,
+            ProgressUpdate::Complete(ProgressComplete::Fail) => {
+                // The progress message is set to the error message when an item fails.
+                self.error = progress_tracker.message().cloned();
+            }
+            ProgressUpdate::Queued
+            | ProgressUpdate::Interrupt
+            | ProgressUpdate::Limit(_)
+            | ProgressUpdate::Delta(_)
+            | ProgressUpdate::Complete(ProgressComplete::Success) => {}
+        }
+    }
+}
+
+impl Default for TuiItemState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,836 @@
+use std::{
+    io::Stderr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, MutexGuard, PoisonError,
+    },
+    thread::JoinHandle,
+    time::Duration,
+};
+
+use interruptible::InterruptSignal;
+use peace_core::{
+    progress::{
+        CmdBlockItemInteractionType, ProgressComplete, ProgressStatus, ProgressTracker,
+        ProgressUpdateAndId,
+    },
+    ItemId,
+};
+use peace_flow_model::FlowSpecInfo;
+use peace_fmt::Presentable;
+use peace_item_model::ItemLocationState;
+use peace_rt_model_core::{
+    async_trait, output::OutputWrite, CmdProgressTracker, Error, IndexMap, NativeError,
+};
+use ratatui::{
+    backend::{Backend, CrosstermBackend},
+    crossterm::{
+        cursor,
+        event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+        execute,
+        terminal::{self, EnterAlternateScreen, LeaveAlternateScreen},
+    },
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, List, ListItem, ListState, Paragraph},
+    Frame, Terminal,
+};
+use tokio::{
+    io::{AsyncWrite, Stdout},
+    sync::mpsc,
+};
+
+use crate::output::{CliColorizeOpt, CliOutput, CliOutputBuilder, TuiItemState};
+
+/// An `OutputWrite` implementation that draws a full-screen terminal UI.
+///
+/// The screen shows:
+///
+/// * A list of items in flow order, with each item's live status. When the
+///   flow's [`FlowSpecInfo`] is provided through [`with_flow_spec_info`], items
+///   are indented under their predecessors in the flow graph.
+/// * A detail pane with the selected item's progress, messages, error, and
+///   state diff.
+/// * An output pane with the values presented during the command, such as state
+///   diffs.
+///
+/// # Key Bindings
+///
+/// * `↑` / `k`: Select the previous item.
+/// * `↓` / `j`: Select the next item.
+/// * `q` / `Esc` / `Ctrl+C`: Interrupt the command, if an interrupt channel was
+///   provided through [`with_interrupt_tx`].
+///
+/// Key events are read on a separate thread, so they are handled while the
+/// command is not sending progress updates. As raw mode stops `Ctrl+C` from
+/// sending `SIGINT`, pressing `Ctrl+C` when the command cannot be interrupted,
+/// or after an interrupt was already requested, restores the terminal and
+/// exits the process with the status `SIGINT` would have. Applications that
+/// decide how to exit themselves can provide a channel through
+/// [`with_exit_tx`], and an exit signal is sent through it instead.
+///
+/// The terminal is also restored when the `TuiOutput` is dropped, such as when
+/// a command returns an error or panics while the terminal UI is shown.
+///
+/// # Outcome Output
+///
+/// The terminal UI is only shown between `progress_begin` and `progress_end`.
+/// Values presented and errors written outside of that are written through a
+/// [`CliOutput`], so the command's outcome remains on screen after the
+/// terminal UI is closed.
+///
+/// # Testing
+///
+/// [`new_with_backend`] accepts any `ratatui` backend, such as `TestBackend`,
+/// which renders to an in-memory buffer. When constructed this way, the
+/// terminal is not switched to raw mode, and key events are not read from the
+/// terminal -- use [`key_event_handle`] to send them instead.
+///
+/// [`key_event_handle`]: Self::key_event_handle
+/// [`new_with_backend`]: Self::new_with_backend
+/// [`with_exit_tx`]: Self::with_exit_tx
+/// [`with_flow_spec_info`]: Self::with_flow_spec_info
+/// [`with_interrupt_tx`]: Self::with_interrupt_tx
+#[derive(Debug)]
+pub struct TuiOutput<B, W>
+where
+    B: Backend,
+{
+    /// Terminal and UI state, shared with the thread that reads key events.
+    tui_state: Arc<Mutex<TuiState<B>>>,
+    /// Output for values presented while the terminal UI is not shown.
+    cli_output: CliOutput<W>,
+    /// Whether to take over the terminal and read key events from it.
+    terminal_interactive: bool,
+    /// Thread that reads key events while the terminal UI is shown.
+    terminal_events_thread: Option<TerminalEventsThread>,
+}
+
+/// Terminal and UI state of a [`TuiOutput`].
+#[derive(Debug)]
+struct TuiState<B>
+where
+    B: Backend,
+{
+    /// Terminal to draw the UI to.
+    terminal: Terminal<B>,
+    /// Whether the terminal UI is currently shown.
+    active: bool,
+    /// Channel to send an interrupt signal to the command through.
+    interrupt_tx: Option<mpsc::Sender<InterruptSignal>>,
+    /// Whether the user has requested the command to be interrupted.
+    interrupt_requested: bool,
+    /// Channel to send an exit signal to the application through.
+    exit_tx: Option<mpsc::Sender<InterruptSignal>>,
+    /// Whether the user has requested the application to exit.
+    exit_requested: bool,
+    /// Whether the exit signal was sent through `exit_tx`.
+    exit_signal_sent: bool,
+    /// The interaction type of the currently running `CmdBlock`.
+    cmd_block_item_interaction_type: Option<CmdBlockItemInteractionType>,
+    /// Live status of each item, in flow order.
+    item_states: IndexMap<ItemId, TuiItemState>,
+    /// Depth of each item in the flow graph, used to indent items.
+    item_depths: IndexMap<ItemId, usize>,
+    /// Index of the selected item.
+    item_selected: usize,
+    /// Text of values presented while the terminal UI is shown.
+    output_text: String,
+}
+
+/// Thread that reads key events from the terminal, so that they are handled
+/// while no progress updates are received.
+#[derive(Debug)]
+struct TerminalEventsThread {
+    /// Set to stop the thread.
+    stop: Arc<AtomicBool>,
+    /// Handle to wait for the thread to stop.
+    join_handle: JoinHandle<()>,
+}
+
+impl TuiOutput<CrosstermBackend<Stderr>, Stdout> {
+    /// Returns a new `TuiOutput` that draws to `stderr`.
+    ///
+    /// Key events are read from the terminal while the terminal UI is shown,
+    /// and outcome output is written to `stdout`.
+    pub fn new() -> Result<Self, NativeError> {
+        let terminal = Terminal::new(CrosstermBackend::new(std::io::stderr()))
+            .map_err(NativeError::TuiTerminalInit)?;
+
+        let mut tui_output = Self::new_internal(terminal, CliOutput::new());
+        tui_output.terminal_interactive = true;
+        Ok(tui_output)
+    }
+}
+
+impl<B, W> TuiOutput<B, W>
+where
+    B: Backend,
+    W: AsyncWrite + std::marker::Unpin,
+{
+    /// Returns a new `TuiOutput` that draws to the given backend.
+    ///
+    /// # Parameters
+    ///
+    /// * `backend`: The `ratatui` backend to draw to, e.g. `TestBackend`.
+    /// * `cli_output`: Output for values presented while the terminal UI is not
+    ///   shown.
+    pub fn new_with_backend(backend: B, cli_output: CliOutput<W>) -> Result<Self, NativeError> {
+        let terminal = Terminal::new(backend).map_err(NativeError::TuiTerminalInit)?;
+
+        Ok(Self::new_internal(terminal, cli_output))
+    }
+
+    fn new_internal(terminal: Terminal<B>, cli_output: CliOutput<W>) -> Self {
+        let tui_state = TuiState {
+            terminal,
+            active: false,
+            interrupt_tx: None,
+            interrupt_requested: false,
+            exit_tx: None,
+            exit_requested: false,
+            exit_signal_sent: false,
+            cmd_block_item_interaction_type: None,
+            item_states: IndexMap::new(),
+            item_depths: IndexMap::new(),
+            item_selected: 0,
+            output_text: String::new(),
+        };
+
+        Self {
+            tui_state: Arc::new(Mutex::new(tui_state)),
+            cli_output,
+            terminal_interactive: false,
+            terminal_events_thread: None,
+        }
+    }
+
+    /// Sets the channel to send an interrupt signal through when the user
+    /// presses `q`, `Esc`, or `Ctrl+C`.
+    ///
+    /// This should be the sender for the `Interruptibility` passed to the
+    /// `CmdCtx`.
+    pub fn with_interrupt_tx(self, interrupt_tx: mpsc::Sender<InterruptSignal>) -> Self {
+        self.tui_state_lock().interrupt_tx = Some(interrupt_tx);
+        self
+    }
+
+    /// Sets the channel to send an exit signal through when the user presses
+    /// `Ctrl+C` and the command cannot be interrupted, or an interrupt was
+    /// already requested.
+    ///
+    /// Without this, the process exits with the status `SIGINT` would have.
+    /// The terminal is restored before the signal is sent, so the application
+    /// may exit the process when it receives the signal.
+    pub fn with_exit_tx(self, exit_tx: mpsc::Sender<InterruptSignal>) -> Self {
+        self.tui_state_lock().exit_tx = Some(exit_tx);
+        self
+    }
+
+    /// Sets the flow whose items are shown, so that items are indented under
+    /// their predecessors in the flow graph.
+    ///
+    /// This is typically `flow.flow_spec_info()`.
+    pub fn with_flow_spec_info(self, flow_spec_info: &FlowSpecInfo) -> Self {
+        self.tui_state_lock().item_depths = flow_spec_info.item_depths().into_iter().collect();
+        self
+    }
+
+    /// Returns a copy of the backend that the terminal UI is drawn to.
+    pub fn backend(&self) -> B
+    where
+        B: Clone,
+    {
+        self.tui_state_lock().terminal.backend().clone()
+    }
+
+    /// Returns the live status of each item, in flow order.
+    pub fn item_states(&self) -> IndexMap<ItemId, TuiItemState> {
+        self.tui_state_lock().item_states.clone()
+    }
+
+    /// Returns the ID of the selected item, if any.
+    pub fn item_selected(&self) -> Option<ItemId> {
+        let tui_state = self.tui_state_lock();
+        tui_state
+            .item_states
+            .get_index(tui_state.item_selected)
+            .map(|(item_id, _item_state)| item_id.clone())
+    }
+
+    /// Returns whether the user has requested the command to be interrupted.
+    pub fn interrupt_requested(&self) -> bool {
+        self.tui_state_lock().interrupt_requested
+    }
+
+    /// Returns whether the user has requested the application to exit.
+    pub fn exit_requested(&self) -> bool {
+        self.tui_state_lock().exit_requested
+    }
+
+    /// Handles a key event, and redraws the terminal UI if it is shown.
+    ///
+    /// Key events read from the terminal are handled the same way. This may be
+    /// called directly when the `TuiOutput` does not read from a terminal.
+    pub fn key_event_handle(&mut self, key_event: KeyEvent) {
+        let terminal_interactive = self.terminal_interactive;
+        let mut tui_state = self.tui_state_lock();
+        tui_state.key_event_handle(key_event);
+        tui_state.exit_signal_send(terminal_interactive);
+    }
+}
+
+impl<B, W> TuiOutput<B, W>
+where
+    B: Backend,
+{
+    /// Returns the terminal and UI state.
+    fn tui_state_lock(&self) -> MutexGuard<'_, TuiState<B>> {
+        self.tui_state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Shows the terminal UI, switching the terminal to raw mode and the
+    /// alternate screen, and starting the thread that reads key events.
+    ///
+    /// This is infallible as progress information is considered transient.
+    fn terminal_enter(&mut self)
+    where
+        B: Send + 'static,
+    {
+        if self.terminal_interactive {
+            let (Ok(()) | Err(_)) = terminal::enable_raw_mode();
+            let (Ok(()) | Err(_)) = execute!(std::io::stderr(), EnterAlternateScreen);
+            self.terminal_events_thread = TerminalEventsThread::spawn(self.tui_state.clone());
+        }
+
+        let mut tui_state = self.tui_state_lock();
+        let (Ok(()) | Err(_)) = tui_state.terminal.clear();
+        tui_state.active = true;
+        tui_state.draw();
+    }
+
+    /// Hides the terminal UI, stopping the thread that reads key events, and
+    /// restoring the terminal from raw mode and the alternate screen.
+    fn terminal_leave(&mut self) {
+        if let Some(terminal_events_thread) = self.terminal_events_thread.take() {
+            terminal_events_thread.stop();
+        }
+
+        self.tui_state_lock().active = false;
+        if self.terminal_interactive {
+            terminal_restore();
+        }
+    }
+}
+
+impl<B, W> Drop for TuiOutput<B, W>
+where
+    B: Backend,
+{
+    /// Restores the terminal if the terminal UI is still shown, such as when
+    /// the command returns an error or panics before `progress_end`.
+    fn drop(&mut self) {
+        if self.tui_state_lock().active {
+            self.terminal_leave();
+        }
+    }
+}
+
+impl TerminalEventsThread {
+    /// How long to wait for a key event before checking whether to stop.
+    const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+    /// Spawns a thread that handles key events read from the terminal.
+    ///
+    /// Raw mode stops `Ctrl+C` from sending `SIGINT`, so when the command
+    /// cannot be interrupted -- there is no interrupt channel, or an interrupt
+    /// was already requested -- `Ctrl+C` restores the terminal, and either
+    /// sends the exit signal and stops the thread, or exits the process.
+    ///
+    /// Returns `None` if the thread could not be spawned.
+    fn spawn<B>(tui_state: Arc<Mutex<TuiState<B>>>) -> Option<Self>
+    where
+        B: Backend + Send + 'static,
+    {
+        let stop = Arc::new(AtomicBool::new(false));
+        let join_handle = std::thread::Builder::new()
+            .name(String::from("peace_tui_events"))
+            .spawn({
+                let stop = stop.clone();
+                move || {
+                    while !stop.load(Ordering::Relaxed) {
+                        match event::poll(Self::POLL_INTERVAL) {
+                            Ok(true) => {}
+                            Ok(false) => continue,
+                            Err(_) => break,
+                        }
+                        let key_event = match event::read() {
+                            Ok(Event::Key(key_event)) => key_event,
+                            Ok(_) => continue,
+                            Err(_) => break,
+                        };
+
+                        let mut tui_state =
+                            tui_state.lock().unwrap_or_else(PoisonError::into_inner);
+                        tui_state.key_event_handle(key_event);
+                        if tui_state.exit_signal_send(true) {
+                            break;
+                        }
+                    }
+                }
+            })
+            .ok()?;
+
+        Some(Self { stop, join_handle })
+    }
+
+    /// Stops the thread, and waits for it to finish.
+    fn stop(self) {
+        self.stop.store(true, Ordering::Relaxed);
+        let (Ok(()) | Err(_)) = self.join_handle.join();
+    }
+}
+
+/// Exit status of a process terminated by `SIGINT`, as reported by shells.
+const EXIT_CODE_SIGINT: i32 = 130;
+
+/// Restores the terminal from raw mode and the alternate screen.
+fn terminal_restore() {
+    let (Ok(()) | Err(_)) = execute!(std::io::stderr(), LeaveAlternateScreen, cursor::Show);
+    let (Ok(()) | Err(_)) = terminal::disable_raw_mode();
+}
+
+impl<B> TuiState<B>
+where
+    B: Backend,
+{
+    /// Handles a key event, and redraws the terminal UI if it is shown.
+    fn key_event_handle(&mut self, key_event: KeyEvent) {
+        if key_event.kind != KeyEventKind::Press {
+            return;
+        }
+
+        match key_event.code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.item_selected = self.item_selected.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j')
+                if self.item_selected + 1 < self.item_states.len() =>
+            {
+                self.item_selected += 1;
+            }
+            KeyCode::Char('q') | KeyCode::Esc => self.interrupt_request(),
+            KeyCode::Char('c') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                if self.interrupt_tx.is_some() && !self.interrupt_requested {
+                    self.interrupt_request()
+                } else {
+                    self.exit_request()
+                }
+            }
+            _ => {}
+        }
+
+        self.draw();
+    }
+
+    /// Records that the user requested the application to exit.
+    fn exit_request(&mut self) {
+        self.exit_requested = true;
+    }
+
+    /// Sends the exit signal if the user requested the application to exit,
+    /// hiding the terminal UI first.
+    ///
+    /// The signal is sent once. Without an exit channel, the process exits
+    /// with the status `SIGINT` would have, if the terminal UI reads from the
+    /// terminal. Returns whether the user requested the application to exit.
+    fn exit_signal_send(&mut self, terminal_interactive: bool) -> bool {
+        if !self.exit_requested {
+            return false;
+        }
+        if self.exit_signal_sent {
+            return true;
+        }
+
+        if self.active {
+            self.active = false;
+            if terminal_interactive {
+                terminal_restore();
+            }
+        }
+        match self.exit_tx.as_ref() {
+            Some(exit_tx) => {
+                self.exit_signal_sent = true;
+                let (Ok(()) | Err(_)) = exit_tx.try_send(InterruptSignal);
+            }
+            None if terminal_interactive => std::process::exit(EXIT_CODE_SIGINT),
+            None => {}
+        }
+
+        true
+    }
+
+    /// Sends an interrupt signal to the command, if not already sent.
+    fn interrupt_request(&mut self) {
+        if self.interrupt_requested {
+            return;
+        }
+        self.interrupt_requested = true;
+
+        if let Some(interrupt_tx) = self.interrupt_tx.as_ref() {
+            let (Ok(()) | Err(_)) = interrupt_tx.try_send(InterruptSignal);
+        }
+    }
+
+    /// Draws the terminal UI, if it is shown.
+    fn draw(&mut self) {
+        if !self.active {
+            return;
+        }
+
+        let Self {
+            terminal,
+            active: _,
+            interrupt_tx,
+            interrupt_requested,
+            exit_tx: _,
+            exit_requested: _,
+            exit_signal_sent: _,
+            cmd_block_item_interaction_type,
+            item_states,
+            item_depths,
+            item_selected,
+            output_text,
+        } = self;
+
+        let (Ok(_) | Err(_)) = terminal.draw(|frame| {
+            let [body_area, footer_area] =
+                Layout::vertical([Constraint::Min(3), Constraint::Length(1)]).areas(frame.area());
+            let [items_area, side_area] =
+                Layout::horizontal([Constraint::Percentage(40), Constraint::Percentage(60)])
+                    .areas(body_area);
+            let [detail_area, output_area] =
+                Layout::vertical([Constraint::Percentage(50), Constraint::Percentage(50)])
+                    .areas(side_area);
+
+            Self::items_render(frame, items_area, item_states, item_depths, *item_selected);
+            Self::detail_render(frame, detail_area, item_states, *item_selected);
+            Self::output_render(frame, output_area, output_text);
+            Self::footer_render(
+                frame,
+                footer_area,
+                *cmd_block_item_interaction_type,
+                interrupt_tx.is_some(),
+                *interrupt_requested,
+            );
+        });
+    }
+
+    fn items_render(
+        frame: &mut Frame<'_>,
+        area: Rect,
+        item_states: &IndexMap<ItemId, TuiItemState>,
+        item_depths: &IndexMap<ItemId, usize>,
+        item_selected: usize,
+    ) {
+        let item_labels = item_states
+            .keys()
+            .map(
+                |item_id| match item_depths.get(item_id).copied().unwrap_or(0) {
+                    0 => item_id.to_string(),
+                    depth => format!("{}└ {item_id}", "  ".repeat(depth - 1)),
+                },
+            )
+            .collect::<Vec<String>>();
+        let item_label_width = item_labels
+            .iter()
+            .map(|item_label| item_label.chars().count())
+            .max()
+            .unwrap_or(0);
+        let list_items = item_states
+            .values()
+            .zip(item_labels.iter())
+            .map(|(item_state, item_label)| {
+                let progress_status = item_state.progress_status();
+                let mut spans = vec![
+                    Span::raw(format!("{item_label:item_label_width$} ")),
+                    Span::styled(
+                        Self::progress_status_label(progress_status),
+                        Self::progress_status_style(progress_status),
+                    ),
+                ];
+                if let Some(units) = Self::units_label(item_state) {
+                    spans.push(Span::raw(format!(" {units}")));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect::<Vec<ListItem<'_>>>();
+
+        let list = List::new(list_items)
+            .block(Block::bordered().title(" Items "))
+            .highlight_style(Style::new().add_modifier(Modifier::REVERSED))
+            .highlight_symbol("> ");
+        let mut list_state = ListState::default().with_selected(Some(item_selected));
+
+        frame.render_stateful_widget(list, area, &mut list_state);
+    }
+
+    fn detail_render(
+        frame: &mut Frame<'_>,
+        area: Rect,
+        item_states: &IndexMap<ItemId, TuiItemState>,
+        item_selected: usize,
+    ) {
+        let Some((item_id, item_state)) = item_states.get_index(item_selected) else {
+            frame.render_widget(Block::bordered().title(" Detail "), area);
+            return;
+        };
+
+        let progress_status = item_state.progress_status();
+        let mut lines = vec![Line::from(vec![
+            Span::raw("status: "),
+            Span::styled(
+                Self::progress_status_label(progress_status),
+                Self::progress_status_style(progress_status),
+            ),
+        ])];
+        if let Some(units) = Self::units_label(item_state) {
+            lines.push(Line::raw(format!("progress: {units}")));
+        }
+        if let Some(item_location_state) = item_state.item_location_state() {
+            let location = match item_location_state {
+                ItemLocationState::Exists => "exists",
+                ItemLocationState::NotExists => "not exists",
+            };
+            lines.push(Line::raw(format!("location: {location}")));
+        }
+        if !item_state.messages().is_empty() {
+            lines.push(Line::raw("messages:"));
+            lines.extend(
+                item_state
+                    .messages()
+                    .iter()
+                    .map(|message| Line::raw(format!("  {message}"))),
+            );
+        }
+        if let Some(error) = item_state.error() {
+            lines.push(Line::styled(
+                format!("error: {error}"),
+                Style::new().fg(Color::Indexed(160)),
+            ));
+        }
+        if let Some(state_diff) = item_state.state_diff() {
+            lines.push(Line::raw("diff:"));
+            lines.extend(
+                state_diff
+                    .lines()
+                    .map(|state_diff_line| Line::raw(format!("  {state_diff_line}"))),
+            );
+        }
+
+        let paragraph =
+            Paragraph::new(lines).block(Block::bordered().title(format!(" {item_id} ")));
+        frame.render_widget(paragraph, area);
+    }
+
+    fn output_render(frame: &mut Frame<'_>, area: Rect, output_text: &str) {
+        // Scroll so that the latest output is visible.
+        let line_count = output_text.lines().count();
+        let rows_visible = usize::from(area.height.saturating_sub(2));
+        let scroll = u16::try_from(line_count.saturating_sub(rows_visible)).unwrap_or(u16::MAX);
+
+        let paragraph = Paragraph::new(output_text)
+            .block(Block::bordered().title(" Output "))
+            .scroll((scroll, 0));
+        frame.render_widget(paragraph, area);
+    }
+
+    fn footer_render(
+        frame: &mut Frame<'_>,
+        area: Rect,
+        cmd_block_item_interaction_type: Option<CmdBlockItemInteractionType>,
+        interruptible: bool,
+        interrupt_requested: bool,
+    ) {
+        let mut spans = vec![Span::raw(" ↑/↓ select")];
+        if interruptible {
+            spans.push(Span::raw("  q interrupt"));
+        }
+        if let Some(cmd_block_item_interaction_type) = cmd_block_item_interaction_type {
+            let interaction = match cmd_block_item_interaction_type {
+                CmdBlockItemInteractionType::Write => "write",
+                CmdBlockItemInteractionType::Read => "read",
+                CmdBlockItemInteractionType::Local => "local",
+            };
+            spans.push(Span::raw(format!("  step: {interaction}")));
+        }
+        if interrupt_requested {
+            spans.push(Span::styled(
+                "  interrupting..",
+                Style::new().fg(Color::Indexed(220)),
+            ));
+        }
+
+        frame.render_widget(Line::from(spans), area);
+    }
+
+    fn progress_status_label(progress_status: &ProgressStatus) -> &'static str {
+        match progress_status {
+            ProgressStatus::Initialized => "initialized",
+            ProgressStatus::Interrupted => "interrupted",
+            ProgressStatus::ExecPending => "pending",
+            ProgressStatus::Queued => "queued",
+            ProgressStatus::Running => "running",
+            ProgressStatus::RunningStalled => "stalled",
+            ProgressStatus::UserPending => "user pending",
+            ProgressStatus::Complete(ProgressComplete::Success) => "complete",
+            ProgressStatus::Complete(ProgressComplete::Fail) => "failed",
+        }
+    }
+
+    /// Returns the style for a progress status, using the same colours as the
+    /// `CliOutput` progress bars.
+    fn progress_status_style(progress_status: &ProgressStatus) -> Style {
+        let color = match progress_status {
+            ProgressStatus::Initialized | ProgressStatus::ExecPending | ProgressStatus::Queued => {
+                Color::Indexed(8)
+            }
+            ProgressStatus::Running => Color::Indexed(32),
+            ProgressStatus::RunningStalled => Color::Indexed(208),
+            ProgressStatus::Interrupted => Color::Indexed(220),
+            ProgressStatus::UserPending => Color::Indexed(75),
+            ProgressStatus::Complete(ProgressComplete::Success) => Color::Indexed(35),
+            ProgressStatus::Complete(ProgressComplete::Fail) => Color::Indexed(160),
+        };
+        Style::new().fg(color)
+    }
+
+    fn units_label(item_state: &TuiItemState) -> Option<String> {
+        item_state
+            .units_total()
+            .map(|units_total| format!("{}/{units_total}", item_state.units_current()))
+    }
+}
+
+#[async_trait(?Send)]
+impl<E, B, W> OutputWrite<E> for TuiOutput<B, W>
+where
+    E: std::error::Error + From<Error>,
+    B: Backend + std::fmt::Debug + Send + std::marker::Unpin + 'static,
+    W: AsyncWrite + std::fmt::Debug + std::marker::Unpin,
+{
+    async fn progress_begin(&mut self, cmd_progress_tracker: &CmdProgressTracker) {
+        {
+            let mut tui_state = self.tui_state_lock();
+            tui_state.item_states = cmd_progress_tracker
+                .progress_trackers()
+                .keys()
+                .map(|item_id| (item_id.clone(), TuiItemState::new()))
+                .collect();
+            tui_state.item_selected = 0;
+            tui_state.interrupt_requested = false;
+            tui_state.cmd_block_item_interaction_type = None;
+            tui_state.output_text.clear();
+        }
+
+        self.terminal_enter();
+    }
+
+    async fn cmd_block_start(
+        &mut self,
+        cmd_block_item_interaction_type: CmdBlockItemInteractionType,
+    ) {
+        let mut tui_state = self.tui_state_lock();
+        tui_state.cmd_block_item_interaction_type = Some(cmd_block_item_interaction_type);
+        tui_state.draw();
+    }
+
+    async fn item_location_state(
+        &mut self,
+        item_id: ItemId,
+        item_location_state: ItemLocationState,
+    ) {
+        let mut tui_state = self.tui_state_lock();
+        if let Some(item_state) = tui_state.item_states.get_mut(&item_id) {
+            item_state.item_location_state_set(item_location_state);
+        }
+        tui_state.draw();
+    }
+
+    async fn item_state_diff(&mut self, item_id: ItemId, state_diff: String) {
+        let mut tui_state = self.tui_state_lock();
+        if let Some(item_state) = tui_state.item_states.get_mut(&item_id) {
+            item_state.state_diff_set(state_diff);
+        }
+        tui_state.draw();
+    }
+
+    async fn progress_update(
+        &mut self,
+        progress_tracker: &ProgressTracker,
+        progress_update_and_id: &ProgressUpdateAndId,
+    ) {
+        let mut tui_state = self.tui_state_lock();
+        if let Some(item_state) = tui_state
+            .item_states
+            .get_mut(&progress_update_and_id.item_id)
+        {
+            item_state.progress_update_apply(progress_tracker, progress_update_and_id);
+        }
+        tui_state.draw();
+    }
+
+    async fn progress_end(&mut self, cmd_progress_tracker: &CmdProgressTracker) {
+        {
+            let mut tui_state = self.tui_state_lock();
+            cmd_progress_tracker.progress_trackers().iter().for_each(
+                |(item_id, progress_tracker)| {
+                    if let Some(item_state) = tui_state.item_states.get_mut(item_id) {
+                        item_state.progress_tracker_sync(progress_tracker);
+                    }
+                },
+            );
+            tui_state.draw();
+        }
+
+        self.terminal_leave();
+    }
+
+    async fn present<P>(&mut self, presentable: P) -> Result<(), E>
+    where
+        P: Presentable,
+    {
+        if !self.tui_state_lock().active {
+            return self.cli_output.present(presentable).await;
+        }
+
+        let mut buffer = Vec::<u8>::new();
+        let mut cli_output = CliOutputBuilder::new_with_writer(&mut buffer)
+            .with_colorize(CliColorizeOpt::Never)
+            .build();
+        <CliOutput<_> as OutputWrite<E>>::present(&mut cli_output, presentable).await?;
+
+        let mut tui_state = self.tui_state_lock();
+        tui_state
+            .output_text
+            .push_str(&String::from_utf8_lossy(&buffer));
+        tui_state.draw();
+
+        Ok(())
+    }
+
+    async fn write_err(&mut self, error: &E) -> Result<(), E> {
+        if !self.tui_state_lock().active {
+            return self.cli_output.write_err(error).await;
+        }
+
+        let mut tui_state = self.tui_state_lock();
+        tui_state.output_text.push_str(&format!("{error}\n"));
+        tui_state.draw();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,14 @@
+//! Reports of command outcomes for CI systems and pull request comments.
+
+pub use self::{
+    cmd_outcome_report::CmdOutcomeReport, report_format::ReportFormat,
+    report_format_parse_error::ReportFormatParseError, report_item::ReportItem,
+    report_item_status::ReportItemStatus, report_writer::ReportWriter,
+};
+
+mod cmd_outcome_report;
+mod report_format;
+mod report_format_parse_error;
+mod report_item;
+mod report_item_status;
+mod report_writer;
@@ -0,0 +1,160 @@
+use peace_cmd_model::CmdOutcome;
+use peace_core::ItemId;
+
+use crate::report::{ReportItem, ReportItemStatus};
+
+/// Per item results of a command execution, to be written as a report.
+///
+/// Use [`ReportWriter`] to write this as JUnit XML or Markdown.
+///
+/// [`ReportWriter`]: crate::report::ReportWriter
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CmdOutcomeReport {
+    /// Name of the command, used as the test suite name.
+    pub name: String,
+    /// Results of each item, in flow order.
+    pub items: Vec<ReportItem>,
+    /// Why the command was interrupted between command blocks, if it was.
+    ///
+    /// When this is `Some`, it is not known which items were processed, so
+    /// `items` is empty.
+    pub interruption: Option<String>,
+}
+
+impl CmdOutcomeReport {
+    /// Returns a report of the `CmdOutcome` for the given items.
+    ///
+    /// `item_ids` should be the IDs of all items in the flow, in order, as a
+    /// `CmdOutcome::Complete` does not record which items were processed.
+    ///
+    /// # Parameters
+    ///
+    /// * `name`: Name of the command, e.g. `"ensure"`.
+    /// * `item_ids`: IDs of the items in the flow.
+    /// * `cmd_outcome`: Outcome of the command execution.
+    pub fn from_cmd_outcome<T, E>(
+        name: impl Into<String>,
+        item_ids: impl IntoIterator<Item = ItemId>,
+        cmd_outcome: &CmdOutcome<T, E>,
+    ) -> Self
+    where
+        E: std::error::Error,
+    {
+        let items = item_ids
+            .into_iter()
+            .filter_map(|item_id| {
+                let status = Self::item_status(&item_id, cmd_outcome)?;
+                Some(ReportItem { item_id, status })
+            })
+            .collect::<Vec<ReportItem>>();
+        let interruption = match cmd_outcome {
+            CmdOutcome::ExecutionInterrupted {
+                cmd_blocks_not_processed,
+                ..
+            } => {
+                let cmd_block_names = cmd_blocks_not_processed
+                    .iter()
+                    .map(|cmd_block_desc| format!("`{}`", cmd_block_desc.cmd_block_name()))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                Some(format!(
+                    "Interrupted before command blocks were processed: {cmd_block_names}."
+                ))
+            }
+            CmdOutcome::Complete { .. }
+            | CmdOutcome::BlockInterrupted { .. }
+            | CmdOutcome::ItemError { .. } => None,
+        };
+
+        Self {
+            name: name.into(),
+            items,
+            interruption,
+        }
+    }
+
+    /// Returns the number of items that were processed successfully.
+    pub fn success_count(&self) -> usize {
+        self.items
+            .iter()
+            .filter(|item| matches!(item.status, ReportItemStatus::Success))
+            .count()
+    }
+
+    /// Returns the number of items that returned an error.
+    pub fn failure_count(&self) -> usize {
+        self.items
+            .iter()
+            .filter(|item| matches!(item.status, ReportItemStatus::Failure { .. }))
+            .count()
+    }
+
+    /// Returns the number of items that were not processed.
+    pub fn skipped_count(&self) -> usize {
+        self.items
+            .iter()
+            .filter(|item| matches!(item.status, ReportItemStatus::Skipped { .. }))
+            .count()
+    }
+
+    /// Returns whether the command was not interrupted, and every item was
+    /// processed successfully.
+    pub fn is_successful(&self) -> bool {
+        self.interruption.is_none()
+            && self
+                .items
+                .iter()
+                .all(|item| matches!(item.status, ReportItemStatus::Success))
+    }
+
+    /// Returns the status of the item, or `None` if it is not known whether
+    /// the item was processed.
+    ///
+    /// `CmdOutcome::ExecutionInterrupted` only records which command blocks
+    /// were processed, not which items.
+    fn item_status<T, E>(
+        item_id: &ItemId,
+        cmd_outcome: &CmdOutcome<T, E>,
+    ) -> Option<ReportItemStatus>
+    where
+        E: std::error::Error,
+    {
+        let item_status = match cmd_outcome {
+            CmdOutcome::Complete { .. } => ReportItemStatus::Success,
+            CmdOutcome::BlockInterrupted {
+                item_stream_outcome,
+                ..
+            } => {
+                if item_stream_outcome.item_ids_not_processed.contains(item_id) {
+                    ReportItemStatus::Skipped {
+                        reason: String::from("Interrupted before the item was processed."),
+                    }
+                } else {
+                    ReportItemStatus::Success
+                }
+            }
+            CmdOutcome::ExecutionInterrupted { .. } => return None,
+            CmdOutcome::ItemError {
+                item_stream_outcome,
+                errors,
+                ..
+            } => {
+                if let Some(error) = errors.get(item_id) {
+                    let message = format!("{error}");
+                    let causes = std::iter::successors(error.source(), |source| source.source())
+                        .map(|source| format!("{source}"))
+                        .collect::<Vec<String>>();
+                    ReportItemStatus::Failure { message, causes }
+                } else if item_stream_outcome.item_ids_not_processed.contains(item_id) {
+                    ReportItemStatus::Skipped {
+                        reason: String::from("Not processed because another item failed."),
+                    }
+                } else {
+                    ReportItemStatus::Success
+                }
+            }
+        };
+
+        Some(item_status)
+    }
+}
@@ -0,0 +1,28 @@
+use std::str::FromStr;
+
+use crate::report::ReportFormatParseError;
+
+/// Format to write a [`CmdOutcomeReport`] in.
+///
+/// [`CmdOutcomeReport`]: crate::report::CmdOutcomeReport
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// JUnit XML, with each item as a test case.
+    ///
+    /// Most CI systems render this natively.
+    JUnitXml,
+    /// Markdown summary table, suitable for a pull request comment.
+    Markdown,
+}
+
+impl FromStr for ReportFormat {
+    type Err = ReportFormatParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "junit" | "junit_xml" => Ok(Self::JUnitXml),
+            "md" | "markdown" => Ok(Self::Markdown),
+            _ => Err(ReportFormatParseError(s.to_string())),
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use std::fmt;
+
+/// Failed to parse report format from string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReportFormatParseError(pub String);
+
+impl fmt::Display for ReportFormatParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Failed to parse report format from string: `\"{}\"`.\n\
+            Valid values are [\"junit\", \"junit_xml\", \"md\", \"markdown\"]",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for ReportFormatParseError {}
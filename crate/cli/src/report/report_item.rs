@@ -0,0 +1,14 @@
+use peace_core::ItemId;
+
+use crate::report::ReportItemStatus;
+
+/// An item's result in a [`CmdOutcomeReport`].
+///
+/// [`CmdOutcomeReport`]: crate::report::CmdOutcomeReport
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReportItem {
+    /// ID of the item.
+    pub item_id: ItemId,
+    /// How the item fared.
+    pub status: ReportItemStatus,
+}
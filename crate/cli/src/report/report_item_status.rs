@@ -0,0 +1,20 @@
+/// How an item fared in a command execution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReportItemStatus {
+    /// The item was processed successfully.
+    Success,
+    /// The item returned an error.
+    Failure {
+        /// The error's display message.
+        message: String,
+        /// Display messages of the error's sources, from outermost to
+        /// innermost.
+        causes: Vec<String>,
+    },
+    /// The item was not processed, e.g. because the command was interrupted,
+    /// or a predecessor item failed.
+    Skipped {
+        /// Why the item was not processed.
+        reason: String,
+    },
+}
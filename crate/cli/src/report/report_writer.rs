@@ -0,0 +1,219 @@
+use std::fmt::Write;
+
+use peace_rt_model_core::{Error, NativeError};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::report::{CmdOutcomeReport, ReportFormat, ReportItemStatus};
+
+/// Writes [`CmdOutcomeReport`]s as JUnit XML or Markdown.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let report = CmdOutcomeReport::from_cmd_outcome("ensure", item_ids, &cmd_outcome);
+///
+/// let file = tokio::fs::File::create("report.xml").await?;
+/// ReportWriter::new(file)
+///     .write(&report, ReportFormat::JUnitXml)
+///     .await?;
+/// ```
+#[derive(Debug)]
+pub struct ReportWriter<W> {
+    /// Stream to write the report to.
+    writer: W,
+}
+
+impl<W> ReportWriter<W>
+where
+    W: AsyncWrite + std::marker::Unpin,
+{
+    /// Returns a new `ReportWriter` that writes to the given writer.
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Returns the inner writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// Writes the report in the given format.
+    pub async fn write(
+        &mut self,
+        report: &CmdOutcomeReport,
+        report_format: ReportFormat,
+    ) -> Result<(), Error> {
+        let report_str = match report_format {
+            ReportFormat::JUnitXml => Self::junit_xml_render(report),
+            ReportFormat::Markdown => Self::markdown_render(report),
+        };
+
+        self.writer
+            .write_all(report_str.as_bytes())
+            .await
+            .map_err(NativeError::ReportWrite)
+            .map_err(Error::Native)?;
+        self.writer
+            .flush()
+            .await
+            .map_err(NativeError::ReportWrite)
+            .map_err(Error::Native)?;
+
+        Ok(())
+    }
+
+    /// Renders the report as JUnit XML, with the command as the test suite,
+    /// and each item as a test case.
+    fn junit_xml_render(report: &CmdOutcomeReport) -> String {
+        let name = xml_escape(&report.name);
+        let tests = report.items.len();
+        let failures = report.failure_count();
+        let skipped = report.skipped_count();
+
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let _ = writeln!(
+            xml,
+            "<testsuites name=\"{name}\" tests=\"{tests}\" failures=\"{failures}\" \
+            errors=\"0\" skipped=\"{skipped}\">"
+        );
+        let _ = writeln!(
+            xml,
+            "  <testsuite name=\"{name}\" tests=\"{tests}\" failures=\"{failures}\" \
+            errors=\"0\" skipped=\"{skipped}\">"
+        );
+        report.items.iter().for_each(|report_item| {
+            let item_id = xml_escape(report_item.item_id.as_str());
+            match &report_item.status {
+                ReportItemStatus::Success => {
+                    let _ = writeln!(
+                        xml,
+                        "    <testcase name=\"{item_id}\" classname=\"{name}\"/>"
+                    );
+                }
+                ReportItemStatus::Failure { message, causes } => {
+                    let _ = writeln!(
+                        xml,
+                        "    <testcase name=\"{item_id}\" classname=\"{name}\">"
+                    );
+                    let details = std::iter::once(message)
+                        .chain(causes.iter())
+                        .map(String::as_str)
+                        .collect::<Vec<&str>>()
+                        .join("\n");
+                    let _ = writeln!(
+                        xml,
+                        "      <failure message=\"{message}\">{details}</failure>",
+                        message = xml_escape(message),
+                        details = xml_escape(&details),
+                    );
+                    xml.push_str("    </testcase>\n");
+                }
+                ReportItemStatus::Skipped { reason } => {
+                    let _ = writeln!(
+                        xml,
+                        "    <testcase name=\"{item_id}\" classname=\"{name}\">"
+                    );
+                    let _ = writeln!(
+                        xml,
+                        "      <skipped message=\"{reason}\"/>",
+                        reason = xml_escape(reason),
+                    );
+                    xml.push_str("    </testcase>\n");
+                }
+            }
+        });
+        if let Some(interruption) = report.interruption.as_deref() {
+            let _ = writeln!(
+                xml,
+                "    <system-err>{interruption}</system-err>",
+                interruption = xml_escape(interruption),
+            );
+        }
+        xml.push_str("  </testsuite>\n");
+        xml.push_str("</testsuites>\n");
+
+        xml
+    }
+
+    /// Renders the report as a Markdown summary and table of items.
+    fn markdown_render(report: &CmdOutcomeReport) -> String {
+        let (icon, outcome) = if report.failure_count() > 0 {
+            ("❌", "failed")
+        } else if report.interruption.is_some() {
+            ("⏸️", "interrupted")
+        } else if report.skipped_count() > 0 {
+            ("⏸️", "incomplete")
+        } else {
+            ("✅", "succeeded")
+        };
+
+        let mut markdown = String::new();
+        let _ = writeln!(
+            markdown,
+            "### {icon} `{name}` {outcome}\n\n\
+            {success} succeeded, {failure} failed, {skipped} skipped.\n",
+            name = report.name,
+            success = report.success_count(),
+            failure = report.failure_count(),
+            skipped = report.skipped_count(),
+        );
+        if let Some(interruption) = report.interruption.as_deref() {
+            let _ = writeln!(markdown, "{interruption}\n");
+        }
+        if report.items.is_empty() {
+            return markdown;
+        }
+        markdown.push_str("| Item | Outcome | Details |\n");
+        markdown.push_str("| :--- | :------ | :------ |\n");
+        report.items.iter().for_each(|report_item| {
+            let (outcome, details) = match &report_item.status {
+                ReportItemStatus::Success => ("✅ success", String::new()),
+                ReportItemStatus::Failure { message, causes } => {
+                    let details = std::iter::once(message)
+                        .chain(causes.iter())
+                        .map(String::as_str)
+                        .collect::<Vec<&str>>()
+                        .join("<br>");
+                    ("❌ failure", details)
+                }
+                ReportItemStatus::Skipped { reason } => ("⏸️ skipped", reason.clone()),
+            };
+            let _ = writeln!(
+                markdown,
+                "| `{item_id}` | {outcome} | {details} |",
+                item_id = report_item.item_id,
+                details = markdown_cell_escape(&details),
+            );
+        });
+
+        markdown
+    }
+}
+
+/// Escapes characters that are not allowed in XML attribute values or text.
+///
+/// Characters that XML 1.0 does not allow at all, such as control characters
+/// other than tab, line feed, and carriage return, are replaced with `U+FFFD`.
+fn xml_escape(s: &str) -> String {
+    s.chars()
+        .fold(String::with_capacity(s.len()), |mut escaped, c| {
+            match c {
+                '&' => escaped.push_str("&amp;"),
+                '<' => escaped.push_str("&lt;"),
+                '>' => escaped.push_str("&gt;"),
+                '"' => escaped.push_str("&quot;"),
+                '\'' => escaped.push_str("&apos;"),
+                '\t' | '\n' | '\r' => escaped.push(c),
+                '\u{0}'..='\u{1F}' | '\u{FFFE}' | '\u{FFFF}' => {
+                    escaped.push(char::REPLACEMENT_CHARACTER)
+                }
+                _ => escaped.push(c),
+            }
+            escaped
+        })
+}
+
+/// Escapes characters that would break a Markdown table cell.
+fn markdown_cell_escape(s: &str) -> String {
+    s.replace('|', "\\|").replace('\n', "<br>")
+}
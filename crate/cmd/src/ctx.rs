@@ -7,9 +7,11 @@ pub use self::{
         CmdCtxBuilderTypes, CmdCtxBuilderTypesCollector, CmdCtxTypesCollectorEmpty,
     },
     cmd_ctx_types::{CmdCtxTypes, CmdCtxTypesCollector, CmdCtxTypesConstrained},
+    profile_sort_filter::ProfileSortFilter,
 };
 
 mod cmd_ctx;
 mod cmd_ctx_builder;
 mod cmd_ctx_builder_types;
 mod cmd_ctx_types;
+mod profile_sort_filter;
@@ -1,23 +1,32 @@
 #![allow(clippy::type_complexity)]
 
-use std::{fmt::Debug, hash::Hash};
+use std::{
+    collections::BTreeMap,
+    fmt::Debug,
+    hash::Hash,
+    path::{Path, PathBuf},
+};
 
 use futures::stream::{StreamExt, TryStreamExt};
 use interruptible::Interruptibility;
 use own::{OwnedOrMutRef, OwnedOrRef};
-use peace_cfg::ItemId;
-use peace_params::ParamsSpecs;
+use peace_cfg::{FlowId, ItemId};
+use peace_core::Profile;
+use peace_params::{ParamsSpecs, ParamsSpecsOrigins, ParamsSpecsProfileOrigins};
 use peace_resource_rt::{
     internal::{FlowParamsFile, ProfileParamsFile, WorkspaceParamsFile},
-    paths::ParamsSpecsFile,
+    paths::{
+        FlowDir, ParamsSpecsFile, PeaceAppDir, ProfileDir, ProfileParentFile, StatesCurrentFile,
+    },
     resources::ts::{Empty, SetUp},
+    states::StatesCurrentStored,
     Resources,
 };
 use peace_rt_model::{
     fn_graph::resman::Resource,
-    params::{FlowParams, ProfileParams, WorkspaceParams},
-    Flow, ItemGraph, ParamsSpecsSerializer, ParamsSpecsTypeReg, StatesTypeReg, Storage, Workspace,
-    WorkspaceInitializer,
+    params::{FlowParams, ProfileParams, ProfileParamsOrigins, WorkspaceParams},
+    Flow, ItemGraph, ParamsSpecsSerializer, ParamsSpecsTypeReg, StatesSerializer, StatesTypeReg,
+    Storage, Workspace, WorkspaceInitializer,
 };
 use serde::{de::DeserializeOwned, Serialize};
 
@@ -128,6 +137,122 @@ fn profile_params_insert<ProfileParamsK>(
     });
 }
 
+/// Returns the profile's ancestor profiles, nearest parent first.
+///
+/// An error is returned if a parent profile does not exist, or if the parents
+/// refer back to a profile in the chain.
+pub(crate) async fn profile_ancestors(
+    storage: &Storage,
+    peace_app_dir: &PeaceAppDir,
+    profile: &Profile,
+) -> Result<Vec<Profile>, peace_rt_model::Error> {
+    let backend = storage.backend();
+
+    let mut profile_ancestors = Vec::<Profile>::new();
+    let mut profile_current = profile.clone();
+    loop {
+        let profile_dir = ProfileDir::from((peace_app_dir, &profile_current));
+        let profile_parent_file = ProfileParentFile::from(&profile_dir);
+        let Some(profile_parent) =
+            WorkspaceInitializer::profile_parent_deserialize(storage, &profile_parent_file).await?
+        else {
+            break;
+        };
+
+        // The cycle may not include `profile`, e.g. `a -> b -> c -> b`, so it is
+        // reported from the first occurrence of the repeated profile.
+        let profiles_chain = std::iter::once(profile).chain(profile_ancestors.iter());
+        if let Some(cycle_start) = profiles_chain
+            .clone()
+            .position(|profile_chained| profile_chained == &profile_parent)
+        {
+            let profiles_cycle = profiles_chain
+                .skip(cycle_start)
+                .cloned()
+                .chain(std::iter::once(profile_parent))
+                .collect::<Vec<Profile>>();
+            return Err(peace_rt_model::Error::ProfileParentCycle {
+                profile: profile.clone(),
+                profiles_cycle,
+            });
+        }
+
+        let profile_parent_dir = ProfileDir::from((peace_app_dir, &profile_parent));
+        let profile_parent_exists = backend.contains_item(&profile_parent_dir).await?
+            || !backend.paths_under(&profile_parent_dir).await?.is_empty();
+        if !profile_parent_exists {
+            return Err(peace_rt_model::Error::ProfileParentNotFound {
+                profile: profile_current,
+                profile_parent,
+            });
+        }
+
+        profile_ancestors.push(profile_parent.clone());
+        profile_current = profile_parent;
+    }
+
+    Ok(profile_ancestors)
+}
+
+/// Returns each profile's ancestor profiles, nearest parent first.
+pub(crate) async fn profile_to_profile_ancestors(
+    storage: &Storage,
+    peace_app_dir: &PeaceAppDir,
+    profiles: &[Profile],
+) -> Result<BTreeMap<Profile, Vec<Profile>>, peace_rt_model::Error> {
+    let mut profile_to_profile_ancestors = BTreeMap::new();
+    for profile in profiles {
+        let profile_ancestors = profile_ancestors(storage, peace_app_dir, profile).await?;
+        profile_to_profile_ancestors.insert(profile.clone(), profile_ancestors);
+    }
+
+    Ok(profile_to_profile_ancestors)
+}
+
+/// Inherits profile params from an ancestor profile.
+///
+/// Params from `profile_params_ancestor` are added to
+/// `profile_params_inherited` if neither the profile nor a nearer ancestor
+/// has a value for the key. Ancestors must be passed in nearest first.
+fn profile_params_inherit<ProfileParamsK>(
+    profile_params: &ProfileParams<ProfileParamsK>,
+    profile_params_inherited: &mut ProfileParams<ProfileParamsK>,
+    profile_params_origins: &mut ProfileParamsOrigins<ProfileParamsK>,
+    profile_ancestor: &Profile,
+    profile_params_ancestor: ProfileParams<ProfileParamsK>,
+) where
+    ProfileParamsK:
+        Clone + Debug + Eq + Hash + DeserializeOwned + Serialize + Send + Sync + 'static,
+{
+    profile_params_ancestor
+        .into_inner()
+        .into_inner()
+        .into_iter()
+        .for_each(|(key, profile_param)| {
+            if !profile_params.contains_key(&key) && !profile_params_inherited.contains_key(&key) {
+                profile_params_origins.insert(key.clone(), profile_ancestor.clone());
+                profile_params_inherited.insert_raw(key, profile_param);
+            }
+        });
+}
+
+/// Adds inherited profile params to a profile's own profile params.
+fn profile_params_extend<ProfileParamsK>(
+    profile_params: &mut ProfileParams<ProfileParamsK>,
+    profile_params_inherited: ProfileParams<ProfileParamsK>,
+) where
+    ProfileParamsK:
+        Clone + Debug + Eq + Hash + DeserializeOwned + Serialize + Send + Sync + 'static,
+{
+    profile_params_inherited
+        .into_inner()
+        .into_inner()
+        .into_iter()
+        .for_each(|(key, profile_param)| {
+            profile_params.insert_raw(key, profile_param);
+        });
+}
+
 /// Serializes flow params to storage.
 async fn flow_params_serialize<FlowParamsK>(
     flow_params: &FlowParams<FlowParamsK>,
@@ -165,70 +290,55 @@ fn flow_params_insert<FlowParamsK>(
     });
 }
 
-#[cfg(not(target_arch = "wasm32"))]
+/// Returns the profiles in the app directory, in sorted order.
+///
+/// Each directory in the app directory, as listed by the workspace's storage
+/// backend, is a profile directory.
 pub(crate) async fn profiles_from_peace_app_dir(
+    storage: &Storage,
     peace_app_dir: &peace_resource_rt::paths::PeaceAppDir,
     profiles_filter_fn: Option<&dyn Fn(&peace_core::Profile) -> bool>,
 ) -> Result<Vec<peace_core::Profile>, peace_rt_model::Error> {
-    use std::{ffi::OsStr, str::FromStr};
-
-    let mut profiles = Vec::new();
-    let mut peace_app_read_dir = tokio::fs::read_dir(peace_app_dir).await.map_err(
-        #[cfg_attr(coverage_nightly, coverage(off))]
-        |error| {
-            peace_rt_model::Error::Native(peace_rt_model::NativeError::PeaceAppDirRead {
-                peace_app_dir: peace_app_dir.to_path_buf(),
-                error,
-            })
-        },
-    )?;
-    while let Some(entry) = peace_app_read_dir.next_entry().await.map_err(
-        #[cfg_attr(coverage_nightly, coverage(off))]
-        |error| {
-            peace_rt_model::Error::Native(peace_rt_model::NativeError::PeaceAppDirEntryRead {
-                peace_app_dir: peace_app_dir.to_path_buf(),
-                error,
-            })
-        },
-    )? {
-        let file_type = entry.file_type().await.map_err(
-            #[cfg_attr(coverage_nightly, coverage(off))]
-            |error| {
-                peace_rt_model::Error::Native(
-                    peace_rt_model::NativeError::PeaceAppDirEntryFileTypeRead {
-                        path: entry.path(),
+    use std::str::FromStr;
+
+    let profile_dirs = storage.backend().dirs_under(peace_app_dir).await?;
+    let profile_dir_names = profile_dirs
+        .iter()
+        .filter_map(|profile_dir| profile_dir.file_name());
+
+    let mut profiles = Vec::with_capacity(profile_dirs.len());
+    for dir_name in profile_dir_names {
+        // Assume non-UTF8 file names are not profile directories
+        let Some(dir_name) = dir_name.to_str() else {
+            continue;
+        };
+
+        let profile = match peace_core::Profile::from_str(dir_name) {
+            Ok(profile) => profile,
+            #[cfg(not(target_arch = "wasm32"))]
+            Err(error) => {
+                return Err(peace_rt_model::Error::Native(
+                    peace_rt_model::NativeError::ProfileDirInvalidName {
+                        dir_name: dir_name.to_string(),
+                        path: peace_app_dir.join(dir_name),
                         error,
                     },
-                )
-            },
-        )?;
-
-        if file_type.is_dir() {
-            let entry_path = entry.path();
-            if let Some(dir_name) = entry_path.file_name().and_then(OsStr::to_str) {
-                // Assume this is a profile directory
-                let profile = peace_core::Profile::from_str(dir_name).map_err(|error| {
-                    peace_rt_model::Error::Native(
-                        peace_rt_model::NativeError::ProfileDirInvalidName {
-                            dir_name: dir_name.to_string(),
-                            path: entry_path.to_path_buf(),
-                            error,
-                        },
-                    )
-                })?;
-
-                if let Some(profiles_filter_fn) = profiles_filter_fn {
-                    if !profiles_filter_fn(&profile) {
-                        // Exclude any profiles that do not pass the filter
-                        continue;
-                    }
-                }
-
-                profiles.push(profile)
+                ));
+            }
+            // Browser storage keys that are not valid profile names are not
+            // profile directories.
+            #[cfg(target_arch = "wasm32")]
+            Err(_error) => continue,
+        };
+
+        if let Some(profiles_filter_fn) = profiles_filter_fn {
+            if !profiles_filter_fn(&profile) {
+                // Exclude any profiles that do not pass the filter
+                continue;
             }
-
-            // Assume non-UTF8 file names are not profile directories
         }
+
+        profiles.push(profile)
     }
 
     // Ensure profiles are in a consistent, sensible order.
@@ -237,17 +347,16 @@ pub(crate) async fn profiles_from_peace_app_dir(
     Ok(profiles)
 }
 
-#[cfg(target_arch = "wasm32")]
-pub(crate) async fn profiles_from_peace_app_dir(
-    _peace_app_dir: &peace_resource_rt::paths::PeaceAppDir,
-    _profiles_filter_fn: Option<&dyn Fn(&peace_core::Profile) -> bool>,
-) -> Result<Vec<peace_core::Profile>, peace_rt_model::Error> {
-    let profiles = Vec::new();
-
-    // Not supported yet -- needs a `Storage` abstraction over both native an web
-    // assembly.
-
-    Ok(profiles)
+/// Returns the map with only the entries for the given profiles.
+///
+/// Used to remove the entries of profiles that are filtered out by the
+/// `ProfileSortFilter`.
+pub(crate) fn profile_map_retain<V>(
+    mut profile_map: BTreeMap<peace_core::Profile, V>,
+    profiles: &[peace_core::Profile],
+) -> BTreeMap<peace_core::Profile, V> {
+    profile_map.retain(|profile, _| profiles.contains(profile));
+    profile_map
 }
 
 /// Registers each item's `Params` and `State` for stateful
@@ -266,6 +375,206 @@ where
     )
 }
 
+/// Merges item parameters from override files onto the provided item
+/// parameters.
+///
+/// Files are merged field-wise in order, so values in later files take
+/// precedence. The returned `ParamsSpecsOrigins` record which file and line
+/// each overridden item's params spec, or each of its fields, was last read
+/// from.
+async fn params_specs_override<E>(
+    flow: &Flow<E>,
+    #[cfg_attr(not(target_arch = "wasm32"), allow(unused_variables))] storage: &Storage,
+    params_specs_type_reg: &ParamsSpecsTypeReg,
+    mut params_specs_provided: ParamsSpecs,
+    params_specs_override_files: &[PathBuf],
+) -> Result<(ParamsSpecs, ParamsSpecsOrigins), peace_rt_model::Error>
+where
+    E: 'static,
+{
+    let mut params_specs_origins = ParamsSpecsOrigins::new();
+
+    for params_specs_override_file in params_specs_override_files {
+        let file_bytes = params_specs_override_file_read(
+            #[cfg(target_arch = "wasm32")]
+            storage,
+            params_specs_override_file,
+        )
+        .await?;
+        let (params_specs_override, params_specs_override_origins) =
+            ParamsSpecsSerializer::<peace_rt_model::Error>::deserialize_override(
+                flow.flow_id(),
+                params_specs_type_reg,
+                params_specs_override_file,
+                file_bytes,
+            )?;
+
+        params_specs_override
+            .into_inner()
+            .into_inner()
+            .into_iter()
+            .for_each(|(item_id, mut params_spec_override)| {
+                if let Some(params_spec_provided) = params_specs_provided.shift_remove(&item_id) {
+                    params_spec_override.merge(&*params_spec_provided);
+                }
+                params_specs_provided.insert_raw(item_id, params_spec_override);
+            });
+        params_specs_origins.merge(params_specs_override_origins);
+    }
+
+    Ok((params_specs_provided, params_specs_origins))
+}
+
+/// Returns the contents of a params specs override file.
+///
+/// Override files are read from the local filesystem, as they are provided by
+/// the user running the command, and are not part of the workspace.
+#[cfg(not(target_arch = "wasm32"))]
+async fn params_specs_override_file_read(
+    params_specs_override_file: &Path,
+) -> Result<Vec<u8>, peace_rt_model::Error> {
+    tokio::fs::read(params_specs_override_file)
+        .await
+        .map_err(|error| {
+            peace_rt_model::Error::Native(peace_rt_model::NativeError::FileRead {
+                path: params_specs_override_file.to_path_buf(),
+                error,
+            })
+        })
+}
+
+/// Returns the contents of a params specs override file.
+///
+/// The browser has no local filesystem, so override files are read from its
+/// storage.
+#[cfg(target_arch = "wasm32")]
+async fn params_specs_override_file_read(
+    storage: &Storage,
+    params_specs_override_file: &Path,
+) -> Result<Vec<u8>, peace_rt_model::Error> {
+    storage
+        .backend()
+        .read_opt(params_specs_override_file)
+        .await?
+        .ok_or_else(|| peace_rt_model::Error::ItemNotExists {
+            path: params_specs_override_file.to_path_buf(),
+        })
+}
+
+/// Layers item params specs over other item params specs.
+///
+/// Params specs in `params_specs_top` are merged field-wise over the params
+/// specs for the same item in `params_specs_bottom`. Items that are only in
+/// one of the maps are kept as is.
+fn params_specs_layer(
+    mut params_specs_bottom: ParamsSpecs,
+    params_specs_top: ParamsSpecs,
+) -> ParamsSpecs {
+    params_specs_top
+        .into_inner()
+        .into_inner()
+        .into_iter()
+        .for_each(|(item_id, mut params_spec_top)| {
+            if let Some(params_spec_bottom) = params_specs_bottom.shift_remove(&item_id) {
+                params_spec_top.merge(&*params_spec_bottom);
+            }
+            params_specs_bottom.insert_raw(item_id, params_spec_top);
+        });
+
+    params_specs_bottom
+}
+
+/// Returns the item params specs to store for a profile.
+///
+/// These are the provided params specs layered over the params specs that the
+/// profile previously stored, in the order of the items in the graph. Params
+/// specs inherited from ancestor profiles, or read from override files, are
+/// not included.
+fn params_specs_own<E>(
+    flow: &Flow<E>,
+    params_specs_provided: ParamsSpecs,
+    params_specs_stored: Option<ParamsSpecs>,
+) -> ParamsSpecs
+where
+    E: 'static,
+{
+    let mut params_specs_layered = match params_specs_stored {
+        Some(params_specs_stored) => params_specs_layer(params_specs_stored, params_specs_provided),
+        None => params_specs_provided,
+    };
+
+    let item_graph = flow.graph();
+    let mut params_specs = ParamsSpecs::with_capacity(item_graph.node_count());
+    item_graph.iter_insertion().for_each(|item_rt| {
+        if let Some((item_id, params_spec_boxed)) =
+            params_specs_layered.shift_remove_entry(item_rt.id())
+        {
+            params_specs.insert_raw(item_id, params_spec_boxed);
+        }
+    });
+
+    params_specs
+}
+
+/// Layers a profile's stored item params specs over its ancestors' stored item
+/// params specs.
+///
+/// The returned `ParamsSpecsProfileOrigins` record the nearest profile that
+/// stored params specs for each item.
+async fn params_specs_stored_inherit<E>(
+    flow: &Flow<E>,
+    storage: &Storage,
+    params_specs_type_reg: &ParamsSpecsTypeReg,
+    peace_app_dir: &PeaceAppDir,
+    profile: &Profile,
+    profile_ancestors: &[Profile],
+    params_specs_stored: Option<ParamsSpecs>,
+) -> Result<(Option<ParamsSpecs>, ParamsSpecsProfileOrigins), peace_rt_model::Error>
+where
+    E: 'static,
+{
+    let mut params_specs_profile_origins = ParamsSpecsProfileOrigins::new();
+    if let Some(params_specs_stored) = params_specs_stored.as_ref() {
+        params_specs_stored.keys().for_each(|item_id| {
+            params_specs_profile_origins.insert(item_id.clone(), profile.clone());
+        });
+    }
+
+    let mut params_specs_stored = params_specs_stored;
+    for profile_ancestor in profile_ancestors {
+        let profile_ancestor_dir = ProfileDir::from((peace_app_dir, profile_ancestor));
+        let flow_dir = FlowDir::from((&profile_ancestor_dir, flow.flow_id()));
+        let params_specs_file = ParamsSpecsFile::from(&flow_dir);
+        let params_specs_ancestor =
+            ParamsSpecsSerializer::<peace_rt_model::Error>::deserialize_opt(
+                profile_ancestor,
+                flow.flow_id(),
+                storage,
+                params_specs_type_reg,
+                flow.mapping_fn_reg(),
+                &params_specs_file,
+            )
+            .await?;
+
+        if let Some(params_specs_ancestor) = params_specs_ancestor {
+            params_specs_ancestor.keys().for_each(|item_id| {
+                params_specs_profile_origins
+                    .entry(item_id.clone())
+                    .or_insert_with(|| profile_ancestor.clone());
+            });
+
+            params_specs_stored = Some(match params_specs_stored {
+                Some(params_specs_stored) => {
+                    params_specs_layer(params_specs_ancestor, params_specs_stored)
+                }
+                None => params_specs_ancestor,
+            });
+        }
+    }
+
+    Ok((params_specs_stored, params_specs_profile_origins))
+}
+
 /// Merges provided item parameters with previously stored item
 /// parameters.
 ///
@@ -279,6 +588,18 @@ fn params_specs_merge<E>(
 where
     E: From<peace_rt_model::Error> + 'static,
 {
+    // Named mapping functions in provided params specs hold no logic until they
+    // are bound to the functions registered on the flow.
+    let mapping_fn_reg = flow.mapping_fn_reg();
+    let mapping_fn_bind_errors = params_specs_provided.mapping_fns_bind(mapping_fn_reg);
+    if let Some(error) = peace_rt_model::Error::from_mapping_fn_bind_errors(
+        flow.flow_id(),
+        mapping_fn_reg,
+        mapping_fn_bind_errors,
+    ) {
+        return Err(error);
+    }
+
     // Combine provided and stored params specs. Provided params specs take
     // precedence.
     //
@@ -383,6 +704,55 @@ where
     }
 }
 
+/// Validates each item's params that can be resolved after item setup.
+///
+/// This ensures invalid params are reported when the command context is
+/// built, instead of partway through a command.
+fn params_validate<E>(
+    item_graph: &ItemGraph<E>,
+    params_specs: &ParamsSpecs,
+    resources: &Resources<SetUp>,
+) -> Result<(), E>
+where
+    E: std::error::Error + 'static,
+{
+    item_graph
+        .iter_insertion()
+        .try_for_each(|item| item.params_validate(params_specs, resources))
+}
+
+/// Reads the stored current states, of only the given items if `item_ids` is
+/// `Some`.
+async fn states_current_stored_read(
+    flow_id: &FlowId,
+    storage: &Storage,
+    states_type_reg: &StatesTypeReg,
+    states_current_file: &StatesCurrentFile,
+    item_ids: Option<&[ItemId]>,
+) -> Result<Option<StatesCurrentStored>, peace_rt_model::Error> {
+    match item_ids {
+        Some(item_ids) => {
+            StatesSerializer::deserialize_stored_items_opt(
+                flow_id,
+                storage,
+                states_type_reg,
+                states_current_file,
+                item_ids,
+            )
+            .await
+        }
+        None => {
+            StatesSerializer::deserialize_stored_opt(
+                flow_id,
+                storage,
+                states_type_reg,
+                states_current_file,
+            )
+            .await
+        }
+    }
+}
+
 async fn item_graph_setup<E>(
     item_graph: &ItemGraph<E>,
     resources: Resources<Empty>,
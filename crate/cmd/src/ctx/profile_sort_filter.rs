@@ -0,0 +1,184 @@
+use std::{any::Any, cmp::Ordering, collections::BTreeMap, fmt, hash::Hash};
+
+use peace_core::Profile;
+use peace_rt_model::params::ProfileParams;
+
+/// Function that decides whether a profile is included.
+///
+/// The `&dyn Any` argument is the profile's `ProfileParams<K>`.
+type ProfileFilterAnyFn = Box<dyn Fn(&Profile, &dyn Any) -> bool>;
+
+/// Function that orders two profiles.
+///
+/// The `&dyn Any` arguments are each profile's `ProfileParams<K>`.
+type ProfileSortAnyFn = Box<dyn Fn((&Profile, &dyn Any), (&Profile, &dyn Any)) -> Ordering>;
+
+/// Filters and sort order applied to the profiles of `MultiProfile*` scopes.
+///
+/// These are applied after profile params are read, so profiles may be
+/// filtered and sorted by their profile params' values.
+///
+/// Profiles are listed in name order before the filters and sort function are
+/// applied, and sorting is stable, so profiles that compare equal remain in
+/// name order.
+#[derive(Default)]
+pub struct ProfileSortFilter {
+    /// Functions that a profile must pass to be included.
+    filter_fns: Vec<ProfileFilterAnyFn>,
+    /// Function to sort profiles with.
+    sort_fn: Option<ProfileSortAnyFn>,
+}
+
+impl ProfileSortFilter {
+    /// Returns a new `ProfileSortFilter` that includes all profiles in name
+    /// order.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a filter that a profile's name must match.
+    ///
+    /// The pattern supports `*` to match any number of characters, and `?` to
+    /// match exactly one character.
+    pub(crate) fn filter_glob_push(&mut self, glob: String) {
+        self.filter_fns
+            .push(Box::new(move |profile, _profile_params| {
+                glob_matches(&glob, profile)
+            }));
+    }
+
+    /// Adds a filter over the profile and its profile params.
+    pub(crate) fn filter_fn_push<K, F>(&mut self, filter_fn: F)
+    where
+        K: Eq + Hash + 'static,
+        F: Fn(&Profile, &ProfileParams<K>) -> bool + 'static,
+    {
+        self.filter_fns
+            .push(Box::new(move |profile, profile_params| {
+                profile_params
+                    .downcast_ref::<ProfileParams<K>>()
+                    .map(|profile_params| filter_fn(profile, profile_params))
+                    .unwrap_or(false)
+            }));
+    }
+
+    /// Sets the function to sort profiles by name.
+    pub(crate) fn sort_fn_set<F>(&mut self, sort_fn: F)
+    where
+        F: Fn(&Profile, &Profile) -> Ordering + 'static,
+    {
+        self.sort_fn = Some(Box::new(move |(profile_a, _), (profile_b, _)| {
+            sort_fn(profile_a, profile_b)
+        }));
+    }
+
+    /// Sets the function to sort profiles by their profile params.
+    pub(crate) fn sort_by_params_fn_set<K, F>(&mut self, sort_fn: F)
+    where
+        K: Eq + Hash + 'static,
+        F: Fn((&Profile, &ProfileParams<K>), (&Profile, &ProfileParams<K>)) -> Ordering + 'static,
+    {
+        self.sort_fn = Some(Box::new(
+            move |(profile_a, profile_params_a), (profile_b, profile_params_b)| match (
+                profile_params_a.downcast_ref::<ProfileParams<K>>(),
+                profile_params_b.downcast_ref::<ProfileParams<K>>(),
+            ) {
+                (Some(profile_params_a), Some(profile_params_b)) => {
+                    sort_fn((profile_a, profile_params_a), (profile_b, profile_params_b))
+                }
+                _ => Ordering::Equal,
+            },
+        ));
+    }
+
+    /// Returns the profiles that pass all filters, in sorted order.
+    ///
+    /// Profiles that do not have an entry in `profile_to_profile_params` are
+    /// filtered and sorted with empty profile params.
+    pub(crate) fn apply<K>(
+        &self,
+        profiles: Vec<Profile>,
+        profile_to_profile_params: &BTreeMap<Profile, ProfileParams<K>>,
+    ) -> Vec<Profile>
+    where
+        K: Eq + Hash + 'static,
+    {
+        if self.filter_fns.is_empty() && self.sort_fn.is_none() {
+            return profiles;
+        }
+
+        let profile_params_empty = ProfileParams::<K>::new();
+        let profile_params_for = |profile: &Profile| -> &dyn Any {
+            profile_to_profile_params
+                .get(profile)
+                .unwrap_or(&profile_params_empty)
+        };
+
+        let mut profiles = profiles
+            .into_iter()
+            .filter(|profile| {
+                let profile_params = profile_params_for(profile);
+                self.filter_fns
+                    .iter()
+                    .all(|filter_fn| filter_fn(profile, profile_params))
+            })
+            .collect::<Vec<Profile>>();
+
+        if let Some(sort_fn) = self.sort_fn.as_ref() {
+            profiles.sort_by(|profile_a, profile_b| {
+                sort_fn(
+                    (profile_a, profile_params_for(profile_a)),
+                    (profile_b, profile_params_for(profile_b)),
+                )
+            });
+        }
+
+        profiles
+    }
+}
+
+impl fmt::Debug for ProfileSortFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProfileSortFilter")
+            .field("filter_fns", &self.filter_fns.len())
+            .field("sort_fn", &self.sort_fn.as_ref().map(|_| "Box<dyn Fn>"))
+            .finish()
+    }
+}
+
+/// Returns whether `text` matches the `glob` pattern.
+///
+/// `*` matches any number of characters, and `?` matches exactly one
+/// character. All other characters are matched literally.
+fn glob_matches(glob: &str, text: &str) -> bool {
+    let glob = glob.chars().collect::<Vec<char>>();
+    let text = text.chars().collect::<Vec<char>>();
+
+    let (mut glob_index, mut text_index) = (0, 0);
+    // Position of the last `*` in `glob`, and the `text` index it was tried at.
+    let mut star_backtrack: Option<(usize, usize)> = None;
+
+    while text_index < text.len() {
+        match glob.get(glob_index) {
+            Some('*') => {
+                star_backtrack = Some((glob_index, text_index));
+                glob_index += 1;
+            }
+            Some(glob_char) if *glob_char == '?' || *glob_char == text[text_index] => {
+                glob_index += 1;
+                text_index += 1;
+            }
+            _ => match star_backtrack {
+                // Let the last `*` consume one more character.
+                Some((star_index, star_text_index)) => {
+                    star_backtrack = Some((star_index, star_text_index + 1));
+                    glob_index = star_index + 1;
+                    text_index = star_text_index + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    glob[glob_index..].iter().all(|glob_char| *glob_char == '*')
+}
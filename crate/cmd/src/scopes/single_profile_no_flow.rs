@@ -9,7 +9,7 @@ use peace_resource_rt::paths::{
 use peace_rt_model::{
     params::{
         KeyKnown, KeyMaybe, ParamsKeys, ParamsKeysImpl, ParamsTypeRegs, ProfileParams,
-        WorkspaceParams,
+        ProfileParamsOrigins, WorkspaceParams,
     },
     Workspace,
 };
@@ -84,6 +84,10 @@ where
     profile_params: ProfileParams<
         <<CmdCtxTypesT::ParamsKeys as ParamsKeys>::ProfileParamsKMaybe as KeyMaybe>::Key,
     >,
+    /// Profile that each profile param's value was read from.
+    profile_params_origins: ProfileParamsOrigins<
+        <<CmdCtxTypesT::ParamsKeys as ParamsKeys>::ProfileParamsKMaybe as KeyMaybe>::Key,
+    >,
 }
 
 /// A command that works with a single profile, not scoped to a flow.
@@ -175,6 +179,9 @@ where
         profile_params: ProfileParams<
             <<CmdCtxTypesT::ParamsKeys as ParamsKeys>::ProfileParamsKMaybe as KeyMaybe>::Key,
         >,
+        profile_params_origins: ProfileParamsOrigins<
+            <<CmdCtxTypesT::ParamsKeys as ParamsKeys>::ProfileParamsKMaybe as KeyMaybe>::Key,
+        >,
     ) -> Self {
         Self {
             output,
@@ -186,6 +193,7 @@ where
             params_type_regs,
             workspace_params,
             profile_params,
+            profile_params_origins,
         }
     }
 
@@ -201,6 +209,7 @@ where
             params_type_regs,
             workspace_params,
             profile_params,
+            profile_params_origins: _,
         } = self;
 
         let interruptibility_state = interruptibility_state.reborrow();
@@ -319,4 +328,12 @@ where
     pub fn profile_params(&self) -> &ProfileParams<ProfileParamsK> {
         &self.profile_params
     }
+
+    /// Returns the profile that each profile param's value was read from.
+    ///
+    /// Values that are not set in this profile are inherited from its parent
+    /// profiles.
+    pub fn profile_params_origins(&self) -> &ProfileParamsOrigins<ProfileParamsK> {
+        &self.profile_params_origins
+    }
 }
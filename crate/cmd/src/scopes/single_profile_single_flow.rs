@@ -495,6 +495,17 @@ where
         &self.params_specs
     }
 
+    /// Returns a mutable reference to the item params specs for the selected
+    /// flow.
+    ///
+    /// Changes are used by subsequent commands run with this context, but are
+    /// not written to the [`ParamsSpecsFile`].
+    ///
+    /// [`ParamsSpecsFile`]: peace_resource_rt::paths::ParamsSpecsFile
+    pub fn params_specs_mut(&mut self) -> &mut ParamsSpecs {
+        &mut self.params_specs
+    }
+
     /// Returns the type registry for each item's `State`.
     ///
     /// This is used to deserialize [`StatesCurrentFile`] and
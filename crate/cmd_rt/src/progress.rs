@@ -66,6 +66,14 @@ impl Progress {
 
                 ControlFlow::Continue(())
             }
+            CmdProgressUpdate::ItemStateDiff {
+                item_id,
+                state_diff,
+            } => {
+                output.item_state_diff(item_id, state_diff).await;
+
+                ControlFlow::Continue(())
+            }
             CmdProgressUpdate::Interrupt => {
                 stream::iter(progress_trackers.iter_mut())
                     .fold(output, |output, (item_id, progress_tracker)| async move {
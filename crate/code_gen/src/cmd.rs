@@ -9,7 +9,8 @@ pub use self::{
     impl_params_deserialize::impl_params_deserialize, impl_params_merge::impl_params_merge,
     impl_with_flow::impl_with_flow, impl_with_param::impl_with_param,
     impl_with_params_k::impl_with_params_k, impl_with_profile::impl_with_profile,
-    impl_with_profile_filter::impl_with_profile_filter, params_scope::ParamsScope,
+    impl_with_profile_filter::impl_with_profile_filter,
+    impl_with_profile_sort_filter::impl_with_profile_sort_filter, params_scope::ParamsScope,
     profile_count::ProfileCount, scope::Scope, struct_definition::struct_definition,
 };
 
@@ -26,6 +27,7 @@ mod impl_with_param;
 mod impl_with_params_k;
 mod impl_with_profile;
 mod impl_with_profile_filter;
+mod impl_with_profile_sort_filter;
 mod params_scope;
 mod profile_count;
 mod scope;
@@ -50,6 +52,7 @@ pub fn cmd_ctx_builder_impl(input: proc_macro::TokenStream) -> proc_macro::Token
 
     let impl_with_profile = impl_with_profile(&scope_struct);
     let impl_with_profile_filter = impl_with_profile_filter(&scope_struct);
+    let impl_with_profile_sort_filter = impl_with_profile_sort_filter(&scope_struct);
 
     let impl_with_flow = impl_with_flow(&scope_struct);
 
@@ -73,6 +76,8 @@ pub fn cmd_ctx_builder_impl(input: proc_macro::TokenStream) -> proc_macro::Token
 
         #impl_with_profile_filter
 
+        #impl_with_profile_sort_filter
+
         #impl_with_flow
 
         #impl_build
@@ -162,11 +162,14 @@ fn impl_build_for(
     let profiles_from_peace_app_dir = profiles_from_peace_app_dir(scope, profile_selection);
     let profile_s_ref = profile_s_ref(scope, profile_selection);
     let cmd_dirs = cmd_dirs(scope);
+    let profile_ancestors = profile_ancestors(scope, profile_params_selection);
+    let profiles_sort_filter = profiles_sort_filter(scope);
     let dirs_to_create = dirs_to_create(scope);
     let scope_fields = scope_fields(scope);
     let states_and_params_read_and_pg_init = states_and_params_read_and_pg_init(scope);
     let resources_insert = resources_insert(scope);
     let states_example_insert = states_example_insert(scope);
+    let params_validate = params_validate(scope);
 
     let scope_builder_deconstruct = scope_builder_deconstruct(
         scope_struct,
@@ -410,6 +413,24 @@ fn impl_build_for(
                     #dirs_to_create
                 ];
 
+                // === Profile Ancestors === //
+                // --- Single --- //
+                // let profile_ancestors = crate::ctx::cmd_ctx_builder::profile_ancestors(
+                //     self.workspace.storage(),
+                //     workspace_dirs.peace_app_dir(),
+                //     profile_s_ref,
+                // )
+                // .await?;
+                // --- Multi --- //
+                // let profile_to_profile_ancestors =
+                //     crate::ctx::cmd_ctx_builder::profile_to_profile_ancestors(
+                //         self.workspace.storage(),
+                //         workspace_dirs.peace_app_dir(),
+                //         &profiles,
+                //     )
+                //     .await?;
+                #profile_ancestors
+
                 // === Profile Params === //
                 // --- Single --- //
                 // let workspace = &self.workspace;
@@ -445,6 +466,22 @@ fn impl_build_for(
                 //     .await?;
                 #profile_params_deserialize
 
+                // === Profile Sort Filter === //
+                // --- Multi --- //
+                // let profiles = self
+                //     .scope_builder
+                //     .profile_sort_filter
+                //     .apply(profiles, &profile_to_profile_params);
+                // let profile_dirs =
+                //     crate::ctx::cmd_ctx_builder::profile_map_retain(profile_dirs, &profiles);
+                // let profile_history_dirs =
+                //     crate::ctx::cmd_ctx_builder::profile_map_retain(profile_history_dirs, &profiles);
+                // let flow_dirs =
+                //     crate::ctx::cmd_ctx_builder::profile_map_retain(flow_dirs, &profiles);
+                // let profile_to_profile_params =
+                //     crate::ctx::cmd_ctx_builder::profile_map_retain(profile_to_profile_params, &profiles);
+                #profiles_sort_filter
+
                 // === Flow Params === //
                 // --- Single --- //
                 // let workspace = &self.workspace;
@@ -481,22 +518,21 @@ fn impl_build_for(
                 #flow_params_deserialize
 
                 // Create directories and write init parameters to storage.
-                #[cfg(target_arch = "wasm32")]
-                {
-                    let storage = self.workspace.storage();
-                    peace_rt_model::WorkspaceInitializer::dirs_create(storage, dirs_to_create).await?;
-                }
+                let storage = self.workspace.storage();
+                peace_rt_model::WorkspaceInitializer::dirs_create(storage, dirs_to_create).await?;
                 #[cfg(not(target_arch = "wasm32"))]
                 {
-                    peace_rt_model::WorkspaceInitializer::dirs_create(dirs_to_create).await?;
-
+                    // In-memory workspaces have an empty workspace directory, and are not on
+                    // the file system.
                     let workspace_dir = workspace_dirs.workspace_dir();
-                    std::env::set_current_dir(workspace_dir).map_err(|error| {
-                        peace_rt_model::Error::Native(peace_rt_model::NativeError::CurrentDirSet {
-                            workspace_dir: workspace_dir.clone(),
-                            error,
-                        })
-                    })?;
+                    if !workspace_dir.as_os_str().is_empty() {
+                        std::env::set_current_dir(workspace_dir).map_err(|error| {
+                            peace_rt_model::Error::Native(peace_rt_model::NativeError::CurrentDirSet {
+                                workspace_dir: workspace_dir.clone(),
+                                error,
+                            })
+                        })?;
+                    }
                 }
 
                 // let crate::ctx::CmdCtxBuilder {
@@ -518,6 +554,8 @@ fn impl_build_for(
                 //
                 //         // === SingleProfileSingleFlow === //
                 //         params_specs_provided,
+                //         params_specs_override_files,
+                //         item_ids,
                 //     },
                 // } = self;
                 #scope_builder_deconstruct
@@ -609,6 +647,17 @@ fn impl_build_for(
                 //     crate::ctx::cmd_ctx_builder::params_and_states_type_reg(item_graph);
                 //
                 // let params_specs_type_reg_ref = &params_specs_type_reg;
+                // let (params_specs_provided, params_specs_origins) =
+                //     crate::ctx::cmd_ctx_builder::params_specs_override(
+                //         flow_ref,
+                //         storage,
+                //         params_specs_type_reg_ref,
+                //         params_specs_provided,
+                //         &params_specs_override_files,
+                //     )
+                //     .await?;
+                // resources.insert(params_specs_origins);
+                //
                 // let profile_to_params_specs = futures::stream::iter(
                 //     flow_dirs
                 //         .iter()
@@ -627,6 +676,7 @@ fn impl_build_for(
                 //                 flow_id,
                 //                 storage,
                 //                 params_specs_type_reg_ref,
+                //                 flow_ref.mapping_fn_reg(),
                 //                 &params_specs_file,
                 //             )
                 //             .await?;
@@ -656,6 +706,7 @@ fn impl_build_for(
                 //     .await?;
                 //
                 // let states_type_reg_ref = &states_type_reg;
+                // let item_ids_ref = item_ids.as_deref();
                 // let profile_to_states_current_stored = futures::stream::iter(
                 //     flow_dirs
                 //         .iter()
@@ -664,16 +715,15 @@ fn impl_build_for(
                 //     .and_then(|(profile, flow_dir)| async move {
                 //         let states_current_file = peace_resource_rt::paths::StatesCurrentFile::from(flow_dir);
                 //
-                //         let states_current_stored = peace_rt_model::StatesSerializer::<
-                //             peace_rt_model::Error
-                //         >::deserialize_stored_opt(
-                //             flow_id,
-                //             storage,
-                //             states_type_reg_ref,
-                //             &states_current_file,
-                //         )
-                //         .await?
-                //         .map(Into::<peace_resource_rt::states::StatesCurrentStored>::into);
+                //         let states_current_stored =
+                //             crate::ctx::cmd_ctx_builder::states_current_stored_read(
+                //                 flow_id,
+                //                 storage,
+                //                 states_type_reg_ref,
+                //                 &states_current_file,
+                //                 item_ids_ref,
+                //             )
+                //             .await?;
                 //
                 //         Ok((profile.clone(), states_current_stored))
                 //     })
@@ -703,6 +753,18 @@ fn impl_build_for(
                 //
                 // // Params specs loading and storage.
                 // let params_specs_type_reg_ref = &params_specs_type_reg;
+                // let params_specs_provided_own = params_specs_provided.clone();
+                // let (params_specs_provided, params_specs_origins) =
+                //     crate::ctx::cmd_ctx_builder::params_specs_override(
+                //         flow_ref,
+                //         storage,
+                //         params_specs_type_reg_ref,
+                //         params_specs_provided,
+                //         &params_specs_override_files,
+                //     )
+                //     .await?;
+                // resources.insert(params_specs_origins);
+                //
                 // let params_specs_file = peace_resource_rt::paths::ParamsSpecsFile::from(&flow_dir);
                 // let params_specs_stored = peace_rt_model::ParamsSpecsSerializer::<
                 //     peace_rt_model::Error
@@ -711,36 +773,74 @@ fn impl_build_for(
                 //     flow_id,
                 //     storage,
                 //     params_specs_type_reg_ref,
+                //     flow_ref.mapping_fn_reg(),
                 //     &params_specs_file,
                 // )
                 // .await?;
+                // let (params_specs_stored_inherited, params_specs_profile_origins) =
+                //     crate::ctx::cmd_ctx_builder::params_specs_stored_inherit(
+                //         flow_ref,
+                //         storage,
+                //         params_specs_type_reg_ref,
+                //         workspace.dirs().peace_app_dir(),
+                //         &profile,
+                //         &profile_ancestors,
+                //         params_specs_stored.clone(),
+                //     )
+                //     .await?;
+                // resources.insert(params_specs_profile_origins);
+                // if let Some(params_specs_stored_inherited) = params_specs_stored_inherited.as_ref() {
+                //     resources.insert(peace_params::ParamsSpecsStored::from(
+                //         params_specs_stored_inherited.clone(),
+                //     ));
+                // }
                 //
                 // let params_specs = crate::ctx::cmd_ctx_builder::params_specs_merge(
                 //     flow_ref,
                 //     params_specs_provided,
-                //     params_specs_stored,
+                //     params_specs_stored_inherited,
                 // )?;
                 //
+                // let params_specs_own = crate::ctx::cmd_ctx_builder::params_specs_own(
+                //     flow_ref,
+                //     params_specs_provided_own,
+                //     params_specs_stored,
+                // );
                 // crate::ctx::cmd_ctx_builder::params_specs_serialize(
-                //     &params_specs,
+                //     &params_specs_own,
                 //     storage,
                 //     &params_specs_file,
                 // )
                 // .await?;
                 //
-                // // States loading and storage.
-                // let states_type_reg_ref = &states_type_reg;
-                // let states_current_file = peace_resource_rt::paths::StatesCurrentFile::from(&flow_dir);
-                // let states_current_stored = peace_rt_model::StatesSerializer::<
+                // // Params used when items were last applied.
+                // let params_applied_file = peace_resource_rt::paths::ParamsAppliedFile::from(&flow_dir);
+                // let params_specs_applied = peace_rt_model::ParamsSpecsSerializer::<
                 //     peace_rt_model::Error
-                // >::deserialize_stored_opt(
+                // >::deserialize_applied_opt(
+                //     &profile,
                 //     flow_id,
                 //     storage,
-                //     states_type_reg_ref,
-                //     &states_current_file,
+                //     params_specs_type_reg_ref,
+                //     &params_applied_file,
                 // )
-                // .await?
-                // .map(Into::<peace_resource_rt::states::StatesCurrentStored>::into);
+                // .await?;
+                // if let Some(params_specs_applied) = params_specs_applied {
+                //     resources.insert(params_specs_applied);
+                // }
+                //
+                // // States loading and storage.
+                // let states_type_reg_ref = &states_type_reg;
+                // let states_current_file = peace_resource_rt::paths::StatesCurrentFile::from(&flow_dir);
+                // let states_current_stored =
+                //     crate::ctx::cmd_ctx_builder::states_current_stored_read(
+                //         flow_id,
+                //         storage,
+                //         states_type_reg_ref,
+                //         &states_current_file,
+                //         item_ids.as_deref(),
+                //     )
+                //     .await?;
                 // if let Some(states_current_stored) = states_current_stored {
                 //     resources.insert(states_current_stored);
                 // }
@@ -778,6 +878,18 @@ fn impl_build_for(
                 // some resources to be inserted for `state_example` to work.
                 resources.merge(resources_override.into_inner());
 
+                // === SingleProfileSingleFlow === //
+                // // Fail early if any resolvable params are invalid.
+                // //
+                // // Needs to come after `resources_override` is merged, because params
+                // // may be resolved from values in resources passed to the builder.
+                // crate::ctx::cmd_ctx_builder::params_validate(
+                //     flow.graph(),
+                //     &params_specs,
+                //     &resources,
+                // )?;
+                #params_validate
+
                 // === SingleProfileSingleFlow === //
                 // // Fetching state example inserts it into resources.
                 // #[cfg(feature = "item_state_example")]
@@ -932,10 +1044,21 @@ fn scope_builder_deconstruct(
         scope_builder_fields.push(flow_params_selection.deconstruct(scope.profile_count()));
     }
 
+    if scope.profile_count() == ProfileCount::Multiple {
+        scope_builder_fields.push(parse_quote! {
+            profile_sort_filter: _
+        });
+    }
     if scope.flow_count() == FlowCount::One {
         scope_builder_fields.push(parse_quote! {
             params_specs_provided
         });
+        scope_builder_fields.push(parse_quote! {
+            params_specs_override_files
+        });
+        scope_builder_fields.push(parse_quote! {
+            item_ids
+        });
     }
 
     quote! {
@@ -954,6 +1077,8 @@ fn scope_builder_deconstruct(
 
                 // // === SingleProfileSingleFlow === //
                 // params_specs_provided,
+                // params_specs_override_files,
+                // item_ids,
 
                 #scope_builder_fields,
             },
@@ -1051,7 +1176,7 @@ fn profile_params_load_save(
             ProfileParamsSelection::None => {
                 let profile_params_k_maybe_type_param =
                     profile_params_selection.k_maybe_type_param();
-                let profile_params_deserialize = quote! {
+                let mut profile_params_deserialize = quote! {
                     let profile_params = peace_rt_model::params::ProfileParams::<
                         <
                             #profile_params_k_maybe_type_param as
@@ -1059,6 +1184,16 @@ fn profile_params_load_save(
                         >::Key
                     >::new();
                 };
+                if scope == Scope::SingleProfileNoFlow {
+                    profile_params_deserialize.extend(quote! {
+                        let profile_params_origins = peace_rt_model::params::ProfileParamsOrigins::<
+                            <
+                                #profile_params_k_maybe_type_param as
+                                peace_rt_model::params::KeyMaybe
+                            >::Key
+                        >::new();
+                    });
+                }
                 (
                     profile_params_deserialize,
                     proc_macro2::TokenStream::new(),
@@ -1083,6 +1218,39 @@ fn profile_params_load_save(
                         profile_params,
                         &profile_params_file,
                     ).await?;
+
+                    // Params that are not set in this profile are inherited from its
+                    // ancestors, and are kept separate so they are not stored in this
+                    // profile.
+                    let mut profile_params_inherited = peace_rt_model::params::ProfileParams::new();
+                    let mut profile_params_origins = profile_params
+                        .keys()
+                        .map(|key| (key.clone(), profile_s_ref.clone()))
+                        .collect::<std::collections::HashMap<_, _>>();
+                    let mut profile_params_origins =
+                        peace_rt_model::params::ProfileParamsOrigins::from(profile_params_origins);
+                    for profile_ancestor in profile_ancestors.iter() {
+                        let profile_ancestor_dir = peace_resource_rt::paths::ProfileDir::from(
+                            (workspace_dirs.peace_app_dir(), profile_ancestor)
+                        );
+                        let profile_ancestor_params_file =
+                            peace_resource_rt::internal::ProfileParamsFile::from(&profile_ancestor_dir);
+                        let profile_ancestor_params = Self::profile_params_deserialize(
+                            storage,
+                            params_type_regs_builder,
+                            &profile_ancestor_params_file,
+                        )
+                        .await?;
+                        if let Some(profile_ancestor_params) = profile_ancestor_params {
+                            crate::ctx::cmd_ctx_builder::profile_params_inherit(
+                                profile_params,
+                                &mut profile_params_inherited,
+                                &mut profile_params_origins,
+                                profile_ancestor,
+                                profile_ancestor_params,
+                            );
+                        }
+                    }
                 };
                 let profile_params_serialize = quote! {
                     crate::ctx::cmd_ctx_builder::profile_params_serialize(
@@ -1091,10 +1259,17 @@ fn profile_params_load_save(
                         &profile_params_file,
                     )
                     .await?;
+
+                    let mut profile_params = profile_params;
+                    crate::ctx::cmd_ctx_builder::profile_params_extend(
+                        &mut profile_params,
+                        profile_params_inherited,
+                    );
                 };
                 let profile_params_insert = quote! {
                     crate::ctx::cmd_ctx_builder::profile_params_insert(profile_params.clone(), &mut resources);
                     resources.insert(profile_params_file);
+                    resources.insert(profile_params_origins.clone());
                 };
 
                 (
@@ -1126,6 +1301,7 @@ fn profile_params_load_save(
                     quote! {
                         let storage = self.workspace.storage();
                         let params_type_regs_builder = &self.scope_builder.params_type_regs_builder;
+                        let profile_to_profile_ancestors = &profile_to_profile_ancestors;
                         let profile_to_profile_params = futures::stream::iter(
                             profile_dirs
                                 .iter()
@@ -1135,7 +1311,7 @@ fn profile_params_load_save(
                                 let profile_params_file =
                                     peace_resource_rt::internal::ProfileParamsFile::from(profile_dir);
 
-                                let profile_params = Self::#params_deserialize_method_name(
+                                let mut profile_params = Self::#params_deserialize_method_name(
                                     storage,
                                     params_type_regs_builder,
                                     &profile_params_file
@@ -1143,6 +1319,45 @@ fn profile_params_load_save(
                                 .await?
                                 .unwrap_or_default();
 
+                                // Params that are not set in this profile are inherited from
+                                // its ancestors.
+                                let mut profile_params_inherited =
+                                    peace_rt_model::params::ProfileParams::new();
+                                let mut profile_params_origins =
+                                    peace_rt_model::params::ProfileParamsOrigins::new();
+                                let profile_ancestors = profile_to_profile_ancestors
+                                    .get(profile)
+                                    .map(Vec::as_slice)
+                                    .unwrap_or_default();
+                                for profile_ancestor in profile_ancestors {
+                                    let profile_ancestor_dir = peace_resource_rt::paths::ProfileDir::from(
+                                        (workspace_dirs.peace_app_dir(), profile_ancestor)
+                                    );
+                                    let profile_ancestor_params_file =
+                                        peace_resource_rt::internal::ProfileParamsFile::from(
+                                            &profile_ancestor_dir
+                                        );
+                                    let profile_ancestor_params = Self::#params_deserialize_method_name(
+                                        storage,
+                                        params_type_regs_builder,
+                                        &profile_ancestor_params_file,
+                                    )
+                                    .await?;
+                                    if let Some(profile_ancestor_params) = profile_ancestor_params {
+                                        crate::ctx::cmd_ctx_builder::profile_params_inherit(
+                                            &profile_params,
+                                            &mut profile_params_inherited,
+                                            &mut profile_params_origins,
+                                            profile_ancestor,
+                                            profile_ancestor_params,
+                                        );
+                                    }
+                                }
+                                crate::ctx::cmd_ctx_builder::profile_params_extend(
+                                    &mut profile_params,
+                                    profile_params_inherited,
+                                );
+
                                 Ok((profile.clone(), profile_params))
                             })
                             .try_collect::<
@@ -1344,6 +1559,7 @@ fn profiles_from_peace_app_dir(
             ProfileSelection::NotSelected => quote! {
                 let workspace_dirs = self.workspace.dirs();
                 let profiles = crate::ctx::cmd_ctx_builder::profiles_from_peace_app_dir(
+                    self.workspace.storage(),
                     workspace_dirs.peace_app_dir(),
                     None,
                 ).await?;
@@ -1356,6 +1572,7 @@ fn profiles_from_peace_app_dir(
                 let workspace_dirs = self.workspace.dirs();
                 let profiles_filter_fn = self.scope_builder.profile_selection.0.as_ref();
                 let profiles = crate::ctx::cmd_ctx_builder::profiles_from_peace_app_dir(
+                    self.workspace.storage(),
                     workspace_dirs.peace_app_dir(),
                     Some(profiles_filter_fn),
                 ).await?;
@@ -1480,6 +1697,74 @@ fn cmd_dirs(scope: Scope) -> proc_macro2::TokenStream {
     dirs_tokens
 }
 
+/// Reads each profile's ancestor profiles, for inheriting profile params and
+/// item params specs.
+///
+/// Ancestors are only read when profile params or item params specs are
+/// read, so that profiles with missing or cyclic parents can still be
+/// listed and fixed.
+fn profile_ancestors(
+    scope: Scope,
+    profile_params_selection: ProfileParamsSelection,
+) -> proc_macro2::TokenStream {
+    let profile_ancestors_used = profile_params_selection == ProfileParamsSelection::Some
+        || scope.flow_count() == FlowCount::One;
+    if !profile_ancestors_used {
+        return proc_macro2::TokenStream::new();
+    }
+
+    match scope.profile_count() {
+        ProfileCount::None => proc_macro2::TokenStream::new(),
+        ProfileCount::One => quote! {
+            let profile_ancestors = crate::ctx::cmd_ctx_builder::profile_ancestors(
+                self.workspace.storage(),
+                workspace_dirs.peace_app_dir(),
+                profile_s_ref,
+            )
+            .await?;
+        },
+        ProfileCount::Multiple => quote! {
+            let profile_to_profile_ancestors =
+                crate::ctx::cmd_ctx_builder::profile_to_profile_ancestors(
+                    self.workspace.storage(),
+                    workspace_dirs.peace_app_dir(),
+                    &profiles,
+                )
+                .await?;
+        },
+    }
+}
+
+/// Filters and sorts the profiles for `MultiProfile*` scopes, and removes the
+/// entries for filtered out profiles from the per-profile maps.
+fn profiles_sort_filter(scope: Scope) -> proc_macro2::TokenStream {
+    if scope.profile_count() != ProfileCount::Multiple {
+        return proc_macro2::TokenStream::new();
+    }
+
+    let mut profiles_sort_filter = quote! {
+        let profiles = self
+            .scope_builder
+            .profile_sort_filter
+            .apply(profiles, &profile_to_profile_params);
+        let profile_dirs =
+            crate::ctx::cmd_ctx_builder::profile_map_retain(profile_dirs, &profiles);
+        let profile_history_dirs =
+            crate::ctx::cmd_ctx_builder::profile_map_retain(profile_history_dirs, &profiles);
+        let profile_to_profile_params =
+            crate::ctx::cmd_ctx_builder::profile_map_retain(profile_to_profile_params, &profiles);
+    };
+
+    if scope.flow_count() == FlowCount::One {
+        profiles_sort_filter.extend(quote! {
+            let flow_dirs =
+                crate::ctx::cmd_ctx_builder::profile_map_retain(flow_dirs, &profiles);
+        });
+    }
+
+    profiles_sort_filter
+}
+
 fn dirs_to_create(scope: Scope) -> proc_macro2::TokenStream {
     let mut dirs_tokens = quote! {
         AsRef::<std::path::Path>::as_ref(workspace_dirs.workspace_dir()),
@@ -1575,6 +1860,9 @@ fn scope_fields(scope: Scope) -> Punctuated<FieldValue, Comma> {
         ProfileCount::One => {
             scope_fields.push(parse_quote!(workspace_params));
             scope_fields.push(parse_quote!(profile_params));
+            if scope == Scope::SingleProfileNoFlow {
+                scope_fields.push(parse_quote!(profile_params_origins));
+            }
         }
         ProfileCount::Multiple => {
             scope_fields.push(parse_quote!(workspace_params));
@@ -1634,6 +1922,19 @@ fn states_and_params_read_and_pg_init(scope: Scope) -> proc_macro2::TokenStream
                     crate::ctx::cmd_ctx_builder::params_and_states_type_reg(item_graph);
 
                 let params_specs_type_reg_ref = &params_specs_type_reg;
+                let (params_specs_provided, params_specs_origins) =
+                    crate::ctx::cmd_ctx_builder::params_specs_override(
+                        flow_ref,
+                        storage,
+                        params_specs_type_reg_ref,
+                        params_specs_provided,
+                        &params_specs_override_files,
+                    )
+                    .await?;
+                resources.insert(params_specs_origins);
+
+                let peace_app_dir = workspace.dirs().peace_app_dir();
+                let profile_to_profile_ancestors = &profile_to_profile_ancestors;
                 let profile_to_params_specs = futures::stream::iter(
                     flow_dirs
                         .iter()
@@ -1652,10 +1953,29 @@ fn states_and_params_read_and_pg_init(scope: Scope) -> proc_macro2::TokenStream
                                 flow_id,
                                 storage,
                                 params_specs_type_reg_ref,
+                                flow_ref.mapping_fn_reg(),
                                 &params_specs_file,
                             )
                             .await?;
 
+                            // Params specs that are not stored in this profile are inherited
+                            // from its ancestors.
+                            let profile_ancestors = profile_to_profile_ancestors
+                                .get(profile)
+                                .map(Vec::as_slice)
+                                .unwrap_or_default();
+                            let (params_specs_stored, _params_specs_profile_origins) =
+                                crate::ctx::cmd_ctx_builder::params_specs_stored_inherit(
+                                    flow_ref,
+                                    storage,
+                                    params_specs_type_reg_ref,
+                                    peace_app_dir,
+                                    profile,
+                                    profile_ancestors,
+                                    params_specs_stored,
+                                )
+                                .await?;
+
                             // For mapping fns, we still need the developer to provide the params spec
                             // so that multi-profile diffs can be done.
                             let params_specs = params_specs_stored.map(|params_specs_stored| {
@@ -1681,6 +2001,7 @@ fn states_and_params_read_and_pg_init(scope: Scope) -> proc_macro2::TokenStream
                     .await?;
 
                 let states_type_reg_ref = &states_type_reg;
+                let item_ids_ref = item_ids.as_deref();
                 let profile_to_states_current_stored = futures::stream::iter(
                     flow_dirs
                         .iter()
@@ -1689,16 +2010,14 @@ fn states_and_params_read_and_pg_init(scope: Scope) -> proc_macro2::TokenStream
                     .and_then(|(profile, flow_dir)| async move {
                         let states_current_file = peace_resource_rt::paths::StatesCurrentFile::from(flow_dir);
 
-                        let states_current_stored = peace_rt_model::StatesSerializer::<
-                            peace_rt_model::Error
-                        >::deserialize_stored_opt(
+                        let states_current_stored = crate::ctx::cmd_ctx_builder::states_current_stored_read(
                             flow_id,
                             storage,
                             states_type_reg_ref,
                             &states_current_file,
+                            item_ids_ref,
                         )
-                        .await?
-                        .map(Into::<peace_resource_rt::states::StatesCurrentStored>::into);
+                        .await?;
 
                         Ok((profile.clone(), states_current_stored))
                     })
@@ -1746,6 +2065,21 @@ fn states_and_params_read_and_pg_init(scope: Scope) -> proc_macro2::TokenStream
 
                 // Params specs loading and storage.
                 let params_specs_type_reg_ref = &params_specs_type_reg;
+
+                // Params specs from override files only apply to this command, so they are
+                // not stored.
+                let params_specs_provided_own = params_specs_provided.clone();
+                let (params_specs_provided, params_specs_origins) =
+                    crate::ctx::cmd_ctx_builder::params_specs_override(
+                        flow_ref,
+                        storage,
+                        params_specs_type_reg_ref,
+                        params_specs_provided,
+                        &params_specs_override_files,
+                    )
+                    .await?;
+                resources.insert(params_specs_origins);
+
                 let params_specs_file = peace_resource_rt::paths::ParamsSpecsFile::from(&flow_dir);
                 let params_specs_stored = peace_rt_model::ParamsSpecsSerializer::<
                     peace_rt_model::Error
@@ -1754,36 +2088,82 @@ fn states_and_params_read_and_pg_init(scope: Scope) -> proc_macro2::TokenStream
                     flow_id,
                     storage,
                     params_specs_type_reg_ref,
+                    flow_ref.mapping_fn_reg(),
                     &params_specs_file,
                 )
                 .await?;
 
+                // Params specs that are not stored in this profile are inherited from its
+                // ancestors.
+                let (params_specs_stored_inherited, params_specs_profile_origins) =
+                    crate::ctx::cmd_ctx_builder::params_specs_stored_inherit(
+                        flow_ref,
+                        storage,
+                        params_specs_type_reg_ref,
+                        workspace.dirs().peace_app_dir(),
+                        &profile,
+                        &profile_ancestors,
+                        params_specs_stored.clone(),
+                    )
+                    .await?;
+                resources.insert(params_specs_profile_origins);
+
+                // Kept so that params specs can be diffed against the stored ones.
+                if let Some(params_specs_stored_inherited) = params_specs_stored_inherited.as_ref() {
+                    resources.insert(peace_params::ParamsSpecsStored::from(
+                        params_specs_stored_inherited.clone(),
+                    ));
+                }
+
                 let params_specs = crate::ctx::cmd_ctx_builder::params_specs_merge(
                     flow_ref,
                     params_specs_provided,
-                    params_specs_stored,
+                    params_specs_stored_inherited,
                 )?;
 
+                // Only this profile's own params specs are stored, so that values
+                // inherited from ancestors or read from override files are not copied
+                // into this profile.
+                let params_specs_own = crate::ctx::cmd_ctx_builder::params_specs_own(
+                    flow_ref,
+                    params_specs_provided_own,
+                    params_specs_stored,
+                );
                 crate::ctx::cmd_ctx_builder::params_specs_serialize(
-                    &params_specs,
+                    &params_specs_own,
                     storage,
                     &params_specs_file,
                 )
                 .await?;
 
+                // Params used when items were last applied, so that clean uses the params
+                // that created each item's resources.
+                let params_applied_file = peace_resource_rt::paths::ParamsAppliedFile::from(&flow_dir);
+                let params_specs_applied = peace_rt_model::ParamsSpecsSerializer::<
+                    peace_rt_model::Error
+                >::deserialize_applied_opt(
+                    &profile,
+                    flow_id,
+                    storage,
+                    params_specs_type_reg_ref,
+                    &params_applied_file,
+                )
+                .await?;
+                if let Some(params_specs_applied) = params_specs_applied {
+                    resources.insert(params_specs_applied);
+                }
+
                 // States loading and storage.
                 let states_type_reg_ref = &states_type_reg;
                 let states_current_file = peace_resource_rt::paths::StatesCurrentFile::from(&flow_dir);
-                let states_current_stored = peace_rt_model::StatesSerializer::<
-                    peace_rt_model::Error
-                >::deserialize_stored_opt(
+                let states_current_stored = crate::ctx::cmd_ctx_builder::states_current_stored_read(
                     flow_id,
                     storage,
                     states_type_reg_ref,
                     &states_current_file,
+                    item_ids.as_deref(),
                 )
-                .await?
-                .map(Into::<peace_resource_rt::states::StatesCurrentStored>::into);
+                .await?;
                 if let Some(states_current_stored) = states_current_stored {
                     resources.insert(states_current_stored);
                 }
@@ -1860,6 +2240,31 @@ fn resources_insert(scope: Scope) -> proc_macro2::TokenStream {
     }
 }
 
+fn params_validate(scope: Scope) -> proc_macro2::TokenStream {
+    match scope {
+        Scope::SingleProfileSingleFlow => {
+            quote! {
+                // Fail early if any resolvable params are invalid.
+                //
+                // Needs to come after `resources_override` is merged, because params
+                // may be resolved from values in resources passed to the builder.
+                //
+                // Secret values are not read here, so they are only needed by
+                // commands that use them.
+                crate::ctx::cmd_ctx_builder::params_validate(
+                    flow.graph(),
+                    &params_specs,
+                    &resources,
+                )?;
+            }
+        }
+        Scope::MultiProfileSingleFlow
+        | Scope::MultiProfileNoFlow
+        | Scope::NoProfileNoFlow
+        | Scope::SingleProfileNoFlow => proc_macro2::TokenStream::new(),
+    }
+}
+
 fn states_example_insert(scope: Scope) -> proc_macro2::TokenStream {
     match scope {
         Scope::SingleProfileSingleFlow => {
@@ -62,6 +62,38 @@ pub fn impl_common_fns(scope_struct: &ScopeStruct) -> proc_macro2::TokenStream {
                 self.scope_builder.params_specs_provided.insert(item_id, params_spec);
                 self
             }
+
+            /// Adds a file of item params specs that override the params specs
+            /// provided in code.
+            ///
+            /// The file maps each item ID to its params spec, and is read as JSON
+            /// if its extension is `json`, and YAML otherwise. Override files are
+            /// merged field-wise in the order they are added, so values in later
+            /// files take precedence.
+            pub fn with_params_specs_override_file<P>(
+                mut self,
+                params_specs_override_file: P,
+            ) -> Self
+            where
+                P: Into<std::path::PathBuf>,
+            {
+                self.scope_builder
+                    .params_specs_override_files
+                    .push(params_specs_override_file.into());
+                self
+            }
+
+            /// Only reads the stored states of the given items when building
+            /// the command context.
+            ///
+            /// Use this when the command only touches these items. With
+            /// `StatesLayout::PerItem`, the other items' state files are not
+            /// read, so `Item::setup` and `Stored` data only see the stored
+            /// states of these items.
+            pub fn with_item_ids(mut self, item_ids: Vec<peace_cfg::ItemId>) -> Self {
+                self.scope_builder.item_ids = Some(item_ids);
+                self
+            }
         });
     };
 
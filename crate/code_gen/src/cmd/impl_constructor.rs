@@ -2,7 +2,9 @@ use proc_macro2::{Ident, Span};
 use quote::quote;
 use syn::{parse_quote, punctuated::Punctuated, FieldValue, Token};
 
-use crate::cmd::{CmdCtxBuilderTypeBuilder, FlowCount, ImplHeaderBuilder, ScopeStruct};
+use crate::cmd::{
+    CmdCtxBuilderTypeBuilder, FlowCount, ImplHeaderBuilder, ProfileCount, ScopeStruct,
+};
 
 /// Generates the constructor for the command context builder for a given scope.
 pub fn impl_constructor(scope_struct: &ScopeStruct) -> proc_macro2::TokenStream {
@@ -16,10 +18,21 @@ pub fn impl_constructor(scope_struct: &ScopeStruct) -> proc_macro2::TokenStream
         scope_field_values::profile_and_flow_selection_push(&mut type_params, scope);
         scope_field_values::params_selection_push(&mut type_params, scope);
 
+        if scope.profile_count() == ProfileCount::Multiple {
+            type_params.push(parse_quote!(
+                profile_sort_filter: crate::ctx::ProfileSortFilter::new()
+            ));
+        }
         if scope.flow_count() == FlowCount::One {
             type_params.push(parse_quote!(
                 params_specs_provided: peace_params::ParamsSpecs::new()
             ));
+            type_params.push(parse_quote!(
+                params_specs_override_files: Vec::new()
+            ));
+            type_params.push(parse_quote!(
+                item_ids: None
+            ));
         }
 
         type_params
@@ -93,6 +106,8 @@ pub fn impl_constructor(scope_struct: &ScopeStruct) -> proc_macro2::TokenStream
 
                     // // === SingleProfileSingleFlow === //
                     // params_specs_provided: peace_params::ParamsSpecs::new()
+                    // params_specs_override_files: Vec::new()
+                    // item_ids: None
 
                     #scope_field_values
                 };
@@ -92,6 +92,8 @@ pub fn impl_with_flow(scope_struct: &ScopeStruct) -> proc_macro2::TokenStream {
                             // profile_params_selection,
                             // flow_params_selection,
                             // params_specs_provided,
+                            // params_specs_override_files,
+                            // item_ids,
                             #scope_builder_fields_flow_not_selected
                         },
                 } = self;
@@ -104,6 +106,8 @@ pub fn impl_with_flow(scope_struct: &ScopeStruct) -> proc_macro2::TokenStream {
                     // profile_params_selection,
                     // flow_params_selection,
                     // params_specs_provided,
+                    // params_specs_override_files,
+                    // item_ids,
                     #scope_builder_fields_flow_selected
                 };
 
@@ -133,8 +137,13 @@ fn scope_builder_fields_flow_not_selected(scope: Scope) -> Punctuated<FieldValue
     if scope.flow_params_supported() {
         field_values.push(parse_quote!(flow_params_selection));
     }
+    if scope.profile_count() == ProfileCount::Multiple {
+        field_values.push(parse_quote!(profile_sort_filter));
+    }
     if scope.flow_count() == FlowCount::One {
         field_values.push(parse_quote!(params_specs_provided));
+        field_values.push(parse_quote!(params_specs_override_files));
+        field_values.push(parse_quote!(item_ids));
     }
 
     field_values
@@ -154,8 +163,13 @@ fn scope_builder_fields_flow_selected(scope: Scope) -> Punctuated<FieldValue, Co
     if scope.flow_params_supported() {
         field_values.push(parse_quote!(flow_params_selection));
     }
+    if scope.profile_count() == ProfileCount::Multiple {
+        field_values.push(parse_quote!(profile_sort_filter));
+    }
     if scope.flow_count() == FlowCount::One {
         field_values.push(parse_quote!(params_specs_provided));
+        field_values.push(parse_quote!(params_specs_override_files));
+        field_values.push(parse_quote!(item_ids));
     }
 
     field_values
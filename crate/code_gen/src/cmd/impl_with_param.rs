@@ -198,6 +198,8 @@ fn impl_with_param_key_unknown(
                             // profile_params_selection,
                             // flow_params_selection,
                             // params_specs_provided,
+                            // params_specs_override_files,
+                            // item_ids,
 
                             #scope_builder_fields_params_none
                         },
@@ -224,6 +226,8 @@ fn impl_with_param_key_unknown(
                     // profile_params_selection,
                     // flow_params_selection,
                     // params_specs_provided,
+                    // params_specs_override_files,
+                    // item_ids,
 
                     #scope_builder_fields_params_some_new
                 };
@@ -358,6 +362,8 @@ fn impl_with_param_key_known(
                             // profile_params_selection,
                             // flow_params_selection,
                             // params_specs_provided,
+                            // params_specs_override_files,
+                            // item_ids,
 
                             #scope_builder_fields_params_some
                         },
@@ -380,6 +386,8 @@ fn impl_with_param_key_known(
                     // profile_params_selection,
                     // flow_params_selection,
                     // params_specs_provided,
+                    // params_specs_override_files,
+                    // item_ids,
 
                     #scope_builder_fields_passthrough
                 };
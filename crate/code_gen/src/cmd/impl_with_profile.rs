@@ -89,6 +89,8 @@ pub fn impl_with_profile(scope_struct: &ScopeStruct) -> proc_macro2::TokenStream
                             // profile_params_selection,
                             // flow_params_selection,
                             // params_specs_provided,
+                            // params_specs_override_files,
+                            // item_ids,
                             #scope_builder_fields_profile_not_selected
                         },
                 } = self;
@@ -101,6 +103,8 @@ pub fn impl_with_profile(scope_struct: &ScopeStruct) -> proc_macro2::TokenStream
                     // profile_params_selection,
                     // flow_params_selection,
                     // params_specs_provided,
+                    // params_specs_override_files,
+                    // item_ids,
                     #scope_builder_fields_profile_selected
                 };
 
@@ -219,6 +223,8 @@ pub fn impl_with_profile_from_workspace_param(
                             // profile_params_selection,
                             // flow_params_selection,
                             // params_specs_provided,
+                            // params_specs_override_files,
+                            // item_ids,
                             #scope_builder_fields_profile_not_selected
                         },
                 } = self;
@@ -231,6 +237,8 @@ pub fn impl_with_profile_from_workspace_param(
                     // profile_params_selection,
                     // flow_params_selection,
                     // params_specs_provided,
+                    // params_specs_override_files,
+                    // item_ids,
                     #scope_builder_fields_profile_from_workspace
                 };
 
@@ -293,5 +301,7 @@ fn scope_builder_fields_remainder_push(
     }
     if scope.flow_count() == FlowCount::One {
         field_values.push(parse_quote!(params_specs_provided));
+        field_values.push(parse_quote!(params_specs_override_files));
+        field_values.push(parse_quote!(item_ids));
     }
 }
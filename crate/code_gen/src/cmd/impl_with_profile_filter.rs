@@ -93,6 +93,8 @@ pub fn impl_with_profile_filter(scope_struct: &ScopeStruct) -> proc_macro2::Toke
                             // profile_params_selection,
                             // flow_params_selection,
                             // params_specs_provided,
+                            // params_specs_override_files,
+                            // item_ids,
                             #scope_builder_fields_profile_not_selected
                         },
                 } = self;
@@ -105,6 +107,8 @@ pub fn impl_with_profile_filter(scope_struct: &ScopeStruct) -> proc_macro2::Toke
                     // profile_params_selection,
                     // flow_params_selection,
                     // params_specs_provided,
+                    // params_specs_override_files,
+                    // item_ids,
                     #scope_builder_fields_profile_filter_fn
                 };
 
@@ -155,7 +159,12 @@ fn scope_builder_fields_remainder_push(
     if scope.flow_params_supported() {
         field_values.push(parse_quote!(flow_params_selection));
     }
+    if scope.profile_count() == ProfileCount::Multiple {
+        field_values.push(parse_quote!(profile_sort_filter));
+    }
     if scope.flow_count() == FlowCount::One {
         field_values.push(parse_quote!(params_specs_provided));
+        field_values.push(parse_quote!(params_specs_override_files));
+        field_values.push(parse_quote!(item_ids));
     }
 }
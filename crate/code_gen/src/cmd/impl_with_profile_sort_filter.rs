@@ -0,0 +1,229 @@
+use quote::quote;
+use syn::parse_quote;
+
+use crate::cmd::{
+    with_params::cmd_ctx_builder_with_params_selected, CmdCtxBuilderTypeBuilder, ImplHeaderBuilder,
+    ParamsScope, ProfileCount, ScopeStruct,
+};
+
+/// Generates the `with_profile_filter_*` and `with_profile_sort*` methods for
+/// the command context builder.
+///
+/// Methods that read profile params are only generated when the profile params
+/// key is known.
+pub fn impl_with_profile_sort_filter(scope_struct: &ScopeStruct) -> proc_macro2::TokenStream {
+    if scope_struct.scope().profile_count() != ProfileCount::Multiple {
+        // Profile sorting and filtering is not supported.
+        return proc_macro2::TokenStream::new();
+    };
+
+    let mut impl_tokens = impl_with_profile_sort_filter_any_params(scope_struct);
+    impl_tokens.extend(impl_with_profile_sort_filter_params_known(scope_struct));
+
+    impl_tokens
+}
+
+fn impl_with_profile_sort_filter_any_params(
+    scope_struct: &ScopeStruct,
+) -> proc_macro2::TokenStream {
+    let scope_builder_name = &scope_struct.item_struct().ident;
+
+    let builder_type = CmdCtxBuilderTypeBuilder::new(scope_builder_name.clone()).build();
+    let impl_header = ImplHeaderBuilder::new(builder_type).build();
+
+    quote! {
+        #impl_header
+        {
+            /// Only includes profiles whose name matches the glob pattern.
+            ///
+            /// `*` matches any number of characters, and `?` matches exactly one
+            /// character. Calling this multiple times includes profiles that
+            /// match all of the patterns.
+            ///
+            /// # Parameters
+            ///
+            /// * `glob`: Pattern that profile names must match, e.g. `"prod_*"`.
+            pub fn with_profile_filter_glob<G>(mut self, glob: G) -> Self
+            where
+                G: Into<String>,
+            {
+                self.scope_builder
+                    .profile_sort_filter
+                    .filter_glob_push(glob.into());
+                self
+            }
+
+            /// Sets the order of profiles.
+            ///
+            /// Profiles are in name order by default. Sorting is stable, so
+            /// profiles that compare equal remain in name order.
+            ///
+            /// This replaces any previously set sort function.
+            pub fn with_profile_sort<F>(mut self, profile_sort_fn: F) -> Self
+            where
+                F: Fn(&peace_core::Profile, &peace_core::Profile) -> std::cmp::Ordering + 'static,
+            {
+                self.scope_builder
+                    .profile_sort_filter
+                    .sort_fn_set(profile_sort_fn);
+                self
+            }
+        }
+    }
+}
+
+fn impl_with_profile_sort_filter_params_known(
+    scope_struct: &ScopeStruct,
+) -> proc_macro2::TokenStream {
+    let scope_builder_name = &scope_struct.item_struct().ident;
+
+    // ```rust,ignore
+    // crate::ctx::CmdCtxBuilder<
+    //     'ctx,
+    //     crate::ctx::CmdCtxBuilderTypesCollector<
+    //         Output,
+    //         AppError,
+    //         peace_rt_model::params::ParamsKeysImpl<
+    //             WorkspaceParamsKMaybe,
+    //             peace_rt_model::params::KeyKnown<ProfileParamsK>,
+    //             FlowParamsKMaybe,
+    //         >,
+    //         WorkspaceParamsSelection,
+    //         crate::scopes::type_params::ProfileParamsSomeMulti<ProfileParamsK>,
+    //         FlowParamsSelection,
+    //         ProfileSelection,
+    //         FlowSelection,
+    //     >,
+    // >
+    // ```
+
+    let builder_type = cmd_ctx_builder_with_params_selected(
+        scope_builder_name,
+        scope_struct,
+        ParamsScope::Profile,
+    );
+    let impl_header = ImplHeaderBuilder::new(builder_type)
+        .with_profile_params_k_maybe(None)
+        .with_profile_params_k(Some(parse_quote!(ProfileParamsK)))
+        .with_profile_params_selection(None)
+        .build();
+
+    quote! {
+        #impl_header
+        {
+            /// Only includes profiles whose profile params pass the filter
+            /// function.
+            ///
+            /// Calling this multiple times includes profiles that pass all of the
+            /// filter functions.
+            pub fn with_profile_filter_params<F>(mut self, profile_filter_fn: F) -> Self
+            where
+                F: Fn(
+                    &peace_core::Profile,
+                    &peace_rt_model::params::ProfileParams<ProfileParamsK>,
+                ) -> bool + 'static,
+            {
+                self.scope_builder
+                    .profile_sort_filter
+                    .filter_fn_push(profile_filter_fn);
+                self
+            }
+
+            /// Only includes profiles whose profile param for `k` equals `value`.
+            ///
+            /// Profiles that do not have the profile param are excluded.
+            ///
+            /// # Parameters
+            ///
+            /// * `k`: Key of the profile param to compare.
+            /// * `value`: Value that the profile param must equal, e.g. `EnvType::Dev`.
+            pub fn with_profile_filter_param_value<ProfileParam>(
+                mut self,
+                k: ProfileParamsK,
+                value: ProfileParam,
+            ) -> Self
+            where
+                ProfileParam: Clone
+                    + std::fmt::Debug
+                    + PartialEq
+                    + serde::Serialize
+                    + Send
+                    + Sync
+                    + 'static,
+            {
+                self.scope_builder.profile_sort_filter.filter_fn_push(
+                    move |_profile, profile_params: &peace_rt_model::params::ProfileParams<ProfileParamsK>| {
+                        profile_params.get::<ProfileParam, _>(&k) == Some(&value)
+                    },
+                );
+                self
+            }
+
+            /// Sets the order of profiles using their profile params.
+            ///
+            /// Profiles are in name order by default. Sorting is stable, so
+            /// profiles that compare equal remain in name order.
+            ///
+            /// This replaces any previously set sort function.
+            pub fn with_profile_sort_by_params<F>(mut self, profile_sort_fn: F) -> Self
+            where
+                F: Fn(
+                    (&peace_core::Profile, &peace_rt_model::params::ProfileParams<ProfileParamsK>),
+                    (&peace_core::Profile, &peace_rt_model::params::ProfileParams<ProfileParamsK>),
+                ) -> std::cmp::Ordering + 'static,
+            {
+                self.scope_builder
+                    .profile_sort_filter
+                    .sort_by_params_fn_set(profile_sort_fn);
+                self
+            }
+
+            /// Orders profiles by the value of their profile param for `k`.
+            ///
+            /// Profiles that do not have the profile param are placed last.
+            /// Profiles with equal values remain in name order.
+            ///
+            /// This replaces any previously set sort function.
+            ///
+            /// # Parameters
+            ///
+            /// * `k`: Key of the profile param to sort by.
+            pub fn with_profile_sort_by_param_value<ProfileParam>(
+                mut self,
+                k: ProfileParamsK,
+            ) -> Self
+            where
+                ProfileParam: Clone
+                    + std::fmt::Debug
+                    + Ord
+                    + serde::Serialize
+                    + Send
+                    + Sync
+                    + 'static,
+            {
+                self.scope_builder.profile_sort_filter.sort_by_params_fn_set(
+                    move |
+                        (_profile_a, profile_params_a): (
+                            &peace_core::Profile,
+                            &peace_rt_model::params::ProfileParams<ProfileParamsK>,
+                        ),
+                        (_profile_b, profile_params_b): (
+                            &peace_core::Profile,
+                            &peace_rt_model::params::ProfileParams<ProfileParamsK>,
+                        )
+                    | {
+                        let value_a = profile_params_a.get::<ProfileParam, _>(&k);
+                        let value_b = profile_params_b.get::<ProfileParam, _>(&k);
+                        match (value_a, value_b) {
+                            (Some(value_a), Some(value_b)) => value_a.cmp(value_b),
+                            (Some(_), None) => std::cmp::Ordering::Less,
+                            (None, Some(_)) => std::cmp::Ordering::Greater,
+                            (None, None) => std::cmp::Ordering::Equal,
+                        }
+                    },
+                );
+                self
+            }
+        }
+    }
+}
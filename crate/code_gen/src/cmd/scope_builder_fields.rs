@@ -49,8 +49,13 @@ pub(crate) fn passthrough(
         }
     }
 
+    if scope.profile_count() == ProfileCount::Multiple {
+        field_values.push(parse_quote!(profile_sort_filter));
+    }
     if scope.flow_count() == FlowCount::One {
         field_values.push(parse_quote!(params_specs_provided));
+        field_values.push(parse_quote!(params_specs_override_files));
+        field_values.push(parse_quote!(item_ids));
     }
 
     field_values
@@ -109,8 +114,13 @@ pub(crate) fn params_none(
         }
     }
 
+    if scope.profile_count() == ProfileCount::Multiple {
+        field_values.push(parse_quote!(profile_sort_filter));
+    }
     if scope.flow_count() == FlowCount::One {
         field_values.push(parse_quote!(params_specs_provided));
+        field_values.push(parse_quote!(params_specs_override_files));
+        field_values.push(parse_quote!(item_ids));
     }
 
     field_values
@@ -160,8 +170,13 @@ pub(crate) fn params_some(scope: Scope, params_scope: ParamsScope) -> Punctuated
         }
     }
 
+    if scope.profile_count() == ProfileCount::Multiple {
+        field_values.push(parse_quote!(profile_sort_filter));
+    }
     if scope.flow_count() == FlowCount::One {
         field_values.push(parse_quote!(params_specs_provided));
+        field_values.push(parse_quote!(params_specs_override_files));
+        field_values.push(parse_quote!(item_ids));
     }
 
     field_values
@@ -245,8 +260,13 @@ pub(crate) fn params_some_new(
         }
     }
 
+    if scope.profile_count() == ProfileCount::Multiple {
+        field_values.push(parse_quote!(profile_sort_filter));
+    }
     if scope.flow_count() == FlowCount::One {
         field_values.push(parse_quote!(params_specs_provided));
+        field_values.push(parse_quote!(params_specs_override_files));
+        field_values.push(parse_quote!(item_ids));
     }
 
     field_values
@@ -39,6 +39,10 @@ use crate::cmd::scope_struct::ScopeStruct;
 ///     pub(crate) flow_params_selection: CmdCtxBuilderTypesT::FlowParamsSelection,
 ///     /// Map of item ID to its parameters. `TypeMap<ItemId, AnySpecRtBoxed>` newtype.
 ///     pub(crate) params_specs_provided: peace_params::ParamsSpecs,
+///     /// Paths to files of params specs that override the provided params specs.
+///     pub(crate) params_specs_override_files: Vec<std::path::PathBuf>,
+///     /// IDs of the items whose stored states to read, or all items if `None`.
+///     pub(crate) item_ids: Option<Vec<peace_cfg::ItemId>>,
 /// }
 /// ```
 pub fn struct_definition(scope_struct: &mut ScopeStruct) -> proc_macro2::TokenStream {
@@ -49,6 +53,7 @@ pub fn struct_definition(scope_struct: &mut ScopeStruct) -> proc_macro2::TokenSt
 
         fields::profile_and_flow_selection_push(&mut fields, scope);
         fields::params_selection_push(&mut fields, scope);
+        fields::profile_sort_filter_push(&mut fields, scope);
         fields::params_specs_push(&mut fields, scope);
 
         Fields::from(fields)
@@ -124,13 +129,30 @@ mod fields {
         }
     }
 
-    /// Appends a `params_specs_provided: ParamsSpecs` field to the given
-    /// fields.
+    /// Appends the `profile_sort_filter: ProfileSortFilter` field to the given
+    /// fields for `MultiProfile*` scopes.
+    pub fn profile_sort_filter_push(fields_named: &mut FieldsNamed, scope: Scope) {
+        if scope.profile_count() == ProfileCount::Multiple {
+            let fields: FieldsNamed = parse_quote!({
+                /// Filters and sort order applied to the listed profiles.
+                pub(crate) profile_sort_filter: crate::ctx::ProfileSortFilter
+            });
+            fields_named.named.extend(fields.named);
+        }
+    }
+
+    /// Appends `params_specs_provided: ParamsSpecs`,
+    /// `params_specs_override_files: Vec<PathBuf>`, and
+    /// `item_ids: Option<Vec<ItemId>>` fields to the given fields.
     pub fn params_specs_push(fields_named: &mut FieldsNamed, scope: Scope) {
         if scope.flow_count() == FlowCount::One {
             let fields_params_specs: FieldsNamed = parse_quote!({
                 /// Map of item ID to its parameters. `TypeMap<ItemId, AnySpecRtBoxed>` newtype.
-                pub(crate) params_specs_provided: peace_params::ParamsSpecs
+                pub(crate) params_specs_provided: peace_params::ParamsSpecs,
+                /// Paths to files of params specs that override the provided params specs.
+                pub(crate) params_specs_override_files: Vec<std::path::PathBuf>,
+                /// IDs of the items whose stored states to read, or all items if `None`.
+                pub(crate) item_ids: Option<Vec<peace_cfg::ItemId>>
             });
             fields_named.named.extend(fields_params_specs.named);
         }
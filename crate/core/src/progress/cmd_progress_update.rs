@@ -38,6 +38,16 @@ pub enum CmdProgressUpdate {
         /// The representation of the state of an `ItemLocation`.
         item_location_state: ItemLocationState,
     },
+    /// `StateDiff` for a single item, presented as text.
+    ///
+    /// This is sent when an item's state diff is computed, so that outputs can
+    /// show the diff alongside the item's progress.
+    ItemStateDiff {
+        /// ID of the `Item`.
+        item_id: ItemId,
+        /// The item's state diff, presented as text.
+        state_diff: String,
+    },
     /// `CmdExecution` has been interrupted, we should indicate this on all
     /// unstarted progress bars.
     Interrupt,
@@ -2,8 +2,8 @@ use dot_ix::model::{
     common::{EdgeId, Edges, NodeHierarchy, NodeId, NodeNames},
     info_graph::{GraphDir, GraphStyle, InfoGraph},
 };
-use fn_graph::{daggy2::Walker, Edge, GraphInfo};
-use peace_core::FlowId;
+use fn_graph::{daggy2::Walker, Edge, FnId, GraphInfo};
+use peace_core::{FlowId, ItemId};
 use serde::{Deserialize, Serialize};
 
 use crate::ItemSpecInfo;
@@ -17,10 +17,7 @@ use dot_ix::model::{
     theme::{AnyIdOrDefaults, CssClassPartials, Theme, ThemeAttr},
 };
 #[cfg(feature = "output_progress")]
-use peace_core::{
-    progress::{ProgressComplete, ProgressStatus},
-    ItemId,
-};
+use peace_core::progress::{ProgressComplete, ProgressStatus};
 
 /// Serializable representation of how a [`Flow`] is configured.
 ///
@@ -42,6 +39,25 @@ impl FlowSpecInfo {
         }
     }
 
+    /// Returns each item's depth in the flow graph, in insertion order.
+    ///
+    /// Items without predecessors have a depth of `0`, and every other item is
+    /// one deeper than its deepest predecessor, regardless of whether their
+    /// dependency is `Edge::Logic` (adjacent) or `Edge::Contains` (nested).
+    ///
+    /// This is used to show items as a tree.
+    pub fn item_depths(&self) -> Vec<(ItemId, usize)> {
+        let graph_info = &self.graph_info;
+        let mut node_depths = vec![None; graph_info.node_count()];
+        graph_info
+            .iter_insertion_with_indices()
+            .map(|(node_index, item_spec_info)| {
+                let depth = node_depth(graph_info, node_index, &mut node_depths);
+                (item_spec_info.item_id.clone(), depth)
+            })
+            .collect()
+    }
+
     /// Returns an [`InfoGraph`] that represents the progress of the flow's
     /// execution.
     pub fn to_progress_info_graph(&self) -> InfoGraph {
@@ -169,6 +185,36 @@ fn item_progress_css_class_partials(progress_status: &ProgressStatus) -> CssClas
     css_class_partials
 }
 
+/// Returns the depth of a node, computing and storing the depths of its
+/// predecessors if they are not yet known.
+fn node_depth(
+    graph_info: &GraphInfo<ItemSpecInfo>,
+    node_index: FnId,
+    node_depths: &mut [Option<usize>],
+) -> usize {
+    if let Some(depth) = node_depths[node_index.index()] {
+        return depth;
+    }
+
+    let depth = graph_info
+        .parents(node_index)
+        .iter(graph_info)
+        .filter(|(edge_index, _parent_node_index)| {
+            matches!(
+                graph_info.edge_weight(*edge_index).copied(),
+                Some(Edge::Logic | Edge::Contains)
+            )
+        })
+        .map(|(_edge_index, parent_node_index)| {
+            node_depth(graph_info, parent_node_index, node_depths) + 1
+        })
+        .max()
+        .unwrap_or(0);
+    node_depths[node_index.index()] = Some(depth);
+
+    depth
+}
+
 /// Returns the list of edges between items in the graph for progress.
 ///
 /// For progress graphs, an edge is rendered between pairs of predecessor and
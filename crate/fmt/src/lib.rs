@@ -6,6 +6,7 @@
 
 // Re-exports
 pub use async_trait::async_trait;
+pub use peace_fmt_derive::Presentable;
 
 pub use crate::{
     either::Either, presentable::Presentable, presentable_ext::PresentableExt, presenter::Presenter,
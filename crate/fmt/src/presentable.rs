@@ -1,7 +1,9 @@
 pub use self::{
-    bold::Bold, code_inline::CodeInline, heading::Heading, heading_level::HeadingLevel,
-    list_bulleted::ListBulleted, list_bulleted_aligned::ListBulletedAligned,
-    list_numbered::ListNumbered, list_numbered_aligned::ListNumberedAligned,
+    bold::Bold, code_inline::CodeInline, diff_kind::DiffKind, heading::Heading,
+    heading_level::HeadingLevel, list_bulleted::ListBulleted,
+    list_bulleted_aligned::ListBulletedAligned, list_numbered::ListNumbered,
+    list_numbered_aligned::ListNumberedAligned, table::Table, table_alignment::TableAlignment,
+    table_column::TableColumn,
 };
 
 use serde::Serialize;
@@ -10,12 +12,16 @@ use crate::Presenter;
 
 mod bold;
 mod code_inline;
+mod diff_kind;
 mod heading;
 mod heading_level;
 mod list_bulleted;
 mod list_bulleted_aligned;
 mod list_numbered;
 mod list_numbered_aligned;
+mod table;
+mod table_alignment;
+mod table_column;
 mod tuple_impl;
 
 /// A type that is presentable to a user.
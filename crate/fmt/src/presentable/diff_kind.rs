@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+/// Kind of change a line in a diff represents.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum DiffKind {
+    /// Value exists in the second state, but not the first.
+    Added,
+    /// Value exists in the first state, but not the second.
+    Removed,
+    /// Value exists in both states, but differs.
+    Modified,
+    /// Value is the same in both states.
+    Unchanged,
+}
+
+impl DiffKind {
+    /// Returns the symbol to prefix a diff line with.
+    ///
+    /// * `Added`: `+`
+    /// * `Removed`: `-`
+    /// * `Modified`: `~`
+    /// * `Unchanged`: `=`
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::Added => "+",
+            Self::Removed => "-",
+            Self::Modified => "~",
+            Self::Unchanged => "=",
+        }
+    }
+}
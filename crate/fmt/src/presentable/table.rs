@@ -0,0 +1,147 @@
+use std::borrow::Cow;
+
+use serde::{
+    ser::{SerializeMap, SerializeSeq},
+    Serialize, Serializer,
+};
+
+use crate::{presentable::TableColumn, Presentable, Presenter};
+
+/// Presents rows of values in aligned columns.
+///
+/// Each row should have one value per column. Values beyond the number of
+/// columns are not presented, and missing values are presented as empty cells.
+///
+/// When serialized, a `Table` is a list of rows, where each row is an object
+/// of values keyed by their column's header, in column order. See
+/// [`column_keys`] for how columns with the same header are keyed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use peace_fmt::presentable::{Table, TableAlignment, TableColumn};
+/// let table = Table::new(
+///     vec![
+///         TableColumn::new("Profile"),
+///         TableColumn::new("Items").with_alignment(TableAlignment::Right),
+///     ],
+///     vec![
+///         vec![String::from("demo"), String::from("3")],
+///         vec![String::from("production"), String::from("12")],
+///     ],
+/// );
+/// ```
+///
+/// [`column_keys`]: Self::column_keys
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Table<'s, P> {
+    /// Header and alignment of each column.
+    columns: Vec<TableColumn<'s>>,
+    /// Values in each row.
+    rows: Vec<Vec<P>>,
+}
+
+impl<'s, P> Table<'s, P> {
+    /// Returns a new `Table`.
+    pub fn new(columns: Vec<TableColumn<'s>>, rows: Vec<Vec<P>>) -> Self {
+        Self { columns, rows }
+    }
+
+    /// Returns the header and alignment of each column.
+    pub fn columns(&self) -> &[TableColumn<'s>] {
+        &self.columns
+    }
+
+    /// Returns the values in each row.
+    pub fn rows(&self) -> &[Vec<P>] {
+        &self.rows
+    }
+
+    /// Returns the key of each column's values when a row is serialized.
+    ///
+    /// This is the column's header. When multiple columns have the same
+    /// header, the first column is keyed by the header, and later columns are
+    /// keyed by the header with their occurrence number, e.g. `Name`,
+    /// `Name (2)`, `Name (3)`, so that no values are lost.
+    pub fn column_keys(&self) -> Vec<Cow<'_, str>> {
+        let mut column_keys = Vec::<Cow<'_, str>>::with_capacity(self.columns.len());
+        self.columns.iter().for_each(|column| {
+            let header = column.header();
+            let is_taken = |key: &str, column_keys: &[Cow<'_, str>]| {
+                column_keys.iter().any(|column_key| column_key == key)
+            };
+
+            let column_key = if is_taken(header, &column_keys) {
+                (2..)
+                    .map(|occurrence| format!("{header} ({occurrence})"))
+                    .find(|key| {
+                        !is_taken(key, &column_keys)
+                            && !self.columns.iter().any(|column| column.header() == key)
+                    })
+                    .map(Cow::Owned)
+                    .unwrap_or(Cow::Borrowed(header))
+            } else {
+                Cow::Borrowed(header)
+            };
+            column_keys.push(column_key);
+        });
+        column_keys
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<P> Presentable for Table<'_, P>
+where
+    P: Presentable,
+{
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        presenter.table(self).await
+    }
+}
+
+impl<P> Serialize for Table<'_, P>
+where
+    P: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let column_keys = self.column_keys();
+        let mut seq = serializer.serialize_seq(Some(self.rows.len()))?;
+        self.rows.iter().try_for_each(|row| {
+            seq.serialize_element(&TableRow {
+                column_keys: &column_keys,
+                values: row,
+            })
+        })?;
+        seq.end()
+    }
+}
+
+/// Serializes a row as an object of values keyed by their column's key.
+struct TableRow<'table, P> {
+    column_keys: &'table [Cow<'table, str>],
+    values: &'table [P],
+}
+
+impl<P> Serialize for TableRow<'_, P>
+where
+    P: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let len = self.column_keys.len().min(self.values.len());
+        let mut map = serializer.serialize_map(Some(len))?;
+        self.column_keys
+            .iter()
+            .zip(self.values.iter())
+            .try_for_each(|(column_key, value)| map.serialize_entry(column_key, value))?;
+        map.end()
+    }
+}
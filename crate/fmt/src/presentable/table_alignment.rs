@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// Horizontal alignment of values in a table column.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum TableAlignment {
+    /// Values are aligned to the left of the column.
+    #[default]
+    Left,
+    /// Values are centered in the column.
+    Center,
+    /// Values are aligned to the right of the column.
+    Right,
+}
@@ -0,0 +1,42 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::presentable::TableAlignment;
+
+/// Header and alignment of a column in a [`Table`].
+///
+/// [`Table`]: crate::presentable::Table
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TableColumn<'s> {
+    /// Text in the column's header.
+    header: Cow<'s, str>,
+    /// Horizontal alignment of values in the column.
+    alignment: TableAlignment,
+}
+
+impl<'s> TableColumn<'s> {
+    /// Returns a new left aligned `TableColumn`.
+    pub fn new(header: impl Into<Cow<'s, str>>) -> Self {
+        Self {
+            header: header.into(),
+            alignment: TableAlignment::default(),
+        }
+    }
+
+    /// Sets the horizontal alignment of values in the column.
+    pub fn with_alignment(mut self, alignment: TableAlignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Returns the text in the column's header.
+    pub fn header(&self) -> &str {
+        &self.header
+    }
+
+    /// Returns the horizontal alignment of values in the column.
+    pub fn alignment(&self) -> TableAlignment {
+        self.alignment
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{presentable::HeadingLevel, Presentable};
+use crate::{
+    presentable::{DiffKind, HeadingLevel, Table},
+    Presentable,
+};
 
 /// Takes a `Presentable` type and presents it to the user.
 ///
@@ -91,6 +94,20 @@ pub trait Presenter<'output> {
     /// * A value used to categorize data, e.g. "stale".
     async fn tag(&mut self, tag: &str) -> Result<(), Self::Error>;
 
+    /// Presents text as a line in a diff, prefixed with the
+    /// [`DiffKind::symbol`].
+    ///
+    /// # Purposes
+    ///
+    /// * A field that is added, removed, or modified between two states.
+    /// * An item whose state is unchanged.
+    ///
+    /// By default, this presents the symbol and text through
+    /// [`Presenter::text`].
+    async fn diff(&mut self, diff_kind: DiffKind, text: &str) -> Result<(), Self::Error> {
+        self.text(&format!("{} {text}", diff_kind.symbol())).await
+    }
+
     /// Presents a numbered list.
     ///
     /// # Purposes
@@ -246,4 +263,52 @@ pub trait Presenter<'output> {
         I: IntoIterator<Item = T>,
         T: 'f,
         F: Fn(T) -> &'f (P0, P1);
+
+    /// Presents rows of values in aligned columns.
+    ///
+    /// Each value in a column is aligned according to the column's
+    /// [`TableAlignment`]. i.e.
+    ///
+    /// ```md
+    /// | Profile    | Items |
+    /// | :--------- | ----: |
+    /// | demo       |     3 |
+    /// | production |    12 |
+    /// ```
+    ///
+    /// # Purposes
+    ///
+    /// * A list of entries with multiple attributes, e.g. profiles.
+    /// * Metrics.
+    ///
+    /// By default, this presents the headers and each row on their own line,
+    /// with values separated by ` | ` and not aligned.
+    ///
+    /// [`TableAlignment`]: crate::presentable::TableAlignment
+    async fn table<'s, P>(&mut self, table: &Table<'s, P>) -> Result<(), Self::Error>
+    where
+        Self: Sized,
+        P: Presentable,
+    {
+        let headers = table
+            .columns()
+            .iter()
+            .map(|column| column.header())
+            .collect::<Vec<&str>>()
+            .join(" | ");
+        self.text(&headers).await?;
+        self.text("\n").await?;
+
+        for row in table.rows() {
+            for (index, value) in row.iter().take(table.columns().len()).enumerate() {
+                if index > 0 {
+                    self.text(" | ").await?;
+                }
+                value.present(self).await?;
+            }
+            self.text("\n").await?;
+        }
+
+        Ok(())
+    }
 }
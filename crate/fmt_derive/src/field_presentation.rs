@@ -0,0 +1,88 @@
+use syn::{Attribute, LitInt, LitStr};
+
+/// How a field is presented, from its `#[presentable(..)]` attributes.
+#[derive(Clone, Debug, Default)]
+pub struct FieldPresentation {
+    /// How the field's value is presented.
+    pub kind: FieldPresentationKind,
+    /// Label to present instead of the field name.
+    pub label: Option<String>,
+}
+
+/// How a field's value is presented.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FieldPresentationKind {
+    /// Uses the field's `Presentable` implementation.
+    #[default]
+    Presentable,
+    /// Presents the field's `Display` string as inline code.
+    CodeInline,
+    /// Presents the field in bold.
+    Bold,
+    /// Presents the field as a bulleted list.
+    List,
+    /// Presents the field as a numbered list.
+    ListNumbered,
+    /// Presents the field as a heading, with the given level.
+    Heading(u8),
+    /// Does not present the field.
+    Skip,
+}
+
+impl FieldPresentation {
+    /// Returns the `FieldPresentation` from a field's attributes.
+    pub fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut field_presentation = Self::default();
+
+        attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("presentable"))
+            .try_for_each(|attr| {
+                attr.parse_nested_meta(|meta| {
+                    let kind = if meta.path.is_ident("code_inline") {
+                        FieldPresentationKind::CodeInline
+                    } else if meta.path.is_ident("bold") {
+                        FieldPresentationKind::Bold
+                    } else if meta.path.is_ident("list") {
+                        FieldPresentationKind::List
+                    } else if meta.path.is_ident("list_numbered") {
+                        FieldPresentationKind::ListNumbered
+                    } else if meta.path.is_ident("skip") {
+                        FieldPresentationKind::Skip
+                    } else if meta.path.is_ident("heading") {
+                        let level_lit: LitInt = meta.value()?.parse()?;
+                        let level = level_lit.base10_parse::<u8>()?;
+                        if !(1..=6).contains(&level) {
+                            return Err(syn::Error::new_spanned(
+                                level_lit,
+                                "Heading level must be between 1 and 6.",
+                            ));
+                        }
+                        FieldPresentationKind::Heading(level)
+                    } else if meta.path.is_ident("label") {
+                        let label: LitStr = meta.value()?.parse()?;
+                        field_presentation.label = Some(label.value());
+                        return Ok(());
+                    } else {
+                        return Err(meta.error(
+                            "Unsupported `presentable` attribute. Expected one of \
+                            `code_inline`, `bold`, `list`, `list_numbered`, `heading = n`, \
+                            `skip`, or `label = \"..\"`.",
+                        ));
+                    };
+
+                    if field_presentation.kind != FieldPresentationKind::Presentable {
+                        return Err(meta.error(
+                            "Only one of `code_inline`, `bold`, `list`, `list_numbered`, \
+                            `heading`, or `skip` may be specified.",
+                        ));
+                    }
+                    field_presentation.kind = kind;
+
+                    Ok(())
+                })
+            })?;
+
+        Ok(field_presentation)
+    }
+}
@@ -0,0 +1,133 @@
+use proc_macro2::TokenStream;
+use syn::{Fields, Ident};
+
+use crate::field_presentation::{FieldPresentation, FieldPresentationKind};
+
+/// Returns the pattern that binds each field, and the statements to present
+/// the fields.
+///
+/// Skipped fields are not bound, so that the generated code does not have
+/// unused variables.
+///
+/// # Parameters
+///
+/// * `peace_fmt_path`: Path to the `peace_fmt` crate.
+/// * `path`: Path of the struct or enum variant, e.g. `Self::Variant`.
+/// * `fields`: Fields of the struct or enum variant.
+pub fn fields_present(
+    peace_fmt_path: &TokenStream,
+    path: &TokenStream,
+    fields: &Fields,
+) -> syn::Result<(TokenStream, TokenStream)> {
+    let field_presentations = fields
+        .iter()
+        .map(|field| FieldPresentation::from_attrs(&field.attrs))
+        .collect::<syn::Result<Vec<FieldPresentation>>>()?;
+
+    let field_bindings = fields
+        .iter()
+        .zip(field_presentations.iter())
+        .enumerate()
+        .map(|(index, (field, field_presentation))| {
+            if field_presentation.kind == FieldPresentationKind::Skip {
+                quote!(_)
+            } else {
+                let binding = field_binding(index, field.ident.as_ref());
+                quote!(#binding)
+            }
+        })
+        .collect::<Vec<TokenStream>>();
+
+    let pattern = match fields {
+        Fields::Named(_) => {
+            let field_names = fields.iter().filter_map(|field| field.ident.as_ref());
+            quote!(#path { #(#field_names: #field_bindings),* })
+        }
+        Fields::Unnamed(_) => quote!(#path ( #(#field_bindings),* )),
+        Fields::Unit => quote!(#path),
+    };
+
+    let is_named = matches!(fields, Fields::Named(_));
+    let fields_present = fields
+        .iter()
+        .zip(field_presentations.iter())
+        .enumerate()
+        .filter(|(_index, (_field, field_presentation))| {
+            field_presentation.kind != FieldPresentationKind::Skip
+        })
+        .map(|(index, (field, field_presentation))| {
+            let binding = field_binding(index, field.ident.as_ref());
+            let value_present = value_present(peace_fmt_path, &binding, field_presentation.kind);
+
+            match field.ident.as_ref().filter(|_| is_named) {
+                Some(field_name) => {
+                    let label = field_presentation.label.clone().unwrap_or_else(|| {
+                        field_name.to_string().trim_start_matches("r#").to_string()
+                    });
+                    match field_presentation.kind {
+                        FieldPresentationKind::Heading(_) => value_present,
+                        FieldPresentationKind::List | FieldPresentationKind::ListNumbered => {
+                            quote! {
+                                presenter.text(#label).await?;
+                                presenter.text(":\n").await?;
+                                #value_present
+                            }
+                        }
+                        FieldPresentationKind::Presentable
+                        | FieldPresentationKind::CodeInline
+                        | FieldPresentationKind::Bold
+                        | FieldPresentationKind::Skip => quote! {
+                            presenter.text(#label).await?;
+                            presenter.text(": ").await?;
+                            #value_present
+                            presenter.text("\n").await?;
+                        },
+                    }
+                }
+                None => value_present,
+            }
+        })
+        .collect::<TokenStream>();
+
+    Ok((pattern, fields_present))
+}
+
+/// Returns the statement to present a field's value.
+fn value_present(
+    peace_fmt_path: &TokenStream,
+    binding: &Ident,
+    kind: FieldPresentationKind,
+) -> TokenStream {
+    match kind {
+        FieldPresentationKind::Presentable => {
+            quote!(#peace_fmt_path::Presentable::present(#binding, presenter).await?;)
+        }
+        FieldPresentationKind::CodeInline => {
+            quote!(presenter.code_inline(&::std::string::ToString::to_string(#binding)).await?;)
+        }
+        FieldPresentationKind::Bold => quote!(presenter.bold(#binding).await?;),
+        FieldPresentationKind::List => quote!(presenter.list_bulleted(#binding).await?;),
+        FieldPresentationKind::ListNumbered => {
+            quote!(presenter.list_numbered(#binding).await?;)
+        }
+        FieldPresentationKind::Heading(level) => {
+            let heading_level = format_ident!("Level{}", level);
+            quote! {
+                presenter
+                    .heading(#peace_fmt_path::presentable::HeadingLevel::#heading_level, #binding)
+                    .await?;
+            }
+        }
+        FieldPresentationKind::Skip => quote!(),
+    }
+}
+
+/// Returns the identifier to bind a field to when destructuring.
+fn field_binding(index: usize, field_name: Option<&Ident>) -> Ident {
+    match field_name {
+        Some(field_name) => {
+            format_ident!("field_{}", field_name.to_string().trim_start_matches("r#"))
+        }
+        None => format_ident!("field_{}", index),
+    }
+}
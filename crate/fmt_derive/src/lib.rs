@@ -0,0 +1,217 @@
+#![recursion_limit = "256"]
+
+//! Presentable derive macro for the peace automation framework.
+
+extern crate proc_macro;
+extern crate proc_macro2;
+#[macro_use]
+extern crate quote;
+#[macro_use]
+extern crate syn;
+
+use proc_macro::TokenStream;
+use syn::{
+    Attribute, DataEnum, DataStruct, DeriveInput, Fields, GenericParam, Ident, Variant,
+    WherePredicate,
+};
+
+use crate::{fields_present::fields_present, variant_attrs::VariantAttrs};
+
+mod field_presentation;
+mod fields_present;
+mod variant_attrs;
+
+/// Used to `#[derive]` the `Presentable` trait.
+///
+/// For regular usage, use `#[derive(Presentable)]`
+///
+/// For peace crates, also add the `#[peace_internal]` attribute, which
+/// references the `peace_fmt` crate instead of the `peace::fmt` re-export.
+///
+/// # Structs
+///
+/// Named fields are presented one per line, as `field_name: value`. Unnamed
+/// fields are presented one after another, so a newtype is presented as its
+/// inner value.
+///
+/// # Enums
+///
+/// Unit variants are presented as the variant name. Variants with unnamed
+/// fields are presented as their fields. Variants with named fields are
+/// presented as the variant name, followed by one field per line.
+///
+/// # Attributes
+///
+/// Fields may be annotated with one of the following:
+///
+/// * `#[presentable(code_inline)]`: Presents the field's `Display` string as
+///   inline code.
+/// * `#[presentable(bold)]`: Presents the field in bold.
+/// * `#[presentable(list)]`: Presents the field as a bulleted list.
+/// * `#[presentable(list_numbered)]`: Presents the field as a numbered list.
+/// * `#[presentable(heading = 2)]`: Presents the field as a heading, with the
+///   given level from `1` to `6`.
+/// * `#[presentable(skip)]`: Does not present the field.
+///
+/// Named fields and variants may also be given a `label = "Label"`, which
+/// replaces the field or variant name.
+///
+/// Unit variants may be annotated with `#[presentable(code_inline)]`, and any
+/// variant may be annotated with `#[presentable(skip)]` to present nothing.
+#[proc_macro_derive(Presentable, attributes(peace_internal, presentable))]
+pub fn presentable(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    impl_presentable(&ast)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn impl_presentable(ast: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &ast.ident;
+
+    let peace_fmt_path = ast
+        .attrs
+        .iter()
+        .find(peace_internal)
+        .map(|_| quote!(peace_fmt))
+        .unwrap_or_else(|| quote!(peace::fmt));
+
+    let present_body = match &ast.data {
+        syn::Data::Struct(DataStruct { fields, .. }) => {
+            struct_present(&peace_fmt_path, name, fields)?
+        }
+        syn::Data::Enum(DataEnum { variants, .. }) => {
+            let variant_arms = variants
+                .iter()
+                .map(|variant| variant_present(&peace_fmt_path, variant))
+                .collect::<syn::Result<Vec<_>>>()?;
+
+            if variant_arms.is_empty() {
+                quote!(match *self {})
+            } else {
+                quote! {
+                    match self {
+                        #(#variant_arms)*
+                    }
+                }
+            }
+        }
+        syn::Data::Union(data_union) => {
+            return Err(syn::Error::new_spanned(
+                data_union.union_token,
+                "`Presentable` cannot be derived for unions.",
+            ));
+        }
+    };
+
+    let mut generics = ast.generics.clone();
+    let type_params = generics
+        .params
+        .iter()
+        .filter_map(|generic_param| match generic_param {
+            GenericParam::Type(type_param) => Some(type_param.ident.clone()),
+            GenericParam::Lifetime(_) | GenericParam::Const(_) => None,
+        })
+        .collect::<Vec<Ident>>();
+    {
+        let where_clause = generics.make_where_clause();
+        type_params.iter().for_each(|type_param| {
+            let where_predicate: WherePredicate =
+                parse_quote!(#type_param: #peace_fmt_path::Presentable);
+            where_clause.predicates.push(where_predicate);
+        });
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        #[#peace_fmt_path::async_trait(?Send)]
+        impl #impl_generics #peace_fmt_path::Presentable
+            for #name #ty_generics
+            #where_clause
+        {
+            async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+            where
+                PR: #peace_fmt_path::Presenter<'output>,
+            {
+                #present_body
+
+                Ok(())
+            }
+        }
+    })
+}
+
+fn peace_internal(attr: &&Attribute) -> bool {
+    attr.path().is_ident("peace_internal")
+}
+
+/// Returns the statements to present a struct's fields.
+fn struct_present(
+    peace_fmt_path: &proc_macro2::TokenStream,
+    name: &Ident,
+    fields: &Fields,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let (pattern, fields_present) = fields_present(peace_fmt_path, &quote!(#name), fields)?;
+
+    Ok(quote! {
+        let #pattern = self;
+        #fields_present
+    })
+}
+
+/// Returns the match arm to present an enum variant.
+fn variant_present(
+    peace_fmt_path: &proc_macro2::TokenStream,
+    variant: &Variant,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let variant_name = &variant.ident;
+    let variant_attrs = VariantAttrs::from_attrs(&variant.attrs)?;
+    let label = variant_attrs
+        .label
+        .clone()
+        .unwrap_or_else(|| variant_name.to_string());
+
+    if variant_attrs.code_inline && !matches!(variant.fields, Fields::Unit) {
+        return Err(syn::Error::new_spanned(
+            variant,
+            "`#[presentable(code_inline)]` is only supported on unit variants.",
+        ));
+    }
+
+    if variant_attrs.skip {
+        let pattern = match &variant.fields {
+            Fields::Named(_) => quote!(Self::#variant_name { .. }),
+            Fields::Unnamed(_) => quote!(Self::#variant_name(..)),
+            Fields::Unit => quote!(Self::#variant_name),
+        };
+        return Ok(quote!(#pattern => {}));
+    }
+
+    let (pattern, fields_present) = fields_present(
+        peace_fmt_path,
+        &quote!(Self::#variant_name),
+        &variant.fields,
+    )?;
+    let body = match &variant.fields {
+        Fields::Unit => {
+            if variant_attrs.code_inline {
+                quote!(presenter.code_inline(#label).await?;)
+            } else {
+                quote!(presenter.text(#label).await?;)
+            }
+        }
+        Fields::Unnamed(_) => fields_present,
+        Fields::Named(_) => quote! {
+            presenter.text(#label).await?;
+            presenter.text("\n").await?;
+            #fields_present
+        },
+    };
+
+    Ok(quote! {
+        #pattern => {
+            #body
+        }
+    })
+}
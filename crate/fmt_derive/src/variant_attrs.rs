@@ -0,0 +1,44 @@
+use syn::{Attribute, LitStr};
+
+/// `#[presentable(..)]` attributes on an enum variant.
+#[derive(Clone, Debug, Default)]
+pub struct VariantAttrs {
+    /// Whether to present a unit variant as inline code.
+    pub code_inline: bool,
+    /// Whether to present nothing for this variant.
+    pub skip: bool,
+    /// Label to present instead of the variant name.
+    pub label: Option<String>,
+}
+
+impl VariantAttrs {
+    /// Returns the `VariantAttrs` from a variant's attributes.
+    pub fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut variant_attrs = Self::default();
+
+        attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("presentable"))
+            .try_for_each(|attr| {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("code_inline") {
+                        variant_attrs.code_inline = true;
+                    } else if meta.path.is_ident("skip") {
+                        variant_attrs.skip = true;
+                    } else if meta.path.is_ident("label") {
+                        let label: LitStr = meta.value()?.parse()?;
+                        variant_attrs.label = Some(label.value());
+                    } else {
+                        return Err(meta.error(
+                            "Unsupported `presentable` attribute on variant. Expected one of \
+                            `code_inline`, `skip`, or `label = \"..\"`.",
+                        ));
+                    }
+
+                    Ok(())
+                })
+            })?;
+
+        Ok(variant_attrs)
+    }
+}
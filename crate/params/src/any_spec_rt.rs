@@ -1,4 +1,4 @@
-use crate::AnySpecDataType;
+use crate::{AnySpecDataType, MappingFnBindError, MappingFnReg};
 
 /// Runtime logic of how to look up values for each field in this struct.
 ///
@@ -28,6 +28,19 @@ pub trait AnySpecRt {
     /// safe. Adding a `where: Self: Sized` bound prevents the method from being
     /// called from `cmd_ctx_builder`.
     fn merge(&mut self, other: &dyn AnySpecDataType);
+    /// Binds named `*Spec::MappingFn`s that hold no logic to the functions
+    /// registered under those names.
+    ///
+    /// Names that are not registered in the `mapping_fn_reg`, or whose
+    /// registered function outputs a different type, are pushed onto
+    /// `mapping_fn_bind_errors`.
+    ///
+    /// For `Self::FieldWise`, each field's `ValueSpec` is bound.
+    fn mapping_fns_bind(
+        &mut self,
+        mapping_fn_reg: &MappingFnReg,
+        mapping_fn_bind_errors: &mut Vec<MappingFnBindError>,
+    );
 }
 
 impl<T> AnySpecRt for Box<T>
@@ -44,4 +57,13 @@ where
     {
         self.as_mut().merge(other)
     }
+
+    fn mapping_fns_bind(
+        &mut self,
+        mapping_fn_reg: &MappingFnReg,
+        mapping_fn_bind_errors: &mut Vec<MappingFnBindError>,
+    ) {
+        self.as_mut()
+            .mapping_fns_bind(mapping_fn_reg, mapping_fn_bind_errors)
+    }
 }
@@ -63,6 +63,8 @@
 
 // Re-exports
 pub use peace_params_derive::{value_impl, Params, ParamsFieldless};
+#[cfg(feature = "json_schema")]
+pub use schemars;
 pub use tynm;
 
 pub use crate::{
@@ -73,16 +75,29 @@ pub use crate::{
     field_wise_spec_rt::FieldWiseSpecRt,
     func::{FromFunc, Func},
     mapping_fn::MappingFn,
+    mapping_fn_bind_error::MappingFnBindError,
     mapping_fn_impl::MappingFnImpl,
+    mapping_fn_name::MappingFnName,
+    mapping_fn_reg::MappingFnReg,
     params::Params,
     params_fieldless::ParamsFieldless,
     params_merge_ext::ParamsMergeExt,
     params_resolve_error::ParamsResolveError,
     params_spec::ParamsSpec,
     params_spec_de::ParamsSpecDe,
+    params_spec_field_origins::ParamsSpecFieldOrigins,
     params_spec_fieldless::ParamsSpecFieldless,
     params_spec_fieldless_de::ParamsSpecFieldlessDe,
+    params_spec_origin::ParamsSpecOrigin,
     params_specs::ParamsSpecs,
+    params_specs_applied::ParamsSpecsApplied,
+    params_specs_origins::ParamsSpecsOrigins,
+    params_specs_profile_origins::ParamsSpecsProfileOrigins,
+    params_specs_stored::ParamsSpecsStored,
+    params_validation_error::ParamsValidationError,
+    secret::Secret,
+    secret_provider::SecretProvider,
+    secret_provider_env::SecretProviderEnv,
     value_resolution_ctx::ValueResolutionCtx,
     value_resolution_mode::ValueResolutionMode,
     value_spec::ValueSpec,
@@ -90,6 +105,16 @@ pub use crate::{
     value_spec_rt::ValueSpecRt,
 };
 
+#[cfg(feature = "json_schema")]
+pub use crate::{
+    params_spec_schema::ParamsSpecSchema, params_spec_schema_reg::ParamsSpecSchemaReg,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::secret_provider_file::SecretProviderFile;
+
+pub mod validate;
+
 mod any_spec_data_type;
 mod any_spec_rt;
 mod any_spec_rt_boxed;
@@ -97,19 +122,39 @@ mod field_name_and_type;
 mod field_wise_spec_rt;
 mod func;
 mod mapping_fn;
+mod mapping_fn_bind_error;
 mod mapping_fn_impl;
+mod mapping_fn_name;
+mod mapping_fn_reg;
 mod params;
 mod params_fieldless;
 mod params_merge_ext;
 mod params_resolve_error;
 mod params_spec;
 mod params_spec_de;
+mod params_spec_field_origins;
 mod params_spec_fieldless;
 mod params_spec_fieldless_de;
+mod params_spec_origin;
 mod params_specs;
+mod params_specs_applied;
+mod params_specs_origins;
+mod params_specs_profile_origins;
+mod params_specs_stored;
+mod params_validation_error;
+mod secret;
+mod secret_provider;
+mod secret_provider_env;
+#[cfg(not(target_arch = "wasm32"))]
+mod secret_provider_file;
 mod std_impl;
 mod value_resolution_ctx;
 mod value_resolution_mode;
 mod value_spec;
 mod value_spec_de;
 mod value_spec_rt;
+
+#[cfg(feature = "json_schema")]
+mod params_spec_schema;
+#[cfg(feature = "json_schema")]
+mod params_spec_schema_reg;
@@ -3,7 +3,7 @@ use std::fmt::Debug;
 use peace_resource_rt::{resources::ts::SetUp, type_reg::untagged::DataType, Resources};
 use serde::{Serialize, Serializer};
 
-use crate::{ParamsResolveError, ValueResolutionCtx};
+use crate::{MappingFnName, ParamsResolveError, ValueResolutionCtx};
 
 /// Type erased mapping function.
 ///
@@ -48,6 +48,19 @@ pub trait MappingFn: Debug + DataType {
     /// Deserialized mapping functions will not hold any function logic, and
     /// Peace uses this function to determine if this is an empty `MappingFn`.
     fn is_valued(&self) -> bool;
+
+    /// Returns the name this mapping function is registered under, if any.
+    ///
+    /// Named mapping functions are rebound from a [`MappingFnReg`] when
+    /// deserialized.
+    ///
+    /// [`MappingFnReg`]: crate::MappingFnReg
+    fn fn_name(&self) -> Option<&MappingFnName>;
+
+    /// Returns the name of the field this mapping function is for.
+    ///
+    /// `None` if this is the top level value type.
+    fn field_name(&self) -> Option<&str>;
 }
 
 impl<T> Clone for Box<dyn MappingFn<Output = T>> {
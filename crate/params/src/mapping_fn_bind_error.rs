@@ -0,0 +1,40 @@
+use crate::MappingFnName;
+
+/// A named mapping function could not be bound to a registered function.
+///
+/// These are collected by [`MappingFnReg::mapping_fn_bind`] and
+/// [`ParamsSpecs::mapping_fns_bind`], so that every unbindable name can be
+/// reported together.
+///
+/// [`MappingFnReg::mapping_fn_bind`]: crate::MappingFnReg::mapping_fn_bind
+/// [`ParamsSpecs::mapping_fns_bind`]: crate::ParamsSpecs::mapping_fns_bind
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MappingFnBindError {
+    /// No mapping function is registered under the name.
+    NameUnknown {
+        /// Name referred to by the params spec.
+        mapping_fn_name: MappingFnName,
+    },
+    /// The mapping function registered under the name outputs a different
+    /// type to the params spec field.
+    OutputTypeMismatch {
+        /// Name referred to by the params spec.
+        mapping_fn_name: MappingFnName,
+        /// Type name of the params spec field.
+        output_type_expected: String,
+        /// Output type name of the registered mapping function.
+        output_type_registered: String,
+    },
+}
+
+impl MappingFnBindError {
+    /// Returns the name of the mapping function that could not be bound.
+    pub fn mapping_fn_name(&self) -> &MappingFnName {
+        match self {
+            Self::NameUnknown { mapping_fn_name }
+            | Self::OutputTypeMismatch {
+                mapping_fn_name, ..
+            } => mapping_fn_name,
+        }
+    }
+}
@@ -8,7 +8,8 @@ use peace_resource_rt::{resources::ts::SetUp, BorrowFail, Resources};
 use serde::{Deserialize, Serialize, Serializer};
 
 use crate::{
-    FromFunc, Func, MappingFn, ParamsResolveError, ValueResolutionCtx, ValueResolutionMode,
+    FromFunc, Func, MappingFn, MappingFnName, ParamsResolveError, ValueResolutionCtx,
+    ValueResolutionMode,
 };
 
 #[cfg(feature = "item_state_example")]
@@ -21,6 +22,11 @@ pub struct MappingFnImpl<T, F, Args> {
     ///
     /// `None` if this is the top level value type.
     field_name: Option<String>,
+    /// Name of the function in the `MappingFnReg`, if it was registered.
+    ///
+    /// This allows the function logic to be rebound after deserialization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    fn_name: Option<MappingFnName>,
     #[serde(
         default = "MappingFnImpl::<T, F, Args>::fn_map_none",
         skip_deserializing,
@@ -28,6 +34,7 @@ pub struct MappingFnImpl<T, F, Args> {
     )]
     fn_map: Option<F>,
     /// Marker.
+    #[serde(default)]
     marker: PhantomData<(T, Args)>,
 }
 
@@ -38,13 +45,67 @@ where
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("MappingFnImpl")
             .field("field_name", &self.field_name)
+            .field("fn_name", &self.fn_name)
             .field("fn_map", &Self::fn_map_stringify(&self.fn_map))
             .field("marker", &self.marker)
             .finish()
     }
 }
 
+/// Only named mapping functions can be written by hand, so `fn_name` is
+/// required.
+#[cfg(feature = "json_schema")]
+impl<T, F, Args> schemars::JsonSchema for MappingFnImpl<T, F, Args> {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed("MappingFn")
+    }
+
+    fn schema_id() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed("peace_params::MappingFn")
+    }
+
+    fn json_schema(_generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "Mapping function registered by name in the flow's `MappingFnReg`.",
+            "type": "object",
+            "properties": {
+                "field_name": {
+                    "description": "This field's name within its parent struct.",
+                    "type": ["string", "null"]
+                },
+                "fn_name": {
+                    "description": "Name the function is registered under.",
+                    "type": "string"
+                }
+            },
+            "required": ["fn_name"]
+        })
+    }
+}
+
+impl<T> MappingFnImpl<T, fn(&()) -> Option<T>, ((),)> {
+    /// Returns a mapping function that refers to a function registered in a
+    /// `MappingFnReg` under the given name.
+    ///
+    /// This holds no logic until it is bound to the registered function, which
+    /// happens when the command context is built.
+    pub fn from_fn_name(field_name: Option<String>, fn_name: MappingFnName) -> Self {
+        Self {
+            field_name,
+            fn_name: Some(fn_name),
+            fn_map: None,
+            marker: PhantomData,
+        }
+    }
+}
+
 impl<T, F, Args> MappingFnImpl<T, F, Args> {
+    /// Sets the name this function is registered under in a `MappingFnReg`.
+    pub fn with_fn_name(mut self, fn_name: MappingFnName) -> Self {
+        self.fn_name = Some(fn_name);
+        self
+    }
+
     fn fn_map_serialize<S>(fn_map: &Option<F>, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -111,6 +172,7 @@ macro_rules! impl_mapping_fn_impl {
                 Self {
                     fn_map: Some(fn_map),
                     field_name,
+                    fn_name: None,
                     marker: PhantomData,
                 }
             }
@@ -305,6 +367,14 @@ macro_rules! impl_mapping_fn_impl {
             fn is_valued(&self) -> bool {
                 self.fn_map.is_some()
             }
+
+            fn fn_name(&self) -> Option<&MappingFnName> {
+                self.fn_name.as_ref()
+            }
+
+            fn field_name(&self) -> Option<&str> {
+                self.field_name.as_deref()
+            }
         }
     };
 }
@@ -0,0 +1,61 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    ops::Deref,
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Name of a mapping function registered in a [`MappingFnReg`],
+/// `Cow<'static, str>` newtype.
+///
+/// The name is serialized in place of the mapping function's logic, so that
+/// the function can be rebound when params specs are deserialized.
+///
+/// [`MappingFnReg`]: crate::MappingFnReg
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct MappingFnName(Cow<'static, str>);
+
+impl MappingFnName {
+    /// Returns a new `MappingFnName`.
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self(name.into())
+    }
+
+    /// Returns the inner `Cow<'static, str>`.
+    pub fn into_inner(self) -> Cow<'static, str> {
+        self.0
+    }
+
+    /// Returns the `&str` held by this name.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Deref for MappingFnName {
+    type Target = Cow<'static, str>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Display for MappingFnName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<&'static str> for MappingFnName {
+    fn from(name: &'static str) -> Self {
+        Self(Cow::Borrowed(name))
+    }
+}
+
+impl From<String> for MappingFnName {
+    fn from(name: String) -> Self {
+        Self(Cow::Owned(name))
+    }
+}
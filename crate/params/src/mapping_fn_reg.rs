@@ -0,0 +1,160 @@
+use std::{
+    any::Any,
+    collections::HashMap,
+    fmt::{self, Debug},
+    sync::Arc,
+};
+
+use crate::{MappingFn, MappingFnBindError, MappingFnImpl, MappingFnName};
+
+/// Constructs a mapping function for a given field name.
+type MappingFnCtor<T> = dyn Fn(Option<String>) -> Box<dyn MappingFn<Output = T>> + Send + Sync;
+
+/// Registry of mapping functions by name.
+///
+/// Mapping functions hold closures which cannot be deserialized. When a
+/// mapping function is registered by name, params specs refer to it by that
+/// name, and the closure is rebound when the params specs are deserialized, so
+/// commands do not need to provide the mapping functions again.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let mut mapping_fn_reg = MappingFnReg::new();
+/// mapping_fn_reg.register("server_url", |server_host: &ServerHost| {
+///     Url::parse(&format!("https://{server_host}")).ok()
+/// });
+///
+/// let flow = Flow::new(flow_id, graph).with_mapping_fn_reg(mapping_fn_reg);
+///
+/// let params_spec = AppDownloadParams::field_wise_spec()
+///     .with_src_from_map_named("server_url")
+///     .build();
+/// ```
+#[derive(Clone, Default)]
+pub struct MappingFnReg(HashMap<MappingFnName, MappingFnRegEntry>);
+
+/// Constructor for a registered mapping function, and its output type name.
+#[derive(Clone)]
+struct MappingFnRegEntry {
+    /// Type name of the mapping function's output.
+    output_type_name: String,
+    /// `Box<MappingFnCtor<T>>` for the mapping function's output type `T`.
+    mapping_fn_ctor: Arc<dyn Any + Send + Sync>,
+}
+
+impl MappingFnReg {
+    /// Returns a new `MappingFnReg`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a mapping function under the given name.
+    ///
+    /// If a function was previously registered with the same name, it is
+    /// replaced.
+    pub fn register<T, F, Args>(&mut self, mapping_fn_name: impl Into<MappingFnName>, f: F)
+    where
+        T: 'static,
+        F: Clone + Send + Sync + 'static,
+        MappingFnImpl<T, F, Args>: From<(Option<String>, F)> + MappingFn<Output = T>,
+    {
+        let mapping_fn_name = mapping_fn_name.into();
+        let mapping_fn_ctor: Box<MappingFnCtor<T>> = {
+            let mapping_fn_name = mapping_fn_name.clone();
+            Box::new(move |field_name: Option<String>| {
+                let mapping_fn = MappingFnImpl::<T, F, Args>::from((field_name, f.clone()))
+                    .with_fn_name(mapping_fn_name.clone());
+                Box::new(mapping_fn) as Box<dyn MappingFn<Output = T>>
+            })
+        };
+
+        self.0.insert(
+            mapping_fn_name,
+            MappingFnRegEntry {
+                output_type_name: tynm::type_name::<T>(),
+                mapping_fn_ctor: Arc::new(mapping_fn_ctor),
+            },
+        );
+    }
+
+    /// Returns whether a mapping function is registered under the given name.
+    pub fn contains(&self, mapping_fn_name: &MappingFnName) -> bool {
+        self.0.contains_key(mapping_fn_name)
+    }
+
+    /// Returns the names of the registered mapping functions, in sorted order.
+    pub fn names(&self) -> Vec<MappingFnName> {
+        let mut mapping_fn_names = self.0.keys().cloned().collect::<Vec<_>>();
+        mapping_fn_names.sort();
+        mapping_fn_names
+    }
+
+    /// Returns the mapping function registered under the given name.
+    ///
+    /// Returns `None` if no function is registered with that name, or if the
+    /// registered function does not output `T`.
+    pub fn mapping_fn<T>(
+        &self,
+        mapping_fn_name: &MappingFnName,
+        field_name: Option<String>,
+    ) -> Option<Box<dyn MappingFn<Output = T>>>
+    where
+        T: 'static,
+    {
+        self.0
+            .get(mapping_fn_name)
+            .and_then(|mapping_fn_reg_entry| {
+                mapping_fn_reg_entry
+                    .mapping_fn_ctor
+                    .downcast_ref::<Box<MappingFnCtor<T>>>()
+            })
+            .map(|mapping_fn_ctor| mapping_fn_ctor(field_name))
+    }
+
+    /// Replaces a named mapping function that has no logic with the function
+    /// registered under its name.
+    ///
+    /// If the name is not registered, or the registered function outputs a
+    /// different type, a [`MappingFnBindError`] is pushed onto
+    /// `mapping_fn_bind_errors`. Mapping functions that already have logic, or
+    /// have no name, are left as is.
+    pub fn mapping_fn_bind<T>(
+        &self,
+        mapping_fn: &mut Box<dyn MappingFn<Output = T>>,
+        mapping_fn_bind_errors: &mut Vec<MappingFnBindError>,
+    ) where
+        T: 'static,
+    {
+        if mapping_fn.is_valued() {
+            return;
+        }
+        let Some(mapping_fn_name) = mapping_fn.fn_name() else {
+            return;
+        };
+
+        let field_name = mapping_fn.field_name().map(String::from);
+        if let Some(mapping_fn_registered) = self.mapping_fn::<T>(mapping_fn_name, field_name) {
+            *mapping_fn = mapping_fn_registered;
+            return;
+        }
+
+        let mapping_fn_bind_error = match self.0.get(mapping_fn_name) {
+            Some(mapping_fn_reg_entry) => MappingFnBindError::OutputTypeMismatch {
+                mapping_fn_name: mapping_fn_name.clone(),
+                output_type_expected: tynm::type_name::<T>(),
+                output_type_registered: mapping_fn_reg_entry.output_type_name.clone(),
+            },
+            None => MappingFnBindError::NameUnknown {
+                mapping_fn_name: mapping_fn_name.clone(),
+            },
+        };
+        mapping_fn_bind_errors.push(mapping_fn_bind_error);
+    }
+}
+
+impl Debug for MappingFnReg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MappingFnReg").field(&self.names()).finish()
+    }
+}
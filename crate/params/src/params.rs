@@ -1,8 +1,9 @@
 use std::fmt::Debug;
 
+use peace_resource_rt::{resources::ts::SetUp, Resources};
 use serde::{de::DeserializeOwned, Serialize};
 
-use crate::FieldWiseSpecRt;
+use crate::{FieldWiseSpecRt, ParamsResolveError, ParamsValidationError};
 
 /// Input parameters to an item.
 ///
@@ -30,4 +31,62 @@ pub trait Params: Clone + Debug + Serialize + DeserializeOwned + Send + Sync + '
 
     /// Returns a builder to construct the `FieldWise` spec.
     fn field_wise_spec() -> Self::FieldWiseBuilder;
+
+    /// Checks that the values of these params satisfy their declared rules.
+    ///
+    /// This is called when the `CmdCtx` is built, for params without secrets
+    /// that can be resolved at that point, and each time the params are
+    /// resolved.
+    ///
+    /// `#[derive(Params)]` implements this using the `#[validate(..)]`
+    /// attributes on the type and its fields. By default no rules are
+    /// checked.
+    fn validate(&self) -> Result<(), ParamsValidationError> {
+        Ok(())
+    }
+
+    /// Returns the keys of the [`Secret`] fields in these params.
+    ///
+    /// This is used when the `CmdCtx` is built, to check that each secret is
+    /// referenced by a key without reading its value.
+    ///
+    /// `#[derive(Params)]` implements this for fields whose type is
+    /// `Secret<T>` or `Option<Secret<T>>`. By default there are no keys.
+    ///
+    /// [`Secret`]: crate::Secret
+    fn secret_keys(&self) -> Vec<&str> {
+        Vec::new()
+    }
+
+    /// Reads the values of [`Secret`] fields from the secret provider in
+    /// `resources`.
+    ///
+    /// This is called each time the params are resolved, before they are
+    /// validated.
+    ///
+    /// `#[derive(Params)]` implements this for fields whose type is
+    /// `Secret<T>` or `Option<Secret<T>>`. By default no values are read.
+    ///
+    /// [`Secret`]: crate::Secret
+    fn secrets_resolve(&mut self, _resources: &Resources<SetUp>) -> Result<(), ParamsResolveError> {
+        Ok(())
+    }
+
+    /// Reads the values of [`Secret`] fields that are present in
+    /// `params_partial` from the secret provider in `resources`.
+    ///
+    /// This is called each time the params are partially resolved, so that
+    /// functions that receive `Params::Partial`, such as
+    /// `Item::try_state_current`, can read secret values.
+    ///
+    /// `#[derive(Params)]` implements this for fields whose type is
+    /// `Secret<T>` or `Option<Secret<T>>`. By default no values are read.
+    ///
+    /// [`Secret`]: crate::Secret
+    fn secrets_resolve_partial(
+        _params_partial: &mut Self::Partial,
+        _resources: &Resources<SetUp>,
+    ) -> Result<(), ParamsResolveError> {
+        Ok(())
+    }
 }
@@ -1,4 +1,6 @@
-use crate::{FieldNameAndType, ValueResolutionCtx};
+use serde::Serialize;
+
+use crate::{FieldNameAndType, ParamsValidationError, ValueResolutionCtx};
 
 /// Failed to resolve values for a `Params` object from `resources`.
 //
@@ -118,4 +120,244 @@ pub enum ParamsResolveError {
         /// Corresponds to `U` in `Fn(&U) -> T`.
         from_type_name: String,
     },
+
+    /// A secret param does not reference a secret key.
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_params::params_resolve_error::secret_key_empty),
+            help("Set the key of each `Secret` to the name of the secret in the secret provider.")
+        )
+    )]
+    #[error(
+        "Secret has an empty key in:\n\
+        \n\
+        ```rust\n\
+        {value_resolution_ctx}\n\
+        ```"
+    )]
+    SecretKeyEmpty {
+        /// Hierarchy of fields traversed to resolve the value.
+        value_resolution_ctx: ValueResolutionCtx,
+    },
+
+    /// No secret provider was inserted into `resources`.
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_params::params_resolve_error::secret_provider_not_found),
+            help(
+                "Insert a `Box<dyn SecretProvider>` into `resources` when building the command context."
+            )
+        )
+    )]
+    #[error("No secret provider to read secret `{secret_key}`.")]
+    SecretProviderNotFound {
+        /// Key of the secret.
+        secret_key: String,
+    },
+
+    /// Failed to borrow the secret provider from `resources`.
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_params::params_resolve_error::secret_provider_borrow_conflict),
+            help("By design `Box<dyn SecretProvider>` must not be borrowed mutably.")
+        )
+    )]
+    #[error("Borrow conflict on the secret provider to read secret `{secret_key}`.")]
+    SecretProviderBorrowConflict {
+        /// Key of the secret.
+        secret_key: String,
+    },
+
+    /// Failed to read a secret from the secret provider.
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_params::params_resolve_error::secret_read),
+            help("Check that the secret provider is accessible.")
+        )
+    )]
+    #[error("Failed to read secret `{secret_key}`.")]
+    SecretRead {
+        /// Key of the secret.
+        secret_key: String,
+        /// Underlying error from the secret provider.
+        #[source]
+        error: Box<dyn std::error::Error + Send + Sync + 'static>,
+    },
+
+    /// Secret does not exist in the secret provider.
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_params::params_resolve_error::secret_not_found),
+            help(
+                "Make sure `{secret_key}` is defined in the secret provider:\n\
+                \n\
+                {secret_provider}"
+            )
+        )
+    )]
+    #[error("Secret `{secret_key}` not found.")]
+    SecretNotFound {
+        /// Key of the secret.
+        secret_key: String,
+        /// Debug representation of the secret provider.
+        secret_provider: String,
+    },
+
+    /// Failed to parse a secret value into the param type.
+    ///
+    /// The secret value is intentionally not included in this error.
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_params::params_resolve_error::secret_parse),
+            help("Make sure the value for `{secret_key}` is a valid `{type_name}`.")
+        )
+    )]
+    #[error("Failed to parse secret `{secret_key}` as `{type_name}`: {error}")]
+    SecretParse {
+        /// Key of the secret.
+        secret_key: String,
+        /// Name of the type the secret was parsed into.
+        type_name: String,
+        /// Parse error message.
+        error: String,
+    },
+
+    /// Resolved params did not satisfy their validation rules.
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_params::params_resolve_error::validation),
+            help(
+                "Update the params provided for `{item_id}` so that they satisfy the rule.",
+                item_id = value_resolution_ctx.item_id()
+            )
+        )
+    )]
+    #[error(
+        "Invalid params for `{item_id}`: {params_validation_error}",
+        item_id = value_resolution_ctx.item_id()
+    )]
+    Validation {
+        /// Hierarchy of fields traversed to resolve the value.
+        value_resolution_ctx: ValueResolutionCtx,
+        /// The rule that was not satisfied.
+        params_validation_error: Box<ParamsValidationError>,
+        /// YAML representation of the resolved params.
+        #[cfg(feature = "error_reporting")]
+        #[source_code]
+        params_source: std::sync::Arc<str>,
+        /// Span of the invalid field within `params_source`, or the whole
+        /// params if the error is not for a particular field.
+        #[cfg(feature = "error_reporting")]
+        #[label("{}", params_validation_error.message())]
+        error_span: miette::SourceSpan,
+    },
+}
+
+impl ParamsResolveError {
+    /// Returns a `ParamsResolveError::Validation` for the given params.
+    ///
+    /// When the `"error_reporting"` feature is enabled, the params are rendered
+    /// as YAML, and the invalid field is labelled.
+    pub fn validation<T>(
+        value_resolution_ctx: ValueResolutionCtx,
+        #[cfg_attr(not(feature = "error_reporting"), allow(unused_variables))] params: &T,
+        params_validation_error: ParamsValidationError,
+    ) -> Self
+    where
+        T: Serialize,
+    {
+        #[cfg(feature = "error_reporting")]
+        {
+            // If the params cannot be serialized, the error is still reported, just
+            // without the params as source code.
+            let params_yaml = serde_yaml::to_string(params).unwrap_or_default();
+            let error_span = params_validation_error
+                .field_name()
+                .and_then(|field_name| field_value_span(&params_yaml, field_name))
+                .unwrap_or_else(|| miette::SourceSpan::from((0, params_yaml.len())));
+
+            Self::Validation {
+                value_resolution_ctx,
+                params_validation_error: Box::new(params_validation_error),
+                params_source: std::sync::Arc::from(params_yaml),
+                error_span,
+            }
+        }
+
+        #[cfg(not(feature = "error_reporting"))]
+        Self::Validation {
+            value_resolution_ctx,
+            params_validation_error: Box::new(params_validation_error),
+        }
+    }
+}
+
+/// Returns the span of a top level field's value in the params serialized as
+/// YAML.
+///
+/// Named fields are serialized as a mapping, so this looks for the
+/// `field_name:` key. Tuple fields are serialized as a sequence, and the field
+/// name is the index of the field. Enum variants are serialized with a
+/// `!Variant` tag before their fields, which is skipped.
+#[cfg(feature = "error_reporting")]
+fn field_value_span(params_yaml: &str, field_name: &str) -> Option<miette::SourceSpan> {
+    // Each top level entry starts at column 0. Nested values are either on the
+    // same line as the entry's key, or on following lines that are indented or
+    // start with `- `.
+    let mut entries = Vec::<(usize, &str)>::new();
+    let mut is_sequence = None;
+    let mut offset = 0;
+    for line in params_yaml.split_inclusive('\n') {
+        let line_offset = offset;
+        offset += line.len();
+
+        let is_tag = line.starts_with('!') && entries.is_empty();
+        let is_indented = line.starts_with([' ', '\n', '#']);
+        if is_tag || is_indented {
+            continue;
+        }
+
+        let is_sequence_item = line.starts_with('-');
+        match is_sequence {
+            None => is_sequence = Some(is_sequence_item),
+            // Sequences within a mapping are not indented, so these belong to
+            // the previous entry.
+            Some(false) if is_sequence_item => continue,
+            Some(_) => {}
+        }
+        entries.push((line_offset, line));
+    }
+
+    let entry_index = if is_sequence? {
+        field_name.parse::<usize>().ok()?
+    } else {
+        entries.iter().position(|(_, line)| {
+            line.strip_prefix(field_name)
+                .is_some_and(|rest| rest.starts_with(':'))
+        })?
+    };
+    let (entry_start, entry_line) = entries.get(entry_index)?;
+    let entry_end = entries
+        .get(entry_index + 1)
+        .map(|(next_entry_start, _)| *next_entry_start)
+        .unwrap_or(params_yaml.len());
+
+    // Skip past `field_name:` or `-`, and any whitespace before the value.
+    let prefix_len = if is_sequence? {
+        1
+    } else {
+        field_name.len() + 1
+    };
+    let value_offset = entry_line[prefix_len..].len() - entry_line[prefix_len..].trim_start().len();
+    let value_start = entry_start + prefix_len + value_offset;
+    let value = params_yaml[value_start..entry_end].trim_end();
+
+    Some(miette::SourceSpan::from((value_start, value.len())))
 }
@@ -4,8 +4,9 @@ use peace_resource_rt::{resources::ts::SetUp, BorrowFail, Resources};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 use crate::{
-    AnySpecDataType, AnySpecRt, FieldWiseSpecRt, MappingFn, MappingFnImpl, Params,
-    ParamsResolveError, ValueResolutionCtx, ValueResolutionMode, ValueSpecRt,
+    AnySpecDataType, AnySpecRt, FieldWiseSpecRt, MappingFn, MappingFnBindError, MappingFnImpl,
+    MappingFnName, MappingFnReg, Params, ParamsResolveError, ParamsSpecsOrigins,
+    ParamsValidationError, ValueResolutionCtx, ValueResolutionMode, ValueSpecRt,
 };
 
 /// How to populate a field's value in an item's params.
@@ -69,7 +70,8 @@ where
     /// This is serialized as `MappingFn` with a string value. For
     /// deserialization, there is no actual backing function, so
     /// the user must provide the `MappingFn` in subsequent command
-    /// context builds.
+    /// context builds, unless the function was registered by name in the
+    /// flow's `MappingFnReg`.
     MappingFn(Box<dyn MappingFn<Output = T>>),
     /// Resolves this value through `ValueSpec`s for each of its fields.
     ///
@@ -104,6 +106,16 @@ where
         let mapping_fn = MappingFnImpl::from((field_name, f));
         Self::MappingFn(Box::new(mapping_fn))
     }
+
+    /// Returns a spec that uses the mapping function registered in the flow's
+    /// `MappingFnReg` under the given name.
+    pub fn from_map_named(
+        field_name: Option<String>,
+        mapping_fn_name: impl Into<MappingFnName>,
+    ) -> Self {
+        let mapping_fn = MappingFnImpl::from_fn_name(field_name, mapping_fn_name.into());
+        Self::MappingFn(Box::new(mapping_fn))
+    }
 }
 
 impl<T> Debug for ParamsSpec<T>
@@ -142,7 +154,7 @@ where
         resources: &Resources<peace_resource_rt::resources::ts::SetUp>,
         value_resolution_ctx: &mut ValueResolutionCtx,
     ) -> Result<T, ParamsResolveError> {
-        match self {
+        let mut params = match self {
             ParamsSpec::Value { value } => Ok(value.clone()),
             ParamsSpec::Stored | ParamsSpec::InMemory => {
                 // Try resolve `T`, through the `value_resolution_ctx` first
@@ -187,13 +199,43 @@ where
             ParamsSpec::FieldWise { field_wise_spec } => {
                 field_wise_spec.resolve(resources, value_resolution_ctx)
             }
-        }
+        }?;
+
+        params.secrets_resolve(resources)?;
+        params.validate().map_err(|params_validation_error| {
+            let params_validation_error = params_validation_error_with_origin(
+                resources,
+                value_resolution_ctx,
+                params_validation_error,
+            );
+            ParamsResolveError::validation(
+                value_resolution_ctx.clone(),
+                &params,
+                params_validation_error,
+            )
+        })?;
+
+        Ok(params)
     }
 
     pub fn resolve_partial(
         &self,
         resources: &Resources<SetUp>,
         value_resolution_ctx: &mut ValueResolutionCtx,
+    ) -> Result<T::Partial, ParamsResolveError> {
+        let mut params_partial =
+            self.resolve_partial_without_secrets(resources, value_resolution_ctx)?;
+
+        T::secrets_resolve_partial(&mut params_partial, resources)?;
+
+        Ok(params_partial)
+    }
+
+    /// Resolves the params partial without reading secret values.
+    fn resolve_partial_without_secrets(
+        &self,
+        resources: &Resources<SetUp>,
+        value_resolution_ctx: &mut ValueResolutionCtx,
     ) -> Result<T::Partial, ParamsResolveError> {
         match self {
             ParamsSpec::Value { value } => Ok(T::Partial::from((*value).clone())),
@@ -244,6 +286,61 @@ where
     }
 }
 
+impl<T> ParamsSpec<T>
+where
+    T: Params<Spec = ParamsSpec<T>> + Clone + Debug + Send + Sync + 'static,
+    T::Partial: From<T>,
+    T: TryFrom<T::Partial>,
+{
+    /// Validates the params if they can already be resolved from `resources`.
+    ///
+    /// Params whose values depend on data that is not yet available, such as
+    /// the state of a predecessor, are not validated until they are resolved.
+    ///
+    /// Secret values are not read, so that commands which do not use a secret
+    /// do not need the secret provider. Each secret must reference a key, and
+    /// the rules of params with secrets are checked when the params are
+    /// resolved, as they may depend on the secret values.
+    pub fn validate(
+        &self,
+        resources: &Resources<SetUp>,
+        value_resolution_ctx: &mut ValueResolutionCtx,
+    ) -> Result<(), ParamsResolveError> {
+        let params_partial =
+            self.resolve_partial_without_secrets(resources, value_resolution_ctx)?;
+        match T::try_from(params_partial) {
+            Ok(params) => {
+                let secret_keys = params.secret_keys();
+                if secret_keys
+                    .iter()
+                    .any(|secret_key| secret_key.trim().is_empty())
+                {
+                    return Err(ParamsResolveError::SecretKeyEmpty {
+                        value_resolution_ctx: value_resolution_ctx.clone(),
+                    });
+                }
+                if !secret_keys.is_empty() {
+                    return Ok(());
+                }
+
+                params.validate().map_err(|params_validation_error| {
+                    let params_validation_error = params_validation_error_with_origin(
+                        resources,
+                        value_resolution_ctx,
+                        params_validation_error,
+                    );
+                    ParamsResolveError::validation(
+                        value_resolution_ctx.clone(),
+                        &params,
+                        params_validation_error,
+                    )
+                })
+            }
+            Err(_params_partial) => Ok(()),
+        }
+    }
+}
+
 impl<T> AnySpecRt for ParamsSpec<T>
 where
     T: Params<Spec = ParamsSpec<T>>
@@ -300,6 +397,22 @@ where
             }
         }
     }
+
+    fn mapping_fns_bind(
+        &mut self,
+        mapping_fn_reg: &MappingFnReg,
+        mapping_fn_bind_errors: &mut Vec<MappingFnBindError>,
+    ) {
+        match self {
+            Self::Stored | Self::Value { .. } | Self::InMemory => {}
+            Self::MappingFn(mapping_fn) => {
+                mapping_fn_reg.mapping_fn_bind(mapping_fn, mapping_fn_bind_errors)
+            }
+            Self::FieldWise { field_wise_spec } => {
+                field_wise_spec.mapping_fns_bind(mapping_fn_reg, mapping_fn_bind_errors)
+            }
+        }
+    }
 }
 
 impl<T> ValueSpecRt for ParamsSpec<T>
@@ -335,3 +448,44 @@ where
             .map(Result::ok)
     }
 }
+
+#[cfg(feature = "json_schema")]
+impl<T> schemars::JsonSchema for ParamsSpec<T>
+where
+    T: Params + schemars::JsonSchema,
+    T::FieldWiseSpec: schemars::JsonSchema,
+{
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        format!("ParamsSpec_for_{}", T::schema_name()).into()
+    }
+
+    fn schema_id() -> std::borrow::Cow<'static, str> {
+        format!("peace_params::ParamsSpec<{}>", T::schema_id()).into()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        crate::ParamsSpecDe::<T>::json_schema(generator)
+    }
+}
+
+/// Sets the file and line that the invalid value was read from, if the item's
+/// params spec was read from a params specs override file.
+fn params_validation_error_with_origin(
+    resources: &Resources<SetUp>,
+    value_resolution_ctx: &ValueResolutionCtx,
+    params_validation_error: ParamsValidationError,
+) -> ParamsValidationError {
+    let origin =
+        resources
+            .try_borrow::<ParamsSpecsOrigins>()
+            .ok()
+            .and_then(|params_specs_origins| {
+                params_specs_origins
+                    .origin(
+                        value_resolution_ctx.item_id(),
+                        params_validation_error.field_name(),
+                    )
+                    .cloned()
+            });
+    params_validation_error.with_origin(origin)
+}
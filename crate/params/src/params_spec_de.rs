@@ -9,6 +9,14 @@ type FnPlaceholder<T> = fn(&()) -> Option<T>;
 /// Exists to deserialize `MappingFn` with a non-type-erased `MappingFnImpl`
 #[derive(Clone, Deserialize)]
 #[serde(bound = "T: Params")]
+#[cfg_attr(
+    feature = "json_schema",
+    derive(schemars::JsonSchema),
+    schemars(
+        bound = "T: Params + schemars::JsonSchema, T::FieldWiseSpec: schemars::JsonSchema",
+        description = "How to populate an item's params."
+    )
+)]
 pub enum ParamsSpecDe<T>
 where
     T: Params,
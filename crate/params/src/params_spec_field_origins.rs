@@ -0,0 +1,71 @@
+use std::collections::BTreeMap;
+
+use crate::ParamsSpecOrigin;
+
+/// Files and lines that each field of an item's params spec was read from.
+///
+/// When a params spec is merged field-wise from multiple files, each field
+/// keeps the origin of the file that last set it. When a file provides a whole
+/// params spec, such as a `!Value`, that file is the origin of every field.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParamsSpecFieldOrigins {
+    /// Origin of the whole params spec, if it was not provided field-wise.
+    params_spec_origin: Option<ParamsSpecOrigin>,
+    /// Origin of each field set by a field-wise params spec.
+    field_origins: BTreeMap<String, ParamsSpecOrigin>,
+}
+
+impl ParamsSpecFieldOrigins {
+    /// Returns `ParamsSpecFieldOrigins` for a whole params spec read from the
+    /// given origin.
+    pub fn from_params_spec(params_spec_origin: ParamsSpecOrigin) -> Self {
+        Self {
+            params_spec_origin: Some(params_spec_origin),
+            field_origins: BTreeMap::new(),
+        }
+    }
+
+    /// Returns `ParamsSpecFieldOrigins` for a field-wise params spec, with the
+    /// origin of each field that it sets.
+    pub fn from_field_origins(field_origins: BTreeMap<String, ParamsSpecOrigin>) -> Self {
+        Self {
+            params_spec_origin: None,
+            field_origins,
+        }
+    }
+
+    /// Returns the origin of the whole params spec, if it was not provided
+    /// field-wise.
+    pub fn params_spec_origin(&self) -> Option<&ParamsSpecOrigin> {
+        self.params_spec_origin.as_ref()
+    }
+
+    /// Returns the origin of each field set by a field-wise params spec.
+    pub fn field_origins(&self) -> &BTreeMap<String, ParamsSpecOrigin> {
+        &self.field_origins
+    }
+
+    /// Returns the origin of the given field's value.
+    ///
+    /// If the field was not set field-wise, this returns the origin of the
+    /// whole params spec, if any.
+    pub fn field_origin(&self, field_name: &str) -> Option<&ParamsSpecOrigin> {
+        self.field_origins
+            .get(field_name)
+            .or(self.params_spec_origin.as_ref())
+    }
+
+    /// Merges origins from a later file over these origins.
+    ///
+    /// This follows how params specs are merged: a whole params spec replaces
+    /// all earlier origins, and a field-wise params spec replaces the origins
+    /// of the fields it sets.
+    pub fn merge(&mut self, other: ParamsSpecFieldOrigins) {
+        if other.params_spec_origin.is_some() {
+            *self = other;
+        } else {
+            self.params_spec_origin = None;
+            self.field_origins.extend(other.field_origins);
+        }
+    }
+}
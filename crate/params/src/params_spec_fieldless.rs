@@ -4,8 +4,8 @@ use peace_resource_rt::{resources::ts::SetUp, BorrowFail, Resources};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    AnySpecDataType, AnySpecRt, MappingFn, MappingFnImpl, ParamsFieldless, ParamsResolveError,
-    ValueResolutionCtx, ValueSpecRt,
+    AnySpecDataType, AnySpecRt, MappingFn, MappingFnBindError, MappingFnImpl, MappingFnName,
+    MappingFnReg, ParamsFieldless, ParamsResolveError, ValueResolutionCtx, ValueSpecRt,
 };
 
 /// How to populate a field's value in an item's params.
@@ -70,7 +70,8 @@ where
     /// This is serialized as `MappingFn` with a string value. For
     /// deserialization, there is no actual backing function, so
     /// the user must provide the `MappingFn` in subsequent command
-    /// context builds.
+    /// context builds, unless the function was registered by name in the
+    /// flow's `MappingFnReg`.
     MappingFn(Box<dyn MappingFn<Output = T>>),
 }
 
@@ -85,6 +86,16 @@ where
         let mapping_fn = MappingFnImpl::from((field_name, f));
         Self::MappingFn(Box::new(mapping_fn))
     }
+
+    /// Returns a spec that uses the mapping function registered in the flow's
+    /// `MappingFnReg` under the given name.
+    pub fn from_map_named(
+        field_name: Option<String>,
+        mapping_fn_name: impl Into<MappingFnName>,
+    ) -> Self {
+        let mapping_fn = MappingFnImpl::from_fn_name(field_name, mapping_fn_name.into());
+        Self::MappingFn(Box::new(mapping_fn))
+    }
 }
 
 impl<T> Debug for ParamsSpecFieldless<T>
@@ -211,6 +222,16 @@ where
             Self::Value { .. } | Self::InMemory | Self::MappingFn(_) => {}
         }
     }
+
+    fn mapping_fns_bind(
+        &mut self,
+        mapping_fn_reg: &MappingFnReg,
+        mapping_fn_bind_errors: &mut Vec<MappingFnBindError>,
+    ) {
+        if let Self::MappingFn(mapping_fn) = self {
+            mapping_fn_reg.mapping_fn_bind(mapping_fn, mapping_fn_bind_errors);
+        }
+    }
 }
 
 impl<T> ValueSpecRt for ParamsSpecFieldless<T>
@@ -245,3 +266,21 @@ where
             .map(Result::ok)
     }
 }
+
+#[cfg(feature = "json_schema")]
+impl<T> schemars::JsonSchema for ParamsSpecFieldless<T>
+where
+    T: ParamsFieldless + Clone + Debug + schemars::JsonSchema + Send + Sync + 'static,
+{
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        format!("ParamsSpecFieldless_for_{}", T::schema_name()).into()
+    }
+
+    fn schema_id() -> std::borrow::Cow<'static, str> {
+        format!("peace_params::ParamsSpecFieldless<{}>", T::schema_id()).into()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        crate::ParamsSpecFieldlessDe::<T>::json_schema(generator)
+    }
+}
@@ -8,6 +8,11 @@ type FnPlaceholder<T> = fn(&()) -> Option<T>;
 
 /// Exists to deserialize `MappingFn` with a non-type-erased `MappingFnImpl`
 #[derive(Clone, Deserialize)]
+#[cfg_attr(
+    feature = "json_schema",
+    derive(schemars::JsonSchema),
+    schemars(description = "How to populate an item's params.")
+)]
 pub enum ParamsSpecFieldlessDe<T>
 where
     T: ParamsFieldless,
@@ -0,0 +1,32 @@
+use std::{fmt, path::PathBuf};
+
+/// File, line, and column that an item's params spec, or one of its fields,
+/// was read from.
+///
+/// This is recorded for params specs read from params specs override files, so
+/// that errors and presentations can refer the user to where a value came
+/// from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParamsSpecOrigin {
+    /// Path to the file the params spec was read from.
+    pub path: PathBuf,
+    /// Line number of the item ID or field name within the file, starting from
+    /// 1.
+    pub line: usize,
+    /// Column number of the item ID or field name within the line, starting
+    /// from 1.
+    pub column: usize,
+}
+
+impl ParamsSpecOrigin {
+    /// Returns a new `ParamsSpecOrigin`.
+    pub fn new(path: PathBuf, line: usize, column: usize) -> Self {
+        Self { path, line, column }
+    }
+}
+
+impl fmt::Display for ParamsSpecOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.path.display(), self.line, self.column)
+    }
+}
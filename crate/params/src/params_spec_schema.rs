@@ -0,0 +1,28 @@
+/// Generates the JSON schema for a [`ParamsSpec`] of this params type.
+///
+/// This is implemented for every `Params` type that implements
+/// `schemars::JsonSchema`, and whose field wise spec implements
+/// `schemars::JsonSchema` -- which is derived when the params type has the
+/// `#[json_schema]` attribute.
+///
+/// Items opt in to schema generation by registering their params type in a
+/// [`ParamsSpecSchemaReg`].
+///
+/// [`ParamsSpec`]: crate::ParamsSpec
+/// [`ParamsSpecSchemaReg`]: crate::ParamsSpecSchemaReg
+pub trait ParamsSpecSchema {
+    /// Returns the JSON schema for a `ParamsSpec<Self>`.
+    ///
+    /// The schema's definitions are added to the `generator`.
+    fn params_spec_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema;
+}
+
+impl<T> ParamsSpecSchema for T
+where
+    T: crate::Params + schemars::JsonSchema,
+    T::FieldWiseSpec: schemars::JsonSchema,
+{
+    fn params_spec_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        generator.subschema_for::<crate::ParamsSpec<T>>()
+    }
+}
@@ -0,0 +1,69 @@
+use peace_core::ItemId;
+use schemars::{Schema, SchemaGenerator};
+
+use crate::ParamsSpecSchema;
+
+/// Generates the JSON schema for an item's params spec.
+type ParamsSpecSchemaFn = fn(&mut SchemaGenerator) -> Schema;
+
+/// Registry of the params types of items whose params specs have a JSON
+/// schema.
+///
+/// Only items that are registered are included in the schema for a flow's
+/// params specs, so item params types do not need to implement `JsonSchema`
+/// unless they are registered.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let mut params_spec_schema_reg = ParamsSpecSchemaReg::new();
+/// params_spec_schema_reg.register::<FileDownloadParams<WebApp>>(item_id!("web_app_download"));
+///
+/// let flow = Flow::new(flow_id, graph).with_params_spec_schema_reg(params_spec_schema_reg);
+/// let schema = ParamsSpecsSchemaBuilder::new().with_flow(&flow).build();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ParamsSpecSchemaReg(Vec<(ItemId, ParamsSpecSchemaFn)>);
+
+impl ParamsSpecSchemaReg {
+    /// Returns a new `ParamsSpecSchemaReg`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the params type of the item with the given ID.
+    ///
+    /// If a params type was previously registered for the same item, it is
+    /// replaced.
+    pub fn register<P>(&mut self, item_id: ItemId)
+    where
+        P: ParamsSpecSchema,
+    {
+        let params_spec_schema_fn: ParamsSpecSchemaFn = P::params_spec_schema;
+        match self
+            .0
+            .iter_mut()
+            .find(|(item_id_registered, _)| item_id_registered == &item_id)
+        {
+            Some((_, params_spec_schema_fn_registered)) => {
+                *params_spec_schema_fn_registered = params_spec_schema_fn;
+            }
+            None => self.0.push((item_id, params_spec_schema_fn)),
+        }
+    }
+
+    /// Returns the function that generates the JSON schema for the params
+    /// spec of the item with the given ID, if its params type is registered.
+    ///
+    /// The function adds the schema's definitions to the generator passed to
+    /// it.
+    pub fn params_spec_schema_fn(
+        &self,
+        item_id: &ItemId,
+    ) -> Option<fn(&mut SchemaGenerator) -> Schema> {
+        self.0
+            .iter()
+            .find(|(item_id_registered, _)| item_id_registered == item_id)
+            .map(|(_, params_spec_schema_fn)| *params_spec_schema_fn)
+    }
+}
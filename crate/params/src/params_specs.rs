@@ -4,7 +4,7 @@ use peace_core::ItemId;
 use peace_resource_rt::type_reg::untagged::TypeMap;
 use serde::Serialize;
 
-use crate::AnySpecRtBoxed;
+use crate::{AnySpecRtBoxed, MappingFnBindError, MappingFnReg};
 
 /// Map of item ID to its params' specs. `TypeMap<ItemId,
 /// AnySpecRtBoxed>` newtype.
@@ -47,6 +47,19 @@ impl ParamsSpecs {
     pub fn into_inner(self) -> TypeMap<ItemId, AnySpecRtBoxed> {
         self.0
     }
+
+    /// Binds named mapping functions in each params spec to the functions
+    /// registered in the `mapping_fn_reg`.
+    ///
+    /// Returns the mapping functions that could not be bound, which is empty
+    /// if every named mapping function was bound.
+    pub fn mapping_fns_bind(&mut self, mapping_fn_reg: &MappingFnReg) -> Vec<MappingFnBindError> {
+        let mut mapping_fn_bind_errors = Vec::new();
+        self.0.values_mut().for_each(|params_spec| {
+            params_spec.mapping_fns_bind(mapping_fn_reg, &mut mapping_fn_bind_errors)
+        });
+        mapping_fn_bind_errors
+    }
 }
 
 impl Deref for ParamsSpecs {
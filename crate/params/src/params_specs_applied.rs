@@ -0,0 +1,47 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::ParamsSpecs;
+
+/// Params used when each item was last applied, stored as `ParamsSpec::Value`s.
+///
+/// This is read from the `params_applied_file` when building a
+/// `SingleProfileSingleFlow` command context, and is updated after each
+/// successful apply.
+///
+/// Cleaning an item uses these params instead of the current params specs, so
+/// that changing a param does not cause clean to target a different resource
+/// than the one that was created.
+#[derive(Clone, Debug, Default)]
+pub struct ParamsSpecsApplied(ParamsSpecs);
+
+impl ParamsSpecsApplied {
+    /// Returns a new `ParamsSpecsApplied` map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the inner `ParamsSpecs`.
+    pub fn into_inner(self) -> ParamsSpecs {
+        self.0
+    }
+}
+
+impl Deref for ParamsSpecsApplied {
+    type Target = ParamsSpecs;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for ParamsSpecsApplied {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<ParamsSpecs> for ParamsSpecsApplied {
+    fn from(params_specs: ParamsSpecs) -> Self {
+        Self(params_specs)
+    }
+}
@@ -0,0 +1,75 @@
+use std::{
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+};
+
+use peace_core::ItemId;
+
+use crate::{ParamsSpecFieldOrigins, ParamsSpecOrigin};
+
+/// Map of item ID to the files and lines its params spec fields were
+/// overridden from.
+///
+/// This is inserted into `Resources` when building a command context with
+/// params specs override files. Items whose params specs were only provided in
+/// code do not have an entry.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParamsSpecsOrigins(HashMap<ItemId, ParamsSpecFieldOrigins>);
+
+impl ParamsSpecsOrigins {
+    /// Returns a new `ParamsSpecsOrigins` map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the inner map.
+    pub fn into_inner(self) -> HashMap<ItemId, ParamsSpecFieldOrigins> {
+        self.0
+    }
+
+    /// Returns the origin of an item's params spec value.
+    ///
+    /// If `field_name` is provided, this returns the origin of that field's
+    /// value, falling back to the origin of the whole params spec.
+    pub fn origin(&self, item_id: &ItemId, field_name: Option<&str>) -> Option<&ParamsSpecOrigin> {
+        self.0
+            .get(item_id)
+            .and_then(|params_spec_field_origins| match field_name {
+                Some(field_name) => params_spec_field_origins.field_origin(field_name),
+                None => params_spec_field_origins.params_spec_origin(),
+            })
+    }
+
+    /// Merges origins from a later file over these origins.
+    pub fn merge(&mut self, other: ParamsSpecsOrigins) {
+        other
+            .0
+            .into_iter()
+            .for_each(|(item_id, params_spec_field_origins)| {
+                self.0
+                    .entry(item_id)
+                    .or_default()
+                    .merge(params_spec_field_origins)
+            });
+    }
+}
+
+impl Deref for ParamsSpecsOrigins {
+    type Target = HashMap<ItemId, ParamsSpecFieldOrigins>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for ParamsSpecsOrigins {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<HashMap<ItemId, ParamsSpecFieldOrigins>> for ParamsSpecsOrigins {
+    fn from(params_specs_origins: HashMap<ItemId, ParamsSpecFieldOrigins>) -> Self {
+        Self(params_specs_origins)
+    }
+}
@@ -0,0 +1,50 @@
+use std::{
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+};
+
+use peace_core::{ItemId, Profile};
+
+/// Map of item ID to the profile its stored params spec was read from.
+///
+/// When a profile has a parent profile, stored params specs are layered with
+/// the profile's own params specs over its parent's. This records the nearest
+/// profile that stored a params spec for each item, so inherited values can be
+/// traced back to the profile that set them.
+///
+/// This is inserted into `Resources` when building a `SingleProfileSingleFlow`
+/// command context. Items with no stored params spec do not have an entry.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParamsSpecsProfileOrigins(HashMap<ItemId, Profile>);
+
+impl ParamsSpecsProfileOrigins {
+    /// Returns a new `ParamsSpecsProfileOrigins` map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the inner map.
+    pub fn into_inner(self) -> HashMap<ItemId, Profile> {
+        self.0
+    }
+}
+
+impl Deref for ParamsSpecsProfileOrigins {
+    type Target = HashMap<ItemId, Profile>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for ParamsSpecsProfileOrigins {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<HashMap<ItemId, Profile>> for ParamsSpecsProfileOrigins {
+    fn from(params_specs_profile_origins: HashMap<ItemId, Profile>) -> Self {
+        Self(params_specs_profile_origins)
+    }
+}
@@ -0,0 +1,35 @@
+use std::ops::Deref;
+
+use crate::ParamsSpecs;
+
+/// `ParamsSpecs` read from the `params_specs_file` before they were merged with
+/// the params specs provided to the command context builder.
+///
+/// This is inserted into `Resources` when building a `SingleProfileSingleFlow`
+/// command context, if the `params_specs_file` existed.
+///
+/// Mapping functions are not serialized, so any `MappingFn` specs in these
+/// `ParamsSpecs` do not have a backing function.
+#[derive(Clone, Debug, Default)]
+pub struct ParamsSpecsStored(ParamsSpecs);
+
+impl ParamsSpecsStored {
+    /// Returns the inner `ParamsSpecs`.
+    pub fn into_inner(self) -> ParamsSpecs {
+        self.0
+    }
+}
+
+impl Deref for ParamsSpecsStored {
+    type Target = ParamsSpecs;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<ParamsSpecs> for ParamsSpecsStored {
+    fn from(params_specs: ParamsSpecs) -> Self {
+        Self(params_specs)
+    }
+}
@@ -0,0 +1,81 @@
+use std::fmt;
+
+use crate::ParamsSpecOrigin;
+
+/// A rule declared on an `Item::Params` was not satisfied.
+///
+/// This is returned by [`Params::validate`], and surfaces as
+/// [`ParamsResolveError::Validation`] when params are resolved.
+///
+/// [`Params::validate`]: crate::Params::validate
+/// [`ParamsResolveError::Validation`]: crate::ParamsResolveError::Validation
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParamsValidationError {
+    /// Name of the field that failed validation, if the rule applies to a
+    /// single field.
+    field_name: Option<String>,
+    /// Description of why the value is invalid.
+    message: String,
+    /// File and line that the invalid value was read from, if it came from a
+    /// params specs override file.
+    origin: Option<ParamsSpecOrigin>,
+}
+
+impl ParamsValidationError {
+    /// Returns a new `ParamsValidationError` for a rule that applies to the
+    /// whole params value.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            field_name: None,
+            message: message.into(),
+            origin: None,
+        }
+    }
+
+    /// Returns a new `ParamsValidationError` for a rule on a particular field.
+    pub fn field(field_name: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field_name: Some(field_name.into()),
+            message: message.into(),
+            origin: None,
+        }
+    }
+
+    /// Returns the name of the field that failed validation, if any.
+    pub fn field_name(&self) -> Option<&str> {
+        self.field_name.as_deref()
+    }
+
+    /// Returns the description of why the value is invalid.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the file and line that the invalid value was read from, if
+    /// any.
+    pub fn origin(&self) -> Option<&ParamsSpecOrigin> {
+        self.origin.as_ref()
+    }
+
+    /// Sets the file and line that the invalid value was read from.
+    pub fn with_origin(mut self, origin: Option<ParamsSpecOrigin>) -> Self {
+        self.origin = origin;
+        self
+    }
+}
+
+impl fmt::Display for ParamsValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.field_name.as_deref() {
+            Some(field_name) => write!(f, "`{field_name}` {}", self.message)?,
+            None => f.write_str(&self.message)?,
+        }
+        if let Some(origin) = self.origin.as_ref() {
+            write!(f, " (from `{origin}`)")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParamsValidationError {}
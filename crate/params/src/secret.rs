@@ -0,0 +1,186 @@
+use std::{
+    fmt::{self, Debug, Display},
+    str::FromStr,
+};
+
+use peace_fmt::{async_trait, Presentable, Presenter};
+use peace_params_derive::value_impl;
+use peace_resource_rt::{resources::ts::SetUp, BorrowFail, Resources};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{ParamsResolveError, SecretProvider};
+
+/// Placeholder rendered in place of a secret value.
+const REDACTED: &str = "******";
+
+/// A sensitive param value, stored only as a reference to the secret.
+///
+/// Only the [`key`] is serialized, so a `Secret` written to
+/// `params_specs.yaml` or `states_*.yaml` never contains the secret value. The
+/// `Debug` and [`Presentable`] implementations redact the value.
+///
+/// The value is filled in when the item's params are resolved, from the
+/// `Box<dyn SecretProvider>` in `resources`, unless a value was provided
+/// through [`with_value`]. Items read it through [`value`].
+///
+/// Two `Secret`s are equal when their keys are equal, so changing the secret
+/// value is not reported as a params change.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// #[derive(Clone, Debug, Params, Serialize, Deserialize)]
+/// pub struct DbParams {
+///     host: String,
+///     password: Secret<String>,
+/// }
+///
+/// // When building the command context:
+/// let secret_provider: Box<dyn SecretProvider> = Box::new(SecretProviderEnv::new());
+/// resources.insert(secret_provider);
+///
+/// // In the item's apply function:
+/// let password = params.password.value();
+/// ```
+///
+/// [`key`]: Self::key
+/// [`value`]: Self::value
+/// [`with_value`]: Self::with_value
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Secret<T> {
+    /// Key used to look up the secret value from a `SecretProvider`.
+    key: String,
+    /// Secret value held in memory, never serialized.
+    #[serde(skip, default = "Option::default")]
+    value: Option<T>,
+}
+
+value_impl!(
+    #[crate_internal]
+    #[value_spec(fieldless)]
+    struct Secret<T>
+    where
+        T: Clone + Debug + Serialize + DeserializeOwned;
+);
+
+impl<T> Secret<T> {
+    /// Returns a new `Secret` that references the given key.
+    pub fn new(key: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            value: None,
+        }
+    }
+
+    /// Sets the in-memory value of this secret.
+    ///
+    /// This value is used for the current process, and is not written when the
+    /// secret is serialized.
+    pub fn with_value(mut self, value: T) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Returns the key used to look up the secret value.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns whether this secret holds an in-memory value.
+    pub fn has_value(&self) -> bool {
+        self.value.is_some()
+    }
+
+    /// Returns the secret value, if it has been resolved or provided.
+    ///
+    /// This is `Some` for params passed to an item's functions after the
+    /// params are resolved.
+    pub fn value(&self) -> Option<&T> {
+        self.value.as_ref()
+    }
+}
+
+impl<T> Secret<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    /// Reads the secret value from the `Box<dyn SecretProvider>` in
+    /// `resources`, if this secret does not already hold a value.
+    ///
+    /// This is called by `#[derive(Params)]` when resolving params, and is not
+    /// usually called directly.
+    pub fn value_resolve(
+        &mut self,
+        resources: &Resources<SetUp>,
+    ) -> Result<(), ParamsResolveError> {
+        if self.value.is_some() {
+            return Ok(());
+        }
+
+        let secret_provider = match resources.try_borrow::<Box<dyn SecretProvider>>() {
+            Ok(secret_provider) => secret_provider,
+            Err(BorrowFail::ValueNotFound) => {
+                return Err(ParamsResolveError::SecretProviderNotFound {
+                    secret_key: self.key.clone(),
+                });
+            }
+            Err(BorrowFail::BorrowConflictImm | BorrowFail::BorrowConflictMut) => {
+                return Err(ParamsResolveError::SecretProviderBorrowConflict {
+                    secret_key: self.key.clone(),
+                });
+            }
+        };
+
+        let value_str = secret_provider
+            .secret_read(&self.key)
+            .map_err(|error| ParamsResolveError::SecretRead {
+                secret_key: self.key.clone(),
+                error,
+            })?
+            .ok_or_else(|| ParamsResolveError::SecretNotFound {
+                secret_key: self.key.clone(),
+                secret_provider: format!("{:?}", &*secret_provider),
+            })?;
+
+        let value = T::from_str(&value_str).map_err(|error| ParamsResolveError::SecretParse {
+            secret_key: self.key.clone(),
+            type_name: tynm::type_name::<T>(),
+            error: error.to_string(),
+        })?;
+        self.value = Some(value);
+
+        Ok(())
+    }
+}
+
+impl<T> PartialEq for Secret<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl<T> Eq for Secret<T> {}
+
+impl<T> Debug for Secret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_struct = f.debug_struct("Secret");
+        debug_struct.field("key", &self.key);
+        if self.value.is_some() {
+            debug_struct.field("value", &REDACTED);
+        }
+        debug_struct.finish()
+    }
+}
+
+#[async_trait(?Send)]
+impl<T> Presentable for Secret<T> {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        presenter.code_inline(&self.key).await?;
+        presenter.text(": ").await?;
+        presenter.text(REDACTED).await
+    }
+}
@@ -0,0 +1,30 @@
+use std::fmt::Debug;
+
+/// Reads secret values referenced by [`Secret`] params.
+///
+/// Implementations may read from a local file, environment variables, or a
+/// secrets manager. To make a provider available, insert it into `resources`
+/// as a `Box<dyn SecretProvider>` when building the command context. Secret
+/// values are then read from it when each item's params are resolved.
+///
+/// [`Secret`]: crate::Secret
+pub trait SecretProvider: Debug + Send + Sync + 'static {
+    /// Returns the secret value for the given key, or `None` if it does not
+    /// exist.
+    fn secret_read(
+        &self,
+        key: &str,
+    ) -> Result<Option<String>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+}
+
+impl<T> SecretProvider for Box<T>
+where
+    T: SecretProvider + ?Sized,
+{
+    fn secret_read(
+        &self,
+        key: &str,
+    ) -> Result<Option<String>, Box<dyn std::error::Error + Send + Sync + 'static>> {
+        (**self).secret_read(key)
+    }
+}
@@ -0,0 +1,57 @@
+use crate::SecretProvider;
+
+/// Reads secrets from environment variables.
+///
+/// The environment variable name is the prefix followed by the secret key in
+/// upper case, with characters that are not alphanumeric replaced with `_`.
+///
+/// For example, with the prefix `"APP_"`, the secret key `"db.password"` is
+/// read from `APP_DB_PASSWORD`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SecretProviderEnv {
+    /// Prefix of environment variable names.
+    prefix: String,
+}
+
+impl SecretProviderEnv {
+    /// Returns a new `SecretProviderEnv` with no variable name prefix.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new `SecretProviderEnv` with the given variable name prefix.
+    pub fn with_prefix(prefix: impl Into<String>) -> Self {
+        Self {
+            prefix: prefix.into(),
+        }
+    }
+
+    /// Returns the environment variable name for the given secret key.
+    pub fn env_var_name(&self, key: &str) -> String {
+        let key_normalized = key.chars().map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        });
+
+        let mut env_var_name = String::with_capacity(self.prefix.len() + key.len());
+        env_var_name.push_str(&self.prefix);
+        env_var_name.extend(key_normalized);
+        env_var_name
+    }
+}
+
+impl SecretProvider for SecretProviderEnv {
+    fn secret_read(
+        &self,
+        key: &str,
+    ) -> Result<Option<String>, Box<dyn std::error::Error + Send + Sync + 'static>> {
+        match std::env::var(self.env_var_name(key)) {
+            Ok(value) => Ok(Some(value)),
+            Err(std::env::VarError::NotPresent) => Ok(None),
+            Err(error @ std::env::VarError::NotUnicode(_)) => Err(Box::new(error)),
+        }
+    }
+}
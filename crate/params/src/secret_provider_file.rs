@@ -0,0 +1,53 @@
+use std::path::{Path, PathBuf};
+
+use crate::SecretProvider;
+
+/// Reads secrets from a local `key=value` file.
+///
+/// This is intended as a stand-in for a secrets manager during development.
+///
+/// Each non-empty line that does not begin with `#` is split on the first
+/// `=`, and surrounding whitespace is trimmed from both the key and the value.
+/// The file is read on every lookup, so changes to the file are picked up
+/// without restarting.
+///
+/// ```text
+/// # secrets.env
+/// db_password = s3cr3t
+/// api_token=abcd
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SecretProviderFile {
+    /// Path to the secrets file.
+    path: PathBuf,
+}
+
+impl SecretProviderFile {
+    /// Returns a new `SecretProviderFile` that reads from the given path.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Returns the path to the secrets file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl SecretProvider for SecretProviderFile {
+    fn secret_read(
+        &self,
+        key: &str,
+    ) -> Result<Option<String>, Box<dyn std::error::Error + Send + Sync + 'static>> {
+        let contents = std::fs::read_to_string(&self.path)?;
+        let value = contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| line.split_once('='))
+            .find(|(line_key, _)| line_key.trim() == key)
+            .map(|(_, value)| value.trim().to_string());
+
+        Ok(value)
+    }
+}
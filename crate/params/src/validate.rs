@@ -0,0 +1,180 @@
+//! Validation rules for `#[validate(..)]` attributes on `Params` fields.
+//!
+//! These are called by the code generated by `#[derive(Params)]`, but may also
+//! be used in a manual [`Params::validate`] implementation.
+//!
+//! [`Params::validate`]: crate::Params::validate
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::ffi::{OsStr, OsString};
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use crate::ParamsValidationError;
+
+/// Returns an error if the value is empty.
+///
+/// Corresponds to `#[validate(non_empty)]`.
+pub fn non_empty<T>(field_name: &str, value: &T) -> Result<(), ParamsValidationError>
+where
+    T: ValueIsEmpty + ?Sized,
+{
+    if value.value_is_empty() {
+        Err(ParamsValidationError::field(
+            field_name,
+            "must not be empty",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns an error if the value is outside the given inclusive bounds.
+///
+/// Corresponds to `#[validate(range(min = 1, max = 10))]`. Either bound may be
+/// omitted.
+pub fn range<T>(
+    field_name: &str,
+    value: &T,
+    min: Option<T>,
+    max: Option<T>,
+) -> Result<(), ParamsValidationError>
+where
+    T: PartialOrd + Display,
+{
+    match (min, max) {
+        (Some(min), Some(max)) if *value < min || *value > max => {
+            Err(ParamsValidationError::field(
+                field_name,
+                format!("must be between {min} and {max}, but was {value}"),
+            ))
+        }
+        (Some(min), None) if *value < min => Err(ParamsValidationError::field(
+            field_name,
+            format!("must be at least {min}, but was {value}"),
+        )),
+        (None, Some(max)) if *value > max => Err(ParamsValidationError::field(
+            field_name,
+            format!("must be at most {max}, but was {value}"),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Returns an error if the value's URL scheme is not one of `schemes`.
+///
+/// The scheme is the text before the first `:` of the value's `Display`
+/// output, so this works for both `String`s and `url::Url`s.
+///
+/// Corresponds to `#[validate(url_scheme("https", "http"))]`.
+pub fn url_scheme<T>(
+    field_name: &str,
+    value: &T,
+    schemes: &[&str],
+) -> Result<(), ParamsValidationError>
+where
+    T: Display + ?Sized,
+{
+    let value = value.to_string();
+    let scheme = value.split_once(':').map(|(scheme, _)| scheme);
+    match scheme {
+        Some(scheme)
+            if schemes
+                .iter()
+                .any(|scheme_allowed| scheme.eq_ignore_ascii_case(scheme_allowed)) =>
+        {
+            Ok(())
+        }
+        _ => {
+            let schemes = schemes
+                .iter()
+                .map(|scheme| format!("`{scheme}`"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            Err(ParamsValidationError::field(
+                field_name,
+                format!("must use one of the URL schemes: {schemes}, but was `{value}`"),
+            ))
+        }
+    }
+}
+
+/// Returns an error if the given function returns an error message.
+///
+/// Corresponds to `#[validate(with = path::to::fn)]`, where the function
+/// signature is `fn(&T) -> Result<(), String>`.
+pub fn with<T, F>(field_name: &str, value: &T, f: F) -> Result<(), ParamsValidationError>
+where
+    T: ?Sized,
+    F: FnOnce(&T) -> Result<(), String>,
+{
+    f(value).map_err(|message| ParamsValidationError::field(field_name, message))
+}
+
+/// Types that may be checked with `#[validate(non_empty)]`.
+pub trait ValueIsEmpty {
+    /// Returns whether this value is empty.
+    fn value_is_empty(&self) -> bool;
+}
+
+impl ValueIsEmpty for str {
+    fn value_is_empty(&self) -> bool {
+        self.trim().is_empty()
+    }
+}
+
+impl ValueIsEmpty for String {
+    fn value_is_empty(&self) -> bool {
+        self.as_str().value_is_empty()
+    }
+}
+
+impl ValueIsEmpty for Path {
+    fn value_is_empty(&self) -> bool {
+        self.as_os_str().is_empty()
+    }
+}
+
+impl ValueIsEmpty for PathBuf {
+    fn value_is_empty(&self) -> bool {
+        self.as_path().value_is_empty()
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl ValueIsEmpty for OsStr {
+    fn value_is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl ValueIsEmpty for OsString {
+    fn value_is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<T> ValueIsEmpty for [T] {
+    fn value_is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<T> ValueIsEmpty for Vec<T> {
+    fn value_is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+/// `None` is treated as empty.
+impl<T> ValueIsEmpty for Option<T>
+where
+    T: ValueIsEmpty,
+{
+    fn value_is_empty(&self) -> bool {
+        self.as_ref().is_none_or(ValueIsEmpty::value_is_empty)
+    }
+}
@@ -4,8 +4,8 @@ use peace_resource_rt::{resources::ts::SetUp, BorrowFail, Resources};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    AnySpecDataType, AnySpecRt, MappingFn, MappingFnImpl, ParamsResolveError, ValueResolutionCtx,
-    ValueSpecRt,
+    AnySpecDataType, AnySpecRt, MappingFn, MappingFnBindError, MappingFnImpl, MappingFnName,
+    MappingFnReg, ParamsResolveError, ValueResolutionCtx, ValueSpecRt,
 };
 
 /// How to populate a field's value in an item's params.
@@ -69,7 +69,8 @@ where
     /// This is serialized as `MappingFn` with a string value. For
     /// deserialization, there is no actual backing function, so
     /// the user must provide the `MappingFn` in subsequent command
-    /// context builds.
+    /// context builds, unless the function was registered by name in the
+    /// flow's `MappingFnReg`.
     MappingFn(Box<dyn MappingFn<Output = T>>),
 }
 
@@ -84,6 +85,16 @@ where
         let mapping_fn = MappingFnImpl::from((field_name, f));
         Self::MappingFn(Box::new(mapping_fn))
     }
+
+    /// Returns a `ValueSpec` that uses the mapping function registered in the
+    /// flow's `MappingFnReg` under the given name.
+    pub fn from_map_named(
+        field_name: Option<String>,
+        mapping_fn_name: impl Into<MappingFnName>,
+    ) -> Self {
+        let mapping_fn = MappingFnImpl::from_fn_name(field_name, mapping_fn_name.into());
+        Self::MappingFn(Box::new(mapping_fn))
+    }
 }
 
 impl<T> Debug for ValueSpec<T>
@@ -195,6 +206,16 @@ where
             Self::Value { .. } | Self::InMemory | Self::MappingFn(_) => {}
         }
     }
+
+    fn mapping_fns_bind(
+        &mut self,
+        mapping_fn_reg: &MappingFnReg,
+        mapping_fn_bind_errors: &mut Vec<MappingFnBindError>,
+    ) {
+        if let Self::MappingFn(mapping_fn) = self {
+            mapping_fn_reg.mapping_fn_bind(mapping_fn, mapping_fn_bind_errors);
+        }
+    }
 }
 
 impl<T> ValueSpecRt for ValueSpec<T>
@@ -219,3 +240,21 @@ where
         ValueSpec::<T>::resolve_partial(self, resources, value_resolution_ctx)
     }
 }
+
+#[cfg(feature = "json_schema")]
+impl<T> schemars::JsonSchema for ValueSpec<T>
+where
+    T: Clone + Debug + schemars::JsonSchema + Send + Sync + 'static,
+{
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        format!("ValueSpec_for_{}", T::schema_name()).into()
+    }
+
+    fn schema_id() -> std::borrow::Cow<'static, str> {
+        format!("peace_params::ValueSpec<{}>", T::schema_id()).into()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        crate::ValueSpecDe::<T>::json_schema(generator)
+    }
+}
@@ -8,6 +8,11 @@ type FnPlaceholder<T> = fn(&()) -> Option<T>;
 
 /// Exists to deserialize `MappingFn` with a non-type-erased `MappingFnImpl`
 #[derive(Clone, Deserialize)]
+#[cfg_attr(
+    feature = "json_schema",
+    derive(schemars::JsonSchema),
+    schemars(description = "How to populate a field's value in an item's params.")
+)]
 pub enum ValueSpecDe<T> {
     /// Loads a stored value spec.
     ///
@@ -1,9 +1,13 @@
 use syn::{DeriveInput, Ident, ImplGenerics, Path, TypeGenerics, WhereClause};
 
-use crate::{spec_is_usable::is_usable_body, spec_merge::spec_merge};
+use crate::{
+    spec_is_usable::is_usable_body, spec_mapping_fns_bind::spec_mapping_fns_bind,
+    spec_merge::spec_merge,
+};
 
 /// `impl AnySpecRt for ValueSpec`, so that Peace can tell if a spec is usable,
-/// and merge provided and stored params together.
+/// merge provided and stored params together, and bind named mapping
+/// functions.
 pub fn impl_any_spec_rt_for_field_wise(
     ast: &DeriveInput,
     generics_split: &(ImplGenerics, TypeGenerics, Option<&WhereClause>),
@@ -14,6 +18,8 @@ pub fn impl_any_spec_rt_for_field_wise(
 
     let is_usable_body = is_usable_body(ast, params_field_wise_name, peace_params_path);
     let spec_merge = spec_merge(ast, params_field_wise_name, peace_params_path);
+    let spec_mapping_fns_bind =
+        spec_mapping_fns_bind(ast, params_field_wise_name, peace_params_path);
 
     quote! {
         impl #impl_generics #peace_params_path::AnySpecRt
@@ -25,6 +31,8 @@ pub fn impl_any_spec_rt_for_field_wise(
             }
 
             #spec_merge
+
+            #spec_mapping_fns_bind
         }
     }
 }
@@ -388,6 +388,11 @@ fn builder_field_methods(
                 Span::call_site(),
             );
 
+            let with_field_name_from_map_named = Ident::new(
+                &format!("with_{self_field_name}_from_map_named"),
+                Span::call_site(),
+            );
+
             let field_spec_ty_deconstruct =
                 field_spec_ty_deconstruct(peace_params_path, &field_name);
 
@@ -441,6 +446,18 @@ fn builder_field_methods(
                     self #proxy_call.#self_field_name = Some(spec);
                     self
                 }
+
+                pub fn #with_field_name_from_map_named(
+                    mut self,
+                    mapping_fn_name: impl Into<#peace_params_path::MappingFnName>,
+                ) -> Self {
+                    let spec = #field_spec_ty_path::from_map_named(
+                        Some(String::from(stringify!(#field_name))),
+                        mapping_fn_name,
+                    );
+                    self #proxy_call.#self_field_name = Some(spec);
+                    self
+                }
             }
         })
         .collect::<Vec<proc_macro2::TokenStream>>();
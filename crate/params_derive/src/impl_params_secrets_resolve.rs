@@ -0,0 +1,235 @@
+use syn::{DeriveInput, Fields, Ident, Path};
+
+use crate::util::{is_option_secret, is_secret, tuple_ident_from_field_index};
+
+/// Generates the `Params::secret_keys`, `Params::secrets_resolve`, and
+/// `Params::secrets_resolve_partial` methods for `Secret<T>` and
+/// `Option<Secret<T>>` fields.
+///
+/// Returns `None` if the type has no such fields, in which case the default
+/// implementations are used.
+///
+/// Generates something like the following:
+///
+/// ```rust,ignore
+/// fn secret_keys(&self) -> Vec<&str> {
+///     let mut secret_keys = Vec::new();
+///     let MyParams { password, .. } = self;
+///     secret_keys.push(password.key());
+///
+///     secret_keys
+/// }
+///
+/// fn secrets_resolve(
+///     &mut self,
+///     resources: &peace_resource_rt::Resources<peace_resource_rt::resources::ts::SetUp>,
+/// ) -> Result<(), peace_params::ParamsResolveError> {
+///     let MyParams { password, .. } = self;
+///     password.value_resolve(resources)?;
+///
+///     Ok(())
+/// }
+///
+/// fn secrets_resolve_partial(
+///     params_partial: &mut Self::Partial,
+///     resources: &peace_resource_rt::Resources<peace_resource_rt::resources::ts::SetUp>,
+/// ) -> Result<(), peace_params::ParamsResolveError> {
+///     let MyParamsPartial { password, .. } = params_partial;
+///     if let Some(password) = password {
+///         password.value_resolve(resources)?;
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub fn impl_params_secrets_resolve(
+    ast: &DeriveInput,
+    t_partial_name: &Ident,
+    peace_params_path: &Path,
+    peace_resource_rt_path: &Path,
+) -> Option<proc_macro2::TokenStream> {
+    let value_resolve = |field_var: &Ident| quote!(#field_var.value_resolve(resources)?;);
+    let key_push = |field_var: &Ident| quote!(secret_keys.push(#field_var.key()););
+    let secret_keys_push =
+        secrets_resolution_tokens(ast, &ast.ident, quote!(self), false, key_push)?;
+    let secrets_resolution =
+        secrets_resolution_tokens(ast, &ast.ident, quote!(self), false, value_resolve)?;
+    let secrets_resolution_partial = secrets_resolution_tokens(
+        ast,
+        t_partial_name,
+        quote!(params_partial),
+        true,
+        value_resolve,
+    )?;
+
+    Some(quote! {
+        fn secret_keys(&self) -> std::vec::Vec<&str> {
+            let mut secret_keys = std::vec::Vec::new();
+            #secret_keys_push
+
+            secret_keys
+        }
+
+        fn secrets_resolve(
+            &mut self,
+            resources: &#peace_resource_rt_path::Resources<
+                #peace_resource_rt_path::resources::ts::SetUp
+            >,
+        ) -> std::result::Result<(), #peace_params_path::ParamsResolveError> {
+            #secrets_resolution
+
+            Ok(())
+        }
+
+        fn secrets_resolve_partial(
+            params_partial: &mut Self::Partial,
+            resources: &#peace_resource_rt_path::Resources<
+                #peace_resource_rt_path::resources::ts::SetUp
+            >,
+        ) -> std::result::Result<(), #peace_params_path::ParamsResolveError> {
+            #secrets_resolution_partial
+
+            Ok(())
+        }
+    })
+}
+
+/// Returns the statements from `field_stmt` for each secret field of `value`,
+/// or `None` if the type has no secret fields.
+///
+/// `type_name` is the name of the params type, or of its `Partial` type when
+/// `partial` is `true`, in which case each field is wrapped in an `Option`.
+fn secrets_resolution_tokens<F>(
+    ast: &DeriveInput,
+    type_name: &Ident,
+    value: proc_macro2::TokenStream,
+    partial: bool,
+    field_stmt: F,
+) -> Option<proc_macro2::TokenStream>
+where
+    F: Fn(&Ident) -> proc_macro2::TokenStream,
+{
+    match &ast.data {
+        syn::Data::Struct(data_struct) => {
+            let fields = &data_struct.fields;
+            fields_secrets_resolution(fields, partial, &field_stmt).map(
+                |(fields_deconstructed, resolution)| match fields {
+                    Fields::Named(_) => quote! {
+                        let #type_name { #(#fields_deconstructed,)* .. } = #value;
+                        #resolution
+                    },
+                    Fields::Unnamed(_) => quote! {
+                        let #type_name(#(#fields_deconstructed),*) = #value;
+                        #resolution
+                    },
+                    Fields::Unit => proc_macro2::TokenStream::new(),
+                },
+            )
+        }
+        syn::Data::Enum(data_enum) => {
+            let variant_arms = data_enum
+                .variants
+                .iter()
+                .filter_map(|variant| {
+                    let variant_name = &variant.ident;
+                    fields_secrets_resolution(&variant.fields, partial, &field_stmt).map(
+                        |(fields_deconstructed, resolution)| match &variant.fields {
+                            Fields::Named(_) => quote! {
+                                #type_name::#variant_name { #(#fields_deconstructed,)* .. } => {
+                                    #resolution
+                                }
+                            },
+                            Fields::Unnamed(_) => quote! {
+                                #type_name::#variant_name(#(#fields_deconstructed),*) => {
+                                    #resolution
+                                }
+                            },
+                            Fields::Unit => proc_macro2::TokenStream::new(),
+                        },
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            if variant_arms.is_empty() {
+                None
+            } else {
+                Some(quote! {
+                    match #value {
+                        #(#variant_arms)*
+                        #[allow(unreachable_patterns)]
+                        _ => {}
+                    }
+                })
+            }
+        }
+        syn::Data::Union(_) => None,
+    }
+}
+
+/// Returns the deconstructed fields and resolution statements for `Secret<T>`
+/// and `Option<Secret<T>>` fields, or `None` if there are no such fields.
+///
+/// For named fields, only secret fields are deconstructed. For tuple fields,
+/// secret fields are deconstructed as `_n`, and other fields as `_`.
+///
+/// When `partial` is `true`, each field is additionally wrapped in an
+/// `Option`, and is only resolved if it is present.
+fn fields_secrets_resolution<F>(
+    fields: &Fields,
+    partial: bool,
+    field_stmt: &F,
+) -> Option<(Vec<proc_macro2::TokenStream>, proc_macro2::TokenStream)>
+where
+    F: Fn(&Ident) -> proc_macro2::TokenStream,
+{
+    let mut fields_deconstructed = Vec::new();
+    let mut resolution = proc_macro2::TokenStream::new();
+
+    fields.iter().enumerate().for_each(|(field_index, field)| {
+        let field_is_secret = is_secret(&field.ty);
+        let field_is_option_secret = is_option_secret(&field.ty);
+        let field_is_any_secret = field_is_secret || field_is_option_secret;
+        let field_var = match &field.ident {
+            Some(field_ident) => {
+                if field_is_any_secret {
+                    fields_deconstructed.push(quote!(#field_ident));
+                }
+                field_ident.clone()
+            }
+            None => {
+                let field_var = tuple_ident_from_field_index(field_index);
+                if field_is_any_secret {
+                    fields_deconstructed.push(quote!(#field_var));
+                } else {
+                    fields_deconstructed.push(quote!(_));
+                }
+                field_var
+            }
+        };
+
+        let field_pattern = match (partial, field_is_secret, field_is_option_secret) {
+            (false, true, _) => Some(None),
+            (false, false, true) | (true, true, _) => Some(Some(quote!(Some(#field_var)))),
+            (true, false, true) => Some(Some(quote!(Some(Some(#field_var))))),
+            (_, false, false) => None,
+        };
+        match field_pattern {
+            Some(None) => resolution.extend(field_stmt(&field_var)),
+            Some(Some(field_pattern)) => {
+                let stmt = field_stmt(&field_var);
+                resolution.extend(quote! {
+                    if let #field_pattern = #field_var {
+                        #stmt
+                    }
+                })
+            }
+            None => {}
+        }
+    });
+
+    if resolution.is_empty() {
+        None
+    } else {
+        Some((fields_deconstructed, resolution))
+    }
+}
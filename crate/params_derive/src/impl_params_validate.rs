@@ -0,0 +1,289 @@
+use syn::{
+    punctuated::Punctuated, Attribute, DeriveInput, Expr, Field, Fields, Ident, LitStr, Path,
+};
+
+use crate::util::{is_phantom_data, tuple_ident_from_field_index};
+
+/// Generates the `Params::validate` method from `#[validate(..)]` attributes.
+///
+/// Returns `None` if neither the type nor any of its fields have validation
+/// rules, in which case the default `Params::validate` implementation is used.
+///
+/// Supported rules:
+///
+/// * Field level: `non_empty`, `range(min = .., max = ..)`, `url_scheme("..",
+///   ..)`, `with = path::to::fn` where the function signature is `fn(&T) ->
+///   Result<(), String>`.
+/// * Type level: `with = path::to::fn` where the function signature is
+///   `fn(&Self) -> Result<(), ParamsValidationError>`.
+///
+/// Generates something like the following:
+///
+/// ```rust,ignore
+/// fn validate(&self) -> Result<(), peace_params::ParamsValidationError> {
+///     let MyParams { src, port, .. } = self;
+///     peace_params::validate::non_empty("src", src)?;
+///     peace_params::validate::range("port", port, Some(1), None)?;
+///
+///     my_params_validate(self)?;
+///
+///     Ok(())
+/// }
+/// ```
+pub fn impl_params_validate(
+    ast: &DeriveInput,
+    peace_params_path: &Path,
+) -> Option<proc_macro2::TokenStream> {
+    let mut errors = Vec::<syn::Error>::new();
+
+    let type_rules = attrs_to_rules(&ast.attrs, &mut errors);
+    let type_validations = type_rules
+        .iter()
+        .filter_map(|rule| match rule {
+            Rule::With(path) => Some(quote!(#path(self)?;)),
+            Rule::NonEmpty | Rule::Range { .. } | Rule::UrlScheme(_) => {
+                errors.push(syn::Error::new_spanned(
+                    &ast.ident,
+                    "Only `#[validate(with = path::to::fn)]` is supported on the `Params` type.",
+                ));
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let fields_validations = match &ast.data {
+        syn::Data::Struct(data_struct) => {
+            let fields = &data_struct.fields;
+            fields_validations(fields, peace_params_path, &mut errors).map(
+                |(fields_deconstructed, validations)| {
+                    let params_name = &ast.ident;
+                    match fields {
+                        Fields::Named(_) => quote! {
+                            let #params_name { #(#fields_deconstructed,)* .. } = self;
+                            #validations
+                        },
+                        Fields::Unnamed(_) => quote! {
+                            let #params_name(#(#fields_deconstructed),*) = self;
+                            #validations
+                        },
+                        Fields::Unit => proc_macro2::TokenStream::new(),
+                    }
+                },
+            )
+        }
+        syn::Data::Enum(data_enum) => {
+            let variant_arms = data_enum
+                .variants
+                .iter()
+                .filter_map(|variant| {
+                    let variant_name = &variant.ident;
+                    fields_validations(&variant.fields, peace_params_path, &mut errors).map(
+                        |(fields_deconstructed, validations)| match &variant.fields {
+                            Fields::Named(_) => quote! {
+                                Self::#variant_name { #(#fields_deconstructed,)* .. } => {
+                                    #validations
+                                }
+                            },
+                            Fields::Unnamed(_) => quote! {
+                                Self::#variant_name(#(#fields_deconstructed),*) => {
+                                    #validations
+                                }
+                            },
+                            Fields::Unit => proc_macro2::TokenStream::new(),
+                        },
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            if variant_arms.is_empty() {
+                None
+            } else {
+                Some(quote! {
+                    match self {
+                        #(#variant_arms)*
+                        #[allow(unreachable_patterns)]
+                        _ => {}
+                    }
+                })
+            }
+        }
+        syn::Data::Union(_) => None,
+    };
+
+    if !errors.is_empty() {
+        let compile_errors = errors.iter().map(syn::Error::to_compile_error);
+        return Some(quote!(#(#compile_errors)*));
+    }
+
+    if fields_validations.is_none() && type_validations.is_empty() {
+        return None;
+    }
+
+    Some(quote! {
+        fn validate(&self) -> std::result::Result<(), #peace_params_path::ParamsValidationError> {
+            #fields_validations
+
+            #(#type_validations)*
+
+            Ok(())
+        }
+    })
+}
+
+/// Returns the deconstructed fields and validation statements for fields with
+/// `#[validate(..)]` attributes, or `None` if no fields have rules.
+///
+/// For named fields, only fields with rules are deconstructed. For tuple
+/// fields, fields with rules are deconstructed as `_n`, and other fields as
+/// `_`.
+fn fields_validations(
+    fields: &Fields,
+    peace_params_path: &Path,
+    errors: &mut Vec<syn::Error>,
+) -> Option<(Vec<proc_macro2::TokenStream>, proc_macro2::TokenStream)> {
+    let mut fields_deconstructed = Vec::new();
+    let mut validations = proc_macro2::TokenStream::new();
+
+    fields.iter().enumerate().for_each(|(field_index, field)| {
+        let rules = attrs_to_rules(&field.attrs, errors);
+        let (field_var, field_name) = field_var_and_name(field, field_index);
+
+        match &field.ident {
+            Some(_) => {
+                if !rules.is_empty() {
+                    fields_deconstructed.push(quote!(#field_var));
+                }
+            }
+            None => {
+                if is_phantom_data(&field.ty) || rules.is_empty() {
+                    fields_deconstructed.push(quote!(_));
+                } else {
+                    fields_deconstructed.push(quote!(#field_var));
+                }
+            }
+        }
+
+        rules.iter().for_each(|rule| {
+            let validation = match rule {
+                Rule::NonEmpty => quote! {
+                    #peace_params_path::validate::non_empty(#field_name, #field_var)?;
+                },
+                Rule::Range { min, max } => {
+                    let min = option_tokens(min.as_deref());
+                    let max = option_tokens(max.as_deref());
+                    quote! {
+                        #peace_params_path::validate::range(
+                            #field_name,
+                            #field_var,
+                            #min,
+                            #max,
+                        )?;
+                    }
+                }
+                Rule::UrlScheme(schemes) => quote! {
+                    #peace_params_path::validate::url_scheme(
+                        #field_name,
+                        #field_var,
+                        &[#(#schemes),*],
+                    )?;
+                },
+                Rule::With(path) => quote! {
+                    #peace_params_path::validate::with(#field_name, #field_var, #path)?;
+                },
+            };
+            validations.extend(validation);
+        });
+    });
+
+    if validations.is_empty() {
+        None
+    } else {
+        Some((fields_deconstructed, validations))
+    }
+}
+
+/// Returns the variable to deconstruct a field into, and the field's name as
+/// shown in validation errors.
+fn field_var_and_name(field: &Field, field_index: usize) -> (Ident, LitStr) {
+    match &field.ident {
+        Some(field_ident) => {
+            let field_name = LitStr::new(&field_ident.to_string(), field_ident.span());
+            (field_ident.clone(), field_name)
+        }
+        None => {
+            let field_var = tuple_ident_from_field_index(field_index);
+            let field_name = LitStr::new(&field_index.to_string(), field_var.span());
+            (field_var, field_name)
+        }
+    }
+}
+
+fn option_tokens(expr: Option<&Expr>) -> proc_macro2::TokenStream {
+    match expr {
+        Some(expr) => quote!(Some(#expr)),
+        None => quote!(None),
+    }
+}
+
+/// A validation rule parsed from a `#[validate(..)]` attribute.
+enum Rule {
+    NonEmpty,
+    Range {
+        min: Option<Box<Expr>>,
+        max: Option<Box<Expr>>,
+    },
+    UrlScheme(Vec<LitStr>),
+    With(Path),
+}
+
+/// Parses rules from all `#[validate(..)]` attributes.
+fn attrs_to_rules(attrs: &[Attribute], errors: &mut Vec<syn::Error>) -> Vec<Rule> {
+    let mut rules = Vec::new();
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("validate"))
+        .for_each(|attr| {
+            let parse_result = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("non_empty") {
+                    rules.push(Rule::NonEmpty);
+                    Ok(())
+                } else if meta.path.is_ident("range") {
+                    let mut min = None;
+                    let mut max = None;
+                    meta.parse_nested_meta(|meta_range| {
+                        if meta_range.path.is_ident("min") {
+                            min = Some(Box::new(meta_range.value()?.parse::<Expr>()?));
+                            Ok(())
+                        } else if meta_range.path.is_ident("max") {
+                            max = Some(Box::new(meta_range.value()?.parse::<Expr>()?));
+                            Ok(())
+                        } else {
+                            Err(meta_range.error("Expected `min` or `max`."))
+                        }
+                    })?;
+                    rules.push(Rule::Range { min, max });
+                    Ok(())
+                } else if meta.path.is_ident("url_scheme") {
+                    let content;
+                    parenthesized!(content in meta.input);
+                    let schemes =
+                        Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?;
+                    rules.push(Rule::UrlScheme(schemes.into_iter().collect()));
+                    Ok(())
+                } else if meta.path.is_ident("with") {
+                    rules.push(Rule::With(meta.value()?.parse::<Path>()?));
+                    Ok(())
+                } else {
+                    Err(meta.error(
+                        "Expected one of `non_empty`, `range(..)`, `url_scheme(..)`, or `with = ..`.",
+                    ))
+                }
+            });
+
+            if let Err(error) = parse_result {
+                errors.push(error);
+            }
+        });
+
+    rules
+}
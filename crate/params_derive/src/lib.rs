@@ -11,8 +11,8 @@ use proc_macro::TokenStream;
 use quote::ToTokens;
 
 use syn::{
-    Data, DeriveInput, GenericParam, Ident, ImplGenerics, Path, Type, TypeGenerics, WhereClause,
-    WherePredicate,
+    Attribute, Data, DeriveInput, GenericParam, Ident, ImplGenerics, Path, Type, TypeGenerics,
+    WhereClause, WherePredicate,
 };
 
 use crate::{
@@ -26,11 +26,16 @@ use crate::{
     impl_from_params_for_params_field_wise::impl_from_params_for_params_field_wise,
     impl_from_params_for_params_partial::impl_from_params_for_params_partial,
     impl_params_merge_ext_for_params::impl_params_merge_ext_for_params,
+    impl_params_secrets_resolve::impl_params_secrets_resolve,
+    impl_params_validate::impl_params_validate,
     impl_try_from_params_partial_for_params::impl_try_from_params_partial_for_params,
     impl_value_spec_rt_for_field_wise::impl_value_spec_rt_for_field_wise,
     type_gen::TypeGen,
     type_gen_external::type_gen_external,
-    util::{is_fieldless_type, serde_bounds_for_type_params, ImplMode},
+    util::{
+        is_fieldless_type, is_json_schema_type, json_schema_derive_attrs,
+        serde_bounds_for_type_params, ImplMode,
+    },
 };
 
 mod field_wise_enum_builder_ctx;
@@ -43,9 +48,12 @@ mod impl_field_wise_spec_rt_for_field_wise_external;
 mod impl_from_params_for_params_field_wise;
 mod impl_from_params_for_params_partial;
 mod impl_params_merge_ext_for_params;
+mod impl_params_secrets_resolve;
+mod impl_params_validate;
 mod impl_try_from_params_partial_for_params;
 mod impl_value_spec_rt_for_field_wise;
 mod spec_is_usable;
+mod spec_mapping_fns_bind;
 mod spec_merge;
 mod type_gen;
 mod type_gen_external;
@@ -92,9 +100,40 @@ mod util;
 ///
 /// * `default`: Enum variant attribute to indicate which variant to instantiate
 ///   for `ParamsPartial::default()`.
+///
+/// * `validate(..)`: Field level attribute declaring rules that the field's
+///   value must satisfy, checked by `Params::validate`:
+///
+///     - `non_empty`: Strings, paths, and collections must not be empty.
+///     - `range(min = 1, max = 10)`: Value must be within the inclusive bounds.
+///     - `url_scheme("https", "http")`: Value must use one of the URL schemes.
+///     - `with = path::to::fn`: Custom `fn(&T) -> Result<(), String>`.
+///
+///   On the type, `validate(with = path::to::fn)` runs a custom `fn(&Self) ->
+///   Result<(), ParamsValidationError>` after the field rules.
+///
+/// * `json_schema`: Type level attribute to derive `schemars::JsonSchema` for
+///   the generated `*FieldWise` type. The type itself must also derive
+///   `JsonSchema`, and `peace_params`' `"json_schema"` feature must be enabled.
+///   A `#[schemars(bound = "..")]` attribute on the type is also applied to the
+///   `*FieldWise` type.
+///
+/// # Secrets
+///
+/// Fields whose type is `Secret<T>` have their values read from the secret
+/// provider in `resources` when the params are resolved, through
+/// `Params::secrets_resolve`.
 #[proc_macro_derive(
     Params,
-    attributes(peace_internal, crate_internal, value_spec, default, serde)
+    attributes(
+        peace_internal,
+        crate_internal,
+        value_spec,
+        default,
+        serde,
+        validate,
+        json_schema
+    )
 )]
 pub fn value_spec(input: TokenStream) -> TokenStream {
     let mut ast = syn::parse(input)
@@ -126,9 +165,12 @@ pub fn value_spec(input: TokenStream) -> TokenStream {
 ///
 /// * `default`: Enum variant attribute to indicate which variant to instantiate
 ///   for `ParamsPartial::default()`.
+///
+/// * `json_schema`: Type level attribute to derive `schemars::JsonSchema` for
+///   the generated `*FieldWise` type.
 #[proc_macro_derive(
     ParamsFieldless,
-    attributes(peace_internal, crate_internal, value_spec, default)
+    attributes(peace_internal, crate_internal, value_spec, default, json_schema)
 )]
 pub fn value_spec_fieldless(input: TokenStream) -> TokenStream {
     let mut ast = syn::parse(input)
@@ -299,21 +341,34 @@ fn impl_value(ast: &mut DeriveInput, impl_mode: ImplMode) -> proc_macro2::TokenS
 
     let mut impl_value_tokens = proc_macro2::TokenStream::new();
     match impl_mode {
-        ImplMode::Fieldwise => impl_value_tokens.extend(quote! {
-            impl #impl_generics #peace_params_path::Params
-            for #value_name #ty_generics
-            #where_clause
-            {
-                type Spec = #peace_params_path::ParamsSpec<#value_name #ty_generics>;
-                type Partial = #t_partial_name #ty_generics;
-                type FieldWiseSpec = #t_field_wise_name #ty_generics;
-                type FieldWiseBuilder = #t_field_wise_builder_name #builder_generics;
-
-                fn field_wise_spec() -> Self::FieldWiseBuilder {
-                    Self::FieldWiseBuilder::default()
+        ImplMode::Fieldwise => {
+            let params_validate = impl_params_validate(ast, &peace_params_path);
+            let params_secrets_resolve = impl_params_secrets_resolve(
+                ast,
+                &t_partial_name,
+                &peace_params_path,
+                &peace_resource_rt_path,
+            );
+            impl_value_tokens.extend(quote! {
+                impl #impl_generics #peace_params_path::Params
+                for #value_name #ty_generics
+                #where_clause
+                {
+                    type Spec = #peace_params_path::ParamsSpec<#value_name #ty_generics>;
+                    type Partial = #t_partial_name #ty_generics;
+                    type FieldWiseSpec = #t_field_wise_name #ty_generics;
+                    type FieldWiseBuilder = #t_field_wise_builder_name #builder_generics;
+
+                    fn field_wise_spec() -> Self::FieldWiseBuilder {
+                        Self::FieldWiseBuilder::default()
+                    }
+
+                    #params_validate
+
+                    #params_secrets_resolve
                 }
-            }
-        }),
+            })
+        }
         ImplMode::Fieldless => {}
     }
 
@@ -473,19 +528,23 @@ fn t_field_wise(
     t_field_wise_name: &Ident,
     t_partial_name: &Ident,
 ) -> proc_macro2::TokenStream {
+    let mut t_field_wise_attrs: Vec<Attribute> = vec![
+        parse_quote! {
+            #[doc="Specification of how to look up values for an item's parameters."]
+        },
+        // `Clone` and `Debug` are implemented manually, so that type parameters do not receive
+        // the `Clone` and `Debug` bounds.
+        parse_quote!(#[derive(serde::Serialize, serde::Deserialize)]),
+    ];
+    if is_json_schema_type(ast) {
+        t_field_wise_attrs.extend(json_schema_derive_attrs(ast, peace_params_path));
+    }
     let mut t_field_wise = TypeGen::gen_from_value_type(
         ast,
         generics_split,
         t_field_wise_name,
         |fields| fields_to_value_spec(fields, peace_params_path),
-        &[
-            parse_quote! {
-                #[doc="Specification of how to look up values for an item's parameters."]
-            },
-            // `Clone` and `Debug` are implemented manually, so that type parameters do not receive
-            // the `Clone` and `Debug` bounds.
-            parse_quote!(#[derive(serde::Serialize, serde::Deserialize)]),
-        ],
+        &t_field_wise_attrs,
         true,
     );
 
@@ -543,17 +602,21 @@ fn t_field_wise_external(
     t_field_wise_name: &Ident,
     t_partial_name: &Ident,
 ) -> proc_macro2::TokenStream {
+    let mut t_field_wise_attrs: Vec<Attribute> = vec![
+        parse_quote! {
+            #[doc="Specification of how to look up values for an item's parameters."]
+        },
+        parse_quote!(#[derive(serde::Serialize, serde::Deserialize)]),
+    ];
+    if is_json_schema_type(ast) {
+        t_field_wise_attrs.extend(json_schema_derive_attrs(ast, peace_params_path));
+    }
     let mut t_field_wise = type_gen_external(
         ast,
         generics_split,
         params_ty,
         t_field_wise_name,
-        &[
-            parse_quote! {
-                #[doc="Specification of how to look up values for an item's parameters."]
-            },
-            parse_quote!(#[derive(serde::Serialize, serde::Deserialize)]),
-        ],
+        &t_field_wise_attrs,
     );
 
     t_field_wise.extend(impl_field_wise_spec_rt_for_field_wise_external(
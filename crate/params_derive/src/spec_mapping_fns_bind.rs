@@ -0,0 +1,165 @@
+use syn::{punctuated::Punctuated, DeriveInput, Fields, Ident, Path, Variant};
+
+use crate::util::{fields_deconstruct, fields_stmt_map, variant_match_arm};
+
+pub fn spec_mapping_fns_bind(
+    ast: &DeriveInput,
+    params_field_wise_name: &Ident,
+    peace_params_path: &Path,
+) -> proc_macro2::TokenStream {
+    let mapping_fns_bind_body = match &ast.data {
+        syn::Data::Struct(data_struct) => {
+            let fields = &data_struct.fields;
+
+            struct_fields_mapping_fns_bind(params_field_wise_name, fields, peace_params_path)
+        }
+        syn::Data::Enum(data_enum) => {
+            let variants = &data_enum.variants;
+
+            variants_mapping_fns_bind(params_field_wise_name, variants, peace_params_path)
+        }
+        syn::Data::Union(data_union) => {
+            let fields = Fields::from(data_union.fields.clone());
+
+            struct_fields_mapping_fns_bind(params_field_wise_name, &fields, peace_params_path)
+        }
+    };
+
+    quote! {
+        fn mapping_fns_bind(
+            &mut self,
+            mapping_fn_reg: &#peace_params_path::MappingFnReg,
+            mapping_fn_bind_errors: &mut Vec<#peace_params_path::MappingFnBindError>,
+        ) {
+            #mapping_fns_bind_body
+        }
+    }
+}
+
+/// Binds mapping functions for the fields within this struct.
+pub fn struct_fields_mapping_fns_bind(
+    params_field_wise_name: &Ident,
+    fields: &Fields,
+    peace_params_path: &Path,
+) -> proc_macro2::TokenStream {
+    let fields_mapping_fns_bind = fields_mapping_fns_bind(fields, peace_params_path);
+    let fields_deconstructed = fields_deconstruct(fields);
+
+    match fields {
+        Fields::Named(_fields_named) => {
+            // Generates:
+            //
+            // ```rust
+            // let #params_field_wise_name {
+            //     field_1,
+            //     field_2,
+            //     marker: PhantomData,
+            // } = self;
+            //
+            // AnySpecRt::mapping_fns_bind(field_1, mapping_fn_reg, mapping_fn_bind_errors);
+            // AnySpecRt::mapping_fns_bind(field_2, mapping_fn_reg, mapping_fn_bind_errors);
+            // ```
+
+            quote! {
+                let #params_field_wise_name {
+                    #(#fields_deconstructed),*
+                } = self;
+
+                #fields_mapping_fns_bind
+            }
+        }
+        Fields::Unnamed(_fields_unnamed) => {
+            // Generates:
+            //
+            // ```rust
+            // let #params_name(_0, _1, PhantomData,) = self;
+            //
+            // AnySpecRt::mapping_fns_bind(_0, mapping_fn_reg, mapping_fn_bind_errors);
+            // AnySpecRt::mapping_fns_bind(_1, mapping_fn_reg, mapping_fn_bind_errors);
+            // ```
+
+            quote! {
+                let #params_field_wise_name(#(#fields_deconstructed),*) = self;
+
+                #fields_mapping_fns_bind
+            }
+        }
+        Fields::Unit => quote! {
+            let _ = (mapping_fn_reg, mapping_fn_bind_errors);
+        },
+    }
+}
+
+/// Binds mapping functions for the fields within each enum variant.
+pub fn variants_mapping_fns_bind(
+    params_field_wise_name: &Ident,
+    variants: &Punctuated<Variant, Token![,]>,
+    peace_params_path: &Path,
+) -> proc_macro2::TokenStream {
+    // Generates:
+    //
+    // ```rust
+    // match self {
+    //     ValueSpec::Variant1 => {}
+    //     ValueSpec::Variant2(_0, _1, PhantomData) => {
+    //         AnySpecRt::mapping_fns_bind(_0, mapping_fn_reg, mapping_fn_bind_errors);
+    //         AnySpecRt::mapping_fns_bind(_1, mapping_fn_reg, mapping_fn_bind_errors);
+    //     }
+    //     ValueSpec::Variant3 {
+    //         field_1,
+    //         field_2,
+    //         marker: PhantomData,
+    //     } => {
+    //         AnySpecRt::mapping_fns_bind(field_1, mapping_fn_reg, mapping_fn_bind_errors);
+    //         AnySpecRt::mapping_fns_bind(field_2, mapping_fn_reg, mapping_fn_bind_errors);
+    //     }
+    // }
+    // ```
+
+    let variant_mapping_fns_bind_arms =
+        variants
+            .iter()
+            .fold(proc_macro2::TokenStream::new(), |mut tokens, variant| {
+                let fields = &variant.fields;
+                let fields_deconstructed = fields_deconstruct(fields);
+                let variant_fields_mapping_fns_bind =
+                    fields_mapping_fns_bind(fields, peace_params_path);
+
+                tokens.extend(variant_match_arm(
+                    params_field_wise_name,
+                    variant,
+                    &fields_deconstructed,
+                    variant_fields_mapping_fns_bind,
+                ));
+
+                tokens
+            });
+
+    // Variants without fields do not use the parameters.
+    quote! {
+        let _ = (&mapping_fn_reg, &mapping_fn_bind_errors);
+
+        match self {
+            #variant_mapping_fns_bind_arms
+        }
+    }
+}
+
+fn fields_mapping_fns_bind(fields: &Fields, peace_params_path: &Path) -> proc_macro2::TokenStream {
+    fields_stmt_map(fields, move |_field, field_name, _field_index| {
+        quote! {
+            #peace_params_path::AnySpecRt::mapping_fns_bind(
+                #field_name,
+                mapping_fn_reg,
+                mapping_fn_bind_errors,
+            );
+        }
+    })
+    .fold(
+        proc_macro2::TokenStream::new(),
+        |mut tokens, next_tokens| {
+            tokens.extend(next_tokens);
+            tokens
+        },
+    )
+}
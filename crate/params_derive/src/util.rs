@@ -42,6 +42,57 @@ pub fn is_fieldless_type(ast: &DeriveInput) -> bool {
     is_known_fieldless_std_lib_spec(&ast.ident) || is_tagged_fieldless(&ast.attrs)
 }
 
+/// Returns whether the type has the `#[json_schema]` attribute.
+///
+/// When present, `schemars::JsonSchema` is derived for the generated
+/// `*FieldWise` type, so that `ParamsSpec<T>` has a JSON schema.
+pub fn is_json_schema_type(ast: &DeriveInput) -> bool {
+    ast.attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("json_schema"))
+}
+
+/// Returns the attributes to derive `schemars::JsonSchema` on a generated type,
+/// referencing `schemars` through the `peace_params` re-export.
+///
+/// `#[schemars(bound = "..")]` attributes on the params type are also applied
+/// to the generated type, so that type parameters such as an item's `Id` marker
+/// do not need to implement `JsonSchema`.
+pub fn json_schema_derive_attrs(ast: &DeriveInput, peace_params_path: &Path) -> Vec<Attribute> {
+    let schemars_path = format!("{}::schemars", quote!(#peace_params_path)).replace(' ', "");
+    let mut json_schema_derive_attrs = vec![
+        parse_quote!(#[derive(#peace_params_path::schemars::JsonSchema)]),
+        parse_quote!(#[schemars(crate = #schemars_path)]),
+    ];
+    json_schema_derive_attrs.extend(
+        ast.attrs
+            .iter()
+            .filter(|attr| is_schemars_bound_attr(attr))
+            .cloned(),
+    );
+    json_schema_derive_attrs
+}
+
+/// Returns whether the attribute is `#[schemars(bound = "..")]`.
+fn is_schemars_bound_attr(attr: &Attribute) -> bool {
+    if !attr.path().is_ident("schemars") {
+        return false;
+    }
+
+    let mut is_bound = false;
+    let _ = attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("bound") {
+            is_bound = true;
+        }
+        // Consume the value so that the remaining metas are parsed.
+        if meta.input.peek(syn::Token![=]) {
+            let _value: syn::Expr = meta.value()?.parse()?;
+        }
+        Ok(())
+    });
+    is_bound
+}
+
 /// Returns if the given `Type`'s spec should be fieldless.
 ///
 /// This applies to std library types, as well as non-`Path` types.
@@ -245,6 +296,22 @@ pub fn is_phantom_data(field_ty: &Type) -> bool {
         if matches!(path.segments.last(), Some(segment) if segment.ident == "PhantomData"))
 }
 
+/// Returns whether the given field is a `Secret<T>`.
+pub fn is_secret(field_ty: &Type) -> bool {
+    matches!(&field_ty, Type::Path(TypePath { path, .. })
+        if matches!(path.segments.last(), Some(segment) if segment.ident == "Secret"))
+}
+
+/// Returns whether the given field is an `Option<Secret<T>>`.
+pub fn is_option_secret(field_ty: &Type) -> bool {
+    matches!(&field_ty, Type::Path(TypePath { path, .. })
+        if matches!(path.segments.last(), Some(segment)
+            if segment.ident == "Option"
+                && matches!(&segment.arguments, PathArguments::AngleBracketed(args)
+                    if matches!(args.args.first(), Some(GenericArgument::Type(ty))
+                        if is_secret(ty)))))
+}
+
 /// Returns idents as `field_name_partial`.
 pub fn field_name_partial(field_name: &Ident) -> Ident {
     format_ident!("{}_partial", field_name)
@@ -14,11 +14,14 @@
 //!         |   |- CmdExecutionN
 //!         |
 //!         |- ProfileParams
+//!         |- ProfileParent
 //!         |
 //!         |- FlowDir  # "flow_name", multiple
 //!             |- StatesMeta
 //!             |- StatesCurrent
 //!             |- StatesGoal
+//!             |- ParamsSpecs
+//!             |- ParamsApplied
 //! ```
 //!
 //! Concrete folder structure example:
@@ -70,18 +73,21 @@
 //! ```
 
 pub use self::{
-    flow_dir::FlowDir, params_specs_file::ParamsSpecsFile, peace_app_dir::PeaceAppDir,
-    peace_dir::PeaceDir, profile_dir::ProfileDir, profile_history_dir::ProfileHistoryDir,
+    flow_dir::FlowDir, params_applied_file::ParamsAppliedFile, params_specs_file::ParamsSpecsFile,
+    peace_app_dir::PeaceAppDir, peace_dir::PeaceDir, profile_dir::ProfileDir,
+    profile_history_dir::ProfileHistoryDir, profile_parent_file::ProfileParentFile,
     states_current_file::StatesCurrentFile, states_goal_file::StatesGoalFile,
     workspace_dir::WorkspaceDir,
 };
 
 mod flow_dir;
+mod params_applied_file;
 mod params_specs_file;
 mod peace_app_dir;
 mod peace_dir;
 mod profile_dir;
 mod profile_history_dir;
+mod profile_parent_file;
 mod states_current_file;
 mod states_goal_file;
 mod workspace_dir;
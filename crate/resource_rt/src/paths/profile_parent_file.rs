@@ -0,0 +1,31 @@
+use std::path::PathBuf;
+
+use crate::paths::ProfileDir;
+
+/// Path to the file that stores the name of the profile's parent profile.
+///
+/// Profile params and item params specs that are not set in a profile are
+/// inherited from its parent profile.
+///
+/// Typically `$workspace_dir/.peace/$app/$profile/profile_parent.yaml`.
+///
+/// See `ProfileParentFile::from<&ProfileDir>` if you want to construct a
+/// `ProfileParentFile` with the conventional `$profile_dir/profile_parent.yaml`
+/// path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProfileParentFile(PathBuf);
+
+crate::paths::pathbuf_newtype!(ProfileParentFile);
+
+impl ProfileParentFile {
+    /// File name of the profile parent file.
+    pub const NAME: &'static str = "profile_parent.yaml";
+}
+
+impl From<&ProfileDir> for ProfileParentFile {
+    fn from(profile_dir: &ProfileDir) -> Self {
+        let path = profile_dir.join(Self::NAME);
+
+        Self(path)
+    }
+}
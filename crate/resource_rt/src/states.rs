@@ -1,9 +1,10 @@
 //! Resources that track current and goal states, and state diffs.
 
 pub use self::{
-    state_diffs::StateDiffs, states_clean::StatesClean, states_cleaned::StatesCleaned,
-    states_cleaned_dry::StatesCleanedDry, states_current::StatesCurrent,
-    states_current_stored::StatesCurrentStored, states_ensured::StatesEnsured,
+    state_diff_node::StateDiffNode, state_diffs::StateDiffs, states_clean::StatesClean,
+    states_cleaned::StatesCleaned, states_cleaned_dry::StatesCleanedDry,
+    states_current::StatesCurrent, states_current_stored::StatesCurrentStored,
+    states_diff_tree::StatesDiffTree, states_ensured::StatesEnsured,
     states_ensured_dry::StatesEnsuredDry, states_goal::StatesGoal,
     states_goal_stored::StatesGoalStored, states_previous::StatesPrevious,
     states_serde::StatesSerde,
@@ -20,12 +21,14 @@ use type_reg::untagged::{BoxDtDisplay, TypeMap};
 
 use crate::internal::StatesMut;
 
+mod state_diff_node;
 mod state_diffs;
 mod states_clean;
 mod states_cleaned;
 mod states_cleaned_dry;
 mod states_current;
 mod states_current_stored;
+mod states_diff_tree;
 mod states_ensured;
 mod states_ensured_dry;
 mod states_goal;
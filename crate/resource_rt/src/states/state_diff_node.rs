@@ -0,0 +1,137 @@
+use serde::{Deserialize, Serialize};
+use serde_yaml::Value;
+
+/// Structured difference between two serialized values.
+///
+/// Unchanged fields are not stored, so that only the fields that change are
+/// presented.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StateDiffNode {
+    /// Value is the same in both states.
+    Unchanged,
+    /// Value exists in the second state, but not the first.
+    Added(String),
+    /// Value exists in the first state, but not the second.
+    Removed(String),
+    /// Value exists in both states, and cannot be compared field by field.
+    Modified {
+        /// The value in the first state.
+        from: String,
+        /// The value in the second state.
+        to: String,
+    },
+    /// Mapping or sequence where at least one field differs.
+    ///
+    /// Each entry is the field name or `[index]`, and the field's difference.
+    Fields(Vec<(String, StateDiffNode)>),
+}
+
+impl StateDiffNode {
+    /// Returns the structured difference between two serialized values.
+    pub fn new(from: &Value, to: &Value) -> Self {
+        if from == to {
+            return Self::Unchanged;
+        }
+
+        let fields = match (from, to) {
+            (Value::Mapping(mapping_from), Value::Mapping(mapping_to)) => {
+                let fields_from = mapping_from.iter().filter_map(|(key, value_from)| {
+                    let node = match mapping_to.get(key) {
+                        Some(value_to) => Self::new(value_from, value_to),
+                        None => Self::Removed(Self::value_fmt(value_from)),
+                    };
+                    Self::field_if_changed(Self::key_fmt(key), node)
+                });
+                let fields_to = mapping_to
+                    .iter()
+                    .filter(|(key, _value_to)| !mapping_from.contains_key(*key))
+                    .map(|(key, value_to)| {
+                        (Self::key_fmt(key), Self::Added(Self::value_fmt(value_to)))
+                    });
+
+                fields_from.chain(fields_to).collect::<Vec<_>>()
+            }
+            (Value::Sequence(sequence_from), Value::Sequence(sequence_to)) => {
+                let len = sequence_from.len().max(sequence_to.len());
+                (0..len)
+                    .filter_map(|index| {
+                        let node = match (sequence_from.get(index), sequence_to.get(index)) {
+                            (Some(value_from), Some(value_to)) => Self::new(value_from, value_to),
+                            (Some(value_from), None) => Self::Removed(Self::value_fmt(value_from)),
+                            (None, Some(value_to)) => Self::Added(Self::value_fmt(value_to)),
+                            (None, None) => Self::Unchanged,
+                        };
+                        Self::field_if_changed(format!("[{index}]"), node)
+                    })
+                    .collect::<Vec<_>>()
+            }
+            (Value::Tagged(tagged_from), Value::Tagged(tagged_to))
+                if tagged_from.tag == tagged_to.tag =>
+            {
+                return Self::new(&tagged_from.value, &tagged_to.value);
+            }
+            _ => {
+                return Self::Modified {
+                    from: Self::value_fmt(from),
+                    to: Self::value_fmt(to),
+                };
+            }
+        };
+
+        if fields.is_empty() {
+            Self::Unchanged
+        } else {
+            Self::Fields(fields)
+        }
+    }
+
+    /// Returns whether the values are the same in both states.
+    pub fn is_unchanged(&self) -> bool {
+        matches!(self, Self::Unchanged)
+    }
+
+    fn field_if_changed(key: String, node: Self) -> Option<(String, Self)> {
+        if node.is_unchanged() {
+            None
+        } else {
+            Some((key, node))
+        }
+    }
+
+    fn key_fmt(key: &Value) -> String {
+        match key {
+            Value::String(s) => s.clone(),
+            _ => Self::value_fmt(key),
+        }
+    }
+
+    /// Formats a value on a single line.
+    pub(crate) fn value_fmt(value: &Value) -> String {
+        match value {
+            Value::Null => String::from("null"),
+            Value::Bool(b) => b.to_string(),
+            Value::Number(n) => n.to_string(),
+            Value::String(s) => format!("{s:?}"),
+            Value::Sequence(sequence) => {
+                let values = sequence
+                    .iter()
+                    .map(Self::value_fmt)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("[{values}]")
+            }
+            Value::Mapping(mapping) => {
+                let entries = mapping
+                    .iter()
+                    .map(|(key, value)| {
+                        format!("{}: {}", Self::key_fmt(key), Self::value_fmt(value))
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("{{{entries}}}")
+            }
+            Value::Tagged(tagged) => format!("{} {}", tagged.tag, Self::value_fmt(&tagged.value)),
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use std::ops::Deref;
+
+use indexmap::IndexMap;
+use peace_core::ItemId;
+use peace_fmt::{presentable::DiffKind, Presentable, Presenter};
+use serde::{Deserialize, Serialize};
+
+use crate::states::{StateDiffNode, States};
+
+/// Field level differences between two `States` maps, for each item.
+///
+/// Unlike [`StateDiffs`], which uses each item's `StateDiff` `Display`
+/// implementation, this is computed from the serialized states, so every
+/// field that changes is shown.
+///
+/// This is presented as a tree per item, with `+` for added fields, `-` for
+/// removed fields, and `~` for modified fields. Unchanged items are collapsed
+/// to a single line.
+///
+/// `DiffCmd` and `EnsureCmd::exec_dry` insert a `StatesDiffTree` into
+/// `resources`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let state_diffs_outcome = DiffCmd::diff_stored(cmd_ctx).await?;
+///
+/// let resources = cmd_ctx.view().resources;
+/// let states_diff_tree = resources.borrow::<StatesDiffTree>();
+/// output.present(&*states_diff_tree).await?;
+/// ```
+///
+/// [`StateDiffs`]: crate::states::StateDiffs
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct StatesDiffTree(IndexMap<ItemId, StateDiffNode>);
+
+impl StatesDiffTree {
+    /// Returns the field level differences between two `States` maps.
+    ///
+    /// Items are ordered by their order in `states_from`, followed by items
+    /// that only exist in `states_to`. Items that only exist in `states_from`
+    /// are [`StateDiffNode::Removed`], and items that only exist in
+    /// `states_to` are [`StateDiffNode::Added`].
+    ///
+    /// # Parameters
+    ///
+    /// * `states_from`: States to compare from, e.g. current states.
+    /// * `states_to`: States to compare to, e.g. goal states.
+    pub fn new<TS0, TS1>(
+        states_from: &States<TS0>,
+        states_to: &States<TS1>,
+    ) -> Result<Self, serde_yaml::Error> {
+        let mut state_diff_nodes = IndexMap::with_capacity(states_from.len());
+
+        states_from.iter().try_for_each(|(item_id, state_from)| {
+            let value_from = serde_yaml::to_value(state_from)?;
+            let state_diff_node = match states_to.get_raw(item_id) {
+                Some(state_to) => {
+                    let value_to = serde_yaml::to_value(state_to)?;
+                    StateDiffNode::new(&value_from, &value_to)
+                }
+                None => StateDiffNode::Removed(StateDiffNode::value_fmt(&value_from)),
+            };
+            state_diff_nodes.insert(item_id.clone(), state_diff_node);
+
+            Ok::<_, serde_yaml::Error>(())
+        })?;
+
+        states_to
+            .iter()
+            .filter(|(item_id, _state_to)| !states_from.contains_key(*item_id))
+            .try_for_each(|(item_id, state_to)| {
+                let value_to = serde_yaml::to_value(state_to)?;
+                let state_diff_node = StateDiffNode::Added(StateDiffNode::value_fmt(&value_to));
+                state_diff_nodes.insert(item_id.clone(), state_diff_node);
+
+                Ok::<_, serde_yaml::Error>(())
+            })?;
+
+        Ok(Self(state_diff_nodes))
+    }
+
+    /// Returns whether any item's state differs.
+    pub fn has_changes(&self) -> bool {
+        self.0
+            .values()
+            .any(|state_diff_node| !state_diff_node.is_unchanged())
+    }
+
+    /// Returns the inner map.
+    pub fn into_inner(self) -> IndexMap<ItemId, StateDiffNode> {
+        self.0
+    }
+
+    /// Returns the lines to present, with the depth of each line.
+    fn lines(&self) -> Vec<(usize, DiffKind, String)> {
+        let mut lines = Vec::new();
+        self.0.iter().for_each(|(item_id, state_diff_node)| {
+            Self::node_lines(&mut lines, 0, item_id.as_str(), state_diff_node);
+        });
+        lines
+    }
+
+    fn node_lines(
+        lines: &mut Vec<(usize, DiffKind, String)>,
+        depth: usize,
+        key: &str,
+        state_diff_node: &StateDiffNode,
+    ) {
+        match state_diff_node {
+            StateDiffNode::Unchanged => {
+                lines.push((depth, DiffKind::Unchanged, key.to_string()));
+            }
+            StateDiffNode::Added(value) => {
+                lines.push((depth, DiffKind::Added, format!("{key}: {value}")));
+            }
+            StateDiffNode::Removed(value) => {
+                lines.push((depth, DiffKind::Removed, format!("{key}: {value}")));
+            }
+            StateDiffNode::Modified { from, to } => {
+                lines.push((depth, DiffKind::Modified, format!("{key}: {from} → {to}")));
+            }
+            StateDiffNode::Fields(fields) => {
+                lines.push((depth, DiffKind::Modified, key.to_string()));
+                fields.iter().for_each(|(field_key, field_node)| {
+                    Self::node_lines(lines, depth + 1, field_key, field_node);
+                });
+            }
+        }
+    }
+}
+
+impl Deref for StatesDiffTree {
+    type Target = IndexMap<ItemId, StateDiffNode>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl Presentable for StatesDiffTree {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        for (depth, diff_kind, text) in self.lines() {
+            if depth > 0 {
+                presenter.text(&"  ".repeat(depth)).await?;
+            }
+            presenter.diff(diff_kind, &text).await?;
+            presenter.text("\n").await?;
+        }
+
+        Ok(())
+    }
+}
@@ -186,6 +186,15 @@ where
 
         match item_apply {
             Ok(mut item_apply) => {
+                // The diff is supplementary to the progress, so it is dropped
+                // instead of waiting when the progress channel is full.
+                #[cfg(feature = "output_progress")]
+                let _progress_send_unused =
+                    progress_tx.try_send(CmdProgressUpdate::ItemStateDiff {
+                        item_id: item_id.clone(),
+                        state_diff: format!("{}", item_apply.state_diff()),
+                    });
+
                 match item_apply.apply_check() {
                     #[cfg(not(feature = "output_progress"))]
                     ApplyCheck::ExecRequired => {}
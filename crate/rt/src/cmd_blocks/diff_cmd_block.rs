@@ -15,7 +15,7 @@ use peace_resource_rt::{
     resources::ts::SetUp,
     states::{
         ts::{Current, CurrentStored, Goal, GoalStored},
-        StateDiffs, States,
+        StateDiffs, States, StatesDiffTree,
     },
     type_reg::untagged::{BoxDtDisplay, TypeMap},
     ResourceFetchError, Resources,
@@ -120,7 +120,7 @@ where
 {
     type CmdCtxTypes = CmdCtxTypesT;
     type InputT = (States<StatesTs0>, States<StatesTs1>);
-    type Outcome = (StateDiffs, Self::InputT);
+    type Outcome = (StateDiffs, Option<StatesDiffTree>, Self::InputT);
 
     #[cfg(feature = "output_progress")]
     fn cmd_block_item_interaction_type(&self) -> CmdBlockItemInteractionType {
@@ -145,8 +145,14 @@ where
     }
 
     fn outcome_insert(&self, resources: &mut Resources<SetUp>, outcome: Self::Outcome) {
-        let (state_diffs, (states_ts0, states_ts1)) = outcome;
+        let (state_diffs, states_diff_tree, (states_ts0, states_ts1)) = outcome;
         resources.insert(state_diffs);
+        match states_diff_tree {
+            Some(states_diff_tree) => resources.insert(states_diff_tree),
+            None => {
+                let _states_diff_tree_stale = resources.try_remove::<StatesDiffTree>();
+            }
+        }
         resources.insert(states_ts0);
         resources.insert(states_ts1);
     }
@@ -154,6 +160,7 @@ where
     fn outcome_type_names(&self) -> Vec<String> {
         vec![
             tynm::type_name::<StateDiffs>(),
+            tynm::type_name::<StatesDiffTree>(),
             tynm::type_name::<States<StatesTs0>>(),
             tynm::type_name::<States<StatesTs1>>(),
         ]
@@ -163,7 +170,7 @@ where
         &self,
         input: Self::InputT,
         cmd_view: &mut SingleProfileSingleFlowView<'_, Self::CmdCtxTypes>,
-        #[cfg(feature = "output_progress")] _progress_tx: &Sender<CmdProgressUpdate>,
+        #[cfg(feature = "output_progress")] progress_tx: &Sender<CmdProgressUpdate>,
     ) -> Result<
         CmdBlockOutcome<Self::Outcome, <Self::CmdCtxTypes as CmdCtxTypesConstrained>::AppError>,
         <Self::CmdCtxTypes as CmdCtxTypesConstrained>::AppError,
@@ -186,8 +193,23 @@ where
             &states_ts0,
             &states_ts1,
         )
-        .await?
-        .map(move |state_diffs| (state_diffs, (states_ts0, states_ts1)));
+        .await?;
+
+        // Diffs are supplementary to the progress, so they are dropped instead
+        // of waiting when the progress channel is full.
+        #[cfg(feature = "output_progress")]
+        for (item_id, state_diff) in stream_outcome.value().iter() {
+            let _progress_send_unused = progress_tx.try_send(CmdProgressUpdate::ItemStateDiff {
+                item_id: item_id.clone(),
+                state_diff: format!("{state_diff}"),
+            });
+        }
+
+        // The tree is supplementary to the `StateDiffs`, so states that cannot
+        // be serialized do not fail the diff.
+        let states_diff_tree = StatesDiffTree::new(&states_ts0, &states_ts1).ok();
+        let stream_outcome = stream_outcome
+            .map(move |state_diffs| (state_diffs, states_diff_tree, (states_ts0, states_ts1)));
 
         Ok(CmdBlockOutcome::new_item_wise(stream_outcome))
     }
@@ -28,7 +28,12 @@ cfg_if::cfg_if! {
 ///
 /// [`StatesDiscoverCmd`]: crate::StatesDiscoverCmd
 #[derive(Debug)]
-pub struct StatesCurrentReadCmdBlock<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+pub struct StatesCurrentReadCmdBlock<CmdCtxTypesT> {
+    /// IDs of the items whose states to read, or all items if `None`.
+    item_ids: Option<Vec<ItemId>>,
+    /// Marker.
+    marker: PhantomData<CmdCtxTypesT>,
+}
 
 impl<CmdCtxTypesT> StatesCurrentReadCmdBlock<CmdCtxTypesT>
 where
@@ -39,22 +44,48 @@ where
         Self::default()
     }
 
+    /// Only reads the states of the given items.
+    ///
+    /// With [`StatesLayout::PerItem`], the other items' state files are not
+    /// read.
+    ///
+    /// [`StatesLayout::PerItem`]: peace_rt_model::StatesLayout::PerItem
+    pub fn with_item_ids(mut self, item_ids: Vec<ItemId>) -> Self {
+        self.item_ids = Some(item_ids);
+        self
+    }
+
     pub(crate) async fn deserialize_internal(
         resources: &mut Resources<SetUp>,
         states_type_reg: &TypeReg<ItemId, BoxDtDisplay>,
+        item_ids: Option<&[ItemId]>,
     ) -> Result<StatesCurrentStored, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError> {
         let flow_id = resources.borrow::<FlowId>();
         let flow_dir = resources.borrow::<FlowDir>();
         let storage = resources.borrow::<Storage>();
         let states_current_file = StatesCurrentFile::from(&*flow_dir);
 
-        let states_current_stored = StatesSerializer::deserialize_stored(
-            &flow_id,
-            &storage,
-            states_type_reg,
-            &states_current_file,
-        )
-        .await?;
+        let states_current_stored = match item_ids {
+            Some(item_ids) => {
+                StatesSerializer::deserialize_stored_items(
+                    &flow_id,
+                    &storage,
+                    states_type_reg,
+                    &states_current_file,
+                    item_ids,
+                )
+                .await?
+            }
+            None => {
+                StatesSerializer::deserialize_stored(
+                    &flow_id,
+                    &storage,
+                    states_type_reg,
+                    &states_current_file,
+                )
+                .await?
+            }
+        };
 
         drop(storage);
         drop(flow_dir);
@@ -68,7 +99,10 @@ where
 
 impl<CmdCtxTypesT> Default for StatesCurrentReadCmdBlock<CmdCtxTypesT> {
     fn default() -> Self {
-        Self(PhantomData)
+        Self {
+            item_ids: None,
+            marker: PhantomData,
+        }
     }
 }
 
@@ -109,7 +143,7 @@ where
             ..
         } = cmd_view;
 
-        Self::deserialize_internal(resources, states_type_reg)
+        Self::deserialize_internal(resources, states_type_reg, self.item_ids.as_deref())
             .await
             .map(CmdBlockOutcome::Single)
     }
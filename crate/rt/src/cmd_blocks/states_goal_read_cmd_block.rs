@@ -28,7 +28,12 @@ cfg_if::cfg_if! {
 ///
 /// [`StatesDiscoverCmd`]: crate::StatesDiscoverCmd
 #[derive(Debug)]
-pub struct StatesGoalReadCmdBlock<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+pub struct StatesGoalReadCmdBlock<CmdCtxTypesT> {
+    /// IDs of the items whose states to read, or all items if `None`.
+    item_ids: Option<Vec<ItemId>>,
+    /// Marker.
+    marker: PhantomData<CmdCtxTypesT>,
+}
 
 impl<CmdCtxTypesT> StatesGoalReadCmdBlock<CmdCtxTypesT>
 where
@@ -39,22 +44,48 @@ where
         Self::default()
     }
 
+    /// Only reads the states of the given items.
+    ///
+    /// With [`StatesLayout::PerItem`], the other items' state files are not
+    /// read.
+    ///
+    /// [`StatesLayout::PerItem`]: peace_rt_model::StatesLayout::PerItem
+    pub fn with_item_ids(mut self, item_ids: Vec<ItemId>) -> Self {
+        self.item_ids = Some(item_ids);
+        self
+    }
+
     pub(crate) async fn deserialize_internal(
         resources: &mut Resources<SetUp>,
         states_type_reg: &TypeReg<ItemId, BoxDtDisplay>,
+        item_ids: Option<&[ItemId]>,
     ) -> Result<StatesGoalStored, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError> {
         let flow_id = resources.borrow::<FlowId>();
         let flow_dir = resources.borrow::<FlowDir>();
         let storage = resources.borrow::<Storage>();
         let states_goal_file = StatesGoalFile::from(&*flow_dir);
 
-        let states_goal_stored = StatesSerializer::deserialize_goal(
-            &flow_id,
-            &storage,
-            states_type_reg,
-            &states_goal_file,
-        )
-        .await?;
+        let states_goal_stored = match item_ids {
+            Some(item_ids) => {
+                StatesSerializer::deserialize_goal_items(
+                    &flow_id,
+                    &storage,
+                    states_type_reg,
+                    &states_goal_file,
+                    item_ids,
+                )
+                .await?
+            }
+            None => {
+                StatesSerializer::deserialize_goal(
+                    &flow_id,
+                    &storage,
+                    states_type_reg,
+                    &states_goal_file,
+                )
+                .await?
+            }
+        };
 
         drop(storage);
         drop(flow_dir);
@@ -68,7 +99,10 @@ where
 
 impl<CmdCtxTypesT> Default for StatesGoalReadCmdBlock<CmdCtxTypesT> {
     fn default() -> Self {
-        Self(PhantomData)
+        Self {
+            item_ids: None,
+            marker: PhantomData,
+        }
     }
 }
 
@@ -109,7 +143,7 @@ where
             ..
         } = cmd_view;
 
-        Self::deserialize_internal(resources, states_type_reg)
+        Self::deserialize_internal(resources, states_type_reg, self.item_ids.as_deref())
             .await
             .map(CmdBlockOutcome::Single)
     }
@@ -13,19 +13,41 @@ pub use self::{
     clean_cmd::CleanCmd,
     diff_cmd::{DiffCmd, DiffInfoSpec, DiffStateSpec},
     ensure_cmd::EnsureCmd,
+    params_diff_cmd::ParamsDiffCmd,
+    profile_copy_cmd::ProfileCopyCmd,
+    profile_create_cmd::ProfileCreateCmd,
+    profile_delete_cmd::ProfileDeleteCmd,
+    profile_list_cmd::ProfileListCmd,
+    profile_parent_set_cmd::ProfileParentSetCmd,
+    profile_rename_cmd::ProfileRenameCmd,
+    reencrypt_cmd::ReencryptCmd,
     states_current_read_cmd::StatesCurrentReadCmd,
     states_current_stored_display_cmd::StatesCurrentStoredDisplayCmd,
     states_discover_cmd::StatesDiscoverCmd,
     states_goal_display_cmd::StatesGoalDisplayCmd,
     states_goal_read_cmd::StatesGoalReadCmd,
+    workspace_export_cmd::WorkspaceExportCmd,
+    workspace_import_cmd::WorkspaceImportCmd,
 };
 
 mod apply_stored_state_sync;
 mod clean_cmd;
 mod diff_cmd;
 mod ensure_cmd;
+mod params_diff_cmd;
+mod profile_copy_cmd;
+mod profile_create_cmd;
+mod profile_delete_cmd;
+mod profile_list_cmd;
+mod profile_parent_set_cmd;
+mod profile_rename_cmd;
+mod profile_storage;
+mod reencrypt_cmd;
 mod states_current_read_cmd;
 mod states_current_stored_display_cmd;
 mod states_discover_cmd;
 mod states_goal_display_cmd;
 mod states_goal_read_cmd;
+mod workspace_archive;
+mod workspace_export_cmd;
+mod workspace_import_cmd;
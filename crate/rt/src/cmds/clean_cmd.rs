@@ -6,8 +6,9 @@ use peace_cmd::{
 };
 use peace_cmd_model::CmdOutcome;
 use peace_cmd_rt::{CmdBlockWrapper, CmdExecution};
+use peace_params::{ParamsSpecs, ParamsSpecsApplied};
 use peace_resource_rt::{
-    paths::{FlowDir, StatesCurrentFile},
+    paths::{FlowDir, ParamsAppliedFile, StatesCurrentFile},
     resources::ts::SetUp,
     states::{States, StatesCleaned, StatesCleanedDry, StatesPrevious},
     Resources,
@@ -56,6 +57,10 @@ where
     /// 3. For `Item`s that return `ApplyCheck::ExecRequired`, run
     ///    `Item::apply_exec_dry`.
     ///
+    /// Params are resolved from the params that each item was last applied
+    /// with, falling back to the current params specs for items that have not
+    /// been applied.
+    ///
     /// [`apply_exec_dry`]: peace_cfg::Item::apply_exec_dry
     /// [`Item::apply_check`]: peace_cfg::Item::apply_check
     /// [`Item::apply_exec_dry`]: peace_cfg::ItemRt::apply_exec_dry
@@ -133,6 +138,10 @@ where
     /// 3. For `Item`s that return `ApplyCheck::ExecRequired`, run
     ///    `Item::apply_exec`.
     ///
+    /// Params are resolved from the params that each item was last applied
+    /// with, falling back to the current params specs for items that have not
+    /// been applied.
+    ///
     /// [`apply_exec`]: peace_cfg::Item::apply_exec
     /// [`Item::apply_check`]: peace_cfg::Item::apply_check
     /// [`Item::apply_exec`]: peace_cfg::ItemRt::apply_exec
@@ -181,6 +190,13 @@ where
                     CleanExecChange::Some(states_previous_and_cleaned) => {
                         let (states_previous, states_cleaned) = *states_previous_and_cleaned;
                         Self::serialize_current(item_graph, resources, &states_cleaned).await?;
+                        Self::serialize_params_applied(
+                            item_graph,
+                            resources,
+                            &states_previous,
+                            &states_cleaned,
+                        )
+                        .await?;
 
                         resources.insert::<StatesPrevious>(states_previous);
 
@@ -262,7 +278,15 @@ where
                 .build()
         };
 
-        let cmd_outcome = cmd_execution.exec(cmd_ctx).await?;
+        // Clean using the params that each item was last applied with, so that changed
+        // params do not cause a different resource to be cleaned.
+        let params_specs_current = Self::params_specs_for_clean(cmd_ctx)
+            .map(|params_specs| std::mem::replace(cmd_ctx.params_specs_mut(), params_specs));
+        let cmd_outcome = cmd_execution.exec(cmd_ctx).await;
+        if let Some(params_specs_current) = params_specs_current {
+            *cmd_ctx.params_specs_mut() = params_specs_current;
+        }
+        let cmd_outcome = cmd_outcome?;
 
         // TODO: Should we run `StatesCurrentFn` again?
         //
@@ -278,6 +302,32 @@ where
         Ok(cmd_outcome)
     }
 
+    /// Returns the params specs to use when cleaning, if any items have params
+    /// recorded from a previous apply.
+    ///
+    /// Items with recorded params use those params, and other items use the
+    /// current params specs.
+    fn params_specs_for_clean(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'_, CmdCtxTypesT>>,
+    ) -> Option<ParamsSpecs> {
+        let SingleProfileSingleFlowView {
+            params_specs,
+            resources,
+            ..
+        } = cmd_ctx.view();
+        let params_specs_applied = resources.try_borrow::<ParamsSpecsApplied>().ok()?;
+
+        let mut params_specs_for_clean = params_specs.clone();
+        params_specs_applied
+            .iter()
+            .filter(|(item_id, _)| params_specs.contains_key(*item_id))
+            .for_each(|(item_id, params_spec_applied)| {
+                params_specs_for_clean.insert_raw(item_id.clone(), params_spec_applied.clone());
+            });
+
+        Some(params_specs_for_clean)
+    }
+
     // TODO: This duplicates a bit of code with `StatesDiscoverCmd`,
     async fn serialize_current(
         item_graph: &ItemGraph<<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
@@ -298,6 +348,63 @@ where
 
         Ok(())
     }
+
+    /// Removes the recorded params for each item whose state was changed by
+    /// this clean.
+    ///
+    /// Items whose state did not change keep the params from their previous
+    /// apply, so that a partial or interrupted clean can still clean the
+    /// remaining items with the params they were applied with.
+    async fn serialize_params_applied(
+        item_graph: &ItemGraph<<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        resources: &mut Resources<SetUp>,
+        states_previous: &StatesPrevious,
+        states_cleaned: &StatesCleaned,
+    ) -> Result<(), <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError> {
+        use peace_rt_model::ParamsSpecsSerializer;
+
+        let Ok(mut params_specs_applied) = resources.try_remove::<ParamsSpecsApplied>() else {
+            return Ok(());
+        };
+
+        let params_specs_applied_count = params_specs_applied.len();
+        item_graph.iter_insertion().try_for_each(|item| {
+            let item_id = item.id();
+            let state_changed = match (
+                states_previous.get_raw(item_id),
+                states_cleaned.get_raw(item_id),
+            ) {
+                (Some(state_previous), Some(state_cleaned)) => {
+                    !item.state_eq(state_previous, state_cleaned)?
+                }
+                (None, Some(_)) => true,
+                (_, None) => false,
+            };
+
+            if state_changed {
+                params_specs_applied.shift_remove(item_id);
+            }
+
+            Ok::<_, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>(())
+        })?;
+
+        if params_specs_applied.len() != params_specs_applied_count {
+            let flow_dir = resources.borrow::<FlowDir>();
+            let storage = resources.borrow::<Storage>();
+            let params_applied_file = ParamsAppliedFile::from(&*flow_dir);
+
+            ParamsSpecsSerializer::serialize_applied(
+                &storage,
+                &params_specs_applied,
+                &params_applied_file,
+            )
+            .await?;
+        }
+
+        resources.insert(params_specs_applied);
+
+        Ok(())
+    }
 }
 
 impl<CmdCtxTypesT> Default for CleanCmd<CmdCtxTypesT> {
@@ -74,6 +74,12 @@ where
     /// For `Current` and `Goal` states, though they are discovered during the
     /// `DiffCmd` execution, they are not serialized.
     ///
+    /// The field level differences between the two states are inserted into
+    /// `resources` as a [`StatesDiffTree`], which may be presented instead of
+    /// the returned `StateDiffs`. If the states cannot be serialized, no
+    /// `StatesDiffTree` is inserted.
+    ///
+    /// [`StatesDiffTree`]: peace_resource_rt::states::StatesDiffTree
     /// [`state_diff`]: peace_cfg::Item::state_diff
     /// [`StatesDiscoverCmd::current_and_goal`]: crate::cmds::StatesDiscoverCmd::current_and_goal
     pub async fn diff<StatesTs0, StatesTs1>(
@@ -6,10 +6,11 @@ use peace_cmd::{
 };
 use peace_cmd_model::CmdOutcome;
 use peace_cmd_rt::{CmdBlockWrapper, CmdExecution};
+use peace_params::{ParamsSpecs, ParamsSpecsApplied};
 use peace_resource_rt::{
-    paths::{FlowDir, StatesCurrentFile, StatesGoalFile},
+    paths::{FlowDir, ParamsAppliedFile, StatesCurrentFile, StatesGoalFile},
     resources::ts::SetUp,
-    states::{States, StatesEnsured, StatesEnsuredDry, StatesGoal, StatesPrevious},
+    states::{States, StatesDiffTree, StatesEnsured, StatesEnsuredDry, StatesGoal, StatesPrevious},
     Resources,
 };
 use peace_rt_model::{ItemGraph, Storage};
@@ -48,6 +49,11 @@ where
     /// 2. For `Item`s that return `ApplyCheck::ExecRequired`, run
     ///    `Item::apply_exec_dry`.
     ///
+    /// The field level differences between the previous states and the dry
+    /// run's ensured states are inserted into `resources` as a
+    /// [`StatesDiffTree`], alongside the [`StatesPrevious`]. If the states
+    /// cannot be serialized, no `StatesDiffTree` is inserted.
+    ///
     /// [`apply_exec_dry`]: peace_cfg::Item::apply_exec_dry
     /// [`Item::apply_check`]: peace_cfg::Item::apply_check
     /// [`Item::apply_exec_dry`]: peace_cfg::ItemRt::apply_exec_dry
@@ -82,14 +88,24 @@ where
     {
         let cmd_outcome = Self::exec_internal(cmd_ctx, apply_stored_state_sync).await?;
 
+        let resources = cmd_ctx.view().resources;
         let cmd_outcome = cmd_outcome.map(|ensure_exec_change| match ensure_exec_change {
-            EnsureExecChange::None => Default::default(),
+            EnsureExecChange::None => {
+                // Don't leave a tree from a previous dry run in `resources`.
+                let _states_diff_tree_stale = resources.try_remove::<StatesDiffTree>();
+                Default::default()
+            }
             EnsureExecChange::Some(stateses_boxed) => {
                 let (states_previous, states_applied_dry, _states_goal) = *stateses_boxed;
-                cmd_ctx
-                    .view()
-                    .resources
-                    .insert::<StatesPrevious>(states_previous);
+                // The tree is supplementary to the dry run's states, so states
+                // that cannot be serialized do not fail the command.
+                match StatesDiffTree::new(&states_previous, &states_applied_dry) {
+                    Ok(states_diff_tree) => resources.insert::<StatesDiffTree>(states_diff_tree),
+                    Err(_error) => {
+                        let _states_diff_tree_stale = resources.try_remove::<StatesDiffTree>();
+                    }
+                }
+                resources.insert::<StatesPrevious>(states_previous);
 
                 states_applied_dry
             }
@@ -152,7 +168,10 @@ where
         let cmd_outcome = Self::exec_internal(cmd_ctx, apply_stored_state_sync).await?;
 
         let SingleProfileSingleFlowView {
-            flow, resources, ..
+            flow,
+            params_specs,
+            resources,
+            ..
         } = cmd_ctx.view();
         let (item_graph, resources) = (flow.graph(), resources);
 
@@ -166,6 +185,14 @@ where
                         let (states_previous, states_applied, states_goal) = *stateses_boxed;
                         Self::serialize_current(item_graph, resources, &states_applied).await?;
                         Self::serialize_goal(item_graph, resources, &states_goal).await?;
+                        Self::serialize_params_applied(
+                            item_graph,
+                            params_specs,
+                            resources,
+                            &states_previous,
+                            &states_applied,
+                        )
+                        .await?;
 
                         resources.insert::<StatesPrevious>(states_previous);
 
@@ -329,6 +356,73 @@ where
 
         Ok(())
     }
+
+    /// Records the params used for each item whose state was changed by this
+    /// apply.
+    ///
+    /// Items whose state did not change keep the params from their previous
+    /// apply, so that a partial or interrupted apply does not overwrite the
+    /// params of items that were not applied.
+    async fn serialize_params_applied(
+        item_graph: &ItemGraph<<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        params_specs: &ParamsSpecs,
+        resources: &mut Resources<SetUp>,
+        states_previous: &StatesPrevious,
+        states_applied: &StatesEnsured,
+    ) -> Result<(), <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError> {
+        use peace_rt_model::ParamsSpecsSerializer;
+
+        let mut params_specs_applied_previous = resources
+            .try_remove::<ParamsSpecsApplied>()
+            .unwrap_or_default();
+        let params_specs_applied = item_graph.iter_insertion().try_fold(
+            ParamsSpecsApplied::from(ParamsSpecs::with_capacity(item_graph.node_count())),
+            |mut params_specs_applied, item| {
+                let item_id = item.id();
+                let params_spec_previous = params_specs_applied_previous.shift_remove(item_id);
+                let state_changed = match (
+                    states_previous.get_raw(item_id),
+                    states_applied.get_raw(item_id),
+                ) {
+                    (Some(state_previous), Some(state_applied)) => {
+                        !item.state_eq(state_previous, state_applied)?
+                    }
+                    (None, Some(_)) => true,
+                    (_, None) => false,
+                };
+
+                match params_spec_previous {
+                    Some(params_spec_previous) if !state_changed => {
+                        params_specs_applied.insert_raw(item_id.clone(), params_spec_previous);
+                    }
+                    _ if states_applied.get_raw(item_id).is_some() => {
+                        let params_spec_applied = item.params_applied(params_specs, resources)?;
+                        params_specs_applied.insert_raw(item_id.clone(), params_spec_applied);
+                    }
+                    _ => {}
+                }
+
+                Ok::<_, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>(params_specs_applied)
+            },
+        )?;
+
+        {
+            let flow_dir = resources.borrow::<FlowDir>();
+            let storage = resources.borrow::<Storage>();
+            let params_applied_file = ParamsAppliedFile::from(&*flow_dir);
+
+            ParamsSpecsSerializer::serialize_applied(
+                &storage,
+                &params_specs_applied,
+                &params_applied_file,
+            )
+            .await?;
+        }
+
+        resources.insert(params_specs_applied);
+
+        Ok(())
+    }
 }
 
 impl<CmdCtxTypesT> Default for EnsureCmd<CmdCtxTypesT> {
@@ -0,0 +1,82 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use peace_cmd::{
+    ctx::{CmdCtx, CmdCtxTypesConstrained},
+    scopes::{SingleProfileSingleFlow, SingleProfileSingleFlowView},
+};
+use peace_params::{ParamsSpecs, ParamsSpecsStored};
+use peace_resource_rt::{resources::ts::SetUp, Resources};
+use peace_rt_model::{Flow, ParamsDiffs};
+
+/// Diffs each item's params between the stored and current params specs.
+///
+/// The stored params specs are those read from `params_specs.yaml` when the
+/// command context was built, and the current params specs are the specs
+/// provided to the command context builder, merged over the stored ones.
+pub struct ParamsDiffCmd<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+
+impl<CmdCtxTypesT> Debug for ParamsDiffCmd<CmdCtxTypesT> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ParamsDiffCmd").field(&self.0).finish()
+    }
+}
+
+impl<CmdCtxTypesT> ParamsDiffCmd<CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    /// Returns the field by field diff of each item's params.
+    ///
+    /// Both the params specs and the params values resolved from them are
+    /// compared. If no params specs were stored before the command context
+    /// was built, every field is reported as changed.
+    ///
+    /// Params that cannot be resolved are reported in that item's diff, so the
+    /// diffs of other items are still returned.
+    pub async fn exec<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+    ) -> Result<ParamsDiffs, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let SingleProfileSingleFlowView {
+            flow,
+            params_specs,
+            resources,
+            ..
+        } = cmd_ctx.view();
+
+        let params_specs_stored = resources.try_borrow::<ParamsSpecsStored>().ok();
+        let params_specs_stored = params_specs_stored
+            .as_deref()
+            .map(|params_specs_stored| &**params_specs_stored);
+
+        Self::diff_any(flow, params_specs_stored, params_specs, resources)
+    }
+
+    /// Returns the field by field diff of each item's params.
+    ///
+    /// This does not take in `CmdCtx` so that params specs from other sources
+    /// may be compared.
+    pub fn diff_any(
+        flow: &Flow<<CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        params_specs_stored: Option<&ParamsSpecs>,
+        params_specs: &ParamsSpecs,
+        resources: &Resources<SetUp>,
+    ) -> Result<ParamsDiffs, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError> {
+        flow.graph()
+            .iter_insertion()
+            .map(|item| {
+                let item_params_diff =
+                    item.params_diff(params_specs_stored, params_specs, resources)?;
+                Ok((item.id().clone(), item_params_diff))
+            })
+            .collect::<Result<ParamsDiffs, _>>()
+    }
+}
+
+impl<CmdCtxTypesT> Default for ParamsDiffCmd<CmdCtxTypesT> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
@@ -0,0 +1,108 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use peace_cfg::Profile;
+use peace_cmd::{
+    ctx::{CmdCtx, CmdCtxTypesConstrained},
+    scopes::MultiProfileNoFlow,
+};
+use peace_resource_rt::paths::ProfileDir;
+use peace_rt_model::{Error, ProfileChange};
+
+use crate::cmds::profile_storage::ProfileStorage;
+
+/// Copies a profile to a new profile.
+///
+/// This is useful to create a profile with the same params as an existing
+/// one, e.g. to create a new environment.
+pub struct ProfileCopyCmd<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+
+impl<CmdCtxTypesT> Debug for ProfileCopyCmd<CmdCtxTypesT> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ProfileCopyCmd").field(&self.0).finish()
+    }
+}
+
+impl<CmdCtxTypesT> ProfileCopyCmd<CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    /// Copies the profile's params and flow params to a new profile, without
+    /// its stored states.
+    ///
+    /// The new profile's flows have not been applied, so states are not
+    /// copied. Use [`exec_with_states`] to copy the stored states as well.
+    ///
+    /// [`exec_with_states`]: Self::exec_with_states
+    pub async fn exec<'ctx>(
+        cmd_ctx: &mut CmdCtx<MultiProfileNoFlow<'ctx, CmdCtxTypesT>>,
+        profile_from: Profile,
+        profile_to: Profile,
+    ) -> Result<ProfileChange, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        Self::exec_internal(cmd_ctx, profile_from, profile_to, false).await
+    }
+
+    /// Copies the profile, including its stored states, to a new profile.
+    ///
+    /// The new profile's states will describe the same resources as the
+    /// original profile, so cleaning one profile's flows affects the other.
+    pub async fn exec_with_states<'ctx>(
+        cmd_ctx: &mut CmdCtx<MultiProfileNoFlow<'ctx, CmdCtxTypesT>>,
+        profile_from: Profile,
+        profile_to: Profile,
+    ) -> Result<ProfileChange, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        Self::exec_internal(cmd_ctx, profile_from, profile_to, true).await
+    }
+
+    async fn exec_internal<'ctx>(
+        cmd_ctx: &mut CmdCtx<MultiProfileNoFlow<'ctx, CmdCtxTypesT>>,
+        profile_from: Profile,
+        profile_to: Profile,
+        states_copy: bool,
+    ) -> Result<ProfileChange, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let storage = cmd_ctx.workspace().storage();
+        let profiles = cmd_ctx.profiles();
+        let peace_app_dir = cmd_ctx.peace_app_dir();
+
+        let profile_dir_from = ProfileDir::from((peace_app_dir, &profile_from));
+        if !ProfileStorage::profile_exists(storage, profiles, &profile_dir_from, &profile_from)
+            .await?
+        {
+            return Err(Error::ProfileNotInScope {
+                profile: profile_from,
+                profiles_in_scope: profiles.to_vec(),
+            }
+            .into());
+        }
+        let profile_dir_to = ProfileDir::from((peace_app_dir, &profile_to));
+        if ProfileStorage::profile_exists(storage, profiles, &profile_dir_to, &profile_to).await? {
+            return Err(Error::ProfileExists {
+                profile: profile_to,
+            }
+            .into());
+        }
+
+        ProfileStorage::profile_dir_copy(storage, &profile_dir_from, &profile_dir_to, states_copy)
+            .await?;
+
+        Ok(ProfileChange::Copied {
+            profile_from,
+            profile_to,
+            states_copied: states_copy,
+        })
+    }
+}
+
+impl<CmdCtxTypesT> Default for ProfileCopyCmd<CmdCtxTypesT> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
@@ -0,0 +1,118 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use peace_cfg::Profile;
+use peace_cmd::{
+    ctx::{CmdCtx, CmdCtxTypesConstrained},
+    scopes::MultiProfileNoFlow,
+};
+use peace_resource_rt::paths::{ProfileDir, ProfileHistoryDir, ProfileParentFile};
+use peace_rt_model::{Error, ProfileChange, WorkspaceInitializer};
+
+use crate::cmds::profile_storage::ProfileStorage;
+
+/// Creates a new profile in a workspace.
+///
+/// Profile params may be written afterwards by building a
+/// `SingleProfileNoFlow` command context for the profile.
+pub struct ProfileCreateCmd<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+
+impl<CmdCtxTypesT> Debug for ProfileCreateCmd<CmdCtxTypesT> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ProfileCreateCmd").field(&self.0).finish()
+    }
+}
+
+impl<CmdCtxTypesT> ProfileCreateCmd<CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    /// Creates the profile's directories.
+    ///
+    /// An error is returned if the profile already exists. The command
+    /// context's profiles are not updated.
+    pub async fn exec<'ctx>(
+        cmd_ctx: &mut CmdCtx<MultiProfileNoFlow<'ctx, CmdCtxTypesT>>,
+        profile: Profile,
+    ) -> Result<ProfileChange, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        Self::exec_internal(cmd_ctx, profile, None).await
+    }
+
+    /// Creates the profile's directories, with `profile_parent` as its parent
+    /// profile.
+    ///
+    /// Profile params and item params specs that are not set in the new
+    /// profile are inherited from the parent profile. An error is returned if
+    /// the profile already exists, or if the parent profile does not exist.
+    pub async fn exec_with_parent<'ctx>(
+        cmd_ctx: &mut CmdCtx<MultiProfileNoFlow<'ctx, CmdCtxTypesT>>,
+        profile: Profile,
+        profile_parent: Profile,
+    ) -> Result<ProfileChange, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        Self::exec_internal(cmd_ctx, profile, Some(profile_parent)).await
+    }
+
+    async fn exec_internal<'ctx>(
+        cmd_ctx: &mut CmdCtx<MultiProfileNoFlow<'ctx, CmdCtxTypesT>>,
+        profile: Profile,
+        profile_parent: Option<Profile>,
+    ) -> Result<ProfileChange, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let storage = cmd_ctx.workspace().storage();
+        let profiles = cmd_ctx.profiles();
+        let peace_app_dir = cmd_ctx.peace_app_dir();
+
+        let profile_dir = ProfileDir::from((peace_app_dir, &profile));
+        if ProfileStorage::profile_exists(storage, profiles, &profile_dir, &profile).await? {
+            return Err(Error::ProfileExists { profile }.into());
+        }
+        if let Some(profile_parent) = profile_parent.as_ref() {
+            let profile_parent_dir = ProfileDir::from((peace_app_dir, profile_parent));
+            if !ProfileStorage::profile_exists(
+                storage,
+                profiles,
+                &profile_parent_dir,
+                profile_parent,
+            )
+            .await?
+            {
+                return Err(Error::ProfileParentNotFound {
+                    profile,
+                    profile_parent: profile_parent.clone(),
+                }
+                .into());
+            }
+        }
+
+        let profile_history_dir = ProfileHistoryDir::from(&profile_dir);
+        storage
+            .backend()
+            .dirs_create(&[&profile_dir, &profile_history_dir])
+            .await?;
+
+        if let Some(profile_parent) = profile_parent.as_ref() {
+            let profile_parent_file = ProfileParentFile::from(&profile_dir);
+            WorkspaceInitializer::profile_parent_serialize(
+                storage,
+                profile_parent,
+                &profile_parent_file,
+            )
+            .await?;
+        }
+
+        Ok(ProfileChange::Created { profile })
+    }
+}
+
+impl<CmdCtxTypesT> Default for ProfileCreateCmd<CmdCtxTypesT> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
@@ -0,0 +1,112 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use peace_cfg::Profile;
+use peace_cmd::{
+    ctx::{CmdCtx, CmdCtxTypesConstrained},
+    scopes::MultiProfileNoFlow,
+};
+use peace_resource_rt::paths::ProfileDir;
+use peace_rt_model::{Error, ProfileChange};
+
+use crate::cmds::profile_storage::ProfileStorage;
+
+/// Deletes a profile.
+pub struct ProfileDeleteCmd<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+
+impl<CmdCtxTypesT> Debug for ProfileDeleteCmd<CmdCtxTypesT> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ProfileDeleteCmd").field(&self.0).finish()
+    }
+}
+
+impl<CmdCtxTypesT> ProfileDeleteCmd<CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    /// Deletes the profile if it has no stored states.
+    ///
+    /// If the profile has stored states, [`Error::ProfileStatesExist`] is
+    /// returned, as deleting them would lose track of the resources they
+    /// describe. Use [`exec_with_states`] to delete the profile regardless.
+    ///
+    /// If other profiles inherit from this profile,
+    /// [`Error::ProfileParentOfProfiles`] is returned.
+    ///
+    /// [`exec_with_states`]: Self::exec_with_states
+    pub async fn exec<'ctx>(
+        cmd_ctx: &mut CmdCtx<MultiProfileNoFlow<'ctx, CmdCtxTypesT>>,
+        profile: Profile,
+    ) -> Result<ProfileChange, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        Self::exec_internal(cmd_ctx, profile, false).await
+    }
+
+    /// Deletes the profile, including its stored states.
+    ///
+    /// If other profiles inherit from this profile,
+    /// [`Error::ProfileParentOfProfiles`] is returned.
+    pub async fn exec_with_states<'ctx>(
+        cmd_ctx: &mut CmdCtx<MultiProfileNoFlow<'ctx, CmdCtxTypesT>>,
+        profile: Profile,
+    ) -> Result<ProfileChange, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        Self::exec_internal(cmd_ctx, profile, true).await
+    }
+
+    async fn exec_internal<'ctx>(
+        cmd_ctx: &mut CmdCtx<MultiProfileNoFlow<'ctx, CmdCtxTypesT>>,
+        profile: Profile,
+        states_delete: bool,
+    ) -> Result<ProfileChange, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let storage = cmd_ctx.workspace().storage();
+        let profiles = cmd_ctx.profiles();
+
+        let profile_dir = ProfileDir::from((cmd_ctx.peace_app_dir(), &profile));
+        if !ProfileStorage::profile_exists(storage, profiles, &profile_dir, &profile).await? {
+            return Err(Error::ProfileNotInScope {
+                profile,
+                profiles_in_scope: profiles.to_vec(),
+            }
+            .into());
+        }
+
+        let profiles_child =
+            ProfileStorage::profiles_child(storage, cmd_ctx.peace_app_dir(), &profile).await?;
+        if !profiles_child.is_empty() {
+            return Err(Error::ProfileParentOfProfiles {
+                profile,
+                profiles_child,
+            }
+            .into());
+        }
+
+        if !states_delete {
+            let states_file_paths =
+                ProfileStorage::states_file_paths(storage, &profile_dir).await?;
+            if !states_file_paths.is_empty() {
+                return Err(Error::ProfileStatesExist {
+                    profile,
+                    states_file_paths,
+                }
+                .into());
+            }
+        }
+
+        storage.backend().dir_remove(&profile_dir).await?;
+
+        Ok(ProfileChange::Deleted { profile })
+    }
+}
+
+impl<CmdCtxTypesT> Default for ProfileDeleteCmd<CmdCtxTypesT> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
@@ -0,0 +1,40 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use peace_cfg::Profile;
+use peace_cmd::{
+    ctx::{CmdCtx, CmdCtxTypesConstrained},
+    scopes::MultiProfileNoFlow,
+};
+
+/// Lists the profiles in a workspace.
+pub struct ProfileListCmd<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+
+impl<CmdCtxTypesT> Debug for ProfileListCmd<CmdCtxTypesT> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ProfileListCmd").field(&self.0).finish()
+    }
+}
+
+impl<CmdCtxTypesT> ProfileListCmd<CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    /// Returns the profiles in the workspace, in sorted order.
+    ///
+    /// These are the profiles that pass the profile filter function, if one
+    /// was provided to the command context builder.
+    pub async fn exec<'ctx>(
+        cmd_ctx: &mut CmdCtx<MultiProfileNoFlow<'ctx, CmdCtxTypesT>>,
+    ) -> Result<Vec<Profile>, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        Ok(cmd_ctx.profiles().to_vec())
+    }
+}
+
+impl<CmdCtxTypesT> Default for ProfileListCmd<CmdCtxTypesT> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
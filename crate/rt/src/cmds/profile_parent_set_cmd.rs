@@ -0,0 +1,130 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use peace_cfg::Profile;
+use peace_cmd::{
+    ctx::{CmdCtx, CmdCtxTypesConstrained},
+    scopes::MultiProfileNoFlow,
+};
+use peace_resource_rt::paths::{ProfileDir, ProfileParentFile};
+use peace_rt_model::{Error, ProfileChange, WorkspaceInitializer};
+
+use crate::cmds::profile_storage::ProfileStorage;
+
+/// Sets or removes a profile's parent profile.
+///
+/// Profile params and item params specs that are not set in a profile are
+/// inherited from its parent profile.
+pub struct ProfileParentSetCmd<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+
+impl<CmdCtxTypesT> Debug for ProfileParentSetCmd<CmdCtxTypesT> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ProfileParentSetCmd").field(&self.0).finish()
+    }
+}
+
+impl<CmdCtxTypesT> ProfileParentSetCmd<CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    /// Sets the profile's parent profile, or removes it if `profile_parent`
+    /// is `None`.
+    ///
+    /// An error is returned if either profile does not exist, or if the
+    /// parent profile is the profile itself or one of its descendants.
+    pub async fn exec<'ctx>(
+        cmd_ctx: &mut CmdCtx<MultiProfileNoFlow<'ctx, CmdCtxTypesT>>,
+        profile: Profile,
+        profile_parent: Option<Profile>,
+    ) -> Result<ProfileChange, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let storage = cmd_ctx.workspace().storage();
+        let profiles = cmd_ctx.profiles();
+        let peace_app_dir = cmd_ctx.peace_app_dir();
+
+        let profile_dir = ProfileDir::from((peace_app_dir, &profile));
+        if !ProfileStorage::profile_exists(storage, profiles, &profile_dir, &profile).await? {
+            return Err(Error::ProfileNotInScope {
+                profile,
+                profiles_in_scope: profiles.to_vec(),
+            }
+            .into());
+        }
+
+        let profile_parent_file = ProfileParentFile::from(&profile_dir);
+        match profile_parent.as_ref() {
+            Some(profile_parent) => {
+                let profile_parent_dir = ProfileDir::from((peace_app_dir, profile_parent));
+                if !ProfileStorage::profile_exists(
+                    storage,
+                    profiles,
+                    &profile_parent_dir,
+                    profile_parent,
+                )
+                .await?
+                {
+                    return Err(Error::ProfileParentNotFound {
+                        profile,
+                        profile_parent: profile_parent.clone(),
+                    }
+                    .into());
+                }
+
+                // Follow the parent's ancestors to make sure they don't lead back to
+                // `profile`.
+                let mut profiles_cycle = vec![profile.clone(), profile_parent.clone()];
+                let mut profile_current = profile_parent.clone();
+                while profile_current != profile {
+                    let profile_current_dir = ProfileDir::from((peace_app_dir, &profile_current));
+                    let profile_current_parent = WorkspaceInitializer::profile_parent_deserialize(
+                        storage,
+                        &ProfileParentFile::from(&profile_current_dir),
+                    )
+                    .await?;
+                    match profile_current_parent {
+                        // An existing cycle that does not include `profile`.
+                        Some(profile_current_parent)
+                            if profiles_cycle[1..].contains(&profile_current_parent) =>
+                        {
+                            break
+                        }
+                        Some(profile_current_parent) => {
+                            profiles_cycle.push(profile_current_parent.clone());
+                            profile_current = profile_current_parent;
+                        }
+                        None => break,
+                    }
+                }
+                if profile_current == profile {
+                    return Err(Error::ProfileParentCycle {
+                        profile,
+                        profiles_cycle,
+                    }
+                    .into());
+                }
+
+                WorkspaceInitializer::profile_parent_serialize(
+                    storage,
+                    profile_parent,
+                    &profile_parent_file,
+                )
+                .await?;
+            }
+            None => {
+                WorkspaceInitializer::profile_parent_remove(storage, &profile_parent_file).await?;
+            }
+        }
+
+        Ok(ProfileChange::ParentSet {
+            profile,
+            profile_parent,
+        })
+    }
+}
+
+impl<CmdCtxTypesT> Default for ProfileParentSetCmd<CmdCtxTypesT> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
@@ -0,0 +1,90 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use peace_cfg::Profile;
+use peace_cmd::{
+    ctx::{CmdCtx, CmdCtxTypesConstrained},
+    scopes::MultiProfileNoFlow,
+};
+use peace_resource_rt::paths::{ProfileDir, ProfileParentFile};
+use peace_rt_model::{Error, ProfileChange, WorkspaceInitializer};
+
+use crate::cmds::profile_storage::ProfileStorage;
+
+/// Renames a profile.
+pub struct ProfileRenameCmd<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+
+impl<CmdCtxTypesT> Debug for ProfileRenameCmd<CmdCtxTypesT> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ProfileRenameCmd").field(&self.0).finish()
+    }
+}
+
+impl<CmdCtxTypesT> ProfileRenameCmd<CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    /// Renames the profile, keeping its params and stored states.
+    ///
+    /// Profiles whose parent is the renamed profile are updated to use the new
+    /// name. An error is returned if the profile does not exist, or if a
+    /// profile with the new name already exists. Workspace params that refer
+    /// to the previous profile name are not updated.
+    pub async fn exec<'ctx>(
+        cmd_ctx: &mut CmdCtx<MultiProfileNoFlow<'ctx, CmdCtxTypesT>>,
+        profile_from: Profile,
+        profile_to: Profile,
+    ) -> Result<ProfileChange, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let storage = cmd_ctx.workspace().storage();
+        let profiles = cmd_ctx.profiles();
+        let peace_app_dir = cmd_ctx.peace_app_dir();
+
+        let profile_dir_from = ProfileDir::from((peace_app_dir, &profile_from));
+        if !ProfileStorage::profile_exists(storage, profiles, &profile_dir_from, &profile_from)
+            .await?
+        {
+            return Err(Error::ProfileNotInScope {
+                profile: profile_from,
+                profiles_in_scope: profiles.to_vec(),
+            }
+            .into());
+        }
+        let profile_dir_to = ProfileDir::from((peace_app_dir, &profile_to));
+        if ProfileStorage::profile_exists(storage, profiles, &profile_dir_to, &profile_to).await? {
+            return Err(Error::ProfileExists {
+                profile: profile_to,
+            }
+            .into());
+        }
+
+        let profiles_child =
+            ProfileStorage::profiles_child(storage, peace_app_dir, &profile_from).await?;
+
+        ProfileStorage::profile_dir_copy(storage, &profile_dir_from, &profile_dir_to, true).await?;
+        storage.backend().dir_remove(&profile_dir_from).await?;
+
+        for profile_child in profiles_child {
+            let profile_child_dir = ProfileDir::from((peace_app_dir, &profile_child));
+            let profile_parent_file = ProfileParentFile::from(&profile_child_dir);
+            WorkspaceInitializer::profile_parent_serialize(
+                storage,
+                &profile_to,
+                &profile_parent_file,
+            )
+            .await?;
+        }
+
+        Ok(ProfileChange::Renamed {
+            profile_from,
+            profile_to,
+        })
+    }
+}
+
+impl<CmdCtxTypesT> Default for ProfileRenameCmd<CmdCtxTypesT> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
@@ -0,0 +1,191 @@
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use peace_cfg::Profile;
+use peace_resource_rt::paths::{
+    ParamsAppliedFile, PeaceAppDir, ProfileDir, ProfileParentFile, StatesCurrentFile,
+    StatesGoalFile,
+};
+use peace_rt_model::{
+    Error, SerializationFormat, StatesLayout, Storage, StorageEncryption, WorkspaceInitializer,
+};
+
+/// Reads and writes whole profile directories for the profile commands.
+#[derive(Debug)]
+pub(crate) struct ProfileStorage;
+
+impl ProfileStorage {
+    /// Returns whether the profile exists, either in the command context's
+    /// profiles, or in storage.
+    pub(crate) async fn profile_exists(
+        storage: &Storage,
+        profiles: &[Profile],
+        profile_dir: &ProfileDir,
+        profile: &Profile,
+    ) -> Result<bool, Error> {
+        if profiles.contains(profile) {
+            return Ok(true);
+        }
+
+        let backend = storage.backend();
+        if backend.contains_item(profile_dir).await? {
+            return Ok(true);
+        }
+        let paths = backend.paths_under(profile_dir).await?;
+        Ok(!paths.is_empty())
+    }
+
+    /// Returns the paths of the stored states files in the profile directory.
+    ///
+    /// `params_applied.yaml` is included, as it records the params used to
+    /// create the resources that the states describe.
+    pub(crate) async fn states_file_paths(
+        storage: &Storage,
+        profile_dir: &ProfileDir,
+    ) -> Result<Vec<PathBuf>, Error> {
+        let paths = storage.backend().paths_under(profile_dir).await?;
+        let states_file_paths = paths
+            .into_iter()
+            .filter(|path| {
+                path.strip_prefix(profile_dir)
+                    .map(Self::is_states_path)
+                    .unwrap_or(false)
+            })
+            .collect::<Vec<PathBuf>>();
+
+        Ok(states_file_paths)
+    }
+
+    /// Returns the profiles whose parent profile is `profile`.
+    ///
+    /// All profiles in the workspace are searched, regardless of the command
+    /// context's profiles.
+    pub(crate) async fn profiles_child(
+        storage: &Storage,
+        peace_app_dir: &PeaceAppDir,
+        profile: &Profile,
+    ) -> Result<Vec<Profile>, Error> {
+        let paths = storage.backend().paths_under(peace_app_dir).await?;
+        let mut profiles_child = Vec::new();
+        for path in paths {
+            let Ok(path_relative) = path.strip_prefix(peace_app_dir) else {
+                continue;
+            };
+            // `$profile/profile_parent.yaml`
+            let mut components = path_relative.components();
+            let (Some(profile_dir_name), Some(file_name), None) =
+                (components.next(), components.next(), components.next())
+            else {
+                continue;
+            };
+            if !SerializationFormat::is_file_path_of(
+                Path::new(file_name.as_os_str()),
+                Path::new(ProfileParentFile::NAME),
+            ) {
+                continue;
+            }
+            let Some(profile_child) = profile_dir_name
+                .as_os_str()
+                .to_str()
+                .and_then(|profile_child| Profile::from_str(profile_child).ok())
+            else {
+                continue;
+            };
+
+            let profile_dir = ProfileDir::from((peace_app_dir, &profile_child));
+            let profile_parent_file = ProfileParentFile::from(&profile_dir);
+            let profile_parent =
+                WorkspaceInitializer::profile_parent_deserialize(storage, &profile_parent_file)
+                    .await?;
+            if profile_parent.as_ref() == Some(profile) {
+                profiles_child.push(profile_child);
+            }
+        }
+        profiles_child.sort();
+
+        Ok(profiles_child)
+    }
+
+    /// Copies the items in one profile directory to another.
+    ///
+    /// Stored states are only copied if `states_copy` is `true`. Encrypted
+    /// files are re-encrypted for their new path.
+    pub(crate) async fn profile_dir_copy(
+        storage: &Storage,
+        profile_dir_from: &ProfileDir,
+        profile_dir_to: &ProfileDir,
+        states_copy: bool,
+    ) -> Result<(), Error> {
+        let backend = storage.backend();
+        backend.dirs_create(&[profile_dir_to]).await?;
+
+        let paths = backend.paths_under(profile_dir_from).await?;
+        for path in paths {
+            let Ok(path_relative) = path.strip_prefix(profile_dir_from) else {
+                continue;
+            };
+            if !states_copy && Self::is_states_path(path_relative) {
+                continue;
+            }
+            let Some(bytes) = backend.read_opt(&path).await? else {
+                continue;
+            };
+
+            let path_to = profile_dir_to.join(path_relative);
+
+            // Encrypted files are bound to their path, so they are re-encrypted
+            // for the path they are copied to.
+            let bytes = match storage.encryption() {
+                Some(encryption) if StorageEncryption::is_encrypted(&bytes) => {
+                    let plaintext = encryption.decrypt(&path, &bytes)?;
+                    encryption.encrypt(&path_to, &plaintext)?
+                }
+                _ => bytes,
+            };
+
+            if let Some(parent) = path_to.parent() {
+                backend.dirs_create(&[parent]).await?;
+            }
+            backend.write(&path_to, &bytes).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns whether a path relative to a profile directory is a stored
+    /// states file.
+    ///
+    /// Both the single file and per item states layouts are matched, e.g.
+    /// `flow/states_current.yaml` and `flow/states_current/item.yaml`, in
+    /// every serialization format.
+    fn is_states_path(path_relative: &Path) -> bool {
+        let states_file_names = [
+            StatesCurrentFile::NAME,
+            StatesGoalFile::NAME,
+            ParamsAppliedFile::NAME,
+        ];
+        let is_states_file = path_relative.file_name().is_some_and(|file_name| {
+            states_file_names.into_iter().any(|states_file_name| {
+                SerializationFormat::is_file_path_of(
+                    Path::new(file_name),
+                    Path::new(states_file_name),
+                )
+            })
+        });
+
+        let is_in_item_states_dir = path_relative.ancestors().skip(1).any(|ancestor| {
+            ancestor.file_name().is_some_and(|dir_name| {
+                [StatesCurrentFile::NAME, StatesGoalFile::NAME]
+                    .into_iter()
+                    .map(|states_file_name| {
+                        StatesLayout::item_states_dir(Path::new(states_file_name))
+                    })
+                    .any(|item_states_dir| item_states_dir.as_os_str() == dir_name)
+            })
+        });
+
+        is_states_file || is_in_item_states_dir
+    }
+}
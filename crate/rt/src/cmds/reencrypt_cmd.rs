@@ -0,0 +1,182 @@
+use std::{
+    fmt::Debug,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+
+use peace_cmd::{
+    ctx::{CmdCtx, CmdCtxTypesConstrained},
+    scopes::SingleProfileSingleFlow,
+};
+use peace_resource_rt::paths::{
+    FlowDir, ParamsAppliedFile, ParamsSpecsFile, StatesCurrentFile, StatesGoalFile,
+};
+use peace_rt_model::{
+    EncryptionKeyProvider, Error, SerializationFormat, StatesLayout, StorageEncryption,
+};
+
+/// Re-encrypts the stored states and params specs of every profile and flow
+/// with a new key.
+///
+/// This is used to rotate the encryption key. The files are decrypted using
+/// the key provider set on the command context's `Workspace`, and encrypted
+/// using the new key provider. Files that are not encrypted, such as files
+/// stored before encryption was enabled, are encrypted with the new key.
+///
+/// All files are decrypted and re-encrypted before any are written, so if the
+/// current key is wrong, none of the files are changed. Each file is then
+/// written to a temporary path, and renamed over the original file once all
+/// files are written.
+///
+/// Commands that run after this must use a `Workspace` with the new key
+/// provider.
+pub struct ReencryptCmd<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+
+impl<CmdCtxTypesT> Debug for ReencryptCmd<CmdCtxTypesT> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ReencryptCmd").field(&self.0).finish()
+    }
+}
+
+impl<CmdCtxTypesT> ReencryptCmd<CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    /// Extension appended to a file's path while its re-encrypted bytes are
+    /// written, before it is renamed over the file.
+    const TEMP_EXTENSION: &'static str = "reencrypt";
+
+    /// Re-encrypts the stored files of every profile and flow with the new
+    /// key, and returns the paths of the files that were re-encrypted.
+    ///
+    /// The re-encrypted files are the files in each flow directory under the
+    /// `PeaceAppDir` that are written encrypted:
+    ///
+    /// * `states_current.yaml` and `states_goal.yaml`, and each item's states
+    ///   file in the `states_current` and `states_goal` directories.
+    /// * `params_specs.yaml` and `params_applied.yaml`.
+    ///
+    /// Files are listed through the storage backend, regardless of the
+    /// current [`StatesLayout`], so states stored with either layout are
+    /// re-encrypted.
+    pub async fn exec<'ctx, P>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+        key_provider_new: P,
+    ) -> Result<Vec<PathBuf>, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+        P: EncryptionKeyProvider,
+    {
+        let peace_app_dir = cmd_ctx.peace_app_dir();
+        let storage = cmd_ctx.workspace().storage();
+        let backend = storage.backend();
+        let file_paths = backend
+            .paths_under(peace_app_dir)
+            .await?
+            .into_iter()
+            .filter(|path| Self::is_encrypted_file(peace_app_dir, path))
+            .collect::<Vec<_>>();
+
+        let mut files_plaintext = Vec::with_capacity(file_paths.len());
+        for file_path in file_paths {
+            let Some(bytes) = backend.read_opt(&file_path).await? else {
+                continue;
+            };
+            let plaintext =
+                StorageEncryption::decrypt_if_has_header(storage.encryption(), &file_path, bytes)
+                    .map_err(Error::Encryption)?;
+            files_plaintext.push((file_path, plaintext));
+        }
+
+        let encryption_new = StorageEncryption::new(key_provider_new);
+        let files_reencrypted = files_plaintext
+            .into_iter()
+            .map(|(file_path, plaintext)| {
+                encryption_new
+                    .encrypt(&file_path, &plaintext)
+                    .map(|bytes| (file_path, bytes))
+                    .map_err(Error::Encryption)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Write every file to its temporary path before replacing any of the
+        // stored files, and clean up if any write fails.
+        let mut file_paths_temp = Vec::with_capacity(files_reencrypted.len());
+        for (file_path, bytes) in &files_reencrypted {
+            let file_path_temp = Self::file_path_temp(file_path);
+            if let Err(error) = backend.write(&file_path_temp, bytes).await {
+                file_paths_temp.push(file_path_temp);
+                for file_path_temp in &file_paths_temp {
+                    // Best effort, the write error is the one to report.
+                    let _ = backend.remove(file_path_temp).await;
+                }
+                return Err(error.into());
+            }
+            file_paths_temp.push(file_path_temp);
+        }
+
+        let mut file_paths_reencrypted = Vec::with_capacity(files_reencrypted.len());
+        for ((file_path, _bytes), file_path_temp) in
+            files_reencrypted.into_iter().zip(file_paths_temp)
+        {
+            backend.rename(&file_path_temp, &file_path).await?;
+            file_paths_reencrypted.push(file_path);
+        }
+
+        Ok(file_paths_reencrypted)
+    }
+
+    /// Returns whether `path` is a file that is written encrypted, i.e. a
+    /// states or params specs file in a flow directory.
+    ///
+    /// Flow directories are `$peace_app_dir/$profile/$flow_id`.
+    fn is_encrypted_file(peace_app_dir: &Path, path: &Path) -> bool {
+        let Ok(path_relative) = path.strip_prefix(peace_app_dir) else {
+            return false;
+        };
+        if SerializationFormat::from_file_path(path).is_none() {
+            return false;
+        }
+
+        // `$profile/$flow_id/$file` or `$profile/$flow_id/$states_dir/$item_file`
+        let flow_dir = match path_relative.components().count() {
+            3 => path.parent(),
+            4 => path.parent().and_then(Path::parent),
+            _ => None,
+        };
+        let Some(flow_dir) = flow_dir.map(|dir| FlowDir::new(dir.to_path_buf())) else {
+            return false;
+        };
+
+        let states_file_paths = [
+            StatesCurrentFile::from(&flow_dir).into_inner(),
+            StatesGoalFile::from(&flow_dir).into_inner(),
+        ];
+        let params_file_paths = [
+            ParamsSpecsFile::from(&flow_dir).into_inner(),
+            ParamsAppliedFile::from(&flow_dir).into_inner(),
+        ];
+        states_file_paths
+            .iter()
+            .chain(params_file_paths.iter())
+            .any(|file_path| SerializationFormat::is_file_path_of(path, file_path))
+            || states_file_paths.iter().any(|states_file_path| {
+                path.parent() == Some(StatesLayout::item_states_dir(states_file_path).as_path())
+            })
+    }
+
+    /// Returns the temporary path that a file's re-encrypted bytes are written
+    /// to, e.g. `states_current.yaml.reencrypt`.
+    fn file_path_temp(file_path: &Path) -> PathBuf {
+        let mut file_path_temp = file_path.as_os_str().to_owned();
+        file_path_temp.push(".");
+        file_path_temp.push(Self::TEMP_EXTENSION);
+        PathBuf::from(file_path_temp)
+    }
+}
+
+impl<CmdCtxTypesT> Default for ReencryptCmd<CmdCtxTypesT> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
@@ -1,5 +1,6 @@
 use std::{fmt::Debug, marker::PhantomData};
 
+use peace_cfg::ItemId;
 use peace_cmd::{
     ctx::{CmdCtx, CmdCtxTypesConstrained},
     scopes::SingleProfileSingleFlow,
@@ -33,9 +34,44 @@ where
     where
         CmdCtxTypesT: 'ctx,
     {
-        let cmd_execution_builder = CmdExecution::<StatesGoalStored, _>::builder().with_cmd_block(
-            CmdBlockWrapper::new(StatesGoalReadCmdBlock::new(), std::convert::identity),
-        );
+        Self::exec_internal(cmd_ctx, StatesGoalReadCmdBlock::new()).await
+    }
+
+    /// Reads [`StatesGoalStored`]s of the given items from storage.
+    ///
+    /// With [`StatesLayout::PerItem`], the other items' state files are not
+    /// read.
+    ///
+    /// [`StatesLayout::PerItem`]: peace_rt_model::StatesLayout::PerItem
+    pub async fn exec_with_item_ids<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+        item_ids: Vec<ItemId>,
+    ) -> Result<
+        CmdOutcome<StatesGoalStored, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+    >
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        Self::exec_internal(
+            cmd_ctx,
+            StatesGoalReadCmdBlock::new().with_item_ids(item_ids),
+        )
+        .await
+    }
+
+    async fn exec_internal<'ctx>(
+        cmd_ctx: &mut CmdCtx<SingleProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+        cmd_block: StatesGoalReadCmdBlock<CmdCtxTypesT>,
+    ) -> Result<
+        CmdOutcome<StatesGoalStored, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>,
+        <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError,
+    >
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let cmd_execution_builder = CmdExecution::<StatesGoalStored, _>::builder()
+            .with_cmd_block(CmdBlockWrapper::new(cmd_block, std::convert::identity));
 
         #[cfg(feature = "output_progress")]
         let cmd_execution_builder = cmd_execution_builder.with_progress_render_enabled(false);
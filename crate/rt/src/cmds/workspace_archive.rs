@@ -0,0 +1,308 @@
+use std::{
+    io::Read,
+    path::{Component, Path, PathBuf},
+};
+
+use peace_cfg::{AppName, FlowId, Profile};
+use peace_resource_rt::paths::{PeaceAppDir, PeaceDir, ProfileDir, ProfileParentFile};
+use peace_rt_model::{
+    Error, SerializationError, Storage, WorkspaceArchiveManifest, WorkspaceArchiveProfile,
+    WorkspaceInitializer,
+};
+
+/// Reads and writes workspace archives for the workspace export and import
+/// commands.
+///
+/// Files are stored in the archive at the same path relative to the
+/// workspace directory, e.g. `.peace/envman/dev/profile_params.yaml`, and
+/// are copied as is, so encrypted files remain encrypted.
+#[derive(Debug)]
+pub(crate) struct WorkspaceArchive;
+
+/// A file read from a workspace archive.
+#[derive(Debug)]
+pub(crate) struct WorkspaceArchiveEntry {
+    /// Path of the file relative to the `.peace/$app` directory.
+    pub(crate) path_relative: PathBuf,
+    /// Contents of the file.
+    pub(crate) bytes: Vec<u8>,
+}
+
+impl WorkspaceArchive {
+    /// Returns the profiles to export for `profile`, which are the profile
+    /// and its ancestors, starting with the profile.
+    pub(crate) async fn profile_with_ancestors(
+        storage: &Storage,
+        peace_app_dir: &PeaceAppDir,
+        profile: Profile,
+    ) -> Result<Vec<Profile>, Error> {
+        let mut profiles = vec![profile];
+        loop {
+            let profile = profiles.last().expect("`profiles` is never empty.");
+            let profile_dir = ProfileDir::from((peace_app_dir, profile));
+            let profile_parent_file = ProfileParentFile::from(&profile_dir);
+            let profile_parent =
+                WorkspaceInitializer::profile_parent_deserialize(storage, &profile_parent_file)
+                    .await?;
+            match profile_parent {
+                Some(profile_parent) if !profiles.contains(&profile_parent) => {
+                    profiles.push(profile_parent);
+                }
+                _ => break,
+            }
+        }
+
+        Ok(profiles)
+    }
+
+    /// Writes the workspace files and the given profiles' files to an
+    /// archive.
+    ///
+    /// Files directly in the `.peace/$app` directory, such as workspace
+    /// params, are always included. Each profile's execution history is not
+    /// included.
+    pub(crate) async fn write(
+        storage: &Storage,
+        app_name: &AppName,
+        peace_app_dir: &PeaceAppDir,
+        profiles: &[Profile],
+    ) -> Result<Vec<u8>, Error> {
+        let backend = storage.backend();
+        let paths = backend.paths_under(peace_app_dir).await?;
+
+        let mut entries = Vec::<(PathBuf, Vec<u8>)>::with_capacity(paths.len());
+        let mut archive_profiles = profiles
+            .iter()
+            .map(|profile| WorkspaceArchiveProfile {
+                profile: profile.clone(),
+                flow_ids: Vec::new(),
+            })
+            .collect::<Vec<WorkspaceArchiveProfile>>();
+        for path in paths {
+            let Ok(path_relative) = path.strip_prefix(peace_app_dir) else {
+                continue;
+            };
+            let mut components = path_relative.components();
+            let Some(first) = components.next() else {
+                continue;
+            };
+
+            // `$profile/..`
+            if let Some(second) = components.next() {
+                let Some(archive_profile) = archive_profiles
+                    .iter_mut()
+                    .find(|archive_profile| first.as_os_str() == archive_profile.profile.as_str())
+                else {
+                    continue;
+                };
+                if Self::is_hidden(second) {
+                    continue;
+                }
+
+                // `$profile/$flow_id/..`
+                if components.next().is_some() {
+                    let Some(flow_id) = second
+                        .as_os_str()
+                        .to_str()
+                        .and_then(|flow_id| flow_id.parse::<FlowId>().ok())
+                    else {
+                        continue;
+                    };
+                    if !archive_profile.flow_ids.contains(&flow_id) {
+                        archive_profile.flow_ids.push(flow_id);
+                    }
+                }
+            }
+
+            let Some(bytes) = backend.read_opt(&path).await? else {
+                continue;
+            };
+            entries.push((path_relative.to_path_buf(), bytes));
+        }
+
+        let manifest = WorkspaceArchiveManifest::new(app_name.clone(), archive_profiles);
+        let manifest_bytes = serde_yaml::to_string(&manifest)
+            .map_err(SerializationError::Yaml)
+            .map_err(Error::WorkspaceArchiveManifestSerialize)?
+            .into_bytes();
+
+        let archive_app_dir = Path::new(PeaceDir::NAME).join(app_name.as_str());
+        let mut builder = tar::Builder::new(Vec::new());
+        Self::entry_append(
+            &mut builder,
+            Path::new(WorkspaceArchiveManifest::PATH),
+            &manifest_bytes,
+        )
+        .map_err(Error::WorkspaceArchiveWrite)?;
+        for (path_relative, bytes) in entries {
+            Self::entry_append(&mut builder, &archive_app_dir.join(path_relative), &bytes)
+                .map_err(Error::WorkspaceArchiveWrite)?;
+        }
+
+        builder.into_inner().map_err(Error::WorkspaceArchiveWrite)
+    }
+
+    /// Reads the manifest and files from an archive.
+    ///
+    /// The manifest is validated against `app_name`, but not against the
+    /// registered flows, and each manifest profile must be a valid
+    /// [`Profile`]. Each file must be directly in the `.peace/$app`
+    /// directory, or in a profile directory of a profile in the manifest, so
+    /// that importing the archive does not write to other profiles. Files in
+    /// a flow directory must be in one of that profile's manifest flows.
+    ///
+    /// The error is boxed, as `Error` is large.
+    pub(crate) fn read(
+        app_name: &AppName,
+        archive_bytes: &[u8],
+    ) -> Result<(WorkspaceArchiveManifest, Vec<WorkspaceArchiveEntry>), Box<Error>> {
+        let (manifest_bytes, entries) =
+            Self::entries_read(archive_bytes).map_err(Error::WorkspaceArchiveRead)?;
+
+        let manifest_bytes = manifest_bytes.ok_or(Error::WorkspaceArchiveManifestNotFound)?;
+        let manifest = serde_yaml::from_slice::<WorkspaceArchiveManifest>(&manifest_bytes)
+            .map_err(SerializationError::Yaml)
+            .map_err(Error::WorkspaceArchiveManifestDeserialize)?;
+
+        if manifest.archive_version != WorkspaceArchiveManifest::ARCHIVE_VERSION {
+            return Err(Box::new(Error::WorkspaceArchiveVersionUnsupported {
+                archive_version: manifest.archive_version,
+                archive_version_supported: WorkspaceArchiveManifest::ARCHIVE_VERSION,
+            }));
+        }
+        if &manifest.app_name != app_name {
+            return Err(Box::new(Error::WorkspaceArchiveAppNameMismatch {
+                app_name_archive: manifest.app_name,
+                app_name_workspace: app_name.clone(),
+            }));
+        }
+
+        // `Profile`s are deserialized without validation, and are used as directory
+        // names when importing.
+        if let Some(archive_profile) = manifest
+            .profiles
+            .iter()
+            .find(|archive_profile| !Profile::is_valid_id(archive_profile.profile.as_str()))
+        {
+            return Err(Box::new(Error::WorkspaceArchiveProfileInvalid {
+                profile: archive_profile.profile.as_str().to_string(),
+            }));
+        }
+
+        let archive_app_dir = Path::new(PeaceDir::NAME).join(app_name.as_str());
+        let mut archive_entries = Vec::with_capacity(entries.len());
+        for (path, bytes) in entries {
+            let Some(path_relative) = path
+                .strip_prefix(&archive_app_dir)
+                .ok()
+                .filter(|path_relative| {
+                    path_relative.components().next().is_some()
+                        && path_relative
+                            .components()
+                            .all(|component| matches!(component, Component::Normal(_)))
+                })
+                .map(Path::to_path_buf)
+            else {
+                return Err(Box::new(Error::WorkspaceArchiveEntryPathInvalid { path }));
+            };
+
+            // `$profile/..`
+            let mut components = path_relative.components();
+            if let (Some(first), Some(second)) = (components.next(), components.next()) {
+                let Some(archive_profile) = manifest
+                    .profiles
+                    .iter()
+                    .find(|archive_profile| first.as_os_str() == archive_profile.profile.as_str())
+                else {
+                    let profile = first.as_os_str().to_string_lossy().into_owned();
+                    let profiles_manifest = manifest
+                        .profiles
+                        .iter()
+                        .map(|archive_profile| archive_profile.profile.clone())
+                        .collect::<Vec<Profile>>();
+                    return Err(Box::new(Error::WorkspaceArchiveEntryProfileUnknown {
+                        path,
+                        profile,
+                        profiles_manifest,
+                    }));
+                };
+
+                // `$profile/$flow_id/..`
+                if components.next().is_some() {
+                    let is_manifest_flow_id = archive_profile
+                        .flow_ids
+                        .iter()
+                        .any(|flow_id| second.as_os_str() == flow_id.as_str());
+                    if !is_manifest_flow_id {
+                        let flow_id = second.as_os_str().to_string_lossy().into_owned();
+                        let flow_ids_manifest = archive_profile.flow_ids.clone();
+                        return Err(Box::new(Error::WorkspaceArchiveEntryFlowIdUnknown {
+                            path,
+                            profile: archive_profile.profile.clone(),
+                            flow_id,
+                            flow_ids_manifest,
+                        }));
+                    }
+                }
+            }
+
+            archive_entries.push(WorkspaceArchiveEntry {
+                path_relative,
+                bytes,
+            });
+        }
+
+        Ok((manifest, archive_entries))
+    }
+
+    /// Reads the manifest's bytes, and the path and bytes of each other file
+    /// in an archive.
+    #[allow(clippy::type_complexity)]
+    fn entries_read(
+        archive_bytes: &[u8],
+    ) -> Result<(Option<Vec<u8>>, Vec<(PathBuf, Vec<u8>)>), std::io::Error> {
+        let mut archive = tar::Archive::new(archive_bytes);
+        let mut manifest_bytes = None;
+        let mut entries = Vec::new();
+        for entry in archive.entries()? {
+            let mut entry = entry?;
+            if !entry.header().entry_type().is_file() {
+                continue;
+            }
+            let path = entry.path()?.into_owned();
+            // The entry's size is not used to allocate the buffer, as it is read
+            // from the archive, which may be crafted.
+            let mut bytes = Vec::new();
+            entry.read_to_end(&mut bytes)?;
+
+            if path == Path::new(WorkspaceArchiveManifest::PATH) {
+                manifest_bytes = Some(bytes);
+            } else {
+                entries.push((path, bytes));
+            }
+        }
+
+        Ok((manifest_bytes, entries))
+    }
+
+    fn entry_append(
+        builder: &mut tar::Builder<Vec<u8>>,
+        path: &Path,
+        bytes: &[u8],
+    ) -> Result<(), std::io::Error> {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(bytes.len() as u64);
+        header.set_mode(0o644);
+        header.set_entry_type(tar::EntryType::Regular);
+        builder.append_data(&mut header, path, bytes)
+    }
+
+    /// Returns whether a path component is hidden, such as the profile's
+    /// `.history` directory.
+    fn is_hidden(component: Component<'_>) -> bool {
+        component
+            .as_os_str()
+            .to_str()
+            .is_some_and(|name| name.starts_with('.'))
+    }
+}
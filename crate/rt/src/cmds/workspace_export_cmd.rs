@@ -0,0 +1,96 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use peace_cfg::Profile;
+use peace_cmd::{
+    ctx::{CmdCtx, CmdCtxTypesConstrained},
+    scopes::MultiProfileNoFlow,
+};
+use peace_resource_rt::paths::ProfileDir;
+use peace_rt_model::Error;
+
+use crate::cmds::{profile_storage::ProfileStorage, workspace_archive::WorkspaceArchive};
+
+/// Exports profiles and workspace params to a portable archive.
+///
+/// The archive contains each profile's params, params specs, and stored
+/// states, and a manifest of the application name, flow IDs, and versions.
+/// Use [`WorkspaceImportCmd`] to import it into another workspace.
+///
+/// [`WorkspaceImportCmd`]: crate::cmds::WorkspaceImportCmd
+pub struct WorkspaceExportCmd<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+
+impl<CmdCtxTypesT> Debug for WorkspaceExportCmd<CmdCtxTypesT> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("WorkspaceExportCmd").field(&self.0).finish()
+    }
+}
+
+impl<CmdCtxTypesT> WorkspaceExportCmd<CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    /// Exports all profiles in the command context, and the workspace params,
+    /// returning the archive's bytes.
+    pub async fn exec<'ctx>(
+        cmd_ctx: &mut CmdCtx<MultiProfileNoFlow<'ctx, CmdCtxTypesT>>,
+    ) -> Result<Vec<u8>, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let workspace = cmd_ctx.workspace();
+        let archive_bytes = WorkspaceArchive::write(
+            workspace.storage(),
+            workspace.app_name(),
+            cmd_ctx.peace_app_dir(),
+            cmd_ctx.profiles(),
+        )
+        .await?;
+
+        Ok(archive_bytes)
+    }
+
+    /// Exports a single profile and its parent profiles, and the workspace
+    /// params, returning the archive's bytes.
+    ///
+    /// Parent profiles are included so that the profile's inherited params
+    /// are available when it is imported.
+    pub async fn exec_profile<'ctx>(
+        cmd_ctx: &mut CmdCtx<MultiProfileNoFlow<'ctx, CmdCtxTypesT>>,
+        profile: Profile,
+    ) -> Result<Vec<u8>, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let workspace = cmd_ctx.workspace();
+        let storage = workspace.storage();
+        let profiles = cmd_ctx.profiles();
+        let peace_app_dir = cmd_ctx.peace_app_dir();
+
+        let profile_dir = ProfileDir::from((peace_app_dir, &profile));
+        if !ProfileStorage::profile_exists(storage, profiles, &profile_dir, &profile).await? {
+            return Err(Error::ProfileNotInScope {
+                profile,
+                profiles_in_scope: profiles.to_vec(),
+            }
+            .into());
+        }
+
+        let profiles_export =
+            WorkspaceArchive::profile_with_ancestors(storage, peace_app_dir, profile).await?;
+        let archive_bytes = WorkspaceArchive::write(
+            storage,
+            workspace.app_name(),
+            peace_app_dir,
+            &profiles_export,
+        )
+        .await?;
+
+        Ok(archive_bytes)
+    }
+}
+
+impl<CmdCtxTypesT> Default for WorkspaceExportCmd<CmdCtxTypesT> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
@@ -0,0 +1,137 @@
+use std::{fmt::Debug, marker::PhantomData, path::Path};
+
+use peace_cfg::FlowId;
+use peace_cmd::{
+    ctx::{CmdCtx, CmdCtxTypesConstrained},
+    scopes::MultiProfileSingleFlow,
+};
+use peace_resource_rt::paths::{ProfileDir, ProfileHistoryDir};
+use peace_rt_model::{Error, SerializationFormat, StorageBackend, WorkspaceArchiveManifest};
+
+use crate::cmds::{profile_storage::ProfileStorage, workspace_archive::WorkspaceArchive};
+
+/// Imports profiles and workspace params from an archive created by
+/// [`WorkspaceExportCmd`].
+///
+/// [`WorkspaceExportCmd`]: crate::cmds::WorkspaceExportCmd
+pub struct WorkspaceImportCmd<CmdCtxTypesT>(PhantomData<CmdCtxTypesT>);
+
+impl<CmdCtxTypesT> Debug for WorkspaceImportCmd<CmdCtxTypesT> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("WorkspaceImportCmd").field(&self.0).finish()
+    }
+}
+
+impl<CmdCtxTypesT> WorkspaceImportCmd<CmdCtxTypesT>
+where
+    CmdCtxTypesT: CmdCtxTypesConstrained,
+{
+    /// Imports the archive's profiles into the workspace, returning the
+    /// archive's manifest.
+    ///
+    /// The manifest is validated before any files are written. An error is
+    /// returned if:
+    ///
+    /// * The archive was exported from a different application.
+    /// * The archive's manifest contains an invalid profile name.
+    /// * The archive contains a flow other than the command context's flow.
+    /// * Any of the archive's profiles already exist in the workspace.
+    /// * The archive contains files in a profile that is not in its manifest.
+    /// * The archive contains files in a flow that is not in its profile's
+    ///   manifest flows.
+    ///
+    /// Workspace params from the archive are only written if the workspace
+    /// does not have them already.
+    pub async fn exec<'ctx>(
+        cmd_ctx: &mut CmdCtx<MultiProfileSingleFlow<'ctx, CmdCtxTypesT>>,
+        archive_bytes: &[u8],
+    ) -> Result<WorkspaceArchiveManifest, <CmdCtxTypesT as CmdCtxTypesConstrained>::AppError>
+    where
+        CmdCtxTypesT: 'ctx,
+    {
+        let workspace = cmd_ctx.workspace();
+        let storage = workspace.storage();
+        let profiles = cmd_ctx.profiles();
+        let peace_app_dir = cmd_ctx.peace_app_dir();
+        let flow_ids_registered = [cmd_ctx.flow().flow_id().clone()];
+
+        let (manifest, entries) =
+            WorkspaceArchive::read(workspace.app_name(), archive_bytes).map_err(|error| *error)?;
+
+        let flow_ids_unknown = manifest
+            .flow_ids()
+            .into_iter()
+            .filter(|flow_id| !flow_ids_registered.contains(flow_id))
+            .collect::<Vec<FlowId>>();
+        if !flow_ids_unknown.is_empty() {
+            return Err(Error::WorkspaceArchiveFlowIdsUnknown {
+                flow_ids_unknown,
+                flow_ids_registered: flow_ids_registered.to_vec(),
+            }
+            .into());
+        }
+
+        for archive_profile in manifest.profiles.iter() {
+            let profile = &archive_profile.profile;
+            let profile_dir = ProfileDir::from((peace_app_dir, profile));
+            if ProfileStorage::profile_exists(storage, profiles, &profile_dir, profile).await? {
+                return Err(Error::ProfileExists {
+                    profile: profile.clone(),
+                }
+                .into());
+            }
+        }
+
+        let backend = storage.backend();
+        for archive_profile in manifest.profiles.iter() {
+            let profile_dir = ProfileDir::from((peace_app_dir, &archive_profile.profile));
+            let profile_history_dir = ProfileHistoryDir::from(&profile_dir);
+            backend
+                .dirs_create(&[&profile_dir, &profile_history_dir])
+                .await?;
+        }
+        for entry in entries {
+            let path = peace_app_dir.join(&entry.path_relative);
+            let is_workspace_file = entry.path_relative.components().count() == 1;
+            if is_workspace_file && Self::workspace_file_exists(backend, &path).await? {
+                continue;
+            }
+            if let Some(parent) = path.parent() {
+                backend.dirs_create(&[parent]).await?;
+            }
+            backend.write(&path, &entry.bytes).await?;
+        }
+
+        Ok(manifest)
+    }
+
+    /// Returns whether the workspace file at `path` exists in any
+    /// serialization format.
+    ///
+    /// Files without a serialization format extension are only matched
+    /// exactly.
+    async fn workspace_file_exists(
+        backend: &dyn StorageBackend,
+        path: &Path,
+    ) -> Result<bool, Error> {
+        if SerializationFormat::from_file_path(path).is_none() {
+            return backend.contains_item(path).await;
+        }
+        for serialization_format in SerializationFormat::ALL {
+            if backend
+                .contains_item(&serialization_format.file_path(path))
+                .await?
+            {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+}
+
+impl<CmdCtxTypesT> Default for WorkspaceImportCmd<CmdCtxTypesT> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
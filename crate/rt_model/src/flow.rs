@@ -1,6 +1,9 @@
 use peace_cfg::FlowId;
 use peace_data::fn_graph::GraphInfo;
 use peace_flow_model::{FlowSpecInfo, ItemSpecInfo};
+use peace_params::MappingFnReg;
+#[cfg(feature = "json_schema")]
+use peace_params::ParamsSpecSchemaReg;
 
 use crate::ItemGraph;
 
@@ -43,6 +46,11 @@ pub struct Flow<E> {
     ///
     /// [`Item`]: peace_cfg::Item
     graph: ItemGraph<E>,
+    /// Mapping functions used in item params specs, registered by name.
+    mapping_fn_reg: MappingFnReg,
+    /// Params types of items whose params specs have a JSON schema.
+    #[cfg(feature = "json_schema")]
+    params_spec_schema_reg: ParamsSpecSchemaReg,
 }
 
 impl<E> PartialEq for Flow<E>
@@ -59,6 +67,9 @@ impl<E> Clone for Flow<E> {
         Self {
             flow_id: self.flow_id.clone(),
             graph: self.graph.clone(),
+            mapping_fn_reg: self.mapping_fn_reg.clone(),
+            #[cfg(feature = "json_schema")]
+            params_spec_schema_reg: self.params_spec_schema_reg.clone(),
         }
     }
 }
@@ -68,7 +79,38 @@ impl<E> Eq for Flow<E> where E: 'static {}
 impl<E> Flow<E> {
     /// Returns a new `Flow`.
     pub fn new(flow_id: FlowId, graph: ItemGraph<E>) -> Self {
-        Self { flow_id, graph }
+        Self {
+            flow_id,
+            graph,
+            mapping_fn_reg: MappingFnReg::new(),
+            #[cfg(feature = "json_schema")]
+            params_spec_schema_reg: ParamsSpecSchemaReg::new(),
+        }
+    }
+
+    /// Sets the mapping functions that item params specs may refer to by name.
+    ///
+    /// Params specs that use named mapping functions are bound to these
+    /// functions when they are deserialized, so commands do not need to
+    /// provide the mapping functions again.
+    pub fn with_mapping_fn_reg(mut self, mapping_fn_reg: MappingFnReg) -> Self {
+        self.mapping_fn_reg = mapping_fn_reg;
+        self
+    }
+
+    /// Sets the params types of items whose params specs have a JSON schema.
+    ///
+    /// Only these items are included by
+    /// [`ParamsSpecsSchemaBuilder::with_flow`].
+    ///
+    /// [`ParamsSpecsSchemaBuilder::with_flow`]: crate::ParamsSpecsSchemaBuilder::with_flow
+    #[cfg(feature = "json_schema")]
+    pub fn with_params_spec_schema_reg(
+        mut self,
+        params_spec_schema_reg: ParamsSpecSchemaReg,
+    ) -> Self {
+        self.params_spec_schema_reg = params_spec_schema_reg;
+        self
     }
 
     /// Returns the flow ID.
@@ -86,6 +128,18 @@ impl<E> Flow<E> {
         &self.graph
     }
 
+    /// Returns the mapping functions registered by name for this flow.
+    pub fn mapping_fn_reg(&self) -> &MappingFnReg {
+        &self.mapping_fn_reg
+    }
+
+    /// Returns the params types of items whose params specs have a JSON
+    /// schema.
+    #[cfg(feature = "json_schema")]
+    pub fn params_spec_schema_reg(&self) -> &ParamsSpecSchemaReg {
+        &self.params_spec_schema_reg
+    }
+
     /// Generates a `FlowSpecInfo` from this `Flow`'s information.
     pub fn flow_spec_info(&self) -> FlowSpecInfo
     where
@@ -1,7 +1,7 @@
 use peace_fmt::Presentable;
 use peace_rt_model_core::{async_trait, output::OutputWrite};
 
-use crate::Error;
+use crate::{Error, SerializationError};
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "output_progress")] {
@@ -77,7 +77,9 @@ where
     where
         P: Presentable,
     {
-        self.buffer = serde_yaml::to_string(&presentable).map_err(Error::StatesSerialize)?;
+        self.buffer = serde_yaml::to_string(&presentable)
+            .map_err(SerializationError::Yaml)
+            .map_err(Error::StatesSerialize)?;
 
         Ok(())
     }
@@ -83,6 +83,7 @@ where
         >>::Error,
     >,
     for<'params> <I::Params<'params> as Params>::Partial: From<I::Params<'params>>,
+    for<'params> <I::Params<'params> as Params>::FieldWiseSpec: From<I::Params<'params>>,
 {
     fn from(item: I) -> Self {
         Self(Box::new(ItemWrapper::from(item)))
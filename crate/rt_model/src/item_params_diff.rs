@@ -0,0 +1,174 @@
+use peace_fmt::{Presentable, Presenter};
+use peace_rt_model_core::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_yaml::{Mapping, Value};
+
+use crate::ParamsFieldDiff;
+
+/// Field by field differences between an item's stored and current params.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ItemParamsDiff {
+    /// Difference for each field of the params.
+    pub field_diffs: Vec<ParamsFieldDiff>,
+    /// Error resolving the stored params, if any.
+    ///
+    /// Params that reference values produced by predecessors are not resolved,
+    /// but are not errors.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value_stored_error: Option<String>,
+    /// Error resolving the params used by the current command context, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value_error: Option<String>,
+}
+
+impl ItemParamsDiff {
+    /// Returns the field by field diff of the given serialized field wise
+    /// specs and resolved values.
+    ///
+    /// Specs and values that serialize to a mapping are split by their top
+    /// level fields, and others are compared as a whole.
+    pub fn new(
+        spec_stored: Option<Value>,
+        spec: Option<Value>,
+        value_stored: Option<Value>,
+        value: Option<Value>,
+    ) -> Self {
+        let specs_stored = spec_stored.map(fields).unwrap_or_default();
+        let specs = spec.map(fields).unwrap_or_default();
+        let values_stored = value_stored.map(fields).unwrap_or_default();
+        let values = value.map(fields).unwrap_or_default();
+
+        let field_names = specs
+            .keys()
+            .chain(specs_stored.keys())
+            .chain(values.keys())
+            .chain(values_stored.keys())
+            .fold(
+                Vec::<Option<String>>::new(),
+                |mut field_names, field_name| {
+                    if !field_names.contains(field_name) {
+                        field_names.push(field_name.clone());
+                    }
+                    field_names
+                },
+            );
+
+        let field_diffs = field_names
+            .into_iter()
+            .map(|field_name| ParamsFieldDiff {
+                spec_stored: specs_stored.get(&field_name).cloned(),
+                spec: specs.get(&field_name).cloned(),
+                value_stored: values_stored.get(&field_name).cloned(),
+                value: values.get(&field_name).cloned(),
+                field_name,
+            })
+            .collect::<Vec<_>>();
+
+        Self {
+            field_diffs,
+            value_stored_error: None,
+            value_error: None,
+        }
+    }
+
+    /// Sets the error resolving the stored params.
+    pub fn with_value_stored_error(mut self, value_stored_error: Option<String>) -> Self {
+        self.value_stored_error = value_stored_error;
+        self
+    }
+
+    /// Sets the error resolving the params used by the current command
+    /// context.
+    pub fn with_value_error(mut self, value_error: Option<String>) -> Self {
+        self.value_error = value_error;
+        self
+    }
+
+    /// Returns whether any field's spec or resolved value has changed.
+    pub fn is_changed(&self) -> bool {
+        self.field_diffs.iter().any(ParamsFieldDiff::is_changed)
+    }
+
+    /// Returns whether the stored or current params could not be resolved.
+    pub fn has_errors(&self) -> bool {
+        self.value_stored_error.is_some() || self.value_error.is_some()
+    }
+
+    /// Returns an iterator over the fields whose spec or value has changed.
+    pub fn changed_fields(&self) -> impl Iterator<Item = &ParamsFieldDiff> {
+        self.field_diffs
+            .iter()
+            .filter(|field_diff| field_diff.is_changed())
+    }
+}
+
+/// Splits a serialized field wise spec or params value into its top level
+/// fields.
+fn fields(value: Value) -> IndexMap<Option<String>, Value> {
+    match &value {
+        Value::Mapping(mapping) => mapping_fields(mapping),
+        _ => whole(value),
+    }
+}
+
+fn mapping_fields(mapping: &Mapping) -> IndexMap<Option<String>, Value> {
+    mapping
+        .iter()
+        .map(|(key, value)| {
+            let field_name = match key {
+                Value::String(field_name) => field_name.clone(),
+                key => serde_yaml::to_string(key)
+                    .map(|key| key.trim_end().to_string())
+                    .unwrap_or_else(|_| format!("{key:?}")),
+            };
+            (Some(field_name), value.clone())
+        })
+        .collect()
+}
+
+fn whole(value: Value) -> IndexMap<Option<String>, Value> {
+    let mut fields = IndexMap::with_capacity(1);
+    fields.insert(None, value);
+    fields
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl Presentable for ItemParamsDiff {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        let mut changed_fields = self.changed_fields().peekable();
+        if changed_fields.peek().is_none() && !self.has_errors() {
+            return presenter.text("no changes").await;
+        }
+
+        let mut first = true;
+        for field_diff in changed_fields {
+            if !first {
+                presenter.text(", ").await?;
+            }
+            first = false;
+            field_diff.present(presenter).await?;
+        }
+
+        let errors = [
+            ("stored params", self.value_stored_error.as_deref()),
+            ("params", self.value_error.as_deref()),
+        ];
+        for (label, error) in errors {
+            let Some(error) = error else {
+                continue;
+            };
+            if !first {
+                presenter.text(", ").await?;
+            }
+            first = false;
+            presenter.text(label).await?;
+            presenter.text(" could not be resolved: ").await?;
+            presenter.text(error).await?;
+        }
+
+        Ok(())
+    }
+}
@@ -3,7 +3,7 @@ use std::{any::Any, fmt::Debug};
 use dyn_clone::DynClone;
 use peace_cfg::{async_trait, FnCtx, ItemId};
 use peace_data::fn_graph::{DataAccess, DataAccessDyn};
-use peace_params::ParamsSpecs;
+use peace_params::{AnySpecRtBoxed, ParamsSpecs};
 use peace_resource_rt::{
     resources::ts::{Empty, SetUp},
     states::StatesCurrent,
@@ -13,7 +13,7 @@ use peace_resource_rt::{
 
 use crate::{
     outcomes::{ItemApplyBoxed, ItemApplyPartialBoxed},
-    ParamsSpecsTypeReg, StatesTypeReg,
+    ItemParamsDiff, ParamsSpecsTypeReg, StatesTypeReg,
 };
 
 /// Internal trait that erases the types from [`Item`]
@@ -58,6 +58,59 @@ pub trait ItemRt<E>:
         states_type_reg: &mut StatesTypeReg,
     );
 
+    /// Validates this item's params if they can be resolved from `resources`.
+    ///
+    /// This is run when the command context is built, so that invalid params
+    /// are reported before any item function is run. Params that depend on
+    /// values produced by predecessors are validated when they are resolved.
+    ///
+    /// See [`Params::validate`].
+    ///
+    /// [`Params::validate`]: peace_params::Params::validate
+    fn params_validate(
+        &self,
+        params_specs: &ParamsSpecs,
+        resources: &Resources<SetUp>,
+    ) -> Result<(), E>
+    where
+        E: Debug + std::error::Error;
+
+    /// Returns the params that this item was applied with, as a
+    /// `ParamsSpec::Value`.
+    ///
+    /// This resolves the params the same way they are resolved during
+    /// `apply_exec`, and is run after an apply so that `CleanCmd` can clean up
+    /// using the params that created the item's resources.
+    fn params_applied(
+        &self,
+        params_specs: &ParamsSpecs,
+        resources: &Resources<SetUp>,
+    ) -> Result<AnySpecRtBoxed, E>
+    where
+        E: Debug + std::error::Error;
+
+    /// Returns the field by field diff between this item's stored params specs
+    /// and the params specs used by the command context.
+    ///
+    /// Specs are compared by their field wise specs, so `Value` and
+    /// `FieldWise` specs with the same field values are not reported as
+    /// changed.
+    ///
+    /// Both specs are also resolved in goal mode, so that changes to values
+    /// that are not directly provided can be shown. Specs whose values are not
+    /// in `resources` yet, such as values produced by predecessors at runtime,
+    /// have no resolved value in the diff. Other resolution errors, such as
+    /// params that fail validation, are recorded in the diff's
+    /// `value_stored_error` and `value_error`.
+    fn params_diff(
+        &self,
+        params_specs_stored: Option<&ParamsSpecs>,
+        params_specs: &ParamsSpecs,
+        resources: &Resources<SetUp>,
+    ) -> Result<ItemParamsDiff, E>
+    where
+        E: Debug + std::error::Error;
+
     /// Returns if the given two states equal.
     ///
     /// This returns an error if the boxed states could not be downcasted to
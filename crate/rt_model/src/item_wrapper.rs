@@ -12,7 +12,8 @@ use peace_data::{
     Data,
 };
 use peace_params::{
-    Params, ParamsMergeExt, ParamsSpec, ParamsSpecs, ValueResolutionCtx, ValueResolutionMode,
+    AnySpecRtBoxed, Params, ParamsMergeExt, ParamsResolveError, ParamsSpec, ParamsSpecs,
+    ValueResolutionCtx, ValueResolutionMode,
 };
 use peace_resource_rt::{
     resources::ts::{Empty, SetUp},
@@ -24,7 +25,7 @@ use type_reg::untagged::BoxDataTypeDowncast;
 
 use crate::{
     outcomes::{ItemApply, ItemApplyBoxed, ItemApplyPartial, ItemApplyPartialBoxed},
-    ItemRt, ParamsSpecsTypeReg, StateDowncastError, StatesTypeReg,
+    ItemParamsDiff, ItemRt, ParamsSpecsTypeReg, StateDowncastError, StatesTypeReg,
 };
 
 #[cfg(feature = "output_progress")]
@@ -77,6 +78,7 @@ where
     for<'params> <I as Item>::Params<'params>:
         TryFrom<<<I as Item>::Params<'params> as Params>::Partial>,
     for<'params> <I::Params<'params> as Params>::Partial: From<I::Params<'params>>,
+    for<'params> <I::Params<'params> as Params>::FieldWiseSpec: From<I::Params<'params>>,
 {
     #[cfg(feature = "item_state_example")]
     fn state_example(
@@ -371,6 +373,99 @@ where
             .map_err(crate::Error::ParamsResolveError)?)
     }
 
+    fn params_validate(
+        &self,
+        params_specs: &ParamsSpecs,
+        resources: &Resources<SetUp>,
+    ) -> Result<(), E> {
+        let item_id = self.id();
+        let params_spec = params_specs
+            .get::<ParamsSpec<I::Params<'_>>, _>(item_id)
+            .ok_or_else(|| crate::Error::ParamsSpecNotFound {
+                item_id: item_id.clone(),
+            })?;
+        let mut value_resolution_ctx = ValueResolutionCtx::new(
+            ValueResolutionMode::Goal,
+            item_id.clone(),
+            tynm::type_name::<I::Params<'_>>(),
+        );
+        Ok(params_spec
+            .validate(resources, &mut value_resolution_ctx)
+            .map_err(crate::Error::ParamsResolveError)?)
+    }
+
+    fn params_diff(
+        &self,
+        params_specs_stored: Option<&ParamsSpecs>,
+        params_specs: &ParamsSpecs,
+        resources: &Resources<SetUp>,
+    ) -> Result<ItemParamsDiff, E> {
+        let item_id = self.id();
+        let params_spec_stored = params_specs_stored.and_then(|params_specs_stored| {
+            params_specs_stored.get::<ParamsSpec<I::Params<'_>>, _>(item_id)
+        });
+        let params_spec = params_specs
+            .get::<ParamsSpec<I::Params<'_>>, _>(item_id)
+            .ok_or_else(|| crate::Error::ParamsSpecNotFound {
+                item_id: item_id.clone(),
+            })?;
+
+        // `Value` specs are compared as field wise specs, so that a field's spec
+        // is the same whether it was provided as a whole value or field wise.
+        let params_spec_to_field_wise = |params_spec: &ParamsSpec<I::Params<'_>>| match params_spec
+        {
+            ParamsSpec::Value { value } => serde_yaml::to_value(
+                <I::Params<'_> as Params>::FieldWiseSpec::from(value.clone()),
+            ),
+            ParamsSpec::FieldWise { field_wise_spec } => serde_yaml::to_value(field_wise_spec),
+            ParamsSpec::Stored | ParamsSpec::InMemory | ParamsSpec::MappingFn(_) => {
+                serde_yaml::to_value(params_spec)
+            }
+        };
+
+        // Values produced by predecessors may not be in `resources` yet, so specs
+        // that reference them are shown without a value. Other resolution
+        // failures are recorded in the diff, so that the diffs of other items are
+        // still shown.
+        let params_resolve_to_value = |params_spec: &ParamsSpec<I::Params<'_>>| {
+            let mut value_resolution_ctx = ValueResolutionCtx::new(
+                ValueResolutionMode::Goal,
+                item_id.clone(),
+                tynm::type_name::<I::Params<'_>>(),
+            );
+            match params_spec.resolve(resources, &mut value_resolution_ctx) {
+                Ok(params) => serde_yaml::to_value(&params)
+                    .map(Some)
+                    .map_err(|error| error.to_string()),
+                Err(ParamsResolveError::InMemory { .. } | ParamsResolveError::FromMap { .. }) => {
+                    Ok(None)
+                }
+                Err(error) => Err(error.to_string()),
+            }
+        };
+
+        let spec_stored = params_spec_stored
+            .map(params_spec_to_field_wise)
+            .transpose()
+            .map_err(crate::Error::ParamsDiffSerialize)?;
+        let spec = params_spec_to_field_wise(params_spec)
+            .map(Some)
+            .map_err(crate::Error::ParamsDiffSerialize)?;
+        let (value_stored, value_stored_error) =
+            match params_spec_stored.map(params_resolve_to_value).transpose() {
+                Ok(value_stored) => (value_stored.flatten(), None),
+                Err(value_stored_error) => (None, Some(value_stored_error)),
+            };
+        let (value, value_error) = match params_resolve_to_value(params_spec) {
+            Ok(value) => (value, None),
+            Err(value_error) => (None, Some(value_error)),
+        };
+
+        Ok(ItemParamsDiff::new(spec_stored, spec, value_stored, value)
+            .with_value_stored_error(value_stored_error)
+            .with_value_error(value_error))
+    }
+
     fn params(
         &self,
         params_specs: &ParamsSpecs,
@@ -476,6 +571,7 @@ where
         ParamsMergeExt + TryFrom<<I::Params<'params> as Params>::Partial>,
     for<'params> <I::Params<'params> as Params>::Partial: From<I::Params<'params>>
         + From<<I::Params<'params> as TryFrom<<I::Params<'params> as Params>::Partial>>::Error>,
+    for<'params> <I::Params<'params> as Params>::FieldWiseSpec: From<I::Params<'params>>,
 {
     fn id(&self) -> &ItemId {
         <I as Item>::id(self)
@@ -526,6 +622,32 @@ where
         states_type_reg.register::<I::State>(I::id(self).clone());
     }
 
+    fn params_validate(
+        &self,
+        params_specs: &ParamsSpecs,
+        resources: &Resources<SetUp>,
+    ) -> Result<(), E> {
+        self.params_validate(params_specs, resources)
+    }
+
+    fn params_applied(
+        &self,
+        params_specs: &ParamsSpecs,
+        resources: &Resources<SetUp>,
+    ) -> Result<AnySpecRtBoxed, E> {
+        let params = self.params(params_specs, resources, ValueResolutionMode::Current)?;
+        Ok(AnySpecRtBoxed::new(ParamsSpec::Value { value: params }))
+    }
+
+    fn params_diff(
+        &self,
+        params_specs_stored: Option<&ParamsSpecs>,
+        params_specs: &ParamsSpecs,
+        resources: &Resources<SetUp>,
+    ) -> Result<ItemParamsDiff, E> {
+        self.params_diff(params_specs_stored, params_specs, resources)
+    }
+
     fn state_eq(&self, state_a: &BoxDtDisplay, state_b: &BoxDtDisplay) -> Result<bool, E> {
         let state_a_downcasted = BoxDataTypeDowncast::<I::State>::downcast_ref(state_a);
         let state_b_downcasted = BoxDataTypeDowncast::<I::State>::downcast_ref(state_b);
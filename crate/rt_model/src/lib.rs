@@ -15,10 +15,13 @@ pub use peace_rt_model_web::*;
 
 pub use crate::{
     flow::Flow, in_memory_text_output::InMemoryTextOutput, item_boxed::ItemBoxed,
-    item_graph::ItemGraph, item_graph_builder::ItemGraphBuilder, item_rt::ItemRt,
-    item_wrapper::ItemWrapper, params_specs_serializer::ParamsSpecsSerializer,
-    params_specs_type_reg::ParamsSpecsTypeReg, states_serializer::StatesSerializer,
-    states_type_reg::StatesTypeReg,
+    item_graph::ItemGraph, item_graph_builder::ItemGraphBuilder, item_params_diff::ItemParamsDiff,
+    item_rt::ItemRt, item_wrapper::ItemWrapper, params_diffs::ParamsDiffs,
+    params_field_diff::ParamsFieldDiff, params_specs_serializer::ParamsSpecsSerializer,
+    params_specs_type_reg::ParamsSpecsTypeReg, profile_change::ProfileChange,
+    states_serializer::StatesSerializer, states_type_reg::StatesTypeReg,
+    workspace_archive_manifest::WorkspaceArchiveManifest,
+    workspace_archive_profile::WorkspaceArchiveProfile,
 };
 
 pub mod outcomes;
@@ -28,12 +31,24 @@ mod in_memory_text_output;
 mod item_boxed;
 mod item_graph;
 mod item_graph_builder;
+mod item_params_diff;
 mod item_rt;
 mod item_wrapper;
+mod params_diffs;
+mod params_field_diff;
 mod params_specs_serializer;
 mod params_specs_type_reg;
+mod profile_change;
 mod states_serializer;
 mod states_type_reg;
+mod workspace_archive_manifest;
+mod workspace_archive_profile;
 
 #[cfg(feature = "error_reporting")]
 mod yaml_error_context_hack;
+
+#[cfg(feature = "json_schema")]
+pub use crate::params_specs_schema_builder::ParamsSpecsSchemaBuilder;
+
+#[cfg(feature = "json_schema")]
+mod params_specs_schema_builder;
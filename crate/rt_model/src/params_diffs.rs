@@ -0,0 +1,80 @@
+use std::ops::{Deref, DerefMut};
+
+use peace_cfg::ItemId;
+use peace_fmt::{Presentable, Presenter};
+use peace_rt_model_core::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use crate::ItemParamsDiff;
+
+/// Params diffs for each item. `IndexMap<ItemId, ItemParamsDiff>` newtype.
+///
+/// Items are in the order they were inserted into the flow's item graph.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ParamsDiffs(IndexMap<ItemId, ItemParamsDiff>);
+
+impl ParamsDiffs {
+    /// Returns a new `ParamsDiffs` map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty `ParamsDiffs` map with the specified capacity.
+    ///
+    /// The `ParamsDiffs` will be able to hold at least capacity elements
+    /// without reallocating. If capacity is 0, the map will not allocate.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(IndexMap::with_capacity(capacity))
+    }
+
+    /// Returns the inner map.
+    pub fn into_inner(self) -> IndexMap<ItemId, ItemParamsDiff> {
+        self.0
+    }
+
+    /// Returns whether any item's params have changed.
+    pub fn is_changed(&self) -> bool {
+        self.0.values().any(ItemParamsDiff::is_changed)
+    }
+}
+
+impl Deref for ParamsDiffs {
+    type Target = IndexMap<ItemId, ItemParamsDiff>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for ParamsDiffs {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<IndexMap<ItemId, ItemParamsDiff>> for ParamsDiffs {
+    fn from(index_map: IndexMap<ItemId, ItemParamsDiff>) -> Self {
+        Self(index_map)
+    }
+}
+
+impl FromIterator<(ItemId, ItemParamsDiff)> for ParamsDiffs {
+    fn from_iter<I: IntoIterator<Item = (ItemId, ItemParamsDiff)>>(iter: I) -> Self {
+        Self(IndexMap::from_iter(iter))
+    }
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl Presentable for ParamsDiffs {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        presenter
+            .list_numbered_with(self.iter(), |(item_id, item_params_diff)| {
+                (item_id, ": ", item_params_diff)
+            })
+            .await
+    }
+}
@@ -0,0 +1,80 @@
+use peace_fmt::{Presentable, Presenter};
+use serde::{Deserialize, Serialize};
+
+/// Difference in a single field of an item's params.
+///
+/// Each value is the serialized form of the spec or params value, and is
+/// `None` when it does not exist, or cannot be broken down into fields.
+///
+/// The `field_name` is `None` when the spec or value is for the params as a
+/// whole, such as an `InMemory` spec.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ParamsFieldDiff {
+    /// Name of the field, or `None` if this is for the whole params.
+    pub field_name: Option<String>,
+    /// Spec for the field read from the `params_specs_file`.
+    pub spec_stored: Option<serde_yaml::Value>,
+    /// Spec for the field used by the current command context.
+    pub spec: Option<serde_yaml::Value>,
+    /// Value resolved from the stored spec.
+    pub value_stored: Option<serde_yaml::Value>,
+    /// Value resolved from the current spec.
+    pub value: Option<serde_yaml::Value>,
+}
+
+impl ParamsFieldDiff {
+    /// Returns whether the spec for this field has changed.
+    pub fn spec_changed(&self) -> bool {
+        self.spec_stored != self.spec
+    }
+
+    /// Returns whether the resolved value for this field has changed.
+    ///
+    /// This is `false` if either value could not be resolved.
+    pub fn value_changed(&self) -> bool {
+        matches!(
+            (&self.value_stored, &self.value),
+            (Some(value_stored), Some(value)) if value_stored != value
+        )
+    }
+
+    /// Returns whether either the spec or resolved value has changed.
+    pub fn is_changed(&self) -> bool {
+        self.spec_changed() || self.value_changed()
+    }
+}
+
+/// Returns the YAML representation of a value on a single line.
+fn value_display(value: Option<&serde_yaml::Value>) -> String {
+    match value {
+        Some(value) => serde_yaml::to_string(value)
+            .map(|value_str| value_str.trim_end().replace('\n', " "))
+            .unwrap_or_else(|_| format!("{value:?}")),
+        None => String::from("<none>"),
+    }
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl Presentable for ParamsFieldDiff {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        match self.field_name.as_deref() {
+            Some(field_name) => presenter.code_inline(field_name).await?,
+            None => presenter.text("params").await?,
+        }
+
+        let (kind, from, to) = if self.value_changed() {
+            ("", self.value_stored.as_ref(), self.value.as_ref())
+        } else {
+            (" spec", self.spec_stored.as_ref(), self.spec.as_ref())
+        };
+
+        presenter.text(kind).await?;
+        presenter.text(" changed from ").await?;
+        presenter.code_inline(&value_display(from)).await?;
+        presenter.text(" to ").await?;
+        presenter.code_inline(&value_display(to)).await
+    }
+}
@@ -0,0 +1,94 @@
+use peace_cfg::ItemId;
+use schemars::Schema;
+
+use crate::{params::ParamsMapSchemaBuilder, Flow};
+
+/// Builds a JSON schema for a params specs override file.
+///
+/// The schema is generated from a flow's items, keyed by each item's ID, so
+/// that editors are able to autocomplete and validate the params specs for
+/// each item.
+///
+/// Only items whose params types are registered in the flow's
+/// [`ParamsSpecSchemaReg`] are included. These `Params` need to derive
+/// `JsonSchema`, and have the `#[json_schema]` attribute so that their field
+/// wise specs also have a schema.
+///
+/// [`ParamsSpecSchemaReg`]: peace_params::ParamsSpecSchemaReg
+///
+/// # JSON and YAML
+///
+/// The schema describes the JSON form of a params specs file, where each
+/// params spec variant is externally tagged:
+///
+/// ```json
+/// {
+///   "item_id": {
+///     "FieldWise": {
+///       "field_wise_spec": {
+///         "src": { "Value": { "value": "https://example.com/file.txt" } },
+///         "dest": "Stored"
+///       }
+///     }
+///   }
+/// }
+/// ```
+///
+/// In YAML files the same variants are written as tags, which JSON schema
+/// validators do not understand:
+///
+/// ```yaml
+/// item_id: !FieldWise
+///   field_wise_spec:
+///     src: !Value
+///       value: https://example.com/file.txt
+///     dest: Stored
+/// ```
+///
+/// So the schema is only able to validate JSON params specs override files.
+pub struct ParamsSpecsSchemaBuilder(ParamsMapSchemaBuilder<ItemId>);
+
+impl ParamsSpecsSchemaBuilder {
+    /// Returns a new `ParamsSpecsSchemaBuilder`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the params spec for each item in the flow whose params type is
+    /// registered in the flow's `ParamsSpecSchemaReg`.
+    pub fn with_flow<E>(self, flow: &Flow<E>) -> Self
+    where
+        E: 'static,
+    {
+        let params_spec_schema_reg = flow.params_spec_schema_reg();
+        let params_map_schema_builder = flow
+            .graph()
+            .iter_insertion()
+            .filter_map(|item| {
+                let item_id = item.id();
+                params_spec_schema_reg
+                    .params_spec_schema_fn(item_id)
+                    .map(|params_spec_schema_fn| (item_id, params_spec_schema_fn))
+            })
+            .fold(
+                self.0,
+                |params_map_schema_builder, (item_id, params_spec_schema_fn)| {
+                    params_map_schema_builder
+                        .with_param_schema(item_id.clone(), params_spec_schema_fn)
+                },
+            );
+
+        Self(params_map_schema_builder)
+    }
+
+    /// Returns the JSON schema for the params specs override file.
+    pub fn build(self) -> Schema {
+        self.0.build()
+    }
+}
+
+impl Default for ParamsSpecsSchemaBuilder {
+    fn default() -> Self {
+        Self(ParamsMapSchemaBuilder::new("ParamsSpecs"))
+    }
+}
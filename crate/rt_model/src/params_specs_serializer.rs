@@ -1,10 +1,21 @@
-use std::marker::PhantomData;
+use std::{
+    collections::{BTreeMap, HashMap},
+    marker::PhantomData,
+    path::Path,
+};
 
 use peace_cfg::{FlowId, Profile};
-use peace_params::ParamsSpecs;
-use peace_resource_rt::{paths::ParamsSpecsFile, type_reg::untagged::TypeMapOpt};
+use peace_params::{
+    MappingFnReg, ParamsSpecFieldOrigins, ParamsSpecOrigin, ParamsSpecs, ParamsSpecsApplied,
+    ParamsSpecsOrigins,
+};
+use peace_resource_rt::{
+    paths::{ParamsAppliedFile, ParamsSpecsFile},
+    type_reg::untagged::TypeMapOpt,
+};
+use saphyr_parser::{Event, Parser, Span, Tag};
 
-use crate::{Error, ParamsSpecsTypeReg, Storage};
+use crate::{Error, ParamsSpecsTypeReg, SerializationError, Storage};
 
 /// Reads and writes [`ParamsSpecs`] to and from storage.
 pub struct ParamsSpecsSerializer<E>(PhantomData<E>);
@@ -15,6 +26,8 @@ where
 {
     /// Serializes the [`ParamsSpecs`] of all [`Item`]s to disk.
     ///
+    /// The file is encrypted if encryption is set on the `Storage`.
+    ///
     /// # Parameters:
     ///
     /// * `storage`: `Storage` to write to.
@@ -28,7 +41,7 @@ where
         params_specs_file: &ParamsSpecsFile,
     ) -> Result<(), E> {
         storage
-            .serialized_write(
+            .serialized_write_encrypted(
                 #[cfg(not(target_arch = "wasm32"))]
                 "ParamsSpecsSerializer::serialize".to_string(),
                 params_specs_file,
@@ -40,13 +53,47 @@ where
         Ok(())
     }
 
+    /// Serializes the params used when each [`Item`] was last applied.
+    ///
+    /// The file is encrypted if encryption is set on the `Storage`.
+    ///
+    /// # Parameters:
+    ///
+    /// * `storage`: `Storage` to write to.
+    /// * `params_specs_applied`: `ParamsSpecsApplied` to serialize.
+    /// * `params_applied_file`: Path to save the serialized params to.
+    ///
+    /// [`Item`]: peace_cfg::Item
+    pub async fn serialize_applied(
+        storage: &Storage,
+        params_specs_applied: &ParamsSpecsApplied,
+        params_applied_file: &ParamsAppliedFile,
+    ) -> Result<(), E> {
+        storage
+            .serialized_write_encrypted(
+                #[cfg(not(target_arch = "wasm32"))]
+                "ParamsSpecsSerializer::serialize_applied".to_string(),
+                params_applied_file,
+                &**params_specs_applied,
+                Error::ParamsSpecsSerialize,
+            )
+            .await?;
+
+        Ok(())
+    }
+
     /// Returns the [`ParamsSpecs`] of all [`Item`]s if it exists on disk.
     ///
+    /// Named mapping functions in the params specs are bound to the functions
+    /// registered in `mapping_fn_reg`. An error is returned if any names are
+    /// not registered.
+    ///
     /// # Parameters:
     ///
     /// * `storage`: `Storage` to read from.
     /// * `params_specs_type_reg`: Type registry with functions to deserialize
     ///   each params spec.
+    /// * `mapping_fn_reg`: Mapping functions registered by name.
     /// * `params_specs_file`: `ParamsSpecsFile` to deserialize.
     ///
     /// [`Item`]: peace_cfg::Item
@@ -55,9 +102,10 @@ where
         flow_id: &FlowId,
         storage: &Storage,
         params_specs_type_reg: &ParamsSpecsTypeReg,
+        mapping_fn_reg: &MappingFnReg,
         params_specs_file: &ParamsSpecsFile,
     ) -> Result<Option<ParamsSpecs>, E> {
-        Self::deserialize_internal(
+        let params_specs_opt = Self::deserialize_internal(
             #[cfg(not(target_arch = "wasm32"))]
             "ParamsSpecsSerializer::deserialize_opt".to_string(),
             profile,
@@ -66,39 +114,98 @@ where
             params_specs_type_reg,
             params_specs_file,
         )
-        .await
+        .await?;
+
+        params_specs_opt
+            .map(|mut params_specs| {
+                let mapping_fn_bind_errors = params_specs.mapping_fns_bind(mapping_fn_reg);
+                match Error::from_mapping_fn_bind_errors(
+                    flow_id,
+                    mapping_fn_reg,
+                    mapping_fn_bind_errors,
+                ) {
+                    Some(error) => Err(E::from(error)),
+                    None => Ok(params_specs),
+                }
+            })
+            .transpose()
     }
 
-    /// Returns the [`ParamsSpecs`] of all [`Item`]s if it exists on disk.
+    /// Returns the params used when each [`Item`] was last applied, if they
+    /// exist on disk.
     ///
     /// # Parameters:
     ///
     /// * `storage`: `Storage` to read from.
     /// * `params_specs_type_reg`: Type registry with functions to deserialize
     ///   each params spec.
-    /// * `params_specs_file`: `ParamsSpecsFile` to deserialize.
+    /// * `params_applied_file`: `ParamsAppliedFile` to deserialize.
     ///
     /// [`Item`]: peace_cfg::Item
-    #[cfg(not(target_arch = "wasm32"))]
-    async fn deserialize_internal(
-        thread_name: String,
+    pub async fn deserialize_applied_opt(
         profile: &Profile,
         flow_id: &FlowId,
         storage: &Storage,
         params_specs_type_reg: &ParamsSpecsTypeReg,
-        params_specs_file: &ParamsSpecsFile,
-    ) -> Result<Option<ParamsSpecs>, E> {
-        let params_specs_opt = storage
-            .serialized_typemap_read_opt(
-                thread_name,
-                params_specs_type_reg,
-                params_specs_file,
-                |error| {
+        params_applied_file: &ParamsAppliedFile,
+    ) -> Result<Option<ParamsSpecsApplied>, E> {
+        Self::deserialize_internal(
+            #[cfg(not(target_arch = "wasm32"))]
+            "ParamsSpecsSerializer::deserialize_applied_opt".to_string(),
+            profile,
+            flow_id,
+            storage,
+            params_specs_type_reg,
+            params_applied_file,
+        )
+        .await
+        .map(|params_specs_opt| params_specs_opt.map(ParamsSpecsApplied::from))
+    }
+
+    /// Returns the [`ParamsSpecs`] in a params specs override file, and the
+    /// line and column each item's params spec, or each field of a field-wise
+    /// params spec, begins at.
+    ///
+    /// The file is deserialized as JSON if its extension is `json`, and YAML
+    /// otherwise. Keys that are not item IDs in the flow are errors.
+    ///
+    /// Override files are provided by the user running the command, so they
+    /// are read by the caller from the local filesystem, and not from the
+    /// workspace's storage backend.
+    ///
+    /// # Parameters:
+    ///
+    /// * `flow_id`: ID of the flow whose params specs are overridden.
+    /// * `params_specs_type_reg`: Type registry with functions to deserialize
+    ///   each params spec.
+    /// * `params_specs_override_path`: Path of the override file, used to
+    ///   determine its format, and recorded in the returned origins.
+    /// * `file_bytes`: Contents of the override file.
+    pub fn deserialize_override(
+        flow_id: &FlowId,
+        params_specs_type_reg: &ParamsSpecsTypeReg,
+        params_specs_override_path: &Path,
+        file_bytes: Vec<u8>,
+    ) -> Result<(ParamsSpecs, ParamsSpecsOrigins), E> {
+        let file_contents =
+            String::from_utf8(file_bytes).map_err(|error| Error::ParamsSpecsOverrideNotUtf8 {
+                params_specs_override_path: params_specs_override_path.to_path_buf(),
+                error,
+            })?;
+
+        let is_json = params_specs_override_path
+            .extension()
+            .is_some_and(|extension| extension == "json");
+        let params_specs = if is_json {
+            let mut deserializer = serde_json::Deserializer::from_str(&file_contents);
+            params_specs_type_reg
+                .deserialize_map(&mut deserializer)
+                .map_err(|error| {
                     #[cfg(not(feature = "error_reporting"))]
                     {
-                        Error::ParamsSpecsDeserialize {
-                            profile: profile.clone(),
+                        Error::ParamsSpecsOverrideDeserializeJson {
                             flow_id: flow_id.clone(),
+                            params_specs_override_path: params_specs_override_path.to_path_buf(),
                             error,
                         }
                     }
@@ -106,26 +213,133 @@ where
                     {
                         use miette::NamedSource;
 
-                        let file_contents = std::fs::read_to_string(params_specs_file).unwrap();
+                        let error_span = Some(miette::SourceOffset::from_location(
+                            &file_contents,
+                            error.line(),
+                            error.column(),
+                        ));
+                        let params_specs_override_source = NamedSource::new(
+                            params_specs_override_path.to_string_lossy(),
+                            file_contents.clone(),
+                        );
+
+                        Error::ParamsSpecsOverrideDeserializeJson {
+                            flow_id: flow_id.clone(),
+                            params_specs_override_path: params_specs_override_path.to_path_buf(),
+                            params_specs_override_source,
+                            error_span,
+                            error,
+                        }
+                    }
+                })?
+        } else {
+            let deserializer = serde_yaml::Deserializer::from_str(&file_contents);
+            params_specs_type_reg
+                .deserialize_map(deserializer)
+                .map_err(|error| {
+                    #[cfg(not(feature = "error_reporting"))]
+                    {
+                        Error::ParamsSpecsOverrideDeserialize {
+                            flow_id: flow_id.clone(),
+                            params_specs_override_path: params_specs_override_path.to_path_buf(),
+                            error,
+                        }
+                    }
+                    #[cfg(feature = "error_reporting")]
+                    {
+                        use miette::NamedSource;
 
                         let (error_span, error_message, context_span) =
                             crate::yaml_error_context_hack::error_and_context(
                                 &file_contents,
                                 &error,
                             );
-                        let params_specs_file_source =
-                            NamedSource::new(params_specs_file.to_string_lossy(), file_contents);
+                        let params_specs_override_source = NamedSource::new(
+                            params_specs_override_path.to_string_lossy(),
+                            file_contents.clone(),
+                        );
 
-                        Error::ParamsSpecsDeserialize {
-                            profile: profile.clone(),
+                        Error::ParamsSpecsOverrideDeserialize {
                             flow_id: flow_id.clone(),
-                            params_specs_file_source,
+                            params_specs_override_path: params_specs_override_path.to_path_buf(),
+                            params_specs_override_source,
                             error_span,
                             error_message,
                             context_span,
                             error,
                         }
                     }
+                })?
+        };
+        let params_specs = ParamsSpecs::from(params_specs);
+
+        let params_spec_locations = params_spec_locations(&file_contents);
+        let params_spec_origin = |(line, column)| {
+            ParamsSpecOrigin::new(params_specs_override_path.to_path_buf(), line, column)
+        };
+        let params_specs_origins = params_specs
+            .keys()
+            .map(|item_id| {
+                let params_spec_field_origins = match params_spec_locations.get(item_id.as_str()) {
+                    Some(ParamsSpecLocation {
+                        item_id_location: _,
+                        field_locations: Some(field_locations),
+                    }) => {
+                        let field_origins = field_locations
+                            .iter()
+                            .map(|(field_name, field_location)| {
+                                (field_name.clone(), params_spec_origin(*field_location))
+                            })
+                            .collect::<BTreeMap<_, _>>();
+                        ParamsSpecFieldOrigins::from_field_origins(field_origins)
+                    }
+                    Some(ParamsSpecLocation {
+                        item_id_location,
+                        field_locations: None,
+                    }) => ParamsSpecFieldOrigins::from_params_spec(params_spec_origin(
+                        *item_id_location,
+                    )),
+                    None => ParamsSpecFieldOrigins::from_params_spec(params_spec_origin((1, 1))),
+                };
+                (item_id.clone(), params_spec_field_origins)
+            })
+            .collect::<HashMap<_, _>>();
+
+        Ok((params_specs, ParamsSpecsOrigins::from(params_specs_origins)))
+    }
+
+    /// Returns the [`ParamsSpecs`] of all [`Item`]s if it exists on disk.
+    ///
+    /// # Parameters:
+    ///
+    /// * `storage`: `Storage` to read from.
+    /// * `params_specs_type_reg`: Type registry with functions to deserialize
+    ///   each params spec.
+    /// * `params_specs_file`: Path of the params specs file to deserialize.
+    ///
+    /// [`Item`]: peace_cfg::Item
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn deserialize_internal(
+        thread_name: String,
+        profile: &Profile,
+        flow_id: &FlowId,
+        storage: &Storage,
+        params_specs_type_reg: &ParamsSpecsTypeReg,
+        params_specs_file: &Path,
+    ) -> Result<Option<ParamsSpecs>, E> {
+        let params_specs_opt = storage
+            .serialized_typemap_read_opt_encrypted(
+                thread_name,
+                params_specs_type_reg,
+                params_specs_file,
+                |file_bytes, error| {
+                    params_specs_deserialize_error(
+                        profile,
+                        flow_id,
+                        params_specs_file,
+                        file_bytes,
+                        error,
+                    )
                 },
             )
             .await
@@ -145,7 +359,7 @@ where
     /// * `storage`: `Storage` to read from.
     /// * `params_specs_type_reg`: Type registry with functions to deserialize
     ///   each params spec.
-    /// * `params_specs_file`: `ParamsSpecsFile` to deserialize.
+    /// * `params_specs_file`: Path of the params specs file to deserialize.
     ///
     /// [`Item`]: peace_cfg::Item
     #[cfg(target_arch = "wasm32")]
@@ -154,40 +368,22 @@ where
         flow_id: &FlowId,
         storage: &Storage,
         params_specs_type_reg: &ParamsSpecsTypeReg,
-        params_specs_file: &ParamsSpecsFile,
+        params_specs_file: &Path,
     ) -> Result<Option<ParamsSpecs>, E> {
         let params_specs_opt = storage
-            .serialized_typemap_read_opt(params_specs_type_reg, params_specs_file, |error| {
-                #[cfg(not(feature = "error_reporting"))]
-                {
-                    Error::ParamsSpecsDeserialize {
-                        profile: profile.clone(),
-                        flow_id: flow_id.clone(),
-                        error,
-                    }
-                }
-                #[cfg(feature = "error_reporting")]
-                {
-                    use miette::NamedSource;
-
-                    let file_contents = std::fs::read_to_string(params_specs_file).unwrap();
-
-                    let (error_span, error_message, context_span) =
-                        crate::yaml_error_context_hack::error_and_context(&file_contents, &error);
-                    let params_specs_file_source =
-                        NamedSource::new(params_specs_file.to_string_lossy(), file_contents);
-
-                    Error::ParamsSpecsDeserialize {
-                        profile: profile.clone(),
-                        flow_id: flow_id.clone(),
-                        params_specs_file_source,
-                        error_span,
-                        error_message,
-                        context_span,
+            .serialized_typemap_read_opt_encrypted(
+                params_specs_type_reg,
+                params_specs_file,
+                |file_bytes, error| {
+                    params_specs_deserialize_error(
+                        profile,
+                        flow_id,
+                        params_specs_file,
+                        file_bytes,
                         error,
-                    }
-                }
-            })
+                    )
+                },
+            )
             .await
             .map(|type_map_opt| {
                 type_map_opt
@@ -198,3 +394,230 @@ where
         Ok(params_specs_opt)
     }
 }
+
+/// Returns the error for when a params specs file fails to be deserialized.
+fn params_specs_deserialize_error(
+    profile: &Profile,
+    flow_id: &FlowId,
+    #[cfg_attr(not(feature = "error_reporting"), allow(unused_variables))] params_specs_file: &Path,
+    #[cfg_attr(not(feature = "error_reporting"), allow(unused_variables))] file_bytes: &[u8],
+    error: SerializationError,
+) -> Error {
+    #[cfg(not(feature = "error_reporting"))]
+    {
+        Error::ParamsSpecsDeserialize {
+            profile: profile.clone(),
+            flow_id: flow_id.clone(),
+            error,
+        }
+    }
+    #[cfg(feature = "error_reporting")]
+    {
+        use miette::NamedSource;
+
+        let file_contents = String::from_utf8_lossy(file_bytes).into_owned();
+
+        let (error_span, error_message, context_span) =
+            crate::yaml_error_context_hack::serialization_error_and_context(&file_contents, &error);
+        let params_specs_file_source =
+            NamedSource::new(params_specs_file.to_string_lossy(), file_contents);
+
+        Error::ParamsSpecsDeserialize {
+            profile: profile.clone(),
+            flow_id: flow_id.clone(),
+            params_specs_file_source,
+            error_span,
+            error_message,
+            context_span,
+            error,
+        }
+    }
+}
+
+/// Line and column of an item's params spec in a params specs override file.
+struct ParamsSpecLocation {
+    /// Line and column of the item ID key.
+    item_id_location: (usize, usize),
+    /// Name, line, and column of each field set by a field-wise params spec.
+    ///
+    /// This is `None` if the params spec is not field-wise, such as `!Value`.
+    field_locations: Option<Vec<(String, (usize, usize))>>,
+}
+
+/// Returns the location of each item's params spec in a params specs override
+/// file.
+///
+/// The file is read in a single pass over its YAML events. JSON is read the
+/// same way, as JSON documents are also YAML documents.
+///
+/// Field-wise params specs are `!FieldWise` tagged mappings in YAML, and
+/// `{"FieldWise": ..}` mappings in JSON. Fields that are `Stored` are not set
+/// by the file, so their locations are not returned.
+fn params_spec_locations(file_contents: &str) -> HashMap<String, ParamsSpecLocation> {
+    let mut params_spec_locations = HashMap::new();
+
+    // The file has already been deserialized, so scan errors are not expected.
+    // If there is one, locations up to that point are returned.
+    let mut events = Parser::new_from_str(file_contents).map_while(Result::ok);
+    let root = events
+        .find(|(event, _span)| !matches!(event, Event::StreamStart | Event::DocumentStart(_)));
+    if !matches!(root, Some((Event::MappingStart(..), _))) {
+        return params_spec_locations;
+    }
+
+    while let Some((event, span)) = events.next() {
+        match event {
+            Event::Scalar(item_id, ..) => {
+                let field_locations = params_spec_field_locations(&mut events);
+                params_spec_locations.insert(
+                    item_id.into_owned(),
+                    ParamsSpecLocation {
+                        item_id_location: span_location(span),
+                        field_locations,
+                    },
+                );
+            }
+            Event::MappingEnd => break,
+            // Keys that are not scalars are not item IDs.
+            event => {
+                node_skip_from(&mut events, &event);
+                node_skip(&mut events);
+            }
+        }
+    }
+
+    params_spec_locations
+}
+
+/// Returns the locations of the fields set by a params spec, if it is
+/// field-wise.
+///
+/// This consumes the params spec's events.
+fn params_spec_field_locations<'input>(
+    events: &mut impl Iterator<Item = (Event<'input>, Span)>,
+) -> Option<Vec<(String, (usize, usize))>> {
+    let (event, _span) = events.next()?;
+    match event {
+        // YAML: `!FieldWise { field_wise_spec: {..} }`
+        Event::MappingStart(_, Some(tag)) if tag_is(&tag, "FieldWise") => {
+            field_wise_field_locations(events)
+        }
+        // JSON: `{ "FieldWise": { "field_wise_spec": {..} } }`
+        Event::MappingStart(_, None) => {
+            let mut field_locations = None;
+            while let Some((key, _span)) = events.next() {
+                match key {
+                    Event::MappingEnd => break,
+                    Event::Scalar(key, ..) if key == "FieldWise" => match events.next()? {
+                        (Event::MappingStart(..), _span) => {
+                            field_locations = field_wise_field_locations(events);
+                        }
+                        (value, _span) => node_skip_from(events, &value),
+                    },
+                    key => {
+                        node_skip_from(events, &key);
+                        node_skip(events);
+                    }
+                }
+            }
+            field_locations
+        }
+        event => {
+            node_skip_from(events, &event);
+            None
+        }
+    }
+}
+
+/// Returns the locations of the fields in the `field_wise_spec` of a
+/// field-wise params spec.
+///
+/// This is called after the `MappingStart` of the `FieldWise` value, and
+/// consumes events up to and including its `MappingEnd`.
+fn field_wise_field_locations<'input>(
+    events: &mut impl Iterator<Item = (Event<'input>, Span)>,
+) -> Option<Vec<(String, (usize, usize))>> {
+    let mut field_locations = Vec::new();
+    while let Some((key, _span)) = events.next() {
+        match key {
+            Event::MappingEnd => return Some(field_locations),
+            Event::Scalar(key, ..) if key == "field_wise_spec" => match events.next()? {
+                (Event::MappingStart(..), _span) => {
+                    while let Some((field_name, span)) = events.next() {
+                        let Event::Scalar(field_name, ..) = field_name else {
+                            if matches!(field_name, Event::MappingEnd) {
+                                break;
+                            }
+                            node_skip_from(events, &field_name);
+                            node_skip(events);
+                            continue;
+                        };
+
+                        let (value_spec, _span) = events.next()?;
+                        let is_stored = match &value_spec {
+                            Event::Scalar(variant, _, _, None) => variant == "Stored",
+                            Event::Scalar(_, _, _, Some(tag))
+                            | Event::MappingStart(_, Some(tag))
+                            | Event::SequenceStart(_, Some(tag)) => tag_is(tag, "Stored"),
+                            _ => false,
+                        };
+                        node_skip_from(events, &value_spec);
+
+                        if !is_stored {
+                            field_locations.push((field_name.into_owned(), span_location(span)));
+                        }
+                    }
+                }
+                (value, _span) => node_skip_from(events, &value),
+            },
+            key => {
+                node_skip_from(events, &key);
+                node_skip(events);
+            }
+        }
+    }
+
+    Some(field_locations)
+}
+
+/// Consumes the events of the next node.
+fn node_skip<'input>(events: &mut impl Iterator<Item = (Event<'input>, Span)>) {
+    if let Some((event, _span)) = events.next() {
+        node_skip_from(events, &event);
+    }
+}
+
+/// Consumes the remaining events of a node whose first event is `event`.
+fn node_skip_from<'input>(
+    events: &mut impl Iterator<Item = (Event<'input>, Span)>,
+    event: &Event<'input>,
+) {
+    if !matches!(event, Event::MappingStart(..) | Event::SequenceStart(..)) {
+        return;
+    }
+
+    let mut depth = 1usize;
+    for (event, _span) in events {
+        match event {
+            Event::MappingStart(..) | Event::SequenceStart(..) => depth += 1,
+            Event::MappingEnd | Event::SequenceEnd => {
+                depth -= 1;
+                if depth == 0 {
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Returns whether `tag` is the local tag `!name`, which is how externally
+/// tagged enum variants are represented in YAML.
+fn tag_is(tag: &Tag, name: &str) -> bool {
+    tag.handle == "!" && tag.suffix == name
+}
+
+/// Returns the 1-based line and column that `span` starts at.
+fn span_location(span: Span) -> (usize, usize) {
+    (span.start.line(), span.start.col() + 1)
+}
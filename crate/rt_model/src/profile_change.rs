@@ -0,0 +1,105 @@
+use peace_cfg::Profile;
+use peace_fmt::{Presentable, Presenter};
+use serde::{Deserialize, Serialize};
+
+/// Change made to a profile by one of the profile commands.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfileChange {
+    /// The profile was created.
+    Created {
+        /// The created profile.
+        profile: Profile,
+    },
+    /// The profile was copied to a new profile.
+    Copied {
+        /// The profile that was copied.
+        profile_from: Profile,
+        /// The new profile.
+        profile_to: Profile,
+        /// Whether stored states were copied to the new profile.
+        states_copied: bool,
+    },
+    /// The profile was renamed.
+    Renamed {
+        /// The profile's previous name.
+        profile_from: Profile,
+        /// The profile's new name.
+        profile_to: Profile,
+    },
+    /// The profile was deleted.
+    Deleted {
+        /// The deleted profile.
+        profile: Profile,
+    },
+    /// The profile's parent profile was set or removed.
+    ParentSet {
+        /// The profile whose parent was set.
+        profile: Profile,
+        /// The profile's new parent, or `None` if the parent was removed.
+        profile_parent: Option<Profile>,
+    },
+}
+
+#[peace_fmt::async_trait(?Send)]
+impl Presentable for ProfileChange {
+    async fn present<'output, PR>(&self, presenter: &mut PR) -> Result<(), PR::Error>
+    where
+        PR: Presenter<'output>,
+    {
+        match self {
+            Self::Created { profile } => {
+                presenter.text("Created profile ").await?;
+                profile.present(presenter).await?;
+                presenter.text(".").await
+            }
+            Self::Copied {
+                profile_from,
+                profile_to,
+                states_copied,
+            } => {
+                presenter.text("Copied profile ").await?;
+                profile_from.present(presenter).await?;
+                presenter.text(" to ").await?;
+                profile_to.present(presenter).await?;
+                if *states_copied {
+                    presenter.text(", including stored states.").await
+                } else {
+                    presenter.text(", excluding stored states.").await
+                }
+            }
+            Self::Renamed {
+                profile_from,
+                profile_to,
+            } => {
+                presenter.text("Renamed profile ").await?;
+                profile_from.present(presenter).await?;
+                presenter.text(" to ").await?;
+                profile_to.present(presenter).await?;
+                presenter.text(".").await
+            }
+            Self::Deleted { profile } => {
+                presenter.text("Deleted profile ").await?;
+                profile.present(presenter).await?;
+                presenter.text(".").await
+            }
+            Self::ParentSet {
+                profile,
+                profile_parent,
+            } => match profile_parent {
+                Some(profile_parent) => {
+                    presenter.text("Set parent of profile ").await?;
+                    profile.present(presenter).await?;
+                    presenter.text(" to ").await?;
+                    profile_parent.present(presenter).await?;
+                    presenter.text(".").await
+                }
+                None => {
+                    presenter.text("Removed parent of profile ").await?;
+                    profile.present(presenter).await?;
+                    presenter.text(".").await
+                }
+            },
+        }
+    }
+}
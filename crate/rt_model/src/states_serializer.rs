@@ -1,16 +1,19 @@
-use std::{marker::PhantomData, path::Path};
+use std::{collections::BTreeSet, marker::PhantomData, path::Path};
 
 use peace_cfg::{FlowId, ItemId};
 use peace_resource_rt::{
     paths::{StatesCurrentFile, StatesGoalFile},
     states::{
         ts::{CurrentStored, GoalStored},
-        States, StatesCurrentStored, StatesGoalStored,
+        States, StatesCurrentStored, StatesGoalStored, StatesSerde,
+    },
+    type_reg::{
+        common::UnknownEntriesSome,
+        untagged::{BoxDtDisplay, TypeMapOpt, TypeReg},
     },
-    type_reg::untagged::{BoxDtDisplay, TypeMapOpt, TypeReg},
 };
 
-use crate::{Error, ItemGraph, Storage};
+use crate::{Error, ItemGraph, SerializationError, SerializationFormat, StatesLayout, Storage};
 
 /// Reads and writes [`StatesCurrentStored`] and [`StatesGoalStored`] to and
 /// from storage.
@@ -20,12 +23,19 @@ impl<E> StatesSerializer<E>
 where
     E: std::error::Error + From<Error> + Send + 'static,
 {
-    /// Returns the [`StatesCurrentStored`] of all [`Item`]s if it exists on
-    /// disk.
+    /// Serializes the [`States`] of all [`Item`]s to disk.
+    ///
+    /// The file is encrypted if encryption is set on the `Storage`.
+    ///
+    /// With [`StatesLayout::PerItem`], each item's state is written to its own
+    /// file, and only the files whose contents differ from the states last
+    /// read or written through the `Storage` are written. Files of items that
+    /// are no longer in the flow are removed. If the states were stored in a
+    /// single file, that file is removed.
     ///
     /// # Parameters:
     ///
-    /// * `storage`: `Storage` to read from.
+    /// * `storage`: `Storage` to write to.
     /// * `states`: States to serialize.
     /// * `states_file_path`: Path to save the serialized states to.
     ///
@@ -39,16 +49,81 @@ where
     where
         TS: Send + Sync,
     {
-        let states_serde = item_graph.states_serde::<serde_yaml::Value, _>(states);
-        storage
-            .serialized_write(
-                #[cfg(not(target_arch = "wasm32"))]
-                "StatesSerializer::serialize".to_string(),
-                states_file_path,
-                &states_serde,
-                Error::StatesSerialize,
-            )
-            .await?;
+        match storage.states_layout() {
+            StatesLayout::SingleFile => {
+                let states_serde = item_graph.states_serde::<serde_yaml::Value, _>(states);
+                storage
+                    .serialized_write_encrypted(
+                        #[cfg(not(target_arch = "wasm32"))]
+                        "StatesSerializer::serialize".to_string(),
+                        states_file_path,
+                        &states_serde,
+                        Error::StatesSerialize,
+                    )
+                    .await?;
+            }
+            StatesLayout::PerItem => {
+                let item_states_dir = StatesLayout::item_states_dir(states_file_path);
+                storage
+                    .backend()
+                    .dirs_create(&[item_states_dir.as_path()])
+                    .await?;
+
+                let mut item_ids = BTreeSet::new();
+                for item in item_graph.iter_insertion() {
+                    let item_id = item.id();
+                    item_ids.insert(item_id.as_str());
+                    let item_states_serde = StatesSerde::<serde_yaml::Value>::from_iter([(
+                        item_id.clone(),
+                        states.get_raw(item_id).cloned(),
+                    )]);
+                    let item_states_file_path =
+                        StatesLayout::item_states_file_path(states_file_path, item_id);
+                    storage
+                        .serialized_write_encrypted_if_changed(
+                            #[cfg(not(target_arch = "wasm32"))]
+                            "StatesSerializer::serialize".to_string(),
+                            &item_states_file_path,
+                            &item_states_serde,
+                            Error::StatesSerialize,
+                        )
+                        .await?;
+                }
+
+                // Items that are no longer in the flow keep their files, so
+                // they are removed.
+                let item_ids_removed = storage
+                    .backend()
+                    .paths_under(&item_states_dir)
+                    .await?
+                    .into_iter()
+                    .filter(|path| path.parent() == Some(item_states_dir.as_path()))
+                    .filter_map(|path| {
+                        let item_id = path.file_stem()?.to_str()?;
+                        (!item_ids.contains(item_id)).then(|| item_id.to_string())
+                    })
+                    .collect::<BTreeSet<String>>();
+                for item_id in item_ids_removed {
+                    storage
+                        .remove(&item_states_dir.join(format!("{item_id}.yaml")))
+                        .await?;
+                }
+
+                // The per-item files supersede the single states file, which
+                // only exists when switching from the single file layout.
+                let mut single_file_exists = false;
+                for serialization_format in SerializationFormat::ALL {
+                    let single_file_path = serialization_format.file_path(states_file_path);
+                    if storage.backend().contains_item(&single_file_path).await? {
+                        single_file_exists = true;
+                        break;
+                    }
+                }
+                if single_file_exists {
+                    storage.remove(states_file_path).await?;
+                }
+            }
+        }
 
         Ok(())
     }
@@ -77,6 +152,44 @@ where
             storage,
             states_type_reg,
             states_current_file,
+            states_type_reg.keys(),
+        )
+        .await?;
+
+        states.ok_or_else(|| E::from(Error::StatesCurrentDiscoverRequired))
+    }
+
+    /// Returns the [`StatesCurrentStored`] of the given [`Item`]s if it exists
+    /// on disk.
+    ///
+    /// With [`StatesLayout::PerItem`], only the given items' files are read,
+    /// so cmds that touch a few items of a large flow do not read every
+    /// item's state.
+    ///
+    /// # Parameters:
+    ///
+    /// * `storage`: `Storage` to read from.
+    /// * `states_type_reg`: Type registry with functions to deserialize each
+    ///   item state.
+    /// * `states_current_file`: `StatesCurrentFile` to deserialize.
+    /// * `item_ids`: IDs of the items whose states to read.
+    ///
+    /// [`Item`]: peace_cfg::Item
+    pub async fn deserialize_stored_items(
+        flow_id: &FlowId,
+        storage: &Storage,
+        states_type_reg: &TypeReg<ItemId, BoxDtDisplay>,
+        states_current_file: &StatesCurrentFile,
+        item_ids: &[ItemId],
+    ) -> Result<StatesCurrentStored, E> {
+        let states = Self::deserialize_internal::<CurrentStored>(
+            #[cfg(not(target_arch = "wasm32"))]
+            "StatesSerializer::deserialize_stored_items".to_string(),
+            flow_id,
+            storage,
+            states_type_reg,
+            states_current_file,
+            item_ids,
         )
         .await?;
 
@@ -106,6 +219,44 @@ where
             storage,
             states_type_reg,
             states_goal_file,
+            states_type_reg.keys(),
+        )
+        .await?;
+
+        states.ok_or_else(|| E::from(Error::StatesGoalDiscoverRequired))
+    }
+
+    /// Returns the [`StatesGoalStored`] of the given [`Item`]s if it exists on
+    /// disk.
+    ///
+    /// With [`StatesLayout::PerItem`], only the given items' files are read,
+    /// so cmds that touch a few items of a large flow do not read every
+    /// item's state.
+    ///
+    /// # Parameters:
+    ///
+    /// * `storage`: `Storage` to read from.
+    /// * `states_type_reg`: Type registry with functions to deserialize each
+    ///   item state.
+    /// * `states_goal_file`: `StatesGoalFile` to deserialize.
+    /// * `item_ids`: IDs of the items whose states to read.
+    ///
+    /// [`Item`]: peace_cfg::Item
+    pub async fn deserialize_goal_items(
+        flow_id: &FlowId,
+        storage: &Storage,
+        states_type_reg: &TypeReg<ItemId, BoxDtDisplay>,
+        states_goal_file: &StatesGoalFile,
+        item_ids: &[ItemId],
+    ) -> Result<StatesGoalStored, E> {
+        let states = Self::deserialize_internal::<GoalStored>(
+            #[cfg(not(target_arch = "wasm32"))]
+            "StatesSerializer::deserialize_goal_items".to_string(),
+            flow_id,
+            storage,
+            states_type_reg,
+            states_goal_file,
+            item_ids,
         )
         .await?;
 
@@ -136,11 +287,15 @@ where
             storage,
             states_type_reg,
             states_current_file,
+            states_type_reg.keys(),
         )
         .await
     }
 
-    /// Returns the [`States`] of all [`Item`]s if it exists on disk.
+    /// Returns the [`StatesCurrentStored`] of the given [`Item`]s if it exists
+    /// on disk.
+    ///
+    /// With [`StatesLayout::PerItem`], only the given items' files are read.
     ///
     /// # Parameters:
     ///
@@ -148,74 +303,41 @@ where
     /// * `states_type_reg`: Type registry with functions to deserialize each
     ///   item state.
     /// * `states_current_file`: `StatesCurrentFile` to deserialize.
-    ///
-    /// # Type Parameters
-    ///
-    /// * `TS`: The states type state to use, such as [`ts::Current`] or
-    ///   [`ts::CurrentStored`].
+    /// * `item_ids`: IDs of the items whose states to read.
     ///
     /// [`Item`]: peace_cfg::Item
-    /// [`ts::Current`]: peace_resource_rt::states::ts::Current
-    /// [`ts::CurrentStored`]: peace_resource_rt::states::ts::CurrentStored
-    #[cfg(not(target_arch = "wasm32"))]
-    async fn deserialize_internal<TS>(
-        thread_name: String,
+    pub async fn deserialize_stored_items_opt(
         flow_id: &FlowId,
         storage: &Storage,
         states_type_reg: &TypeReg<ItemId, BoxDtDisplay>,
-        states_file_path: &Path,
-    ) -> Result<Option<States<TS>>, E>
-    where
-        TS: Send + Sync,
-    {
-        let states_opt = storage
-            .serialized_typemap_read_opt(thread_name, states_type_reg, states_file_path, |error| {
-                #[cfg(not(feature = "error_reporting"))]
-                {
-                    Error::StatesDeserialize {
-                        flow_id: flow_id.clone(),
-                        error,
-                    }
-                }
-                #[cfg(feature = "error_reporting")]
-                {
-                    use miette::NamedSource;
-
-                    let file_contents = std::fs::read_to_string(states_file_path).unwrap();
-
-                    let (error_span, error_message, context_span) =
-                        crate::yaml_error_context_hack::error_and_context(&file_contents, &error);
-                    let states_file_source =
-                        NamedSource::new(states_file_path.to_string_lossy(), file_contents);
-
-                    Error::StatesDeserialize {
-                        flow_id: flow_id.clone(),
-                        states_file_source,
-                        error_span,
-                        error_message,
-                        context_span,
-                        error,
-                    }
-                }
-            })
-            .await
-            .map(|type_map_opt| {
-                type_map_opt
-                    .map(TypeMapOpt::into_type_map)
-                    .map(States::from)
-            })?;
-
-        Ok(states_opt)
+        states_current_file: &StatesCurrentFile,
+        item_ids: &[ItemId],
+    ) -> Result<Option<StatesCurrentStored>, E> {
+        Self::deserialize_internal(
+            #[cfg(not(target_arch = "wasm32"))]
+            "StatesSerializer::deserialize_stored_items_opt".to_string(),
+            flow_id,
+            storage,
+            states_type_reg,
+            states_current_file,
+            item_ids,
+        )
+        .await
     }
 
-    /// Returns the [`States`] of all [`Item`]s if it exists on disk.
+    /// Returns the [`States`] of the given [`Item`]s if it exists on disk.
+    ///
+    /// With [`StatesLayout::PerItem`], only the files of the given items are
+    /// read. If none of them exist, the single states file is read, so states
+    /// stored before the layout was changed are still used.
     ///
     /// # Parameters:
     ///
     /// * `storage`: `Storage` to read from.
     /// * `states_type_reg`: Type registry with functions to deserialize each
     ///   item state.
-    /// * `states_current_file`: `StatesCurrentFile` to deserialize.
+    /// * `states_file_path`: Path of the states file to deserialize.
+    /// * `item_ids`: IDs of the items whose states to read.
     ///
     /// # Type Parameters
     ///
@@ -225,53 +347,121 @@ where
     /// [`Item`]: peace_cfg::Item
     /// [`ts::Current`]: peace_resource_rt::states::ts::Current
     /// [`ts::CurrentStored`]: peace_resource_rt::states::ts::CurrentStored
-    #[cfg(target_arch = "wasm32")]
     async fn deserialize_internal<TS>(
+        #[cfg(not(target_arch = "wasm32"))] thread_name: String,
         flow_id: &FlowId,
         storage: &Storage,
         states_type_reg: &TypeReg<ItemId, BoxDtDisplay>,
         states_file_path: &Path,
+        item_ids: impl IntoIterator<Item = &ItemId>,
     ) -> Result<Option<States<TS>>, E>
     where
         TS: Send + Sync,
     {
-        let states_opt = storage
-            .serialized_typemap_read_opt(states_type_reg, states_file_path, |error| {
-                #[cfg(not(feature = "error_reporting"))]
-                {
-                    Error::StatesDeserialize {
-                        flow_id: flow_id.clone(),
-                        error,
+        let item_ids = item_ids.into_iter().collect::<Vec<&ItemId>>();
+        if storage.states_layout() == StatesLayout::PerItem {
+            let mut type_map_opt = None;
+            for item_id in item_ids.iter().copied() {
+                let item_states_file_path =
+                    StatesLayout::item_states_file_path(states_file_path, item_id);
+                let item_type_map_opt = Self::typemap_read_opt(
+                    #[cfg(not(target_arch = "wasm32"))]
+                    thread_name.clone(),
+                    flow_id,
+                    storage,
+                    states_type_reg,
+                    &item_states_file_path,
+                )
+                .await?;
+
+                match (&mut type_map_opt, item_type_map_opt) {
+                    (_, None) => {}
+                    (None, Some(item_type_map_opt)) => type_map_opt = Some(item_type_map_opt),
+                    (Some(type_map_opt), Some(item_type_map_opt)) => {
+                        type_map_opt.extend(item_type_map_opt.into_inner().0);
                     }
                 }
-                #[cfg(feature = "error_reporting")]
-                {
-                    use miette::NamedSource;
+            }
 
-                    let file_contents = std::fs::read_to_string(states_file_path).unwrap();
+            if let Some(type_map_opt) = type_map_opt {
+                return Ok(Some(States::from(type_map_opt.into_type_map())));
+            }
+        }
 
-                    let (error_span, error_message, context_span) =
-                        crate::yaml_error_context_hack::error_and_context(&file_contents, &error);
-                    let states_file_source =
-                        NamedSource::new(states_file_path.to_string_lossy(), file_contents);
+        let states_opt = Self::typemap_read_opt(
+            #[cfg(not(target_arch = "wasm32"))]
+            thread_name,
+            flow_id,
+            storage,
+            states_type_reg,
+            states_file_path,
+        )
+        .await?
+        .map(|mut type_map_opt| {
+            type_map_opt.retain(|item_id, _| item_ids.contains(&item_id));
+            States::from(type_map_opt.into_type_map())
+        });
 
-                    Error::StatesDeserialize {
-                        flow_id: flow_id.clone(),
-                        states_file_source,
-                        error_span,
-                        error_message,
-                        context_span,
-                        error,
-                    }
-                }
-            })
+        Ok(states_opt)
+    }
+
+    /// Deserializes the states in the given file, if it exists.
+    async fn typemap_read_opt(
+        #[cfg(not(target_arch = "wasm32"))] thread_name: String,
+        flow_id: &FlowId,
+        storage: &Storage,
+        states_type_reg: &TypeReg<ItemId, BoxDtDisplay>,
+        states_file_path: &Path,
+    ) -> Result<
+        Option<TypeMapOpt<ItemId, BoxDtDisplay, UnknownEntriesSome<serde_yaml::Value>>>,
+        Error,
+    > {
+        storage
+            .serialized_typemap_read_opt_encrypted(
+                #[cfg(not(target_arch = "wasm32"))]
+                thread_name,
+                states_type_reg,
+                states_file_path,
+                |file_bytes, error| {
+                    states_deserialize_error(flow_id, states_file_path, file_bytes, error)
+                },
+            )
             .await
-            .map(|type_map_opt| {
-                type_map_opt
-                    .map(TypeMapOpt::into_type_map)
-                    .map(States::from)
-            })?;
+    }
+}
 
-        Ok(states_opt)
+/// Returns the error for when a states file fails to be deserialized.
+fn states_deserialize_error(
+    flow_id: &FlowId,
+    #[cfg_attr(not(feature = "error_reporting"), allow(unused_variables))] states_file_path: &Path,
+    #[cfg_attr(not(feature = "error_reporting"), allow(unused_variables))] file_bytes: &[u8],
+    error: SerializationError,
+) -> Error {
+    #[cfg(not(feature = "error_reporting"))]
+    {
+        Error::StatesDeserialize {
+            flow_id: flow_id.clone(),
+            error,
+        }
+    }
+    #[cfg(feature = "error_reporting")]
+    {
+        use miette::NamedSource;
+
+        let file_contents = String::from_utf8_lossy(file_bytes).into_owned();
+
+        let (error_span, error_message, context_span) =
+            crate::yaml_error_context_hack::serialization_error_and_context(&file_contents, &error);
+        let states_file_source =
+            NamedSource::new(states_file_path.to_string_lossy(), file_contents);
+
+        Error::StatesDeserialize {
+            flow_id: flow_id.clone(),
+            states_file_source,
+            error_span,
+            error_message,
+            context_span,
+            error,
+        }
     }
 }
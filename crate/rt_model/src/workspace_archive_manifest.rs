@@ -0,0 +1,51 @@
+use peace_cfg::{AppName, FlowId};
+use serde::{Deserialize, Serialize};
+
+use crate::WorkspaceArchiveProfile;
+
+/// Describes the contents of a workspace archive.
+///
+/// This is written at the root of the archive by `WorkspaceExportCmd`, and is
+/// validated by `WorkspaceImportCmd` before any files are imported.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkspaceArchiveManifest {
+    /// Version of the archive layout.
+    pub archive_version: u32,
+    /// Version of `peace` that exported the archive.
+    pub peace_version: String,
+    /// Name of the application whose workspace was exported.
+    pub app_name: AppName,
+    /// Profiles in the archive, and the flows that have data in each profile.
+    pub profiles: Vec<WorkspaceArchiveProfile>,
+}
+
+impl WorkspaceArchiveManifest {
+    /// Version of the archive layout written by this version of `peace`.
+    pub const ARCHIVE_VERSION: u32 = 1;
+    /// Path of the manifest file within the archive.
+    pub const PATH: &'static str = "peace_archive_manifest.yaml";
+
+    /// Returns a new manifest for the current archive and `peace` versions.
+    pub fn new(app_name: AppName, profiles: Vec<WorkspaceArchiveProfile>) -> Self {
+        Self {
+            archive_version: Self::ARCHIVE_VERSION,
+            peace_version: String::from(env!("CARGO_PKG_VERSION")),
+            app_name,
+            profiles,
+        }
+    }
+
+    /// Returns the IDs of all flows across the archive's profiles, without
+    /// duplicates, in the order they first appear.
+    pub fn flow_ids(&self) -> Vec<FlowId> {
+        self.profiles
+            .iter()
+            .flat_map(|profile| profile.flow_ids.iter())
+            .fold(Vec::new(), |mut flow_ids, flow_id| {
+                if !flow_ids.contains(flow_id) {
+                    flow_ids.push(flow_id.clone());
+                }
+                flow_ids
+            })
+    }
+}
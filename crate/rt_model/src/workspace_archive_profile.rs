@@ -0,0 +1,11 @@
+use peace_cfg::{FlowId, Profile};
+use serde::{Deserialize, Serialize};
+
+/// A profile within a workspace archive.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkspaceArchiveProfile {
+    /// The exported profile.
+    pub profile: Profile,
+    /// Flows that have params specs or states stored in the profile.
+    pub flow_ids: Vec<FlowId>,
+}
@@ -1,3 +1,5 @@
+use peace_rt_model_core::SerializationError;
+
 /// Returns the error location and message to pass to miette.
 ///
 /// TODO: Replace hack.
@@ -88,3 +90,37 @@ pub(crate) fn error_and_context(
         .unwrap_or(error_string);
     (error_span, error_message, context_span)
 }
+
+/// Returns the error location and message to pass to miette, for a workspace
+/// file in any serialization format.
+///
+/// Only YAML and JSON errors carry a location. MessagePack files are binary,
+/// so there is no source text to point into.
+pub(crate) fn serialization_error_and_context(
+    file_contents: &str,
+    error: &SerializationError,
+) -> (
+    Option<miette::SourceOffset>,
+    String,
+    Option<miette::SourceOffset>,
+) {
+    match error {
+        SerializationError::Yaml(error) => error_and_context(file_contents, error),
+        SerializationError::Json(error) => {
+            let error_span = Some(miette::SourceOffset::from_location(
+                file_contents,
+                error.line(),
+                error.column(),
+            ));
+            let error_string = format!("{error}");
+            let error_message = error_string
+                .split(" at line ")
+                .next()
+                .map(str::to_string)
+                .unwrap_or(error_string);
+            (error_span, error_message, None)
+        }
+        SerializationError::MessagePackSerialize(error) => (None, format!("{error}"), None),
+        SerializationError::MessagePackDeserialize(error) => (None, format!("{error}"), None),
+    }
+}
@@ -0,0 +1,66 @@
+use std::fmt;
+
+use base64::Engine;
+use chacha20poly1305::{aead::OsRng, ChaCha20Poly1305, KeyInit};
+
+use crate::{EncryptionError, EncryptionKeyProvider};
+
+/// 256 bit key used to encrypt workspace files at rest.
+///
+/// Keys are stored and passed around as standard base64 encoded strings,
+/// e.g. the output of `openssl rand -base64 32`.
+///
+/// The `Debug` implementation does not print the key bytes.
+#[derive(Clone, PartialEq, Eq)]
+pub struct EncryptionKey([u8; Self::LEN]);
+
+impl EncryptionKey {
+    /// Number of bytes in an encryption key.
+    pub const LEN: usize = 32;
+
+    /// Returns a new `EncryptionKey` from the given bytes.
+    pub fn new(bytes: [u8; Self::LEN]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns a new randomly generated `EncryptionKey`.
+    pub fn generate() -> Self {
+        let key = ChaCha20Poly1305::generate_key(&mut OsRng);
+        Self(key.into())
+    }
+
+    /// Decodes an `EncryptionKey` from a standard base64 encoded string.
+    ///
+    /// Leading and trailing whitespace is ignored.
+    pub fn from_base64(key_base64: &str) -> Result<Self, EncryptionError> {
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(key_base64.trim())
+            .map_err(EncryptionError::KeyDecode)?;
+        let length = bytes.len();
+        <[u8; Self::LEN]>::try_from(bytes)
+            .map(Self)
+            .map_err(|_bytes| EncryptionError::KeyLength { length })
+    }
+
+    /// Returns this key as a standard base64 encoded string.
+    pub fn to_base64(&self) -> String {
+        base64::engine::general_purpose::STANDARD.encode(self.0)
+    }
+
+    /// Returns the bytes of this key.
+    pub fn as_bytes(&self) -> &[u8; Self::LEN] {
+        &self.0
+    }
+}
+
+impl fmt::Debug for EncryptionKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("EncryptionKey(..)")
+    }
+}
+
+impl EncryptionKeyProvider for EncryptionKey {
+    fn key(&self) -> Result<EncryptionKey, EncryptionError> {
+        Ok(self.clone())
+    }
+}
@@ -0,0 +1,37 @@
+use crate::{EncryptionError, EncryptionKey, EncryptionKeyProvider};
+
+/// Reads the encryption key from an environment variable.
+///
+/// The variable's value is the standard base64 encoded key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EncryptionKeyEnvVar {
+    /// Name of the environment variable.
+    env_var: String,
+}
+
+impl EncryptionKeyEnvVar {
+    /// Returns a new `EncryptionKeyEnvVar` that reads the key from the given
+    /// environment variable.
+    pub fn new(env_var: impl Into<String>) -> Self {
+        Self {
+            env_var: env_var.into(),
+        }
+    }
+
+    /// Returns the name of the environment variable.
+    pub fn env_var(&self) -> &str {
+        &self.env_var
+    }
+}
+
+impl EncryptionKeyProvider for EncryptionKeyEnvVar {
+    fn key(&self) -> Result<EncryptionKey, EncryptionError> {
+        let key_base64 =
+            std::env::var(&self.env_var).map_err(|error| EncryptionError::KeyEnvVarRead {
+                env_var: self.env_var.clone(),
+                error,
+            })?;
+
+        EncryptionKey::from_base64(&key_base64)
+    }
+}
@@ -0,0 +1,38 @@
+use std::path::{Path, PathBuf};
+
+use crate::{EncryptionError, EncryptionKey, EncryptionKeyProvider};
+
+/// Reads the encryption key from a file.
+///
+/// The file contains the standard base64 encoded key, and may end with a
+/// newline.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EncryptionKeyFile {
+    /// Path to the key file.
+    path: PathBuf,
+}
+
+impl EncryptionKeyFile {
+    /// Returns a new `EncryptionKeyFile` that reads the key from the given
+    /// path.
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// Returns the path to the key file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl EncryptionKeyProvider for EncryptionKeyFile {
+    fn key(&self) -> Result<EncryptionKey, EncryptionError> {
+        let key_base64 =
+            std::fs::read_to_string(&self.path).map_err(|error| EncryptionError::KeyFileRead {
+                path: self.path.clone(),
+                error,
+            })?;
+
+        EncryptionKey::from_base64(&key_base64)
+    }
+}
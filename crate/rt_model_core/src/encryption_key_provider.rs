@@ -0,0 +1,22 @@
+use std::fmt::Debug;
+
+use crate::{EncryptionError, EncryptionKey};
+
+/// Provides the key used to encrypt and decrypt workspace files.
+///
+/// The key is requested each time a file is encrypted or decrypted, so
+/// implementations may fetch it from an external secret store. Errors from
+/// such stores may be returned as [`EncryptionError::KeyProvider`].
+///
+/// Implementations provided by peace:
+///
+/// * [`EncryptionKey`]: Uses the key directly.
+/// * [`EncryptionKeyFile`]: Reads the key from a file.
+/// * [`EncryptionKeyEnvVar`]: Reads the key from an environment variable.
+///
+/// [`EncryptionKeyFile`]: crate::EncryptionKeyFile
+/// [`EncryptionKeyEnvVar`]: crate::EncryptionKeyEnvVar
+pub trait EncryptionKeyProvider: Debug + Send + Sync + 'static {
+    /// Returns the encryption key.
+    fn key(&self) -> Result<EncryptionKey, EncryptionError>;
+}
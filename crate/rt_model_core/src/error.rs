@@ -1,13 +1,20 @@
 use std::path::PathBuf;
 
 use peace_cmd_model::CmdExecutionError;
-use peace_core::{FlowId, ItemId, Profile};
-use peace_params::{ParamsResolveError, ParamsSpecs};
+use peace_core::{AppName, FlowId, ItemId, Profile};
+use peace_params::{
+    MappingFnBindError, MappingFnName, MappingFnReg, ParamsResolveError, ParamsSpecs,
+};
 use peace_resource_rt::paths::ParamsSpecsFile;
 
-pub use self::{apply_cmd_error::ApplyCmdError, state_downcast_error::StateDowncastError};
+pub use self::{
+    apply_cmd_error::ApplyCmdError, encryption_error::EncryptionError,
+    serialization_error::SerializationError, state_downcast_error::StateDowncastError,
+};
 
 mod apply_cmd_error;
+mod encryption_error;
+mod serialization_error;
 mod state_downcast_error;
 
 cfg_if::cfg_if! {
@@ -166,6 +173,51 @@ pub enum Error {
         params_specs_not_usable: Vec<ItemId>,
     },
 
+    /// Params specs refer to mapping functions by names that are not
+    /// registered in the flow's `MappingFnReg`.
+    #[error(
+        "Params specs for flow `{flow_id}` refer to mapping functions that are not registered: {}.",
+        mapping_fn_names_list(mapping_fn_names_unknown)
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::mapping_fn_names_unknown),
+            help("{}", mapping_fn_names_unknown_help(mapping_fn_names_registered))
+        )
+    )]
+    MappingFnNamesUnknown {
+        /// ID of the flow whose params specs were being bound.
+        flow_id: FlowId,
+        /// Names referred to by params specs that are not registered.
+        mapping_fn_names_unknown: Vec<MappingFnName>,
+        /// Names of the mapping functions registered for the flow.
+        mapping_fn_names_registered: Vec<MappingFnName>,
+    },
+
+    /// Params specs refer to mapping functions by names that are registered
+    /// in the flow's `MappingFnReg`, but with a different output type.
+    #[error(
+        "Params specs for flow `{flow_id}` refer to mapping functions with a different output type: {}.",
+        mapping_fn_output_type_mismatches_list(mapping_fn_output_type_mismatches)
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::mapping_fn_output_type_mismatch),
+            help(
+                "Register each mapping function under a name that is not used by functions with a different output type."
+            )
+        )
+    )]
+    MappingFnOutputTypeMismatch {
+        /// ID of the flow whose params specs were being bound.
+        flow_id: FlowId,
+        /// Mapping functions whose registered output type differs from the
+        /// params spec field type.
+        mapping_fn_output_type_mismatches: Vec<MappingFnBindError>,
+    },
+
     /// In a `MultiProfileSingleFlow` diff, neither profile had `Params::Specs`
     /// defined.
     #[error("Params specifications not defined for `{profile_a}` or `{profile_b}`.")]
@@ -243,7 +295,7 @@ pub enum Error {
         context_span: Option<miette::SourceOffset>,
         /// Underlying error.
         #[source]
-        error: serde_yaml::Error,
+        error: SerializationError,
     },
 
     /// Failed to serialize states.
@@ -252,7 +304,7 @@ pub enum Error {
         feature = "error_reporting",
         diagnostic(code(peace_rt_model::states_serialize))
     )]
-    StatesSerialize(#[source] serde_yaml::Error),
+    StatesSerialize(#[source] SerializationError),
 
     /// Failed to deserialize params specs.
     #[error("Failed to deserialize params specs for `{profile}/{flow_id}`.")]
@@ -290,16 +342,117 @@ pub enum Error {
         context_span: Option<miette::SourceOffset>,
         /// Underlying error.
         #[source]
+        error: SerializationError,
+    },
+
+    /// A params specs override file is not valid UTF-8.
+    #[error(
+        "Params specs override file is not valid UTF-8: `{}`.",
+        params_specs_override_path.display()
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::params_specs_override_not_utf8),
+            help("Make sure the file is a YAML or JSON file saved with UTF-8 encoding.")
+        )
+    )]
+    ParamsSpecsOverrideNotUtf8 {
+        /// Path to the params specs override file.
+        params_specs_override_path: PathBuf,
+        /// Underlying error.
+        #[source]
+        error: std::string::FromUtf8Error,
+    },
+
+    /// Failed to deserialize a YAML params specs override file.
+    #[error(
+        "Failed to deserialize params specs override file: `{}`.",
+        params_specs_override_path.display()
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::params_specs_override_deserialize),
+            help(
+                "Make sure each key in the file is the ID of an item in the `{flow_id}` flow,\n\
+                and each value is a params spec for that item."
+            )
+        )
+    )]
+    ParamsSpecsOverrideDeserialize {
+        /// Flow ID whose params specs are being overridden.
+        flow_id: FlowId,
+        /// Path to the params specs override file.
+        params_specs_override_path: PathBuf,
+        /// Source text to be deserialized.
+        #[cfg(feature = "error_reporting")]
+        #[source_code]
+        params_specs_override_source: miette::NamedSource<String>,
+        /// Offset within the source text that the error occurred.
+        #[cfg(feature = "error_reporting")]
+        #[label("{}", error_message)]
+        error_span: Option<miette::SourceOffset>,
+        /// Message explaining the error.
+        #[cfg(feature = "error_reporting")]
+        error_message: String,
+        /// Offset within the source text surrounding the error.
+        #[cfg(feature = "error_reporting")]
+        #[label]
+        context_span: Option<miette::SourceOffset>,
+        /// Underlying error.
+        #[source]
         error: serde_yaml::Error,
     },
 
+    /// Failed to deserialize a JSON params specs override file.
+    #[error(
+        "Failed to deserialize params specs override file: `{}`.",
+        params_specs_override_path.display()
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::params_specs_override_deserialize_json),
+            help(
+                "Make sure each key in the file is the ID of an item in the `{flow_id}` flow,\n\
+                and each value is a params spec for that item."
+            )
+        )
+    )]
+    ParamsSpecsOverrideDeserializeJson {
+        /// Flow ID whose params specs are being overridden.
+        flow_id: FlowId,
+        /// Path to the params specs override file.
+        params_specs_override_path: PathBuf,
+        /// Source text to be deserialized.
+        #[cfg(feature = "error_reporting")]
+        #[source_code]
+        params_specs_override_source: miette::NamedSource<String>,
+        /// Offset within the source text that the error occurred.
+        #[cfg(feature = "error_reporting")]
+        #[label("{}", error)]
+        error_span: Option<miette::SourceOffset>,
+        /// Underlying error.
+        #[source]
+        error: serde_json::Error,
+    },
+
     /// Failed to serialize params specs.
     #[error("Failed to serialize params specs.")]
     #[cfg_attr(
         feature = "error_reporting",
         diagnostic(code(peace_rt_model::params_specs_serialize))
     )]
-    ParamsSpecsSerialize(#[source] serde_yaml::Error),
+    ParamsSpecsSerialize(#[source] SerializationError),
+
+    /// Failed to serialize params or params specs to compute a params diff.
+    #[error("Failed to serialize params or params specs to compute a params diff.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::params_diff_serialize))
+    )]
+    ParamsDiffSerialize(#[source] serde_yaml::Error),
 
     /// Params specs file does not exist.
     ///
@@ -388,13 +541,21 @@ pub enum Error {
     )]
     StateDiffsSerializeJson(#[source] serde_json::Error),
 
+    /// Failed to serialize a JSON Lines output event.
+    #[error("Failed to serialize a JSON Lines output event.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::json_lines_serialize))
+    )]
+    JsonLinesSerialize(#[source] serde_json::Error),
+
     /// Failed to serialize workspace init params.
     #[error("Failed to serialize workspace init params.")]
     #[cfg_attr(
         feature = "error_reporting",
         diagnostic(code(peace_rt_model::workspace_init_params_serialize))
     )]
-    WorkspaceParamsSerialize(#[source] serde_yaml::Error),
+    WorkspaceParamsSerialize(#[source] SerializationError),
 
     /// Failed to deserialize workspace init params.
     #[error("Failed to deserialize workspace init params.")]
@@ -402,7 +563,7 @@ pub enum Error {
         feature = "error_reporting",
         diagnostic(code(peace_rt_model::workspace_init_params_deserialize))
     )]
-    WorkspaceParamsDeserialize(#[source] serde_yaml::Error),
+    WorkspaceParamsDeserialize(#[source] SerializationError),
 
     /// Workspace params does not exist, so cannot look up `Profile`.
     #[error("Workspace params does not exist, so cannot look up `Profile`.")]
@@ -420,6 +581,210 @@ pub enum Error {
     )]
     WorkspaceParamsProfileNone,
 
+    /// Failed to write workspace archive.
+    #[error("Failed to write workspace archive.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::workspace_archive_write))
+    )]
+    WorkspaceArchiveWrite(#[source] std::io::Error),
+
+    /// Failed to read workspace archive.
+    #[error("Failed to read workspace archive.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::workspace_archive_read),
+            help("Make sure the archive was created by `WorkspaceExportCmd`.")
+        )
+    )]
+    WorkspaceArchiveRead(#[source] std::io::Error),
+
+    /// Failed to serialize workspace archive manifest.
+    #[error("Failed to serialize workspace archive manifest.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::workspace_archive_manifest_serialize))
+    )]
+    WorkspaceArchiveManifestSerialize(#[source] SerializationError),
+
+    /// Failed to deserialize workspace archive manifest.
+    #[error("Failed to deserialize workspace archive manifest.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::workspace_archive_manifest_deserialize))
+    )]
+    WorkspaceArchiveManifestDeserialize(#[source] SerializationError),
+
+    /// Workspace archive does not contain a manifest.
+    #[error("Workspace archive does not contain a manifest.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::workspace_archive_manifest_not_found),
+            help("Make sure the archive was created by `WorkspaceExportCmd`.")
+        )
+    )]
+    WorkspaceArchiveManifestNotFound,
+
+    /// Workspace archive version is not supported.
+    #[error(
+        "Workspace archive version `{archive_version}` is not supported, \
+        expected `{archive_version_supported}`."
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::workspace_archive_version_unsupported),
+            help("Import the archive with the version of the tool that exported it.")
+        )
+    )]
+    WorkspaceArchiveVersionUnsupported {
+        /// Version of the archive.
+        archive_version: u32,
+        /// Version of the archive that is supported.
+        archive_version_supported: u32,
+    },
+
+    /// Workspace archive was exported from a different application.
+    #[error(
+        "Workspace archive was exported from `{app_name_archive}`, \
+        but is being imported into `{app_name_workspace}`."
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::workspace_archive_app_name_mismatch))
+    )]
+    WorkspaceArchiveAppNameMismatch {
+        /// Application name in the archive's manifest.
+        app_name_archive: AppName,
+        /// Application name of the workspace.
+        app_name_workspace: AppName,
+    },
+
+    /// Workspace archive contains flows that are not registered.
+    #[error(
+        "Workspace archive contains flows that are not registered: [{flow_ids_unknown}].",
+        flow_ids_unknown = flow_ids_unknown
+            .iter()
+            .map(|flow_id| format!("{flow_id}"))
+            .collect::<Vec<_>>()
+            .join(",")
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::workspace_archive_flow_ids_unknown),
+            help(
+                "Registered flows: [{flow_ids_registered}]",
+                flow_ids_registered = flow_ids_registered
+                    .iter()
+                    .map(|flow_id| format!("{flow_id}"))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            )
+        )
+    )]
+    WorkspaceArchiveFlowIdsUnknown {
+        /// Flow IDs in the archive that are not registered.
+        flow_ids_unknown: Vec<FlowId>,
+        /// Flow IDs that are registered.
+        flow_ids_registered: Vec<FlowId>,
+    },
+
+    /// Workspace archive entry path is outside the application directory.
+    #[error("Workspace archive entry `{}` is outside the application directory.", path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::workspace_archive_entry_path_invalid),
+            help("Make sure the archive was created by `WorkspaceExportCmd`.")
+        )
+    )]
+    WorkspaceArchiveEntryPathInvalid {
+        /// Path of the entry in the archive.
+        path: PathBuf,
+    },
+
+    /// Workspace archive entry is in a profile that is not in the archive's
+    /// manifest.
+    #[error(
+        "Workspace archive entry `{}` is in profile `{profile}`, which is not in the archive's manifest.",
+        path.display()
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::workspace_archive_entry_profile_unknown),
+            help(
+                "Profiles in the manifest: [{profiles_manifest}]\n\
+                Make sure the archive was created by `WorkspaceExportCmd`.",
+                profiles_manifest = profiles_manifest
+                    .iter()
+                    .map(|profile| format!("{profile}"))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            )
+        )
+    )]
+    WorkspaceArchiveEntryProfileUnknown {
+        /// Path of the entry in the archive.
+        path: PathBuf,
+        /// Profile directory that the entry is in.
+        profile: String,
+        /// Profiles in the archive's manifest.
+        profiles_manifest: Vec<Profile>,
+    },
+
+    /// Workspace archive entry is in a flow that is not in its profile's
+    /// manifest flows.
+    #[error(
+        "Workspace archive entry `{}` is in flow `{flow_id}`, which is not in profile `{profile}`'s manifest flows.",
+        path.display()
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::workspace_archive_entry_flow_id_unknown),
+            help(
+                "Flows in the manifest for `{profile}`: [{flow_ids_manifest}]\n\
+                Make sure the archive was created by `WorkspaceExportCmd`.",
+                flow_ids_manifest = flow_ids_manifest
+                    .iter()
+                    .map(|flow_id| format!("{flow_id}"))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            )
+        )
+    )]
+    WorkspaceArchiveEntryFlowIdUnknown {
+        /// Path of the entry in the archive.
+        path: PathBuf,
+        /// Profile directory that the entry is in.
+        profile: Profile,
+        /// Flow directory that the entry is in.
+        flow_id: String,
+        /// Flows in the profile's manifest entry.
+        flow_ids_manifest: Vec<FlowId>,
+    },
+
+    /// Workspace archive manifest contains an invalid profile name.
+    #[error("Workspace archive manifest contains invalid profile `{profile}`.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::workspace_archive_profile_invalid),
+            help(
+                "Profiles must begin with a letter or underscore, and contain only letters, numbers, and underscores.\n\
+                Make sure the archive was created by `WorkspaceExportCmd`."
+            )
+        )
+    )]
+    WorkspaceArchiveProfileInvalid {
+        /// The invalid profile name.
+        profile: String,
+    },
+
     /// Profile to diff does not exist in `MultiProfileSingleFlow` scope.
     ///
     /// This could mean the caller provided a profile that does not exist, or
@@ -462,13 +827,133 @@ pub enum Error {
         profile: Profile,
     },
 
+    /// Profile to create, copy to, or rename to already exists.
+    #[error("Profile `{profile}` already exists.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::profile_exists),
+            help("Choose a different profile name, or delete the existing profile first.")
+        )
+    )]
+    ProfileExists {
+        /// The profile that already exists.
+        profile: Profile,
+    },
+
+    /// Profile to delete has stored states.
+    ///
+    /// Deleting the profile would lose track of the resources that the states
+    /// describe.
+    #[error("Profile `{profile}` has stored states, so it was not deleted.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::profile_states_exist),
+            help(
+                "Clean the profile's flows before deleting it, or delete it with states.\n\
+                States files: [{states_file_paths}]",
+                states_file_paths = states_file_paths
+                    .iter()
+                    .map(|path| format!("{}", path.display()))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            )
+        )
+    )]
+    ProfileStatesExist {
+        /// The profile that has stored states.
+        profile: Profile,
+        /// Paths to the stored states files.
+        states_file_paths: Vec<PathBuf>,
+    },
+
+    /// Profile to delete is the parent of other profiles.
+    #[error("Profile `{profile}` is the parent of other profiles, so it was not deleted.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::profile_parent_of_profiles),
+            help(
+                "Set a different parent for the child profiles before deleting it.\n\
+                Child profiles: [{profiles_child}]",
+                profiles_child = profiles_child
+                    .iter()
+                    .map(|profile| format!("{profile}"))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            )
+        )
+    )]
+    ProfileParentOfProfiles {
+        /// The profile that is a parent.
+        profile: Profile,
+        /// Profiles whose parent is `profile`.
+        profiles_child: Vec<Profile>,
+    },
+
+    /// Profile's parent profile does not exist.
+    #[error("Profile `{profile}`'s parent profile `{profile_parent}` does not exist.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::profile_parent_not_found),
+            help("Create the parent profile, or set a different parent for `{profile}`.")
+        )
+    )]
+    ProfileParentNotFound {
+        /// The profile whose parent does not exist.
+        profile: Profile,
+        /// The parent profile that does not exist.
+        profile_parent: Profile,
+    },
+
+    /// Profile parents form a cycle.
+    #[error(
+        "Profile `{profile}`'s parent profiles form a cycle: {profiles_cycle}.",
+        profiles_cycle = profiles_cycle
+            .iter()
+            .map(|profile| format!("`{profile}`"))
+            .collect::<Vec<_>>()
+            .join(" -> ")
+    )]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::profile_parent_cycle),
+            help("Set the parent of one of the profiles so they do not refer back to each other.")
+        )
+    )]
+    ProfileParentCycle {
+        /// The profile whose parents were being resolved.
+        profile: Profile,
+        /// Profiles in the cycle, beginning and ending with the same profile.
+        profiles_cycle: Vec<Profile>,
+    },
+
+    /// Failed to serialize profile parent.
+    #[error("Failed to serialize profile parent.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::profile_parent_serialize))
+    )]
+    ProfileParentSerialize(#[source] SerializationError),
+
+    /// Failed to deserialize profile parent.
+    #[error("Failed to deserialize profile parent.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::profile_parent_deserialize))
+    )]
+    ProfileParentDeserialize(#[source] SerializationError),
+
     /// Failed to serialize profile init params.
     #[error("Failed to serialize profile init params.")]
     #[cfg_attr(
         feature = "error_reporting",
         diagnostic(code(peace_rt_model::profile_init_params_serialize))
     )]
-    ProfileParamsSerialize(#[source] serde_yaml::Error),
+    ProfileParamsSerialize(#[source] SerializationError),
 
     /// Failed to deserialize profile init params.
     #[error("Failed to deserialize profile init params.")]
@@ -476,7 +961,7 @@ pub enum Error {
         feature = "error_reporting",
         diagnostic(code(peace_rt_model::profile_init_params_deserialize))
     )]
-    ProfileParamsDeserialize(#[source] serde_yaml::Error),
+    ProfileParamsDeserialize(#[source] SerializationError),
 
     /// Failed to serialize flow init params.
     #[error("Failed to serialize flow init params.")]
@@ -484,7 +969,7 @@ pub enum Error {
         feature = "error_reporting",
         diagnostic(code(peace_rt_model::flow_init_params_serialize))
     )]
-    FlowParamsSerialize(#[source] serde_yaml::Error),
+    FlowParamsSerialize(#[source] SerializationError),
 
     /// Failed to deserialize flow init params.
     #[error("Failed to deserialize flow init params.")]
@@ -492,7 +977,7 @@ pub enum Error {
         feature = "error_reporting",
         diagnostic(code(peace_rt_model::flow_init_params_deserialize))
     )]
-    FlowParamsDeserialize(#[source] serde_yaml::Error),
+    FlowParamsDeserialize(#[source] SerializationError),
 
     /// Item does not exist in storage.
     #[error("Item does not exist in storage: `{}`.", path.display())]
@@ -505,6 +990,26 @@ pub enum Error {
         path: PathBuf,
     },
 
+    /// Error from a `StorageBackend` implementation.
+    ///
+    /// This allows backends outside of the peace framework to surface their
+    /// own error types.
+    #[error("Failed to access workspace storage.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::storage_backend))
+    )]
+    StorageBackend(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+
+    /// Failed to encrypt or decrypt a workspace file.
+    #[error("Failed to encrypt or decrypt a workspace file.")]
+    Encryption(
+        #[cfg_attr(feature = "error_reporting", diagnostic_source)]
+        #[source]
+        #[from]
+        EncryptionError,
+    ),
+
     /// Error downcasting a `BoxDtDisplay` into an item's concrete state type.
     #[error("Error downcasting a `BoxDtDisplay` into an item's concrete state type.")]
     StateDowncastError(
@@ -535,6 +1040,45 @@ pub enum Error {
     ),
 }
 
+impl Error {
+    /// Returns the error for mapping functions that could not be bound, or
+    /// `None` if every mapping function was bound.
+    ///
+    /// Unknown names are reported in preference to output type mismatches, as
+    /// registering a missing function may also resolve the mismatch.
+    pub fn from_mapping_fn_bind_errors(
+        flow_id: &FlowId,
+        mapping_fn_reg: &MappingFnReg,
+        mapping_fn_bind_errors: Vec<MappingFnBindError>,
+    ) -> Option<Self> {
+        let mapping_fn_names_unknown = mapping_fn_bind_errors
+            .iter()
+            .filter_map(|mapping_fn_bind_error| match mapping_fn_bind_error {
+                MappingFnBindError::NameUnknown { mapping_fn_name } => {
+                    Some(mapping_fn_name.clone())
+                }
+                MappingFnBindError::OutputTypeMismatch { .. } => None,
+            })
+            .collect::<Vec<_>>();
+
+        if !mapping_fn_names_unknown.is_empty() {
+            Some(Self::MappingFnNamesUnknown {
+                flow_id: flow_id.clone(),
+                mapping_fn_names_unknown,
+                mapping_fn_names_registered: mapping_fn_reg.names(),
+            })
+        } else if !mapping_fn_bind_errors.is_empty() {
+            // Every remaining error is an output type mismatch.
+            Some(Self::MappingFnOutputTypeMismatch {
+                flow_id: flow_id.clone(),
+                mapping_fn_output_type_mismatches: mapping_fn_bind_errors,
+            })
+        } else {
+            None
+        }
+    }
+}
+
 #[cfg(feature = "error_reporting")]
 fn params_specs_mismatch_display(
     item_ids_with_no_params: &[ItemId],
@@ -603,3 +1147,47 @@ fn params_specs_mismatch_display(
 
     items.join("\n")
 }
+
+fn mapping_fn_names_list(mapping_fn_names: &[MappingFnName]) -> String {
+    mapping_fn_names
+        .iter()
+        .map(|mapping_fn_name| format!("`{mapping_fn_name}`"))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn mapping_fn_output_type_mismatches_list(
+    mapping_fn_output_type_mismatches: &[MappingFnBindError],
+) -> String {
+    mapping_fn_output_type_mismatches
+        .iter()
+        .map(|mapping_fn_bind_error| match mapping_fn_bind_error {
+            MappingFnBindError::NameUnknown { mapping_fn_name } => format!("`{mapping_fn_name}`"),
+            MappingFnBindError::OutputTypeMismatch {
+                mapping_fn_name,
+                output_type_expected,
+                output_type_registered,
+            } => format!(
+                "`{mapping_fn_name}` outputs `{output_type_registered}` \
+                but `{output_type_expected}` is expected"
+            ),
+        })
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+#[cfg(feature = "error_reporting")]
+fn mapping_fn_names_unknown_help(mapping_fn_names_registered: &[MappingFnName]) -> String {
+    if mapping_fn_names_registered.is_empty() {
+        String::from(
+            "No mapping functions are registered.\n\
+            Register them with `Flow::with_mapping_fn_reg(..)`.",
+        )
+    } else {
+        format!(
+            "Registered mapping functions are: {}.\n\
+            Register any missing functions with `Flow::with_mapping_fn_reg(..)`.",
+            mapping_fn_names_list(mapping_fn_names_registered)
+        )
+    }
+}
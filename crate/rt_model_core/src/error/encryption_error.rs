@@ -0,0 +1,135 @@
+use std::path::PathBuf;
+
+/// Error encrypting or decrypting workspace files.
+#[cfg_attr(feature = "error_reporting", derive(miette::Diagnostic))]
+#[derive(Debug, thiserror::Error)]
+pub enum EncryptionError {
+    /// A stored file is encrypted, but no encryption key provider is set.
+    #[error("`{}` is encrypted, but no encryption key was provided.", path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::encryption_error::key_missing),
+            help("Set the key provider using `Workspace::with_encryption_key_provider`.")
+        )
+    )]
+    KeyMissing {
+        /// Path to the encrypted file.
+        path: PathBuf,
+    },
+
+    /// A stored file is not encrypted, but encryption is enabled.
+    ///
+    /// This happens when the file was stored before encryption was enabled,
+    /// or the encrypted file has been replaced.
+    #[error("`{}` is not encrypted, but encryption is enabled.", path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::encryption_error::not_encrypted),
+            help(
+                "If the file was stored before encryption was enabled, use the `ReencryptCmd` \
+                to encrypt it."
+            )
+        )
+    )]
+    NotEncrypted {
+        /// Path to the file.
+        path: PathBuf,
+    },
+
+    /// Failed to read the encryption key file.
+    #[error("Failed to read encryption key file: `{}`.", path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::encryption_error::key_file_read))
+    )]
+    KeyFileRead {
+        /// Path to the key file.
+        path: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// Failed to read the encryption key environment variable.
+    #[error("Failed to read encryption key from `{env_var}` environment variable.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::encryption_error::key_env_var_read),
+            help("Set `{env_var}` to a base64 encoded 32 byte key.")
+        )
+    )]
+    KeyEnvVarRead {
+        /// Name of the environment variable.
+        env_var: String,
+        /// Underlying error.
+        #[source]
+        error: std::env::VarError,
+    },
+
+    /// Encryption key is not valid base64.
+    #[error("Encryption key is not valid base64.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::encryption_error::key_decode))
+    )]
+    KeyDecode(#[source] base64::DecodeError),
+
+    /// Encryption key is not 32 bytes long.
+    #[error("Encryption key must be 32 bytes, but was {length} bytes.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::encryption_error::key_length),
+            help("A key may be generated using `openssl rand -base64 32`.")
+        )
+    )]
+    KeyLength {
+        /// Number of bytes in the decoded key.
+        length: usize,
+    },
+
+    /// Error from an `EncryptionKeyProvider` implementation.
+    ///
+    /// This allows key providers outside of the peace framework to surface
+    /// their own error types.
+    #[error("Failed to retrieve encryption key.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::encryption_error::key_provider))
+    )]
+    KeyProvider(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+
+    /// Failed to encrypt a file.
+    #[error("Failed to encrypt `{}`.", path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::encryption_error::encrypt))
+    )]
+    Encrypt {
+        /// Path to the file.
+        path: PathBuf,
+    },
+
+    /// Failed to decrypt a file.
+    ///
+    /// This happens when the key is not the one that the file was encrypted
+    /// with, or the file has been modified.
+    #[error("Failed to decrypt `{}`.", path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model::encryption_error::decrypt),
+            help(
+                "Make sure the encryption key is the one that the file was encrypted with.\n\
+                If the key was rotated, use the `ReencryptCmd` with the previous key."
+            )
+        )
+    )]
+    Decrypt {
+        /// Path to the file.
+        path: PathBuf,
+    },
+}
@@ -28,6 +28,62 @@ pub enum NativeError {
         error: std::io::Error,
     },
 
+    /// Failed to list entries in a workspace directory.
+    #[error("Failed to list entries in directory: `{}`", path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model_native::dir_read))
+    )]
+    DirRead {
+        /// Path to the directory.
+        path: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// Failed to read entry in a workspace directory.
+    #[error("Failed to read entry in directory: `{}`", path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model_native::dir_entry_read))
+    )]
+    DirEntryRead {
+        /// Path to the directory.
+        path: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// Failed to read entry file type in a workspace directory.
+    #[error("Failed to read entry file type: `{}`", path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model_native::dir_entry_file_type_read))
+    )]
+    DirEntryFileTypeRead {
+        /// Path to the entry.
+        path: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// Failed to remove a workspace directory.
+    #[error("Failed to remove directory: `{}`", path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model_native::dir_remove))
+    )]
+    DirRemove {
+        /// Path to the directory.
+        path: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
     /// Failed to create file for writing.
     #[error("Failed to create file for writing: `{path}`")]
     #[cfg_attr(
@@ -70,6 +126,36 @@ pub enum NativeError {
         error: std::io::Error,
     },
 
+    /// Failed to remove file.
+    #[error("Failed to remove file: `{path}`")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model_native::file_remove))
+    )]
+    FileRemove {
+        /// Path to the file.
+        path: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
+    /// Failed to rename file.
+    #[error("Failed to rename file: `{path_from}` to `{path_to}`")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model_native::file_rename))
+    )]
+    FileRename {
+        /// Path to the file to rename.
+        path_from: PathBuf,
+        /// Path to rename the file to.
+        path_to: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+
     /// Failed to write to file.
     #[error("Failed to write to file: `{path}`")]
     #[cfg_attr(
@@ -129,6 +215,30 @@ pub enum NativeError {
         error: ProfileInvalidFmt<'static>,
     },
 
+    /// Failed to write a JSON Lines output event.
+    #[error("Failed to write a JSON Lines output event.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model_native::json_lines_write))
+    )]
+    JsonLinesWrite(#[source] std::io::Error),
+
+    /// Failed to write command outcome report.
+    #[error("Failed to write command outcome report.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model_native::report_write))
+    )]
+    ReportWrite(#[source] std::io::Error),
+
+    /// Failed to initialize the terminal for the TUI output.
+    #[error("Failed to initialize the terminal for the TUI output.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model_native::tui_terminal_init))
+    )]
+    TuiTerminalInit(#[source] std::io::Error),
+
     /// Failed to write to stdout.
     #[error("Failed to write to stdout.")]
     #[cfg_attr(
@@ -0,0 +1,41 @@
+/// Error serializing or deserializing a workspace file.
+///
+/// Each variant corresponds to a [`SerializationFormat`]. MessagePack errors
+/// are boxed, as they are much larger than the YAML and JSON errors.
+///
+/// [`SerializationFormat`]: crate::SerializationFormat
+#[cfg_attr(feature = "error_reporting", derive(miette::Diagnostic))]
+#[derive(Debug, thiserror::Error)]
+pub enum SerializationError {
+    /// Failed to serialize or deserialize YAML.
+    #[error("YAML serialization error.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::serialization_error::yaml))
+    )]
+    Yaml(#[source] serde_yaml::Error),
+
+    /// Failed to serialize or deserialize JSON.
+    #[error("JSON serialization error.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::serialization_error::json))
+    )]
+    Json(#[source] serde_json::Error),
+
+    /// Failed to serialize MessagePack.
+    #[error("MessagePack serialization error.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::serialization_error::message_pack_serialize))
+    )]
+    MessagePackSerialize(#[source] Box<rmp_serde::encode::Error>),
+
+    /// Failed to deserialize MessagePack.
+    #[error("MessagePack deserialization error.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model::serialization_error::message_pack_deserialize))
+    )]
+    MessagePackDeserialize(#[source] Box<rmp_serde::decode::Error>),
+}
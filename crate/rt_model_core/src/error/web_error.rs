@@ -121,6 +121,22 @@ pub enum WebError {
         /// Stringified JS error.
         error: String,
     },
+    /// Failed to set an item in browser storage as it is not valid UTF-8.
+    ///
+    /// Browser storage stores strings, so bytes written through the
+    /// `StorageBackend` API must be valid UTF-8.
+    #[error("Failed to set an item in browser storage as it is not valid UTF-8: `{path}`.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model_web::storage_set_item_utf8))
+    )]
+    StorageSetItemUtf8 {
+        /// Key to set.
+        path: PathBuf,
+        /// The UTF-8 conversion error.
+        #[source]
+        error: std::str::Utf8Error,
+    },
     /// Failed to remove an item from browser storage.
     ///
     /// This failure mode happens when the `remove_item` call to the browser
@@ -139,6 +155,24 @@ pub enum WebError {
         /// Stringified JS error.
         error: String,
     },
+    /// Failed to list the keys in browser storage.
+    ///
+    /// This failure mode happens when the `length` or `key` call to the
+    /// browser fails.
+    ///
+    /// Note: The original `JsValue` error is converted to a `String` to allow
+    /// this type to be `Send`.
+    #[error("Failed to list the keys in browser storage under: `{dir}`. Error: `{error}`")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model_web::storage_keys_list))
+    )]
+    StorageKeysList {
+        /// Directory whose keys were being listed.
+        dir: PathBuf,
+        /// Stringified JS error.
+        error: String,
+    },
     /// Failed to fetch browser Window object.
     #[error("Failed to fetch browser Window object.")]
     #[cfg_attr(
@@ -0,0 +1,147 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use async_trait::async_trait;
+
+use crate::{Error, StorageBackend};
+
+/// Stores workspace files in memory.
+///
+/// Clones of this backend share the same items, so a clone may be kept to
+/// inspect what a command has stored, e.g. the serialized
+/// `states_current.yaml`.
+///
+/// Nothing is written to the file system, which makes this useful for tests.
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryStorageBackend {
+    /// Items stored in this backend, keyed by path.
+    items: Arc<Mutex<BTreeMap<PathBuf, Vec<u8>>>>,
+    /// Directories created through `dirs_create`, so that empty directories
+    /// are listed.
+    dirs: Arc<Mutex<BTreeSet<PathBuf>>>,
+}
+
+impl InMemoryStorageBackend {
+    /// Returns a new empty `InMemoryStorageBackend`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a copy of the bytes stored at the given path, if any.
+    pub fn get(&self, path: &Path) -> Option<Vec<u8>> {
+        self.items().get(path).cloned()
+    }
+
+    /// Returns the item stored at the given path as a string, if it exists
+    /// and is valid UTF-8.
+    pub fn get_string(&self, path: &Path) -> Option<String> {
+        self.get(path)
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+    }
+
+    /// Stores the bytes at the given path, replacing any existing item.
+    ///
+    /// This may be used to set up the stored workspace files before running a
+    /// command.
+    pub fn insert<B>(&self, path: PathBuf, bytes: B)
+    where
+        B: Into<Vec<u8>>,
+    {
+        self.items().insert(path, bytes.into());
+    }
+
+    /// Returns the paths of all stored items, in sorted order.
+    pub fn paths(&self) -> Vec<PathBuf> {
+        self.items().keys().cloned().collect()
+    }
+
+    /// Locks and returns the stored items.
+    ///
+    /// A panic while the lock is held cannot leave the map in a partially
+    /// updated state, so a poisoned lock is still used.
+    fn items(&self) -> MutexGuard<'_, BTreeMap<PathBuf, Vec<u8>>> {
+        self.items.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Locks and returns the created directories.
+    fn dirs(&self) -> MutexGuard<'_, BTreeSet<PathBuf>> {
+        self.dirs.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Two backends are equal if they store the same items.
+impl PartialEq for InMemoryStorageBackend {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.items, &other.items) || *self.items() == *other.items()
+    }
+}
+
+impl Eq for InMemoryStorageBackend {}
+
+#[async_trait(?Send)]
+impl StorageBackend for InMemoryStorageBackend {
+    async fn dirs_create(&self, dirs: &[&Path]) -> Result<(), Error> {
+        self.dirs().extend(dirs.iter().map(|dir| dir.to_path_buf()));
+        Ok(())
+    }
+
+    async fn contains_item(&self, path: &Path) -> Result<bool, Error> {
+        Ok(self.items().contains_key(path) || self.dirs().contains(path))
+    }
+
+    async fn read_opt(&self, path: &Path) -> Result<Option<Vec<u8>>, Error> {
+        Ok(self.get(path))
+    }
+
+    async fn write(&self, path: &Path, bytes: &[u8]) -> Result<(), Error> {
+        self.insert(path.to_path_buf(), bytes);
+        Ok(())
+    }
+
+    async fn remove(&self, path: &Path) -> Result<(), Error> {
+        self.items().remove(path);
+        Ok(())
+    }
+
+    async fn rename(&self, path_from: &Path, path_to: &Path) -> Result<(), Error> {
+        let mut items = self.items();
+        if let Some(bytes) = items.remove(path_from) {
+            items.insert(path_to.to_path_buf(), bytes);
+        }
+        Ok(())
+    }
+
+    async fn paths_under(&self, dir: &Path) -> Result<Vec<PathBuf>, Error> {
+        Ok(self
+            .items()
+            .keys()
+            .filter(|path| path.starts_with(dir) && *path != dir)
+            .cloned()
+            .collect())
+    }
+
+    async fn dirs_under(&self, dir: &Path) -> Result<Vec<PathBuf>, Error> {
+        let items = self.items();
+        let dirs = self.dirs();
+        let dirs_under = items
+            .keys()
+            .filter_map(|path| path.parent())
+            .chain(dirs.iter().map(PathBuf::as_path))
+            .filter_map(|path| {
+                let dir_name = path.strip_prefix(dir).ok()?.components().next()?;
+                Some(dir.join(dir_name))
+            })
+            .collect::<BTreeSet<PathBuf>>();
+
+        Ok(dirs_under.into_iter().collect())
+    }
+
+    async fn dir_remove(&self, dir: &Path) -> Result<(), Error> {
+        self.items().retain(|path, _bytes| !path.starts_with(dir));
+        self.dirs().retain(|path| !path.starts_with(dir));
+        Ok(())
+    }
+}
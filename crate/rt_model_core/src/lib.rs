@@ -13,14 +13,32 @@ pub mod output;
 pub mod params;
 
 pub use crate::{
-    error::{ApplyCmdError, Error, StateDowncastError},
+    encryption_key::EncryptionKey,
+    encryption_key_env_var::EncryptionKeyEnvVar,
+    encryption_key_file::EncryptionKeyFile,
+    encryption_key_provider::EncryptionKeyProvider,
+    error::{ApplyCmdError, EncryptionError, Error, SerializationError, StateDowncastError},
+    in_memory_storage_backend::InMemoryStorageBackend,
     items_state_stored_stale::ItemsStateStoredStale,
+    serialization_format::SerializationFormat,
     state_stored_and_discovered::StateStoredAndDiscovered,
+    states_layout::StatesLayout,
+    storage_backend::StorageBackend,
+    storage_encryption::StorageEncryption,
 };
 
+mod encryption_key;
+mod encryption_key_env_var;
+mod encryption_key_file;
+mod encryption_key_provider;
 mod error;
+mod in_memory_storage_backend;
 mod items_state_stored_stale;
+mod serialization_format;
 mod state_stored_and_discovered;
+mod states_layout;
+mod storage_backend;
+mod storage_encryption;
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "output_progress")] {
@@ -1,3 +1,4 @@
-pub use self::output_write::OutputWrite;
+pub use self::{output_write::OutputWrite, tee_output::TeeOutput};
 
 mod output_write;
+mod tee_output;
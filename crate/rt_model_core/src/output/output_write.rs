@@ -71,6 +71,15 @@ pub trait OutputWrite<E>: Debug + Unpin {
         item_location_state: peace_item_model::ItemLocationState,
     );
 
+    /// Notifies this `OutputWrite` of an item's state diff, presented as text.
+    ///
+    /// This is called when an `Item`'s `StateDiff` is computed, such as in
+    /// `DiffCmd` and `EnsureCmd`. By default this does nothing.
+    #[cfg(feature = "output_progress")]
+    async fn item_state_diff(&mut self, item_id: peace_core::ItemId, state_diff: String) {
+        let (_item_id, _state_diff) = (item_id, state_diff);
+    }
+
     /// Renders progress information, and returns when no more progress
     /// information is available to write.
     ///
@@ -0,0 +1,159 @@
+use async_trait::async_trait;
+use peace_fmt::Presentable;
+
+use crate::output::OutputWrite;
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "output_progress")] {
+        use peace_core::{
+            progress::{CmdBlockItemInteractionType, ProgressTracker, ProgressUpdateAndId},
+            ItemId,
+        };
+        use peace_item_model::ItemLocationState;
+
+        use crate::CmdProgressTracker;
+    }
+}
+
+/// An `OutputWrite` that forwards to two `OutputWrite`s.
+///
+/// This allows a command to render progress interactively while also keeping
+/// a machine-readable log of the same run, e.g. a `CliOutput` writing to
+/// `stderr` together with a `JsonLinesOutput` writing to a file.
+///
+/// More than two outputs can be combined by nesting `TeeOutput`s:
+///
+/// ```rust,ignore
+/// let output = TeeOutput::new(cli_output, TeeOutput::new(json_output, webi_output));
+/// ```
+///
+/// # Progress
+///
+/// Both outputs receive every progress call. Since the `CmdProgressTracker`
+/// is shared, at most one of the outputs should render progress bars, e.g. a
+/// `CliOutput` with `CliProgressFormatOpt::ProgressBar`, and the other should
+/// use a non-interactive format.
+///
+/// # Errors
+///
+/// For `present` and `write_err`, both outputs are always written to. If
+/// either fails, the first error is returned.
+#[derive(Debug)]
+pub struct TeeOutput<O0, O1> {
+    /// First output to forward to.
+    output_0: O0,
+    /// Second output to forward to.
+    output_1: O1,
+}
+
+impl<O0, O1> TeeOutput<O0, O1> {
+    /// Returns a new `TeeOutput` that forwards to both outputs.
+    pub fn new(output_0: O0, output_1: O1) -> Self {
+        Self { output_0, output_1 }
+    }
+
+    /// Returns a reference to the first output.
+    pub fn output_0(&self) -> &O0 {
+        &self.output_0
+    }
+
+    /// Returns a reference to the second output.
+    pub fn output_1(&self) -> &O1 {
+        &self.output_1
+    }
+
+    /// Returns the inner outputs.
+    pub fn into_inner(self) -> (O0, O1) {
+        let Self { output_0, output_1 } = self;
+        (output_0, output_1)
+    }
+}
+
+#[async_trait(?Send)]
+impl<E, O0, O1> OutputWrite<E> for TeeOutput<O0, O1>
+where
+    O0: OutputWrite<E>,
+    O1: OutputWrite<E>,
+{
+    #[cfg(feature = "output_progress")]
+    async fn progress_begin(&mut self, cmd_progress_tracker: &CmdProgressTracker) {
+        self.output_0.progress_begin(cmd_progress_tracker).await;
+        self.output_1.progress_begin(cmd_progress_tracker).await;
+    }
+
+    #[cfg(feature = "output_progress")]
+    async fn cmd_block_start(
+        &mut self,
+        cmd_block_item_interaction_type: CmdBlockItemInteractionType,
+    ) {
+        self.output_0
+            .cmd_block_start(cmd_block_item_interaction_type)
+            .await;
+        self.output_1
+            .cmd_block_start(cmd_block_item_interaction_type)
+            .await;
+    }
+
+    #[cfg(feature = "output_progress")]
+    async fn item_location_state(
+        &mut self,
+        item_id: ItemId,
+        item_location_state: ItemLocationState,
+    ) {
+        self.output_0
+            .item_location_state(item_id.clone(), item_location_state)
+            .await;
+        self.output_1
+            .item_location_state(item_id, item_location_state)
+            .await;
+    }
+
+    #[cfg(feature = "output_progress")]
+    async fn item_state_diff(&mut self, item_id: ItemId, state_diff: String) {
+        self.output_0
+            .item_state_diff(item_id.clone(), state_diff.clone())
+            .await;
+        self.output_1.item_state_diff(item_id, state_diff).await;
+    }
+
+    #[cfg(feature = "output_progress")]
+    async fn progress_update(
+        &mut self,
+        progress_tracker: &ProgressTracker,
+        progress_update_and_id: &ProgressUpdateAndId,
+    ) {
+        self.output_0
+            .progress_update(progress_tracker, progress_update_and_id)
+            .await;
+        self.output_1
+            .progress_update(progress_tracker, progress_update_and_id)
+            .await;
+    }
+
+    #[cfg(feature = "output_progress")]
+    async fn progress_end(&mut self, cmd_progress_tracker: &CmdProgressTracker) {
+        self.output_0.progress_end(cmd_progress_tracker).await;
+        self.output_1.progress_end(cmd_progress_tracker).await;
+    }
+
+    async fn present<P>(&mut self, presentable: P) -> Result<(), E>
+    where
+        E: std::error::Error,
+        P: Presentable,
+    {
+        let result_0 = self.output_0.present(&presentable).await;
+        let result_1 = self.output_1.present(&presentable).await;
+
+        result_0.and(result_1)
+    }
+
+    async fn write_err(&mut self, error: &E) -> Result<(), E>
+    where
+        E: std::error::Error,
+    {
+        let result_0 = self.output_0.write_err(error).await;
+        let result_1 = self.output_1.write_err(error).await;
+
+        result_0.and(result_1)
+    }
+}
@@ -32,6 +32,7 @@ pub use self::{
     params_type_regs::ParamsTypeRegs,
     params_type_regs_builder::ParamsTypeRegsBuilder,
     profile_params::ProfileParams,
+    profile_params_origins::ProfileParamsOrigins,
     workspace_params::WorkspaceParams,
 };
 
@@ -40,4 +41,11 @@ mod params_keys;
 mod params_type_regs;
 mod params_type_regs_builder;
 mod profile_params;
+mod profile_params_origins;
 mod workspace_params;
+
+#[cfg(feature = "json_schema")]
+pub use self::params_map_schema_builder::ParamsMapSchemaBuilder;
+
+#[cfg(feature = "json_schema")]
+mod params_map_schema_builder;
@@ -0,0 +1,101 @@
+use std::marker::PhantomData;
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+use serde::Serialize;
+
+/// Builds a JSON schema for a map of params, such as a `WorkspaceParams`,
+/// `ProfileParams`, or `FlowParams` file.
+///
+/// Each param is registered with the key it is stored under and the type of
+/// its value, the same as when the params are provided to the command context
+/// builder.
+///
+/// # Type Parameters
+///
+/// * `K`: Type of key for the params map.
+pub struct ParamsMapSchemaBuilder<K> {
+    /// Title of the schema.
+    title: String,
+    /// Generator that collects the definitions of each value type.
+    generator: SchemaGenerator,
+    /// Schema for each param, keyed by its serialized key.
+    properties: serde_json::Map<String, serde_json::Value>,
+    /// Marker.
+    marker: PhantomData<K>,
+}
+
+impl<K> ParamsMapSchemaBuilder<K>
+where
+    K: Serialize,
+{
+    /// Returns a new `ParamsMapSchemaBuilder` for a schema with the given
+    /// title.
+    pub fn new<T>(title: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            title: title.into(),
+            generator: SchemaGenerator::default(),
+            properties: serde_json::Map::new(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Adds the param stored under `key`, whose value is a `V`.
+    ///
+    /// Keys that do not serialize to a plain value are skipped, as they cannot
+    /// be written in a params file.
+    pub fn with_param<V>(self, key: K) -> Self
+    where
+        V: JsonSchema,
+    {
+        self.with_param_schema(key, |generator| generator.subschema_for::<V>())
+    }
+
+    /// Adds the param stored under `key`, whose value schema is returned by
+    /// `value_schema_fn`.
+    ///
+    /// This is used when the value type is only known through a trait object,
+    /// such as an item in a flow. Definitions that the schema refers to should
+    /// be added to the generator passed to `value_schema_fn`.
+    ///
+    /// Keys that do not serialize to a plain value are skipped, as they cannot
+    /// be written in a params file.
+    pub fn with_param_schema<F>(mut self, key: K, value_schema_fn: F) -> Self
+    where
+        F: FnOnce(&mut SchemaGenerator) -> Schema,
+    {
+        let key = match serde_json::to_value(&key) {
+            Ok(serde_json::Value::String(key)) => key,
+            Ok(serde_json::Value::Object(_) | serde_json::Value::Array(_)) | Err(_) => {
+                return self;
+            }
+            Ok(key) => key.to_string(),
+        };
+        let value_schema = value_schema_fn(&mut self.generator);
+        self.properties.insert(key, value_schema.to_value());
+        self
+    }
+
+    /// Returns the JSON schema for the params map.
+    ///
+    /// Only the registered keys are allowed.
+    pub fn build(mut self) -> Schema {
+        let mut schema = json_schema!({
+            "title": self.title,
+            "type": "object",
+            "properties": self.properties,
+            "additionalProperties": false
+        });
+        if let Some(meta_schema) = self.generator.settings().meta_schema.as_deref() {
+            schema.insert("$schema".to_string(), meta_schema.into());
+        }
+        let definitions = self.generator.take_definitions(true);
+        if !definitions.is_empty() {
+            schema.insert("$defs".to_string(), definitions.into());
+        }
+
+        schema
+    }
+}
@@ -0,0 +1,77 @@
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    ops::{Deref, DerefMut},
+};
+
+use peace_core::Profile;
+
+/// Map of profile params key to the profile its value was read from.
+///
+/// When a profile has a parent profile, profile params that are not set in
+/// the profile are inherited from its parent, and its parent's parent, and so
+/// on. This records which profile each resolved value came from.
+///
+/// This is inserted into `Resources` when building a `SingleProfile*` command
+/// context with profile params.
+///
+/// # Type Parameters
+///
+/// * `K`: Type of key for the `ProfileParams` map.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProfileParamsOrigins<K>(HashMap<K, Profile>)
+where
+    K: Eq + Hash;
+
+impl<K> ProfileParamsOrigins<K>
+where
+    K: Eq + Hash,
+{
+    /// Returns a new `ProfileParamsOrigins` map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the inner map.
+    pub fn into_inner(self) -> HashMap<K, Profile> {
+        self.0
+    }
+}
+
+impl<K> Default for ProfileParamsOrigins<K>
+where
+    K: Eq + Hash,
+{
+    fn default() -> Self {
+        Self(HashMap::new())
+    }
+}
+
+impl<K> Deref for ProfileParamsOrigins<K>
+where
+    K: Eq + Hash,
+{
+    type Target = HashMap<K, Profile>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<K> DerefMut for ProfileParamsOrigins<K>
+where
+    K: Eq + Hash,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<K> From<HashMap<K, Profile>> for ProfileParamsOrigins<K>
+where
+    K: Eq + Hash,
+{
+    fn from(profile_params_origins: HashMap<K, Profile>) -> Self {
+        Self(profile_params_origins)
+    }
+}
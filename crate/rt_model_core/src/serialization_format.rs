@@ -0,0 +1,179 @@
+use std::{
+    fmt,
+    hash::Hash,
+    path::{Path, PathBuf},
+};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use type_reg::{
+    common::UnknownEntriesSome,
+    untagged::{DataTypeWrapper, TypeMapOpt, TypeReg},
+};
+
+use crate::SerializationError;
+
+/// Format that workspace files are serialized in.
+///
+/// The format is chosen per workspace through
+/// `Workspace::with_serialization_format`, and is recorded in each file's
+/// extension, e.g. `states_current.yaml` is stored as `states_current.json`
+/// when using [`Json`]. See [`file_path`].
+///
+/// When reading files, files in the workspace's format are read first. If a
+/// file does not exist in that format, the file in another format is read,
+/// using the format recorded in its extension, so workspaces stored in a
+/// different format can still be read.
+///
+/// [`Json`]: Self::Json
+/// [`file_path`]: Self::file_path
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SerializationFormat {
+    /// Human readable YAML, the default.
+    #[default]
+    Yaml,
+    /// JSON, for interoperability with other tools.
+    Json,
+    /// Compact binary format, which is faster to read and write for large
+    /// flows.
+    ///
+    /// Browser storage only stores strings, so this format is only supported
+    /// by web workspaces that use browser storage when encryption is set.
+    MessagePack,
+}
+
+impl SerializationFormat {
+    /// All serialization formats.
+    pub const ALL: [Self; 3] = [Self::Yaml, Self::Json, Self::MessagePack];
+
+    /// Returns the file extension for this format, e.g. `"yaml"`.
+    pub fn file_extension(self) -> &'static str {
+        match self {
+            Self::Yaml => "yaml",
+            Self::Json => "json",
+            Self::MessagePack => "msgpack",
+        }
+    }
+
+    /// Returns the path of the file in this format, e.g.
+    /// `states_current.yaml` becomes `states_current.json` for [`Json`].
+    ///
+    /// [`Json`]: Self::Json
+    pub fn file_path(self, path: &Path) -> PathBuf {
+        path.with_extension(self.file_extension())
+    }
+
+    /// Returns the format recorded in the given path's extension, if any.
+    pub fn from_file_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?;
+        Self::ALL
+            .into_iter()
+            .find(|format| extension == format.file_extension())
+    }
+
+    /// Returns this format, followed by the other formats.
+    ///
+    /// This is the order that files are looked up in when they are read.
+    pub fn read_order(self) -> impl Iterator<Item = Self> {
+        std::iter::once(self).chain(Self::ALL.into_iter().filter(move |format| *format != self))
+    }
+
+    /// Returns whether `path` is the file at `file_path` in any format.
+    ///
+    /// For example, `states_current.json` is the `states_current.yaml` file in
+    /// [`Json`].
+    ///
+    /// [`Json`]: Self::Json
+    pub fn is_file_path_of(path: &Path, file_path: &Path) -> bool {
+        Self::from_file_path(path).is_some()
+            && path.with_extension("") == file_path.with_extension("")
+    }
+
+    /// Serializes the given value in this format.
+    pub fn serialize<T>(self, t: &T) -> Result<Vec<u8>, SerializationError>
+    where
+        T: Serialize + ?Sized,
+    {
+        match self {
+            Self::Yaml => {
+                let mut bytes = Vec::new();
+                serde_yaml::to_writer(&mut bytes, t).map_err(SerializationError::Yaml)?;
+                Ok(bytes)
+            }
+            Self::Json => {
+                let mut bytes = serde_json::to_vec_pretty(t).map_err(SerializationError::Json)?;
+                bytes.push(b'\n');
+                Ok(bytes)
+            }
+            Self::MessagePack => rmp_serde::to_vec_named(t)
+                .map_err(Box::new)
+                .map_err(SerializationError::MessagePackSerialize),
+        }
+    }
+
+    /// Deserializes a value from bytes in this format.
+    pub fn deserialize<T>(self, bytes: &[u8]) -> Result<T, SerializationError>
+    where
+        T: DeserializeOwned,
+    {
+        match self {
+            Self::Yaml => serde_yaml::from_slice(bytes).map_err(SerializationError::Yaml),
+            Self::Json => serde_json::from_slice(bytes).map_err(SerializationError::Json),
+            Self::MessagePack => rmp_serde::from_slice(bytes)
+                .map_err(Box::new)
+                .map_err(SerializationError::MessagePackDeserialize),
+        }
+    }
+
+    /// Deserializes a type map from bytes in this format.
+    ///
+    /// Entries whose keys are not registered in the type registry are
+    /// returned as unknown entries.
+    pub fn deserialize_typemap_opt<K, BoxDT>(
+        self,
+        type_reg: &TypeReg<K, BoxDT>,
+        bytes: &[u8],
+    ) -> Result<TypeMapOpt<K, BoxDT, UnknownEntriesSome<serde_yaml::Value>>, SerializationError>
+    where
+        K: Clone + fmt::Debug + DeserializeOwned + Eq + Hash + 'static,
+        BoxDT: DataTypeWrapper + 'static,
+    {
+        match self {
+            Self::Yaml => {
+                let deserializer = serde_yaml::Deserializer::from_slice(bytes);
+                type_reg
+                    .deserialize_map_opt_with_unknowns::<'_, serde_yaml::Value, _, _>(deserializer)
+                    .map_err(SerializationError::Yaml)
+            }
+            Self::Json => {
+                let mut deserializer = serde_json::Deserializer::from_slice(bytes);
+                let type_map_opt = type_reg
+                    .deserialize_map_opt_with_unknowns::<'_, serde_yaml::Value, _, _>(
+                        &mut deserializer,
+                    )
+                    .map_err(SerializationError::Json)?;
+                deserializer.end().map_err(SerializationError::Json)?;
+                Ok(type_map_opt)
+            }
+            Self::MessagePack => {
+                let mut deserializer = rmp_serde::Deserializer::from_read_ref(bytes);
+                type_reg
+                    .deserialize_map_opt_with_unknowns::<'_, serde_yaml::Value, _, _>(
+                        &mut deserializer,
+                    )
+                    .map_err(Box::new)
+                    .map_err(SerializationError::MessagePackDeserialize)
+            }
+        }
+    }
+}
+
+impl fmt::Display for SerializationFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Yaml => write!(f, "yaml"),
+            Self::Json => write!(f, "json"),
+            Self::MessagePack => write!(f, "message_pack"),
+        }
+    }
+}
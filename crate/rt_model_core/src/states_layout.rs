@@ -0,0 +1,47 @@
+use std::path::{Path, PathBuf};
+
+use peace_core::ItemId;
+use serde::{Deserialize, Serialize};
+
+/// How stored states are laid out in a flow directory.
+///
+/// The layout is chosen per workspace through `Workspace::with_states_layout`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StatesLayout {
+    /// All items' states are stored in one file, e.g. `states_current.yaml`.
+    #[default]
+    SingleFile,
+    /// Each item's state is stored in its own file, e.g.
+    /// `states_current/<item_id>.yaml`.
+    ///
+    /// Only the states that changed are written, and only the states of items
+    /// in the flow are read. This is faster for flows with many items, or with
+    /// large states.
+    PerItem,
+}
+
+impl StatesLayout {
+    /// Returns the directory that stores each item's state file, when using
+    /// the [`PerItem`] layout.
+    ///
+    /// This is the states file path without its extension, e.g.
+    /// `states_current.yaml` becomes `states_current`.
+    ///
+    /// [`PerItem`]: Self::PerItem
+    pub fn item_states_dir(states_file_path: &Path) -> PathBuf {
+        states_file_path.with_extension("")
+    }
+
+    /// Returns the path to the file that stores an item's state, when using
+    /// the [`PerItem`] layout.
+    ///
+    /// This is the [`item_states_dir`] joined with the item ID, e.g.
+    /// `states_current.yaml` becomes `states_current/<item_id>.yaml`.
+    ///
+    /// [`PerItem`]: Self::PerItem
+    /// [`item_states_dir`]: Self::item_states_dir
+    pub fn item_states_file_path(states_file_path: &Path, item_id: &ItemId) -> PathBuf {
+        Self::item_states_dir(states_file_path).join(format!("{item_id}.yaml"))
+    }
+}
@@ -0,0 +1,109 @@
+use std::{
+    collections::BTreeSet,
+    fmt::Debug,
+    path::{Path, PathBuf},
+};
+
+use async_trait::async_trait;
+
+use crate::Error;
+
+/// Reads and writes the bytes of files stored in a workspace.
+///
+/// `Storage` serializes and deserializes workspace files, such as params and
+/// states, and delegates to a `StorageBackend` to store the bytes. This allows
+/// a workspace to be stored outside of the file system, e.g. in a database or
+/// remote service, without changing how the files are serialized.
+///
+/// # Implementors
+///
+/// Paths are the same paths that would be used if the workspace were stored
+/// on the file system, e.g. `.peace/envman/dev/deploy/states_current.yaml`.
+/// Implementations may use them as opaque keys, in which case writing a path
+/// should succeed even if its parent "directory" has not been created.
+#[async_trait(?Send)]
+pub trait StorageBackend: Debug + Send + Sync + 'static {
+    /// Creates the directories used by the workspace.
+    ///
+    /// This does nothing by default, for backends that use paths as opaque
+    /// keys.
+    async fn dirs_create(&self, dirs: &[&Path]) -> Result<(), Error> {
+        let _dirs = dirs;
+        Ok(())
+    }
+
+    /// Returns whether an item exists at the given path.
+    async fn contains_item(&self, path: &Path) -> Result<bool, Error>;
+
+    /// Returns the bytes stored at the given path, if any.
+    async fn read_opt(&self, path: &Path) -> Result<Option<Vec<u8>>, Error>;
+
+    /// Stores the bytes at the given path, replacing any existing item.
+    async fn write(&self, path: &Path, bytes: &[u8]) -> Result<(), Error>;
+
+    /// Removes the item at the given path.
+    ///
+    /// This should succeed if the item does not exist.
+    async fn remove(&self, path: &Path) -> Result<(), Error>;
+
+    /// Moves the item at `path_from` to `path_to`, replacing any existing item
+    /// at `path_to`.
+    ///
+    /// By default, the item is written to `path_to` and removed from
+    /// `path_from`. Backends that can replace an item atomically, such as a
+    /// file system rename, should override this.
+    async fn rename(&self, path_from: &Path, path_to: &Path) -> Result<(), Error> {
+        if let Some(bytes) = self.read_opt(path_from).await? {
+            self.write(path_to, &bytes).await?;
+            self.remove(path_from).await?;
+        }
+        Ok(())
+    }
+
+    /// Returns the paths of all items under the given directory, in sorted
+    /// order.
+    ///
+    /// Items in nested directories are included. This is used to copy,
+    /// rename, and delete profiles.
+    async fn paths_under(&self, dir: &Path) -> Result<Vec<PathBuf>, Error>;
+
+    /// Returns the paths of the directories directly in the given directory,
+    /// in sorted order.
+    ///
+    /// This is used to list profiles. By default, directories are derived
+    /// from the paths returned by [`paths_under`], so only directories that
+    /// contain an item are returned. Backends that store directories, such
+    /// as the file system, should override this so that empty directories
+    /// are also returned.
+    ///
+    /// [`paths_under`]: Self::paths_under
+    async fn dirs_under(&self, dir: &Path) -> Result<Vec<PathBuf>, Error> {
+        let dirs = self
+            .paths_under(dir)
+            .await?
+            .iter()
+            .filter_map(|path| {
+                let mut components = path.strip_prefix(dir).ok()?.components();
+                let dir_name = components.next()?;
+
+                // Items directly in `dir` are not in a directory.
+                components.next().map(|_| dir.join(dir_name))
+            })
+            .collect::<BTreeSet<PathBuf>>();
+
+        Ok(dirs.into_iter().collect())
+    }
+
+    /// Removes the given directory and all items under it.
+    ///
+    /// This should succeed if the directory does not exist. By default, each
+    /// item returned by [`paths_under`] is removed.
+    ///
+    /// [`paths_under`]: Self::paths_under
+    async fn dir_remove(&self, dir: &Path) -> Result<(), Error> {
+        for path in self.paths_under(dir).await? {
+            self.remove(&path).await?;
+        }
+        Ok(())
+    }
+}
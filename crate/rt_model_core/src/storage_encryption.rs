@@ -0,0 +1,211 @@
+use std::{ffi::OsStr, path::Path, sync::Arc};
+
+use base64::Engine;
+use chacha20poly1305::{
+    aead::{Aead, OsRng, Payload},
+    AeadCore, ChaCha20Poly1305, Key, KeyInit, Nonce,
+};
+use peace_resource_rt::paths::PeaceDir;
+
+use crate::{EncryptionError, EncryptionKeyProvider};
+
+/// Encrypts and decrypts workspace files at rest.
+///
+/// Files are encrypted with ChaCha20-Poly1305 using the key from the
+/// [`EncryptionKeyProvider`]. The encrypted file is text, so that it may be
+/// stored in backends that only support strings:
+///
+/// ```text
+/// # peace encrypted: chacha20poly1305
+/// <base64 of nonce followed by ciphertext>
+/// ```
+///
+/// The file's path within the `.peace` directory is used as associated data,
+/// so an encrypted file fails to decrypt if it is moved to another file's path,
+/// such as another profile's states. The workspace directory itself may still
+/// be moved.
+///
+/// When encryption is enabled, files that are written encrypted must begin
+/// with the header when read, so that an encrypted file cannot be replaced
+/// with a plaintext one. Workspaces that were stored before encryption was
+/// enabled are encrypted using the `ReencryptCmd`.
+#[derive(Clone, Debug)]
+pub struct StorageEncryption {
+    /// Provides the key to encrypt and decrypt files with.
+    key_provider: Arc<dyn EncryptionKeyProvider>,
+}
+
+impl StorageEncryption {
+    /// First line of an encrypted file.
+    pub const HEADER: &'static [u8] = b"# peace encrypted: chacha20poly1305\n";
+    /// Number of bytes in the nonce that prefixes the ciphertext.
+    const NONCE_LEN: usize = 12;
+
+    /// Returns a new `StorageEncryption` using the given key provider.
+    pub fn new<P>(key_provider: P) -> Self
+    where
+        P: EncryptionKeyProvider,
+    {
+        Self {
+            key_provider: Arc::new(key_provider),
+        }
+    }
+
+    /// Returns the key provider used to encrypt and decrypt files.
+    pub fn key_provider(&self) -> &dyn EncryptionKeyProvider {
+        self.key_provider.as_ref()
+    }
+
+    /// Returns whether the given bytes are an encrypted file.
+    pub fn is_encrypted(bytes: &[u8]) -> bool {
+        bytes.starts_with(Self::HEADER)
+    }
+
+    /// Returns the encrypted form of the given plaintext bytes.
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: Path of the file being encrypted, which the ciphertext is
+    ///   bound to.
+    /// * `plaintext`: Bytes to encrypt.
+    pub fn encrypt(&self, path: &Path, plaintext: &[u8]) -> Result<Vec<u8>, EncryptionError> {
+        let cipher = self.cipher()?;
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let associated_data = Self::associated_data(path);
+        let payload = Payload {
+            msg: plaintext,
+            aad: associated_data.as_bytes(),
+        };
+        let ciphertext =
+            cipher
+                .encrypt(&nonce, payload)
+                .map_err(|_error| EncryptionError::Encrypt {
+                    path: path.to_path_buf(),
+                })?;
+
+        let mut nonce_and_ciphertext = Vec::with_capacity(nonce.len() + ciphertext.len());
+        nonce_and_ciphertext.extend_from_slice(&nonce);
+        nonce_and_ciphertext.extend_from_slice(&ciphertext);
+
+        let mut bytes = Self::HEADER.to_vec();
+        bytes.extend_from_slice(
+            base64::engine::general_purpose::STANDARD
+                .encode(nonce_and_ciphertext)
+                .as_bytes(),
+        );
+        bytes.push(b'\n');
+
+        Ok(bytes)
+    }
+
+    /// Returns the plaintext bytes of the given encrypted file.
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: Path of the file being decrypted, which must be the path it
+    ///   was encrypted for.
+    /// * `bytes`: Contents of the encrypted file, including the header.
+    pub fn decrypt(&self, path: &Path, bytes: &[u8]) -> Result<Vec<u8>, EncryptionError> {
+        let decrypt_error = || EncryptionError::Decrypt {
+            path: path.to_path_buf(),
+        };
+
+        let body = bytes.strip_prefix(Self::HEADER).ok_or_else(decrypt_error)?;
+        let nonce_and_ciphertext = base64::engine::general_purpose::STANDARD
+            .decode(body.trim_ascii())
+            .map_err(|_error| decrypt_error())?;
+        if nonce_and_ciphertext.len() < Self::NONCE_LEN {
+            return Err(decrypt_error());
+        }
+        let (nonce, ciphertext) = nonce_and_ciphertext.split_at(Self::NONCE_LEN);
+        let associated_data = Self::associated_data(path);
+        let payload = Payload {
+            msg: ciphertext,
+            aad: associated_data.as_bytes(),
+        };
+
+        self.cipher()?
+            .decrypt(Nonce::from_slice(nonce), payload)
+            .map_err(|_error| decrypt_error())
+    }
+
+    /// Returns the plaintext of a file that is written encrypted, decrypting
+    /// it if encryption is enabled.
+    ///
+    /// This is used for files that may contain sensitive values, such as
+    /// stored states and params specs.
+    ///
+    /// An error is returned if:
+    ///
+    /// * `encryption` is set, and the bytes are not encrypted.
+    /// * `encryption` is `None`, and the bytes are encrypted.
+    pub fn decrypt_if_encrypted(
+        encryption: Option<&Self>,
+        path: &Path,
+        bytes: Vec<u8>,
+    ) -> Result<Vec<u8>, EncryptionError> {
+        match encryption {
+            Some(encryption) => {
+                if Self::is_encrypted(&bytes) {
+                    encryption.decrypt(path, &bytes)
+                } else {
+                    Err(EncryptionError::NotEncrypted {
+                        path: path.to_path_buf(),
+                    })
+                }
+            }
+            None => Self::decrypt_if_has_header(None, path, bytes),
+        }
+    }
+
+    /// Returns the plaintext of the given file bytes, decrypting them if they
+    /// begin with the encryption header.
+    ///
+    /// This is used for files that are not written encrypted, such as
+    /// workspace params, and to read files that were stored before encryption
+    /// was enabled.
+    ///
+    /// An error is returned if the bytes are encrypted, and `encryption` is
+    /// `None`.
+    pub fn decrypt_if_has_header(
+        encryption: Option<&Self>,
+        path: &Path,
+        bytes: Vec<u8>,
+    ) -> Result<Vec<u8>, EncryptionError> {
+        if !Self::is_encrypted(&bytes) {
+            return Ok(bytes);
+        }
+
+        match encryption {
+            Some(encryption) => encryption.decrypt(path, &bytes),
+            None => Err(EncryptionError::KeyMissing {
+                path: path.to_path_buf(),
+            }),
+        }
+    }
+
+    /// Returns the associated data that binds a file's ciphertext to its path.
+    ///
+    /// This is the path after the last `.peace` directory, joined with `/` so
+    /// that it is the same on every platform. Paths that are not in a `.peace`
+    /// directory are used whole.
+    fn associated_data(path: &Path) -> String {
+        let path_in_peace_dir = path
+            .ancestors()
+            .find(|ancestor| ancestor.file_name() == Some(OsStr::new(PeaceDir::NAME)))
+            .and_then(|peace_dir| path.strip_prefix(peace_dir).ok())
+            .unwrap_or(path);
+
+        path_in_peace_dir
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    /// Returns the cipher initialized with the provided key.
+    fn cipher(&self) -> Result<ChaCha20Poly1305, EncryptionError> {
+        let key = self.key_provider.key()?;
+        Ok(ChaCha20Poly1305::new(Key::from_slice(key.as_bytes())))
+    }
+}
@@ -0,0 +1,189 @@
+use std::path::{Path, PathBuf};
+
+use futures::{stream, StreamExt, TryStreamExt};
+use peace_rt_model_core::{async_trait, Error, NativeError, StorageBackend};
+use tokio::{fs::File, io::AsyncWriteExt};
+
+/// Stores workspace files on the file system.
+///
+/// This is the default `StorageBackend` for a `Workspace`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FileStorageBackend;
+
+#[async_trait(?Send)]
+impl StorageBackend for FileStorageBackend {
+    async fn dirs_create(&self, dirs: &[&Path]) -> Result<(), Error> {
+        stream::iter(dirs)
+            .map(Result::<_, Error>::Ok)
+            .try_for_each(|dir| async move {
+                tokio::fs::create_dir_all(dir).await.map_err(|error| {
+                    let path = dir.to_path_buf();
+                    Error::Native(NativeError::WorkspaceDirCreate { path, error })
+                })
+            })
+            .await
+    }
+
+    async fn contains_item(&self, path: &Path) -> Result<bool, Error> {
+        Ok(path.exists())
+    }
+
+    async fn read_opt(&self, path: &Path) -> Result<Option<Vec<u8>>, Error> {
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        tokio::fs::read(path).await.map(Some).map_err(
+            // Tests currently don't cover file system failure cases,
+            // e.g. permission errors.
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            |error| {
+                let path = path.to_path_buf();
+                Error::Native(NativeError::FileRead { path, error })
+            },
+        )
+    }
+
+    async fn write(&self, path: &Path, bytes: &[u8]) -> Result<(), Error> {
+        let mut file = File::create(path).await.map_err(
+            // Tests currently don't cover file system failure cases,
+            // e.g. disk space limits.
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            |error| {
+                let path = path.to_path_buf();
+                Error::Native(NativeError::FileCreate { path, error })
+            },
+        )?;
+        file.write_all(bytes).await.map_err(
+            // Tests currently don't cover file system failure cases,
+            // e.g. disk space limits.
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            |error| {
+                let path = path.to_path_buf();
+                Error::Native(NativeError::FileWrite { path, error })
+            },
+        )?;
+        file.flush().await.map_err(
+            // Tests currently don't cover file system failure cases,
+            // e.g. disk space limits.
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            |error| {
+                let path = path.to_path_buf();
+                Error::Native(NativeError::FileWrite { path, error })
+            },
+        )
+    }
+
+    async fn remove(&self, path: &Path) -> Result<(), Error> {
+        match tokio::fs::remove_file(path).await {
+            Ok(()) => Ok(()),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(error) => {
+                let path = path.to_path_buf();
+                Err(Error::Native(NativeError::FileRemove { path, error }))
+            }
+        }
+    }
+
+    async fn rename(&self, path_from: &Path, path_to: &Path) -> Result<(), Error> {
+        tokio::fs::rename(path_from, path_to).await.map_err(
+            // Tests currently don't cover file system failure cases,
+            // e.g. permission errors.
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            |error| {
+                let path_from = path_from.to_path_buf();
+                let path_to = path_to.to_path_buf();
+                Error::Native(NativeError::FileRename {
+                    path_from,
+                    path_to,
+                    error,
+                })
+            },
+        )
+    }
+
+    async fn paths_under(&self, dir: &Path) -> Result<Vec<PathBuf>, Error> {
+        let mut paths = Vec::new();
+        let mut dirs_to_read = vec![dir.to_path_buf()];
+        while let Some(dir) = dirs_to_read.pop() {
+            let mut read_dir = match tokio::fs::read_dir(&dir).await {
+                Ok(read_dir) => read_dir,
+                Err(error) if error.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(error) => {
+                    return Err(Error::Native(NativeError::DirRead { path: dir, error }));
+                }
+            };
+            while let Some(entry) = read_dir.next_entry().await.map_err(
+                #[cfg_attr(coverage_nightly, coverage(off))]
+                |error| {
+                    let path = dir.clone();
+                    Error::Native(NativeError::DirEntryRead { path, error })
+                },
+            )? {
+                let file_type = entry.file_type().await.map_err(
+                    #[cfg_attr(coverage_nightly, coverage(off))]
+                    |error| {
+                        let path = entry.path();
+                        Error::Native(NativeError::DirEntryFileTypeRead { path, error })
+                    },
+                )?;
+                if file_type.is_dir() {
+                    dirs_to_read.push(entry.path());
+                } else {
+                    paths.push(entry.path());
+                }
+            }
+        }
+
+        // Ensure paths are in a consistent order.
+        paths.sort();
+
+        Ok(paths)
+    }
+
+    async fn dirs_under(&self, dir: &Path) -> Result<Vec<PathBuf>, Error> {
+        let mut dirs = Vec::new();
+        let mut read_dir = match tokio::fs::read_dir(dir).await {
+            Ok(read_dir) => read_dir,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(dirs),
+            Err(error) => {
+                let path = dir.to_path_buf();
+                return Err(Error::Native(NativeError::DirRead { path, error }));
+            }
+        };
+        while let Some(entry) = read_dir.next_entry().await.map_err(
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            |error| {
+                let path = dir.to_path_buf();
+                Error::Native(NativeError::DirEntryRead { path, error })
+            },
+        )? {
+            let file_type = entry.file_type().await.map_err(
+                #[cfg_attr(coverage_nightly, coverage(off))]
+                |error| {
+                    let path = entry.path();
+                    Error::Native(NativeError::DirEntryFileTypeRead { path, error })
+                },
+            )?;
+            if file_type.is_dir() {
+                dirs.push(entry.path());
+            }
+        }
+
+        // Ensure dirs are in a consistent order.
+        dirs.sort();
+
+        Ok(dirs)
+    }
+
+    async fn dir_remove(&self, dir: &Path) -> Result<(), Error> {
+        match tokio::fs::remove_dir_all(dir).await {
+            Ok(()) => Ok(()),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(error) => {
+                let path = dir.to_path_buf();
+                Err(Error::Native(NativeError::DirRemove { path, error }))
+            }
+        }
+    }
+}
@@ -9,12 +9,14 @@
 pub use tokio_util::io::SyncIoBridge;
 
 pub use crate::{
-    storage::Storage, workspace::Workspace, workspace_dirs_builder::WorkspaceDirsBuilder,
-    workspace_initializer::WorkspaceInitializer, workspace_spec::WorkspaceSpec,
+    file_storage_backend::FileStorageBackend, storage::Storage, workspace::Workspace,
+    workspace_dirs_builder::WorkspaceDirsBuilder, workspace_initializer::WorkspaceInitializer,
+    workspace_spec::WorkspaceSpec,
 };
 
 pub mod workspace;
 
+mod file_storage_backend;
 mod storage;
 mod workspace_dirs_builder;
 mod workspace_initializer;
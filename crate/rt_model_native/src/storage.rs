@@ -1,10 +1,20 @@
-use std::{fmt::Debug, hash::Hash, io::Write, path::Path, sync::Mutex};
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap, HashSet},
+    fmt::{self, Debug},
+    hash::{Hash, Hasher},
+    io::Write,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
 
 use peace_resource_rt::type_reg::{
     common::UnknownEntriesSome,
     untagged::{DataTypeWrapper, TypeMapOpt, TypeReg},
 };
-use peace_rt_model_core::{Error, NativeError};
+use peace_rt_model_core::{
+    Error, NativeError, SerializationError, SerializationFormat, StatesLayout, StorageBackend,
+    StorageEncryption,
+};
 use serde::{de::DeserializeOwned, Serialize};
 use tokio::{
     fs::File,
@@ -12,16 +22,115 @@ use tokio::{
 };
 use tokio_util::io::SyncIoBridge;
 
-/// Wrapper around file system operations.
-#[derive(Clone, Debug)]
-pub struct Storage;
+use crate::FileStorageBackend;
+
+/// Serializes workspace files, and stores them through a [`StorageBackend`].
+///
+/// By default, files are stored on the file system using the
+/// [`FileStorageBackend`].
+///
+/// When [`StorageEncryption`] is set, files written using
+/// [`serialized_write_encrypted`] are encrypted, and files read using
+/// [`serialized_typemap_read_opt_encrypted`] must be encrypted. Other reads
+/// decrypt files that are encrypted, and return other files unchanged.
+///
+/// Files are written in the [`SerializationFormat`] set on this `Storage`,
+/// with the format's file extension. When reading, files in other formats are
+/// read if the file does not exist in this `Storage`'s format.
+///
+/// [`serialized_write_encrypted`]: Self::serialized_write_encrypted
+/// [`serialized_typemap_read_opt_encrypted`]: Self::serialized_typemap_read_opt_encrypted
+#[derive(Clone)]
+pub struct Storage {
+    /// Backend that stores the serialized bytes.
+    backend: Arc<dyn StorageBackend>,
+    /// Format to serialize files in.
+    serialization_format: SerializationFormat,
+    /// How stored states are laid out in a flow directory.
+    states_layout: StatesLayout,
+    /// Encrypts and decrypts sensitive files, if set.
+    encryption: Option<StorageEncryption>,
+    /// Paths of files read or written in this `Storage`'s serialization
+    /// format, so files in other formats are only removed on the first write.
+    file_paths_in_format: Arc<Mutex<HashSet<PathBuf>>>,
+    /// Digests of the plaintext of files last read or written through this
+    /// `Storage`, so unchanged files are not written again.
+    file_digests: Arc<Mutex<HashMap<PathBuf, u64>>>,
+}
 
 impl Storage {
+    /// Returns a new `Storage` that stores files through the given backend.
+    pub fn new<B>(backend: B) -> Self
+    where
+        B: StorageBackend,
+    {
+        Self {
+            backend: Arc::new(backend),
+            serialization_format: SerializationFormat::default(),
+            states_layout: StatesLayout::default(),
+            encryption: None,
+            file_paths_in_format: Arc::default(),
+            file_digests: Arc::default(),
+        }
+    }
+
+    /// Sets the backend that stores the serialized bytes, keeping the other
+    /// settings.
+    pub fn with_storage_backend<B>(self, backend: B) -> Self
+    where
+        B: StorageBackend,
+    {
+        Self {
+            backend: Arc::new(backend),
+            file_paths_in_format: Arc::default(),
+            file_digests: Arc::default(),
+            ..self
+        }
+    }
+
+    /// Sets the format to serialize files in.
+    pub fn with_serialization_format(mut self, serialization_format: SerializationFormat) -> Self {
+        self.serialization_format = serialization_format;
+        self
+    }
+
+    /// Sets how stored states are laid out in a flow directory.
+    pub fn with_states_layout(mut self, states_layout: StatesLayout) -> Self {
+        self.states_layout = states_layout;
+        self
+    }
+
+    /// Sets the encryption used for sensitive files.
+    pub fn with_encryption(mut self, encryption: StorageEncryption) -> Self {
+        self.encryption = Some(encryption);
+        self
+    }
+
+    /// Returns a reference to the backend that stores the serialized bytes.
+    pub fn backend(&self) -> &dyn StorageBackend {
+        self.backend.as_ref()
+    }
+
+    /// Returns the format that files are serialized in.
+    pub fn serialization_format(&self) -> SerializationFormat {
+        self.serialization_format
+    }
+
+    /// Returns how stored states are laid out in a flow directory.
+    pub fn states_layout(&self) -> StatesLayout {
+        self.states_layout
+    }
+
+    /// Returns the encryption used for sensitive files, if set.
+    pub fn encryption(&self) -> Option<&StorageEncryption> {
+        self.encryption.as_ref()
+    }
+
     /// Reads a serializable item from the given path.
     ///
     /// # Parameters
     ///
-    /// * `thread_name`: Name of the thread to use to do the read operation.
+    /// * `thread_name`: Name of the thread to use to do the deserialization.
     /// * `file_path`: Path to the file to read the serialized item.
     /// * `f_map_err`: Maps the deserialization error (if any) to an [`Error`].
     pub async fn serialized_read<T, F>(
@@ -32,28 +141,20 @@ impl Storage {
     ) -> Result<T, Error>
     where
         T: Serialize + DeserializeOwned + Send + Sync,
-        F: FnOnce(serde_yaml::Error) -> Error + Send,
+        F: FnOnce(SerializationError) -> Error + Send,
     {
-        if file_path.exists() {
-            let t = self
-                .read_with_sync_api(thread_name, file_path, |file| {
-                    serde_yaml::from_reader::<_, T>(file).map_err(f_map_err)
-                })
-                .await?;
-
-            Ok(t)
-        } else {
-            Err(Error::ItemNotExists {
+        self.serialized_read_opt(thread_name, file_path, f_map_err)
+            .await?
+            .ok_or_else(|| Error::ItemNotExists {
                 path: file_path.to_path_buf(),
             })
-        }
     }
 
     /// Reads a serializable item from the given path if the file exists.
     ///
     /// # Parameters
     ///
-    /// * `thread_name`: Name of the thread to use to do the read operation.
+    /// * `thread_name`: Name of the thread to use to do the deserialization.
     /// * `file_path`: Path to the file to read the serialized item.
     /// * `f_map_err`: Maps the deserialization error (if any) to an [`Error`].
     pub async fn serialized_read_opt<T, F>(
@@ -64,26 +165,28 @@ impl Storage {
     ) -> Result<Option<T>, Error>
     where
         T: DeserializeOwned + Send + Sync,
-        F: FnOnce(serde_yaml::Error) -> Error + Send,
+        F: FnOnce(SerializationError) -> Error + Send,
     {
-        if file_path.exists() {
-            let t = self
-                .read_with_sync_api(thread_name, file_path, |file| {
-                    serde_yaml::from_reader::<_, T>(file).map_err(f_map_err)
-                })
-                .await?;
+        let Some((serialization_format, bytes)) = self.plaintext_read_opt(file_path, false).await?
+        else {
+            return Ok(None);
+        };
 
-            Ok(Some(t))
-        } else {
-            Ok(None)
-        }
+        Self::run_on_thread(thread_name, move || {
+            serialization_format
+                .deserialize::<T>(&bytes)
+                .map_err(f_map_err)
+                .map_err(Box::new)
+        })
+        .map(Some)
+        .map_err(|error: Box<Error>| *error)
     }
 
     /// Deserializes a typemap from the given path if the file exists.
     ///
     /// # Parameters
     ///
-    /// * `thread_name`: Name of the thread to use to do the read operation.
+    /// * `thread_name`: Name of the thread to use to do the deserialization.
     /// * `type_reg`: Type registry with the stateful deserialization mappings.
     /// * `file_path`: Path to the file to read the serialized item.
     /// * `f_map_err`: Maps the deserialization error (if any) to an [`Error`].
@@ -97,33 +200,55 @@ impl Storage {
     where
         K: Clone + Debug + DeserializeOwned + Eq + Hash + Send + Sync + 'static,
         BoxDT: DataTypeWrapper + Send + 'static,
-        F: FnOnce(serde_yaml::Error) -> Error + Send,
+        F: FnOnce(SerializationError) -> Error + Send,
     {
-        if file_path.exists() {
-            let type_map_opt = self
-                .read_with_sync_api(thread_name, file_path, |file| {
-                    let deserializer = serde_yaml::Deserializer::from_reader(file);
-                    let type_map_opt = type_reg
-                        .deserialize_map_opt_with_unknowns::<'_, serde_yaml::Value, _, _>(
-                            deserializer,
-                        )
-                        .map_err(f_map_err)?;
-
-                    Result::<_, Error>::Ok(type_map_opt)
-                })
-                .await?;
+        self.typemap_read_opt(thread_name, type_reg, file_path, false, |_bytes, error| {
+            f_map_err(error)
+        })
+        .await
+    }
 
-            Ok(Some(type_map_opt))
-        } else {
-            Ok(None)
-        }
+    /// Deserializes a typemap from the given path if the file exists,
+    /// decrypting it if encryption is set.
+    ///
+    /// This is used for files written using [`serialized_write_encrypted`].
+    /// When encryption is set, an error is returned if the file is not
+    /// encrypted.
+    ///
+    /// The plaintext bytes are passed to `f_map_err` when deserialization
+    /// fails, so that errors are able to show the file contents without
+    /// reading the file again.
+    ///
+    /// # Parameters
+    ///
+    /// * `thread_name`: Name of the thread to use to do the deserialization.
+    /// * `type_reg`: Type registry with the stateful deserialization mappings.
+    /// * `file_path`: Path to the file to read the serialized item.
+    /// * `f_map_err`: Maps the file's plaintext bytes and the deserialization
+    ///   error (if any) to an [`Error`].
+    ///
+    /// [`serialized_write_encrypted`]: Self::serialized_write_encrypted
+    pub async fn serialized_typemap_read_opt_encrypted<K, BoxDT, F>(
+        &self,
+        thread_name: String,
+        type_reg: &TypeReg<K, BoxDT>,
+        file_path: &Path,
+        f_map_err: F,
+    ) -> Result<Option<TypeMapOpt<K, BoxDT, UnknownEntriesSome<serde_yaml::Value>>>, Error>
+    where
+        K: Clone + Debug + DeserializeOwned + Eq + Hash + Send + Sync + 'static,
+        BoxDT: DataTypeWrapper + Send + 'static,
+        F: FnOnce(&[u8], SerializationError) -> Error + Send,
+    {
+        self.typemap_read_opt(thread_name, type_reg, file_path, true, f_map_err)
+            .await
     }
 
     /// Writes a serializable item to the given path.
     ///
     /// # Parameters
     ///
-    /// * `thread_name`: Name of the thread to use to do the write operation.
+    /// * `thread_name`: Name of the thread to use to do the serialization.
     /// * `file_path`: Path to the file to store the serialized item.
     /// * `t`: Item to serialize.
     /// * `f_map_err`: Maps the serialization error (if any) to an [`Error`].
@@ -136,13 +261,100 @@ impl Storage {
     ) -> Result<(), Error>
     where
         T: Serialize + Send + Sync,
-        F: FnOnce(serde_yaml::Error) -> Error + Send,
+        F: FnOnce(SerializationError) -> Error + Send,
     {
-        self.write_with_sync_api(thread_name, file_path, |file| {
-            serde_yaml::to_writer(file, t).map_err(f_map_err)
-        })
-        .await?;
+        self.serialized_write_internal(thread_name, file_path, t, f_map_err, None)
+            .await
+    }
+
+    /// Writes a serializable item to the given path, encrypting it if
+    /// encryption is set.
+    ///
+    /// This is used for files that may contain sensitive values, such as
+    /// stored states and params specs.
+    ///
+    /// # Parameters
+    ///
+    /// * `thread_name`: Name of the thread to use to do the serialization.
+    /// * `file_path`: Path to the file to store the serialized item.
+    /// * `t`: Item to serialize.
+    /// * `f_map_err`: Maps the serialization error (if any) to an [`Error`].
+    pub async fn serialized_write_encrypted<T, F>(
+        &self,
+        thread_name: String,
+        file_path: &Path,
+        t: &T,
+        f_map_err: F,
+    ) -> Result<(), Error>
+    where
+        T: Serialize + Send + Sync,
+        F: FnOnce(SerializationError) -> Error + Send,
+    {
+        self.serialized_write_internal(
+            thread_name,
+            file_path,
+            t,
+            f_map_err,
+            self.encryption.as_ref(),
+        )
+        .await
+    }
+
+    /// Writes a serializable item to the given path if it differs from the
+    /// stored item, encrypting it if encryption is set.
+    ///
+    /// The stored item is not read. Instead, the item is compared with the
+    /// item last read from or written to the path through this `Storage`, so
+    /// it is always written if it has not been read or written before.
+    ///
+    /// Returns whether the item was written.
+    ///
+    /// # Parameters
+    ///
+    /// * `thread_name`: Name of the thread to use to do the serialization.
+    /// * `file_path`: Path to the file to store the serialized item.
+    /// * `t`: Item to serialize.
+    /// * `f_map_err`: Maps the serialization error (if any) to an [`Error`].
+    pub async fn serialized_write_encrypted_if_changed<T, F>(
+        &self,
+        thread_name: String,
+        file_path: &Path,
+        t: &T,
+        f_map_err: F,
+    ) -> Result<bool, Error>
+    where
+        T: Serialize + Send + Sync,
+        F: FnOnce(SerializationError) -> Error + Send,
+    {
+        let bytes = self.serialize(thread_name, t, f_map_err).await?;
+        let digest = plaintext_digest(&bytes);
+        let unchanged = self
+            .file_digests
+            .lock()
+            .map(|file_digests| file_digests.get(file_path) == Some(&digest))
+            .unwrap_or(false);
+        if unchanged {
+            return Ok(false);
+        }
 
+        self.write_encrypted(file_path, bytes, self.encryption.as_ref())
+            .await?;
+
+        Ok(true)
+    }
+
+    /// Removes the item at the given path in every serialization format, if
+    /// it exists.
+    pub async fn remove(&self, file_path: &Path) -> Result<(), Error> {
+        if let Ok(mut file_paths_in_format) = self.file_paths_in_format.lock() {
+            file_paths_in_format.remove(file_path);
+        }
+        self.file_digest_update(file_path, None);
+        for serialization_format in SerializationFormat::ALL {
+            self.backend
+                .remove(&serialization_format.file_path(file_path))
+                .await?;
+        }
         Ok(())
     }
 
@@ -151,6 +363,9 @@ impl Storage {
     ///
     /// This method buffers the write, and calls flush on the buffer when the
     /// passed in closure returns.
+    ///
+    /// This reads from the file system directly, regardless of the storage
+    /// backend.
     pub async fn read_with_sync_api<'f, F, T, E>(
         &self,
         thread_name: String,
@@ -173,21 +388,7 @@ impl Storage {
         )?;
         let mut sync_io_bridge = SyncIoBridge::new(BufReader::new(file));
 
-        // `tokio::task::spawn_blocking` doesn't work because it needs the closure's
-        // environment to be `'static`
-        let t = std::thread::scope(move |s| {
-            std::thread::Builder::new()
-                .name(thread_name)
-                .spawn_scoped(s, move || f(&mut sync_io_bridge))
-                .map_err(NativeError::StorageSyncThreadSpawn)
-                .map_err(Error::Native)?
-                .join()
-                .map_err(Mutex::new)
-                .map_err(NativeError::StorageSyncThreadJoin)
-                .map_err(Error::Native)?
-        })?;
-
-        Ok(t)
+        Self::run_on_thread(thread_name, move || f(&mut sync_io_bridge))
     }
 
     /// Writes to a file, bridging to libraries that take a synchronous `Write`
@@ -196,6 +397,9 @@ impl Storage {
     /// This method buffers the write, and calls flush on the buffer when the
     /// passed in closure returns.
     ///
+    /// This writes to the file system directly, regardless of the storage
+    /// backend.
+    ///
     /// # Parameters
     ///
     /// * `thread_name`: Name of the thread to use to do the write operation.
@@ -223,34 +427,237 @@ impl Storage {
         )?;
         let mut sync_io_bridge = SyncIoBridge::new(BufWriter::new(file));
 
-        // `tokio::task::spawn_blocking` doesn't work because it needs the closure's
-        // environment to be `'static`
-        let t = std::thread::scope(move |s| {
+        Self::run_on_thread(thread_name, move || {
+            let t = f(&mut sync_io_bridge).map_err(Box::new)?;
+
+            sync_io_bridge.flush().map_err(
+                // Tests currently don't cover file system failure cases,
+                // e.g. disk space limits.
+                #[cfg_attr(coverage_nightly, coverage(off))]
+                |error| {
+                    let path = file_path.to_path_buf();
+                    Box::new(Error::Native(NativeError::FileWrite { path, error }))
+                },
+            )?;
+
+            Result::<_, Box<Error>>::Ok(t)
+        })
+        .map_err(|error| *error)
+    }
+
+    /// Serializes the item, encrypts it if `encryption` is `Some`, and writes
+    /// it to the backend.
+    async fn serialized_write_internal<T, F>(
+        &self,
+        thread_name: String,
+        file_path: &Path,
+        t: &T,
+        f_map_err: F,
+        encryption: Option<&StorageEncryption>,
+    ) -> Result<(), Error>
+    where
+        T: Serialize + Send + Sync,
+        F: FnOnce(SerializationError) -> Error + Send,
+    {
+        let bytes = self.serialize(thread_name, t, f_map_err).await?;
+        self.write_encrypted(file_path, bytes, encryption).await
+    }
+
+    /// Serializes the item in this `Storage`'s serialization format.
+    async fn serialize<T, F>(
+        &self,
+        thread_name: String,
+        t: &T,
+        f_map_err: F,
+    ) -> Result<Vec<u8>, Error>
+    where
+        T: Serialize + Send + Sync,
+        F: FnOnce(SerializationError) -> Error + Send,
+    {
+        let serialization_format = self.serialization_format;
+        Self::run_on_thread(thread_name, move || {
+            serialization_format
+                .serialize(t)
+                .map_err(f_map_err)
+                .map_err(Box::new)
+        })
+        .map_err(|error: Box<Error>| *error)
+    }
+
+    /// Encrypts the bytes if `encryption` is `Some`, and writes them to the
+    /// backend in this `Storage`'s serialization format.
+    ///
+    /// If the file was not already read from or written to in this format,
+    /// the format may have changed, so the file in other formats is removed.
+    /// This means it is not read instead of this file if the format is
+    /// changed again.
+    async fn write_encrypted(
+        &self,
+        file_path: &Path,
+        bytes: Vec<u8>,
+        encryption: Option<&StorageEncryption>,
+    ) -> Result<(), Error> {
+        let format_file_path = self.serialization_format.file_path(file_path);
+        // Files written without encryption when encryption is set are not
+        // recorded, so they are encrypted when written through
+        // `serialized_write_encrypted_if_changed`.
+        let digest =
+            (encryption.is_some() || self.encryption.is_none()).then(|| plaintext_digest(&bytes));
+        let bytes = match encryption {
+            Some(encryption) => encryption.encrypt(&format_file_path, &bytes)?,
+            None => bytes,
+        };
+
+        let format_may_have_changed = self
+            .file_paths_in_format
+            .lock()
+            .map(|file_paths_in_format| !file_paths_in_format.contains(file_path))
+            .unwrap_or(true);
+
+        self.backend.write(&format_file_path, &bytes).await?;
+        self.file_path_in_format_insert(file_path);
+        self.file_digest_update(file_path, digest);
+        if format_may_have_changed {
+            for serialization_format in self.serialization_format.read_order().skip(1) {
+                let other_format_file_path = serialization_format.file_path(file_path);
+                if self.backend.contains_item(&other_format_file_path).await? {
+                    self.backend.remove(&other_format_file_path).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Deserializes a typemap from the given path if the file exists.
+    ///
+    /// When `encrypted` is `true`, the file must be encrypted if encryption is
+    /// set.
+    async fn typemap_read_opt<K, BoxDT, F>(
+        &self,
+        thread_name: String,
+        type_reg: &TypeReg<K, BoxDT>,
+        file_path: &Path,
+        encrypted: bool,
+        f_map_err: F,
+    ) -> Result<Option<TypeMapOpt<K, BoxDT, UnknownEntriesSome<serde_yaml::Value>>>, Error>
+    where
+        K: Clone + Debug + DeserializeOwned + Eq + Hash + Send + Sync + 'static,
+        BoxDT: DataTypeWrapper + Send + 'static,
+        F: FnOnce(&[u8], SerializationError) -> Error + Send,
+    {
+        let Some((serialization_format, bytes)) =
+            self.plaintext_read_opt(file_path, encrypted).await?
+        else {
+            return Ok(None);
+        };
+
+        Self::run_on_thread(thread_name, move || {
+            serialization_format
+                .deserialize_typemap_opt(type_reg, &bytes)
+                .map_err(|error| Box::new(f_map_err(&bytes, error)))
+        })
+        .map(Some)
+        .map_err(|error: Box<Error>| *error)
+    }
+
+    /// Returns the serialization format and plaintext bytes of the file at
+    /// the given path, if it exists in any format.
+    ///
+    /// When `encrypted` is `true`, the file must be encrypted if encryption is
+    /// set. Otherwise the file is only decrypted if it is encrypted.
+    async fn plaintext_read_opt(
+        &self,
+        file_path: &Path,
+        encrypted: bool,
+    ) -> Result<Option<(SerializationFormat, Vec<u8>)>, Error> {
+        for serialization_format in self.serialization_format.read_order() {
+            let format_file_path = serialization_format.file_path(file_path);
+            let Some(bytes) = self.backend.read_opt(&format_file_path).await? else {
+                continue;
+            };
+            let encryption = self.encryption.as_ref();
+            let bytes = if encrypted {
+                StorageEncryption::decrypt_if_encrypted(encryption, &format_file_path, bytes)?
+            } else {
+                StorageEncryption::decrypt_if_has_header(encryption, &format_file_path, bytes)?
+            };
+            if serialization_format == self.serialization_format {
+                self.file_path_in_format_insert(file_path);
+            }
+            let digest = (serialization_format == self.serialization_format
+                && (encrypted || encryption.is_none()))
+            .then(|| plaintext_digest(&bytes));
+            self.file_digest_update(file_path, digest);
+
+            return Ok(Some((serialization_format, bytes)));
+        }
+
+        Ok(None)
+    }
+
+    /// Records that a file was read or written in this `Storage`'s
+    /// serialization format.
+    fn file_path_in_format_insert(&self, file_path: &Path) {
+        if let Ok(mut file_paths_in_format) = self.file_paths_in_format.lock() {
+            file_paths_in_format.insert(file_path.to_path_buf());
+        }
+    }
+
+    /// Records the digest of the plaintext last read from or written to a
+    /// file, or clears it if `None`.
+    fn file_digest_update(&self, file_path: &Path, digest: Option<u64>) {
+        if let Ok(mut file_digests) = self.file_digests.lock() {
+            match digest {
+                Some(digest) => file_digests.insert(file_path.to_path_buf(), digest),
+                None => file_digests.remove(file_path),
+            };
+        }
+    }
+
+    /// Runs the given function on a separate named thread.
+    ///
+    /// `tokio::task::spawn_blocking` doesn't work because it needs the
+    /// closure's environment to be `'static`.
+    fn run_on_thread<F, T, E>(thread_name: String, f: F) -> Result<T, E>
+    where
+        F: FnOnce() -> Result<T, E> + Send,
+        T: Send,
+        E: From<Error> + Send,
+    {
+        std::thread::scope(move |s| {
             std::thread::Builder::new()
                 .name(thread_name)
-                .spawn_scoped(s, move || {
-                    let t = f(&mut sync_io_bridge)?;
-
-                    sync_io_bridge.flush().map_err(
-                        // Tests currently don't cover file system failure cases,
-                        // e.g. disk space limits.
-                        #[cfg_attr(coverage_nightly, coverage(off))]
-                        |error| {
-                            let path = file_path.to_path_buf();
-                            NativeError::FileWrite { path, error }
-                        },
-                    )?;
-
-                    Result::<_, Error>::Ok(t)
-                })
+                .spawn_scoped(s, f)
                 .map_err(NativeError::StorageSyncThreadSpawn)
                 .map_err(Error::Native)?
                 .join()
                 .map_err(Mutex::new)
                 .map_err(NativeError::StorageSyncThreadJoin)
                 .map_err(Error::Native)?
-        })?;
+        })
+    }
+}
 
-        Ok(t)
+impl Debug for Storage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Storage")
+            .field("backend", &self.backend)
+            .field("serialization_format", &self.serialization_format)
+            .field("states_layout", &self.states_layout)
+            .field("encryption", &self.encryption)
+            .finish_non_exhaustive()
     }
 }
+
+impl Default for Storage {
+    fn default() -> Self {
+        Self::new(FileStorageBackend)
+    }
+}
+
+/// Returns the digest of a file's plaintext, to detect whether it changed.
+fn plaintext_digest(bytes: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
@@ -25,13 +25,11 @@ impl WorkspaceDirsBuilder {
         use peace_resource_rt::paths::WorkspaceDir;
 
         let workspace_dir = {
-            let working_dir = std::env::current_dir()
-                .map_err(NativeError::WorkingDirRead)
-                .map_err(Error::Native)?;
             let workspace_dir = match workspace_spec {
-                WorkspaceSpec::WorkingDir => working_dir,
+                WorkspaceSpec::WorkingDir => Self::working_dir().map_err(Error::Native)?,
                 WorkspaceSpec::Path(path) => path,
                 WorkspaceSpec::FirstDirWithFile(file_name) => {
+                    let working_dir = Self::working_dir().map_err(Error::Native)?;
                     Self::first_dir_with_file(&working_dir, &file_name).ok_or({
                         Error::Native(NativeError::WorkspaceFileNotFound {
                             working_dir,
@@ -39,6 +37,7 @@ impl WorkspaceDirsBuilder {
                         })
                     })?
                 }
+                WorkspaceSpec::InMemory(_) => PathBuf::new(),
             };
 
             WorkspaceDir::new(workspace_dir)
@@ -50,6 +49,10 @@ impl WorkspaceDirsBuilder {
         Ok(WorkspaceDirs::new(workspace_dir, peace_dir, peace_app_dir))
     }
 
+    fn working_dir() -> Result<PathBuf, NativeError> {
+        std::env::current_dir().map_err(NativeError::WorkingDirRead)
+    }
+
     fn first_dir_with_file(working_dir: &Path, path: &OsStr) -> Option<PathBuf> {
         let mut candidate_dir = working_dir.to_path_buf();
         loop {
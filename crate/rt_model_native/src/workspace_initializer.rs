@@ -1,14 +1,14 @@
 use std::{fmt::Debug, hash::Hash, path::Path};
 
-use futures::{stream, StreamExt, TryStreamExt};
-
+use peace_core::Profile;
 use peace_resource_rt::{
     internal::{FlowParamsFile, ProfileParamsFile, WorkspaceParamsFile},
+    paths::ProfileParentFile,
     type_reg::untagged::{TypeMapOpt, TypeReg},
 };
 use peace_rt_model_core::{
     params::{FlowParams, ProfileParams, WorkspaceParams},
-    Error, NativeError,
+    Error,
 };
 use serde::{de::DeserializeOwned, Serialize};
 
@@ -49,19 +49,15 @@ pub struct WorkspaceInitializer;
 
 impl WorkspaceInitializer {
     /// Creates directories used by the peace framework.
-    pub async fn dirs_create<'f, I>(dirs: I) -> Result<(), Error>
+    ///
+    /// Directories are only created if the storage backend stores files on
+    /// the file system.
+    pub async fn dirs_create<'f, I>(storage: &Storage, dirs: I) -> Result<(), Error>
     where
         I: IntoIterator<Item = &'f Path>,
     {
-        stream::iter(dirs)
-            .map(Result::<_, Error>::Ok)
-            .try_for_each(|dir| async move {
-                tokio::fs::create_dir_all(dir).await.map_err(|error| {
-                    let path = dir.to_path_buf();
-                    Error::Native(NativeError::WorkspaceDirCreate { path, error })
-                })
-            })
-            .await
+        let dirs = dirs.into_iter().collect::<Vec<&Path>>();
+        storage.backend().dirs_create(&dirs).await
     }
 
     pub async fn workspace_params_serialize<K>(
@@ -146,6 +142,44 @@ impl WorkspaceInitializer {
             })
     }
 
+    /// Writes the name of the profile's parent profile.
+    pub async fn profile_parent_serialize(
+        storage: &Storage,
+        profile_parent: &Profile,
+        profile_parent_file: &ProfileParentFile,
+    ) -> Result<(), Error> {
+        storage
+            .serialized_write(
+                "profile_parent_serialize".to_string(),
+                profile_parent_file,
+                profile_parent,
+                Error::ProfileParentSerialize,
+            )
+            .await
+    }
+
+    /// Returns the name of the profile's parent profile, if it has one.
+    pub async fn profile_parent_deserialize(
+        storage: &Storage,
+        profile_parent_file: &ProfileParentFile,
+    ) -> Result<Option<Profile>, Error> {
+        storage
+            .serialized_read_opt(
+                "profile_parent_deserialize".to_string(),
+                profile_parent_file,
+                Error::ProfileParentDeserialize,
+            )
+            .await
+    }
+
+    /// Removes the profile's parent profile, if it has one.
+    pub async fn profile_parent_remove(
+        storage: &Storage,
+        profile_parent_file: &ProfileParentFile,
+    ) -> Result<(), Error> {
+        storage.remove(profile_parent_file).await
+    }
+
     pub async fn flow_params_serialize<K>(
         storage: &Storage,
         flow_params: &FlowParams<K>,
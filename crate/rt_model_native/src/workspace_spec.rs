@@ -1,5 +1,7 @@
 use std::{ffi::OsString, path::PathBuf};
 
+use peace_rt_model_core::InMemoryStorageBackend;
+
 /// Describes how to discover the workspace directory.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum WorkspaceSpec {
@@ -19,4 +21,12 @@ pub enum WorkspaceSpec {
     /// The workspace directory is the parent directory that contains a file or
     /// directory with the provided name.
     FirstDirWithFile(OsString),
+    /// Store workspace files in memory.
+    ///
+    /// The workspace directory is an empty relative path, so files are stored
+    /// under `.peace/<app_name>/..` in the backend. Nothing is written to the
+    /// file system, and the working directory is not changed.
+    ///
+    /// A clone of the backend may be kept to inspect the stored files.
+    InMemory(InMemoryStorageBackend),
 }
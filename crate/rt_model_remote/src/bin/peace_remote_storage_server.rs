@@ -0,0 +1,23 @@
+//! Reference server for the `RemoteStorageBackend` HTTP API.
+//!
+//! Usage: `peace_remote_storage_server [ADDRESS]`
+//!
+//! `ADDRESS` defaults to `127.0.0.1:7878`.
+
+use peace_rt_model_remote::RemoteStorageServer;
+use tokio::net::TcpListener;
+
+#[tokio::main]
+async fn main() -> std::io::Result<()> {
+    let address = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| String::from("127.0.0.1:7878"));
+    let listener = TcpListener::bind(&address).await?;
+
+    eprintln!(
+        "Serving remote workspace on http://{}",
+        listener.local_addr()?
+    );
+
+    RemoteStorageServer::new().serve(listener).await
+}
@@ -0,0 +1,72 @@
+//! Remote workspace storage for the peace automation framework.
+//!
+//! [`RemoteStorageBackend`] stores workspace files -- params, params specs,
+//! and states -- behind an HTTP API, so that multiple users can share one
+//! source of truth for a workspace.
+//!
+//! ```rust,ignore
+//! let storage_backend = RemoteStorageBackend::new(Url::parse("http://localhost:7878/")?)?;
+//! storage_backend
+//!     .lock_acquire(String::from("azriel"), Duration::from_secs(300))
+//!     .await?;
+//!
+//! let workspace = Workspace::new(app_name!(), WorkspaceSpec::WorkingDir)?
+//!     .with_storage_backend(storage_backend);
+//! ```
+//!
+//! # HTTP API
+//!
+//! Items are keyed by their path from the `.peace` directory, e.g.
+//! `.peace/envman/dev/deploy/states_current.yaml`.
+//!
+//! * `GET /items/{key}`: Returns the item, with its version in the `ETag`
+//!   header. `404` if it does not exist.
+//! * `HEAD /items/{key}`: Same as `GET`, without the body.
+//! * `PUT /items/{key}`: Stores the request body as the item.
+//! * `DELETE /items/{key}`: Removes the item.
+//! * `GET /items?prefix={dir_key}`: Returns the keys of the items under the
+//!   directory as a sorted JSON array.
+//! * `DELETE /items?prefix={dir_key}`: Removes the items under the directory.
+//! * `POST /lock`: Acquires or renews the lease on the workspace, given a JSON
+//!   [`RemoteLeaseRequest`]. The lease is renewed if the `x-peace-lease` header
+//!   contains its ID. Returns the [`RemoteLease`], or `423` with the
+//!   [`RemoteLeaseHeld`] if it is held and the header does not contain its ID.
+//! * `DELETE /lock/{lease_id}`: Releases the lease.
+//!
+//! `PUT` and `DELETE` requests:
+//!
+//! * Fail with `412` if the `If-Match` header does not match the item's `ETag`,
+//!   or if `If-None-Match: *` is sent and the item exists. Directory removals
+//!   do not check item versions.
+//! * Fail with `423` if the workspace lease is held, and the `x-peace-lease`
+//!   header does not contain its ID. The response contains the
+//!   [`RemoteLeaseHeld`], which does not include the lease ID.
+//!
+//! # Reference Server
+//!
+//! With the `"server"` feature, [`RemoteStorageServer`] implements this API,
+//! and the `peace_remote_storage_server` binary runs it:
+//!
+//! ```bash
+//! cargo run -p peace_rt_model_remote --features server -- 127.0.0.1:7878
+//! ```
+
+// Re-exports
+pub use reqwest::Url;
+
+pub use crate::{
+    remote_error::RemoteError, remote_lease::RemoteLease, remote_lease_held::RemoteLeaseHeld,
+    remote_lease_request::RemoteLeaseRequest, remote_storage_backend::RemoteStorageBackend,
+};
+
+#[cfg(feature = "server")]
+pub use crate::remote_storage_server::RemoteStorageServer;
+
+mod remote_error;
+mod remote_lease;
+mod remote_lease_held;
+mod remote_lease_request;
+mod remote_storage_backend;
+
+#[cfg(feature = "server")]
+mod remote_storage_server;
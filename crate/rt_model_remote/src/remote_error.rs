@@ -0,0 +1,145 @@
+use std::path::PathBuf;
+
+use crate::RemoteLeaseHeld;
+
+/// Errors when accessing a remote workspace.
+#[cfg_attr(feature = "error_reporting", derive(miette::Diagnostic))]
+#[derive(Debug, thiserror::Error)]
+pub enum RemoteError {
+    /// Base URL for the remote workspace cannot have path segments.
+    #[error("Base URL for the remote workspace cannot have path segments: `{base_url}`.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model_remote::base_url_cannot_be_a_base),
+            help("Use a URL such as `http://localhost:7878/`.")
+        )
+    )]
+    BaseUrlCannotBeABase {
+        /// The base URL.
+        base_url: String,
+    },
+
+    /// Failed to send request to the remote workspace.
+    #[error("Failed to send `{method}` request to `{url}`.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model_remote::request))
+    )]
+    Request {
+        /// HTTP method of the request.
+        method: String,
+        /// URL of the request.
+        url: String,
+        /// Underlying HTTP client error.
+        #[source]
+        error: reqwest::Error,
+    },
+
+    /// Remote workspace responded with an unexpected status.
+    #[error("Remote workspace responded to `{method} {url}` with status `{status}`: {body}")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model_remote::response_status))
+    )]
+    ResponseStatus {
+        /// HTTP method of the request.
+        method: String,
+        /// URL of the request.
+        url: String,
+        /// HTTP status code of the response.
+        status: u16,
+        /// Body of the response.
+        body: String,
+    },
+
+    /// Failed to read response body from the remote workspace.
+    #[error("Failed to read response body from `{url}`.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model_remote::response_body_read))
+    )]
+    ResponseBodyRead {
+        /// URL of the request.
+        url: String,
+        /// Underlying HTTP client error.
+        #[source]
+        error: reqwest::Error,
+    },
+
+    /// Item was changed in the remote workspace since it was read.
+    #[error("`{}` was changed in the remote workspace since it was read.", path.display())]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model_remote::version_conflict),
+            help(
+                "Someone else has changed the workspace.\n\
+                Run the command again to use the latest stored values."
+            )
+        )
+    )]
+    VersionConflict {
+        /// Path to the item.
+        path: PathBuf,
+    },
+
+    /// Remote workspace is locked by someone else.
+    #[error("Remote workspace is locked by `{}`.", lease_held.holder)]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(
+            code(peace_rt_model_remote::locked),
+            help(
+                "Wait for the lease to be released or expire, which is in {}ms.",
+                lease_held.ttl_remaining_ms
+            )
+        )
+    )]
+    Locked {
+        /// The lease held on the workspace.
+        lease_held: RemoteLeaseHeld,
+    },
+
+    /// Failed to serialize lease request.
+    #[error("Failed to serialize lease request.")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model_remote::lease_request_serialize))
+    )]
+    LeaseRequestSerialize(#[source] serde_json::Error),
+
+    /// Failed to deserialize lease from the remote workspace.
+    #[error("Failed to deserialize lease from the remote workspace: {body}")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model_remote::lease_deserialize))
+    )]
+    LeaseDeserialize {
+        /// Body of the response.
+        body: String,
+        /// Underlying deserialization error.
+        #[source]
+        error: serde_json::Error,
+    },
+
+    /// Failed to deserialize item keys from the remote workspace.
+    #[error("Failed to deserialize item keys from the remote workspace: {body}")]
+    #[cfg_attr(
+        feature = "error_reporting",
+        diagnostic(code(peace_rt_model_remote::item_keys_deserialize))
+    )]
+    ItemKeysDeserialize {
+        /// Body of the response.
+        body: String,
+        /// Underlying deserialization error.
+        #[source]
+        error: serde_json::Error,
+    },
+}
+
+impl From<RemoteError> for peace_rt_model_core::Error {
+    fn from(remote_error: RemoteError) -> Self {
+        peace_rt_model_core::Error::StorageBackend(Box::new(remote_error))
+    }
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+/// Lease on the lock of a remote workspace.
+///
+/// While a lease is held, only requests with the lease ID may write to or
+/// remove items from the workspace. The lease expires after `ttl_ms`, unless
+/// it is renewed by acquiring the lock again with the lease ID.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemoteLease {
+    /// ID of the lease, sent with each write request.
+    pub lease_id: String,
+    /// Name of who holds the lease, e.g. a username.
+    pub holder: String,
+    /// Number of milliseconds from when the lease was acquired until it
+    /// expires.
+    pub ttl_ms: u64,
+}
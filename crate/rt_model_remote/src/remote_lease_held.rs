@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// Lease on the lock of a remote workspace, as seen by clients that do not
+/// hold it.
+///
+/// This is returned with `423` responses. It does not contain the lease ID, so
+/// it cannot be used to write to the workspace or release the lock.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemoteLeaseHeld {
+    /// Name of who holds the lease, e.g. a username.
+    pub holder: String,
+    /// Number of milliseconds until the lease expires, unless it is renewed.
+    pub ttl_remaining_ms: u64,
+}
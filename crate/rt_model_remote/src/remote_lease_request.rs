@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// Request to acquire or renew the lock of a remote workspace.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemoteLeaseRequest {
+    /// Name of who is acquiring the lease, e.g. a username.
+    pub holder: String,
+    /// Number of milliseconds until the lease expires.
+    pub ttl_ms: u64,
+}
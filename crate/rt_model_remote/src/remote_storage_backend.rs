@@ -0,0 +1,441 @@
+use std::{
+    collections::HashMap,
+    path::{Component, Path, PathBuf},
+    sync::{Mutex, MutexGuard, PoisonError},
+    time::Duration,
+};
+
+use peace_resource_rt::paths::PeaceDir;
+use peace_rt_model_core::{async_trait, Error, StorageBackend};
+use reqwest::{
+    header::{CONTENT_TYPE, ETAG, IF_MATCH, IF_NONE_MATCH},
+    Client, Method, RequestBuilder, Response, StatusCode, Url,
+};
+use serde::de::DeserializeOwned;
+
+use crate::{RemoteError, RemoteLease, RemoteLeaseRequest};
+
+/// Stores workspace files in a remote workspace over HTTP.
+///
+/// Items are keyed by their path from the `.peace` directory, so users with
+/// different workspace directories share the same items.
+///
+/// # Concurrency
+///
+/// The version of each item is tracked from its `ETag` when it is read or
+/// written. Writes and removals of a tracked item only succeed if the remote
+/// item has not changed since, otherwise [`RemoteError::VersionConflict`] is
+/// returned. Writes of an item that has not been read only succeed if the
+/// item does not exist in the remote workspace.
+///
+/// Removing a directory removes all items under it, regardless of their
+/// versions.
+///
+/// [`lock_acquire`] takes a lease on the workspace, so that only this backend
+/// may change items until the lease is released or expires.
+///
+/// See the crate documentation for the HTTP API.
+///
+/// [`lock_acquire`]: Self::lock_acquire
+#[derive(Debug)]
+pub struct RemoteStorageBackend {
+    /// Base URL of the remote workspace.
+    base_url: Url,
+    /// HTTP client.
+    client: Client,
+    /// `ETag` of each item last read or written, `None` if it did not exist.
+    etags: Mutex<HashMap<String, Option<String>>>,
+    /// ID of the lease held on the workspace lock, if any.
+    lease_id: Mutex<Option<String>>,
+}
+
+impl RemoteStorageBackend {
+    /// Header used to send the lease ID with write requests.
+    pub const LEASE_HEADER: &'static str = "x-peace-lease";
+
+    /// Returns a new `RemoteStorageBackend` for the remote workspace at the
+    /// given base URL.
+    pub fn new(base_url: Url) -> Result<Self, RemoteError> {
+        if base_url.cannot_be_a_base() {
+            return Err(RemoteError::BaseUrlCannotBeABase {
+                base_url: base_url.to_string(),
+            });
+        }
+
+        Ok(Self {
+            base_url,
+            client: Client::new(),
+            etags: Mutex::new(HashMap::new()),
+            lease_id: Mutex::new(None),
+        })
+    }
+
+    /// Returns the base URL of the remote workspace.
+    pub fn base_url(&self) -> &Url {
+        &self.base_url
+    }
+
+    /// Acquires or renews the lock on the remote workspace.
+    ///
+    /// Acquiring the lock while this backend holds the lease renews it. Other
+    /// backends are not able to acquire or renew the lease until it is
+    /// released or expires, even if they use the same `holder`.
+    ///
+    /// # Parameters
+    ///
+    /// * `holder`: Name of who is acquiring the lease, e.g. a username.
+    /// * `ttl`: Duration until the lease expires.
+    pub async fn lock_acquire(
+        &self,
+        holder: String,
+        ttl: Duration,
+    ) -> Result<RemoteLease, RemoteError> {
+        let lease_request = RemoteLeaseRequest {
+            holder,
+            ttl_ms: u64::try_from(ttl.as_millis()).unwrap_or(u64::MAX),
+        };
+        let body =
+            serde_json::to_vec(&lease_request).map_err(RemoteError::LeaseRequestSerialize)?;
+        let url = self.url(["lock"]);
+        let request = self.with_lease(
+            self.client
+                .post(url.clone())
+                .header(CONTENT_TYPE, "application/json")
+                .body(body),
+        );
+        let response = self.send(Method::POST, &url, request).await?;
+
+        match response.status() {
+            StatusCode::OK => {
+                let lease: RemoteLease = Self::lease_from_response(&url, response).await?;
+                *self.lease_id_guard() = Some(lease.lease_id.clone());
+                Ok(lease)
+            }
+            StatusCode::LOCKED => {
+                let lease_held = Self::lease_from_response(&url, response).await?;
+                Err(RemoteError::Locked { lease_held })
+            }
+            _ => Err(Self::response_status_error(Method::POST, &url, response).await),
+        }
+    }
+
+    /// Releases the lock on the remote workspace, if this backend holds it.
+    pub async fn lock_release(&self) -> Result<(), RemoteError> {
+        let Some(lease_id) = self.lease_id_guard().clone() else {
+            return Ok(());
+        };
+        let url = self.url(["lock", lease_id.as_str()]);
+        let response = self
+            .send(Method::DELETE, &url, self.client.delete(url.clone()))
+            .await?;
+
+        match response.status() {
+            // `NOT_FOUND` means the lease already expired.
+            StatusCode::NO_CONTENT | StatusCode::NOT_FOUND => {
+                *self.lease_id_guard() = None;
+                Ok(())
+            }
+            _ => Err(Self::response_status_error(Method::DELETE, &url, response).await),
+        }
+    }
+
+    /// Returns the key for the item at the given path.
+    ///
+    /// This is the path from the `.peace` directory, or the normal components
+    /// of the path if it is not within a `.peace` directory.
+    fn key(path: &Path) -> Vec<String> {
+        let is_normal = |component: &Component<'_>| matches!(component, Component::Normal(_));
+        let from_peace_dir = path
+            .components()
+            .skip_while(|component| component.as_os_str() != PeaceDir::NAME)
+            .filter(is_normal)
+            .collect::<Vec<_>>();
+        let components = if from_peace_dir.is_empty() {
+            path.components().filter(is_normal).collect::<Vec<_>>()
+        } else {
+            from_peace_dir
+        };
+
+        components
+            .into_iter()
+            .map(|component| component.as_os_str().to_string_lossy().into_owned())
+            .collect()
+    }
+
+    /// Returns the URL with the given path segments appended to the base URL.
+    fn url<'s, I>(&self, segments: I) -> Url
+    where
+        I: IntoIterator<Item = &'s str>,
+    {
+        let mut url = self.base_url.clone();
+        url.path_segments_mut()
+            .expect("Checked that `base_url` can be a base in `RemoteStorageBackend::new`.")
+            .pop_if_empty()
+            .extend(segments);
+        url
+    }
+
+    /// Returns the key and URL for the item at the given path.
+    fn item_key_and_url(&self, path: &Path) -> (String, Url) {
+        let key = Self::key(path);
+        let url = self.url(std::iter::once("items").chain(key.iter().map(String::as_str)));
+        (key.join("/"), url)
+    }
+
+    /// Returns the key of the directory at the given path, and the URL for
+    /// the items under it.
+    fn dir_key_and_url(&self, dir: &Path) -> (String, Url) {
+        let key = Self::key(dir).join("/");
+        let mut url = self.url(["items"]);
+        url.query_pairs_mut().append_pair("prefix", &key);
+        (key, url)
+    }
+
+    /// Adds the version precondition and lease headers for writing an item.
+    ///
+    /// If the item's version is not known, it is only written if it does not
+    /// exist, so that items written by someone else are not overwritten.
+    fn with_write_preconditions(&self, key: &str, request: RequestBuilder) -> RequestBuilder {
+        let request = match self.etags_guard().get(key) {
+            Some(Some(etag)) => request.header(IF_MATCH, etag),
+            Some(None) | None => request.header(IF_NONE_MATCH, "*"),
+        };
+        self.with_lease(request)
+    }
+
+    /// Adds the version precondition and lease headers for removing an item.
+    fn with_remove_preconditions(&self, key: &str, request: RequestBuilder) -> RequestBuilder {
+        let request = match self.etags_guard().get(key) {
+            Some(Some(etag)) => request.header(IF_MATCH, etag),
+            Some(None) => request.header(IF_NONE_MATCH, "*"),
+            None => request,
+        };
+        self.with_lease(request)
+    }
+
+    /// Adds the lease header, if this backend holds the lease.
+    fn with_lease(&self, request: RequestBuilder) -> RequestBuilder {
+        match self.lease_id_guard().as_deref() {
+            Some(lease_id) => request.header(Self::LEASE_HEADER, lease_id),
+            None => request,
+        }
+    }
+
+    /// Records the `ETag` of an item from the response.
+    fn etag_record(&self, key: String, response: &Response) {
+        let etag = response
+            .headers()
+            .get(ETAG)
+            .and_then(|etag| etag.to_str().ok())
+            .map(String::from);
+        self.etags_guard().insert(key, etag);
+    }
+
+    /// Maps the response to a changing request into an error if it failed.
+    async fn change_response_check(
+        &self,
+        method: Method,
+        url: &Url,
+        path: &Path,
+        response: Response,
+    ) -> Result<Response, RemoteError> {
+        match response.status() {
+            status if status.is_success() => Ok(response),
+            StatusCode::PRECONDITION_FAILED => Err(RemoteError::VersionConflict {
+                path: path.to_path_buf(),
+            }),
+            StatusCode::LOCKED => {
+                let lease_held = Self::lease_from_response(url, response).await?;
+                Err(RemoteError::Locked { lease_held })
+            }
+            _ => Err(Self::response_status_error(method, url, response).await),
+        }
+    }
+
+    async fn send(
+        &self,
+        method: Method,
+        url: &Url,
+        request: RequestBuilder,
+    ) -> Result<Response, RemoteError> {
+        request.send().await.map_err(|error| RemoteError::Request {
+            method: method.to_string(),
+            url: url.to_string(),
+            error,
+        })
+    }
+
+    async fn response_bytes(url: &Url, response: Response) -> Result<Vec<u8>, RemoteError> {
+        response
+            .bytes()
+            .await
+            .map(|bytes| bytes.to_vec())
+            .map_err(|error| RemoteError::ResponseBodyRead {
+                url: url.to_string(),
+                error,
+            })
+    }
+
+    async fn item_keys_from_response(
+        url: &Url,
+        response: Response,
+    ) -> Result<Vec<String>, RemoteError> {
+        let bytes = Self::response_bytes(url, response).await?;
+        serde_json::from_slice(&bytes).map_err(|error| RemoteError::ItemKeysDeserialize {
+            body: String::from_utf8_lossy(&bytes).into_owned(),
+            error,
+        })
+    }
+
+    async fn lease_from_response<T>(url: &Url, response: Response) -> Result<T, RemoteError>
+    where
+        T: DeserializeOwned,
+    {
+        let bytes = Self::response_bytes(url, response).await?;
+        serde_json::from_slice(&bytes).map_err(|error| RemoteError::LeaseDeserialize {
+            body: String::from_utf8_lossy(&bytes).into_owned(),
+            error,
+        })
+    }
+
+    async fn response_status_error(method: Method, url: &Url, response: Response) -> RemoteError {
+        let status = response.status().as_u16();
+        let body = Self::response_bytes(url, response)
+            .await
+            .map(|bytes| String::from_utf8_lossy(&bytes).into_owned())
+            .unwrap_or_default();
+
+        RemoteError::ResponseStatus {
+            method: method.to_string(),
+            url: url.to_string(),
+            status,
+            body,
+        }
+    }
+
+    fn etags_guard(&self) -> MutexGuard<'_, HashMap<String, Option<String>>> {
+        self.etags.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn lease_id_guard(&self) -> MutexGuard<'_, Option<String>> {
+        self.lease_id.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[async_trait(?Send)]
+impl StorageBackend for RemoteStorageBackend {
+    async fn contains_item(&self, path: &Path) -> Result<bool, Error> {
+        let (key, url) = self.item_key_and_url(path);
+        let response = self
+            .send(Method::HEAD, &url, self.client.head(url.clone()))
+            .await?;
+
+        match response.status() {
+            StatusCode::OK => {
+                self.etag_record(key, &response);
+                Ok(true)
+            }
+            StatusCode::NOT_FOUND => {
+                self.etags_guard().insert(key, None);
+                Ok(false)
+            }
+            _ => Err(Self::response_status_error(Method::HEAD, &url, response)
+                .await
+                .into()),
+        }
+    }
+
+    async fn read_opt(&self, path: &Path) -> Result<Option<Vec<u8>>, Error> {
+        let (key, url) = self.item_key_and_url(path);
+        let response = self
+            .send(Method::GET, &url, self.client.get(url.clone()))
+            .await?;
+
+        match response.status() {
+            StatusCode::OK => {
+                self.etag_record(key, &response);
+                let bytes = Self::response_bytes(&url, response).await?;
+                Ok(Some(bytes))
+            }
+            StatusCode::NOT_FOUND => {
+                self.etags_guard().insert(key, None);
+                Ok(None)
+            }
+            _ => Err(Self::response_status_error(Method::GET, &url, response)
+                .await
+                .into()),
+        }
+    }
+
+    async fn write(&self, path: &Path, bytes: &[u8]) -> Result<(), Error> {
+        let (key, url) = self.item_key_and_url(path);
+        let request =
+            self.with_write_preconditions(&key, self.client.put(url.clone()).body(bytes.to_vec()));
+        let response = self.send(Method::PUT, &url, request).await?;
+        let response = self
+            .change_response_check(Method::PUT, &url, path, response)
+            .await?;
+
+        self.etag_record(key, &response);
+        Ok(())
+    }
+
+    async fn remove(&self, path: &Path) -> Result<(), Error> {
+        let (key, url) = self.item_key_and_url(path);
+        let request = self.with_remove_preconditions(&key, self.client.delete(url.clone()));
+        let response = self.send(Method::DELETE, &url, request).await?;
+
+        if response.status() != StatusCode::NOT_FOUND {
+            self.change_response_check(Method::DELETE, &url, path, response)
+                .await?;
+        }
+
+        self.etags_guard().insert(key, None);
+        Ok(())
+    }
+
+    async fn paths_under(&self, dir: &Path) -> Result<Vec<PathBuf>, Error> {
+        let (dir_key, url) = self.dir_key_and_url(dir);
+        let response = self
+            .send(Method::GET, &url, self.client.get(url.clone()))
+            .await?;
+        if response.status() != StatusCode::OK {
+            return Err(Self::response_status_error(Method::GET, &url, response)
+                .await
+                .into());
+        }
+        let keys = Self::item_keys_from_response(&url, response).await?;
+
+        // Keys are relative to the `.peace` directory, so paths are built from
+        // `dir`, which may be in a different workspace directory.
+        let paths = keys
+            .iter()
+            .filter_map(|key| {
+                let key_relative = if dir_key.is_empty() {
+                    Some(key.as_str())
+                } else {
+                    key.strip_prefix(dir_key.as_str())
+                        .and_then(|rest| rest.strip_prefix('/'))
+                };
+                key_relative.map(|key_relative| dir.join(key_relative))
+            })
+            .collect::<Vec<_>>();
+
+        Ok(paths)
+    }
+
+    async fn dir_remove(&self, dir: &Path) -> Result<(), Error> {
+        let (dir_key, url) = self.dir_key_and_url(dir);
+        let request = self.with_lease(self.client.delete(url.clone()));
+        let response = self.send(Method::DELETE, &url, request).await?;
+        self.change_response_check(Method::DELETE, &url, dir, response)
+            .await?;
+
+        let dir_key_prefix = format!("{dir_key}/");
+        self.etags_guard()
+            .iter_mut()
+            .filter(|(key, _)| dir_key.is_empty() || key.starts_with(&dir_key_prefix))
+            .for_each(|(_, etag)| *etag = None);
+        Ok(())
+    }
+}
@@ -0,0 +1,319 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+use axum::{
+    body::Bytes,
+    extract::{Path, Query, State},
+    http::{
+        header::{ETAG, IF_MATCH, IF_NONE_MATCH},
+        HeaderMap, StatusCode,
+    },
+    response::{IntoResponse, Response},
+    routing::{delete, get, post},
+    Json, Router,
+};
+use serde::Deserialize;
+use tokio::net::TcpListener;
+
+use crate::{RemoteLease, RemoteLeaseHeld, RemoteLeaseRequest, RemoteStorageBackend};
+
+/// Reference server for the [`RemoteStorageBackend`] HTTP API.
+///
+/// Items and the lease are held in memory, so they are lost when the server
+/// stops. This is intended for trying out remote workspaces locally, and for
+/// tests.
+#[derive(Clone, Debug, Default)]
+pub struct RemoteStorageServer {
+    /// Items and lease of the workspace.
+    state: Arc<Mutex<ServerState>>,
+}
+
+/// Items and lease of the workspace.
+#[derive(Debug, Default)]
+struct ServerState {
+    /// Items keyed by their path from the `.peace` directory.
+    items: HashMap<String, StoredItem>,
+    /// Version to assign to the next written item.
+    version_next: u64,
+    /// Lease on the workspace lock, if any.
+    lease: Option<(RemoteLease, Instant)>,
+}
+
+/// Item stored in the workspace.
+#[derive(Debug)]
+struct StoredItem {
+    /// Version of the item, used as its `ETag`.
+    version: u64,
+    /// Bytes of the item.
+    bytes: Bytes,
+}
+
+/// Query parameters for requests on the items under a directory.
+#[derive(Debug, Deserialize)]
+struct ItemsQuery {
+    /// Key of the directory, i.e. its path from the `.peace` directory.
+    prefix: String,
+}
+
+impl ItemsQuery {
+    /// Returns whether the item with the given key is under the directory.
+    fn contains(&self, key: &str) -> bool {
+        let prefix = self.prefix.trim_end_matches('/');
+        prefix.is_empty()
+            || key
+                .strip_prefix(prefix)
+                .is_some_and(|rest| rest.starts_with('/'))
+    }
+}
+
+impl RemoteStorageServer {
+    /// Returns a new `RemoteStorageServer` with no items.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the router that serves the HTTP API.
+    pub fn router(&self) -> Router {
+        Router::new()
+            .route("/items", get(Self::items_list).delete(Self::items_delete))
+            .route(
+                "/items/*path",
+                get(Self::item_get)
+                    .put(Self::item_put)
+                    .delete(Self::item_delete),
+            )
+            .route("/lock", post(Self::lock_acquire))
+            .route("/lock/:lease_id", delete(Self::lock_release))
+            .with_state(self.clone())
+    }
+
+    /// Serves the HTTP API on the given listener until the process stops.
+    pub async fn serve(self, listener: TcpListener) -> std::io::Result<()> {
+        axum::serve(listener, self.router()).await
+    }
+
+    async fn item_get(State(server): State<Self>, Path(key): Path<String>) -> Response {
+        let state = server.state_guard();
+        match state.items.get(&key) {
+            Some(stored_item) => (
+                [(ETAG, Self::etag(stored_item.version))],
+                stored_item.bytes.clone(),
+            )
+                .into_response(),
+            None => StatusCode::NOT_FOUND.into_response(),
+        }
+    }
+
+    async fn item_put(
+        State(server): State<Self>,
+        Path(key): Path<String>,
+        headers: HeaderMap,
+        bytes: Bytes,
+    ) -> Response {
+        let mut state = server.state_guard();
+        if let Some(response) = state.change_rejection(&key, &headers) {
+            return response;
+        }
+
+        let version = state.version_next;
+        state.version_next += 1;
+        let status = match state.items.insert(key, StoredItem { version, bytes }) {
+            Some(_) => StatusCode::OK,
+            None => StatusCode::CREATED,
+        };
+
+        (status, [(ETAG, Self::etag(version))]).into_response()
+    }
+
+    async fn item_delete(
+        State(server): State<Self>,
+        Path(key): Path<String>,
+        headers: HeaderMap,
+    ) -> Response {
+        let mut state = server.state_guard();
+        if let Some(response) = state.change_rejection(&key, &headers) {
+            return response;
+        }
+
+        match state.items.remove(&key) {
+            Some(_) => StatusCode::NO_CONTENT.into_response(),
+            None => StatusCode::NOT_FOUND.into_response(),
+        }
+    }
+
+    async fn items_list(
+        State(server): State<Self>,
+        Query(items_query): Query<ItemsQuery>,
+    ) -> Json<Vec<String>> {
+        let state = server.state_guard();
+        let mut keys = state
+            .items
+            .keys()
+            .filter(|key| items_query.contains(key))
+            .cloned()
+            .collect::<Vec<_>>();
+        keys.sort();
+
+        Json(keys)
+    }
+
+    async fn items_delete(
+        State(server): State<Self>,
+        Query(items_query): Query<ItemsQuery>,
+        headers: HeaderMap,
+    ) -> Response {
+        let mut state = server.state_guard();
+        if let Some(response) = state.lease_rejection(&headers) {
+            return response;
+        }
+
+        state.items.retain(|key, _| !items_query.contains(key));
+
+        StatusCode::NO_CONTENT.into_response()
+    }
+
+    /// Returns a new lease ID from 128 random bits, hex encoded.
+    ///
+    /// The lease ID is the only credential a client presents to write through
+    /// or release the lock, so it must not be guessable.
+    fn lease_id_generate() -> Result<String, getrandom::Error> {
+        let mut lease_id_bytes = [0u8; 16];
+        getrandom::getrandom(&mut lease_id_bytes)?;
+
+        Ok(lease_id_bytes
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<String>())
+    }
+
+    async fn lock_acquire(
+        State(server): State<Self>,
+        headers: HeaderMap,
+        Json(lease_request): Json<RemoteLeaseRequest>,
+    ) -> Response {
+        let mut state = server.state_guard();
+        let RemoteLeaseRequest { holder, ttl_ms } = lease_request;
+        let lease_id_request = headers
+            .get(RemoteStorageBackend::LEASE_HEADER)
+            .and_then(|value| value.to_str().ok());
+
+        // The lease is only renewed for the client that holds its ID, even if
+        // another client uses the same holder name.
+        let lease_id = match state.lease_active() {
+            Some((lease, _)) if lease_id_request == Some(lease.lease_id.as_str()) => {
+                lease.lease_id.clone()
+            }
+            Some((lease, expires_at)) => return Self::locked_response(lease, expires_at),
+            None => match Self::lease_id_generate() {
+                Ok(lease_id) => lease_id,
+                Err(_) => return StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+            },
+        };
+
+        let lease = RemoteLease {
+            lease_id,
+            holder,
+            ttl_ms,
+        };
+        let expires_at = Instant::now() + Duration::from_millis(ttl_ms);
+        state.lease = Some((lease.clone(), expires_at));
+
+        Json(lease).into_response()
+    }
+
+    async fn lock_release(State(server): State<Self>, Path(lease_id): Path<String>) -> StatusCode {
+        let mut state = server.state_guard();
+        match state.lease_active() {
+            Some((lease, _)) if lease.lease_id == lease_id => {
+                state.lease = None;
+                StatusCode::NO_CONTENT
+            }
+            _ => StatusCode::NOT_FOUND,
+        }
+    }
+
+    /// Returns the `423` response for a lease held by someone else.
+    ///
+    /// Only the holder and remaining time are returned, as the lease ID is
+    /// the credential to write through or release the lock.
+    fn locked_response(lease: &RemoteLease, expires_at: Instant) -> Response {
+        let ttl_remaining = expires_at.saturating_duration_since(Instant::now());
+        let lease_held = RemoteLeaseHeld {
+            holder: lease.holder.clone(),
+            ttl_remaining_ms: u64::try_from(ttl_remaining.as_millis()).unwrap_or(u64::MAX),
+        };
+
+        (StatusCode::LOCKED, Json(lease_held)).into_response()
+    }
+
+    fn etag(version: u64) -> String {
+        format!("\"{version}\"")
+    }
+
+    fn state_guard(&self) -> MutexGuard<'_, ServerState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl ServerState {
+    /// Returns the lease and when it expires if it has not expired, clearing
+    /// it otherwise.
+    fn lease_active(&mut self) -> Option<(&RemoteLease, Instant)> {
+        if let Some((_, expires_at)) = self.lease.as_ref() {
+            if *expires_at <= Instant::now() {
+                self.lease = None;
+            }
+        }
+        self.lease
+            .as_ref()
+            .map(|(lease, expires_at)| (lease, *expires_at))
+    }
+
+    /// Returns the response to reject a change with, if the lease is held and
+    /// the request does not hold it.
+    fn lease_rejection(&mut self, headers: &HeaderMap) -> Option<Response> {
+        let lease_id = headers
+            .get(RemoteStorageBackend::LEASE_HEADER)
+            .and_then(|value| value.to_str().ok());
+
+        match self.lease_active() {
+            Some((lease, expires_at)) if lease_id != Some(lease.lease_id.as_str()) => {
+                Some(RemoteStorageServer::locked_response(lease, expires_at))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the response to reject a change with, if the request does not
+    /// hold the lease, or its version preconditions do not match the stored
+    /// item.
+    fn change_rejection(&mut self, key: &str, headers: &HeaderMap) -> Option<Response> {
+        let header_str = |name| headers.get(name).and_then(|value| value.to_str().ok());
+
+        if let Some(response) = self.lease_rejection(headers) {
+            return Some(response);
+        }
+
+        let etag = self
+            .items
+            .get(key)
+            .map(|stored_item| RemoteStorageServer::etag(stored_item.version));
+        let if_match_ok = match header_str(IF_MATCH.as_str()) {
+            Some(if_match) => etag.as_deref() == Some(if_match),
+            None => true,
+        };
+        let if_none_match_ok = match header_str(IF_NONE_MATCH.as_str()) {
+            Some("*") => etag.is_none(),
+            _ => true,
+        };
+
+        if if_match_ok && if_none_match_ok {
+            None
+        } else {
+            Some(StatusCode::PRECONDITION_FAILED.into_response())
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
     fmt::Debug,
-    hash::Hash,
+    hash::{Hash, Hasher},
     path::{Path, PathBuf},
+    sync::{Arc, Mutex},
 };
 
 use base64::Engine;
@@ -9,17 +11,41 @@ use peace_resource_rt::type_reg::{
     common::UnknownEntriesSome,
     untagged::{DataTypeWrapper, TypeMapOpt, TypeReg},
 };
-use peace_rt_model_core::{Error, WebError};
+use peace_rt_model_core::{
+    async_trait, Error, SerializationError, SerializationFormat, StatesLayout, StorageBackend,
+    StorageEncryption, WebError,
+};
 use serde::{de::DeserializeOwned, Serialize};
 use wasm_bindgen::prelude::*;
 
 use crate::WorkspaceSpec;
 
 /// Wrapper to retrieve `web_sys::Storage` on demand.
+///
+/// Workspace items are stored in the browser storage, unless a different
+/// backend is set with [`with_storage_backend`].
+///
+/// Items are written in the [`SerializationFormat`] set on this `Storage`,
+/// with the format's file extension. When reading, items in other formats are
+/// read if the item does not exist in this `Storage`'s format.
+///
+/// [`with_storage_backend`]: Self::with_storage_backend
 #[derive(Clone, Debug)]
 pub struct Storage {
     /// Describes how to store peace automation data.
     workspace_spec: WorkspaceSpec,
+    /// Backend that stores workspace items instead of the browser storage,
+    /// if set.
+    backend: Option<Arc<dyn StorageBackend>>,
+    /// Format to serialize items in.
+    serialization_format: SerializationFormat,
+    /// How stored states are laid out in a flow directory.
+    states_layout: StatesLayout,
+    /// Encrypts and decrypts sensitive items, if set.
+    encryption: Option<StorageEncryption>,
+    /// Digests of the plaintext of items last read or written through this
+    /// `Storage`, so unchanged items are not written again.
+    item_digests: Arc<Mutex<HashMap<PathBuf, u64>>>,
 }
 
 #[wasm_bindgen(module = "/js/workspace.js")]
@@ -33,7 +59,80 @@ extern "C" {
 impl Storage {
     /// Returns a new `Storage`.
     pub fn new(workspace_spec: WorkspaceSpec) -> Self {
-        Self { workspace_spec }
+        Self {
+            workspace_spec,
+            backend: None,
+            serialization_format: SerializationFormat::default(),
+            states_layout: StatesLayout::default(),
+            encryption: None,
+            item_digests: Arc::default(),
+        }
+    }
+
+    /// Sets the backend that stores workspace items, instead of the browser
+    /// storage.
+    ///
+    /// The browser storage is still returned by [`get`], for items that store
+    /// their own data in it.
+    ///
+    /// [`get`]: Self::get
+    pub fn with_storage_backend<B>(mut self, backend: B) -> Self
+    where
+        B: StorageBackend,
+    {
+        self.backend = Some(Arc::new(backend));
+        self.item_digests = Arc::default();
+        self
+    }
+
+    /// Returns the backend that stores workspace items.
+    ///
+    /// This is the browser storage unless a different backend was set with
+    /// [`with_storage_backend`].
+    ///
+    /// [`with_storage_backend`]: Self::with_storage_backend
+    pub fn backend(&self) -> &dyn StorageBackend {
+        match self.backend.as_deref() {
+            Some(backend) => backend,
+            None => self,
+        }
+    }
+
+    /// Sets the format to serialize items in.
+    ///
+    /// Browser storage only stores strings, so
+    /// [`SerializationFormat::MessagePack`] may only be used with browser
+    /// storage when encryption is set.
+    pub fn with_serialization_format(mut self, serialization_format: SerializationFormat) -> Self {
+        self.serialization_format = serialization_format;
+        self
+    }
+
+    /// Returns the format that items are serialized in.
+    pub fn serialization_format(&self) -> SerializationFormat {
+        self.serialization_format
+    }
+
+    /// Sets how stored states are laid out in a flow directory.
+    pub fn with_states_layout(mut self, states_layout: StatesLayout) -> Self {
+        self.states_layout = states_layout;
+        self
+    }
+
+    /// Returns how stored states are laid out in a flow directory.
+    pub fn states_layout(&self) -> StatesLayout {
+        self.states_layout
+    }
+
+    /// Sets the encryption used for sensitive items.
+    pub fn with_encryption(mut self, encryption: StorageEncryption) -> Self {
+        self.encryption = Some(encryption);
+        self
+    }
+
+    /// Returns the encryption used for sensitive items, if set.
+    pub fn encryption(&self) -> Option<&StorageEncryption> {
+        self.encryption.as_ref()
     }
 
     /// Returns the browser storage used for the workspace.
@@ -357,10 +456,15 @@ impl Storage {
     ) -> Result<Option<T>, Error>
     where
         T: DeserializeOwned + Send + Sync,
-        F: FnOnce(serde_yaml::Error) -> Error + Send,
+        F: FnOnce(SerializationError) -> Error + Send,
     {
-        self.get_item_opt(path)?
-            .map(|s| serde_yaml::from_str::<T>(&s).map_err(f_map_err))
+        self.get_item_plaintext_opt(path, false)
+            .await?
+            .map(|(serialization_format, bytes)| {
+                serialization_format
+                    .deserialize::<T>(&bytes)
+                    .map_err(f_map_err)
+            })
             .transpose()
     }
 
@@ -381,16 +485,54 @@ impl Storage {
     where
         K: Clone + Debug + DeserializeOwned + Eq + Hash + Sync + 'static,
         BoxDT: DataTypeWrapper + 'static,
-        F: FnOnce(serde_yaml::Error) -> Error + Send,
+        F: FnOnce(SerializationError) -> Error + Send,
     {
-        self.get_item_opt(path)?
-            .map(|s| {
-                let deserializer = serde_yaml::Deserializer::from_str(&s);
-                let type_map_opt = type_reg
-                    .deserialize_map_opt_with_unknowns::<'_, serde_yaml::Value, _, _>(deserializer)
-                    .map_err(f_map_err)?;
-
-                Ok(type_map_opt)
+        self.get_item_plaintext_opt(path, false)
+            .await?
+            .map(|(serialization_format, bytes)| {
+                serialization_format
+                    .deserialize_typemap_opt(type_reg, &bytes)
+                    .map_err(f_map_err)
+            })
+            .transpose()
+    }
+
+    /// Deserializes a typemap from the given path if the file exists,
+    /// decrypting it if encryption is set.
+    ///
+    /// This is used for files written using [`serialized_write_encrypted`].
+    /// When encryption is set, an error is returned if the file is not
+    /// encrypted.
+    ///
+    /// The plaintext bytes are passed to `f_map_err` when deserialization
+    /// fails, so that errors are able to show the file contents without
+    /// reading the file again.
+    ///
+    /// # Parameters
+    ///
+    /// * `type_reg`: Type registry with the stateful deserialization mappings.
+    /// * `path`: Path to the file to read the serialized item.
+    /// * `f_map_err`: Maps the file's plaintext bytes and the deserialization
+    ///   error (if any) to an [`Error`].
+    ///
+    /// [`serialized_write_encrypted`]: Self::serialized_write_encrypted
+    pub async fn serialized_typemap_read_opt_encrypted<K, BoxDT, F>(
+        &self,
+        type_reg: &TypeReg<K, BoxDT>,
+        path: &Path,
+        f_map_err: F,
+    ) -> Result<Option<TypeMapOpt<K, BoxDT, UnknownEntriesSome<serde_yaml::Value>>>, Error>
+    where
+        K: Clone + Debug + DeserializeOwned + Eq + Hash + Sync + 'static,
+        BoxDT: DataTypeWrapper + 'static,
+        F: FnOnce(&[u8], SerializationError) -> Error + Send,
+    {
+        self.get_item_plaintext_opt(path, true)
+            .await?
+            .map(|(serialization_format, bytes)| {
+                serialization_format
+                    .deserialize_typemap_opt(type_reg, &bytes)
+                    .map_err(|error| f_map_err(&bytes, error))
             })
             .transpose()
     }
@@ -410,13 +552,167 @@ impl Storage {
     ) -> Result<(), Error>
     where
         T: Serialize + Send + Sync,
-        F: FnOnce(serde_yaml::Error) -> Error + Send,
+        F: FnOnce(SerializationError) -> Error + Send,
     {
-        self.set_item(path, &serde_yaml::to_string(t).map_err(f_map_err)?)?;
+        let bytes = self.serialization_format.serialize(t).map_err(f_map_err)?;
+        self.write_encrypted(path, bytes, None).await
+    }
 
+    /// Writes a serializable item to the given path, encrypting it if
+    /// encryption is set.
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: Path to store the serialized item.
+    /// * `t`: Item to serialize.
+    /// * `f_map_err`: Maps the serialization error (if any) to an [`Error`].
+    pub async fn serialized_write_encrypted<T, F>(
+        &self,
+        path: &Path,
+        t: &T,
+        f_map_err: F,
+    ) -> Result<(), Error>
+    where
+        T: Serialize + Send + Sync,
+        F: FnOnce(SerializationError) -> Error + Send,
+    {
+        let bytes = self.serialization_format.serialize(t).map_err(f_map_err)?;
+        self.write_encrypted(path, bytes, self.encryption.as_ref())
+            .await
+    }
+
+    /// Writes a serializable item to the given path if it differs from the
+    /// stored item, encrypting it if encryption is set.
+    ///
+    /// The stored item is not read. Instead, the item is compared with the
+    /// item last read from or written to the path through this `Storage`, so
+    /// it is always written if it has not been read or written before.
+    ///
+    /// Returns whether the item was written.
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: Path to store the serialized item.
+    /// * `t`: Item to serialize.
+    /// * `f_map_err`: Maps the serialization error (if any) to an [`Error`].
+    pub async fn serialized_write_encrypted_if_changed<T, F>(
+        &self,
+        path: &Path,
+        t: &T,
+        f_map_err: F,
+    ) -> Result<bool, Error>
+    where
+        T: Serialize + Send + Sync,
+        F: FnOnce(SerializationError) -> Error + Send,
+    {
+        let bytes = self.serialization_format.serialize(t).map_err(f_map_err)?;
+        let digest = plaintext_digest(&bytes);
+        let unchanged = self
+            .item_digests
+            .lock()
+            .map(|item_digests| item_digests.get(path) == Some(&digest))
+            .unwrap_or(false);
+        if unchanged {
+            return Ok(false);
+        }
+
+        self.write_encrypted(path, bytes, self.encryption.as_ref())
+            .await?;
+
+        Ok(true)
+    }
+
+    /// Encrypts the bytes if `encryption` is `Some`, and writes them to the
+    /// backend in this `Storage`'s serialization format.
+    ///
+    /// The item in other formats is removed, so that it is not read instead of
+    /// this item if the format is changed again.
+    async fn write_encrypted(
+        &self,
+        path: &Path,
+        bytes: Vec<u8>,
+        encryption: Option<&StorageEncryption>,
+    ) -> Result<(), Error> {
+        let format_path = self.serialization_format.file_path(path);
+        // Items written without encryption when encryption is set are not
+        // recorded, so they are encrypted when written through
+        // `serialized_write_encrypted_if_changed`.
+        let digest =
+            (encryption.is_some() || self.encryption.is_none()).then(|| plaintext_digest(&bytes));
+        let bytes = match encryption {
+            Some(encryption) => encryption.encrypt(&format_path, &bytes)?,
+            None => bytes,
+        };
+
+        let backend = self.backend();
+        backend.write(&format_path, &bytes).await?;
+        self.item_digest_update(path, digest);
+        for serialization_format in self.serialization_format.read_order().skip(1) {
+            backend
+                .remove(&serialization_format.file_path(path))
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Returns the serialization format and plaintext bytes of the item at
+    /// the given path, if it exists in any format.
+    ///
+    /// When `encrypted` is `true`, the item must be encrypted if encryption is
+    /// set. Otherwise the item is only decrypted if it is encrypted.
+    async fn get_item_plaintext_opt(
+        &self,
+        path: &Path,
+        encrypted: bool,
+    ) -> Result<Option<(SerializationFormat, Vec<u8>)>, Error> {
+        let encryption = self.encryption.as_ref();
+        let backend = self.backend();
+        for serialization_format in self.serialization_format.read_order() {
+            let format_path = serialization_format.file_path(path);
+            let Some(bytes) = backend.read_opt(&format_path).await? else {
+                continue;
+            };
+            let bytes = if encrypted {
+                StorageEncryption::decrypt_if_encrypted(encryption, &format_path, bytes)
+            } else {
+                StorageEncryption::decrypt_if_has_header(encryption, &format_path, bytes)
+            }
+            .map_err(Error::Encryption)?;
+            let digest = (serialization_format == self.serialization_format
+                && (encrypted || encryption.is_none()))
+            .then(|| plaintext_digest(&bytes));
+            self.item_digest_update(path, digest);
+
+            return Ok(Some((serialization_format, bytes)));
+        }
+
+        Ok(None)
+    }
+
+    /// Removes the workspace item at the given path in every serialization
+    /// format, if it exists.
+    pub async fn remove(&self, path: &Path) -> Result<(), Error> {
+        self.item_digest_update(path, None);
+        let backend = self.backend();
+        for serialization_format in SerializationFormat::ALL {
+            backend
+                .remove(&serialization_format.file_path(path))
+                .await?;
+        }
         Ok(())
     }
 
+    /// Records the digest of the plaintext last read from or written to an
+    /// item, or clears it if `None`.
+    fn item_digest_update(&self, path: &Path, digest: Option<u64>) {
+        if let Ok(mut item_digests) = self.item_digests.lock() {
+            match digest {
+                Some(digest) => item_digests.insert(path.to_path_buf(), digest),
+                None => item_digests.remove(path),
+            };
+        }
+    }
+
     /// Deletes an item from the web storage.
     pub fn remove_item(&self, path: &Path) -> Result<(), Error> {
         let storage = self.get()?;
@@ -428,4 +724,82 @@ impl Storage {
             })
         })
     }
+
+    /// Returns the keys of all items under the given directory, in sorted
+    /// order.
+    pub fn paths_under(&self, dir: &Path) -> Result<Vec<PathBuf>, Error> {
+        let storage = self.get()?;
+        let keys_list_error = |js_value| {
+            Error::Web(WebError::StorageKeysList {
+                dir: dir.to_path_buf(),
+                error: crate::stringify_js_value(js_value),
+            })
+        };
+        let length = storage.length().map_err(keys_list_error)?;
+        let mut paths = (0..length)
+            .map(|index| storage.key(index).map_err(keys_list_error))
+            .filter_map(Result::transpose)
+            .map(|key| key.map(PathBuf::from))
+            .filter(|path| {
+                path.as_ref()
+                    .map(|path| path.starts_with(dir) && path != dir)
+                    .unwrap_or(true)
+            })
+            .collect::<Result<Vec<PathBuf>, Error>>()?;
+
+        // Ensure paths are in a consistent order.
+        paths.sort();
+
+        Ok(paths)
+    }
+}
+
+/// Stores items in the browser storage.
+///
+/// Browser storage stores strings, so the bytes written must be valid UTF-8.
+///
+/// This does not delegate to the backend set by
+/// [`Storage::with_storage_backend`]; use [`Storage::backend`] to access
+/// workspace items.
+#[async_trait(?Send)]
+impl StorageBackend for Storage {
+    /// Directories are stored as empty items, as browser storage has no
+    /// directories.
+    async fn dirs_create(&self, dirs: &[&Path]) -> Result<(), Error> {
+        self.set_items(dirs.iter().map(|dir| (*dir, "")))
+    }
+
+    async fn contains_item(&self, path: &Path) -> Result<bool, Error> {
+        Storage::contains_item(self, path)
+    }
+
+    async fn read_opt(&self, path: &Path) -> Result<Option<Vec<u8>>, Error> {
+        self.get_item_opt(path)
+            .map(|value| value.map(String::into_bytes))
+    }
+
+    async fn write(&self, path: &Path, bytes: &[u8]) -> Result<(), Error> {
+        let value = std::str::from_utf8(bytes).map_err(|error| {
+            Error::Web(WebError::StorageSetItemUtf8 {
+                path: path.to_path_buf(),
+                error,
+            })
+        })?;
+        self.set_item(path, value)
+    }
+
+    async fn remove(&self, path: &Path) -> Result<(), Error> {
+        self.remove_item(path)
+    }
+
+    async fn paths_under(&self, dir: &Path) -> Result<Vec<PathBuf>, Error> {
+        Storage::paths_under(self, dir)
+    }
+}
+
+/// Returns the digest of an item's plaintext, to detect whether it changed.
+fn plaintext_digest(bytes: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    hasher.finish()
 }
@@ -9,7 +9,10 @@
 
 use peace_core::AppName;
 use peace_resource_rt::internal::WorkspaceDirs;
-use peace_rt_model_core::Error;
+use peace_rt_model_core::{
+    EncryptionKeyProvider, Error, SerializationFormat, StatesLayout, StorageBackend,
+    StorageEncryption,
+};
 
 use crate::{Storage, WorkspaceDirsBuilder, WorkspaceSpec};
 
@@ -44,6 +47,50 @@ impl Workspace {
         })
     }
 
+    /// Sets the backend that stores this workspace's items.
+    ///
+    /// By default, items are stored in the browser storage.
+    pub fn with_storage_backend<B>(mut self, storage_backend: B) -> Self
+    where
+        B: StorageBackend,
+    {
+        self.storage = self.storage.with_storage_backend(storage_backend);
+        self
+    }
+
+    /// Sets the format that this workspace's items are written in.
+    ///
+    /// Items are read in the format that they were written in, so changing
+    /// the format does not require existing items to be converted.
+    pub fn with_serialization_format(mut self, serialization_format: SerializationFormat) -> Self {
+        self.storage = self.storage.with_serialization_format(serialization_format);
+        self
+    }
+
+    /// Sets how stored states are laid out in each flow directory.
+    ///
+    /// When switching from [`StatesLayout::SingleFile`] to
+    /// [`StatesLayout::PerItem`], existing states items are still read, and
+    /// are replaced by per-item items the next time states are written.
+    pub fn with_states_layout(mut self, states_layout: StatesLayout) -> Self {
+        self.storage = self.storage.with_states_layout(states_layout);
+        self
+    }
+
+    /// Sets the key provider used to encrypt stored states and params specs.
+    ///
+    /// Existing unencrypted items are still readable, and are encrypted the
+    /// next time they are written.
+    pub fn with_encryption_key_provider<P>(mut self, key_provider: P) -> Self
+    where
+        P: EncryptionKeyProvider,
+    {
+        self.storage = self
+            .storage
+            .with_encryption(StorageEncryption::new(key_provider));
+        self
+    }
+
     /// Returns the underlying data.
     pub fn into_inner(self) -> (AppName, WorkspaceDirs, Storage) {
         let Self {
@@ -1,7 +1,9 @@
 use std::{fmt::Debug, hash::Hash, path::Path};
 
+use peace_core::Profile;
 use peace_resource_rt::{
     internal::{FlowParamsFile, ProfileParamsFile, WorkspaceParamsFile},
+    paths::ProfileParentFile,
     type_reg::untagged::{TypeMapOpt, TypeReg},
 };
 use peace_rt_model_core::{
@@ -51,9 +53,8 @@ impl WorkspaceInitializer {
     where
         I: IntoIterator<Item = &'f Path>,
     {
-        storage.set_items(dirs.into_iter().map(|dir| (dir, "")))?;
-
-        Result::<_, Error>::Ok(())
+        let dirs = dirs.into_iter().collect::<Vec<&Path>>();
+        storage.backend().dirs_create(&dirs).await
     }
 
     pub async fn workspace_params_serialize<K>(
@@ -134,6 +135,39 @@ impl WorkspaceInitializer {
             })
     }
 
+    /// Writes the name of the profile's parent profile.
+    pub async fn profile_parent_serialize(
+        storage: &Storage,
+        profile_parent: &Profile,
+        profile_parent_file: &ProfileParentFile,
+    ) -> Result<(), Error> {
+        storage
+            .serialized_write(
+                profile_parent_file,
+                profile_parent,
+                Error::ProfileParentSerialize,
+            )
+            .await
+    }
+
+    /// Returns the name of the profile's parent profile, if it has one.
+    pub async fn profile_parent_deserialize(
+        storage: &Storage,
+        profile_parent_file: &ProfileParentFile,
+    ) -> Result<Option<Profile>, Error> {
+        storage
+            .serialized_read_opt(profile_parent_file, Error::ProfileParentDeserialize)
+            .await
+    }
+
+    /// Removes the profile's parent profile, if it has one.
+    pub async fn profile_parent_remove(
+        storage: &Storage,
+        profile_parent_file: &ProfileParentFile,
+    ) -> Result<(), Error> {
+        storage.remove(profile_parent_file).await
+    }
+
     pub async fn flow_params_serialize<K>(
         storage: &Storage,
         flow_params: &FlowParams<K>,
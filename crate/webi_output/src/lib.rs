@@ -16,6 +16,7 @@ pub mod assets;
 mod cmd_exec_spawn_ctx;
 mod cmd_exec_to_leptos_ctx;
 mod flow_webi_fns;
+mod webi_md_presenter;
 mod webi_output;
 mod webi_server;
 
@@ -0,0 +1,281 @@
+use std::convert::Infallible;
+
+use peace_fmt::{
+    async_trait,
+    presentable::{HeadingLevel, Table, TableAlignment},
+    Presentable, Presenter,
+};
+
+/// Web interface markdown presenter.
+///
+/// Renders `Presentable` data as markdown source, to be sent to the web UI in
+/// a [`WebUiUpdate::Markdown`].
+///
+/// [`WebUiUpdate::Markdown`]: peace_webi_model::WebUiUpdate::Markdown
+#[derive(Debug, Default)]
+pub(crate) struct WebiMdPresenter {
+    /// The rendered markdown source.
+    markdown_src: String,
+}
+
+impl WebiMdPresenter {
+    /// Returns a new `WebiMdPresenter`.
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the rendered markdown source.
+    pub(crate) fn into_inner(self) -> String {
+        self.markdown_src
+    }
+
+    async fn list_aligned_item_write<P0, P1>(
+        &mut self,
+        presentable_0: &P0,
+        presentable_1: &P1,
+    ) -> Result<(), Infallible>
+    where
+        P0: Presentable,
+        P1: Presentable,
+    {
+        presentable_0.present(self).await?;
+        self.markdown_src.push_str(": ");
+        presentable_1.present(self).await?;
+        self.markdown_src.push('\n');
+        Ok(())
+    }
+
+    /// Writes a table row, escaping each cell so that it stays within its
+    /// column.
+    fn table_row_write<'cell, I>(&mut self, column_count: usize, cells: I)
+    where
+        I: IntoIterator<Item = &'cell str>,
+    {
+        let mut cells = cells.into_iter();
+        (0..column_count).for_each(|_| {
+            let cell = cells.next().unwrap_or("");
+            let cell = cell.replace('|', "\\|").replace(['\r', '\n'], " ");
+            self.markdown_src.push_str("| ");
+            self.markdown_src.push_str(&cell);
+            self.markdown_src.push(' ');
+        });
+        self.markdown_src.push_str("|\n");
+    }
+}
+
+#[async_trait(?Send)]
+impl<'output> Presenter<'output> for WebiMdPresenter {
+    type Error = Infallible;
+
+    async fn heading<P>(
+        &mut self,
+        heading_level: HeadingLevel,
+        presentable: &P,
+    ) -> Result<(), Self::Error>
+    where
+        P: Presentable + ?Sized,
+    {
+        let leading_hashes = match heading_level {
+            HeadingLevel::Level1 => "#",
+            HeadingLevel::Level2 => "##",
+            HeadingLevel::Level3 => "###",
+            HeadingLevel::Level4 => "####",
+            HeadingLevel::Level5 => "#####",
+            HeadingLevel::Level6 => "######",
+        };
+
+        self.markdown_src.push_str(leading_hashes);
+        self.markdown_src.push(' ');
+        presentable.present(self).await?;
+        self.markdown_src.push_str("\n\n");
+
+        Ok(())
+    }
+
+    async fn id(&mut self, id: &str) -> Result<(), Self::Error> {
+        self.markdown_src.push_str(id);
+        Ok(())
+    }
+
+    async fn name(&mut self, name: &str) -> Result<(), Self::Error> {
+        self.markdown_src.push_str(&format!("**{name}**"));
+        Ok(())
+    }
+
+    async fn text(&mut self, text: &str) -> Result<(), Self::Error> {
+        self.markdown_src.push_str(text);
+        Ok(())
+    }
+
+    async fn bold<P>(&mut self, presentable: &P) -> Result<(), Self::Error>
+    where
+        P: Presentable + ?Sized,
+    {
+        self.markdown_src.push_str("**");
+        presentable.present(self).await?;
+        self.markdown_src.push_str("**");
+        Ok(())
+    }
+
+    async fn tag(&mut self, tag: &str) -> Result<(), Self::Error> {
+        self.markdown_src.push_str(&format!("⦗{tag}⦘"));
+        Ok(())
+    }
+
+    async fn code_inline(&mut self, code: &str) -> Result<(), Self::Error> {
+        self.markdown_src.push_str(&format!("`{code}`"));
+        Ok(())
+    }
+
+    async fn list_numbered<'f, P, I>(&mut self, iter: I) -> Result<(), Self::Error>
+    where
+        P: Presentable + ?Sized + 'f,
+        I: IntoIterator<Item = &'f P>,
+    {
+        self.list_numbered_with(iter, std::convert::identity).await
+    }
+
+    async fn list_numbered_with<'f, P, I, T, F>(&mut self, iter: I, f: F) -> Result<(), Self::Error>
+    where
+        P: Presentable,
+        I: IntoIterator<Item = T>,
+        T: 'f,
+        F: Fn(T) -> P,
+    {
+        for (index, entry) in iter.into_iter().enumerate() {
+            let list_number = index + 1;
+            self.markdown_src.push_str(&format!("{list_number}. "));
+            let presentable = f(entry);
+            presentable.present(self).await?;
+            self.markdown_src.push('\n');
+        }
+
+        Ok(())
+    }
+
+    async fn list_numbered_aligned<'f, P0, P1, I>(&mut self, iter: I) -> Result<(), Self::Error>
+    where
+        P0: Presentable + 'f,
+        P1: Presentable + 'f,
+        I: IntoIterator<Item = &'f (P0, P1)>,
+    {
+        self.list_numbered_aligned_with(iter, std::convert::identity)
+            .await
+    }
+
+    async fn list_numbered_aligned_with<'f, P0, P1, I, T, F>(
+        &mut self,
+        iter: I,
+        f: F,
+    ) -> Result<(), Self::Error>
+    where
+        P0: Presentable + 'f,
+        P1: Presentable + 'f,
+        I: IntoIterator<Item = T>,
+        T: 'f,
+        F: Fn(T) -> &'f (P0, P1),
+    {
+        for (index, entry) in iter.into_iter().enumerate() {
+            let list_number = index + 1;
+            self.markdown_src.push_str(&format!("{list_number}. "));
+            let (presentable_0, presentable_1) = f(entry);
+            self.list_aligned_item_write(presentable_0, presentable_1)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn list_bulleted<'f, P, I>(&mut self, iter: I) -> Result<(), Self::Error>
+    where
+        P: Presentable + ?Sized + 'f,
+        I: IntoIterator<Item = &'f P>,
+    {
+        self.list_bulleted_with(iter, std::convert::identity).await
+    }
+
+    async fn list_bulleted_with<'f, P, I, T, F>(&mut self, iter: I, f: F) -> Result<(), Self::Error>
+    where
+        P: Presentable,
+        I: IntoIterator<Item = T>,
+        T: 'f,
+        F: Fn(T) -> P,
+    {
+        for entry in iter.into_iter() {
+            self.markdown_src.push_str("* ");
+            let presentable = f(entry);
+            presentable.present(self).await?;
+            self.markdown_src.push('\n');
+        }
+
+        Ok(())
+    }
+
+    async fn list_bulleted_aligned<'f, P0, P1, I>(&mut self, iter: I) -> Result<(), Self::Error>
+    where
+        P0: Presentable + 'f,
+        P1: Presentable + 'f,
+        I: IntoIterator<Item = &'f (P0, P1)>,
+    {
+        self.list_bulleted_aligned_with(iter, std::convert::identity)
+            .await
+    }
+
+    async fn list_bulleted_aligned_with<'f, P0, P1, I, T, F>(
+        &mut self,
+        iter: I,
+        f: F,
+    ) -> Result<(), Self::Error>
+    where
+        P0: Presentable + 'f,
+        P1: Presentable + 'f,
+        I: IntoIterator<Item = T>,
+        T: 'f,
+        F: Fn(T) -> &'f (P0, P1),
+    {
+        for entry in iter.into_iter() {
+            self.markdown_src.push_str("* ");
+            let (presentable_0, presentable_1) = f(entry);
+            self.list_aligned_item_write(presentable_0, presentable_1)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn table<'s, P>(&mut self, table: &Table<'s, P>) -> Result<(), Self::Error>
+    where
+        P: Presentable,
+    {
+        let columns = table.columns();
+        let column_count = columns.len();
+
+        // Render each value separately, so that it can be escaped before being
+        // placed in its cell.
+        let mut rows_cells = Vec::with_capacity(table.rows().len());
+        for row in table.rows() {
+            let mut row_cells = Vec::with_capacity(column_count);
+            for presentable in row.iter().take(column_count) {
+                let mut cell_presenter = WebiMdPresenter::new();
+                presentable.present(&mut cell_presenter).await?;
+                row_cells.push(cell_presenter.into_inner());
+            }
+            rows_cells.push(row_cells);
+        }
+
+        self.table_row_write(column_count, columns.iter().map(|column| column.header()));
+        self.table_row_write(
+            column_count,
+            columns.iter().map(|column| match column.alignment() {
+                TableAlignment::Left => ":--",
+                TableAlignment::Center => ":-:",
+                TableAlignment::Right => "--:",
+            }),
+        );
+        rows_cells.iter().for_each(|row_cells| {
+            self.table_row_write(column_count, row_cells.iter().map(String::as_str))
+        });
+
+        Ok(())
+    }
+}
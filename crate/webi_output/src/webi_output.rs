@@ -4,6 +4,8 @@ use peace_value_traits::AppError;
 use peace_webi_model::WebUiUpdate;
 use tokio::sync::mpsc;
 
+use crate::webi_md_presenter::WebiMdPresenter;
+
 cfg_if::cfg_if! {
     if #[cfg(feature = "output_progress")] {
         use peace_core::{
@@ -115,13 +117,18 @@ where
     #[cfg(feature = "output_progress")]
     async fn progress_end(&mut self, _cmd_progress_tracker: &CmdProgressTracker) {}
 
-    async fn present<P>(&mut self, _presentable: P) -> Result<(), AppErrorT>
+    async fn present<P>(&mut self, presentable: P) -> Result<(), AppErrorT>
     where
         AppErrorT: std::error::Error,
         P: Presentable,
     {
-        // TODO: send rendered / renderable markdown to the channel.
-        let markdown_src = String::from("TODO: presentable.present(md_presenter).");
+        let mut presenter = WebiMdPresenter::new();
+        presentable
+            .present(&mut presenter)
+            .await
+            .unwrap_or_else(|infallible| match infallible {});
+        let markdown_src = presenter.into_inner();
+
         if let Some(web_ui_update_tx) = self.web_ui_update_tx.as_ref() {
             let _result = web_ui_update_tx
                 .send(WebUiUpdate::Markdown { markdown_src })
@@ -1,7 +1,14 @@
 use peace::{
     cfg::{app_name, item_id, FlowId, ItemId, Profile},
-    cmd::{ctx::CmdCtx, scopes::SingleProfileSingleFlow},
+    cmd::{
+        ctx::CmdCtx,
+        scopes::{
+            SingleProfileSingleFlow, SingleProfileSingleFlowView,
+            SingleProfileSingleFlowViewAndOutput,
+        },
+    },
     cmd_model::CmdOutcome,
+    resource_rt::states::StatesDiffTree,
     rt::cmds::{
         CleanCmd, DiffCmd, EnsureCmd, StatesCurrentStoredDisplayCmd, StatesDiscoverCmd,
         StatesGoalDisplayCmd,
@@ -135,11 +142,10 @@ where
     O: OutputWrite<DownloadError>,
 {
     let cmd_outcome = DiffCmd::diff_stored(cmd_ctx).await?;
-    let states_diff = cmd_outcome
+    cmd_outcome
         .value()
         .expect("Expected `states_diff` to exist.");
-    cmd_ctx.output_mut().present(states_diff).await?;
-    Ok(())
+    states_diff_tree_present(cmd_ctx).await
 }
 
 pub async fn ensure_dry<O>(cmd_ctx: &mut DownloadCmdCtx<'_, O>) -> Result<(), DownloadError>
@@ -147,10 +153,28 @@ where
     O: OutputWrite<DownloadError>,
 {
     let states_ensured_dry_outcome = EnsureCmd::exec_dry(cmd_ctx).await?;
-    let states_ensured_dry = states_ensured_dry_outcome
+    states_ensured_dry_outcome
         .value()
         .expect("Expected `states_ensured_dry` to exist.");
-    cmd_ctx.output_mut().present(states_ensured_dry).await?;
+    states_diff_tree_present(cmd_ctx).await
+}
+
+/// Presents the `StatesDiffTree` inserted by `DiffCmd` or
+/// `EnsureCmd::exec_dry`.
+async fn states_diff_tree_present<O>(
+    cmd_ctx: &mut DownloadCmdCtx<'_, O>,
+) -> Result<(), DownloadError>
+where
+    O: OutputWrite<DownloadError>,
+{
+    let SingleProfileSingleFlowViewAndOutput {
+        output,
+        cmd_view: SingleProfileSingleFlowView { resources, .. },
+        ..
+    } = cmd_ctx.view_and_output();
+    output
+        .present(&*resources.borrow::<StatesDiffTree>())
+        .await?;
     Ok(())
 }
 
@@ -9,7 +9,7 @@ use peace::{
         presentable::{Heading, HeadingLevel, ListNumberedAligned},
         PresentableExt,
     },
-    resource_rt::states::StateDiffs,
+    resource_rt::states::{StateDiffs, StatesDiffTree},
     rt::cmds::DiffCmd,
     rt_model::{output::OutputWrite, Flow},
 };
@@ -85,6 +85,24 @@ impl EnvDiffCmd {
         Ok(())
     }
 
+    async fn states_diff_tree_present<O>(
+        output: &mut O,
+        states_diff_tree: &StatesDiffTree,
+    ) -> Result<(), EnvManError>
+    where
+        O: OutputWrite<EnvManError> + Send,
+    {
+        output
+            .present(&(
+                Heading::new(HeadingLevel::Level1, "State Diffs"),
+                states_diff_tree,
+                "\n",
+            ))
+            .await?;
+
+        Ok(())
+    }
+
     async fn state_diffs_present<O>(
         output: &mut O,
         flow: &Flow<EnvManError>,
@@ -131,12 +149,14 @@ macro_rules! run {
 
                 let SingleProfileSingleFlowViewAndOutput {
                     output,
-                    cmd_view: SingleProfileSingleFlowView { flow, .. },
+                    cmd_view: SingleProfileSingleFlowView { resources, .. },
                     ..
                 } = ctx.view_and_output();
 
-                if let Some(state_diffs) = state_diffs_outcome.value() {
-                    Self::state_diffs_present(output, flow, &state_diffs).await?;
+                if state_diffs_outcome.value().is_some() {
+                    let states_diff_tree = resources.borrow::<StatesDiffTree>();
+
+                    Self::states_diff_tree_present(output, &states_diff_tree).await?;
                 }
 
                 Ok(())
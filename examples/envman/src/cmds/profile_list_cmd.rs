@@ -1,7 +1,7 @@
 use peace::{
     cfg::app_name,
     cmd::{ctx::CmdCtx, scopes::MultiProfileNoFlowView},
-    fmt::presentable::{Heading, HeadingLevel},
+    fmt::presentable::{Heading, HeadingLevel, Table, TableColumn},
     rt_model::{output::OutputWrite, Workspace, WorkspaceSpec},
 };
 
@@ -46,13 +46,17 @@ impl ProfileListCmd {
             .present(Heading::new(HeadingLevel::Level1, String::from("Profiles")))
             .await?;
 
-        let profiles_presentable = profile_to_profile_params
+        let profile_rows = profile_to_profile_params
             .iter()
             .filter_map(|(profile, profile_params)| {
                 let env_type = profile_params.get::<EnvType, _>(&ProfileParamsKey::EnvType);
-                env_type.map(|env_type| (profile, " - type: ".to_string(), env_type))
+                env_type.map(|env_type| vec![profile.to_string(), env_type.to_string()])
             })
             .collect::<Vec<_>>();
+        let profiles_presentable = Table::new(
+            vec![TableColumn::new("Profile"), TableColumn::new("Type")],
+            profile_rows,
+        );
         output.present(&profiles_presentable).await?;
 
         Ok(())
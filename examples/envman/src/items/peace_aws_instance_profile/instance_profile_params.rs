@@ -16,6 +16,12 @@ use serde::{Deserialize, Serialize};
 #[derive(Derivative, Params, PartialEq, Eq, Deserialize, Serialize)]
 #[derivative(Clone, Debug)]
 #[serde(bound = "")]
+#[cfg_attr(
+    feature = "json_schema",
+    derive(schemars::JsonSchema),
+    schemars(bound = ""),
+    json_schema
+)]
 pub struct InstanceProfileParams<Id> {
     /// Name for both the instance profile and role.
     ///
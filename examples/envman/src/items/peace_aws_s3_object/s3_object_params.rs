@@ -19,6 +19,12 @@ use serde::{Deserialize, Serialize};
 #[derive(Derivative, Params, PartialEq, Eq, Deserialize, Serialize)]
 #[derivative(Clone, Debug)]
 #[serde(bound = "")]
+#[cfg_attr(
+    feature = "json_schema",
+    derive(schemars::JsonSchema),
+    schemars(bound = ""),
+    json_schema
+)]
 pub struct S3ObjectParams<Id> {
     /// Path to the file to upload.
     file_path: PathBuf,
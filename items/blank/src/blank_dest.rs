@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 
 /// Destination for blank state.
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Params)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct BlankDest(pub Option<u32>);
 
 impl fmt::Display for BlankDest {
@@ -18,6 +18,12 @@ use crate::{BlankDest, BlankSrc};
 #[derive(Derivative, Params, PartialEq, Eq, Deserialize, Serialize)]
 #[derivative(Clone, Debug)]
 #[serde(bound = "")]
+#[cfg_attr(
+    feature = "json_schema",
+    derive(schemars::JsonSchema),
+    schemars(bound = ""),
+    json_schema
+)]
 pub struct BlankParams<Id> {
     /// Source / goal value for the state.
     pub src: BlankSrc,
@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 
 /// Source for blank state.
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Params)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct BlankSrc(pub u32);
 
 impl std::ops::Deref for BlankSrc {
@@ -19,12 +19,20 @@ use url::Url;
 ///   parameters from each other.
 #[derive(Params, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(bound = "")]
+#[cfg_attr(
+    feature = "json_schema",
+    derive(schemars::JsonSchema),
+    schemars(bound = ""),
+    json_schema
+)]
 pub struct FileDownloadParams<Id> {
     /// Url of the file to download.
+    #[validate(url_scheme("http", "https"))]
     src: Url,
     /// Path of the destination.
     ///
     /// Must be a file path, and not a directory.
+    #[validate(non_empty)]
     dest: PathBuf,
     /// How to store the content of the download -- text or base64 encoded.
     ///
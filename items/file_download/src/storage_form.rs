@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 
 /// Form to store the response.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub enum StorageForm {
     /// Download and store the response text as-is.
     ///
@@ -6,6 +6,7 @@ use tokio::process::Command;
 
 /// Shell command to execute.
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Params)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct ShCmd {
     /// Command to run.
     program: OsString,
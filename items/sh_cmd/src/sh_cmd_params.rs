@@ -18,6 +18,12 @@ use crate::ShCmd;
 #[derive(Derivative, Params, PartialEq, Eq, Deserialize, Serialize)]
 #[derivative(Clone, Debug)]
 #[serde(bound = "")]
+#[cfg_attr(
+    feature = "json_schema",
+    derive(schemars::JsonSchema),
+    schemars(bound = ""),
+    json_schema
+)]
 pub struct ShCmdParams<Id> {
     /// Shell command to run to discover the example state.
     ///
@@ -23,10 +23,18 @@ use serde::{Deserialize, Serialize};
 #[derive(Derivative, Params, PartialEq, Eq, Deserialize, Serialize)]
 #[derivative(Clone, Debug)]
 #[serde(bound = "")]
+#[cfg_attr(
+    feature = "json_schema",
+    derive(schemars::JsonSchema),
+    schemars(bound = ""),
+    json_schema
+)]
 pub struct TarXParams<Id> {
     /// Path of the tar file to extract.
+    #[validate(non_empty)]
     tar_path: PathBuf,
     /// Directory path to extract the tar file to.
+    #[validate(non_empty)]
     dest: PathBuf,
     /// Marker for unique tar extraction parameters type.
     marker: PhantomData<Id>,
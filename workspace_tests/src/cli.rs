@@ -1 +1,2 @@
 mod output;
+mod report;
@@ -4,6 +4,7 @@ mod cli_md_presenter;
 mod cli_output;
 mod cli_output_builder;
 mod cli_output_target;
+mod json_lines_output;
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "output_progress")] {
@@ -11,3 +12,10 @@ cfg_if::cfg_if! {
         mod cli_progress_format_opt_parse_error;
     }
 }
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "output_tui")] {
+        mod tui_item_state;
+        mod tui_output;
+    }
+}
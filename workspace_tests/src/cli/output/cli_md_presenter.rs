@@ -3,7 +3,7 @@ use peace::{
     cli::output::{CliMdPresenter, CliOutput, CliOutputBuilder},
     cli_model::OutputFormat,
     fmt::{
-        presentable::{Bold, CodeInline, HeadingLevel},
+        presentable::{Bold, CodeInline, DiffKind, HeadingLevel},
         Presenter,
     },
 };
@@ -289,6 +289,53 @@ async fn presents_code_inline_with_backticks_blue_text_color_enabled(
     Ok(())
 }
 
+#[tokio::test]
+async fn presents_diff_with_symbol_color_disabled() -> Result<(), Box<dyn std::error::Error>> {
+    stream::iter([
+        (DiffKind::Added, "+ field: 1"),
+        (DiffKind::Removed, "- field: 1"),
+        (DiffKind::Modified, "~ field: 1"),
+        (DiffKind::Unchanged, "= field: 1"),
+    ])
+    .map(Result::<_, Box<dyn std::error::Error>>::Ok)
+    .try_for_each(|(diff_kind, expected)| async move {
+        let mut buffer = Vec::new();
+        let mut cli_output = cli_output(&mut buffer, CliColorizeOpt::Never);
+        let mut presenter = CliMdPresenter::new(&mut cli_output);
+
+        presenter.diff(diff_kind, "field: 1").await?;
+
+        let output = String::from_utf8(buffer)?;
+        assert_eq!(expected, output);
+        Ok(())
+    })
+    .await
+}
+
+#[tokio::test]
+async fn presents_diff_with_symbol_colored_text_color_enabled(
+) -> Result<(), Box<dyn std::error::Error>> {
+    stream::iter([
+        (DiffKind::Added, "\u{1b}[38;5;77m+ field: 1\u{1b}[0m"),
+        (DiffKind::Removed, "\u{1b}[38;5;203m- field: 1\u{1b}[0m"),
+        (DiffKind::Modified, "\u{1b}[38;5;220m~ field: 1\u{1b}[0m"),
+        (DiffKind::Unchanged, "\u{1b}[38;5;243m= field: 1\u{1b}[0m"),
+    ])
+    .map(Result::<_, Box<dyn std::error::Error>>::Ok)
+    .try_for_each(|(diff_kind, expected)| async move {
+        let mut buffer = Vec::new();
+        let mut cli_output = cli_output(&mut buffer, CliColorizeOpt::Always);
+        let mut presenter = CliMdPresenter::new(&mut cli_output);
+
+        presenter.diff(diff_kind, "field: 1").await?;
+
+        let output = String::from_utf8(buffer)?;
+        assert_eq!(expected, output);
+        Ok(())
+    })
+    .await
+}
+
 #[tokio::test]
 async fn presents_list_numbered_color_disabled() -> Result<(), Box<dyn std::error::Error>> {
     let mut buffer = Vec::new();
@@ -1113,6 +1113,76 @@ msg_update: NoChange"#,
             in_memory_term.contents()
         );
     }
+
+    #[tokio::test]
+    async fn progress_update_with_progress_format_lines_writes_status_changes() {
+        let mut buffer = Vec::new();
+        let mut cli_output = cli_output_progress(
+            &mut buffer,
+            OutputFormat::Text,
+            CliColorizeOpt::Never,
+            CliProgressFormatOpt::Lines,
+        );
+        let (mut cmd_progress_tracker, _progress_bar) = cmd_progress_tracker(&cli_output);
+
+        <CliOutput<_> as OutputWrite<Error>>::progress_begin(
+            &mut cli_output,
+            &cmd_progress_tracker,
+        )
+        .await;
+
+        let progress_trackers = cmd_progress_tracker.progress_trackers_mut();
+        let progress_tracker = progress_trackers
+            .get_mut(&item_id!("test_item_id"))
+            .unwrap();
+        progress_tracker.set_progress_status(ProgressStatus::Running);
+        progress_tracker.set_progress_limit(ProgressLimit::Steps(100));
+        let progress_updates = [
+            ProgressUpdate::Limit(ProgressLimit::Steps(100)),
+            ProgressUpdate::Delta(ProgressDelta::Inc(21)),
+            ProgressUpdate::Delta(ProgressDelta::Inc(21)),
+            ProgressUpdate::Delta(ProgressDelta::Inc(1)),
+            ProgressUpdate::Complete(ProgressComplete::Success),
+        ];
+        for progress_update in progress_updates {
+            if let ProgressUpdate::Delta(ProgressDelta::Inc(unit_count)) = &progress_update {
+                progress_tracker.inc(*unit_count);
+            }
+            let progress_update_and_id = ProgressUpdateAndId {
+                item_id: item_id!("test_item_id"),
+                progress_update,
+                msg_update: ProgressMsgUpdate::NoChange,
+            };
+            <CliOutput<_> as OutputWrite<Error>>::progress_update(
+                &mut cli_output,
+                progress_tracker,
+                &progress_update_and_id,
+            )
+            .await;
+        }
+
+        let CliOutputTarget::InMemory(in_memory_term) = cli_output.progress_target() else {
+            ({
+                #[cfg_attr(coverage_nightly, coverage(off))]
+                || -> ! { unreachable!("This is set in `cli_output_progress`.") }
+            })();
+        };
+        let contents = in_memory_term.contents();
+        let lines = contents
+            .lines()
+            .map(|line| line.split_once(' ').map(|(_timestamp, rest)| rest))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                Some("test_item_id started"),
+                Some("test_item_id progress 20%"),
+                Some("test_item_id progress 40%"),
+                Some("test_item_id complete"),
+            ],
+            lines
+        );
+        assert!(!contents.contains('\u{1b}'));
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
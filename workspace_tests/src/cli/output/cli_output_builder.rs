@@ -119,6 +119,18 @@ async fn build_progress_format_auto_passes_stderr_for_non_interactive_terminal(
 ) -> Result<(), Box<dyn std::error::Error>> {
     let cli_output = CliOutputBuilder::new().build();
 
+    assert_eq!(CliProgressFormat::Lines, cli_output.progress_format());
+    Ok(())
+}
+
+#[cfg(feature = "output_progress")]
+#[tokio::test]
+async fn build_progress_format_auto_passes_outcome_for_non_interactive_terminal_json(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let cli_output = CliOutputBuilder::new()
+        .with_outcome_format(OutputFormat::Json)
+        .build();
+
     assert_eq!(CliProgressFormat::Outcome, cli_output.progress_format());
     Ok(())
 }
@@ -34,6 +34,14 @@ fn from_str_returns_ok_for_progress_bar() {
     )
 }
 
+#[test]
+fn from_str_returns_ok_for_lines() {
+    assert_eq!(
+        Ok(CliProgressFormatOpt::Lines),
+        CliProgressFormatOpt::from_str("lines")
+    )
+}
+
 #[test]
 fn from_str_returns_ok_for_none() {
     assert_eq!(
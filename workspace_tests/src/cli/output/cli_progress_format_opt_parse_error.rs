@@ -6,7 +6,7 @@ fn display_includes_auto_output_pb_progress_bar() {
 
     assert_eq!(
         "Failed to parse CLI progress format from string: `\"rara\"`.\n\
-        Valid values are [\"auto\", \"outcome\", \"pb\", \"progress_bar\", \"none\"]",
+        Valid values are [\"auto\", \"outcome\", \"pb\", \"progress_bar\", \"lines\", \"none\"]",
         format!("{error}")
     );
 }
@@ -0,0 +1,204 @@
+use peace::{
+    cfg::{item_id, State},
+    cli::output::{JsonLinesEvent, JsonLinesEventKind, JsonLinesOutput},
+    cmd_model::CmdExecutionId,
+    resource_rt::{internal::StatesMut, states::StatesCurrentStored},
+    rt_model::output::OutputWrite,
+};
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "output_progress")] {
+        use peace::{
+            cfg::progress::{
+                CmdBlockItemInteractionType,
+                ProgressLimit,
+                ProgressMsgUpdate,
+                ProgressTracker,
+                ProgressUpdate,
+                ProgressUpdateAndId,
+            },
+            rt_model::{
+                indicatif::{MultiProgress, ProgressBar, ProgressDrawTarget},
+                CmdProgressTracker,
+                IndexMap,
+            },
+        };
+    }
+}
+
+#[tokio::test]
+async fn present_writes_presentation_event() -> Result<(), Box<dyn std::error::Error>> {
+    let mut buffer = Vec::new();
+    let mut json_lines_output =
+        JsonLinesOutput::new_with_writer(&mut buffer).with_cmd_execution_id(CmdExecutionId::new(3));
+    let states_current_stored = {
+        let mut states = StatesMut::new();
+        states.insert(item_id!("item_0"), State::new("logical", 1.1));
+        StatesCurrentStored::from(states)
+    };
+
+    <JsonLinesOutput<_> as OutputWrite<Error>>::present(
+        &mut json_lines_output,
+        &states_current_stored,
+    )
+    .await?;
+
+    let events = events(&buffer)?;
+    assert_eq!(1, events.len());
+    let event = &events[0];
+    assert_eq!(JsonLinesEvent::SCHEMA_VERSION, event.schema_version);
+    assert_eq!(CmdExecutionId::new(3), event.cmd_execution_id);
+    assert_eq!(
+        JsonLinesEventKind::Presentation {
+            value: serde_json::json!({
+                "item_0": { "logical": "logical", "physical": 1.1 }
+            }),
+        },
+        event.kind
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn write_err_writes_error_event_with_causes() -> Result<(), Box<dyn std::error::Error>> {
+    let mut buffer = Vec::new();
+    let mut json_lines_output = JsonLinesOutput::new_with_writer(&mut buffer);
+    let error = Error::JsonLinesOutputTest(std::io::Error::other("disk full"));
+
+    <JsonLinesOutput<_> as OutputWrite<Error>>::write_err(&mut json_lines_output, &error).await?;
+
+    let buffer_str = String::from_utf8(buffer.clone())?;
+    assert!(buffer_str.starts_with(r#"{"schema_version":1,"cmd_execution_id":0,"timestamp":"#));
+    assert!(buffer_str.ends_with(
+        r#""event":"error","message":"JsonLinesOutputTest display message.","causes":["disk full"]}
+"#
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn cmd_execution_next_tags_events_with_next_cmd_execution_id(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut buffer = Vec::new();
+    let mut json_lines_output =
+        JsonLinesOutput::new_with_writer(&mut buffer).with_cmd_execution_id(CmdExecutionId::new(3));
+
+    <JsonLinesOutput<_> as OutputWrite<Error>>::present(&mut json_lines_output, "one").await?;
+    let cmd_execution_id = json_lines_output.cmd_execution_next();
+    <JsonLinesOutput<_> as OutputWrite<Error>>::present(&mut json_lines_output, "two").await?;
+
+    assert_eq!(CmdExecutionId::new(4), cmd_execution_id);
+    assert_eq!(
+        vec![3, 4],
+        events(&buffer)?
+            .iter()
+            .map(|event| event.cmd_execution_id.into_inner())
+            .collect::<Vec<_>>()
+    );
+    Ok(())
+}
+
+#[cfg(feature = "output_progress")]
+#[tokio::test]
+async fn progress_events_are_written_per_line_with_cmd_execution_id(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut buffer = Vec::new();
+    let mut json_lines_output = JsonLinesOutput::new_with_writer(&mut buffer);
+    let mut cmd_progress_tracker = cmd_progress_tracker();
+
+    for cmd_execution_index in 0..2 {
+        if cmd_execution_index > 0 {
+            json_lines_output.cmd_execution_next();
+        }
+        <JsonLinesOutput<_> as OutputWrite<Error>>::progress_begin(
+            &mut json_lines_output,
+            &cmd_progress_tracker,
+        )
+        .await;
+        <JsonLinesOutput<_> as OutputWrite<Error>>::cmd_block_start(
+            &mut json_lines_output,
+            CmdBlockItemInteractionType::Local,
+        )
+        .await;
+        let progress_tracker = cmd_progress_tracker
+            .progress_trackers_mut()
+            .get_mut(&item_id!("test_item_id"))
+            .unwrap();
+        <JsonLinesOutput<_> as OutputWrite<Error>>::progress_update(
+            &mut json_lines_output,
+            progress_tracker,
+            &ProgressUpdateAndId {
+                item_id: item_id!("test_item_id"),
+                progress_update: ProgressUpdate::Limit(ProgressLimit::Steps(100)),
+                msg_update: ProgressMsgUpdate::NoChange,
+            },
+        )
+        .await;
+        <JsonLinesOutput<_> as OutputWrite<Error>>::progress_end(
+            &mut json_lines_output,
+            &cmd_progress_tracker,
+        )
+        .await;
+    }
+
+    let events = events(&buffer)?;
+    assert_eq!(
+        vec![
+            JsonLinesEventKind::ProgressBegin {
+                item_ids: vec![item_id!("test_item_id")],
+            },
+            JsonLinesEventKind::CmdBlockStart {
+                cmd_block_item_interaction_type: CmdBlockItemInteractionType::Local,
+            },
+            JsonLinesEventKind::ProgressUpdate(ProgressUpdateAndId {
+                item_id: item_id!("test_item_id"),
+                progress_update: ProgressUpdate::Limit(ProgressLimit::Steps(100)),
+                msg_update: ProgressMsgUpdate::NoChange,
+            }),
+            JsonLinesEventKind::ProgressEnd,
+        ],
+        events[0..4]
+            .iter()
+            .map(|event| event.kind.clone())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![0, 0, 0, 0, 1, 1, 1, 1],
+        events
+            .iter()
+            .map(|event| event.cmd_execution_id.into_inner())
+            .collect::<Vec<_>>()
+    );
+    Ok(())
+}
+
+/// Deserializes each line in the buffer as a `JsonLinesEvent`.
+fn events(buffer: &[u8]) -> Result<Vec<JsonLinesEvent>, Box<dyn std::error::Error>> {
+    let events = std::str::from_utf8(buffer)?
+        .lines()
+        .map(serde_json::from_str::<JsonLinesEvent>)
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(events)
+}
+
+#[cfg(feature = "output_progress")]
+fn cmd_progress_tracker() -> CmdProgressTracker {
+    let multi_progress = MultiProgress::with_draw_target(ProgressDrawTarget::hidden());
+    let mut progress_trackers = IndexMap::new();
+    let progress_bar = multi_progress.add(ProgressBar::hidden());
+    let progress_tracker = ProgressTracker::new(progress_bar);
+    progress_trackers.insert(item_id!("test_item_id"), progress_tracker);
+    CmdProgressTracker::new(multi_progress, progress_trackers)
+}
+
+#[derive(Debug, thiserror::Error)]
+enum Error {
+    /// JsonLinesOutputTest display message.
+    #[error("JsonLinesOutputTest display message.")]
+    JsonLinesOutputTest(#[source] std::io::Error),
+
+    // Framework errors
+    /// A `peace` runtime error occurred.
+    #[error("A `peace` runtime error occurred.")]
+    PeaceRtError(#[from] peace::rt_model::Error),
+}
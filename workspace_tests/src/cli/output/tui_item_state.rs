@@ -0,0 +1,35 @@
+use peace::{cfg::progress::ProgressStatus, cli::output::TuiItemState};
+
+#[test]
+fn new_is_initialized_without_messages() {
+    let tui_item_state = TuiItemState::new();
+
+    assert_eq!(
+        &ProgressStatus::Initialized,
+        tui_item_state.progress_status()
+    );
+    assert_eq!(0, tui_item_state.units_current());
+    assert_eq!(None, tui_item_state.units_total());
+    assert_eq!(None, tui_item_state.item_location_state());
+    assert!(tui_item_state.messages().is_empty());
+    assert_eq!(None, tui_item_state.error());
+}
+
+#[test]
+fn default_is_new() {
+    assert_eq!(TuiItemState::new(), TuiItemState::default());
+}
+
+#[test]
+fn clone() {
+    let tui_item_state = TuiItemState::new();
+
+    assert_eq!(tui_item_state, tui_item_state.clone());
+}
+
+#[test]
+fn debug() {
+    let tui_item_state = TuiItemState::new();
+
+    assert!(format!("{tui_item_state:?}").starts_with("TuiItemState {"));
+}
@@ -0,0 +1,420 @@
+use peace::{
+    cfg::{
+        flow_id, item_id,
+        progress::{
+            ItemLocationState, ProgressComplete, ProgressLimit, ProgressMsgUpdate, ProgressStatus,
+            ProgressTracker, ProgressUpdate, ProgressUpdateAndId,
+        },
+    },
+    cli::{
+        output::{CliColorizeOpt, CliOutputBuilder, TuiOutput},
+        ratatui::{
+            backend::TestBackend,
+            crossterm::event::{KeyCode, KeyEvent, KeyModifiers},
+        },
+    },
+    cmd::interruptible::InterruptSignal,
+    fmt::presentable::CodeInline,
+    rt_model::{
+        indicatif::{MultiProgress, ProgressBar, ProgressDrawTarget},
+        output::OutputWrite,
+        CmdProgressTracker, Flow, IndexMap, ItemGraphBuilder,
+    },
+};
+use peace_items::blank::BlankItem;
+
+use crate::PeaceTestError;
+
+#[tokio::test]
+async fn progress_begin_draws_items_in_flow_order() -> Result<(), Box<dyn std::error::Error>> {
+    let mut buffer = Vec::new();
+    let mut tui_output = tui_output(&mut buffer)?;
+    let cmd_progress_tracker = cmd_progress_tracker();
+
+    <TuiOutput<_, _> as OutputWrite<Error>>::progress_begin(&mut tui_output, &cmd_progress_tracker)
+        .await;
+
+    let lines = screen_lines(&tui_output.backend());
+    let item_0_line = line_index(&lines, "item_0 initialized");
+    let item_1_line = line_index(&lines, "item_1 initialized");
+    assert!(item_0_line < item_1_line, "{lines:#?}");
+    assert_eq!(Some(item_id!("item_0")), tui_output.item_selected());
+    assert!(lines[0].contains(" Items "), "{lines:#?}");
+    assert!(lines[0].contains(" item_0 "), "{lines:#?}");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn progress_update_shows_progress_and_messages() -> Result<(), Box<dyn std::error::Error>> {
+    let mut buffer = Vec::new();
+    let mut tui_output = tui_output(&mut buffer)?;
+    let mut cmd_progress_tracker = cmd_progress_tracker();
+    <TuiOutput<_, _> as OutputWrite<Error>>::progress_begin(&mut tui_output, &cmd_progress_tracker)
+        .await;
+
+    let progress_tracker = cmd_progress_tracker
+        .progress_trackers_mut()
+        .get_mut(&item_id!("item_0"))
+        .unwrap();
+    progress_tracker.set_progress_status(ProgressStatus::Running);
+    progress_tracker.set_progress_limit(ProgressLimit::Steps(5));
+    progress_tracker.inc(2);
+    progress_tracker.set_message(Some(String::from("downloading")));
+    let progress_update_and_id = ProgressUpdateAndId {
+        item_id: item_id!("item_0"),
+        progress_update: ProgressUpdate::Limit(ProgressLimit::Steps(5)),
+        msg_update: ProgressMsgUpdate::Set(String::from("downloading")),
+    };
+    <TuiOutput<_, _> as OutputWrite<Error>>::progress_update(
+        &mut tui_output,
+        progress_tracker,
+        &progress_update_and_id,
+    )
+    .await;
+
+    let lines = screen_lines(&tui_output.backend());
+    line_index(&lines, "item_0 running 2/5");
+    line_index(&lines, "status: running");
+    line_index(&lines, "progress: 2/5");
+    line_index(&lines, "messages:");
+    line_index(&lines, "  downloading");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn progress_update_fail_shows_error() -> Result<(), Box<dyn std::error::Error>> {
+    let mut buffer = Vec::new();
+    let mut tui_output = tui_output(&mut buffer)?;
+    let mut cmd_progress_tracker = cmd_progress_tracker();
+    <TuiOutput<_, _> as OutputWrite<Error>>::progress_begin(&mut tui_output, &cmd_progress_tracker)
+        .await;
+
+    let progress_tracker = cmd_progress_tracker
+        .progress_trackers_mut()
+        .get_mut(&item_id!("item_0"))
+        .unwrap();
+    progress_tracker.set_progress_status(ProgressStatus::Complete(ProgressComplete::Fail));
+    progress_tracker.set_message(Some(String::from("connection refused")));
+    let progress_update_and_id = ProgressUpdateAndId {
+        item_id: item_id!("item_0"),
+        progress_update: ProgressUpdate::Complete(ProgressComplete::Fail),
+        msg_update: ProgressMsgUpdate::Set(String::from("connection refused")),
+    };
+    <TuiOutput<_, _> as OutputWrite<Error>>::progress_update(
+        &mut tui_output,
+        progress_tracker,
+        &progress_update_and_id,
+    )
+    .await;
+
+    let item_states = tui_output.item_states();
+    let item_state = &item_states[&item_id!("item_0")];
+    assert_eq!(Some("connection refused"), item_state.error());
+    let lines = screen_lines(&tui_output.backend());
+    line_index(&lines, "item_0 failed");
+    line_index(&lines, "error: connection refused");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn item_location_state_shows_location() -> Result<(), Box<dyn std::error::Error>> {
+    let mut buffer = Vec::new();
+    let mut tui_output = tui_output(&mut buffer)?;
+    let cmd_progress_tracker = cmd_progress_tracker();
+    <TuiOutput<_, _> as OutputWrite<Error>>::progress_begin(&mut tui_output, &cmd_progress_tracker)
+        .await;
+
+    <TuiOutput<_, _> as OutputWrite<Error>>::item_location_state(
+        &mut tui_output,
+        item_id!("item_0"),
+        ItemLocationState::Exists,
+    )
+    .await;
+
+    let lines = screen_lines(&tui_output.backend());
+    line_index(&lines, "location: exists");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn with_flow_spec_info_indents_items_under_predecessors(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let flow_spec_info = {
+        let mut item_graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        let [fn_id_0, fn_id_1] = item_graph_builder.add_fns([
+            BlankItem::<()>::new(item_id!("item_0")).into(),
+            BlankItem::<()>::new(item_id!("item_1")).into(),
+        ]);
+        item_graph_builder.add_logic_edge(fn_id_0, fn_id_1)?;
+        Flow::new(flow_id!("test_flow"), item_graph_builder.build()).flow_spec_info()
+    };
+    let mut buffer = Vec::new();
+    let mut tui_output = tui_output(&mut buffer)?.with_flow_spec_info(&flow_spec_info);
+    let cmd_progress_tracker = cmd_progress_tracker();
+
+    <TuiOutput<_, _> as OutputWrite<Error>>::progress_begin(&mut tui_output, &cmd_progress_tracker)
+        .await;
+
+    let lines = screen_lines(&tui_output.backend());
+    let item_0_line = line_index(&lines, "> item_0   initialized");
+    let item_1_line = line_index(&lines, "  └ item_1 initialized");
+    assert!(item_0_line < item_1_line, "{lines:#?}");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn item_state_diff_shows_diff_of_selected_item() -> Result<(), Box<dyn std::error::Error>> {
+    let mut buffer = Vec::new();
+    let mut tui_output = tui_output(&mut buffer)?;
+    let cmd_progress_tracker = cmd_progress_tracker();
+    <TuiOutput<_, _> as OutputWrite<Error>>::progress_begin(&mut tui_output, &cmd_progress_tracker)
+        .await;
+
+    <TuiOutput<_, _> as OutputWrite<Error>>::item_state_diff(
+        &mut tui_output,
+        item_id!("item_1"),
+        String::from("file contents changed"),
+    )
+    .await;
+
+    let item_states = tui_output.item_states();
+    assert_eq!(
+        Some("file contents changed"),
+        item_states[&item_id!("item_1")].state_diff()
+    );
+    let lines = screen_lines(&tui_output.backend());
+    assert!(
+        lines.iter().all(|line| !line.contains("diff:")),
+        "{lines:#?}"
+    );
+
+    tui_output.key_event_handle(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+
+    let lines = screen_lines(&tui_output.backend());
+    let diff_line = line_index(&lines, "diff:");
+    let state_diff_line = line_index(&lines, "  file contents changed");
+    assert_eq!(diff_line + 1, state_diff_line, "{lines:#?}");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn key_down_and_up_changes_selected_item() -> Result<(), Box<dyn std::error::Error>> {
+    let mut buffer = Vec::new();
+    let mut tui_output = tui_output(&mut buffer)?;
+    let cmd_progress_tracker = cmd_progress_tracker();
+    <TuiOutput<_, _> as OutputWrite<Error>>::progress_begin(&mut tui_output, &cmd_progress_tracker)
+        .await;
+
+    tui_output.key_event_handle(KeyEvent::from(KeyCode::Down));
+    assert_eq!(Some(item_id!("item_1")), tui_output.item_selected());
+    let lines = screen_lines(&tui_output.backend());
+    assert!(lines[0].contains(" item_1 "), "{lines:#?}");
+
+    // Selection stops at the last item.
+    tui_output.key_event_handle(KeyEvent::from(KeyCode::Char('j')));
+    assert_eq!(Some(item_id!("item_1")), tui_output.item_selected());
+
+    tui_output.key_event_handle(KeyEvent::from(KeyCode::Up));
+    assert_eq!(Some(item_id!("item_0")), tui_output.item_selected());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn key_q_sends_interrupt_signal_once() -> Result<(), Box<dyn std::error::Error>> {
+    let (interrupt_tx, mut interrupt_rx) = tokio::sync::mpsc::channel::<InterruptSignal>(16);
+    let mut buffer = Vec::new();
+    let mut tui_output = tui_output(&mut buffer)?.with_interrupt_tx(interrupt_tx);
+    let cmd_progress_tracker = cmd_progress_tracker();
+    <TuiOutput<_, _> as OutputWrite<Error>>::progress_begin(&mut tui_output, &cmd_progress_tracker)
+        .await;
+
+    tui_output.key_event_handle(KeyEvent::from(KeyCode::Char('q')));
+    tui_output.key_event_handle(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+
+    assert!(tui_output.interrupt_requested());
+    assert!(interrupt_rx.try_recv().is_ok());
+    assert!(interrupt_rx.try_recv().is_err());
+    let lines = screen_lines(&tui_output.backend());
+    line_index(&lines, "q interrupt");
+    line_index(&lines, "interrupting..");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn key_ctrl_c_after_interrupt_sends_exit_signal_once(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let (interrupt_tx, mut interrupt_rx) = tokio::sync::mpsc::channel::<InterruptSignal>(16);
+    let (exit_tx, mut exit_rx) = tokio::sync::mpsc::channel::<InterruptSignal>(16);
+    let mut buffer = Vec::new();
+    let mut tui_output = tui_output(&mut buffer)?
+        .with_interrupt_tx(interrupt_tx)
+        .with_exit_tx(exit_tx);
+    let cmd_progress_tracker = cmd_progress_tracker();
+    <TuiOutput<_, _> as OutputWrite<Error>>::progress_begin(&mut tui_output, &cmd_progress_tracker)
+        .await;
+
+    tui_output.key_event_handle(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+    assert!(!tui_output.exit_requested());
+    assert!(exit_rx.try_recv().is_err());
+
+    tui_output.key_event_handle(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+    tui_output.key_event_handle(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+
+    assert!(tui_output.exit_requested());
+    assert!(interrupt_rx.try_recv().is_ok());
+    assert!(interrupt_rx.try_recv().is_err());
+    assert!(exit_rx.try_recv().is_ok());
+    assert!(exit_rx.try_recv().is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn key_ctrl_c_without_interrupt_tx_sends_exit_signal(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let (exit_tx, mut exit_rx) = tokio::sync::mpsc::channel::<InterruptSignal>(16);
+    let mut buffer = Vec::new();
+    let mut tui_output = tui_output(&mut buffer)?.with_exit_tx(exit_tx);
+    let cmd_progress_tracker = cmd_progress_tracker();
+    <TuiOutput<_, _> as OutputWrite<Error>>::progress_begin(&mut tui_output, &cmd_progress_tracker)
+        .await;
+
+    tui_output.key_event_handle(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+
+    assert!(!tui_output.interrupt_requested());
+    assert!(tui_output.exit_requested());
+    assert!(exit_rx.try_recv().is_ok());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn key_ctrl_c_without_interrupt_tx_or_exit_tx_requests_exit(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut buffer = Vec::new();
+    let mut tui_output = tui_output(&mut buffer)?;
+    let cmd_progress_tracker = cmd_progress_tracker();
+    <TuiOutput<_, _> as OutputWrite<Error>>::progress_begin(&mut tui_output, &cmd_progress_tracker)
+        .await;
+
+    // `TestBackend` is not a terminal, so the process is not exited.
+    tui_output.key_event_handle(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+
+    assert!(!tui_output.interrupt_requested());
+    assert!(tui_output.exit_requested());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn present_while_active_renders_to_output_pane() -> Result<(), Box<dyn std::error::Error>> {
+    let mut buffer = Vec::new();
+    let mut tui_output = tui_output(&mut buffer)?;
+    let cmd_progress_tracker = cmd_progress_tracker();
+    <TuiOutput<_, _> as OutputWrite<Error>>::progress_begin(&mut tui_output, &cmd_progress_tracker)
+        .await;
+
+    <TuiOutput<_, _> as OutputWrite<Error>>::present(
+        &mut tui_output,
+        CodeInline::new("presented".into()),
+    )
+    .await?;
+
+    let lines = screen_lines(&tui_output.backend());
+    line_index(&lines, " Output ");
+    line_index(&lines, "`presented`");
+    drop(tui_output);
+    assert!(buffer.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn present_after_progress_end_writes_to_cli_output() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut buffer = Vec::new();
+    let mut tui_output = tui_output(&mut buffer)?;
+    let cmd_progress_tracker = cmd_progress_tracker();
+    <TuiOutput<_, _> as OutputWrite<Error>>::progress_begin(&mut tui_output, &cmd_progress_tracker)
+        .await;
+    <TuiOutput<_, _> as OutputWrite<Error>>::progress_end(&mut tui_output, &cmd_progress_tracker)
+        .await;
+
+    <TuiOutput<_, _> as OutputWrite<Error>>::present(
+        &mut tui_output,
+        CodeInline::new("presented".into()),
+    )
+    .await?;
+    <TuiOutput<_, _> as OutputWrite<Error>>::write_err(&mut tui_output, &Error::TuiOutputTest)
+        .await?;
+
+    drop(tui_output);
+    assert_eq!(
+        "`presented`TuiOutputTest display message.\n",
+        String::from_utf8(buffer)?
+    );
+
+    Ok(())
+}
+
+#[derive(Debug, thiserror::Error)]
+enum Error {
+    /// TuiOutputTest display message.
+    #[error("TuiOutputTest display message.")]
+    TuiOutputTest,
+
+    // Framework errors
+    /// A `peace` runtime error occurred.
+    #[error("A `peace` runtime error occurred.")]
+    PeaceRtError(#[from] peace::rt_model::Error),
+}
+
+fn tui_output(
+    buffer: &mut Vec<u8>,
+) -> Result<TuiOutput<TestBackend, &mut Vec<u8>>, Box<dyn std::error::Error>> {
+    let cli_output = CliOutputBuilder::new_with_writer(buffer)
+        .with_colorize(CliColorizeOpt::Never)
+        .build();
+    let tui_output = TuiOutput::new_with_backend(TestBackend::new(80, 16), cli_output)?;
+    Ok(tui_output)
+}
+
+fn cmd_progress_tracker() -> CmdProgressTracker {
+    let multi_progress = MultiProgress::with_draw_target(ProgressDrawTarget::hidden());
+    let progress_trackers = [item_id!("item_0"), item_id!("item_1")]
+        .into_iter()
+        .map(|item_id| {
+            let progress_bar = multi_progress.add(ProgressBar::hidden());
+            (item_id, ProgressTracker::new(progress_bar))
+        })
+        .collect::<IndexMap<_, _>>();
+
+    CmdProgressTracker::new(multi_progress, progress_trackers)
+}
+
+/// Returns the rendered screen as lines of text.
+fn screen_lines(backend: &TestBackend) -> Vec<String> {
+    let buffer = backend.buffer();
+    let width = usize::from(buffer.area.width);
+    buffer
+        .content()
+        .chunks(width)
+        .map(|cells| cells.iter().map(|cell| cell.symbol()).collect::<String>())
+        .collect()
+}
+
+/// Returns the index of the first line that contains `text`.
+fn line_index(lines: &[String], text: &str) -> usize {
+    lines
+        .iter()
+        .position(|line| line.contains(text))
+        .unwrap_or_else(|| panic!("Expected `{text}` to be on screen:\n{lines:#?}"))
+}
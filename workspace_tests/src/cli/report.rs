@@ -0,0 +1,3 @@
+mod cmd_outcome_report;
+mod report_format;
+mod report_writer;
@@ -0,0 +1,145 @@
+use peace::{
+    cfg::item_id,
+    cli::report::{CmdOutcomeReport, ReportItem, ReportItemStatus},
+    cmd_model::{CmdBlockDesc, CmdOutcome, ItemStreamOutcome},
+    rt_model::{fn_graph::StreamOutcomeState, IndexMap},
+};
+
+#[test]
+fn from_cmd_outcome_complete_marks_all_items_success() {
+    let cmd_outcome = CmdOutcome::<(), TestError>::Complete {
+        value: (),
+        cmd_blocks_processed: Vec::new(),
+    };
+
+    let report = CmdOutcomeReport::from_cmd_outcome(
+        "ensure",
+        [item_id!("item_0"), item_id!("item_1")],
+        &cmd_outcome,
+    );
+
+    assert_eq!("ensure", report.name);
+    assert_eq!(
+        vec![
+            ReportItem {
+                item_id: item_id!("item_0"),
+                status: ReportItemStatus::Success,
+            },
+            ReportItem {
+                item_id: item_id!("item_1"),
+                status: ReportItemStatus::Success,
+            },
+        ],
+        report.items
+    );
+    assert_eq!(2, report.success_count());
+    assert!(report.is_successful());
+}
+
+#[test]
+fn from_cmd_outcome_item_error_marks_failures_and_skipped() {
+    let cmd_outcome = CmdOutcome::<(), TestError>::ItemError {
+        item_stream_outcome: ItemStreamOutcome {
+            value: (),
+            state: StreamOutcomeState::Interrupted,
+            item_ids_processed: vec![item_id!("item_0"), item_id!("item_1")],
+            item_ids_not_processed: vec![item_id!("item_2")],
+        },
+        cmd_blocks_processed: Vec::new(),
+        cmd_blocks_not_processed: Vec::new(),
+        errors: {
+            let mut errors = IndexMap::new();
+            errors.insert(item_id!("item_1"), TestError::Download(DownloadError));
+            errors
+        },
+    };
+
+    let report = CmdOutcomeReport::from_cmd_outcome(
+        "ensure",
+        [item_id!("item_0"), item_id!("item_1"), item_id!("item_2")],
+        &cmd_outcome,
+    );
+
+    assert_eq!(ReportItemStatus::Success, report.items[0].status);
+    assert_eq!(
+        ReportItemStatus::Failure {
+            message: "Failed to download file.".to_string(),
+            causes: vec!["Connection refused.".to_string()],
+        },
+        report.items[1].status
+    );
+    assert!(matches!(
+        report.items[2].status,
+        ReportItemStatus::Skipped { .. }
+    ));
+    assert_eq!(1, report.success_count());
+    assert_eq!(1, report.failure_count());
+    assert_eq!(1, report.skipped_count());
+    assert!(!report.is_successful());
+}
+
+#[test]
+fn from_cmd_outcome_block_interrupted_marks_not_processed_skipped() {
+    let cmd_outcome = CmdOutcome::<(), TestError>::BlockInterrupted {
+        item_stream_outcome: ItemStreamOutcome {
+            value: (),
+            state: StreamOutcomeState::Interrupted,
+            item_ids_processed: vec![item_id!("item_0")],
+            item_ids_not_processed: vec![item_id!("item_1")],
+        },
+        cmd_blocks_processed: Vec::new(),
+        cmd_blocks_not_processed: Vec::new(),
+    };
+
+    let report = CmdOutcomeReport::from_cmd_outcome(
+        "ensure",
+        [item_id!("item_0"), item_id!("item_1")],
+        &cmd_outcome,
+    );
+
+    assert_eq!(ReportItemStatus::Success, report.items[0].status);
+    assert!(matches!(
+        report.items[1].status,
+        ReportItemStatus::Skipped { .. }
+    ));
+}
+
+#[test]
+fn from_cmd_outcome_execution_interrupted_records_interruption_without_items() {
+    let cmd_outcome = CmdOutcome::<(), TestError>::ExecutionInterrupted {
+        value: None,
+        cmd_blocks_processed: vec![CmdBlockDesc::new(
+            String::from("StatesCurrentReadCmdBlock"),
+            Vec::new(),
+            vec![String::from("States<Current>")],
+        )],
+        cmd_blocks_not_processed: vec![CmdBlockDesc::new(
+            String::from("ApplyExecCmdBlock"),
+            vec![String::from("States<Current>")],
+            vec![String::from("States<Ensured>")],
+        )],
+    };
+
+    let report = CmdOutcomeReport::from_cmd_outcome(
+        "ensure",
+        [item_id!("item_0"), item_id!("item_1")],
+        &cmd_outcome,
+    );
+
+    assert!(report.items.is_empty());
+    assert_eq!(
+        Some("Interrupted before command blocks were processed: `ApplyExecCmdBlock`."),
+        report.interruption.as_deref()
+    );
+    assert!(!report.is_successful());
+}
+
+#[derive(Debug, thiserror::Error)]
+enum TestError {
+    #[error("Failed to download file.")]
+    Download(#[source] DownloadError),
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Connection refused.")]
+struct DownloadError;
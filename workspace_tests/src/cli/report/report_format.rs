@@ -0,0 +1,29 @@
+use std::str::FromStr;
+
+use peace::cli::report::{ReportFormat, ReportFormatParseError};
+
+#[test]
+fn from_str_returns_ok_for_junit_xml() {
+    assert_eq!(Ok(ReportFormat::JUnitXml), ReportFormat::from_str("junit"));
+    assert_eq!(
+        Ok(ReportFormat::JUnitXml),
+        ReportFormat::from_str("junit_xml")
+    );
+}
+
+#[test]
+fn from_str_returns_ok_for_markdown() {
+    assert_eq!(Ok(ReportFormat::Markdown), ReportFormat::from_str("md"));
+    assert_eq!(
+        Ok(ReportFormat::Markdown),
+        ReportFormat::from_str("markdown")
+    );
+}
+
+#[test]
+fn from_str_returns_err_for_unknown_string() {
+    assert_eq!(
+        Err(ReportFormatParseError("html".to_string())),
+        ReportFormat::from_str("html")
+    );
+}
@@ -0,0 +1,180 @@
+use peace::{
+    cfg::item_id,
+    cli::report::{CmdOutcomeReport, ReportFormat, ReportItem, ReportItemStatus, ReportWriter},
+};
+
+#[tokio::test]
+async fn write_junit_xml_writes_test_case_per_item() -> Result<(), Box<dyn std::error::Error>> {
+    let mut report_writer = ReportWriter::new(Vec::<u8>::new());
+
+    report_writer
+        .write(&report(), ReportFormat::JUnitXml)
+        .await?;
+
+    let xml = String::from_utf8(report_writer.into_inner())?;
+    assert_eq!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="ensure" tests="3" failures="1" errors="0" skipped="1">
+  <testsuite name="ensure" tests="3" failures="1" errors="0" skipped="1">
+    <testcase name="item_0" classname="ensure"/>
+    <testcase name="item_1" classname="ensure">
+      <failure message="Failed to write &lt;file&gt;.">Failed to write &lt;file&gt;.
+Permission &quot;denied&quot;.</failure>
+    </testcase>
+    <testcase name="item_2" classname="ensure">
+      <skipped message="Interrupted."/>
+    </testcase>
+  </testsuite>
+</testsuites>
+"#,
+        xml
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn write_markdown_writes_summary_table() -> Result<(), Box<dyn std::error::Error>> {
+    let mut report_writer = ReportWriter::new(Vec::<u8>::new());
+
+    report_writer
+        .write(&report(), ReportFormat::Markdown)
+        .await?;
+
+    let markdown = String::from_utf8(report_writer.into_inner())?;
+    assert_eq!(
+        "### ❌ `ensure` failed\n\
+        \n\
+        1 succeeded, 1 failed, 1 skipped.\n\
+        \n\
+        | Item | Outcome | Details |\n\
+        | :--- | :------ | :------ |\n\
+        | `item_0` | ✅ success |  |\n\
+        | `item_1` | ❌ failure | Failed to write <file>.<br>Permission \"denied\". |\n\
+        | `item_2` | ⏸️ skipped | Interrupted. |\n",
+        markdown
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn write_markdown_escapes_table_cell_separators() -> Result<(), Box<dyn std::error::Error>> {
+    let mut report_writer = ReportWriter::new(Vec::<u8>::new());
+    let report = CmdOutcomeReport {
+        name: "clean".to_string(),
+        items: vec![ReportItem {
+            item_id: item_id!("item_0"),
+            status: ReportItemStatus::Skipped {
+                reason: "a | b\nc".to_string(),
+            },
+        }],
+        interruption: None,
+    };
+
+    report_writer.write(&report, ReportFormat::Markdown).await?;
+
+    let markdown = String::from_utf8(report_writer.into_inner())?;
+    assert!(markdown.starts_with("### ⏸️ `clean` incomplete\n"));
+    assert!(markdown.ends_with("| `item_0` | ⏸️ skipped | a \\| b<br>c |\n"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn write_junit_xml_replaces_characters_not_allowed_in_xml(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut report_writer = ReportWriter::new(Vec::<u8>::new());
+    let report = CmdOutcomeReport {
+        name: "ensure".to_string(),
+        items: vec![ReportItem {
+            item_id: item_id!("item_0"),
+            status: ReportItemStatus::Failure {
+                message: "\u{1b}[31merror\u{1b}[0m\u{0}".to_string(),
+                causes: vec!["a\tb\r\nc".to_string()],
+            },
+        }],
+        interruption: None,
+    };
+
+    report_writer.write(&report, ReportFormat::JUnitXml).await?;
+
+    let xml = String::from_utf8(report_writer.into_inner())?;
+    assert!(xml.contains(
+        "<failure message=\"\u{FFFD}[31merror\u{FFFD}[0m\u{FFFD}\">\
+        \u{FFFD}[31merror\u{FFFD}[0m\u{FFFD}\na\tb\r\nc</failure>"
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn write_junit_xml_writes_interruption() -> Result<(), Box<dyn std::error::Error>> {
+    let mut report_writer = ReportWriter::new(Vec::<u8>::new());
+    let report = CmdOutcomeReport {
+        name: "ensure".to_string(),
+        items: Vec::new(),
+        interruption: Some("Interrupted before `ApplyExecCmdBlock`.".to_string()),
+    };
+
+    report_writer.write(&report, ReportFormat::JUnitXml).await?;
+
+    let xml = String::from_utf8(report_writer.into_inner())?;
+    assert_eq!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="ensure" tests="0" failures="0" errors="0" skipped="0">
+  <testsuite name="ensure" tests="0" failures="0" errors="0" skipped="0">
+    <system-err>Interrupted before `ApplyExecCmdBlock`.</system-err>
+  </testsuite>
+</testsuites>
+"#,
+        xml
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn write_markdown_writes_interruption() -> Result<(), Box<dyn std::error::Error>> {
+    let mut report_writer = ReportWriter::new(Vec::<u8>::new());
+    let report = CmdOutcomeReport {
+        name: "ensure".to_string(),
+        items: Vec::new(),
+        interruption: Some("Interrupted before `ApplyExecCmdBlock`.".to_string()),
+    };
+
+    report_writer.write(&report, ReportFormat::Markdown).await?;
+
+    let markdown = String::from_utf8(report_writer.into_inner())?;
+    assert_eq!(
+        "### ⏸️ `ensure` interrupted\n\
+        \n\
+        0 succeeded, 0 failed, 0 skipped.\n\
+        \n\
+        Interrupted before `ApplyExecCmdBlock`.\n\
+        \n",
+        markdown
+    );
+    Ok(())
+}
+
+fn report() -> CmdOutcomeReport {
+    CmdOutcomeReport {
+        name: "ensure".to_string(),
+        items: vec![
+            ReportItem {
+                item_id: item_id!("item_0"),
+                status: ReportItemStatus::Success,
+            },
+            ReportItem {
+                item_id: item_id!("item_1"),
+                status: ReportItemStatus::Failure {
+                    message: "Failed to write <file>.".to_string(),
+                    causes: vec!["Permission \"denied\".".to_string()],
+                },
+            },
+            ReportItem {
+                item_id: item_id!("item_2"),
+                status: ReportItemStatus::Skipped {
+                    reason: "Interrupted.".to_string(),
+                },
+            },
+        ],
+        interruption: None,
+    }
+}
@@ -1,10 +1,19 @@
-use std::collections::BTreeMap;
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
 
 use peace::{
     cfg::{app_name, profile, Profile, ProfileInvalidFmt},
     cmd::ctx::CmdCtx,
-    resource_rt::paths::{ProfileDir, ProfileHistoryDir},
-    rt_model::{params::ParamsTypeRegs, Error as PeaceRtError, NativeError},
+    resource_rt::{
+        internal::ProfileParamsFile,
+        paths::{ProfileDir, ProfileHistoryDir, ProfileParentFile},
+    },
+    rt_model::{
+        async_trait, params::ParamsTypeRegs, Error as PeaceRtError, InMemoryStorageBackend,
+        NativeError, StorageBackend, Workspace, WorkspaceSpec,
+    },
 };
 
 use crate::{no_op_output::NoOpOutput, test_support::workspace_with, PeaceTestError};
@@ -369,6 +378,300 @@ async fn build_with_workspace_params_with_profile_params_with_profile_filter(
     Ok(())
 }
 
+#[tokio::test]
+async fn build_with_profile_filter_glob() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let profile_dev_a = profile!("dev_a");
+    let profile_dev_b = profile!("dev_b");
+    let profile_prod_a = profile!("prod_a");
+    let workspace = workspace_with(
+        &tempdir,
+        app_name!("test_multi_profile_no_flow"),
+        &[
+            profile_dev_a.clone(),
+            profile_dev_b.clone(),
+            profile_prod_a.clone(),
+        ],
+        None,
+    )
+    .await?;
+
+    let output = NoOpOutput;
+    let cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, _>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile_filter_glob("dev_*")
+    .with_profile_filter_glob("*_?")
+    .build()
+    .await?;
+
+    let peace_app_dir = workspace.dirs().peace_app_dir();
+    let profile_dirs = [&profile_dev_a, &profile_dev_b]
+        .into_iter()
+        .map(|profile| (profile.clone(), ProfileDir::from((peace_app_dir, profile))))
+        .collect::<BTreeMap<_, _>>();
+    let profile_history_dirs = profile_dirs
+        .iter()
+        .map(|(profile, profile_dir)| (profile.clone(), ProfileHistoryDir::from(profile_dir)))
+        .collect::<BTreeMap<_, _>>();
+
+    let scope = cmd_ctx.scope();
+    assert_eq!(&[profile_dev_a, profile_dev_b], scope.profiles());
+    assert_eq!(&profile_dirs, scope.profile_dirs());
+    assert_eq!(&profile_history_dirs, scope.profile_history_dirs());
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_with_profile_sort() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let profile = profile!("test_profile");
+    let profile_other = profile!("test_profile_other");
+    let workspace = workspace_with(
+        &tempdir,
+        app_name!("test_multi_profile_no_flow"),
+        &[profile.clone(), profile_other.clone()],
+        None,
+    )
+    .await?;
+
+    let output = NoOpOutput;
+    let cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, _>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile_sort(|profile_a, profile_b| profile_b.cmp(profile_a))
+    .build()
+    .await?;
+
+    let scope = cmd_ctx.scope();
+    assert_eq!(&[profile_other, profile], scope.profiles());
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_with_profile_params_with_profile_filter_param_value(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let profile = profile!("test_profile");
+    let profile_other = profile!("test_profile_other");
+    let workspace = workspace_with(
+        &tempdir,
+        app_name!("test_multi_profile_no_flow"),
+        &[profile.clone(), profile_other.clone()],
+        None,
+    )
+    .await?;
+    profile_params_write(&workspace, &profile_other, 3).await?;
+
+    let output = NoOpOutput;
+    let cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, _>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile_params_k::<String>()
+    .with_profile_param::<u32>(String::from("profile_param_0"))
+    .with_profile_param::<u64>(String::from("profile_param_1"))
+    .with_profile_filter_param_value(String::from("profile_param_0"), 3u32)
+    .build()
+    .await?;
+
+    let scope = cmd_ctx.scope();
+    let profile_to_profile_params = scope.profile_to_profile_params();
+    assert_eq!(std::slice::from_ref(&profile_other), scope.profiles());
+    assert_eq!(
+        vec![&profile_other],
+        profile_to_profile_params.keys().collect::<Vec<_>>()
+    );
+    assert_eq!(1, scope.profile_dirs().len());
+    assert_eq!(1, scope.profile_history_dirs().len());
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_with_profile_params_with_profile_filter_params(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let profile = profile!("test_profile");
+    let profile_other = profile!("test_profile_other");
+    let workspace = workspace_with(
+        &tempdir,
+        app_name!("test_multi_profile_no_flow"),
+        &[profile.clone(), profile_other.clone()],
+        None,
+    )
+    .await?;
+    profile_params_write(&workspace, &profile_other, 3).await?;
+
+    let output = NoOpOutput;
+    let cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, _>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile_params_k::<String>()
+    .with_profile_param::<u32>(String::from("profile_param_0"))
+    .with_profile_param::<u64>(String::from("profile_param_1"))
+    .with_profile_filter_params(|_profile, profile_params| {
+        profile_params
+            .get::<u32, _>("profile_param_0")
+            .is_some_and(|profile_param_0| *profile_param_0 < 2)
+    })
+    .build()
+    .await?;
+
+    let scope = cmd_ctx.scope();
+    assert_eq!(&[profile], scope.profiles());
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_with_profile_params_with_profile_sort_by_param_value(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let profile_a = profile!("profile_a");
+    let profile_b = profile!("profile_b");
+    let profile_c = profile!("profile_c");
+    let workspace = workspace_with(
+        &tempdir,
+        app_name!("test_multi_profile_no_flow"),
+        &[profile_a.clone(), profile_b.clone(), profile_c.clone()],
+        None,
+    )
+    .await?;
+    profile_params_write(&workspace, &profile_a, 3).await?;
+    profile_params_write(&workspace, &profile_c, 0).await?;
+
+    let output = NoOpOutput;
+    let cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, _>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile_params_k::<String>()
+    .with_profile_param::<u32>(String::from("profile_param_0"))
+    .with_profile_param::<u64>(String::from("profile_param_1"))
+    .with_profile_sort_by_param_value::<u32>(String::from("profile_param_0"))
+    .build()
+    .await?;
+
+    let scope = cmd_ctx.scope();
+    assert_eq!(&[profile_c, profile_b, profile_a], scope.profiles());
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_with_profile_params_inherits_profile_params_from_profile_parent(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let profile_parent = profile!("test_profile_parent");
+    let profile = profile!("test_profile");
+    let workspace = workspace_with(
+        &tempdir,
+        app_name!("test_multi_profile_no_flow"),
+        &[profile.clone(), profile_parent.clone()],
+        None,
+    )
+    .await?;
+    profile_params_write(&workspace, &profile_parent, 3).await?;
+    let profile_dir = ProfileDir::from((workspace.dirs().peace_app_dir(), &profile));
+    tokio::fs::write(
+        ProfileParamsFile::from(&profile_dir),
+        "profile_param_1: 5\n",
+    )
+    .await?;
+    tokio::fs::write(
+        ProfileParentFile::from(&profile_dir),
+        format!("{profile_parent}\n"),
+    )
+    .await?;
+
+    let output = NoOpOutput;
+    let cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, _>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile_params_k::<String>()
+    .with_profile_param::<u32>(String::from("profile_param_0"))
+    .with_profile_param::<u64>(String::from("profile_param_1"))
+    .with_profile_filter_param_value(String::from("profile_param_0"), 3u32)
+    .build()
+    .await?;
+
+    let scope = cmd_ctx.scope();
+    let profile_params = scope
+        .profile_to_profile_params()
+        .get(&profile)
+        .expect("Expected profile params to exist for child profile.");
+    assert_eq!(&[profile.clone(), profile_parent], scope.profiles());
+    assert_eq!(Some(&3u32), profile_params.get("profile_param_0"));
+    assert_eq!(Some(&5u64), profile_params.get("profile_param_1"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_lists_profiles_from_storage_backend() -> Result<(), Box<dyn std::error::Error>> {
+    let workspace = Workspace::new(
+        app_name!("test_multi_profile_no_flow"),
+        WorkspaceSpec::InMemory(InMemoryStorageBackend::new()),
+    )?;
+    let profile = profile!("test_profile");
+    let profile_other = profile!("test_profile_other");
+    for profile in [&profile_other, &profile] {
+        CmdCtx::builder_single_profile_no_flow::<PeaceTestError, _>(
+            NoOpOutput.into(),
+            (&workspace).into(),
+        )
+        .with_profile_param_value(String::from("profile_param_0"), Some(1u32))
+        .with_profile(profile.clone())
+        .build()
+        .await?;
+    }
+
+    let output = NoOpOutput;
+    let cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, _>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .build()
+    .await?;
+
+    assert_eq!(&[profile, profile_other], cmd_ctx.scope().profiles());
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_lists_profiles_from_storage_backend_with_required_methods(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let workspace = Workspace::new(
+        app_name!("test_multi_profile_no_flow"),
+        WorkspaceSpec::InMemory(InMemoryStorageBackend::new()),
+    )?
+    .with_storage_backend(RequiredMethodsStorageBackend::default());
+    let profile = profile!("test_profile");
+    let profile_other = profile!("test_profile_other");
+    for profile in [&profile_other, &profile] {
+        CmdCtx::builder_single_profile_no_flow::<PeaceTestError, _>(
+            NoOpOutput.into(),
+            (&workspace).into(),
+        )
+        .with_profile_param_value(String::from("profile_param_0"), Some(1u32))
+        .with_profile(profile.clone())
+        .build()
+        .await?;
+    }
+
+    let output = NoOpOutput;
+    let cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, _>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .build()
+    .await?;
+
+    assert_eq!(&[profile, profile_other], cmd_ctx.scope().profiles());
+    Ok(())
+}
+
 #[tokio::test]
 async fn list_profile_dirs_invalid_profile_name() -> Result<(), Box<dyn std::error::Error>> {
     let tempdir = tempfile::tempdir()?;
@@ -480,3 +783,47 @@ async fn debug() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+/// Overwrites the profile params for the profile, with `profile_param_0` set to
+/// the given value.
+async fn profile_params_write(
+    workspace: &Workspace,
+    profile: &Profile,
+    profile_param_0: u32,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let profile_dir = ProfileDir::from((workspace.dirs().peace_app_dir(), profile));
+    let profile_params_file = ProfileParamsFile::from(&profile_dir);
+    tokio::fs::write(
+        &profile_params_file,
+        format!("profile_param_0: {profile_param_0}\nprofile_param_1: 2\n"),
+    )
+    .await?;
+    Ok(())
+}
+
+/// Implements only the required `StorageBackend` methods.
+#[derive(Clone, Debug, Default)]
+struct RequiredMethodsStorageBackend(InMemoryStorageBackend);
+
+#[async_trait(?Send)]
+impl StorageBackend for RequiredMethodsStorageBackend {
+    async fn contains_item(&self, path: &Path) -> Result<bool, PeaceRtError> {
+        self.0.contains_item(path).await
+    }
+
+    async fn read_opt(&self, path: &Path) -> Result<Option<Vec<u8>>, PeaceRtError> {
+        self.0.read_opt(path).await
+    }
+
+    async fn write(&self, path: &Path, bytes: &[u8]) -> Result<(), PeaceRtError> {
+        self.0.write(path, bytes).await
+    }
+
+    async fn remove(&self, path: &Path) -> Result<(), PeaceRtError> {
+        self.0.remove(path).await
+    }
+
+    async fn paths_under(&self, dir: &Path) -> Result<Vec<PathBuf>, PeaceRtError> {
+        self.0.paths_under(dir).await
+    }
+}
@@ -495,6 +495,55 @@ async fn build_with_workspace_params_with_profile_filter() -> Result<(), Box<dyn
     Ok(())
 }
 
+#[tokio::test]
+async fn build_with_profile_filter_glob_with_profile_sort() -> Result<(), Box<dyn std::error::Error>>
+{
+    let tempdir = tempfile::tempdir()?;
+    let profile_dev_a = profile!("dev_a");
+    let profile_dev_b = profile!("dev_b");
+    let profile_prod_a = profile!("prod_a");
+    let flow_id = flow_id!("test_flow_id");
+    let flow = Flow::<PeaceTestError>::new(flow_id, ItemGraphBuilder::new().build());
+    let workspace = workspace_with(
+        &tempdir,
+        app_name!("test_multi_profile_single_flow"),
+        &[
+            profile_dev_a.clone(),
+            profile_dev_b.clone(),
+            profile_prod_a.clone(),
+        ],
+        Some(flow.flow_id()),
+    )
+    .await?;
+
+    let output = NoOpOutput;
+    let cmd_ctx = CmdCtx::builder_multi_profile_single_flow(output.into(), (&workspace).into())
+        .with_profile_filter_glob("dev_*")
+        .with_profile_sort(|profile_a, profile_b| profile_b.cmp(profile_a))
+        .with_flow((&flow).into())
+        .build()
+        .await?;
+
+    let peace_app_dir = workspace.dirs().peace_app_dir();
+    let flow_dirs = [&profile_dev_a, &profile_dev_b]
+        .into_iter()
+        .map(|profile| {
+            let profile_dir = ProfileDir::from((peace_app_dir, profile));
+            (
+                profile.clone(),
+                FlowDir::from((&profile_dir, flow.flow_id())),
+            )
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    let scope = cmd_ctx.scope();
+    assert_eq!(&[profile_dev_b, profile_dev_a], scope.profiles());
+    assert_eq!(2, scope.profile_dirs().len());
+    assert_eq!(2, scope.profile_history_dirs().len());
+    assert_eq!(&flow_dirs, scope.flow_dirs());
+    Ok(())
+}
+
 #[tokio::test]
 async fn build_with_workspace_params_with_profile_params_with_profile_filter(
 ) -> Result<(), Box<dyn std::error::Error>> {
@@ -1,7 +1,11 @@
 use peace::{
-    cfg::{app_name, profile},
+    cfg::{app_name, profile, Profile},
     cmd::ctx::CmdCtx,
-    resource_rt::paths::{ProfileDir, ProfileHistoryDir},
+    resource_rt::{
+        internal::ProfileParamsFile,
+        paths::{ProfileDir, ProfileHistoryDir, ProfileParentFile},
+    },
+    rt_model::{Error as PeaceRtError, Workspace},
 };
 
 use crate::{no_op_output::NoOpOutput, test_support::workspace, PeaceTestError};
@@ -235,3 +239,190 @@ async fn build_with_workspace_params_with_profile_params_with_profile_from_param
     assert_eq!(Some(&2u64), profile_params.get("profile_param_1"));
     Ok(())
 }
+
+#[tokio::test]
+async fn build_with_profile_params_inherits_profile_params_from_profile_parent(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_no_flow"))?;
+    let profile_parent = profile!("test_profile_parent");
+    let profile = profile!("test_profile");
+
+    let output = NoOpOutput;
+    let _cmd_ctx_parent = CmdCtx::builder_single_profile_no_flow::<PeaceTestError, _>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile_param_value(String::from("profile_param_0"), Some(1u32))
+    .with_profile_param_value(String::from("profile_param_1"), Some(2u64))
+    .with_profile(profile_parent.clone())
+    .build()
+    .await?;
+    profile_parent_write(&workspace, &profile, &profile_parent).await?;
+
+    let output = NoOpOutput;
+    let cmd_ctx = CmdCtx::builder_single_profile_no_flow::<PeaceTestError, _>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile_param_value(String::from("profile_param_1"), Some(3u64))
+    .with_profile_param::<u32>(String::from("profile_param_0"))
+    .with_profile(profile.clone())
+    .build()
+    .await?;
+
+    let scope = cmd_ctx.scope();
+    let profile_params = scope.profile_params();
+    let profile_params_origins = scope.profile_params_origins();
+    assert_eq!(Some(&1u32), profile_params.get("profile_param_0"));
+    assert_eq!(Some(&3u64), profile_params.get("profile_param_1"));
+    assert_eq!(
+        Some(&profile_parent),
+        profile_params_origins.get("profile_param_0")
+    );
+    assert_eq!(
+        Some(&profile),
+        profile_params_origins.get("profile_param_1")
+    );
+
+    // Inherited values are not stored in the child profile.
+    let profile_dir = ProfileDir::from((workspace.dirs().peace_app_dir(), &profile));
+    let profile_params_file = ProfileParamsFile::from(&profile_dir);
+    let profile_params_contents = tokio::fs::read_to_string(&profile_params_file).await?;
+    assert!(!profile_params_contents.contains("profile_param_0"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_with_profile_params_returns_err_when_profile_parent_not_found(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_no_flow"))?;
+    let profile_parent = profile!("test_profile_parent");
+    let profile = profile!("test_profile");
+    profile_parent_write(&workspace, &profile, &profile_parent).await?;
+
+    let output = NoOpOutput;
+    let cmd_ctx_result = CmdCtx::builder_single_profile_no_flow::<PeaceTestError, _>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile_param_value(String::from("profile_param_0"), Some(1u32))
+    .with_profile(profile.clone())
+    .build()
+    .await;
+
+    ({
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        || {
+            assert!(
+                matches!(
+                    &cmd_ctx_result,
+                    Err(PeaceTestError::PeaceRt(PeaceRtError::ProfileParentNotFound {
+                        profile: profile_actual,
+                        profile_parent: profile_parent_actual,
+                    }))
+                    if profile_actual == &profile
+                    && profile_parent_actual == &profile_parent
+                ),
+                "was {cmd_ctx_result:#?}"
+            );
+        }
+    })();
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_with_profile_params_returns_err_when_profile_parent_cycle(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_no_flow"))?;
+    let profile_a = profile!("profile_a");
+    let profile_b = profile!("profile_b");
+    profile_parent_write(&workspace, &profile_a, &profile_b).await?;
+    profile_parent_write(&workspace, &profile_b, &profile_a).await?;
+
+    let output = NoOpOutput;
+    let cmd_ctx_result = CmdCtx::builder_single_profile_no_flow::<PeaceTestError, _>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile_param_value(String::from("profile_param_0"), Some(1u32))
+    .with_profile(profile_a.clone())
+    .build()
+    .await;
+
+    ({
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        || {
+            assert!(
+                matches!(
+                    &cmd_ctx_result,
+                    Err(PeaceTestError::PeaceRt(PeaceRtError::ProfileParentCycle {
+                        profile,
+                        profiles_cycle,
+                    }))
+                    if profile == &profile_a
+                    && profiles_cycle == &[profile_a.clone(), profile_b.clone(), profile_a.clone()]
+                ),
+                "was {cmd_ctx_result:#?}"
+            );
+        }
+    })();
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_with_profile_params_returns_err_with_only_cycle_when_profile_parent_cycle_excludes_profile(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_no_flow"))?;
+    let profile_a = profile!("profile_a");
+    let profile_b = profile!("profile_b");
+    let profile_c = profile!("profile_c");
+    profile_parent_write(&workspace, &profile_a, &profile_b).await?;
+    profile_parent_write(&workspace, &profile_b, &profile_c).await?;
+    profile_parent_write(&workspace, &profile_c, &profile_b).await?;
+
+    let output = NoOpOutput;
+    let cmd_ctx_result = CmdCtx::builder_single_profile_no_flow::<PeaceTestError, _>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile_param_value(String::from("profile_param_0"), Some(1u32))
+    .with_profile(profile_a.clone())
+    .build()
+    .await;
+
+    ({
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        || {
+            assert!(
+                matches!(
+                    &cmd_ctx_result,
+                    Err(PeaceTestError::PeaceRt(PeaceRtError::ProfileParentCycle {
+                        profile,
+                        profiles_cycle,
+                    }))
+                    if profile == &profile_a
+                    && profiles_cycle == &[profile_b.clone(), profile_c.clone(), profile_b.clone()]
+                ),
+                "was {cmd_ctx_result:#?}"
+            );
+        }
+    })();
+    Ok(())
+}
+
+/// Writes `profile_parent` as the parent of `profile`.
+async fn profile_parent_write(
+    workspace: &Workspace,
+    profile: &Profile,
+    profile_parent: &Profile,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let profile_dir = ProfileDir::from((workspace.dirs().peace_app_dir(), profile));
+    tokio::fs::create_dir_all(&profile_dir).await?;
+    let profile_parent_file = ProfileParentFile::from(&profile_dir);
+    tokio::fs::write(&profile_parent_file, format!("{profile_parent}\n")).await?;
+    Ok(())
+}
@@ -1,14 +1,20 @@
 use peace::{
     cfg::{app_name, flow_id, item_id, profile, Item, Profile},
     cmd::ctx::CmdCtx,
-    params::{Params, ParamsSpec, ValueResolutionCtx, ValueResolutionMode, ValueSpec},
+    params::{
+        MappingFnBindError, MappingFnName, MappingFnReg, Params, ParamsSpec, ParamsSpecOrigin,
+        ParamsSpecsOrigins, ParamsSpecsProfileOrigins, ValueResolutionCtx, ValueResolutionMode,
+        ValueSpec,
+    },
     resource_rt::{
-        paths::{FlowDir, ProfileDir, ProfileHistoryDir},
+        paths::{FlowDir, ParamsSpecsFile, ProfileDir, ProfileHistoryDir, ProfileParentFile},
         type_reg::untagged::BoxDataTypeDowncast,
     },
     rt_model::{Flow, ItemGraphBuilder},
 };
 
+use peace_items::blank::{BlankDest, BlankItem, BlankParams, BlankSrc};
+
 use crate::{
     no_op_output::NoOpOutput,
     test_support::{assert_flow_params, assert_profile_params, assert_workspace_params, workspace},
@@ -1029,6 +1035,242 @@ async fn build_with_item_params_returns_err_when_value_spec_not_provided_for_pre
     Ok(())
 }
 
+#[tokio::test]
+async fn build_with_item_params_returns_ok_when_spec_not_provided_for_previous_named_mapping_fn(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_single_flow"))?;
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow_id");
+    let flow = {
+        let item_graph = {
+            let mut item_graph_builder = ItemGraphBuilder::new();
+            item_graph_builder.add_fn(VecCopyItem::new(VecCopyItem::ID_DEFAULT.clone()).into());
+            item_graph_builder.build()
+        };
+        let mut mapping_fn_reg = MappingFnReg::new();
+        mapping_fn_reg.register("vec_from_u8", |n: &u8| Some(vec![*n]));
+        Flow::<PeaceTestError>::new(flow_id, item_graph).with_mapping_fn_reg(mapping_fn_reg)
+    };
+
+    let mut output = NoOpOutput;
+    let _cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        (&mut output).into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile.clone())
+    .with_flow((&flow).into())
+    .with_resource(1u8)
+    .with_item_params::<VecCopyItem>(
+        VecCopyItem::ID_DEFAULT.clone(),
+        VecA::field_wise_spec()
+            .with_0_from_map_named("vec_from_u8")
+            .build(),
+    )
+    .build()
+    .await?;
+
+    let cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        (&mut output).into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile.clone())
+    .with_flow((&flow).into())
+    .with_resource(1u8)
+    // Note: no item_params for `VecCopyItem`
+    .build()
+    .await?;
+
+    let scope = cmd_ctx.scope();
+    let params_specs = scope.params_specs();
+    let resources = scope.resources();
+    let vec_a_spec = params_specs
+        .get::<ParamsSpec<<VecCopyItem as Item>::Params<'_>>, _>(VecCopyItem::ID_DEFAULT);
+    let mut value_resolution_ctx = ValueResolutionCtx::new(
+        ValueResolutionMode::Current,
+        VecCopyItem::ID_DEFAULT.clone(),
+        tynm::type_name::<VecA>(),
+    );
+    ({
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        || {
+            assert!(
+                matches!(vec_a_spec,
+                    Some(ParamsSpec::FieldWise {
+                        field_wise_spec: VecAFieldWise(ValueSpec::<Vec<u8>>::MappingFn(mapping_fn)),
+                    })
+                    if mapping_fn.is_valued()
+                    && mapping_fn.fn_name().map(MappingFnName::as_str) == Some("vec_from_u8")
+                ),
+                "was {vec_a_spec:?}"
+            );
+        }
+    })();
+    assert_eq!(
+        Some(VecA(vec![1u8])),
+        vec_a_spec.and_then(|vec_a_spec| vec_a_spec
+            .resolve(resources, &mut value_resolution_ctx)
+            .ok()),
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_with_item_params_returns_err_when_stored_mapping_fn_name_not_registered(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_single_flow"))?;
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow_id");
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::new();
+        item_graph_builder.add_fn(VecCopyItem::new(VecCopyItem::ID_DEFAULT.clone()).into());
+        item_graph_builder.build()
+    };
+    let flow = {
+        let mut mapping_fn_reg = MappingFnReg::new();
+        mapping_fn_reg.register("vec_from_u8", |n: &u8| Some(vec![*n]));
+        Flow::<PeaceTestError>::new(flow_id.clone(), item_graph.clone())
+            .with_mapping_fn_reg(mapping_fn_reg)
+    };
+
+    let mut output = NoOpOutput;
+    let _cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        (&mut output).into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile.clone())
+    .with_flow((&flow).into())
+    .with_resource(1u8)
+    .with_item_params::<VecCopyItem>(
+        VecCopyItem::ID_DEFAULT.clone(),
+        VecA::field_wise_spec()
+            .with_0_from_map_named("vec_from_u8")
+            .build(),
+    )
+    .build()
+    .await?;
+
+    // Mapping function is no longer registered.
+    let flow = Flow::<PeaceTestError>::new(flow_id, item_graph);
+    let cmd_ctx_result = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        (&mut output).into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile.clone())
+    .with_flow((&flow).into())
+    .build()
+    .await;
+
+    ({
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        || {
+            assert!(
+                matches!(
+                    &cmd_ctx_result,
+                    Err(PeaceTestError::PeaceRt(
+                        peace::rt_model::Error::MappingFnNamesUnknown {
+                            flow_id: _,
+                            mapping_fn_names_unknown,
+                            mapping_fn_names_registered,
+                        }
+                    ))
+                    if mapping_fn_names_unknown == &[MappingFnName::from("vec_from_u8")]
+                    && mapping_fn_names_registered.is_empty()
+                ),
+                "was {cmd_ctx_result:#?}"
+            );
+        }
+    })();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_with_item_params_returns_err_when_stored_mapping_fn_output_type_differs(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_single_flow"))?;
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow_id");
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::new();
+        item_graph_builder.add_fn(VecCopyItem::new(VecCopyItem::ID_DEFAULT.clone()).into());
+        item_graph_builder.build()
+    };
+    let flow = {
+        let mut mapping_fn_reg = MappingFnReg::new();
+        mapping_fn_reg.register("vec_from_u8", |n: &u8| Some(vec![*n]));
+        Flow::<PeaceTestError>::new(flow_id.clone(), item_graph.clone())
+            .with_mapping_fn_reg(mapping_fn_reg)
+    };
+
+    let mut output = NoOpOutput;
+    let _cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        (&mut output).into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile.clone())
+    .with_flow((&flow).into())
+    .with_resource(1u8)
+    .with_item_params::<VecCopyItem>(
+        VecCopyItem::ID_DEFAULT.clone(),
+        VecA::field_wise_spec()
+            .with_0_from_map_named("vec_from_u8")
+            .build(),
+    )
+    .build()
+    .await?;
+
+    // Mapping function is registered under the same name with a different output
+    // type.
+    let flow = {
+        let mut mapping_fn_reg = MappingFnReg::new();
+        mapping_fn_reg.register("vec_from_u8", |n: &u8| Some(*n));
+        Flow::<PeaceTestError>::new(flow_id, item_graph).with_mapping_fn_reg(mapping_fn_reg)
+    };
+    let cmd_ctx_result = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        (&mut output).into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile.clone())
+    .with_flow((&flow).into())
+    .build()
+    .await;
+
+    ({
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        || {
+            assert!(
+                matches!(
+                    &cmd_ctx_result,
+                    Err(PeaceTestError::PeaceRt(
+                        peace::rt_model::Error::MappingFnOutputTypeMismatch {
+                            flow_id: _,
+                            mapping_fn_output_type_mismatches,
+                        }
+                    ))
+                    if matches!(
+                        mapping_fn_output_type_mismatches.as_slice(),
+                        [MappingFnBindError::OutputTypeMismatch {
+                            mapping_fn_name,
+                            output_type_expected,
+                            output_type_registered,
+                        }]
+                        if mapping_fn_name == &MappingFnName::from("vec_from_u8")
+                        && output_type_expected == "Vec<u8>"
+                        && output_type_registered == "u8"
+                    )
+                ),
+                "was {cmd_ctx_result:#?}"
+            );
+        }
+    })();
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn build_with_item_params_returns_params_specs_mismatch_err_when_item_renamed(
 ) -> Result<(), Box<dyn std::error::Error>> {
@@ -1175,3 +1417,515 @@ async fn build_with_item_params_returns_ok_when_new_item_added_with_params_provi
 
     Ok(())
 }
+
+#[tokio::test]
+async fn build_with_item_params_inherits_params_specs_from_profile_parent(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_single_flow"))?;
+    let profile_parent = profile!("test_profile_parent");
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow_id");
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::new();
+        item_graph_builder.add_fn(VecCopyItem::default().into());
+        item_graph_builder.build()
+    };
+    let flow = Flow::<PeaceTestError>::new(flow_id.clone(), item_graph);
+
+    let mut output = NoOpOutput;
+    let _cmd_ctx_parent = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        (&mut output).into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile_parent.clone())
+    .with_flow((&flow).into())
+    .with_item_params::<VecCopyItem>(VecCopyItem::ID_DEFAULT.clone(), VecA(vec![1u8]).into())
+    .build()
+    .await?;
+
+    let profile_dir = ProfileDir::from((workspace.dirs().peace_app_dir(), &profile));
+    tokio::fs::create_dir_all(&profile_dir).await?;
+    tokio::fs::write(
+        ProfileParentFile::from(&profile_dir),
+        format!("{profile_parent}\n"),
+    )
+    .await?;
+
+    let cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        (&mut output).into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile.clone())
+    .with_flow((&flow).into())
+    .build()
+    .await?;
+
+    let scope = cmd_ctx.scope();
+    let params_specs = scope.params_specs();
+    let resources = scope.resources();
+    let vec_a_spec = params_specs
+        .get::<ParamsSpec<<VecCopyItem as Item>::Params<'_>>, _>(VecCopyItem::ID_DEFAULT);
+    assert!(matches!(vec_a_spec,
+        Some(ParamsSpec::Value { value: VecA(value) })
+        if value == &[1u8]
+    ));
+    let params_specs_profile_origins = resources.borrow::<ParamsSpecsProfileOrigins>();
+    assert_eq!(
+        Some(&profile_parent),
+        params_specs_profile_origins.get(VecCopyItem::ID_DEFAULT)
+    );
+
+    // Inherited params specs are not stored in the child profile.
+    let flow_dir = FlowDir::from((&profile_dir, &flow_id));
+    let params_specs_contents = tokio::fs::read_to_string(ParamsSpecsFile::from(&flow_dir)).await?;
+    assert!(!params_specs_contents.contains(VecCopyItem::ID_DEFAULT.as_str()));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_with_params_specs_override_file_uses_override_params_specs(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_single_flow"))?;
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow_id");
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::new();
+        item_graph_builder.add_fn(VecCopyItem::default().into());
+        item_graph_builder.build()
+    };
+    let flow = Flow::<PeaceTestError>::new(flow_id, item_graph);
+    let params_specs_override_path = tempdir.path().join("params_override.yaml");
+    tokio::fs::write(
+        &params_specs_override_path,
+        "# Overrides for the `dev` environment.\n\
+        vec_copy: !Value\n  value:\n  - 2\n",
+    )
+    .await?;
+
+    let mut output = NoOpOutput;
+    let cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        (&mut output).into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile.clone())
+    .with_flow((&flow).into())
+    .with_item_params::<VecCopyItem>(VecCopyItem::ID_DEFAULT.clone(), VecA(vec![1u8]).into())
+    .with_params_specs_override_file(&params_specs_override_path)
+    .build()
+    .await?;
+
+    let scope = cmd_ctx.scope();
+    let params_specs = scope.params_specs();
+    let resources = scope.resources();
+    let vec_a_spec = params_specs
+        .get::<ParamsSpec<<VecCopyItem as Item>::Params<'_>>, _>(VecCopyItem::ID_DEFAULT);
+    assert!(matches!(vec_a_spec,
+        Some(ParamsSpec::Value { value: VecA(value) })
+        if value == &[2u8]
+    ));
+    let params_specs_origins = resources.borrow::<ParamsSpecsOrigins>();
+    assert_eq!(
+        Some(&ParamsSpecOrigin::new(params_specs_override_path, 2, 1)),
+        params_specs_origins.origin(VecCopyItem::ID_DEFAULT, None)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_with_params_specs_override_file_does_not_store_override_params_specs(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_single_flow"))?;
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow_id");
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::new();
+        item_graph_builder.add_fn(VecCopyItem::default().into());
+        item_graph_builder.build()
+    };
+    let flow = Flow::<PeaceTestError>::new(flow_id, item_graph);
+    let params_specs_override_path = tempdir.path().join("params_override.yaml");
+    tokio::fs::write(
+        &params_specs_override_path,
+        "vec_copy: !Value\n  value:\n  - 2\n",
+    )
+    .await?;
+
+    let mut output = NoOpOutput;
+    let _cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        (&mut output).into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile.clone())
+    .with_flow((&flow).into())
+    .with_item_params::<VecCopyItem>(VecCopyItem::ID_DEFAULT.clone(), VecA(vec![1u8]).into())
+    .with_params_specs_override_file(&params_specs_override_path)
+    .build()
+    .await?;
+
+    let cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        (&mut output).into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile.clone())
+    .with_flow((&flow).into())
+    .build()
+    .await?;
+
+    let scope = cmd_ctx.scope();
+    let params_specs = scope.params_specs();
+    let vec_a_spec = params_specs
+        .get::<ParamsSpec<<VecCopyItem as Item>::Params<'_>>, _>(VecCopyItem::ID_DEFAULT);
+    assert!(matches!(vec_a_spec,
+        Some(ParamsSpec::Value { value: VecA(value) })
+        if value == &[1u8]
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_with_params_specs_override_files_merges_files_in_order(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_single_flow"))?;
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow_id");
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::new();
+        item_graph_builder.add_fn(VecCopyItem::default().into());
+        item_graph_builder.build()
+    };
+    let flow = Flow::<PeaceTestError>::new(flow_id, item_graph);
+    let params_specs_override_yaml_path = tempdir.path().join("params_override.yaml");
+    tokio::fs::write(
+        &params_specs_override_yaml_path,
+        "vec_copy: !Value\n  value:\n  - 2\n",
+    )
+    .await?;
+    let params_specs_override_json_path = tempdir.path().join("params_override.json");
+    tokio::fs::write(
+        &params_specs_override_json_path,
+        "{\n  \"vec_copy\": { \"Value\": { \"value\": [3] } }\n}\n",
+    )
+    .await?;
+
+    let mut output = NoOpOutput;
+    let cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        (&mut output).into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile.clone())
+    .with_flow((&flow).into())
+    .with_item_params::<VecCopyItem>(VecCopyItem::ID_DEFAULT.clone(), VecA(vec![1u8]).into())
+    .with_params_specs_override_file(&params_specs_override_yaml_path)
+    .with_params_specs_override_file(&params_specs_override_json_path)
+    .build()
+    .await?;
+
+    let scope = cmd_ctx.scope();
+    let params_specs = scope.params_specs();
+    let resources = scope.resources();
+    let vec_a_spec = params_specs
+        .get::<ParamsSpec<<VecCopyItem as Item>::Params<'_>>, _>(VecCopyItem::ID_DEFAULT);
+    assert!(matches!(vec_a_spec,
+        Some(ParamsSpec::Value { value: VecA(value) })
+        if value == &[3u8]
+    ));
+    let params_specs_origins = resources.borrow::<ParamsSpecsOrigins>();
+    assert_eq!(
+        Some(&ParamsSpecOrigin::new(
+            params_specs_override_json_path,
+            2,
+            3
+        )),
+        params_specs_origins.origin(VecCopyItem::ID_DEFAULT, None)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_with_params_specs_override_files_merges_fields_across_files(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_single_flow"))?;
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow_id");
+    let item_id = item_id!("blank");
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::new();
+        item_graph_builder.add_fn(BlankItem::<()>::new(item_id.clone()).into());
+        item_graph_builder.build()
+    };
+    let flow = Flow::<PeaceTestError>::new(flow_id, item_graph);
+    let params_specs_override_yaml_path = tempdir.path().join("params_override.yaml");
+    tokio::fs::write(
+        &params_specs_override_yaml_path,
+        "blank: !FieldWise\n\
+        \x20 field_wise_spec:\n\
+        \x20   src: !Value\n\
+        \x20     value: 1\n\
+        \x20   dest: !Value\n\
+        \x20     value: 2\n\
+        \x20   marker: null\n",
+    )
+    .await?;
+    let params_specs_override_json_path = tempdir.path().join("params_override.json");
+    tokio::fs::write(
+        &params_specs_override_json_path,
+        "{\n\
+        \x20 \"blank\": {\n\
+        \x20   \"FieldWise\": {\n\
+        \x20     \"field_wise_spec\": {\n\
+        \x20       \"src\": { \"Value\": { \"value\": 3 } },\n\
+        \x20       \"dest\": \"Stored\",\n\
+        \x20       \"marker\": null\n\
+        \x20     }\n\
+        \x20   }\n\
+        \x20 }\n\
+        }\n",
+    )
+    .await?;
+
+    let mut output = NoOpOutput;
+    let cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        (&mut output).into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile.clone())
+    .with_flow((&flow).into())
+    // `BlankItem`'s data borrows its params, which is needed when the
+    // `item_state_example` feature computes example states during `build`.
+    .with_resource(BlankParams::<()>::new(BlankSrc(0), BlankDest(None)))
+    .with_params_specs_override_file(&params_specs_override_yaml_path)
+    .with_params_specs_override_file(&params_specs_override_json_path)
+    .build()
+    .await?;
+
+    let scope = cmd_ctx.scope();
+    let params_specs = scope.params_specs();
+    let resources = scope.resources();
+    let mut value_resolution_ctx = ValueResolutionCtx::new(
+        ValueResolutionMode::Current,
+        item_id.clone(),
+        tynm::type_name::<BlankParams<()>>(),
+    );
+    let blank_params = params_specs
+        .get::<ParamsSpec<BlankParams<()>>, _>(&item_id)
+        .map(|blank_params_spec| blank_params_spec.resolve(resources, &mut value_resolution_ctx))
+        .transpose()?;
+    assert_eq!(
+        Some(BlankParams::<()>::new(BlankSrc(3), BlankDest(Some(2)))),
+        blank_params
+    );
+    let params_specs_origins = resources.borrow::<ParamsSpecsOrigins>();
+    assert_eq!(
+        Some(&ParamsSpecOrigin::new(
+            params_specs_override_json_path.clone(),
+            5,
+            9
+        )),
+        params_specs_origins.origin(&item_id, Some("src"))
+    );
+    assert_eq!(
+        Some(&ParamsSpecOrigin::new(
+            params_specs_override_yaml_path.clone(),
+            5,
+            5
+        )),
+        params_specs_origins.origin(&item_id, Some("dest"))
+    );
+    assert_eq!(None, params_specs_origins.origin(&item_id, None));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_with_params_specs_override_file_records_origins_of_same_name_keys(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_single_flow"))?;
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow_id");
+    let item_id_blank = item_id!("blank");
+    let item_id_dest = item_id!("dest");
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::new();
+        item_graph_builder.add_fn(BlankItem::<()>::new(item_id_blank.clone()).into());
+        item_graph_builder.add_fn(BlankItem::<()>::new(item_id_dest.clone()).into());
+        item_graph_builder.build()
+    };
+    let flow = Flow::<PeaceTestError>::new(flow_id, item_graph);
+    let params_specs_override_path = tempdir.path().join("params_override.yaml");
+    tokio::fs::write(
+        &params_specs_override_path,
+        "blank: !FieldWise\n\
+        \x20 field_wise_spec: {src: !Value {value: 1}, dest: !Value {value: 2}, marker: null}\n\
+        dest: !FieldWise\n\
+        \x20 field_wise_spec:\n\
+        \x20   src: !Value\n\
+        \x20     value: 3\n\
+        \x20   dest: !Value\n\
+        \x20     value: 4\n\
+        \x20   marker: null\n",
+    )
+    .await?;
+
+    let mut output = NoOpOutput;
+    let cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        (&mut output).into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile.clone())
+    .with_flow((&flow).into())
+    // `BlankItem`'s data borrows its params, which is needed when the
+    // `item_state_example` feature computes example states during `build`.
+    .with_resource(BlankParams::<()>::new(BlankSrc(0), BlankDest(None)))
+    .with_params_specs_override_file(&params_specs_override_path)
+    .build()
+    .await?;
+
+    let resources = cmd_ctx.scope().resources();
+    let params_specs_origins = resources.borrow::<ParamsSpecsOrigins>();
+    let origin =
+        |line, column| ParamsSpecOrigin::new(params_specs_override_path.clone(), line, column);
+    assert_eq!(
+        Some(&origin(2, 21)),
+        params_specs_origins.origin(&item_id_blank, Some("src"))
+    );
+    assert_eq!(
+        Some(&origin(2, 45)),
+        params_specs_origins.origin(&item_id_blank, Some("dest"))
+    );
+    assert_eq!(
+        Some(&origin(5, 5)),
+        params_specs_origins.origin(&item_id_dest, Some("src"))
+    );
+    assert_eq!(
+        Some(&origin(7, 5)),
+        params_specs_origins.origin(&item_id_dest, Some("dest"))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_with_params_specs_override_file_records_origin_columns_in_single_line_json(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_single_flow"))?;
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow_id");
+    let item_id = item_id!("blank");
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::new();
+        item_graph_builder.add_fn(BlankItem::<()>::new(item_id.clone()).into());
+        item_graph_builder.add_fn(VecCopyItem::default().into());
+        item_graph_builder.build()
+    };
+    let flow = Flow::<PeaceTestError>::new(flow_id, item_graph);
+    let params_specs_override_path = tempdir.path().join("params_override.json");
+    tokio::fs::write(
+        &params_specs_override_path,
+        "{\"blank\": {\"FieldWise\": {\"field_wise_spec\": {\
+        \"src\": {\"Value\": {\"value\": 1}}, \
+        \"dest\": {\"Value\": {\"value\": 2}}, \
+        \"marker\": null}}}, \
+        \"vec_copy\": {\"Value\": {\"value\": [2]}}}",
+    )
+    .await?;
+
+    let mut output = NoOpOutput;
+    let cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        (&mut output).into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile.clone())
+    .with_flow((&flow).into())
+    // `BlankItem`'s data borrows its params, which is needed when the
+    // `item_state_example` feature computes example states during `build`.
+    .with_resource(BlankParams::<()>::new(BlankSrc(0), BlankDest(None)))
+    .with_params_specs_override_file(&params_specs_override_path)
+    .build()
+    .await?;
+
+    let resources = cmd_ctx.scope().resources();
+    let params_specs_origins = resources.borrow::<ParamsSpecsOrigins>();
+    let origin =
+        |line, column| ParamsSpecOrigin::new(params_specs_override_path.clone(), line, column);
+    assert_eq!(
+        Some(&origin(1, 46)),
+        params_specs_origins.origin(&item_id, Some("src"))
+    );
+    assert_eq!(
+        Some(&origin(1, 78)),
+        params_specs_origins.origin(&item_id, Some("dest"))
+    );
+    assert_eq!(
+        Some(&origin(1, 130)),
+        params_specs_origins.origin(VecCopyItem::ID_DEFAULT, None)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_with_params_specs_override_file_returns_err_when_item_id_not_in_flow(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace(&tempdir, app_name!("test_single_profile_single_flow"))?;
+    let profile = profile!("test_profile");
+    let flow_id = flow_id!("test_flow_id");
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::new();
+        item_graph_builder.add_fn(VecCopyItem::default().into());
+        item_graph_builder.build()
+    };
+    let flow = Flow::<PeaceTestError>::new(flow_id, item_graph);
+    let params_specs_override_path = tempdir.path().join("params_override.yaml");
+    tokio::fs::write(
+        &params_specs_override_path,
+        "mismatch_id: !Value\n  value:\n  - 2\n",
+    )
+    .await?;
+
+    let mut output = NoOpOutput;
+    let cmd_ctx_result = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        (&mut output).into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile.clone())
+    .with_flow((&flow).into())
+    .with_item_params::<VecCopyItem>(VecCopyItem::ID_DEFAULT.clone(), VecA(vec![1u8]).into())
+    .with_params_specs_override_file(&params_specs_override_path)
+    .build()
+    .await;
+
+    ({
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        || {
+            assert!(
+                matches!(
+                    &cmd_ctx_result,
+                    Err(PeaceTestError::PeaceRt(
+                        peace::rt_model::Error::ParamsSpecsOverrideDeserialize {
+                            params_specs_override_path: path,
+                            error,
+                            ..
+                        }
+                    ))
+                    if path == &params_specs_override_path
+                    && error.to_string().contains("mismatch_id")
+                ),
+                "was {cmd_ctx_result:#?}"
+            );
+        }
+    })();
+
+    Ok(())
+}
@@ -85,7 +85,7 @@ CmdBlocks:
     Outcome: States<Goal>
   - DiffCmdBlock:
     Input: (States<Current>, States<Goal>)
-    Outcome: (StateDiffs, States<Current>, States<Goal>)
+    Outcome: (StateDiffs, StatesDiffTree, States<Current>, States<Goal>)
 "#,
                     cmd_execution_src
                 );
@@ -174,7 +174,7 @@ CmdBlocks:
     Outcome: States<Current>
   - DiffCmdBlock:
     Input: (States<Current>, States<Goal>)
-    Outcome: (StateDiffs, States<Current>, States<Goal>)
+    Outcome: (StateDiffs, StatesDiffTree, States<Current>, States<Goal>)
 "#,
                     cmd_execution_src
                 );
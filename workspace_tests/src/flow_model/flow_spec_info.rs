@@ -62,6 +62,26 @@ fn to_progress_info_graph() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn item_depths() -> Result<(), Box<dyn std::error::Error>> {
+    let flow_spec_info = flow_spec_info()?;
+
+    let item_depths = flow_spec_info.item_depths();
+
+    assert_eq!(
+        vec![
+            (item_id!("a"), 0),
+            (item_id!("b"), 1),
+            (item_id!("c"), 1),
+            (item_id!("d"), 2),
+            (item_id!("e"), 3),
+            (item_id!("f"), 0),
+        ],
+        item_depths
+    );
+    Ok(())
+}
+
 #[test]
 fn clone() -> Result<(), Box<dyn std::error::Error>> {
     let flow_spec_info = flow_spec_info()?;
@@ -3,8 +3,10 @@ use peace::{
     cli_model::OutputFormat,
 };
 
+mod derive;
 mod either;
 mod presentable;
+mod presenter;
 
 /// Returns a new `CliOutput` with `OutputFormat::Text`.
 fn cli_output(buffer: &mut Vec<u8>, colorize: CliColorizeOpt) -> CliOutput<&mut Vec<u8>> {
@@ -0,0 +1,158 @@
+use std::marker::PhantomData;
+
+use peace::{
+    cli::output::{CliColorizeOpt, CliMdPresenter},
+    fmt::Presentable,
+};
+use serde::Serialize;
+
+use crate::fmt::cli_output;
+
+#[derive(Presentable, Serialize)]
+struct ServerState {
+    #[presentable(heading = 2)]
+    title: String,
+    #[presentable(code_inline)]
+    address: String,
+    #[presentable(bold, label = "Status")]
+    status: String,
+    port: String,
+    #[presentable(list)]
+    tags: Vec<String>,
+    #[presentable(list_numbered)]
+    steps: Vec<String>,
+    #[presentable(skip)]
+    internal: u32,
+}
+
+#[derive(Presentable, Serialize)]
+struct Wrapper(#[presentable(code_inline)] String);
+
+#[derive(Presentable, Serialize)]
+struct Generic<T> {
+    value: T,
+    #[presentable(skip)]
+    marker: PhantomData<T>,
+}
+
+#[derive(Presentable, Serialize)]
+enum ServerChange {
+    #[presentable(code_inline)]
+    None,
+    #[presentable(label = "Restart required")]
+    Restart,
+    Wrapped(Wrapper),
+    Resize {
+        #[presentable(code_inline)]
+        from: u32,
+        #[presentable(code_inline)]
+        to: u32,
+    },
+    #[presentable(skip)]
+    Hidden(u32),
+}
+
+#[tokio::test]
+async fn struct_named_fields_present_one_per_line() -> Result<(), Box<dyn std::error::Error>> {
+    let server_state = ServerState {
+        title: String::from("Server"),
+        address: String::from("127.0.0.1"),
+        status: String::from("running"),
+        port: String::from("8080"),
+        tags: vec![String::from("web"), String::from("prod")],
+        steps: vec![String::from("build"), String::from("deploy")],
+        internal: 123,
+    };
+
+    let output = present(&server_state).await?;
+
+    assert_eq!(
+        "\
+        ## Server\n\
+        \n\
+        address: `127.0.0.1`\n\
+        Status: **running**\n\
+        port: 8080\n\
+        tags:\n\
+        * web\n\
+        * prod\n\
+        steps:\n\
+        1. build\n\
+        2. deploy\n\
+        ",
+        output
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn struct_unnamed_fields_present_without_label() -> Result<(), Box<dyn std::error::Error>> {
+    let output = present(&Wrapper(String::from("abc"))).await?;
+
+    assert_eq!("`abc`", output);
+    Ok(())
+}
+
+#[tokio::test]
+async fn struct_generic_present() -> Result<(), Box<dyn std::error::Error>> {
+    let generic = Generic {
+        value: String::from("abc"),
+        marker: PhantomData,
+    };
+
+    let output = present(&generic).await?;
+
+    assert_eq!("value: abc\n", output);
+    Ok(())
+}
+
+#[tokio::test]
+async fn enum_unit_variant_present_as_name() -> Result<(), Box<dyn std::error::Error>> {
+    assert_eq!("`None`", present(&ServerChange::None).await?);
+    assert_eq!("Restart required", present(&ServerChange::Restart).await?);
+    Ok(())
+}
+
+#[tokio::test]
+async fn enum_unnamed_variant_present_as_fields() -> Result<(), Box<dyn std::error::Error>> {
+    let output = present(&ServerChange::Wrapped(Wrapper(String::from("abc")))).await?;
+
+    assert_eq!("`abc`", output);
+    Ok(())
+}
+
+#[tokio::test]
+async fn enum_named_variant_present_as_name_and_fields() -> Result<(), Box<dyn std::error::Error>> {
+    let output = present(&ServerChange::Resize { from: 1, to: 2 }).await?;
+
+    assert_eq!(
+        "\
+        Resize\n\
+        from: `1`\n\
+        to: `2`\n\
+        ",
+        output
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn enum_skipped_variant_presents_nothing() -> Result<(), Box<dyn std::error::Error>> {
+    let output = present(&ServerChange::Hidden(1)).await?;
+
+    assert_eq!("", output);
+    Ok(())
+}
+
+async fn present<P>(presentable: &P) -> Result<String, Box<dyn std::error::Error>>
+where
+    P: Presentable,
+{
+    let mut buffer = Vec::new();
+    let mut cli_output = cli_output(&mut buffer, CliColorizeOpt::Never);
+    let mut presenter = CliMdPresenter::new(&mut cli_output);
+
+    presentable.present(&mut presenter).await?;
+
+    Ok(String::from_utf8(buffer)?)
+}
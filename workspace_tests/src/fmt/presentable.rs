@@ -5,6 +5,7 @@ mod list_bulleted;
 mod list_bulleted_aligned;
 mod list_numbered;
 mod list_numbered_aligned;
+mod table;
 
 use peace::fmt::Presentable;
 
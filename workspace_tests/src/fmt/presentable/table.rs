@@ -0,0 +1,175 @@
+use peace::{
+    cli::output::{CliColorizeOpt, CliMdPresenter},
+    fmt::{
+        presentable::{Table, TableAlignment, TableColumn},
+        Presentable,
+    },
+};
+
+use crate::fmt::cli_output;
+
+#[tokio::test]
+async fn present_markdown_when_color_disabled() -> Result<(), Box<dyn std::error::Error>> {
+    let mut buffer = Vec::new();
+    let mut cli_output = cli_output(&mut buffer, CliColorizeOpt::Never);
+    let mut presenter = CliMdPresenter::new(&mut cli_output);
+
+    table().present(&mut presenter).await?;
+
+    let output = String::from_utf8(buffer)?;
+    assert_eq!(
+        "\
+        | Profile    | Items | Env  |\n\
+        | :--------- | ----: | :--: |\n\
+        | demo       |     3 | dev  |\n\
+        | production |    12 | prod |\n\
+        ",
+        output
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn present_box_drawing_when_color_enabled() -> Result<(), Box<dyn std::error::Error>> {
+    let mut buffer = Vec::new();
+    let mut cli_output = cli_output(&mut buffer, CliColorizeOpt::Always);
+    let mut presenter = CliMdPresenter::new(&mut cli_output);
+
+    table().present(&mut presenter).await?;
+
+    let output = String::from_utf8(buffer)?;
+    assert_eq!(
+        "\
+        ┌────────────┬───────┬──────┐\n\
+        │ Profile    │ Items │ Env  │\n\
+        ├────────────┼───────┼──────┤\n\
+        │ demo       │     3 │ dev  │\n\
+        │ production │    12 │ prod │\n\
+        └────────────┴───────┴──────┘\n\
+        ",
+        console::strip_ansi_codes(&output)
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn present_missing_values_as_empty_cells() -> Result<(), Box<dyn std::error::Error>> {
+    let mut buffer = Vec::new();
+    let mut cli_output = cli_output(&mut buffer, CliColorizeOpt::Never);
+    let mut presenter = CliMdPresenter::new(&mut cli_output);
+
+    Table::new(
+        vec![TableColumn::new("Name"), TableColumn::new("Value")],
+        vec![vec!["a"], vec!["b", "1", "ignored"]],
+    )
+    .present(&mut presenter)
+    .await?;
+
+    let output = String::from_utf8(buffer)?;
+    assert_eq!(
+        "\
+        | Name | Value |\n\
+        | :--- | :---- |\n\
+        | a    |       |\n\
+        | b    | 1     |\n\
+        ",
+        output
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn present_markdown_escapes_pipes_and_line_breaks() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut buffer = Vec::new();
+    let mut cli_output = cli_output(&mut buffer, CliColorizeOpt::Never);
+    let mut presenter = CliMdPresenter::new(&mut cli_output);
+
+    Table::new(
+        vec![TableColumn::new("Name|Id"), TableColumn::new("Value")],
+        vec![vec!["a|b", "line 1\nline 2"], vec!["c", "1\r\n2"]],
+    )
+    .present(&mut presenter)
+    .await?;
+
+    let output = String::from_utf8(buffer)?;
+    assert_eq!(
+        "\
+        | Name\\|Id | Value         |\n\
+        | :------- | :------------ |\n\
+        | a\\|b     | line 1 line 2 |\n\
+        | c        | 1  2          |\n\
+        ",
+        output
+    );
+    Ok(())
+}
+
+#[test]
+fn serialize() -> Result<(), serde_yaml::Error> {
+    assert_eq!(
+        "\
+        - Profile: demo\n  \
+          Items: '3'\n  \
+          Env: dev\n\
+        - Profile: production\n  \
+          Items: '12'\n  \
+          Env: prod\n\
+        ",
+        serde_yaml::to_string(&table())?
+    );
+    Ok(())
+}
+
+#[test]
+fn serialize_json() -> Result<(), serde_json::Error> {
+    assert_eq!(
+        r#"[{"Profile":"demo","Items":"3","Env":"dev"},{"Profile":"production","Items":"12","Env":"prod"}]"#,
+        serde_json::to_string(&table())?
+    );
+    Ok(())
+}
+
+#[test]
+fn serialize_json_keys_columns_with_duplicate_headers_by_occurrence(
+) -> Result<(), serde_json::Error> {
+    let table = Table::new(
+        vec![
+            TableColumn::new("Name"),
+            TableColumn::new("Name"),
+            TableColumn::new("Name (2)"),
+        ],
+        vec![vec!["a", "b", "c"]],
+    );
+
+    assert_eq!(
+        r#"[{"Name":"a","Name (3)":"b","Name (2)":"c"}]"#,
+        serde_json::to_string(&table)?
+    );
+    Ok(())
+}
+
+#[test]
+fn serialize_json_skips_missing_values() -> Result<(), serde_json::Error> {
+    let table = Table::new(
+        vec![TableColumn::new("Name"), TableColumn::new("Value")],
+        vec![vec!["a"], vec!["b", "1", "ignored"]],
+    );
+
+    assert_eq!(
+        r#"[{"Name":"a"},{"Name":"b","Value":"1"}]"#,
+        serde_json::to_string(&table)?
+    );
+    Ok(())
+}
+
+fn table() -> Table<'static, &'static str> {
+    Table::new(
+        vec![
+            TableColumn::new("Profile"),
+            TableColumn::new("Items").with_alignment(TableAlignment::Right),
+            TableColumn::new("Env").with_alignment(TableAlignment::Center),
+        ],
+        vec![vec!["demo", "3", "dev"], vec!["production", "12", "prod"]],
+    )
+}
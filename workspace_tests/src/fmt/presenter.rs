@@ -0,0 +1,182 @@
+use peace::fmt::{
+    async_trait,
+    presentable::{DiffKind, HeadingLevel, Table, TableColumn},
+    Presentable, Presenter,
+};
+
+#[tokio::test]
+async fn diff_default_presents_symbol_and_text() -> Result<(), std::io::Error> {
+    let mut text_presenter = TextPresenter::default();
+
+    text_presenter.diff(DiffKind::Added, "port: 80").await?;
+    text_presenter.diff(DiffKind::Removed, "host").await?;
+
+    assert_eq!("+ port: 80- host", text_presenter.text);
+    Ok(())
+}
+
+#[tokio::test]
+async fn table_default_presents_headers_and_rows() -> Result<(), std::io::Error> {
+    let mut text_presenter = TextPresenter::default();
+    let table = Table::new(
+        vec![TableColumn::new("Profile"), TableColumn::new("Items")],
+        vec![
+            vec![String::from("demo"), String::from("3")],
+            vec![
+                String::from("production"),
+                String::from("12"),
+                String::from("extra"),
+            ],
+        ],
+    );
+
+    text_presenter.table(&table).await?;
+
+    assert_eq!(
+        "Profile | Items\ndemo | 3\nproduction | 12\n",
+        text_presenter.text
+    );
+    Ok(())
+}
+
+/// Presenter that only implements the required methods, and collects text.
+#[derive(Debug, Default)]
+struct TextPresenter {
+    text: String,
+}
+
+#[async_trait(?Send)]
+impl Presenter<'static> for TextPresenter {
+    type Error = std::io::Error;
+
+    async fn heading<P>(
+        &mut self,
+        _heading_level: HeadingLevel,
+        presentable: &P,
+    ) -> Result<(), Self::Error>
+    where
+        P: Presentable + ?Sized,
+    {
+        presentable.present(self).await
+    }
+
+    async fn id(&mut self, id: &str) -> Result<(), Self::Error> {
+        self.text(id).await
+    }
+
+    async fn name(&mut self, name: &str) -> Result<(), Self::Error> {
+        self.text(name).await
+    }
+
+    async fn text(&mut self, text: &str) -> Result<(), Self::Error> {
+        self.text.push_str(text);
+        Ok(())
+    }
+
+    async fn bold<P>(&mut self, presentable: &P) -> Result<(), Self::Error>
+    where
+        P: Presentable + ?Sized,
+    {
+        presentable.present(self).await
+    }
+
+    async fn code_inline(&mut self, text: &str) -> Result<(), Self::Error> {
+        self.text(text).await
+    }
+
+    async fn tag(&mut self, tag: &str) -> Result<(), Self::Error> {
+        self.text(tag).await
+    }
+
+    async fn list_numbered<'f, P, I>(&mut self, _iter: I) -> Result<(), Self::Error>
+    where
+        P: Presentable + ?Sized + 'f,
+        I: IntoIterator<Item = &'f P>,
+    {
+        Ok(())
+    }
+
+    async fn list_numbered_with<'f, P, I, T, F>(
+        &mut self,
+        _iter: I,
+        _f: F,
+    ) -> Result<(), Self::Error>
+    where
+        P: Presentable,
+        I: IntoIterator<Item = T>,
+        T: 'f,
+        F: Fn(T) -> P,
+    {
+        Ok(())
+    }
+
+    async fn list_numbered_aligned<'f, P0, P1, I>(&mut self, _iter: I) -> Result<(), Self::Error>
+    where
+        P0: Presentable + 'f,
+        P1: Presentable + 'f,
+        I: IntoIterator<Item = &'f (P0, P1)>,
+    {
+        Ok(())
+    }
+
+    async fn list_numbered_aligned_with<'f, P0, P1, I, T, F>(
+        &mut self,
+        _iter: I,
+        _f: F,
+    ) -> Result<(), Self::Error>
+    where
+        P0: Presentable + 'f,
+        P1: Presentable + 'f,
+        I: IntoIterator<Item = T>,
+        T: 'f,
+        F: Fn(T) -> &'f (P0, P1),
+    {
+        Ok(())
+    }
+
+    async fn list_bulleted<'f, P, I>(&mut self, _iter: I) -> Result<(), Self::Error>
+    where
+        P: Presentable + ?Sized + 'f,
+        I: IntoIterator<Item = &'f P>,
+    {
+        Ok(())
+    }
+
+    async fn list_bulleted_with<'f, P, I, T, F>(
+        &mut self,
+        _iter: I,
+        _f: F,
+    ) -> Result<(), Self::Error>
+    where
+        P: Presentable,
+        I: IntoIterator<Item = T>,
+        T: 'f,
+        F: Fn(T) -> P,
+    {
+        Ok(())
+    }
+
+    async fn list_bulleted_aligned<'f, P0, P1, I>(&mut self, _iter: I) -> Result<(), Self::Error>
+    where
+        P0: Presentable + 'f,
+        P1: Presentable + 'f,
+        I: IntoIterator<Item = &'f (P0, P1)>,
+    {
+        Ok(())
+    }
+
+    async fn list_bulleted_aligned_with<'f, P0, P1, I, T, F>(
+        &mut self,
+        _iter: I,
+        _f: F,
+    ) -> Result<(), Self::Error>
+    where
+        P0: Presentable + 'f,
+        P1: Presentable + 'f,
+        I: IntoIterator<Item = T>,
+        T: 'f,
+        F: Fn(T) -> &'f (P0, P1),
+    {
+        Ok(())
+    }
+}
@@ -1,4 +1,8 @@
-use peace::fmt::{async_trait, presentable::HeadingLevel, Presentable, Presenter};
+use peace::fmt::{
+    async_trait,
+    presentable::{DiffKind, HeadingLevel, Table, TableColumn},
+    Presentable, Presenter,
+};
 
 use crate::{fn_name::fn_name_short, FnInvocation};
 
@@ -92,6 +96,14 @@ impl Presenter<'static> for FnTrackerPresenter {
         Ok(())
     }
 
+    async fn diff(&mut self, diff_kind: DiffKind, text: &str) -> Result<(), Self::Error> {
+        self.fn_invocations.push(FnInvocation::new(
+            fn_name_short!(),
+            vec![Some(format!("{diff_kind:?}")), Some(format!("{text:?}"))],
+        ));
+        Ok(())
+    }
+
     async fn list_numbered<'f, P, I>(&mut self, _iter: I) -> Result<(), Self::Error>
     where
         P: Presentable + ?Sized + 'f,
@@ -207,6 +219,22 @@ impl Presenter<'static> for FnTrackerPresenter {
 
         Ok(())
     }
+
+    async fn table<'s, P>(&mut self, table: &Table<'s, P>) -> Result<(), Self::Error>
+    where
+        P: Presentable,
+    {
+        let headers = table
+            .columns()
+            .iter()
+            .map(|column| column.header())
+            .collect::<Vec<&str>>();
+        self.fn_invocations.push(FnInvocation::new(
+            fn_name_short!(),
+            vec![Some(format!("{headers:?}"))],
+        ));
+        Ok(())
+    }
 }
 
 #[tokio::test]
@@ -221,6 +249,9 @@ async fn coverage() -> Result<(), std::io::Error> {
     fn_tracker_presenter.bold("").await?;
     fn_tracker_presenter.tag("the_tag").await?;
     fn_tracker_presenter.code_inline("the_code_inline").await?;
+    fn_tracker_presenter
+        .diff(DiffKind::Added, "the_diff")
+        .await?;
     fn_tracker_presenter
         .list_numbered(std::iter::once("abc"))
         .await?;
@@ -245,6 +276,12 @@ async fn coverage() -> Result<(), std::io::Error> {
     fn_tracker_presenter
         .list_bulleted_aligned_with(std::iter::once(&("abc", "def")), std::convert::identity)
         .await?;
+    fn_tracker_presenter
+        .table(&Table::new(
+            vec![TableColumn::new("the_header")],
+            vec![vec!["abc"]],
+        ))
+        .await?;
 
     [
         FnInvocation::new("heading", vec![Some(String::from("Level1")), None]),
@@ -257,6 +294,13 @@ async fn coverage() -> Result<(), std::io::Error> {
             "code_inline",
             vec![Some(String::from("\"the_code_inline\""))],
         ),
+        FnInvocation::new(
+            "diff",
+            vec![
+                Some(String::from("Added")),
+                Some(String::from("\"the_diff\"")),
+            ],
+        ),
         FnInvocation::new("list_numbered", vec![None]),
         FnInvocation::new("list_numbered_with", vec![None, None]),
         FnInvocation::new("list_numbered_aligned", vec![None]),
@@ -265,6 +309,7 @@ async fn coverage() -> Result<(), std::io::Error> {
         FnInvocation::new("list_bulleted_with", vec![None, None]),
         FnInvocation::new("list_bulleted_aligned", vec![None]),
         FnInvocation::new("list_bulleted_aligned_with", vec![None, None]),
+        FnInvocation::new("table", vec![Some(String::from("[\"the_header\"]"))]),
     ]
     .into_iter()
     .zip(fn_tracker_presenter.fn_invocations().iter())
@@ -5,7 +5,7 @@ use peace::{
     cmd::{ctx::CmdCtx, scopes::SingleProfileSingleFlowView},
     cmd_model::CmdOutcome,
     data::Data,
-    params::{ParamsSpec, ValueResolutionCtx, ValueResolutionMode},
+    params::{ParamsResolveError, ParamsSpec, ValueResolutionCtx, ValueResolutionMode},
     resource_rt::paths::{FlowDir, ProfileDir},
     rt::cmds::{CleanCmd, DiffCmd, EnsureCmd, StatesDiscoverCmd},
     rt_model::{Flow, InMemoryTextOutput, ItemGraph, ItemGraphBuilder, Workspace, WorkspaceSpec},
@@ -766,6 +766,48 @@ async fn clean_removes_files_in_dest_directory() -> Result<(), Box<dyn std::erro
     Ok(())
 }
 
+#[tokio::test]
+async fn build_returns_err_when_dest_empty() -> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = FlowId::new(crate::fn_name_short!())?;
+    let TestEnv {
+        tempdir: _tempdir,
+        workspace,
+        profile,
+        graph,
+        output,
+        tar_path,
+        dest: _,
+    } = test_env(&flow_id, TAR_X2_TAR).await?;
+    let flow = Flow::new(flow_id, graph);
+
+    let result = CmdCtx::builder_single_profile_single_flow(output.into(), workspace.into())
+        .with_profile(profile.clone())
+        .with_flow((&flow).into())
+        .with_item_params::<TarXItem<TarXTest>>(
+            TarXTest::ID.clone(),
+            TarXParams::<TarXTest>::new(tar_path, PathBuf::new()).into(),
+        )
+        .await;
+
+    assert!(
+        matches!(
+            &result,
+            Err(TarXError::PeaceRtError(
+                peace::rt_model::Error::ParamsResolveError(ParamsResolveError::Validation {
+                    value_resolution_ctx,
+                    params_validation_error,
+                    ..
+                })
+            ))
+            if value_resolution_ctx.item_id() == TarXTest::ID
+            && params_validation_error.field_name() == Some("dest")
+        ),
+        "Expected `result` to be a params validation error, but was `{result:?}`."
+    );
+
+    Ok(())
+}
+
 async fn test_env(
     flow_id: &FlowId,
     tar_bytes: &[u8],
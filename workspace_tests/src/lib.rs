@@ -31,6 +31,7 @@ mod params;
 mod resource_rt;
 mod rt;
 mod rt_model;
+mod rt_model_remote;
 #[cfg(feature = "webi")]
 mod webi;
 
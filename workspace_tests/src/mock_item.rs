@@ -434,6 +434,7 @@ where
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Params)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema), json_schema)]
 pub struct MockSrc(pub u8);
 
 impl Deref for MockSrc {
@@ -3,11 +3,19 @@ mod any_spec_rt_boxed;
 mod derive;
 mod field_name_and_type;
 mod mapping_fn_impl;
+mod mapping_fn_reg;
 mod params_spec;
 mod params_spec_de;
 mod params_spec_fieldless;
 mod params_spec_fieldless_de;
+#[cfg(feature = "json_schema")]
+mod params_spec_json_schema;
 mod params_specs;
+mod params_validation_error;
+mod secret;
+mod secret_provider_env;
+mod secret_provider_file;
+mod validate;
 mod value_resolution_ctx;
 mod value_resolution_mode;
 mod value_spec;
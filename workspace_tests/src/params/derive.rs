@@ -2588,6 +2588,174 @@ mod external_fields {
     }
 }
 
+mod validate {
+    use std::path::{Path, PathBuf};
+
+    use serde::{Deserialize, Serialize};
+
+    use peace::params::{Params, ParamsValidationError};
+
+    #[derive(Clone, Debug, Params, PartialEq, Eq, Serialize, Deserialize)]
+    #[validate(with = StructValidated::src_dest_differ)]
+    pub struct StructValidated {
+        #[validate(non_empty)]
+        src: PathBuf,
+        #[validate(non_empty)]
+        dest: PathBuf,
+        #[validate(range(min = 1, max = 10))]
+        count: u32,
+        unchecked: String,
+    }
+
+    impl StructValidated {
+        fn src_dest_differ(&self) -> Result<(), ParamsValidationError> {
+            if self.src == self.dest {
+                Err(ParamsValidationError::new("`src` and `dest` must differ"))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[derive(Clone, Debug, Params, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct TupleValidated(
+        String,
+        #[validate(url_scheme("https"))] String,
+        #[validate(with = tuple_is_even)] u32,
+    );
+
+    fn tuple_is_even(n: &u32) -> Result<(), String> {
+        if n.is_multiple_of(2) {
+            Ok(())
+        } else {
+            Err(String::from("must be even"))
+        }
+    }
+
+    #[derive(Clone, Debug, Params, PartialEq, Eq, Serialize, Deserialize)]
+    pub enum EnumValidated {
+        Named {
+            #[validate(non_empty)]
+            name: String,
+        },
+        Tuple(#[validate(range(max = 5))] u8),
+        Unit,
+    }
+
+    #[derive(Clone, Debug, Params, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct NotValidated {
+        src: PathBuf,
+    }
+
+    fn struct_validated() -> StructValidated {
+        StructValidated {
+            src: PathBuf::from("src"),
+            dest: PathBuf::from("dest"),
+            count: 1,
+            unchecked: String::new(),
+        }
+    }
+
+    #[test]
+    fn struct_validate_returns_ok_when_valid() {
+        assert_eq!(Ok(()), struct_validated().validate());
+    }
+
+    #[test]
+    fn struct_validate_returns_err_when_field_invalid() {
+        let params = StructValidated {
+            dest: PathBuf::new(),
+            ..struct_validated()
+        };
+        assert_eq!(
+            Err(ParamsValidationError::field("dest", "must not be empty")),
+            params.validate()
+        );
+
+        let params = StructValidated {
+            count: 11,
+            ..struct_validated()
+        };
+        assert_eq!(
+            Err(ParamsValidationError::field(
+                "count",
+                "must be between 1 and 10, but was 11"
+            )),
+            params.validate()
+        );
+    }
+
+    #[test]
+    fn struct_validate_runs_type_level_rule_after_field_rules() {
+        let params = StructValidated {
+            dest: PathBuf::from("src"),
+            ..struct_validated()
+        };
+
+        assert_eq!(
+            Err(ParamsValidationError::new("`src` and `dest` must differ")),
+            params.validate()
+        );
+    }
+
+    #[test]
+    fn tuple_validate() {
+        let valid = TupleValidated(String::new(), String::from("https://example.com"), 2);
+        assert_eq!(Ok(()), valid.validate());
+
+        let invalid_scheme = TupleValidated(String::new(), String::from("http://example.com"), 2);
+        assert_eq!(
+            Some("1"),
+            invalid_scheme.validate().unwrap_err().field_name()
+        );
+
+        let invalid_even = TupleValidated(String::new(), String::from("https://example.com"), 3);
+        assert_eq!(
+            Err(ParamsValidationError::field("2", "must be even")),
+            invalid_even.validate()
+        );
+    }
+
+    #[test]
+    fn enum_validate() {
+        assert_eq!(
+            Ok(()),
+            EnumValidated::Named {
+                name: String::from("a")
+            }
+            .validate()
+        );
+        assert_eq!(Ok(()), EnumValidated::Tuple(5).validate());
+        assert_eq!(Ok(()), EnumValidated::Unit.validate());
+
+        assert_eq!(
+            Err(ParamsValidationError::field("name", "must not be empty")),
+            EnumValidated::Named {
+                name: String::new()
+            }
+            .validate()
+        );
+        assert_eq!(
+            Err(ParamsValidationError::field(
+                "0",
+                "must be at most 5, but was 6"
+            )),
+            EnumValidated::Tuple(6).validate()
+        );
+    }
+
+    #[test]
+    fn validate_defaults_to_ok_when_no_rules() {
+        assert_eq!(
+            Ok(()),
+            NotValidated {
+                src: PathBuf::new()
+            }
+            .validate()
+        );
+    }
+}
+
 macro_rules! params_tests {
     (
         $params_ty:ident,
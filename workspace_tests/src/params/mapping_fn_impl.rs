@@ -10,6 +10,7 @@ fn debug() {
     assert_eq!(
         "MappingFnImpl { \
             field_name: Some(\"field_name\"), \
+            fn_name: None, \
             fn_map: \"Some(Fn(&bool,) -> Option<Option<u16>>)\", \
             marker: PhantomData<(core::option::Option<u16>, (bool,))> \
         }",
@@ -24,6 +25,7 @@ fn debug() {
     assert_eq!(
         "MappingFnImpl { \
             field_name: Some(\"field_name\"), \
+            fn_name: None, \
             fn_map: \"Some(Fn(&u32, &u64) -> Option<Option<u16>>)\", \
             marker: PhantomData<(core::option::Option<u16>, (u32, u64))> \
         }",
@@ -0,0 +1,167 @@
+use peace::{
+    cfg::item_id,
+    params::{
+        MappingFnBindError, MappingFnName, MappingFnReg, ParamsResolveError, ValueResolutionCtx,
+        ValueResolutionMode, ValueSpec,
+    },
+    resource_rt::{resources::ts::SetUp, Resources},
+};
+
+#[test]
+fn mapping_fn_returns_registered_fn_with_field_name() -> Result<(), ParamsResolveError> {
+    let mut mapping_fn_reg = MappingFnReg::new();
+    mapping_fn_reg.register("add_one", |n: &u8| n.checked_add(1));
+
+    let mapping_fn = mapping_fn_reg
+        .mapping_fn::<u8>(&MappingFnName::from("add_one"), Some(String::from("field")))
+        .expect("Expected `add_one` to be registered.");
+    let mut resources = Resources::new();
+    resources.insert(1u8);
+    let resources = Resources::<SetUp>::from(resources);
+    let mut value_resolution_ctx = ValueResolutionCtx::new(
+        ValueResolutionMode::Current,
+        item_id!("item_id"),
+        tynm::type_name::<u8>(),
+    );
+
+    assert_eq!(2u8, mapping_fn.map(&resources, &mut value_resolution_ctx)?);
+    assert!(mapping_fn.is_valued());
+    assert_eq!(
+        Some("add_one"),
+        mapping_fn.fn_name().map(MappingFnName::as_str)
+    );
+    assert_eq!(Some("field"), mapping_fn.field_name());
+
+    Ok(())
+}
+
+#[test]
+fn mapping_fn_returns_none_when_name_not_registered() {
+    let mapping_fn_reg = MappingFnReg::new();
+
+    assert!(mapping_fn_reg
+        .mapping_fn::<u8>(&MappingFnName::from("add_one"), None)
+        .is_none());
+}
+
+#[test]
+fn mapping_fn_returns_none_when_output_type_differs() {
+    let mut mapping_fn_reg = MappingFnReg::new();
+    mapping_fn_reg.register("add_one", |n: &u8| n.checked_add(1));
+
+    assert!(mapping_fn_reg
+        .mapping_fn::<u16>(&MappingFnName::from("add_one"), None)
+        .is_none());
+}
+
+#[test]
+fn mapping_fn_bind_binds_named_mapping_fn() {
+    let mut mapping_fn_reg = MappingFnReg::new();
+    mapping_fn_reg.register("add_one", |n: &u8| n.checked_add(1));
+    let mut value_spec = ValueSpec::<u8>::from_map_named(Some(String::from("field")), "add_one");
+    let mut mapping_fn_bind_errors = Vec::new();
+
+    let ValueSpec::MappingFn(mapping_fn) = &mut value_spec else {
+        panic!("Expected `ValueSpec::from_map_named` to return a `MappingFn`.");
+    };
+    assert!(!mapping_fn.is_valued());
+    mapping_fn_reg.mapping_fn_bind(mapping_fn, &mut mapping_fn_bind_errors);
+
+    assert!(mapping_fn.is_valued());
+    assert_eq!(Some("field"), mapping_fn.field_name());
+    assert!(mapping_fn_bind_errors.is_empty());
+}
+
+#[test]
+fn mapping_fn_bind_records_unknown_name() {
+    let mapping_fn_reg = MappingFnReg::new();
+    let mut value_spec = ValueSpec::<u8>::from_map_named(None, "add_one");
+    let mut mapping_fn_bind_errors = Vec::new();
+
+    let ValueSpec::MappingFn(mapping_fn) = &mut value_spec else {
+        panic!("Expected `ValueSpec::from_map_named` to return a `MappingFn`.");
+    };
+    mapping_fn_reg.mapping_fn_bind(mapping_fn, &mut mapping_fn_bind_errors);
+
+    assert!(!mapping_fn.is_valued());
+    assert_eq!(
+        vec![MappingFnBindError::NameUnknown {
+            mapping_fn_name: MappingFnName::from("add_one")
+        }],
+        mapping_fn_bind_errors
+    );
+}
+
+#[test]
+fn mapping_fn_bind_records_output_type_mismatch() {
+    let mut mapping_fn_reg = MappingFnReg::new();
+    mapping_fn_reg.register("add_one", |n: &u8| n.checked_add(1));
+    let mut value_spec = ValueSpec::<u16>::from_map_named(None, "add_one");
+    let mut mapping_fn_bind_errors = Vec::new();
+
+    let ValueSpec::MappingFn(mapping_fn) = &mut value_spec else {
+        panic!("Expected `ValueSpec::from_map_named` to return a `MappingFn`.");
+    };
+    mapping_fn_reg.mapping_fn_bind(mapping_fn, &mut mapping_fn_bind_errors);
+
+    assert!(!mapping_fn.is_valued());
+    assert_eq!(
+        vec![MappingFnBindError::OutputTypeMismatch {
+            mapping_fn_name: MappingFnName::from("add_one"),
+            output_type_expected: String::from("u16"),
+            output_type_registered: String::from("u8"),
+        }],
+        mapping_fn_bind_errors
+    );
+}
+
+#[test]
+fn mapping_fn_bind_does_not_replace_mapping_fn_with_logic() -> Result<(), ParamsResolveError> {
+    let mut mapping_fn_reg = MappingFnReg::new();
+    mapping_fn_reg.register("add_one", |n: &u8| n.checked_add(1));
+    let mut value_spec = ValueSpec::<u8>::from_map(None, |n: &u8| n.checked_add(2));
+    let mut mapping_fn_bind_errors = Vec::new();
+
+    let ValueSpec::MappingFn(mapping_fn) = &mut value_spec else {
+        panic!("Expected `ValueSpec::from_map` to return a `MappingFn`.");
+    };
+    mapping_fn_reg.mapping_fn_bind(mapping_fn, &mut mapping_fn_bind_errors);
+
+    let mut resources = Resources::new();
+    resources.insert(1u8);
+    let resources = Resources::<SetUp>::from(resources);
+    let mut value_resolution_ctx = ValueResolutionCtx::new(
+        ValueResolutionMode::Current,
+        item_id!("item_id"),
+        tynm::type_name::<u8>(),
+    );
+    assert_eq!(3u8, mapping_fn.map(&resources, &mut value_resolution_ctx)?);
+    assert!(mapping_fn_bind_errors.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn names_returns_sorted_names() {
+    let mut mapping_fn_reg = MappingFnReg::new();
+    mapping_fn_reg.register("b", |n: &u8| Some(*n));
+    mapping_fn_reg.register("a", |n: &u16| Some(*n));
+
+    assert_eq!(
+        vec![MappingFnName::from("a"), MappingFnName::from("b")],
+        mapping_fn_reg.names()
+    );
+    assert!(mapping_fn_reg.contains(&MappingFnName::from("a")));
+    assert!(!mapping_fn_reg.contains(&MappingFnName::from("c")));
+}
+
+#[test]
+fn debug() {
+    let mut mapping_fn_reg = MappingFnReg::new();
+    mapping_fn_reg.register("add_one", |n: &u8| n.checked_add(1));
+
+    assert_eq!(
+        r#"MappingFnReg([MappingFnName("add_one")])"#,
+        format!("{mapping_fn_reg:?}")
+    );
+}
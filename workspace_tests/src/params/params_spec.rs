@@ -2,7 +2,8 @@ use peace::{
     cfg::item_id,
     params::{
         AnySpecRt, AnySpecRtBoxed, FieldNameAndType, FieldWiseSpecRt, Params, ParamsResolveError,
-        ParamsSpec, ValueResolutionCtx, ValueResolutionMode, ValueSpec, ValueSpecRt,
+        ParamsSpec, ParamsSpecFieldOrigins, ParamsSpecOrigin, ParamsSpecsOrigins,
+        ValueResolutionCtx, ValueResolutionMode, ValueSpec, ValueSpecRt,
     },
     resource_rt::{resources::ts::SetUp, Resources},
 };
@@ -28,6 +29,7 @@ fn debug() {
     assert_eq!(
         "MappingFn(MappingFnImpl { \
             field_name: Some(\"field\"), \
+            fn_name: None, \
             fn_map: \"Some(Fn(&u8,) -> Option<MockSrc>)\", \
             marker: PhantomData<(workspace_tests::mock_item::MockSrc, (u8,))> \
         })",
@@ -1027,3 +1029,275 @@ fn merge_field_wise_with_field_wise_deep_merges() {
         if matches!(f0, ValueSpec::InMemory)
     ));
 }
+
+#[test]
+fn resolve_returns_err_when_validation_fails() {
+    let resources = Resources::<SetUp>::from(Resources::new());
+    let mut value_resolution_ctx = ValueResolutionCtx::new(
+        ValueResolutionMode::Current,
+        item_id!("resolve_returns_err_when_validation_fails"),
+        tynm::type_name::<PortParams>(),
+    );
+    let port_params_spec = ParamsSpec::<PortParams>::Value {
+        value: PortParams { port: 0 },
+    };
+
+    let error =
+        ValueSpecRt::resolve(&port_params_spec, &resources, &mut value_resolution_ctx).unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            ParamsResolveError::Validation {
+                params_validation_error,
+                ..
+            }
+            if params_validation_error.field_name() == Some("port")
+        ),
+        "Expected `error` to be `ParamsResolveError::Validation`, but was `{error:?}`."
+    );
+    assert_eq!(
+        "Invalid params for `resolve_returns_err_when_validation_fails`: \
+        `port` must be at least 1, but was 0",
+        error.to_string()
+    );
+}
+
+#[cfg(feature = "error_reporting")]
+#[test]
+fn resolve_validation_err_labels_invalid_field() {
+    let resources = Resources::<SetUp>::from(Resources::new());
+    let mut value_resolution_ctx = ValueResolutionCtx::new(
+        ValueResolutionMode::Current,
+        item_id!("resolve_validation_err_labels_invalid_field"),
+        tynm::type_name::<PortParams>(),
+    );
+    let port_params_spec = ParamsSpec::<PortParams>::Value {
+        value: PortParams { port: 0 },
+    };
+
+    let error =
+        ValueSpecRt::resolve(&port_params_spec, &resources, &mut value_resolution_ctx).unwrap_err();
+
+    match error {
+        ParamsResolveError::Validation {
+            params_source,
+            params_validation_error,
+            error_span,
+            ..
+        } => {
+            let params_source =
+                peace::miette::SourceCode::read_span(&params_source, &error_span, 0, 0)
+                    .map(|span_contents| String::from_utf8_lossy(span_contents.data()).to_string())
+                    .unwrap();
+            assert_eq!("0", params_source);
+            assert_eq!(
+                "must be at least 1, but was 0",
+                params_validation_error.message()
+            );
+        }
+        error => {
+            panic!("Expected `error` to be a labelled `Validation` error, but was `{error:?}`.")
+        }
+    }
+}
+
+#[cfg(feature = "error_reporting")]
+#[test]
+fn resolve_validation_err_labels_invalid_field_after_nested_value() {
+    let error = validation_error_resolve(ServerParams {
+        hosts: vec![String::from("a"), String::from("b")],
+        port: 0,
+    });
+
+    assert_eq!(
+        (
+            String::from("hosts:\n- a\n- b\nport: 0\n"),
+            String::from("0")
+        ),
+        validation_error_source_and_label(error)
+    );
+}
+
+#[cfg(feature = "error_reporting")]
+#[test]
+fn resolve_validation_err_labels_invalid_tuple_field() {
+    let error = validation_error_resolve(ServerTupleParams(
+        vec![String::from("a"), String::from("b")],
+        0,
+    ));
+
+    assert_eq!(
+        (String::from("- - a\n  - b\n- 0\n"), String::from("0")),
+        validation_error_source_and_label(error)
+    );
+}
+
+#[cfg(feature = "error_reporting")]
+#[test]
+fn resolve_validation_err_labels_invalid_enum_variant_field() {
+    let error = validation_error_resolve(ServerEnumParams::Server {
+        hosts: vec![String::from("a")],
+        port: 0,
+    });
+
+    assert_eq!(
+        (
+            String::from("!Server\nhosts:\n- a\nport: 0\n"),
+            String::from("0")
+        ),
+        validation_error_source_and_label(error)
+    );
+}
+
+#[test]
+fn validate_returns_ok_when_params_not_resolvable() -> Result<(), ParamsResolveError> {
+    let resources = Resources::<SetUp>::from(Resources::new());
+    let mut value_resolution_ctx = ValueResolutionCtx::new(
+        ValueResolutionMode::Goal,
+        item_id!("validate_returns_ok_when_params_not_resolvable"),
+        tynm::type_name::<PortParams>(),
+    );
+
+    ParamsSpec::<PortParams>::InMemory.validate(&resources, &mut value_resolution_ctx)
+}
+
+#[test]
+fn validate_returns_err_when_resolvable_params_invalid() {
+    let resources = {
+        let mut resources = Resources::new();
+        resources.insert(0u16);
+        Resources::<SetUp>::from(resources)
+    };
+    let mut value_resolution_ctx = ValueResolutionCtx::new(
+        ValueResolutionMode::Goal,
+        item_id!("validate_returns_err_when_resolvable_params_invalid"),
+        tynm::type_name::<PortParams>(),
+    );
+    let port_params_spec = PortParams::field_wise_spec().with_port_in_memory().build();
+
+    let result = port_params_spec.validate(&resources, &mut value_resolution_ctx);
+
+    assert!(
+        matches!(result, Err(ParamsResolveError::Validation { .. })),
+        "Expected `result` to be `Err(ParamsResolveError::Validation {{ .. }})`, but was `{result:?}`."
+    );
+}
+
+#[test]
+fn validate_returns_err_with_origin_when_params_read_from_override_file() {
+    let item_id = item_id!("validate_returns_err_with_origin_when_params_read_from_override_file");
+    let params_spec_origin = ParamsSpecOrigin::new("params_override.yaml".into(), 3, 5);
+    let resources = {
+        let mut resources = Resources::new();
+        resources.insert(0u16);
+        resources.insert(ParamsSpecsOrigins::from(
+            [(
+                item_id.clone(),
+                ParamsSpecFieldOrigins::from_field_origins(
+                    [(String::from("port"), params_spec_origin.clone())]
+                        .into_iter()
+                        .collect(),
+                ),
+            )]
+            .into_iter()
+            .collect::<std::collections::HashMap<_, _>>(),
+        ));
+        Resources::<SetUp>::from(resources)
+    };
+    let mut value_resolution_ctx = ValueResolutionCtx::new(
+        ValueResolutionMode::Goal,
+        item_id,
+        tynm::type_name::<PortParams>(),
+    );
+    let port_params_spec = PortParams::field_wise_spec().with_port_in_memory().build();
+
+    let result = port_params_spec.validate(&resources, &mut value_resolution_ctx);
+
+    ({
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        || {
+            assert!(
+                matches!(
+                    &result,
+                    Err(ParamsResolveError::Validation {
+                        params_validation_error,
+                        ..
+                    })
+                    if params_validation_error.origin() == Some(&params_spec_origin)
+                    && params_validation_error
+                        .to_string()
+                        .ends_with("(from `params_override.yaml:3:5`)")
+                ),
+                "was {result:?}"
+            );
+        }
+    })();
+}
+
+#[derive(Clone, Debug, Params, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PortParams {
+    #[validate(range(min = 1))]
+    port: u16,
+}
+
+#[cfg(feature = "error_reporting")]
+#[derive(Clone, Debug, Params, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ServerParams {
+    hosts: Vec<String>,
+    #[validate(range(min = 1))]
+    port: u16,
+}
+
+#[cfg(feature = "error_reporting")]
+#[derive(Clone, Debug, Params, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ServerTupleParams(Vec<String>, #[validate(range(min = 1))] u16);
+
+#[cfg(feature = "error_reporting")]
+#[derive(Clone, Debug, Params, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum ServerEnumParams {
+    Server {
+        hosts: Vec<String>,
+        #[validate(range(min = 1))]
+        port: u16,
+    },
+}
+
+#[cfg(feature = "error_reporting")]
+fn validation_error_resolve<T>(params: T) -> ParamsResolveError
+where
+    T: Params<Spec = ParamsSpec<T>>,
+    T::Partial: From<T>,
+{
+    let resources = Resources::<SetUp>::from(Resources::new());
+    let mut value_resolution_ctx = ValueResolutionCtx::new(
+        ValueResolutionMode::Current,
+        item_id!("validation_error_resolve"),
+        tynm::type_name::<T>(),
+    );
+    let params_spec = ParamsSpec::<T>::Value { value: params };
+
+    params_spec
+        .resolve(&resources, &mut value_resolution_ctx)
+        .unwrap_err()
+}
+
+/// Returns the params source and the labelled text of a validation error.
+#[cfg(feature = "error_reporting")]
+fn validation_error_source_and_label(error: ParamsResolveError) -> (String, String) {
+    match error {
+        ParamsResolveError::Validation {
+            params_source,
+            error_span,
+            ..
+        } => {
+            let label = peace::miette::SourceCode::read_span(&params_source, &error_span, 0, 0)
+                .map(|span_contents| String::from_utf8_lossy(span_contents.data()).to_string())
+                .unwrap();
+            (params_source.to_string(), label)
+        }
+        error => {
+            panic!("Expected `error` to be a labelled `Validation` error, but was `{error:?}`.")
+        }
+    }
+}
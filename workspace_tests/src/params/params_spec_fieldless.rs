@@ -35,6 +35,7 @@ fn debug() {
     assert_eq!(
         "MappingFn(MappingFnImpl { \
             field_name: Some(\"field\"), \
+            fn_name: None, \
             fn_map: \"Some(Fn(&u8,) -> Option<MockSrc>)\", \
             marker: PhantomData<(workspace_tests::mock_item::MockSrc, (u8,))> \
         })",
@@ -0,0 +1,80 @@
+use peace::params::{ParamsSpec, ParamsSpecFieldless, ValueSpec};
+use schemars::schema_for;
+use serde_json::{json, Value};
+
+use crate::vec_copy_item::VecA;
+
+#[test]
+fn params_spec_schema_includes_field_wise_variant() {
+    let schema = schema_for!(ParamsSpec<VecA>).to_value();
+
+    assert_eq!(Some("ParamsSpec_for_VecA"), schema["title"].as_str());
+    assert_eq!(
+        vec!["Stored", "Value", "InMemory", "MappingFn", "FieldWise"],
+        variant_names(&schema)
+    );
+    assert_eq!(
+        Some("#/$defs/VecAFieldWise"),
+        schema["oneOf"][4]["properties"]["FieldWise"]["properties"]["field_wise_spec"]["$ref"]
+            .as_str()
+    );
+    assert!(schema["$defs"]["VecAFieldWise"].is_object());
+}
+
+#[test]
+fn params_spec_fieldless_schema_excludes_field_wise_variant() {
+    let schema = schema_for!(ParamsSpecFieldless<u8>).to_value();
+
+    assert_eq!(
+        Some("ParamsSpecFieldless_for_uint8"),
+        schema["title"].as_str()
+    );
+    assert_eq!(
+        vec!["Stored", "Value", "InMemory", "MappingFn"],
+        variant_names(&schema)
+    );
+}
+
+#[test]
+fn value_spec_schema_uses_value_type_schema() {
+    let schema = schema_for!(ValueSpec<u8>).to_value();
+
+    assert_eq!(Some("ValueSpec_for_uint8"), schema["title"].as_str());
+    assert_eq!(
+        Some("How to populate a field's value in an item's params."),
+        schema["description"].as_str()
+    );
+    assert_eq!(
+        Some("integer"),
+        schema["oneOf"][1]["properties"]["Value"]["properties"]["value"]["type"].as_str()
+    );
+}
+
+#[test]
+fn mapping_fn_schema_requires_fn_name() {
+    let schema = schema_for!(ValueSpec<u8>).to_value();
+
+    let mapping_fn_schema = &schema["$defs"]["MappingFn"];
+    assert_eq!(json!(["fn_name"]), mapping_fn_schema["required"]);
+    assert_eq!(
+        json!(["string", "null"]),
+        mapping_fn_schema["properties"]["field_name"]["type"]
+    );
+}
+
+/// Returns the variant names of an externally tagged enum schema.
+fn variant_names(schema: &Value) -> Vec<&str> {
+    schema["oneOf"]
+        .as_array()
+        .expect("Expected `oneOf` to be an array.")
+        .iter()
+        .filter_map(|variant| {
+            variant["const"].as_str().or_else(|| {
+                variant["required"]
+                    .as_array()
+                    .and_then(|required| required.first())
+                    .and_then(Value::as_str)
+            })
+        })
+        .collect()
+}
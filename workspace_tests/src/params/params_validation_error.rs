@@ -0,0 +1,26 @@
+use peace::params::ParamsValidationError;
+
+#[test]
+fn display_field() {
+    assert_eq!(
+        "`dest` must not be empty",
+        ParamsValidationError::field("dest", "must not be empty").to_string()
+    );
+}
+
+#[test]
+fn display_without_field() {
+    assert_eq!(
+        "`src` and `dest` must differ",
+        ParamsValidationError::new("`src` and `dest` must differ").to_string()
+    );
+}
+
+#[test]
+fn field_name_and_message() {
+    let error = ParamsValidationError::field("dest", "must not be empty");
+
+    assert_eq!(Some("dest"), error.field_name());
+    assert_eq!("must not be empty", error.message());
+    assert_eq!(None, ParamsValidationError::new("invalid").field_name());
+}
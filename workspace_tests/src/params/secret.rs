@@ -0,0 +1,723 @@
+use peace::{
+    cfg::{app_name, async_trait, item_id, profile, ApplyCheck, FlowId, FnCtx, Item, ItemId},
+    cli::output::{CliColorizeOpt, CliMdPresenter, CliOutputBuilder},
+    cli_model::OutputFormat,
+    cmd::ctx::CmdCtx,
+    cmd_model::CmdOutcome,
+    fmt::Presentable,
+    params::{
+        Params, ParamsResolveError, ParamsSpec, Secret, SecretProvider, ValueResolutionCtx,
+        ValueResolutionMode, ValueSpec, ValueSpecRt,
+    },
+    resource_rt::{
+        resources::ts::{Empty, SetUp},
+        Resources,
+    },
+    rt::cmds::StatesDiscoverCmd,
+    rt_model::{Flow, ItemGraphBuilder, Workspace, WorkspaceSpec},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    mock_item::{MockDiff, MockItemError, MockState},
+    NoOpOutput, PeaceTestError,
+};
+
+#[derive(Debug)]
+struct SecretProviderMap(Vec<(&'static str, &'static str)>);
+
+impl SecretProvider for SecretProviderMap {
+    fn secret_read(
+        &self,
+        key: &str,
+    ) -> Result<Option<String>, Box<dyn std::error::Error + Send + Sync + 'static>> {
+        Ok(self
+            .0
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| v.to_string()))
+    }
+}
+
+#[derive(Clone, Debug, Params, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DbParams {
+    host: String,
+    password: Secret<String>,
+}
+
+#[derive(Clone, Debug, Params, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DbPortParams(String, Secret<u16>);
+
+#[derive(Clone, Debug, Params, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DbOptionalParams {
+    host: String,
+    password: Option<Secret<String>>,
+}
+
+#[derive(Clone, Debug, Params, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DbPortValidatedParams {
+    #[validate(with = port_is_not_zero)]
+    port: Secret<u16>,
+}
+
+fn port_is_not_zero(port: &Secret<u16>) -> Result<(), String> {
+    match port.value() {
+        Some(0) => Err(String::from("must not be 0")),
+        Some(_) => Ok(()),
+        None => Err(String::from("must be read from the secret provider")),
+    }
+}
+
+#[derive(Clone, Debug, Params, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SecretItemParams {
+    value: Secret<u8>,
+}
+
+/// Item whose state is the value of its secret param.
+#[derive(Clone, Debug)]
+struct SecretItem;
+
+impl SecretItem {
+    const ID: &'static ItemId = &item_id!("secret_item");
+}
+
+#[async_trait(?Send)]
+impl Item for SecretItem {
+    type Data<'exec> = ();
+    type Error = MockItemError;
+    type Params<'exec> = SecretItemParams;
+    type State = MockState;
+    type StateDiff = MockDiff;
+
+    fn id(&self) -> &ItemId {
+        Self::ID
+    }
+
+    async fn setup(&self, _resources: &mut Resources<Empty>) -> Result<(), MockItemError> {
+        Ok(())
+    }
+
+    #[cfg(feature = "item_state_example")]
+    fn state_example(params: &Self::Params<'_>, _data: Self::Data<'_>) -> Self::State {
+        MockState(params.value.value().copied().unwrap_or_default())
+    }
+
+    async fn try_state_current(
+        _fn_ctx: FnCtx<'_>,
+        params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+    ) -> Result<Option<Self::State>, MockItemError> {
+        Ok(params_partial
+            .value
+            .as_ref()
+            .and_then(Secret::value)
+            .copied()
+            .map(MockState))
+    }
+
+    async fn state_current(
+        _fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        _data: Self::Data<'_>,
+    ) -> Result<Self::State, MockItemError> {
+        Ok(MockState(params.value.value().copied().unwrap_or_default()))
+    }
+
+    async fn try_state_goal(
+        fn_ctx: FnCtx<'_>,
+        params_partial: &<Self::Params<'_> as Params>::Partial,
+        data: Self::Data<'_>,
+    ) -> Result<Option<Self::State>, MockItemError> {
+        Self::try_state_current(fn_ctx, params_partial, data).await
+    }
+
+    async fn state_goal(
+        fn_ctx: FnCtx<'_>,
+        params: &Self::Params<'_>,
+        data: Self::Data<'_>,
+    ) -> Result<Self::State, MockItemError> {
+        Self::state_current(fn_ctx, params, data).await
+    }
+
+    async fn state_diff(
+        _params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+        state_current: &MockState,
+        state_goal: &MockState,
+    ) -> Result<Self::StateDiff, MockItemError> {
+        Ok(MockDiff(
+            i16::from(state_goal.0) - i16::from(state_current.0),
+        ))
+    }
+
+    async fn state_clean(
+        _params_partial: &<Self::Params<'_> as Params>::Partial,
+        _data: Self::Data<'_>,
+    ) -> Result<Self::State, MockItemError> {
+        Ok(MockState::new())
+    }
+
+    async fn apply_check(
+        _params: &Self::Params<'_>,
+        _data: Self::Data<'_>,
+        _state_current: &Self::State,
+        _state_target: &Self::State,
+        _diff: &Self::StateDiff,
+    ) -> Result<ApplyCheck, MockItemError> {
+        Ok(ApplyCheck::ExecNotRequired)
+    }
+
+    async fn apply_dry(
+        _fn_ctx: FnCtx<'_>,
+        _params: &Self::Params<'_>,
+        _data: Self::Data<'_>,
+        _state_current: &Self::State,
+        state_target: &Self::State,
+        _diff: &Self::StateDiff,
+    ) -> Result<Self::State, MockItemError> {
+        Ok(state_target.clone())
+    }
+
+    async fn apply(
+        _fn_ctx: FnCtx<'_>,
+        _params: &Self::Params<'_>,
+        _data: Self::Data<'_>,
+        _state_current: &Self::State,
+        state_target: &Self::State,
+        _diff: &Self::StateDiff,
+    ) -> Result<Self::State, MockItemError> {
+        Ok(state_target.clone())
+    }
+
+    #[cfg(feature = "item_interactions")]
+    fn interactions(
+        _params: &Self::Params<'_>,
+        _data: Self::Data<'_>,
+    ) -> Vec<peace::item_model::ItemInteraction> {
+        Vec::new()
+    }
+}
+
+#[test]
+fn debug_redacts_value() {
+    let secret = Secret::<String>::new("db_password").with_value(String::from("s3cr3t"));
+
+    let debug_str = format!("{secret:?}");
+    assert_eq!(
+        r#"Secret { key: "db_password", value: "******" }"#,
+        debug_str
+    );
+    assert!(!debug_str.contains("s3cr3t"));
+}
+
+#[test]
+fn debug_without_value() {
+    assert_eq!(
+        r#"Secret { key: "db_password" }"#,
+        format!("{:?}", Secret::<String>::new("db_password"))
+    );
+}
+
+#[test]
+fn serialize_writes_key_only() -> Result<(), serde_yaml::Error> {
+    let secret = Secret::<String>::new("db_password").with_value(String::from("s3cr3t"));
+
+    assert_eq!("key: db_password\n", serde_yaml::to_string(&secret)?);
+    Ok(())
+}
+
+#[test]
+fn deserialize_has_no_value() -> Result<(), serde_yaml::Error> {
+    let secret = serde_yaml::from_str::<Secret<String>>("key: db_password")?;
+
+    assert_eq!("db_password", secret.key());
+    assert!(!secret.has_value());
+    Ok(())
+}
+
+#[test]
+fn params_spec_serialize_does_not_contain_secret_value() -> Result<(), serde_yaml::Error> {
+    let params_spec: ParamsSpec<DbParams> = DbParams {
+        host: String::from("localhost"),
+        password: Secret::new("db_password").with_value(String::from("s3cr3t")),
+    }
+    .into();
+
+    let serialized = serde_yaml::to_string(&params_spec)?;
+    assert_eq!(
+        r#"!Value
+value:
+  host: localhost
+  password:
+    key: db_password
+"#,
+        serialized
+    );
+    Ok(())
+}
+
+#[test]
+fn value_spec_serialize_does_not_contain_secret_value() -> Result<(), serde_yaml::Error> {
+    let value_spec: ValueSpec<Secret<String>> = Secret::new("db_password")
+        .with_value(String::from("s3cr3t"))
+        .into();
+
+    let serialized = serde_yaml::to_string(&value_spec)?;
+    assert!(!serialized.contains("s3cr3t"));
+    Ok(())
+}
+
+#[test]
+fn eq_compares_key_only() {
+    assert_eq!(
+        Secret::<String>::new("db_password").with_value(String::from("a")),
+        Secret::<String>::new("db_password").with_value(String::from("b"))
+    );
+    assert_eq!(
+        Secret::<String>::new("db_password"),
+        Secret::<String>::new("db_password").with_value(String::from("a"))
+    );
+    assert_ne!(
+        Secret::<String>::new("db_password"),
+        Secret::<String>::new("db_password_2")
+    );
+}
+
+#[test]
+fn resolve_uses_in_memory_value() -> Result<(), ParamsResolveError> {
+    let resources = resources_with_secret_provider(vec![("db_password", "from_provider")]);
+    let params_spec: ParamsSpec<DbParams> = DbParams {
+        host: String::from("localhost"),
+        password: Secret::new("db_password").with_value(String::from("in_memory")),
+    }
+    .into();
+
+    let db_params = ValueSpecRt::resolve(
+        &params_spec,
+        &resources,
+        &mut value_resolution_ctx::<DbParams>(),
+    )?;
+
+    assert_eq!(
+        Some("in_memory"),
+        db_params.password.value().map(String::as_str)
+    );
+    Ok(())
+}
+
+#[test]
+fn resolve_reads_secret_from_secret_provider() -> Result<(), ParamsResolveError> {
+    let resources = resources_with_secret_provider(vec![("db_password", "s3cr3t")]);
+    let params_spec: ParamsSpec<DbParams> = DbParams {
+        host: String::from("localhost"),
+        password: Secret::new("db_password"),
+    }
+    .into();
+
+    let db_params = ValueSpecRt::resolve(
+        &params_spec,
+        &resources,
+        &mut value_resolution_ctx::<DbParams>(),
+    )?;
+
+    assert_eq!(
+        Some("s3cr3t"),
+        db_params.password.value().map(String::as_str)
+    );
+    Ok(())
+}
+
+#[test]
+fn resolve_field_wise_reads_secret_from_secret_provider() -> Result<(), ParamsResolveError> {
+    let resources = resources_with_secret_provider(vec![("db_port", "5432")]);
+    let params_spec = DbPortParams::field_wise_spec()
+        .with_0(String::from("localhost"))
+        .with_1(Secret::new("db_port"))
+        .build();
+
+    let DbPortParams(_, port) = ValueSpecRt::resolve(
+        &params_spec,
+        &resources,
+        &mut value_resolution_ctx::<DbPortParams>(),
+    )?;
+
+    assert_eq!(Some(&5432), port.value());
+    Ok(())
+}
+
+#[test]
+fn resolve_reads_optional_secret_from_secret_provider() -> Result<(), ParamsResolveError> {
+    let resources = resources_with_secret_provider(vec![("db_password", "s3cr3t")]);
+    let params_spec: ParamsSpec<DbOptionalParams> = DbOptionalParams {
+        host: String::from("localhost"),
+        password: Some(Secret::new("db_password")),
+    }
+    .into();
+
+    let db_params = ValueSpecRt::resolve(
+        &params_spec,
+        &resources,
+        &mut value_resolution_ctx::<DbOptionalParams>(),
+    )?;
+
+    assert_eq!(
+        Some("s3cr3t"),
+        db_params
+            .password
+            .as_ref()
+            .and_then(Secret::value)
+            .map(String::as_str)
+    );
+    Ok(())
+}
+
+#[test]
+fn resolve_does_not_read_absent_optional_secret() -> Result<(), ParamsResolveError> {
+    let resources = Resources::<SetUp>::from(Resources::new());
+    let params_spec: ParamsSpec<DbOptionalParams> = DbOptionalParams {
+        host: String::from("localhost"),
+        password: None,
+    }
+    .into();
+
+    let db_params = ValueSpecRt::resolve(
+        &params_spec,
+        &resources,
+        &mut value_resolution_ctx::<DbOptionalParams>(),
+    )?;
+
+    assert_eq!(None, db_params.password);
+    Ok(())
+}
+
+#[test]
+fn resolve_partial_reads_secret_from_secret_provider() -> Result<(), ParamsResolveError> {
+    let resources = resources_with_secret_provider(vec![("db_password", "s3cr3t")]);
+    let params_spec: ParamsSpec<DbParams> = DbParams {
+        host: String::from("localhost"),
+        password: Secret::new("db_password"),
+    }
+    .into();
+
+    let db_params_partial =
+        params_spec.resolve_partial(&resources, &mut value_resolution_ctx::<DbParams>())?;
+
+    assert_eq!(
+        Some("s3cr3t"),
+        db_params_partial
+            .password
+            .as_ref()
+            .and_then(Secret::value)
+            .map(String::as_str)
+    );
+    Ok(())
+}
+
+#[test]
+fn resolve_partial_field_wise_reads_secret_from_secret_provider() -> Result<(), ParamsResolveError>
+{
+    let resources = resources_with_secret_provider(vec![("db_port", "5432")]);
+    let params_spec = DbPortParams::field_wise_spec()
+        .with_1(Secret::new("db_port"))
+        .build();
+
+    let db_port_params_partial =
+        params_spec.resolve_partial(&resources, &mut value_resolution_ctx::<DbPortParams>())?;
+
+    assert_eq!(None, db_port_params_partial.0);
+    assert_eq!(
+        Some(&5432),
+        db_port_params_partial.1.as_ref().and_then(Secret::value)
+    );
+    Ok(())
+}
+
+#[test]
+fn resolve_partial_reads_optional_secret_from_secret_provider() -> Result<(), ParamsResolveError> {
+    let resources = resources_with_secret_provider(vec![("db_password", "s3cr3t")]);
+    let params_spec: ParamsSpec<DbOptionalParams> = DbOptionalParams {
+        host: String::from("localhost"),
+        password: Some(Secret::new("db_password")),
+    }
+    .into();
+
+    let db_params_partial =
+        params_spec.resolve_partial(&resources, &mut value_resolution_ctx::<DbOptionalParams>())?;
+
+    assert_eq!(
+        Some("s3cr3t"),
+        db_params_partial
+            .password
+            .as_ref()
+            .and_then(Option::as_ref)
+            .and_then(Secret::value)
+            .map(String::as_str)
+    );
+    Ok(())
+}
+
+#[test]
+fn validate_does_not_read_secret_value() -> Result<(), ParamsResolveError> {
+    let resources = Resources::<SetUp>::from(Resources::new());
+    let params_spec: ParamsSpec<DbPortValidatedParams> = DbPortValidatedParams {
+        port: Secret::new("db_port"),
+    }
+    .into();
+
+    params_spec.validate(
+        &resources,
+        &mut value_resolution_ctx::<DbPortValidatedParams>(),
+    )
+}
+
+#[test]
+fn validate_returns_err_when_secret_key_empty() {
+    let resources = Resources::<SetUp>::from(Resources::new());
+    let params_spec: ParamsSpec<DbPortValidatedParams> = DbPortValidatedParams {
+        port: Secret::new(""),
+    }
+    .into();
+
+    let result = params_spec.validate(
+        &resources,
+        &mut value_resolution_ctx::<DbPortValidatedParams>(),
+    );
+
+    assert!(
+        matches!(&result, Err(ParamsResolveError::SecretKeyEmpty { .. })),
+        "Expected `result` to be a `SecretKeyEmpty` error, but was `{result:?}`."
+    );
+}
+
+#[test]
+fn resolve_returns_err_when_secret_value_invalid() {
+    let resources = resources_with_secret_provider(vec![("db_port", "0")]);
+    let params_spec: ParamsSpec<DbPortValidatedParams> = DbPortValidatedParams {
+        port: Secret::new("db_port"),
+    }
+    .into();
+
+    let result = params_spec.resolve(
+        &resources,
+        &mut value_resolution_ctx::<DbPortValidatedParams>(),
+    );
+
+    assert!(
+        matches!(
+            &result,
+            Err(ParamsResolveError::Validation {
+                params_validation_error,
+                ..
+            })
+            if params_validation_error.message() == "must not be 0"
+        ),
+        "Expected `result` to be a `Validation` error, but was `{result:?}`."
+    );
+}
+
+#[tokio::test]
+async fn cmd_ctx_build_does_not_need_secret_provider() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(SecretItem.into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let output = &mut NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .with_item_params::<SecretItem>(
+        SecretItem::ID.clone(),
+        SecretItemParams {
+            value: Secret::new("item_value"),
+        }
+        .into(),
+    )
+    .await?;
+
+    let cmd_outcome = StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+
+    let CmdOutcome::ItemError { errors, .. } = &cmd_outcome else {
+        panic!("Expected `cmd_outcome` to be an `ItemError`, but was `{cmd_outcome:?}`.");
+    };
+    assert!(
+        matches!(
+            errors.get(SecretItem::ID),
+            Some(PeaceTestError::PeaceRt(
+                peace::rt_model::Error::ParamsResolveError(
+                    ParamsResolveError::SecretProviderNotFound { secret_key }
+                )
+            ))
+            if secret_key == "item_value"
+        ),
+        "Expected `errors` to contain a `SecretProviderNotFound` error, but was `{errors:?}`."
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn state_current_reads_secret_from_secret_provider() -> Result<(), Box<dyn std::error::Error>>
+{
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(SecretItem.into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let secret_provider: Box<dyn SecretProvider> =
+        Box::new(SecretProviderMap(vec![("item_value", "123")]));
+    let output = &mut NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .with_resource(secret_provider)
+    .with_item_params::<SecretItem>(
+        SecretItem::ID.clone(),
+        SecretItemParams {
+            value: Secret::new("item_value"),
+        }
+        .into(),
+    )
+    .await?;
+
+    let CmdOutcome::Complete {
+        value: states_current,
+        cmd_blocks_processed: _,
+    } = StatesDiscoverCmd::current(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesDiscoverCmd::current` to complete successfully.");
+    };
+
+    assert_eq!(
+        Some(MockState(123)).as_ref(),
+        states_current.get::<MockState, _>(SecretItem::ID)
+    );
+    Ok(())
+}
+
+#[test]
+fn resolve_returns_error_when_secret_provider_not_found() {
+    let resources = Resources::<SetUp>::from(Resources::new());
+    let params_spec: ParamsSpec<DbParams> = DbParams {
+        host: String::from("localhost"),
+        password: Secret::new("db_password"),
+    }
+    .into();
+
+    let error = ValueSpecRt::resolve(
+        &params_spec,
+        &resources,
+        &mut value_resolution_ctx::<DbParams>(),
+    )
+    .unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            ParamsResolveError::SecretProviderNotFound { secret_key }
+            if secret_key == "db_password"
+        ),
+        "Expected `error` to be `SecretProviderNotFound`, but was `{error:?}`."
+    );
+}
+
+#[test]
+fn resolve_returns_error_when_secret_not_found() {
+    let resources = resources_with_secret_provider(Vec::new());
+    let params_spec: ParamsSpec<DbParams> = DbParams {
+        host: String::from("localhost"),
+        password: Secret::new("db_password"),
+    }
+    .into();
+
+    let error = ValueSpecRt::resolve(
+        &params_spec,
+        &resources,
+        &mut value_resolution_ctx::<DbParams>(),
+    )
+    .unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            ParamsResolveError::SecretNotFound { secret_key, .. }
+            if secret_key == "db_password"
+        ),
+        "Expected `error` to be `SecretNotFound`, but was `{error:?}`."
+    );
+}
+
+#[test]
+fn resolve_returns_error_when_secret_parse_fails() {
+    let resources = resources_with_secret_provider(vec![("db_port", "not_a_port")]);
+    let params_spec: ParamsSpec<DbPortParams> =
+        DbPortParams(String::from("localhost"), Secret::new("db_port")).into();
+
+    let error = ValueSpecRt::resolve(
+        &params_spec,
+        &resources,
+        &mut value_resolution_ctx::<DbPortParams>(),
+    )
+    .unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            ParamsResolveError::SecretParse { secret_key, type_name, .. }
+            if secret_key == "db_port" && type_name == "u16"
+        ),
+        "Expected `error` to be `SecretParse`, but was `{error:?}`."
+    );
+    assert!(!error.to_string().contains("not_a_port"));
+}
+
+#[tokio::test]
+async fn present_redacts_value() -> Result<(), Box<dyn std::error::Error>> {
+    let mut buffer = Vec::new();
+    let mut cli_output = CliOutputBuilder::new_with_writer(&mut buffer)
+        .with_outcome_format(OutputFormat::Text)
+        .with_colorize(CliColorizeOpt::Never)
+        .build();
+    let mut presenter = CliMdPresenter::new(&mut cli_output);
+
+    Secret::<String>::new("db_password")
+        .with_value(String::from("s3cr3t"))
+        .present(&mut presenter)
+        .await?;
+
+    let output = String::from_utf8(buffer)?;
+    assert_eq!("`db_password`: ******", output);
+    Ok(())
+}
+
+fn resources_with_secret_provider(secrets: Vec<(&'static str, &'static str)>) -> Resources<SetUp> {
+    let mut resources = Resources::new();
+    let secret_provider: Box<dyn SecretProvider> = Box::new(SecretProviderMap(secrets));
+    resources.insert(secret_provider);
+    Resources::<SetUp>::from(resources)
+}
+
+fn value_resolution_ctx<T>() -> ValueResolutionCtx {
+    ValueResolutionCtx::new(
+        ValueResolutionMode::Current,
+        item_id!("secret"),
+        tynm::type_name::<T>(),
+    )
+}
@@ -0,0 +1,36 @@
+use peace::params::{SecretProvider, SecretProviderEnv};
+
+#[test]
+fn env_var_name_normalizes_key() {
+    let secret_provider = SecretProviderEnv::with_prefix("APP_");
+
+    assert_eq!(
+        "APP_DB_PASSWORD",
+        secret_provider.env_var_name("db.password")
+    );
+}
+
+#[test]
+fn secret_read_returns_env_var_value() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    // Only this test uses this variable, as tests run in parallel.
+    let env_var = "PEACE_WORKSPACE_TESTS_SECRET_PROVIDER_ENV_SECRET_READ_DB_PASSWORD";
+    std::env::set_var(env_var, "s3cr3t");
+    let secret_provider =
+        SecretProviderEnv::with_prefix("PEACE_WORKSPACE_TESTS_SECRET_PROVIDER_ENV_SECRET_READ_");
+
+    let secret = secret_provider.secret_read("db_password");
+    std::env::remove_var(env_var);
+
+    assert_eq!(Some(String::from("s3cr3t")), secret?);
+    Ok(())
+}
+
+#[test]
+fn secret_read_returns_none_when_env_var_not_present(
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let secret_provider =
+        SecretProviderEnv::with_prefix("PEACE_WORKSPACE_TESTS_SECRET_PROVIDER_ENV_");
+
+    assert_eq!(None, secret_provider.secret_read("not_present")?);
+    Ok(())
+}
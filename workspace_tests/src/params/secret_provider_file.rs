@@ -0,0 +1,36 @@
+use peace::params::{SecretProvider, SecretProviderFile};
+
+#[test]
+fn secret_read_returns_value_from_file() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let tempdir = tempfile::tempdir()?;
+    let secrets_path = tempdir.path().join("secrets.env");
+    std::fs::write(
+        &secrets_path,
+        "# comment\n\
+        \n\
+        db_password = s3cr3t\n\
+        api_token=a=b\n",
+    )?;
+    let secret_provider = SecretProviderFile::new(secrets_path);
+
+    assert_eq!(
+        Some(String::from("s3cr3t")),
+        secret_provider.secret_read("db_password")?
+    );
+    assert_eq!(
+        Some(String::from("a=b")),
+        secret_provider.secret_read("api_token")?
+    );
+    assert_eq!(None, secret_provider.secret_read("comment")?);
+    Ok(())
+}
+
+#[test]
+fn secret_read_returns_error_when_file_does_not_exist(
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let tempdir = tempfile::tempdir()?;
+    let secret_provider = SecretProviderFile::new(tempdir.path().join("secrets.env"));
+
+    assert!(secret_provider.secret_read("db_password").is_err());
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use std::path::PathBuf;
+
+use peace::params::{validate, ParamsValidationError};
+
+#[test]
+fn non_empty_returns_ok_when_value_not_empty() {
+    assert_eq!(Ok(()), validate::non_empty("name", "abc"));
+    assert_eq!(Ok(()), validate::non_empty("path", &PathBuf::from("a")));
+    assert_eq!(Ok(()), validate::non_empty("values", &vec![1u8]));
+    assert_eq!(Ok(()), validate::non_empty("opt", &Some(String::from("a"))));
+}
+
+#[test]
+fn non_empty_returns_err_when_value_empty() {
+    let expected = Err(ParamsValidationError::field("field", "must not be empty"));
+
+    assert_eq!(expected, validate::non_empty("field", " "));
+    assert_eq!(expected, validate::non_empty("field", &PathBuf::new()));
+    assert_eq!(expected, validate::non_empty("field", &Vec::<u8>::new()));
+    assert_eq!(expected, validate::non_empty("field", &None::<String>));
+}
+
+#[test]
+fn range_returns_ok_when_value_within_bounds() {
+    assert_eq!(Ok(()), validate::range("port", &1u16, Some(1), Some(10)));
+    assert_eq!(Ok(()), validate::range("port", &10u16, Some(1), Some(10)));
+    assert_eq!(Ok(()), validate::range("port", &100u16, Some(1), None));
+    assert_eq!(Ok(()), validate::range("port", &0u16, None, Some(10)));
+}
+
+#[test]
+fn range_returns_err_when_value_outside_bounds() {
+    assert_eq!(
+        Err(ParamsValidationError::field(
+            "port",
+            "must be between 1 and 10, but was 11"
+        )),
+        validate::range("port", &11u16, Some(1), Some(10))
+    );
+    assert_eq!(
+        Err(ParamsValidationError::field(
+            "port",
+            "must be at least 1, but was 0"
+        )),
+        validate::range("port", &0u16, Some(1), None)
+    );
+    assert_eq!(
+        Err(ParamsValidationError::field(
+            "port",
+            "must be at most 10, but was 11"
+        )),
+        validate::range("port", &11u16, None, Some(10))
+    );
+}
+
+#[test]
+fn url_scheme_returns_ok_when_scheme_allowed() {
+    let url = String::from("https://example.com/file.txt");
+
+    assert_eq!(
+        Ok(()),
+        validate::url_scheme("src", &url, &["http", "https"])
+    );
+    assert_eq!(
+        Ok(()),
+        validate::url_scheme("src", "HTTP://example.com", &["http", "https"])
+    );
+}
+
+#[test]
+fn url_scheme_returns_err_when_scheme_not_allowed() {
+    assert_eq!(
+        Err(ParamsValidationError::field(
+            "src",
+            "must use one of the URL schemes: `http`, `https`, but was `ftp://example.com`"
+        )),
+        validate::url_scheme("src", "ftp://example.com", &["http", "https"])
+    );
+    assert!(validate::url_scheme("src", "example.com", &["http"]).is_err());
+}
+
+#[test]
+fn with_maps_message_to_field_error() {
+    let is_even = |n: &u32| {
+        if n.is_multiple_of(2) {
+            Ok(())
+        } else {
+            Err(String::from("must be even"))
+        }
+    };
+
+    assert_eq!(Ok(()), validate::with("count", &2u32, is_even));
+    assert_eq!(
+        Err(ParamsValidationError::field("count", "must be even")),
+        validate::with("count", &3u32, is_even)
+    );
+}
@@ -1,8 +1,8 @@
 use peace::{
     cfg::item_id,
     params::{
-        AnySpecRt, AnySpecRtBoxed, ParamsResolveError, ValueResolutionCtx, ValueResolutionMode,
-        ValueSpec, ValueSpecRt,
+        AnySpecRt, AnySpecRtBoxed, MappingFnReg, ParamsResolveError, ValueResolutionCtx,
+        ValueResolutionMode, ValueSpec, ValueSpecRt,
     },
     resource_rt::{resources::ts::SetUp, Resources},
 };
@@ -25,6 +25,7 @@ fn debug() {
     assert_eq!(
         "MappingFn(MappingFnImpl { \
             field_name: Some(\"field\"), \
+            fn_name: None, \
             fn_map: \"Some(Fn(&u8,) -> Option<MockSrc>)\", \
             marker: PhantomData<(workspace_tests::mock_item::MockSrc, (u8,))> \
         })",
@@ -91,6 +92,22 @@ marker: null
     Ok(())
 }
 
+#[test]
+fn serialize_from_map_named() -> Result<(), serde_yaml::Error> {
+    let u8_spec: ValueSpec<u8> = ValueSpec::<u8>::from_map_named(None, "u8_from_flag");
+    assert_eq!(
+        r#"!MappingFn
+field_name: null
+fn_name: u8_from_flag
+fn_map: None
+marker: null
+"#,
+        serde_yaml::to_string(&u8_spec)?,
+    );
+
+    Ok(())
+}
+
 #[test]
 fn deserialize_stored() -> Result<(), serde_yaml::Error> {
     assert!(matches!(
@@ -166,6 +183,35 @@ marker: null
     Ok(())
 }
 
+#[test]
+fn deserialize_from_map_named() -> Result<(), serde_yaml::Error> {
+    let deserialized = serde_yaml::from_str(
+        r#"!MappingFn
+field_name: null
+fn_name: u8_from_flag
+fn_map: Some(Fn(&bool,) -> Option<u8>)
+marker: null
+"#,
+    )?;
+
+    ({
+        #[cfg_attr(coverage_nightly, coverage(off))]
+        || {
+            assert!(
+                matches!(
+                    &deserialized,
+                    ValueSpec::<u8>::MappingFn(mapping_fn)
+                    if !mapping_fn.is_valued()
+                    && mapping_fn.fn_name().map(|fn_name| fn_name.as_str()) == Some("u8_from_flag")
+                ),
+                "was {deserialized:?}"
+            );
+        }
+    })();
+
+    Ok(())
+}
+
 #[test]
 fn is_usable_returns_false_for_stored() {
     assert!(!ValueSpec::<u8>::Stored.is_usable());
@@ -196,6 +242,20 @@ marker: null
     Ok(())
 }
 
+#[test]
+fn is_usable_returns_true_when_named_mapping_fn_is_bound() {
+    let mut mapping_fn_reg = MappingFnReg::new();
+    mapping_fn_reg.register("u8_from_flag", |flag: &bool| Some(u8::from(*flag)));
+    let mut u8_spec = ValueSpec::<u8>::from_map_named(None, "u8_from_flag");
+    let mut mapping_fn_bind_errors = Vec::new();
+
+    assert!(!u8_spec.is_usable());
+    u8_spec.mapping_fns_bind(&mapping_fn_reg, &mut mapping_fn_bind_errors);
+
+    assert!(u8_spec.is_usable());
+    assert!(mapping_fn_bind_errors.is_empty());
+}
+
 #[test]
 fn resolve_stored_param() -> Result<(), ParamsResolveError> {
     let resources = {
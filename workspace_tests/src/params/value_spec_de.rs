@@ -21,6 +21,7 @@ fn debug() -> Result<(), serde_yaml::Error> {
     assert_eq!(
         "MappingFn(MappingFnImpl { \
             field_name: Some(\"field\"), \
+            fn_name: None, \
             fn_map: \"None\", \
             marker: PhantomData<(workspace_tests::mock_item::MockSrc, ((),))> \
         })",
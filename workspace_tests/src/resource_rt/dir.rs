@@ -1,3 +1,4 @@
+mod params_applied_file;
 mod peace_dir;
 mod profile_dir;
 mod profile_history_dir;
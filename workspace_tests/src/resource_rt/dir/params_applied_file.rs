@@ -0,0 +1,91 @@
+use std::{
+    ffi::OsStr,
+    path::{Path, PathBuf},
+};
+
+use peace::{
+    cfg::{app_name, flow_id, profile},
+    resource_rt::paths::{FlowDir, ParamsAppliedFile, PeaceAppDir, PeaceDir, ProfileDir},
+};
+
+#[test]
+pub fn debug() {
+    let params_applied_file =
+        ParamsAppliedFile::from(Path::new("test_params_applied.yaml").to_path_buf());
+
+    assert_eq!(
+        r#"ParamsAppliedFile("test_params_applied.yaml")"#,
+        format!("{params_applied_file:?}")
+    );
+}
+
+#[test]
+pub fn partial_eq() {
+    let params_applied_file_0 =
+        ParamsAppliedFile::from(Path::new("test_params_applied.yaml").to_path_buf());
+    #[allow(clippy::redundant_clone)] // https://github.com/rust-lang/rust-clippy/issues/9011
+    let params_applied_file_1 = params_applied_file_0.clone();
+
+    assert_eq!(params_applied_file_0, params_applied_file_1);
+}
+
+#[test]
+pub fn from_path_buf() {
+    let params_applied_file =
+        ParamsAppliedFile::from(Path::new("test_params_applied.yaml").to_path_buf());
+
+    assert_eq!(Path::new("test_params_applied.yaml"), &*params_applied_file);
+}
+
+#[test]
+pub fn from_flow_dir_relative() {
+    let app_name = app_name!();
+    let peace_dir = PeaceDir::from(Path::new(".").to_path_buf());
+    let profile = profile!("test_profile");
+    let peace_app_dir = PeaceAppDir::from((&peace_dir, &app_name));
+    let profile_dir = ProfileDir::from((&peace_app_dir, &profile));
+    let flow_dir = FlowDir::from((&profile_dir, &flow_id!("test_flow")));
+    let params_applied_file = ParamsAppliedFile::from(&flow_dir);
+
+    let path = PathBuf::from_iter([
+        ".",
+        &**app_name!(),
+        "test_profile",
+        "test_flow",
+        "params_applied.yaml",
+    ]);
+    assert_eq!(path, &*params_applied_file);
+}
+
+#[test]
+pub fn into_inner_returns_path_buf() {
+    let params_applied_file =
+        ParamsAppliedFile::new(Path::new("test_params_applied.yaml").to_path_buf());
+
+    assert_eq!(
+        Path::new("test_params_applied.yaml").to_path_buf(),
+        params_applied_file.into_inner()
+    );
+}
+
+#[test]
+pub fn as_ref_os_str() {
+    let params_applied_file =
+        ParamsAppliedFile::new(Path::new("test_params_applied.yaml").to_path_buf());
+
+    assert_eq!(
+        OsStr::new("test_params_applied.yaml"),
+        <ParamsAppliedFile as AsRef<OsStr>>::as_ref(&params_applied_file)
+    );
+}
+
+#[test]
+pub fn as_ref_path() {
+    let params_applied_file =
+        ParamsAppliedFile::new(Path::new("test_params_applied.yaml").to_path_buf());
+
+    assert_eq!(
+        Path::new("test_params_applied.yaml"),
+        <ParamsAppliedFile as AsRef<Path>>::as_ref(&params_applied_file)
+    );
+}
@@ -3,10 +3,12 @@ use peace::{
     resource_rt::{internal::StatesMut, states::StatesCurrent, type_reg::untagged::TypeMap},
 };
 
+mod state_diff_node;
 mod states_cleaned;
 mod states_cleaned_dry;
 mod states_current;
 mod states_current_stored;
+mod states_diff_tree;
 mod states_ensured;
 mod states_ensured_dry;
 mod states_goal;
@@ -0,0 +1,77 @@
+use peace::resource_rt::states::StateDiffNode;
+use serde_yaml::Value;
+
+#[test]
+fn new_returns_unchanged_for_equal_values() -> Result<(), serde_yaml::Error> {
+    let value: Value = serde_yaml::from_str("{ a: 1, b: [1, 2] }")?;
+
+    assert_eq!(StateDiffNode::Unchanged, StateDiffNode::new(&value, &value));
+    Ok(())
+}
+
+#[test]
+fn new_returns_changed_fields_only() -> Result<(), serde_yaml::Error> {
+    let from: Value = serde_yaml::from_str("{ a: 1, b: 2, c: abc }")?;
+    let to: Value = serde_yaml::from_str("{ a: 1, b: 3, d: true }")?;
+
+    assert_eq!(
+        StateDiffNode::Fields(vec![
+            (
+                String::from("b"),
+                StateDiffNode::Modified {
+                    from: String::from("2"),
+                    to: String::from("3"),
+                }
+            ),
+            (
+                String::from("c"),
+                StateDiffNode::Removed(String::from("\"abc\""))
+            ),
+            (
+                String::from("d"),
+                StateDiffNode::Added(String::from("true"))
+            ),
+        ]),
+        StateDiffNode::new(&from, &to)
+    );
+    Ok(())
+}
+
+#[test]
+fn new_compares_sequences_by_index() -> Result<(), serde_yaml::Error> {
+    let from: Value = serde_yaml::from_str("[1, 2, 3]")?;
+    let to: Value = serde_yaml::from_str("[1, 4]")?;
+
+    assert_eq!(
+        StateDiffNode::Fields(vec![
+            (
+                String::from("[1]"),
+                StateDiffNode::Modified {
+                    from: String::from("2"),
+                    to: String::from("4"),
+                }
+            ),
+            (
+                String::from("[2]"),
+                StateDiffNode::Removed(String::from("3"))
+            ),
+        ]),
+        StateDiffNode::new(&from, &to)
+    );
+    Ok(())
+}
+
+#[test]
+fn new_returns_modified_for_different_types() -> Result<(), serde_yaml::Error> {
+    let from: Value = serde_yaml::from_str("{ a: 1 }")?;
+    let to: Value = serde_yaml::from_str("[1]")?;
+
+    assert_eq!(
+        StateDiffNode::Modified {
+            from: String::from("{a: 1}"),
+            to: String::from("[1]"),
+        },
+        StateDiffNode::new(&from, &to)
+    );
+    Ok(())
+}
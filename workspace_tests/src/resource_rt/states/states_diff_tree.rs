@@ -0,0 +1,120 @@
+use peace::{
+    cfg::{item_id, State},
+    cli::output::{CliColorizeOpt, CliMdPresenter, CliOutputBuilder},
+    cli_model::OutputFormat,
+    fmt::Presentable,
+    resource_rt::{
+        internal::StatesMut,
+        states::{StateDiffNode, StatesCurrent, StatesDiffTree, StatesGoal},
+    },
+};
+
+#[test]
+fn new_collapses_unchanged_items() -> Result<(), serde_yaml::Error> {
+    let (states_current, states_goal) = states_current_and_goal();
+
+    let states_diff_tree = StatesDiffTree::new(&states_current, &states_goal)?;
+
+    assert_eq!(
+        Some(&StateDiffNode::Unchanged),
+        states_diff_tree.get(&item_id!("item_unchanged"))
+    );
+    assert!(states_diff_tree.has_changes());
+    Ok(())
+}
+
+#[test]
+fn new_includes_items_only_in_states_from_as_removed() -> Result<(), serde_yaml::Error> {
+    let (states_current, states_goal) = states_current_and_goal();
+
+    let states_diff_tree = StatesDiffTree::new(&states_current, &states_goal)?;
+
+    assert_eq!(
+        Some(&StateDiffNode::Removed(String::from("\"abc\""))),
+        states_diff_tree.get(&item_id!("item_removed"))
+    );
+    Ok(())
+}
+
+#[test]
+fn new_includes_items_only_in_states_to_as_added() -> Result<(), serde_yaml::Error> {
+    let (states_current, states_goal) = states_current_and_goal();
+
+    let states_diff_tree = StatesDiffTree::new(&states_current, &states_goal)?;
+
+    assert_eq!(
+        Some(&StateDiffNode::Added(String::from("1"))),
+        states_diff_tree.get(&item_id!("item_added"))
+    );
+    assert_eq!(
+        vec![
+            item_id!("item_unchanged"),
+            item_id!("item_changed"),
+            item_id!("item_removed"),
+            item_id!("item_added"),
+        ],
+        states_diff_tree.keys().cloned().collect::<Vec<_>>()
+    );
+    Ok(())
+}
+
+#[test]
+fn has_changes_returns_false_when_all_items_unchanged() -> Result<(), serde_yaml::Error> {
+    let (states_current, _states_goal) = states_current_and_goal();
+
+    let states_diff_tree = StatesDiffTree::new(&states_current, &states_current)?;
+
+    assert!(!states_diff_tree.has_changes());
+    Ok(())
+}
+
+#[tokio::test]
+async fn present_renders_tree_per_item() -> Result<(), Box<dyn std::error::Error>> {
+    let (states_current, states_goal) = states_current_and_goal();
+    let states_diff_tree = StatesDiffTree::new(&states_current, &states_goal)?;
+
+    let mut buffer = Vec::new();
+    let mut cli_output = CliOutputBuilder::new_with_writer(&mut buffer)
+        .with_outcome_format(OutputFormat::Text)
+        .with_colorize(CliColorizeOpt::Never)
+        .build();
+    let mut presenter = CliMdPresenter::new(&mut cli_output);
+    states_diff_tree.present(&mut presenter).await?;
+
+    let output = String::from_utf8(buffer)?;
+    assert_eq!(
+        "\
+        = item_unchanged\n\
+        ~ item_changed\n  \
+          ~ logical: \"v1\" → \"v2\"\n\
+        - item_removed: \"abc\"\n\
+        + item_added: 1\n\
+        ",
+        output
+    );
+    Ok(())
+}
+
+fn states_current_and_goal() -> (StatesCurrent, StatesGoal) {
+    let states_current = {
+        let mut states = StatesMut::new();
+        states.insert(item_id!("item_unchanged"), 1u32);
+        states.insert(
+            item_id!("item_changed"),
+            State::new(String::from("v1"), 0u32),
+        );
+        states.insert(item_id!("item_removed"), String::from("abc"));
+        StatesCurrent::from(states)
+    };
+    let states_goal = {
+        let mut states = StatesMut::new();
+        states.insert(item_id!("item_unchanged"), 1u32);
+        states.insert(
+            item_id!("item_changed"),
+            State::new(String::from("v2"), 0u32),
+        );
+        states.insert(item_id!("item_added"), 1u32);
+        StatesGoal::from(states)
+    };
+    (states_current, states_goal)
+}
@@ -27,7 +27,7 @@ fn input_type_names_includes_states_ts0_and_states_ts1() {
 }
 
 #[test]
-fn outcome_type_names_includes_state_diffs_states_ts0_and_states_ts1() {
+fn outcome_type_names_includes_state_diffs_states_diff_tree_states_ts0_and_states_ts1() {
     macro_rules! assert_outcome_type_names {
         ($states_ts0:ident, $states_ts1:ident, $expected:expr) => {
             let cmd_block = DiffCmdBlock::<PeaceCmdCtxTypes, $states_ts0, $states_ts1>::new();
@@ -41,11 +41,21 @@ fn outcome_type_names_includes_state_diffs_states_ts0_and_states_ts1() {
     assert_outcome_type_names!(
         Current,
         Goal,
-        &["StateDiffs", "States<Current>", "States<Goal>"]
+        &[
+            "StateDiffs",
+            "StatesDiffTree",
+            "States<Current>",
+            "States<Goal>"
+        ]
     );
     assert_outcome_type_names!(
         CurrentStored,
         GoalStored,
-        &["StateDiffs", "States<CurrentStored>", "States<GoalStored>"]
+        &[
+            "StateDiffs",
+            "StatesDiffTree",
+            "States<CurrentStored>",
+            "States<GoalStored>"
+        ]
     );
 }
@@ -2,8 +2,18 @@ mod apply_stored_state_sync;
 mod clean_cmd;
 mod diff_cmd;
 mod ensure_cmd;
+mod params_diff_cmd;
+mod profile_copy_cmd;
+mod profile_create_cmd;
+mod profile_delete_cmd;
+mod profile_list_cmd;
+mod profile_parent_set_cmd;
+mod profile_rename_cmd;
+mod reencrypt_cmd;
 mod states_current_read_cmd;
 mod states_current_stored_display_cmd;
 mod states_discover_cmd;
 mod states_goal_display_cmd;
 mod states_goal_read_cmd;
+mod workspace_export_cmd;
+mod workspace_import_cmd;
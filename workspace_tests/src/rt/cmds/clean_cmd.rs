@@ -2,6 +2,7 @@ use peace::{
     cfg::{app_name, profile, FlowId},
     cmd::ctx::CmdCtx,
     cmd_model::CmdOutcome,
+    params::{ParamsSpec, ParamsSpecsApplied},
     resource_rt::type_reg::untagged::BoxDataTypeDowncast,
     rt::cmds::{
         ApplyStoredStateSync, CleanCmd, EnsureCmd, StatesCurrentReadCmd, StatesDiscoverCmd,
@@ -1022,6 +1023,139 @@ async fn states_current_not_serialized_on_states_discover_cmd_block_fail(
     Ok(())
 }
 
+#[tokio::test]
+async fn exec_cleans_using_params_applied_when_params_changed(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(
+            MockItem::<()>::default()
+                .with_apply(
+                    |_fn_ctx, params, _data, _state_current, state_target, _diff| {
+                        if params.0 == 1 {
+                            Ok(state_target.clone())
+                        } else {
+                            Err(MockItemError::Synthetic(format!(
+                                "Expected apply params to be `MockSrc(1)`, but was `{params:?}`"
+                            )))
+                        }
+                    },
+                )
+                .into(),
+        );
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let output = &mut NoOpOutput;
+
+    // Ensure states with `MockSrc(1)`.
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+    .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+    let CmdOutcome::Complete { .. } = EnsureCmd::exec(&mut cmd_ctx).await? else {
+        panic!("Expected `EnsureCmd::exec` to complete successfully.");
+    };
+
+    // Clean states after changing params to `MockSrc(2)`.
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(2).into())
+    .await?;
+    let CmdOutcome::Complete {
+        value: states_cleaned,
+        cmd_blocks_processed: _,
+    } = CleanCmd::exec(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `CleanCmd::exec` to complete successfully.");
+    };
+
+    assert_eq!(
+        Some(MockState::new()).as_ref(),
+        states_cleaned.get::<MockState, _>(MockItem::<()>::ID_DEFAULT)
+    );
+    // The command context's params specs are restored after cleaning.
+    assert!(matches!(
+        cmd_ctx
+            .view()
+            .params_specs
+            .get::<ParamsSpec<MockSrc>, _>(MockItem::<()>::ID_DEFAULT),
+        Some(ParamsSpec::Value { value: MockSrc(2) })
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_removes_params_applied_for_items_cleaned() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let output = &mut NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .with_item_params::<VecCopyItem>(VecCopyItem::ID_DEFAULT.clone(), VecA(vec![0, 1]).into())
+    .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+    let CmdOutcome::Complete { .. } = EnsureCmd::exec(&mut cmd_ctx).await? else {
+        panic!("Expected `EnsureCmd::exec` to complete successfully.");
+    };
+    let CmdOutcome::Complete { .. } = CleanCmd::exec(&mut cmd_ctx).await? else {
+        panic!("Expected `CleanCmd::exec` to complete successfully.");
+    };
+
+    // Params applied for cleaned items are not loaded for later commands.
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .with_item_params::<VecCopyItem>(VecCopyItem::ID_DEFAULT.clone(), VecA(vec![2]).into())
+    .await?;
+    let params_spec_applied_exists = cmd_ctx
+        .view()
+        .resources
+        .try_borrow::<ParamsSpecsApplied>()
+        .ok()
+        .and_then(|params_specs_applied| {
+            params_specs_applied
+                .get::<ParamsSpec<VecA>, _>(VecCopyItem::ID_DEFAULT)
+                .cloned()
+        })
+        .is_some();
+    assert!(!params_spec_applied_exists);
+
+    Ok(())
+}
+
 #[test]
 fn debug() {
     let debug_str = format!("{:?}", CleanCmd::<PeaceCmdCtxTypes>::default());
@@ -7,7 +7,7 @@ use peace::{
     params::ParamsSpec,
     resource_rt::states::{
         ts::{Current, CurrentStored, Goal, GoalStored},
-        StatesCurrent, StatesGoal,
+        StatesCurrent, StatesDiffTree, StatesGoal,
     },
     rt::cmds::{DiffCmd, StatesDiscoverCmd},
     rt_model::{output::OutputWrite, Flow, ItemGraphBuilder, Workspace, WorkspaceSpec},
@@ -96,6 +96,52 @@ async fn diff_stored_contains_state_diff_for_each_item() -> Result<(), Box<dyn s
     Ok(())
 }
 
+#[tokio::test]
+async fn diff_stored_inserts_states_diff_tree() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.add_fn(MockItem::<()>::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let output = &mut NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .with_item_params::<VecCopyItem>(
+        VecCopyItem::ID_DEFAULT.clone(),
+        VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+    )
+    .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+    .await?;
+    let CmdOutcome::Complete {
+        value: (states_current, states_goal),
+        cmd_blocks_processed: _,
+    } = StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesDiscoverCmd::current_and_goal` to complete successfully.");
+    };
+
+    DiffCmd::diff_stored(&mut cmd_ctx).await?;
+
+    let states_diff_tree = cmd_ctx.resources().borrow::<StatesDiffTree>();
+    let states_diff_tree_expected = StatesDiffTree::new(&states_current, &states_goal)?;
+    assert_eq!(states_diff_tree_expected, *states_diff_tree);
+    assert!(states_diff_tree.has_changes());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn diff_discover_current_on_demand() -> Result<(), Box<dyn std::error::Error>> {
     let tempdir = tempfile::tempdir()?;
@@ -5,8 +5,10 @@ use peace::{
         interruptible::{InterruptSignal, InterruptStrategy, Interruptibility},
     },
     cmd_model::{CmdBlockDesc, CmdOutcome},
+    params::{ParamsSpec, ParamsSpecsApplied},
     resource_rt::{
-        paths::{StatesCurrentFile, StatesGoalFile},
+        paths::{FlowDir, ParamsAppliedFile, StatesCurrentFile, StatesGoalFile},
+        states::{StatesDiffTree, StatesPrevious},
         type_reg::untagged::BoxDataTypeDowncast,
     },
     rt::cmds::{ApplyStoredStateSync, EnsureCmd, StatesCurrentReadCmd, StatesDiscoverCmd},
@@ -96,6 +98,108 @@ async fn resources_ensured_dry_does_not_alter_state() -> Result<(), Box<dyn std:
     Ok(())
 }
 
+#[tokio::test]
+async fn exec_dry_inserts_states_diff_tree() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.add_fn(MockItem::<()>::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let output = &mut NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .with_item_params::<VecCopyItem>(
+        VecCopyItem::ID_DEFAULT.clone(),
+        VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+    )
+    .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+    .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+
+    let CmdOutcome::Complete {
+        value: states_ensured_dry,
+        cmd_blocks_processed: _,
+    } = EnsureCmd::exec_dry(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `EnsureCmd::exec_dry` to complete successfully.");
+    };
+
+    let resources = cmd_ctx.resources();
+    let states_diff_tree = resources.borrow::<StatesDiffTree>();
+    let states_diff_tree_expected =
+        StatesDiffTree::new(&*resources.borrow::<StatesPrevious>(), &states_ensured_dry)?;
+    assert_eq!(states_diff_tree_expected, *states_diff_tree);
+    assert!(states_diff_tree.has_changes());
+    assert!(states_diff_tree.contains_key(VecCopyItem::ID_DEFAULT));
+    assert!(states_diff_tree.contains_key(MockItem::<()>::ID_DEFAULT));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_dry_removes_stale_states_diff_tree_when_nothing_applied(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.add_fn(MockItem::<()>::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let output = &mut NoOpOutput;
+
+    let (interrupt_tx, interrupt_rx) = mpsc::channel::<InterruptSignal>(16);
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_interruptibility(Interruptibility::new(
+        interrupt_rx.into(),
+        InterruptStrategy::FinishCurrent,
+    ))
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .with_item_params::<VecCopyItem>(
+        VecCopyItem::ID_DEFAULT.clone(),
+        VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+    )
+    .with_item_params::<MockItem<()>>(MockItem::<()>::ID_DEFAULT.clone(), MockSrc(1).into())
+    .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+    EnsureCmd::exec_dry(&mut cmd_ctx).await?;
+    assert!(cmd_ctx.resources().try_borrow::<StatesDiffTree>().is_ok());
+
+    // Interrupting before any item is applied leaves nothing to diff.
+    interrupt_tx.send(InterruptSignal).await?;
+    let cmd_outcome = EnsureCmd::exec_dry(&mut cmd_ctx).await?;
+
+    assert!(matches!(
+        cmd_outcome,
+        CmdOutcome::ExecutionInterrupted { .. }
+    ));
+    assert!(cmd_ctx.resources().try_borrow::<StatesDiffTree>().is_err());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn resources_ensured_contains_state_ensured_for_each_item_when_state_not_yet_ensured(
 ) -> Result<(), Box<dyn std::error::Error>> {
@@ -1922,6 +2026,69 @@ mock: 1
     Ok(())
 }
 
+#[tokio::test]
+async fn exec_records_params_applied_for_each_item_applied(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let output = &mut NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .with_item_params::<VecCopyItem>(VecCopyItem::ID_DEFAULT.clone(), VecA(vec![0, 1]).into())
+    .await?;
+    StatesDiscoverCmd::current_and_goal(&mut cmd_ctx).await?;
+    let CmdOutcome::Complete { .. } = EnsureCmd::exec(&mut cmd_ctx).await? else {
+        panic!("Expected `EnsureCmd::exec` to complete successfully.");
+    };
+    let params_applied_file = {
+        let flow_dir = cmd_ctx.view().resources.borrow::<FlowDir>();
+        ParamsAppliedFile::from(&*flow_dir)
+    };
+    assert!(params_applied_file.exists());
+
+    // Params applied are loaded when building the command context, even if the
+    // params specs have since changed.
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .with_item_params::<VecCopyItem>(VecCopyItem::ID_DEFAULT.clone(), VecA(vec![2]).into())
+    .await?;
+    let params_specs_applied = cmd_ctx
+        .view()
+        .resources
+        .try_borrow::<ParamsSpecsApplied>()
+        .ok()
+        .and_then(|params_specs_applied| {
+            params_specs_applied
+                .get::<ParamsSpec<VecA>, _>(VecCopyItem::ID_DEFAULT)
+                .cloned()
+        });
+    assert!(matches!(
+        params_specs_applied,
+        Some(ParamsSpec::Value { value: VecA(value) })
+        if value == [0u8, 1]
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn debug() {
     let debug_str = format!("{:?}", EnsureCmd::<PeaceCmdCtxTypes>::default());
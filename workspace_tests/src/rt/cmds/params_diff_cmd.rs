@@ -0,0 +1,290 @@
+use peace::{
+    cfg::{app_name, item_id, profile, FlowId, ItemId},
+    cli::output::CliOutputBuilder,
+    cli_model::OutputFormat,
+    cmd::ctx::CmdCtx,
+    params::{ParamsSpec, ParamsSpecsStored},
+    resource_rt::paths::{FlowDir, ParamsSpecsFile},
+    rt::cmds::ParamsDiffCmd,
+    rt_model::{output::OutputWrite, Flow, ItemGraphBuilder, Workspace, WorkspaceSpec},
+};
+use peace_items::tar_x::{TarXError, TarXItem, TarXParams};
+
+use crate::{NoOpOutput, PeaceTestError, VecA, VecCopyItem};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct TarXTest;
+
+impl TarXTest {
+    const ID: &'static ItemId = &item_id!("tar_x_test");
+}
+
+#[tokio::test]
+async fn exec_reports_changed_params_when_provided_params_differ_from_stored(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let output = &mut NoOpOutput;
+
+    // Store params specs.
+    let _cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .with_item_params::<VecCopyItem>(VecCopyItem::ID_DEFAULT.clone(), VecA(vec![0, 1]).into())
+    .await?;
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .with_item_params::<VecCopyItem>(VecCopyItem::ID_DEFAULT.clone(), VecA(vec![0, 2]).into())
+    .await?;
+
+    let params_diffs = ParamsDiffCmd::exec(&mut cmd_ctx).await?;
+
+    assert!(params_diffs.is_changed());
+    let item_params_diff = params_diffs
+        .get(VecCopyItem::ID_DEFAULT)
+        .expect("Expected `params_diffs` to contain diff for `VecCopyItem`.");
+    let field_diff = item_params_diff
+        .field_diffs
+        .first()
+        .expect("Expected `VecA` params to be diffed as a whole.");
+    assert_eq!(None, field_diff.field_name);
+    assert_eq!(
+        Some(serde_yaml::to_value(vec![0u8, 1])?),
+        field_diff.value_stored
+    );
+    assert_eq!(Some(serde_yaml::to_value(vec![0u8, 2])?), field_diff.value);
+    assert!(field_diff.spec_changed());
+    assert!(field_diff.value_changed());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_reports_no_changes_when_params_loaded_from_stored(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let output = &mut NoOpOutput;
+
+    // Store params specs.
+    let _cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .with_item_params::<VecCopyItem>(VecCopyItem::ID_DEFAULT.clone(), VecA(vec![0, 1]).into())
+    .await?;
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .with_item_params::<VecCopyItem>(VecCopyItem::ID_DEFAULT.clone(), ParamsSpec::Stored)
+    .await?;
+
+    let params_diffs = ParamsDiffCmd::exec(&mut cmd_ctx).await?;
+
+    assert!(!params_diffs.is_changed());
+    assert!(cmd_ctx
+        .view()
+        .resources
+        .try_borrow::<ParamsSpecsStored>()
+        .is_ok());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_reports_all_params_changed_when_no_params_stored(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let output = &mut NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .with_item_params::<VecCopyItem>(VecCopyItem::ID_DEFAULT.clone(), VecA(vec![0, 1]).into())
+    .await?;
+
+    let params_diffs = ParamsDiffCmd::exec(&mut cmd_ctx).await?;
+
+    assert!(params_diffs.is_changed());
+    let field_diff = &params_diffs
+        .get(VecCopyItem::ID_DEFAULT)
+        .expect("Expected `params_diffs` to contain diff for `VecCopyItem`.")
+        .field_diffs[0];
+    assert_eq!(None, field_diff.spec_stored);
+    assert_eq!(None, field_diff.value_stored);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn params_diffs_present_changed_values() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let output = &mut NoOpOutput;
+
+    let _cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .with_item_params::<VecCopyItem>(VecCopyItem::ID_DEFAULT.clone(), VecA(vec![1]).into())
+    .await?;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .with_item_params::<VecCopyItem>(VecCopyItem::ID_DEFAULT.clone(), VecA(vec![2]).into())
+    .await?;
+    let params_diffs = ParamsDiffCmd::exec(&mut cmd_ctx).await?;
+
+    let mut buffer = Vec::new();
+    let mut cli_output = CliOutputBuilder::new_with_writer(&mut buffer)
+        .with_outcome_format(OutputFormat::Text)
+        .build();
+    <_ as OutputWrite<PeaceTestError>>::present(&mut cli_output, &params_diffs).await?;
+
+    assert_eq!(
+        "1. `vec_copy`: params changed from `- 1` to `- 2`\n",
+        String::from_utf8(buffer)?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_reports_resolve_error_in_item_params_diff_when_stored_params_invalid(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<TarXError>::new();
+        graph_builder.add_fn(TarXItem::<TarXTest>::new(TarXTest::ID.clone()).into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let output = &mut NoOpOutput;
+
+    // Store params specs, then make the stored `tar_path` empty, which fails
+    // validation when the stored params are resolved.
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<TarXError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .with_item_params::<TarXItem<TarXTest>>(
+        TarXTest::ID.clone(),
+        TarXParams::<TarXTest>::new("stored.tar".into(), "dest".into()).into(),
+    )
+    .await?;
+    let params_specs_file = {
+        let flow_dir = cmd_ctx.view().resources.borrow::<FlowDir>();
+        ParamsSpecsFile::from(&*flow_dir)
+    };
+    let params_specs_stored = tokio::fs::read_to_string(&params_specs_file).await?;
+    assert!(params_specs_stored.contains("stored.tar"));
+    tokio::fs::write(
+        &params_specs_file,
+        params_specs_stored.replace("stored.tar", "''"),
+    )
+    .await?;
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<TarXError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .with_item_params::<TarXItem<TarXTest>>(
+        TarXTest::ID.clone(),
+        TarXParams::<TarXTest>::new("current.tar".into(), "dest".into()).into(),
+    )
+    .await?;
+
+    let params_diffs = ParamsDiffCmd::exec(&mut cmd_ctx).await?;
+
+    let item_params_diff = params_diffs
+        .get(TarXTest::ID)
+        .expect("Expected `params_diffs` to contain diff for `TarXItem`.");
+    assert!(item_params_diff.has_errors());
+    assert!(
+        item_params_diff
+            .value_stored_error
+            .as_deref()
+            .is_some_and(|error| error.contains("tar_path")),
+        "{item_params_diff:?}"
+    );
+    assert_eq!(None, item_params_diff.value_error);
+    let tar_path_field_diff = item_params_diff
+        .field_diffs
+        .iter()
+        .find(|field_diff| field_diff.field_name.as_deref() == Some("tar_path"))
+        .expect("Expected `tar_path` to be diffed.");
+    assert_eq!(None, tar_path_field_diff.value_stored);
+    assert_eq!(
+        Some(serde_yaml::to_value("current.tar")?),
+        tar_path_field_diff.value
+    );
+
+    Ok(())
+}
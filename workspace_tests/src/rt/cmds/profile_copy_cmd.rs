@@ -0,0 +1,237 @@
+use peace::{
+    cfg::{app_name, flow_id, profile},
+    cmd::ctx::CmdCtx,
+    resource_rt::paths::{FlowDir, ProfileDir},
+    rt::cmds::ProfileCopyCmd,
+    rt_model::{EncryptionKey, Error, ProfileChange, StorageEncryption},
+};
+
+use crate::{test_support::workspace_with, NoOpOutput, PeaceTestError};
+
+#[tokio::test]
+async fn exec_copies_profile_without_states() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let flow_id = flow_id!("test_flow");
+    let workspace = workspace_with(
+        &tempdir,
+        app_name!(),
+        &[profile!("test_profile")],
+        Some(&flow_id),
+    )
+    .await?;
+    let peace_app_dir = workspace.dirs().peace_app_dir();
+    let flow_dir = FlowDir::from((
+        &ProfileDir::from((peace_app_dir, &profile!("test_profile"))),
+        &flow_id,
+    ));
+    tokio::fs::write(flow_dir.join("states_current.yaml"), "{}\n").await?;
+    let output = &mut NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .await?;
+    let profile_change = ProfileCopyCmd::exec(
+        &mut cmd_ctx,
+        profile!("test_profile"),
+        profile!("test_profile_copy"),
+    )
+    .await?;
+
+    let profile_copy_dir = ProfileDir::from((peace_app_dir, &profile!("test_profile_copy")));
+    let flow_copy_dir = FlowDir::from((&profile_copy_dir, &flow_id));
+    assert_eq!(
+        ProfileChange::Copied {
+            profile_from: profile!("test_profile"),
+            profile_to: profile!("test_profile_copy"),
+            states_copied: false,
+        },
+        profile_change
+    );
+    assert!(profile_copy_dir.join("profile_params.yaml").exists());
+    assert!(flow_copy_dir.join("flow_params.yaml").exists());
+    assert!(!flow_copy_dir.join("states_current.yaml").exists());
+    assert!(flow_dir.join("states_current.yaml").exists());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_with_states_copies_profile_with_states() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let flow_id = flow_id!("test_flow");
+    let workspace = workspace_with(
+        &tempdir,
+        app_name!(),
+        &[profile!("test_profile")],
+        Some(&flow_id),
+    )
+    .await?;
+    let peace_app_dir = workspace.dirs().peace_app_dir();
+    let flow_dir = FlowDir::from((
+        &ProfileDir::from((peace_app_dir, &profile!("test_profile"))),
+        &flow_id,
+    ));
+    tokio::fs::create_dir_all(flow_dir.join("states_goal")).await?;
+    tokio::fs::write(flow_dir.join("states_goal").join("item.yaml"), "{}\n").await?;
+    let output = &mut NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .await?;
+    let profile_change = ProfileCopyCmd::exec_with_states(
+        &mut cmd_ctx,
+        profile!("test_profile"),
+        profile!("test_profile_copy"),
+    )
+    .await?;
+
+    let flow_copy_dir = FlowDir::from((
+        &ProfileDir::from((peace_app_dir, &profile!("test_profile_copy"))),
+        &flow_id,
+    ));
+    assert_eq!(
+        ProfileChange::Copied {
+            profile_from: profile!("test_profile"),
+            profile_to: profile!("test_profile_copy"),
+            states_copied: true,
+        },
+        profile_change
+    );
+    assert_eq!(
+        "{}\n",
+        tokio::fs::read_to_string(flow_copy_dir.join("states_goal").join("item.yaml")).await?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_with_states_reencrypts_encrypted_files_for_profile_to(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let flow_id = flow_id!("test_flow");
+    let encryption_key = EncryptionKey::generate();
+    let workspace = workspace_with(
+        &tempdir,
+        app_name!(),
+        &[profile!("test_profile")],
+        Some(&flow_id),
+    )
+    .await?
+    .with_encryption_key_provider(encryption_key.clone());
+    let storage_encryption = StorageEncryption::new(encryption_key);
+    let peace_app_dir = workspace.dirs().peace_app_dir();
+    let flow_dir = FlowDir::from((
+        &ProfileDir::from((peace_app_dir, &profile!("test_profile"))),
+        &flow_id,
+    ));
+    let states_goal_path = flow_dir.join("states_goal.yaml");
+    tokio::fs::write(
+        &states_goal_path,
+        storage_encryption.encrypt(&states_goal_path, b"{}\n")?,
+    )
+    .await?;
+    let output = &mut NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .await?;
+    ProfileCopyCmd::exec_with_states(
+        &mut cmd_ctx,
+        profile!("test_profile"),
+        profile!("test_profile_copy"),
+    )
+    .await?;
+
+    let flow_copy_dir = FlowDir::from((
+        &ProfileDir::from((peace_app_dir, &profile!("test_profile_copy"))),
+        &flow_id,
+    ));
+    let states_goal_copy_path = flow_copy_dir.join("states_goal.yaml");
+    let states_goal_copy_bytes = tokio::fs::read(&states_goal_copy_path).await?;
+    assert_eq!(
+        b"{}\n".to_vec(),
+        storage_encryption.decrypt(&states_goal_copy_path, &states_goal_copy_bytes)?
+    );
+    assert!(storage_encryption
+        .decrypt(&states_goal_path, &states_goal_copy_bytes)
+        .is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_returns_error_when_profile_to_exists() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace_with(
+        &tempdir,
+        app_name!(),
+        &[profile!("test_profile"), profile!("test_profile_other")],
+        None,
+    )
+    .await?;
+    let output = &mut NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .await?;
+    let error = ProfileCopyCmd::exec(
+        &mut cmd_ctx,
+        profile!("test_profile"),
+        profile!("test_profile_other"),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            PeaceTestError::PeaceRt(Error::ProfileExists { profile })
+            if profile == &profile!("test_profile_other")
+        ),
+        "Expected error to be `ProfileExists`, but was: {error:?}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_returns_error_when_profile_from_not_exists() -> Result<(), Box<dyn std::error::Error>>
+{
+    let tempdir = tempfile::tempdir()?;
+    let workspace =
+        workspace_with(&tempdir, app_name!(), &[profile!("test_profile")], None).await?;
+    let output = &mut NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .await?;
+    let error = ProfileCopyCmd::exec(
+        &mut cmd_ctx,
+        profile!("test_profile_missing"),
+        profile!("test_profile_copy"),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            PeaceTestError::PeaceRt(Error::ProfileNotInScope { profile, .. })
+            if profile == &profile!("test_profile_missing")
+        ),
+        "Expected error to be `ProfileNotInScope`, but was: {error:?}"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,154 @@
+use peace::{
+    cfg::{app_name, profile},
+    cmd::ctx::CmdCtx,
+    resource_rt::paths::{ProfileDir, ProfileHistoryDir, ProfileParentFile},
+    rt::cmds::{ProfileCreateCmd, ProfileListCmd},
+    rt_model::{Error, InMemoryStorageBackend, ProfileChange, Workspace, WorkspaceSpec},
+};
+
+use crate::{test_support::workspace_with, NoOpOutput, PeaceTestError};
+
+#[tokio::test]
+async fn exec_creates_profile_dirs() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace =
+        workspace_with(&tempdir, app_name!(), &[profile!("test_profile")], None).await?;
+    let output = &mut NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .await?;
+    let profile_change = ProfileCreateCmd::exec(&mut cmd_ctx, profile!("test_profile_new")).await?;
+
+    let profile_dir = ProfileDir::from((
+        workspace.dirs().peace_app_dir(),
+        &profile!("test_profile_new"),
+    ));
+    assert_eq!(
+        ProfileChange::Created {
+            profile: profile!("test_profile_new")
+        },
+        profile_change
+    );
+    assert!(profile_dir.exists());
+    assert!(ProfileHistoryDir::from(&profile_dir).exists());
+
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .await?;
+    assert_eq!(
+        vec![profile!("test_profile"), profile!("test_profile_new")],
+        ProfileListCmd::exec(&mut cmd_ctx).await?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_creates_profile_listed_by_in_memory_workspace(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let storage_backend = InMemoryStorageBackend::new();
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::InMemory(storage_backend.clone()),
+    )?;
+    let output = &mut NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .await?;
+    ProfileCreateCmd::exec(&mut cmd_ctx, profile!("test_profile_new")).await?;
+
+    let profile_dir = ProfileDir::from((
+        workspace.dirs().peace_app_dir(),
+        &profile!("test_profile_new"),
+    ));
+    assert!(storage_backend
+        .paths()
+        .iter()
+        .all(|path| !path.starts_with(&profile_dir)));
+
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .await?;
+    assert_eq!(
+        vec![profile!("test_profile_new")],
+        ProfileListCmd::exec(&mut cmd_ctx).await?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_returns_error_when_profile_exists() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace =
+        workspace_with(&tempdir, app_name!(), &[profile!("test_profile")], None).await?;
+    let output = &mut NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .await?;
+    let error = ProfileCreateCmd::exec(&mut cmd_ctx, profile!("test_profile"))
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            PeaceTestError::PeaceRt(Error::ProfileExists { profile })
+            if profile == &profile!("test_profile")
+        ),
+        "Expected error to be `ProfileExists`, but was: {error:?}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_with_parent_creates_profile_with_profile_parent(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace =
+        workspace_with(&tempdir, app_name!(), &[profile!("test_profile")], None).await?;
+    let output = &mut NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .await?;
+    let profile_change = ProfileCreateCmd::exec_with_parent(
+        &mut cmd_ctx,
+        profile!("test_profile_new"),
+        profile!("test_profile"),
+    )
+    .await?;
+
+    let profile_dir = ProfileDir::from((
+        workspace.dirs().peace_app_dir(),
+        &profile!("test_profile_new"),
+    ));
+    assert_eq!(
+        ProfileChange::Created {
+            profile: profile!("test_profile_new")
+        },
+        profile_change
+    );
+    assert_eq!(
+        "test_profile\n",
+        tokio::fs::read_to_string(ProfileParentFile::from(&profile_dir)).await?
+    );
+
+    Ok(())
+}
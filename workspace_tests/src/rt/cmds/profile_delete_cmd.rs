@@ -0,0 +1,137 @@
+use peace::{
+    cfg::{app_name, flow_id, profile},
+    cmd::ctx::CmdCtx,
+    resource_rt::paths::{FlowDir, ProfileDir},
+    rt::cmds::{ProfileDeleteCmd, ProfileParentSetCmd},
+    rt_model::{Error, ProfileChange},
+};
+
+use crate::{test_support::workspace_with, NoOpOutput, PeaceTestError};
+
+#[tokio::test]
+async fn exec_deletes_profile_without_states() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace_with(
+        &tempdir,
+        app_name!(),
+        &[profile!("test_profile"), profile!("test_profile_other")],
+        Some(&flow_id!("test_flow")),
+    )
+    .await?;
+    let profile_dir = ProfileDir::from((
+        workspace.dirs().peace_app_dir(),
+        &profile!("test_profile_other"),
+    ));
+    let output = &mut NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .await?;
+    let profile_change =
+        ProfileDeleteCmd::exec(&mut cmd_ctx, profile!("test_profile_other")).await?;
+
+    assert_eq!(
+        ProfileChange::Deleted {
+            profile: profile!("test_profile_other")
+        },
+        profile_change
+    );
+    assert!(!profile_dir.exists());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_returns_error_when_states_exist() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let flow_id = flow_id!("test_flow");
+    let workspace = workspace_with(
+        &tempdir,
+        app_name!(),
+        &[profile!("test_profile")],
+        Some(&flow_id),
+    )
+    .await?;
+    let profile_dir =
+        ProfileDir::from((workspace.dirs().peace_app_dir(), &profile!("test_profile")));
+    let states_current_file = FlowDir::from((&profile_dir, &flow_id)).join("states_current.yaml");
+    tokio::fs::write(&states_current_file, "{}\n").await?;
+    let output = &mut NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .await?;
+    let error = ProfileDeleteCmd::exec(&mut cmd_ctx, profile!("test_profile"))
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            PeaceTestError::PeaceRt(Error::ProfileStatesExist {
+                profile,
+                states_file_paths,
+            })
+            if profile == &profile!("test_profile")
+            && states_file_paths == std::slice::from_ref(&states_current_file)
+        ),
+        "Expected error to be `ProfileStatesExist`, but was: {error:?}"
+    );
+    assert!(profile_dir.exists());
+
+    // Deleting with states succeeds.
+    ProfileDeleteCmd::exec_with_states(&mut cmd_ctx, profile!("test_profile")).await?;
+    assert!(!profile_dir.exists());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_returns_error_when_profile_is_parent() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace_with(
+        &tempdir,
+        app_name!(),
+        &[profile!("test_profile"), profile!("test_profile_child")],
+        None,
+    )
+    .await?;
+    let profile_dir =
+        ProfileDir::from((workspace.dirs().peace_app_dir(), &profile!("test_profile")));
+    let output = &mut NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .await?;
+    ProfileParentSetCmd::exec(
+        &mut cmd_ctx,
+        profile!("test_profile_child"),
+        Some(profile!("test_profile")),
+    )
+    .await?;
+    let error = ProfileDeleteCmd::exec_with_states(&mut cmd_ctx, profile!("test_profile"))
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            PeaceTestError::PeaceRt(Error::ProfileParentOfProfiles {
+                profile,
+                profiles_child,
+            })
+            if profile == &profile!("test_profile")
+            && profiles_child == &[profile!("test_profile_child")]
+        ),
+        "was {error:#?}"
+    );
+    assert!(profile_dir.exists());
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use peace::{
+    cfg::{app_name, profile},
+    cmd::ctx::CmdCtx,
+    rt::cmds::ProfileListCmd,
+};
+
+use crate::{test_support::workspace_with, NoOpOutput, PeaceTestError};
+
+#[tokio::test]
+async fn exec_returns_profiles_in_sorted_order() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace_with(
+        &tempdir,
+        app_name!(),
+        &[profile!("test_profile_b"), profile!("test_profile_a")],
+        None,
+    )
+    .await?;
+    let output = &mut NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .await?;
+    let profiles = ProfileListCmd::exec(&mut cmd_ctx).await?;
+
+    assert_eq!(
+        vec![profile!("test_profile_a"), profile!("test_profile_b")],
+        profiles
+    );
+
+    Ok(())
+}
@@ -0,0 +1,191 @@
+use peace::{
+    cfg::{app_name, profile},
+    cmd::ctx::CmdCtx,
+    resource_rt::paths::{ProfileDir, ProfileParentFile},
+    rt::cmds::ProfileParentSetCmd,
+    rt_model::{Error, ProfileChange},
+};
+
+use crate::{test_support::workspace_with, NoOpOutput, PeaceTestError};
+
+#[tokio::test]
+async fn exec_sets_profile_parent() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace_with(
+        &tempdir,
+        app_name!(),
+        &[profile!("test_profile"), profile!("test_profile_parent")],
+        None,
+    )
+    .await?;
+    let output = &mut NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .await?;
+    let profile_change = ProfileParentSetCmd::exec(
+        &mut cmd_ctx,
+        profile!("test_profile"),
+        Some(profile!("test_profile_parent")),
+    )
+    .await?;
+
+    let profile_dir =
+        ProfileDir::from((workspace.dirs().peace_app_dir(), &profile!("test_profile")));
+    let profile_parent_file = ProfileParentFile::from(&profile_dir);
+    assert_eq!(
+        ProfileChange::ParentSet {
+            profile: profile!("test_profile"),
+            profile_parent: Some(profile!("test_profile_parent")),
+        },
+        profile_change
+    );
+    assert_eq!(
+        "test_profile_parent\n",
+        tokio::fs::read_to_string(&profile_parent_file).await?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_removes_profile_parent() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace_with(
+        &tempdir,
+        app_name!(),
+        &[profile!("test_profile"), profile!("test_profile_parent")],
+        None,
+    )
+    .await?;
+    let output = &mut NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .await?;
+    ProfileParentSetCmd::exec(
+        &mut cmd_ctx,
+        profile!("test_profile"),
+        Some(profile!("test_profile_parent")),
+    )
+    .await?;
+    let profile_change =
+        ProfileParentSetCmd::exec(&mut cmd_ctx, profile!("test_profile"), None).await?;
+
+    let profile_dir =
+        ProfileDir::from((workspace.dirs().peace_app_dir(), &profile!("test_profile")));
+    assert_eq!(
+        ProfileChange::ParentSet {
+            profile: profile!("test_profile"),
+            profile_parent: None,
+        },
+        profile_change
+    );
+    assert!(!ProfileParentFile::from(&profile_dir).exists());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_returns_error_when_profile_parent_not_found() -> Result<(), Box<dyn std::error::Error>>
+{
+    let tempdir = tempfile::tempdir()?;
+    let workspace =
+        workspace_with(&tempdir, app_name!(), &[profile!("test_profile")], None).await?;
+    let output = &mut NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .await?;
+    let error = ProfileParentSetCmd::exec(
+        &mut cmd_ctx,
+        profile!("test_profile"),
+        Some(profile!("test_profile_parent")),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            PeaceTestError::PeaceRt(Error::ProfileParentNotFound {
+                profile,
+                profile_parent,
+            })
+            if profile == &profile!("test_profile")
+            && profile_parent == &profile!("test_profile_parent")
+        ),
+        "was {error:#?}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_returns_error_when_profile_parent_is_descendant(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace_with(
+        &tempdir,
+        app_name!(),
+        &[
+            profile!("profile_a"),
+            profile!("profile_b"),
+            profile!("profile_c"),
+        ],
+        None,
+    )
+    .await?;
+    let output = &mut NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .await?;
+    ProfileParentSetCmd::exec(
+        &mut cmd_ctx,
+        profile!("profile_b"),
+        Some(profile!("profile_a")),
+    )
+    .await?;
+    ProfileParentSetCmd::exec(
+        &mut cmd_ctx,
+        profile!("profile_c"),
+        Some(profile!("profile_b")),
+    )
+    .await?;
+    let error = ProfileParentSetCmd::exec(
+        &mut cmd_ctx,
+        profile!("profile_a"),
+        Some(profile!("profile_c")),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            PeaceTestError::PeaceRt(Error::ProfileParentCycle {
+                profile,
+                profiles_cycle,
+            })
+            if profile == &profile!("profile_a")
+            && profiles_cycle == &[
+                profile!("profile_a"),
+                profile!("profile_c"),
+                profile!("profile_b"),
+                profile!("profile_a"),
+            ]
+        ),
+        "was {error:#?}"
+    );
+
+    Ok(())
+}
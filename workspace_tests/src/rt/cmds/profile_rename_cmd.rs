@@ -0,0 +1,109 @@
+use peace::{
+    cfg::{app_name, flow_id, profile},
+    cmd::ctx::CmdCtx,
+    resource_rt::paths::{FlowDir, ProfileDir, ProfileParentFile},
+    rt::cmds::{ProfileListCmd, ProfileParentSetCmd, ProfileRenameCmd},
+    rt_model::ProfileChange,
+};
+
+use crate::{test_support::workspace_with, NoOpOutput, PeaceTestError};
+
+#[tokio::test]
+async fn exec_moves_profile_with_states() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let flow_id = flow_id!("test_flow");
+    let workspace = workspace_with(
+        &tempdir,
+        app_name!(),
+        &[profile!("test_profile")],
+        Some(&flow_id),
+    )
+    .await?;
+    let peace_app_dir = workspace.dirs().peace_app_dir();
+    let profile_dir = ProfileDir::from((peace_app_dir, &profile!("test_profile")));
+    tokio::fs::write(
+        FlowDir::from((&profile_dir, &flow_id)).join("states_current.yaml"),
+        "{}\n",
+    )
+    .await?;
+    let output = &mut NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .await?;
+    let profile_change = ProfileRenameCmd::exec(
+        &mut cmd_ctx,
+        profile!("test_profile"),
+        profile!("test_profile_renamed"),
+    )
+    .await?;
+
+    let profile_renamed_dir = ProfileDir::from((peace_app_dir, &profile!("test_profile_renamed")));
+    assert_eq!(
+        ProfileChange::Renamed {
+            profile_from: profile!("test_profile"),
+            profile_to: profile!("test_profile_renamed"),
+        },
+        profile_change
+    );
+    assert!(!profile_dir.exists());
+    assert!(FlowDir::from((&profile_renamed_dir, &flow_id))
+        .join("states_current.yaml")
+        .exists());
+
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .await?;
+    assert_eq!(
+        vec![profile!("test_profile_renamed")],
+        ProfileListCmd::exec(&mut cmd_ctx).await?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_updates_profile_parent_of_child_profiles() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace_with(
+        &tempdir,
+        app_name!(),
+        &[profile!("test_profile"), profile!("test_profile_child")],
+        None,
+    )
+    .await?;
+    let output = &mut NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .await?;
+    ProfileParentSetCmd::exec(
+        &mut cmd_ctx,
+        profile!("test_profile_child"),
+        Some(profile!("test_profile")),
+    )
+    .await?;
+    ProfileRenameCmd::exec(
+        &mut cmd_ctx,
+        profile!("test_profile"),
+        profile!("test_profile_renamed"),
+    )
+    .await?;
+
+    let profile_child_dir = ProfileDir::from((
+        workspace.dirs().peace_app_dir(),
+        &profile!("test_profile_child"),
+    ));
+    assert_eq!(
+        "test_profile_renamed\n",
+        tokio::fs::read_to_string(ProfileParentFile::from(&profile_child_dir)).await?
+    );
+
+    Ok(())
+}
@@ -0,0 +1,368 @@
+use std::path::Path;
+
+use peace::{
+    cfg::{app_name, profile, FlowId},
+    cmd::ctx::CmdCtx,
+    rt::cmds::{ReencryptCmd, StatesCurrentReadCmd, StatesDiscoverCmd},
+    rt_model::{
+        EncryptionError, EncryptionKey, Error, Flow, InMemoryStorageBackend, ItemGraphBuilder,
+        StatesLayout, StorageBackend, StorageEncryption, Workspace, WorkspaceSpec,
+    },
+};
+
+use crate::{
+    peace_cmd_ctx_types::PeaceCmdCtxTypes, NoOpOutput, PeaceTestError, VecA, VecCopyItem,
+    VecCopyState,
+};
+
+#[tokio::test]
+async fn exec_reencrypts_files_with_new_key() -> Result<(), Box<dyn std::error::Error>> {
+    let storage_backend = InMemoryStorageBackend::new();
+    let key_old = EncryptionKey::generate();
+    let key_new = EncryptionKey::generate();
+    let flow = test_flow(FlowId::new(crate::fn_name_short!())?);
+    let output = &mut NoOpOutput;
+
+    // Store encrypted files with the old key, then rotate to the new key.
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::InMemory(storage_backend.clone()),
+    )?
+    .with_encryption_key_provider(key_old.clone());
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .with_item_params::<VecCopyItem>(
+        VecCopyItem::ID_DEFAULT.clone(),
+        VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+    )
+    .await?;
+    StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+    let file_paths_reencrypted = ReencryptCmd::exec(&mut cmd_ctx, key_new.clone()).await?;
+
+    let flow_dir = Path::new(".peace/workspace_tests/test_profile").join(flow.flow_id().as_str());
+    assert_eq!(
+        vec![
+            flow_dir.join("params_specs.yaml"),
+            flow_dir.join("states_current.yaml"),
+        ],
+        file_paths_reencrypted
+    );
+    let states_current_bytes = storage_backend
+        .get(&flow_dir.join("states_current.yaml"))
+        .expect("Expected `states_current.yaml` to be stored.");
+    assert!(StorageEncryption::is_encrypted(&states_current_bytes));
+
+    // Reading with the new key succeeds.
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::InMemory(storage_backend.clone()),
+    )?
+    .with_encryption_key_provider(key_new);
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .await?;
+    let states_current_stored = StatesCurrentReadCmd::exec(&mut cmd_ctx)
+        .await?
+        .value()
+        .cloned()
+        .expect("Expected states current stored to be read.");
+    assert_eq!(
+        Some(VecCopyState::new()).as_ref(),
+        states_current_stored.get::<VecCopyState, _>(VecCopyItem::ID_DEFAULT)
+    );
+
+    // Reading with the old key fails.
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::InMemory(storage_backend.clone()),
+    )?
+    .with_encryption_key_provider(key_old);
+    let cmd_ctx_result = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .await;
+    assert!(matches!(
+        cmd_ctx_result,
+        Err(PeaceTestError::PeaceRt(Error::Encryption(
+            EncryptionError::Decrypt { .. }
+        )))
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_encrypts_files_that_are_not_encrypted() -> Result<(), Box<dyn std::error::Error>> {
+    let storage_backend = InMemoryStorageBackend::new();
+    let flow = test_flow(FlowId::new(crate::fn_name_short!())?);
+    let output = &mut NoOpOutput;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::InMemory(storage_backend.clone()),
+    )?;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .with_item_params::<VecCopyItem>(
+        VecCopyItem::ID_DEFAULT.clone(),
+        VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+    )
+    .await?;
+    StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+
+    let file_paths_reencrypted =
+        ReencryptCmd::exec(&mut cmd_ctx, EncryptionKey::generate()).await?;
+
+    assert_eq!(2, file_paths_reencrypted.len());
+    file_paths_reencrypted.iter().for_each(|file_path| {
+        let bytes = storage_backend
+            .get(file_path)
+            .expect("Expected re-encrypted file to be stored.");
+        assert!(StorageEncryption::is_encrypted(&bytes));
+    });
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_reencrypts_per_item_states_files() -> Result<(), Box<dyn std::error::Error>> {
+    let storage_backend = InMemoryStorageBackend::new();
+    let flow = test_flow(FlowId::new(crate::fn_name_short!())?);
+    let output = &mut NoOpOutput;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::InMemory(storage_backend.clone()),
+    )?
+    .with_states_layout(StatesLayout::PerItem)
+    .with_encryption_key_provider(EncryptionKey::generate());
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .with_item_params::<VecCopyItem>(
+        VecCopyItem::ID_DEFAULT.clone(),
+        VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+    )
+    .await?;
+    StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+
+    let file_paths_reencrypted =
+        ReencryptCmd::exec(&mut cmd_ctx, EncryptionKey::generate()).await?;
+
+    let flow_dir = Path::new(".peace/workspace_tests/test_profile").join(flow.flow_id().as_str());
+    assert_eq!(
+        vec![
+            flow_dir.join("params_specs.yaml"),
+            flow_dir
+                .join("states_current")
+                .join(format!("{}.yaml", VecCopyItem::ID_DEFAULT)),
+        ],
+        file_paths_reencrypted
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_reencrypts_files_of_every_profile_and_flow() -> Result<(), Box<dyn std::error::Error>>
+{
+    let storage_backend = InMemoryStorageBackend::new();
+    let key_old = EncryptionKey::generate();
+    let flow = test_flow(FlowId::new(crate::fn_name_short!())?);
+    let flow_other = test_flow(FlowId::new("other_flow")?);
+    let output = &mut NoOpOutput;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::InMemory(storage_backend.clone()),
+    )?
+    .with_encryption_key_provider(key_old);
+    for (profile, flow) in [
+        (profile!("test_profile"), &flow),
+        (profile!("test_profile"), &flow_other),
+        (profile!("other_profile"), &flow),
+    ] {
+        let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+            output.into(),
+            (&workspace).into(),
+        )
+        .with_profile(profile)
+        .with_flow(flow.into())
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .await?;
+        StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+    }
+
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .await?;
+    let file_paths_reencrypted =
+        ReencryptCmd::exec(&mut cmd_ctx, EncryptionKey::generate()).await?;
+
+    let peace_app_dir = Path::new(".peace/workspace_tests");
+    let flow_dirs = [
+        peace_app_dir
+            .join("other_profile")
+            .join(flow.flow_id().as_str()),
+        peace_app_dir
+            .join("test_profile")
+            .join(flow.flow_id().as_str()),
+        peace_app_dir.join("test_profile").join("other_flow"),
+    ];
+    let file_paths_expected = flow_dirs
+        .iter()
+        .flat_map(|flow_dir| {
+            [
+                flow_dir.join("params_specs.yaml"),
+                flow_dir.join("states_current.yaml"),
+            ]
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(file_paths_expected, file_paths_reencrypted);
+    let paths_stored = storage_backend.paths_under(peace_app_dir).await?;
+    assert!(
+        paths_stored.iter().all(|path| path
+            .extension()
+            .is_none_or(|extension| extension != "reencrypt")),
+        "Expected temporary files to be renamed, but found: {paths_stored:?}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn states_read_returns_error_when_encryption_set_and_file_not_encrypted(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let storage_backend = InMemoryStorageBackend::new();
+    let flow = test_flow(FlowId::new(crate::fn_name_short!())?);
+    let output = &mut NoOpOutput;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::InMemory(storage_backend.clone()),
+    )?;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .with_item_params::<VecCopyItem>(
+        VecCopyItem::ID_DEFAULT.clone(),
+        VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+    )
+    .await?;
+    StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::InMemory(storage_backend.clone()),
+    )?
+    .with_encryption_key_provider(EncryptionKey::generate());
+    let cmd_ctx_result = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .await;
+
+    let flow_dir = Path::new(".peace/workspace_tests/test_profile").join(flow.flow_id().as_str());
+    assert!(matches!(
+        cmd_ctx_result,
+        Err(PeaceTestError::PeaceRt(Error::Encryption(EncryptionError::NotEncrypted { path })))
+            if path == flow_dir.join("params_specs.yaml")
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_does_not_write_files_when_current_key_is_wrong(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let storage_backend = InMemoryStorageBackend::new();
+    let flow = test_flow(FlowId::new(crate::fn_name_short!())?);
+    let output = &mut NoOpOutput;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::InMemory(storage_backend.clone()),
+    )?
+    .with_encryption_key_provider(EncryptionKey::generate());
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .with_item_params::<VecCopyItem>(
+        VecCopyItem::ID_DEFAULT.clone(),
+        VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+    )
+    .await?;
+    StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+
+    // Encrypt `params_specs.yaml` with a different key.
+    let flow_dir = Path::new(".peace/workspace_tests/test_profile").join(flow.flow_id().as_str());
+    let states_current_file = flow_dir.join("states_current.yaml");
+    let params_specs_file = flow_dir.join("params_specs.yaml");
+    let params_specs_bytes =
+        StorageEncryption::new(EncryptionKey::generate()).encrypt(&params_specs_file, b"{}\n")?;
+    storage_backend.insert(params_specs_file.clone(), params_specs_bytes.clone());
+    let states_current_bytes = storage_backend.get(&states_current_file);
+
+    let result = ReencryptCmd::exec(&mut cmd_ctx, EncryptionKey::generate()).await;
+
+    assert!(matches!(
+        result,
+        Err(PeaceTestError::PeaceRt(Error::Encryption(EncryptionError::Decrypt { path }))) if path == params_specs_file
+    ));
+    assert_eq!(
+        states_current_bytes,
+        storage_backend.get(&states_current_file)
+    );
+    assert_eq!(
+        Some(params_specs_bytes),
+        storage_backend.get(&params_specs_file)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn debug() {
+    let debug_str = format!("{:?}", ReencryptCmd::<PeaceCmdCtxTypes>::default());
+    assert_eq!(
+        r#"ReencryptCmd(PhantomData<workspace_tests::peace_cmd_ctx_types::PeaceCmdCtxTypes>)"#,
+        debug_str,
+    );
+}
+
+fn test_flow(flow_id: FlowId) -> Flow<PeaceTestError> {
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    Flow::new(flow_id, graph)
+}
@@ -1,9 +1,13 @@
 use peace::{
-    cfg::{app_name, profile, FlowId},
+    cfg::{app_name, item_id, profile, FlowId},
     cmd::ctx::CmdCtx,
     cmd_model::CmdOutcome,
+    resource_rt::states::StatesCurrentStored,
     rt::cmds::{StatesCurrentReadCmd, StatesDiscoverCmd},
-    rt_model::{Error, Flow, ItemGraphBuilder, Workspace, WorkspaceSpec},
+    rt_model::{
+        Error, Flow, InMemoryStorageBackend, ItemGraphBuilder, StatesLayout, Workspace,
+        WorkspaceSpec,
+    },
 };
 
 use crate::{
@@ -76,6 +80,83 @@ async fn reads_states_current_stored_from_disk_when_present(
     Ok(())
 }
 
+#[tokio::test]
+async fn reads_states_current_stored_of_given_items_when_per_item(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::InMemory(InMemoryStorageBackend::new()),
+    )?
+    .with_states_layout(StatesLayout::PerItem);
+    let item_one = item_id!("one");
+    let item_two = item_id!("two");
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fns([
+            VecCopyItem::new(item_one.clone()).into(),
+            VecCopyItem::new(item_two.clone()).into(),
+        ]);
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+
+    // Write current states to storage.
+    let output = &mut NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .with_item_params::<VecCopyItem>(item_one.clone(), VecA(vec![0, 1, 2]).into())
+    .with_item_params::<VecCopyItem>(item_two.clone(), VecA(vec![3, 4, 5]).into())
+    .await?;
+    let CmdOutcome::Complete {
+        value: states_current_from_discover,
+        cmd_blocks_processed: _,
+    } = StatesDiscoverCmd::current(&mut cmd_ctx).await?
+    else {
+        panic!("Expected `StatesDiscoverCmd::current` to complete successfully.");
+    };
+
+    // Only read `item_one`'s stored state at build and through the command.
+    let output = &mut NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .with_item_params::<VecCopyItem>(item_one.clone(), VecA(vec![0, 1, 2]).into())
+    .with_item_params::<VecCopyItem>(item_two.clone(), VecA(vec![3, 4, 5]).into())
+    .with_item_ids(vec![item_one.clone()])
+    .await?;
+    {
+        let states_current_stored_from_build = cmd_ctx.resources().borrow::<StatesCurrentStored>();
+        assert_eq!(
+            vec![&item_one],
+            states_current_stored_from_build.keys().collect::<Vec<_>>()
+        );
+    }
+    let CmdOutcome::Complete {
+        value: states_current_stored_from_read,
+        cmd_blocks_processed: _,
+    } = StatesCurrentReadCmd::exec_with_item_ids(&mut cmd_ctx, vec![item_one.clone()]).await?
+    else {
+        panic!("Expected `StatesCurrentReadCmd::exec_with_item_ids` to complete successfully.");
+    };
+
+    assert_eq!(
+        vec![&item_one],
+        states_current_stored_from_read.keys().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        states_current_from_discover.get::<VecCopyState, _>(&item_one),
+        states_current_stored_from_read.get::<VecCopyState, _>(&item_one)
+    );
+    Ok(())
+}
+
 #[tokio::test]
 async fn returns_error_when_states_not_on_disk() -> Result<(), Box<dyn std::error::Error>> {
     let tempdir = tempfile::tempdir()?;
@@ -1,3 +1,5 @@
+use std::path::Path;
+
 use peace::{
     cfg::{app_name, profile, FlowId, ItemId},
     cmd::ctx::CmdCtx,
@@ -8,7 +10,10 @@ use peace::{
         type_reg::untagged::{BoxDtDisplay, TypeReg},
     },
     rt::cmds::{EnsureCmd, StatesCurrentReadCmd, StatesDiscoverCmd, StatesGoalReadCmd},
-    rt_model::{Flow, ItemGraphBuilder, Workspace, WorkspaceSpec},
+    rt_model::{
+        Flow, InMemoryStorageBackend, ItemGraphBuilder, SerializationFormat, Workspace,
+        WorkspaceSpec,
+    },
 };
 
 use crate::{
@@ -1135,3 +1140,115 @@ fn debug() {
         debug_str
     );
 }
+
+#[tokio::test]
+async fn current_stores_states_in_in_memory_workspace() -> Result<(), Box<dyn std::error::Error>> {
+    let storage_backend = InMemoryStorageBackend::new();
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::InMemory(storage_backend.clone()),
+    )?;
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let output = &mut NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .with_item_params::<VecCopyItem>(
+        VecCopyItem::ID_DEFAULT.clone(),
+        VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+    )
+    .await?;
+
+    StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+
+    let flow_dir = Path::new(".peace/workspace_tests/test_profile").join(flow.flow_id().as_str());
+    let states_current_yaml = storage_backend
+        .get_string(&flow_dir.join("states_current.yaml"))
+        .expect("Expected `states_current.yaml` to be stored in memory.");
+    assert_eq!("vec_copy: []\n", states_current_yaml);
+    assert!(storage_backend
+        .paths()
+        .contains(&flow_dir.join("params_specs.yaml")));
+    assert!(!Path::new(".peace").exists());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn current_stores_states_in_workspace_serialization_format(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let flow_dir = Path::new(".peace/workspace_tests/test_profile").join(flow.flow_id().as_str());
+
+    for serialization_format in [SerializationFormat::Json, SerializationFormat::MessagePack] {
+        let storage_backend = InMemoryStorageBackend::new();
+        let workspace = Workspace::new(
+            app_name!(),
+            WorkspaceSpec::InMemory(storage_backend.clone()),
+        )?
+        .with_serialization_format(serialization_format);
+        let output = &mut NoOpOutput;
+        let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+            output.into(),
+            (&workspace).into(),
+        )
+        .with_profile(profile!("test_profile"))
+        .with_flow((&flow).into())
+        .with_item_params::<VecCopyItem>(
+            VecCopyItem::ID_DEFAULT.clone(),
+            VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+        )
+        .await?;
+
+        StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+
+        let states_current_file =
+            serialization_format.file_path(&flow_dir.join("states_current.yaml"));
+        let states_current_bytes = storage_backend
+            .get(&states_current_file)
+            .expect("Expected states current file to be stored in memory.");
+        assert!(serialization_format
+            .deserialize::<serde_yaml::Value>(&states_current_bytes)
+            .is_ok());
+        assert!(storage_backend
+            .get(&flow_dir.join("states_current.yaml"))
+            .is_none());
+
+        // Reading finds the file in its format, even when the workspace format differs.
+        let workspace = Workspace::new(
+            app_name!(),
+            WorkspaceSpec::InMemory(storage_backend.clone()),
+        )?;
+        let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+            output.into(),
+            (&workspace).into(),
+        )
+        .with_profile(profile!("test_profile"))
+        .with_flow((&flow).into())
+        .await?;
+        let states_current_stored = StatesCurrentReadCmd::exec(&mut cmd_ctx)
+            .await?
+            .value()
+            .cloned()
+            .expect("Expected states current stored to be read.");
+        assert_eq!(
+            Some(VecCopyState::new()).as_ref(),
+            states_current_stored.get::<VecCopyState, _>(VecCopyItem::ID_DEFAULT)
+        );
+    }
+
+    Ok(())
+}
@@ -1,9 +1,12 @@
 use peace::{
-    cfg::{app_name, profile, FlowId},
+    cfg::{app_name, item_id, profile, FlowId},
     cmd::ctx::CmdCtx,
     cmd_model::CmdOutcome,
     rt::cmds::{StatesDiscoverCmd, StatesGoalReadCmd},
-    rt_model::{Error, Flow, ItemGraphBuilder, Workspace, WorkspaceSpec},
+    rt_model::{
+        Error, Flow, InMemoryStorageBackend, ItemGraphBuilder, StatesLayout, Workspace,
+        WorkspaceSpec,
+    },
 };
 
 use crate::{
@@ -74,6 +77,59 @@ async fn reads_states_goal_from_disk_when_present() -> Result<(), Box<dyn std::e
     Ok(())
 }
 
+#[tokio::test]
+async fn reads_states_goal_of_given_items_when_per_item() -> Result<(), Box<dyn std::error::Error>>
+{
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::InMemory(InMemoryStorageBackend::new()),
+    )?
+    .with_states_layout(StatesLayout::PerItem);
+    let item_one = item_id!("one");
+    let item_two = item_id!("two");
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fns([
+            VecCopyItem::new(item_one.clone()).into(),
+            VecCopyItem::new(item_two.clone()).into(),
+        ]);
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let output = &mut NoOpOutput;
+
+    // Write goal states to storage.
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .with_item_params::<VecCopyItem>(item_one.clone(), VecA(vec![0, 1, 2]).into())
+    .with_item_params::<VecCopyItem>(item_two.clone(), VecA(vec![3, 4, 5]).into())
+    .await?;
+    StatesDiscoverCmd::goal(&mut cmd_ctx).await?;
+
+    // Only read `item_two`'s goal state.
+    let CmdOutcome::Complete {
+        value: states_goal_from_read,
+        cmd_blocks_processed: _,
+    } = StatesGoalReadCmd::exec_with_item_ids(&mut cmd_ctx, vec![item_two.clone()]).await?
+    else {
+        panic!("Expected `StatesGoalReadCmd::exec_with_item_ids` to complete successfully.");
+    };
+
+    assert_eq!(
+        vec![&item_two],
+        states_goal_from_read.keys().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        Some(VecCopyState::from(vec![3, 4, 5])).as_ref(),
+        states_goal_from_read.get::<VecCopyState, _>(&item_two)
+    );
+    Ok(())
+}
+
 #[tokio::test]
 async fn returns_error_when_states_not_on_disk() -> Result<(), Box<dyn std::error::Error>> {
     let tempdir = tempfile::tempdir()?;
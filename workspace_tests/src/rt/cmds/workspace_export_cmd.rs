@@ -0,0 +1,140 @@
+use std::io::Read;
+
+use peace::{
+    cfg::{app_name, flow_id, profile},
+    cmd::ctx::CmdCtx,
+    resource_rt::paths::{FlowDir, ProfileDir, ProfileParentFile},
+    rt::cmds::WorkspaceExportCmd,
+    rt_model::{WorkspaceArchiveManifest, WorkspaceArchiveProfile},
+};
+
+use crate::{test_support::workspace_with, NoOpOutput, PeaceTestError};
+
+#[tokio::test]
+async fn exec_exports_all_profiles_and_workspace_params() -> Result<(), Box<dyn std::error::Error>>
+{
+    let tempdir = tempfile::tempdir()?;
+    let flow_id = flow_id!("test_flow");
+    let workspace = workspace_with(
+        &tempdir,
+        app_name!(),
+        &[profile!("test_profile"), profile!("test_profile_other")],
+        Some(&flow_id),
+    )
+    .await?;
+    let peace_app_dir = workspace.dirs().peace_app_dir();
+    let profile_dir = ProfileDir::from((peace_app_dir, &profile!("test_profile")));
+    let flow_dir = FlowDir::from((&profile_dir, &flow_id));
+    tokio::fs::write(flow_dir.join("states_current.yaml"), "{}\n").await?;
+    tokio::fs::create_dir_all(profile_dir.join(".history")).await?;
+    tokio::fs::write(profile_dir.join(".history").join("0.yaml"), "{}\n").await?;
+    let output = &mut NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .await?;
+    let archive_bytes = WorkspaceExportCmd::exec(&mut cmd_ctx).await?;
+
+    let (manifest, mut paths) = archive_read(&archive_bytes)?;
+    paths.sort();
+    assert_eq!(
+        vec![
+            WorkspaceArchiveProfile {
+                profile: profile!("test_profile"),
+                flow_ids: vec![flow_id.clone()],
+            },
+            WorkspaceArchiveProfile {
+                profile: profile!("test_profile_other"),
+                flow_ids: vec![flow_id.clone()],
+            },
+        ],
+        manifest.profiles
+    );
+    assert_eq!(app_name!(), manifest.app_name);
+    assert_eq!(
+        WorkspaceArchiveManifest::ARCHIVE_VERSION,
+        manifest.archive_version
+    );
+    let archive_app_dir = format!(".peace/{}", app_name!());
+    assert!(paths.contains(&format!("{archive_app_dir}/workspace_params.yaml")));
+    assert!(paths.contains(&format!(
+        "{archive_app_dir}/test_profile/test_flow/states_current.yaml"
+    )));
+    assert!(paths.contains(&format!(
+        "{archive_app_dir}/test_profile_other/profile_params.yaml"
+    )));
+    assert!(!paths.iter().any(|path| path.contains(".history")));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_profile_exports_profile_and_its_parents() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let workspace = workspace_with(
+        &tempdir,
+        app_name!(),
+        &[
+            profile!("test_profile"),
+            profile!("test_profile_parent"),
+            profile!("test_profile_other"),
+        ],
+        None,
+    )
+    .await?;
+    let peace_app_dir = workspace.dirs().peace_app_dir();
+    let profile_dir = ProfileDir::from((peace_app_dir, &profile!("test_profile")));
+    tokio::fs::write(
+        ProfileParentFile::from(&profile_dir),
+        "test_profile_parent\n",
+    )
+    .await?;
+    let output = &mut NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .await?;
+    let archive_bytes =
+        WorkspaceExportCmd::exec_profile(&mut cmd_ctx, profile!("test_profile")).await?;
+
+    let (manifest, paths) = archive_read(&archive_bytes)?;
+    assert_eq!(
+        vec![profile!("test_profile"), profile!("test_profile_parent")],
+        manifest
+            .profiles
+            .into_iter()
+            .map(|archive_profile| archive_profile.profile)
+            .collect::<Vec<_>>()
+    );
+    assert!(!paths.iter().any(|path| path.contains("test_profile_other")));
+
+    Ok(())
+}
+
+/// Returns the manifest and the paths of the other files in the archive.
+fn archive_read(
+    archive_bytes: &[u8],
+) -> Result<(WorkspaceArchiveManifest, Vec<String>), Box<dyn std::error::Error>> {
+    let mut manifest = None;
+    let mut paths = Vec::new();
+    for entry in tar::Archive::new(archive_bytes).entries()? {
+        let mut entry = entry?;
+        let path = entry.path()?.to_string_lossy().into_owned();
+        if path == WorkspaceArchiveManifest::PATH {
+            let mut manifest_str = String::new();
+            entry.read_to_string(&mut manifest_str)?;
+            manifest = Some(serde_yaml::from_str(&manifest_str)?);
+        } else {
+            paths.push(path);
+        }
+    }
+
+    Ok((
+        manifest.ok_or("Expected archive to contain manifest.")?,
+        paths,
+    ))
+}
@@ -0,0 +1,440 @@
+use peace::{
+    cfg::{app_name, flow_id, profile, FlowId},
+    cmd::ctx::CmdCtx,
+    resource_rt::paths::{FlowDir, ProfileDir},
+    rt::cmds::{WorkspaceExportCmd, WorkspaceImportCmd},
+    rt_model::{Error, Flow, ItemGraphBuilder, WorkspaceArchiveManifest, WorkspaceArchiveProfile},
+};
+
+use crate::{test_support::workspace_with, NoOpOutput, PeaceTestError};
+
+#[tokio::test]
+async fn exec_imports_profiles_from_archive() -> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = flow_id!("test_flow");
+    let tempdir_from = tempfile::tempdir()?;
+    let archive_bytes = archive_export(&tempdir_from, &[profile!("test_profile")]).await?;
+    let tempdir_to = tempfile::tempdir()?;
+    let workspace = workspace_with(
+        &tempdir_to,
+        app_name!(),
+        &[profile!("test_profile_existing")],
+        None,
+    )
+    .await?;
+    let peace_app_dir = workspace.dirs().peace_app_dir();
+    let workspace_params_before =
+        tokio::fs::read_to_string(peace_app_dir.join("workspace_params.yaml")).await?;
+    let output = &mut NoOpOutput;
+
+    let flow = test_flow(flow_id.clone());
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_flow((&flow).into())
+    .await?;
+    let manifest = WorkspaceImportCmd::exec(&mut cmd_ctx, &archive_bytes).await?;
+
+    let profile_dir = ProfileDir::from((peace_app_dir, &profile!("test_profile")));
+    let flow_dir = FlowDir::from((&profile_dir, &flow_id));
+    assert_eq!(vec![flow_id.clone()], manifest.flow_ids());
+    assert!(profile_dir.join("profile_params.yaml").exists());
+    assert!(flow_dir.join("flow_params.yaml").exists());
+    assert_eq!(
+        "{}\n",
+        tokio::fs::read_to_string(flow_dir.join("states_current.yaml")).await?
+    );
+    assert_eq!(
+        workspace_params_before,
+        tokio::fs::read_to_string(peace_app_dir.join("workspace_params.yaml")).await?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_returns_error_when_app_name_mismatch() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir_from = tempfile::tempdir()?;
+    let archive_bytes = archive_export(&tempdir_from, &[profile!("test_profile")]).await?;
+    let tempdir_to = tempfile::tempdir()?;
+    let workspace = workspace_with(
+        &tempdir_to,
+        app_name!("test_app_other"),
+        &[profile!("test_profile_existing")],
+        None,
+    )
+    .await?;
+    let output = &mut NoOpOutput;
+
+    let flow = test_flow(flow_id!("test_flow"));
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_flow((&flow).into())
+    .await?;
+    let error = WorkspaceImportCmd::exec(&mut cmd_ctx, &archive_bytes)
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            PeaceTestError::PeaceRt(Error::WorkspaceArchiveAppNameMismatch {
+                app_name_archive,
+                app_name_workspace,
+            })
+            if app_name_archive == &app_name!()
+            && app_name_workspace == &app_name!("test_app_other")
+        ),
+        "Expected error to be `WorkspaceArchiveAppNameMismatch`, but was: {error:?}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_returns_error_when_flow_id_unknown() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir_from = tempfile::tempdir()?;
+    let archive_bytes = archive_export(&tempdir_from, &[profile!("test_profile")]).await?;
+    let tempdir_to = tempfile::tempdir()?;
+    let workspace = workspace_with(
+        &tempdir_to,
+        app_name!(),
+        &[profile!("test_profile_existing")],
+        None,
+    )
+    .await?;
+    let output = &mut NoOpOutput;
+
+    let flow = test_flow(flow_id!("test_flow_other"));
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_flow((&flow).into())
+    .await?;
+    let error = WorkspaceImportCmd::exec(&mut cmd_ctx, &archive_bytes)
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            PeaceTestError::PeaceRt(Error::WorkspaceArchiveFlowIdsUnknown {
+                flow_ids_unknown,
+                ..
+            })
+            if flow_ids_unknown == &[flow_id!("test_flow")]
+        ),
+        "Expected error to be `WorkspaceArchiveFlowIdsUnknown`, but was: {error:?}"
+    );
+    let profile_dir =
+        ProfileDir::from((workspace.dirs().peace_app_dir(), &profile!("test_profile")));
+    assert!(!profile_dir.exists());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_returns_error_when_profile_exists() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir_from = tempfile::tempdir()?;
+    let archive_bytes = archive_export(&tempdir_from, &[profile!("test_profile")]).await?;
+    let tempdir_to = tempfile::tempdir()?;
+    let workspace =
+        workspace_with(&tempdir_to, app_name!(), &[profile!("test_profile")], None).await?;
+    let output = &mut NoOpOutput;
+
+    let flow = test_flow(flow_id!("test_flow"));
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_flow((&flow).into())
+    .await?;
+    let error = WorkspaceImportCmd::exec(&mut cmd_ctx, &archive_bytes)
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            PeaceTestError::PeaceRt(Error::ProfileExists { profile })
+            if profile == &profile!("test_profile")
+        ),
+        "Expected error to be `ProfileExists`, but was: {error:?}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_returns_error_when_entry_profile_not_in_manifest(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir_from = tempfile::tempdir()?;
+    let archive_bytes = archive_export(&tempdir_from, &[profile!("test_profile")]).await?;
+    let archive_bytes = archive_with_entry(
+        &archive_bytes,
+        &format!(
+            ".peace/{}/test_profile_existing/profile_params.yaml",
+            app_name!()
+        ),
+        b"overwritten: true\n",
+    )?;
+    let tempdir_to = tempfile::tempdir()?;
+    let workspace = workspace_with(
+        &tempdir_to,
+        app_name!(),
+        &[profile!("test_profile_existing")],
+        None,
+    )
+    .await?;
+    let peace_app_dir = workspace.dirs().peace_app_dir();
+    let profile_dir_existing =
+        ProfileDir::from((peace_app_dir, &profile!("test_profile_existing")));
+    let profile_params_before =
+        tokio::fs::read_to_string(profile_dir_existing.join("profile_params.yaml")).await?;
+    let output = &mut NoOpOutput;
+
+    let flow = test_flow(flow_id!("test_flow"));
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_flow((&flow).into())
+    .await?;
+    let error = WorkspaceImportCmd::exec(&mut cmd_ctx, &archive_bytes)
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            PeaceTestError::PeaceRt(Error::WorkspaceArchiveEntryProfileUnknown {
+                profile,
+                profiles_manifest,
+                ..
+            })
+            if profile == "test_profile_existing"
+            && profiles_manifest == &[profile!("test_profile")]
+        ),
+        "Expected error to be `WorkspaceArchiveEntryProfileUnknown`, but was: {error:?}"
+    );
+    assert_eq!(
+        profile_params_before,
+        tokio::fs::read_to_string(profile_dir_existing.join("profile_params.yaml")).await?
+    );
+    assert!(!ProfileDir::from((peace_app_dir, &profile!("test_profile"))).exists());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_returns_error_when_entry_flow_id_not_in_manifest(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir_from = tempfile::tempdir()?;
+    let archive_bytes = archive_export(&tempdir_from, &[profile!("test_profile")]).await?;
+    let archive_bytes = archive_with_entry(
+        &archive_bytes,
+        &format!(
+            ".peace/{}/test_profile/other_flow/states_current.yaml",
+            app_name!()
+        ),
+        b"{}\n",
+    )?;
+    let tempdir_to = tempfile::tempdir()?;
+    let workspace = workspace_with(
+        &tempdir_to,
+        app_name!(),
+        &[profile!("test_profile_existing")],
+        None,
+    )
+    .await?;
+    let peace_app_dir = workspace.dirs().peace_app_dir();
+    let output = &mut NoOpOutput;
+
+    let flow = test_flow(flow_id!("test_flow"));
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_flow((&flow).into())
+    .await?;
+    let error = WorkspaceImportCmd::exec(&mut cmd_ctx, &archive_bytes)
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            PeaceTestError::PeaceRt(Error::WorkspaceArchiveEntryFlowIdUnknown {
+                profile,
+                flow_id,
+                flow_ids_manifest,
+                ..
+            })
+            if profile == &profile!("test_profile")
+            && flow_id == "other_flow"
+            && flow_ids_manifest == &[flow_id!("test_flow")]
+        ),
+        "Expected error to be `WorkspaceArchiveEntryFlowIdUnknown`, but was: {error:?}"
+    );
+    assert!(!ProfileDir::from((peace_app_dir, &profile!("test_profile"))).exists());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_returns_error_when_manifest_profile_invalid() -> Result<(), Box<dyn std::error::Error>>
+{
+    let archive_bytes = {
+        let profile = serde_yaml::from_str::<peace::cfg::Profile>("../../escaped")?;
+        let manifest = WorkspaceArchiveManifest::new(
+            app_name!(),
+            vec![WorkspaceArchiveProfile {
+                profile,
+                flow_ids: Vec::new(),
+            }],
+        );
+        let manifest_bytes = serde_yaml::to_string(&manifest)?.into_bytes();
+        let mut builder = tar::Builder::new(Vec::new());
+        let mut header = tar::Header::new_gnu();
+        header.set_size(manifest_bytes.len() as u64);
+        header.set_mode(0o644);
+        header.set_entry_type(tar::EntryType::Regular);
+        builder.append_data(
+            &mut header,
+            WorkspaceArchiveManifest::PATH,
+            manifest_bytes.as_slice(),
+        )?;
+        builder.into_inner()?
+    };
+    let tempdir_to = tempfile::tempdir()?;
+    let workspace = workspace_with(
+        &tempdir_to,
+        app_name!(),
+        &[profile!("test_profile_existing")],
+        None,
+    )
+    .await?;
+    let output = &mut NoOpOutput;
+
+    let flow = test_flow(flow_id!("test_flow"));
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_flow((&flow).into())
+    .await?;
+    let error = WorkspaceImportCmd::exec(&mut cmd_ctx, &archive_bytes)
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            PeaceTestError::PeaceRt(Error::WorkspaceArchiveProfileInvalid { profile })
+            if profile == "../../escaped"
+        ),
+        "Expected error to be `WorkspaceArchiveProfileInvalid`, but was: {error:?}"
+    );
+    assert!(!tempdir_to.path().join("escaped").exists());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exec_returns_error_when_entry_size_exceeds_archive(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let archive_bytes = {
+        let mut header = tar::Header::new_gnu();
+        header.set_path(format!(".peace/{}/workspace_params.yaml", app_name!()))?;
+        header.set_size(u64::MAX);
+        header.set_mode(0o644);
+        header.set_entry_type(tar::EntryType::Regular);
+        header.set_cksum();
+        header.as_bytes().to_vec()
+    };
+    let tempdir_to = tempfile::tempdir()?;
+    let workspace = workspace_with(
+        &tempdir_to,
+        app_name!(),
+        &[profile!("test_profile_existing")],
+        None,
+    )
+    .await?;
+    let output = &mut NoOpOutput;
+
+    let flow = test_flow(flow_id!("test_flow"));
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_flow((&flow).into())
+    .await?;
+    let error = WorkspaceImportCmd::exec(&mut cmd_ctx, &archive_bytes)
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            PeaceTestError::PeaceRt(Error::WorkspaceArchiveRead(_))
+        ),
+        "Expected error to be `WorkspaceArchiveRead`, but was: {error:?}"
+    );
+
+    Ok(())
+}
+
+/// Returns a copy of the archive with an additional entry.
+fn archive_with_entry(
+    archive_bytes: &[u8],
+    path: &str,
+    bytes: &[u8],
+) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let mut builder = tar::Builder::new(Vec::new());
+    let mut archive = tar::Archive::new(archive_bytes);
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let mut header = entry.header().clone();
+        let path = entry.path()?.into_owned();
+        builder.append_data(&mut header, path, &mut entry)?;
+    }
+    let mut header = tar::Header::new_gnu();
+    header.set_size(bytes.len() as u64);
+    header.set_mode(0o644);
+    header.set_entry_type(tar::EntryType::Regular);
+    builder.append_data(&mut header, path, bytes)?;
+
+    Ok(builder.into_inner()?)
+}
+
+/// Returns an archive of a workspace with the given profiles, each with a
+/// `test_flow` that has stored current states.
+async fn archive_export(
+    tempdir: &tempfile::TempDir,
+    profiles: &[peace::cfg::Profile],
+) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let flow_id = flow_id!("test_flow");
+    let workspace = workspace_with(tempdir, app_name!(), profiles, Some(&flow_id)).await?;
+    let peace_app_dir = workspace.dirs().peace_app_dir();
+    for profile in profiles {
+        let flow_dir = FlowDir::from((&ProfileDir::from((peace_app_dir, profile)), &flow_id));
+        tokio::fs::write(flow_dir.join("states_current.yaml"), "{}\n").await?;
+    }
+    let output = &mut NoOpOutput;
+
+    let mut cmd_ctx = CmdCtx::builder_multi_profile_no_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .await?;
+    let archive_bytes = WorkspaceExportCmd::exec(&mut cmd_ctx).await?;
+
+    Ok(archive_bytes)
+}
+
+fn test_flow(flow_id: FlowId) -> Flow<PeaceTestError> {
+    Flow::new(flow_id, ItemGraphBuilder::new().build())
+}
@@ -1,11 +1,24 @@
+mod encryption_key;
+mod encryption_key_env_var;
+mod encryption_key_file;
 #[cfg(feature = "error_reporting")]
 mod error;
+mod in_memory_storage_backend;
 mod item_boxed;
 mod item_graph;
 mod item_graph_builder;
+mod item_params_diff;
 mod item_wrapper;
 mod native;
 mod outcomes;
+mod output;
+#[cfg(feature = "json_schema")]
+mod params_map_schema_builder;
+#[cfg(feature = "json_schema")]
+mod params_specs_schema_builder;
+mod serialization_format;
+mod states_layout;
 mod states_serializer;
 mod storage;
+mod storage_encryption;
 mod workspace_dirs_builder;
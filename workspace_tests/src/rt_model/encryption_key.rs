@@ -0,0 +1,59 @@
+use peace::rt_model::{EncryptionError, EncryptionKey, EncryptionKeyProvider};
+
+#[test]
+fn from_base64_decodes_32_byte_key() -> Result<(), EncryptionError> {
+    let encryption_key =
+        EncryptionKey::from_base64("AQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQE=\n")?;
+
+    assert_eq!(&[1u8; 32], encryption_key.as_bytes());
+    Ok(())
+}
+
+#[test]
+fn from_base64_returns_error_when_not_base64() {
+    let result = EncryptionKey::from_base64("not base64!");
+
+    assert!(matches!(result, Err(EncryptionError::KeyDecode(_))));
+}
+
+#[test]
+fn from_base64_returns_error_when_not_32_bytes() {
+    let result = EncryptionKey::from_base64("AQEBAQ==");
+
+    assert!(matches!(
+        result,
+        Err(EncryptionError::KeyLength { length: 4 })
+    ));
+}
+
+#[test]
+fn to_base64_round_trips() -> Result<(), EncryptionError> {
+    let encryption_key = EncryptionKey::generate();
+
+    assert_eq!(
+        encryption_key,
+        EncryptionKey::from_base64(&encryption_key.to_base64())?
+    );
+    Ok(())
+}
+
+#[test]
+fn generate_returns_different_keys() {
+    assert_ne!(EncryptionKey::generate(), EncryptionKey::generate());
+}
+
+#[test]
+fn key_returns_self() -> Result<(), EncryptionError> {
+    let encryption_key = EncryptionKey::new([2u8; 32]);
+
+    assert_eq!(encryption_key, EncryptionKeyProvider::key(&encryption_key)?);
+    Ok(())
+}
+
+#[test]
+fn debug_does_not_print_key() {
+    assert_eq!(
+        "EncryptionKey(..)",
+        format!("{:?}", EncryptionKey::new([1u8; 32]))
+    );
+}
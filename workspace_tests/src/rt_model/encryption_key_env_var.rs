@@ -0,0 +1,30 @@
+use peace::rt_model::{EncryptionError, EncryptionKey, EncryptionKeyEnvVar, EncryptionKeyProvider};
+
+#[test]
+fn key_reads_key_from_env_var() -> Result<(), EncryptionError> {
+    // Only this test uses this variable, as tests run in parallel.
+    let env_var = "PEACE_TEST_ENCRYPTION_KEY_ENV_VAR_KEY_READS_KEY";
+    let encryption_key = EncryptionKey::generate();
+    std::env::set_var(env_var, encryption_key.to_base64());
+
+    let encryption_key_env_var = EncryptionKeyEnvVar::new(env_var);
+    let encryption_key_read = encryption_key_env_var.key();
+    std::env::remove_var(env_var);
+
+    assert_eq!(env_var, encryption_key_env_var.env_var());
+    assert_eq!(encryption_key, encryption_key_read?);
+    Ok(())
+}
+
+#[test]
+fn key_returns_error_when_env_var_not_set() {
+    let env_var = "PEACE_TEST_ENCRYPTION_KEY_ENV_VAR_NOT_SET";
+
+    let result = EncryptionKeyEnvVar::new(env_var).key();
+
+    assert!(matches!(
+        result,
+        Err(EncryptionError::KeyEnvVarRead { env_var: env_var_actual, .. })
+            if env_var_actual == env_var
+    ));
+}
@@ -0,0 +1,29 @@
+use peace::rt_model::{EncryptionError, EncryptionKey, EncryptionKeyFile, EncryptionKeyProvider};
+
+#[test]
+fn key_reads_key_from_file() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let key_path = tempdir.path().join("key");
+    let encryption_key = EncryptionKey::generate();
+    std::fs::write(&key_path, format!("{}\n", encryption_key.to_base64()))?;
+
+    let encryption_key_file = EncryptionKeyFile::new(key_path.clone());
+
+    assert_eq!(key_path, encryption_key_file.path());
+    assert_eq!(encryption_key, encryption_key_file.key()?);
+    Ok(())
+}
+
+#[test]
+fn key_returns_error_when_file_not_exists() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let key_path = tempdir.path().join("key");
+
+    let result = EncryptionKeyFile::new(key_path.clone()).key();
+
+    assert!(matches!(
+        result,
+        Err(EncryptionError::KeyFileRead { path, .. }) if path == key_path
+    ));
+    Ok(())
+}
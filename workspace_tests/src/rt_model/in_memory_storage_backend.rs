@@ -0,0 +1,135 @@
+use std::path::{Path, PathBuf};
+
+use peace::rt_model::{InMemoryStorageBackend, StorageBackend};
+
+#[test]
+fn clone_shares_items() {
+    let storage_backend = InMemoryStorageBackend::new();
+    let storage_backend_clone = storage_backend.clone();
+
+    storage_backend_clone.insert(PathBuf::from("a.yaml"), "a: 1\n");
+
+    assert_eq!(
+        Some(String::from("a: 1\n")),
+        storage_backend.get_string(Path::new("a.yaml"))
+    );
+}
+
+#[test]
+fn partial_eq_compares_items() {
+    let storage_backend_0 = InMemoryStorageBackend::new();
+    let storage_backend_1 = InMemoryStorageBackend::new();
+    assert_eq!(storage_backend_0, storage_backend_1);
+
+    storage_backend_0.insert(PathBuf::from("a.yaml"), "a: 1\n");
+    assert_ne!(storage_backend_0, storage_backend_1);
+
+    storage_backend_1.insert(PathBuf::from("a.yaml"), "a: 1\n");
+    assert_eq!(storage_backend_0, storage_backend_1);
+}
+
+#[test]
+fn paths_returns_sorted_paths() {
+    let storage_backend = InMemoryStorageBackend::new();
+    storage_backend.insert(PathBuf::from("b.yaml"), "");
+    storage_backend.insert(PathBuf::from("a.yaml"), "");
+
+    assert_eq!(
+        vec![PathBuf::from("a.yaml"), PathBuf::from("b.yaml")],
+        storage_backend.paths()
+    );
+}
+
+#[test]
+fn get_string_returns_none_when_not_utf8() {
+    let storage_backend = InMemoryStorageBackend::new();
+    storage_backend.insert(PathBuf::from("a.bin"), vec![0xff, 0xfe]);
+
+    assert_eq!(
+        Some(vec![0xff, 0xfe]),
+        storage_backend.get(Path::new("a.bin"))
+    );
+    assert_eq!(None, storage_backend.get_string(Path::new("a.bin")));
+}
+
+#[tokio::test]
+async fn storage_backend_write_read_remove() -> Result<(), Box<dyn std::error::Error>> {
+    let storage_backend = InMemoryStorageBackend::new();
+    let path = Path::new(".peace/app/a.yaml");
+
+    storage_backend.write(path, b"a: 1\n").await?;
+    assert!(storage_backend.contains_item(path).await?);
+    assert_eq!(
+        Some(b"a: 1\n".to_vec()),
+        storage_backend.read_opt(path).await?
+    );
+
+    storage_backend.remove(path).await?;
+    assert!(!storage_backend.contains_item(path).await?);
+    assert_eq!(None, storage_backend.read_opt(path).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn storage_backend_paths_under_and_dir_remove() -> Result<(), Box<dyn std::error::Error>> {
+    let storage_backend = InMemoryStorageBackend::new();
+    storage_backend.insert(PathBuf::from(".peace/app/dev/a.yaml"), "a: 1\n");
+    storage_backend.insert(PathBuf::from(".peace/app/dev/flow/b.yaml"), "b: 2\n");
+    storage_backend.insert(PathBuf::from(".peace/app/dev_other/c.yaml"), "c: 3\n");
+
+    let dir = Path::new(".peace/app/dev");
+    assert_eq!(
+        vec![
+            PathBuf::from(".peace/app/dev/a.yaml"),
+            PathBuf::from(".peace/app/dev/flow/b.yaml"),
+        ],
+        storage_backend.paths_under(dir).await?
+    );
+
+    storage_backend.dir_remove(dir).await?;
+    assert!(storage_backend.paths_under(dir).await?.is_empty());
+    assert_eq!(
+        vec![PathBuf::from(".peace/app/dev_other/c.yaml")],
+        storage_backend.paths()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn storage_backend_dirs_under_returns_created_empty_dirs(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let storage_backend = InMemoryStorageBackend::new();
+    storage_backend.insert(PathBuf::from(".peace/app/workspace_params.yaml"), "");
+    storage_backend.insert(PathBuf::from(".peace/app/dev/flow/a.yaml"), "a: 1\n");
+    storage_backend
+        .dirs_create(&[
+            Path::new(".peace/app/prod"),
+            Path::new(".peace/app/prod/.history"),
+        ])
+        .await?;
+
+    assert!(
+        storage_backend
+            .contains_item(Path::new(".peace/app/prod"))
+            .await?
+    );
+    assert_eq!(
+        vec![
+            PathBuf::from(".peace/app/dev"),
+            PathBuf::from(".peace/app/prod")
+        ],
+        storage_backend.dirs_under(Path::new(".peace/app")).await?
+    );
+
+    storage_backend
+        .dir_remove(Path::new(".peace/app/prod"))
+        .await?;
+    assert_eq!(
+        vec![PathBuf::from(".peace/app/dev")],
+        storage_backend.dirs_under(Path::new(".peace/app")).await?
+    );
+
+    Ok(())
+}
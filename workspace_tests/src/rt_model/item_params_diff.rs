@@ -0,0 +1,161 @@
+use peace::{
+    cli::output::CliOutputBuilder,
+    cli_model::OutputFormat,
+    rt_model::{output::OutputWrite, ItemParamsDiff, ParamsFieldDiff},
+};
+
+use crate::PeaceTestError;
+
+fn yaml(s: &str) -> serde_yaml::Value {
+    serde_yaml::from_str(s).unwrap()
+}
+
+#[test]
+fn new_splits_field_wise_specs_and_values_by_field() {
+    let item_params_diff = ItemParamsDiff::new(
+        Some(yaml("bucket: !Value\n  value: a\nregion: InMemory\n")),
+        Some(yaml("bucket: !Value\n  value: b\nregion: InMemory\n")),
+        Some(yaml("bucket: a\nregion: ap-southeast-2\n")),
+        Some(yaml("bucket: b\nregion: ap-southeast-2\n")),
+    );
+
+    assert_eq!(
+        vec![
+            ParamsFieldDiff {
+                field_name: Some(String::from("bucket")),
+                spec_stored: Some(yaml("!Value\nvalue: a\n")),
+                spec: Some(yaml("!Value\nvalue: b\n")),
+                value_stored: Some(yaml("a")),
+                value: Some(yaml("b")),
+            },
+            ParamsFieldDiff {
+                field_name: Some(String::from("region")),
+                spec_stored: Some(yaml("InMemory")),
+                spec: Some(yaml("InMemory")),
+                value_stored: Some(yaml("ap-southeast-2")),
+                value: Some(yaml("ap-southeast-2")),
+            },
+        ],
+        item_params_diff.field_diffs
+    );
+    assert!(item_params_diff.is_changed());
+    assert_eq!(
+        vec!["bucket"],
+        item_params_diff
+            .changed_fields()
+            .filter_map(|field_diff| field_diff.field_name.as_deref())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn new_compares_whole_spec_when_not_field_wise() {
+    let item_params_diff = ItemParamsDiff::new(
+        Some(yaml("InMemory")),
+        Some(yaml("bucket: !Value\n  value: a\n")),
+        None,
+        Some(yaml("bucket: a\n")),
+    );
+
+    assert_eq!(
+        vec![
+            ParamsFieldDiff {
+                field_name: Some(String::from("bucket")),
+                spec_stored: None,
+                spec: Some(yaml("!Value\nvalue: a\n")),
+                value_stored: None,
+                value: Some(yaml("a")),
+            },
+            ParamsFieldDiff {
+                field_name: None,
+                spec_stored: Some(yaml("InMemory")),
+                spec: None,
+                value_stored: None,
+                value: None,
+            },
+        ],
+        item_params_diff.field_diffs
+    );
+}
+
+#[test]
+fn is_changed_false_when_specs_and_values_equal() {
+    let item_params_diff = ItemParamsDiff::new(
+        Some(yaml("!Value\nvalue: [1, 2]\n")),
+        Some(yaml("!Value\nvalue: [1, 2]\n")),
+        Some(yaml("[1, 2]")),
+        Some(yaml("[1, 2]")),
+    );
+
+    assert!(!item_params_diff.is_changed());
+}
+
+#[test]
+fn value_changed_false_when_value_not_resolved() {
+    let params_field_diff = ParamsFieldDiff {
+        field_name: Some(String::from("bucket")),
+        spec_stored: Some(yaml("InMemory")),
+        spec: Some(yaml("InMemory")),
+        value_stored: None,
+        value: Some(yaml("a")),
+    };
+
+    assert!(!params_field_diff.value_changed());
+    assert!(!params_field_diff.is_changed());
+}
+
+#[tokio::test]
+async fn present_includes_resolve_errors() -> Result<(), Box<dyn std::error::Error>> {
+    let item_params_diff = ItemParamsDiff::new(None, None, None, None)
+        .with_value_stored_error(Some(String::from("`tar_path` must not be empty")));
+
+    assert!(item_params_diff.has_errors());
+    assert!(!item_params_diff.is_changed());
+    assert_eq!(
+        "stored params could not be resolved: `tar_path` must not be empty",
+        present_text(&item_params_diff).await?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn present_no_changes_when_unchanged_without_errors() -> Result<(), Box<dyn std::error::Error>>
+{
+    let item_params_diff = ItemParamsDiff::new(None, None, Some(yaml("a: 1")), Some(yaml("a: 1")));
+
+    assert!(!item_params_diff.has_errors());
+    assert_eq!("no changes", present_text(&item_params_diff).await?);
+
+    Ok(())
+}
+
+#[test]
+fn serialize() -> Result<(), serde_yaml::Error> {
+    let item_params_diff = ItemParamsDiff::new(None, None, Some(yaml("a: 1")), Some(yaml("a: 2")));
+
+    assert_eq!(
+        r#"field_diffs:
+- field_name: a
+  spec_stored: null
+  spec: null
+  value_stored: 1
+  value: 2
+"#,
+        serde_yaml::to_string(&item_params_diff)?
+    );
+
+    Ok(())
+}
+
+async fn present_text(
+    item_params_diff: &ItemParamsDiff,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let mut buffer = Vec::new();
+    let mut cli_output = CliOutputBuilder::new_with_writer(&mut buffer)
+        .with_outcome_format(OutputFormat::Text)
+        .build();
+    <_ as OutputWrite<PeaceTestError>>::present(&mut cli_output, item_params_diff).await?;
+
+    Ok(String::from_utf8(buffer)?)
+}
@@ -2,7 +2,7 @@ use diff::{VecDiff, VecDiffType};
 use peace::{
     cfg::{item_id, ApplyCheck, FnCtx},
     data::marker::{ApplyDry, Clean, Current, Goal},
-    params::{ParamsSpec, ParamsSpecs},
+    params::{Params, ParamsSpec, ParamsSpecs},
     resource_rt::{
         internal::StatesMut,
         resources::ts::SetUp,
@@ -12,7 +12,7 @@ use peace::{
     },
     rt_model::{Error as PeaceRtError, ItemRt, ItemWrapper, StateDowncastError},
 };
-use peace_items::blank::BlankItem;
+use peace_items::blank::{BlankDest, BlankItem, BlankParams, BlankSrc};
 cfg_if::cfg_if! {
     if #[cfg(feature = "output_progress")] {
         use peace::cfg::progress::{ProgressLimit, ProgressSender};
@@ -640,6 +640,92 @@ async fn apply_exec_for_clean() -> Result<(), VecCopyError> {
     Ok(())
 }
 
+#[tokio::test]
+async fn params_diff_compares_value_spec_with_field_wise_spec_by_field(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let item_wrapper =
+        ItemWrapper::<_, PeaceTestError>::from(BlankItem::<()>::new(item_id!("blank")));
+    let mut params_specs_stored = ParamsSpecs::new();
+    params_specs_stored.insert(
+        item_id!("blank"),
+        ParamsSpec::Value {
+            value: BlankParams::<()>::new(BlankSrc(1), BlankDest(None)),
+        },
+    );
+    let mut params_specs = ParamsSpecs::new();
+    params_specs.insert(
+        item_id!("blank"),
+        BlankParams::<()>::field_wise_spec()
+            .with_src(BlankSrc(1))
+            .with_dest(BlankDest(Some(2)))
+            .build(),
+    );
+    let mut resources = Resources::new();
+    <dyn ItemRt<_>>::setup(&item_wrapper, &mut resources).await?;
+    let resources = Resources::<SetUp>::from(resources);
+
+    let item_params_diff =
+        item_wrapper.params_diff(Some(&params_specs_stored), &params_specs, &resources)?;
+
+    let field_names_changed = item_params_diff
+        .changed_fields()
+        .map(|field_diff| field_diff.field_name.as_deref())
+        .collect::<Vec<_>>();
+    assert_eq!(vec![Some("dest")], field_names_changed);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn params_diff_returns_no_value_when_params_not_resolvable_yet() -> Result<(), VecCopyError> {
+    let vec_copy_item = VecCopyItem::default();
+    let item_wrapper = ItemWrapper::<_, VecCopyError>::from(vec_copy_item);
+    let (_params_specs, resources) = resources_set_up(&item_wrapper).await?;
+    let mut params_specs = ParamsSpecs::new();
+    params_specs.insert(
+        VecCopyItem::ID_DEFAULT.clone(),
+        ParamsSpec::<VecA>::InMemory,
+    );
+
+    let item_params_diff = item_wrapper.params_diff(None, &params_specs, &resources)?;
+
+    assert!(item_params_diff
+        .field_diffs
+        .iter()
+        .all(|field_diff| field_diff.value.is_none()));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn params_diff_records_error_when_params_resolve_fails() -> Result<(), VecCopyError> {
+    let vec_copy_item = VecCopyItem::default();
+    let item_wrapper = ItemWrapper::<_, VecCopyError>::from(vec_copy_item);
+    let (_params_specs, mut resources) = resources_set_up(&item_wrapper).await?;
+    resources.insert(VecA(vec![0, 1]));
+    let mut params_specs = ParamsSpecs::new();
+    params_specs.insert(
+        VecCopyItem::ID_DEFAULT.clone(),
+        ParamsSpec::<VecA>::InMemory,
+    );
+
+    let _vec_a = resources.borrow_mut::<VecA>();
+    let item_params_diff = item_wrapper.params_diff(None, &params_specs, &resources)?;
+
+    assert!(
+        item_params_diff
+            .value_error
+            .as_deref()
+            .is_some_and(|error| error.starts_with("Borrow conflict on `VecA`")),
+        "Expected `value_error` to be the `InMemoryBorrowConflict` error, but was `{:?}`.",
+        item_params_diff.value_error
+    );
+    assert_eq!(None, item_params_diff.value_stored_error);
+    assert_eq!(None, item_params_diff.field_diffs[0].value);
+
+    Ok(())
+}
+
 async fn resources_set_up(
     item_wrapper: &VecCopyItemWrapper,
 ) -> Result<(ParamsSpecs, Resources<SetUp>), VecCopyError> {
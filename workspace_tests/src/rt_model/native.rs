@@ -1 +1,2 @@
+mod file_storage_backend;
 mod workspace_spec;
@@ -0,0 +1,115 @@
+use peace::rt_model::{FileStorageBackend, StorageBackend};
+
+#[tokio::test]
+async fn write_then_read_opt_returns_bytes() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let file_path = tempdir.path().join("t.yaml");
+
+    FileStorageBackend.write(&file_path, b"a: 1\n").await?;
+    let bytes = FileStorageBackend.read_opt(&file_path).await?;
+
+    assert!(FileStorageBackend.contains_item(&file_path).await?);
+    assert_eq!(Some(b"a: 1\n".to_vec()), bytes);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_opt_returns_none_when_file_not_exists() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let file_path = tempdir.path().join("t.yaml");
+
+    let bytes = FileStorageBackend.read_opt(&file_path).await?;
+
+    assert!(!FileStorageBackend.contains_item(&file_path).await?);
+    assert_eq!(None, bytes);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn remove_removes_file() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let file_path = tempdir.path().join("t.yaml");
+    tokio::fs::write(&file_path, b"a: 1\n").await?;
+
+    FileStorageBackend.remove(&file_path).await?;
+
+    assert!(!file_path.exists());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn paths_under_returns_nested_file_paths() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let dir = tempdir.path().join("dev");
+    tokio::fs::create_dir_all(dir.join("flow")).await?;
+    tokio::fs::write(dir.join("b.yaml"), b"b: 2\n").await?;
+    tokio::fs::write(dir.join("flow").join("a.yaml"), b"a: 1\n").await?;
+
+    let paths = FileStorageBackend.paths_under(&dir).await?;
+
+    assert_eq!(
+        vec![dir.join("b.yaml"), dir.join("flow").join("a.yaml")],
+        paths
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn paths_under_returns_empty_when_dir_not_exists() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+
+    let paths = FileStorageBackend
+        .paths_under(&tempdir.path().join("dev"))
+        .await?;
+
+    assert!(paths.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn dir_remove_removes_dir() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let dir = tempdir.path().join("dev");
+    tokio::fs::create_dir_all(dir.join("flow")).await?;
+    tokio::fs::write(dir.join("flow").join("a.yaml"), b"a: 1\n").await?;
+
+    FileStorageBackend.dir_remove(&dir).await?;
+    FileStorageBackend.dir_remove(&dir).await?;
+
+    assert!(!dir.exists());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn dirs_under_returns_dirs_including_empty_dirs() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let dir = tempdir.path().join("app");
+    tokio::fs::create_dir_all(dir.join("dev").join(".history")).await?;
+    tokio::fs::create_dir_all(dir.join("prod")).await?;
+    tokio::fs::write(dir.join("workspace_params.yaml"), b"a: 1\n").await?;
+
+    let dirs = FileStorageBackend.dirs_under(&dir).await?;
+
+    assert_eq!(vec![dir.join("dev"), dir.join("prod")], dirs);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn dirs_under_returns_empty_when_dir_not_exists() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+
+    let dirs = FileStorageBackend
+        .dirs_under(&tempdir.path().join("app"))
+        .await?;
+
+    assert!(dirs.is_empty());
+
+    Ok(())
+}
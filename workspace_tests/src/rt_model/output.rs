@@ -0,0 +1 @@
+mod tee_output;
@@ -0,0 +1,95 @@
+use peace::{
+    fmt::presentable::CodeInline,
+    rt_model::{
+        output::{OutputWrite, TeeOutput},
+        InMemoryTextOutput,
+    },
+};
+
+use crate::{FnInvocation, FnTrackerOutput, PeaceTestError};
+
+#[tokio::test]
+async fn present_forwards_to_both_outputs() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tee_output = TeeOutput::new(FnTrackerOutput::new(), InMemoryTextOutput::new());
+
+    <TeeOutput<_, _> as OutputWrite<PeaceTestError>>::present(
+        &mut tee_output,
+        CodeInline::new("abc".into()),
+    )
+    .await?;
+
+    let (fn_tracker_output, in_memory_text_output) = tee_output.into_inner();
+    assert_eq!(
+        &[FnInvocation::new(
+            "present",
+            vec![Some("abc\n".to_string())]
+        )],
+        fn_tracker_output.fn_invocations()
+    );
+    assert_eq!("abc\n", in_memory_text_output.into_inner());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn write_err_forwards_to_both_outputs() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tee_output = TeeOutput::new(FnTrackerOutput::new(), InMemoryTextOutput::new());
+    let error = PeaceTestError::TempDir(std::io::Error::other("tee test"));
+
+    <TeeOutput<_, _> as OutputWrite<PeaceTestError>>::write_err(&mut tee_output, &error).await?;
+
+    let (fn_tracker_output, in_memory_text_output) = tee_output.into_inner();
+    assert_eq!(
+        &[FnInvocation::new(
+            "write_err",
+            vec![Some(format!("{error:?}"))]
+        )],
+        fn_tracker_output.fn_invocations()
+    );
+    assert_eq!(
+        "Failed to initialize tempdir.\n",
+        in_memory_text_output.into_inner()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn nested_tee_output_forwards_to_all_outputs() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tee_output = TeeOutput::new(
+        FnTrackerOutput::new(),
+        TeeOutput::new(FnTrackerOutput::new(), InMemoryTextOutput::new()),
+    );
+
+    <TeeOutput<_, _> as OutputWrite<PeaceTestError>>::present(
+        &mut tee_output,
+        CodeInline::new("abc".into()),
+    )
+    .await?;
+
+    let expected_fn_invocations = [FnInvocation::new(
+        "present",
+        vec![Some("abc\n".to_string())],
+    )];
+    assert_eq!(
+        &expected_fn_invocations,
+        tee_output.output_0().fn_invocations()
+    );
+    assert_eq!(
+        &expected_fn_invocations,
+        tee_output.output_1().output_0().fn_invocations()
+    );
+
+    let (_, tee_output) = tee_output.into_inner();
+    let (_, in_memory_text_output) = tee_output.into_inner();
+    assert_eq!("abc\n", in_memory_text_output.into_inner());
+
+    Ok(())
+}
+
+#[test]
+fn debug() {
+    let tee_output = TeeOutput::new(FnTrackerOutput::new(), InMemoryTextOutput::new());
+
+    assert!(format!("{tee_output:?}").starts_with("TeeOutput {"));
+}
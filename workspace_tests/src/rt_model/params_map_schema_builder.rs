@@ -0,0 +1,65 @@
+use peace::rt_model::params::ParamsMapSchemaBuilder;
+use serde_json::json;
+
+#[test]
+fn build_includes_each_param_and_denies_other_keys() {
+    let schema = ParamsMapSchemaBuilder::<String>::new("WorkspaceParams")
+        .with_param::<u32>(String::from("server_port"))
+        .with_param::<String>(String::from("server_name"))
+        .build()
+        .to_value();
+
+    assert_eq!(Some("WorkspaceParams"), schema["title"].as_str());
+    assert_eq!(Some("object"), schema["type"].as_str());
+    assert_eq!(json!(false), schema["additionalProperties"]);
+    assert_eq!(
+        Some("integer"),
+        schema["properties"]["server_port"]["type"].as_str()
+    );
+    assert_eq!(
+        Some("string"),
+        schema["properties"]["server_name"]["type"].as_str()
+    );
+}
+
+#[test]
+fn build_includes_definitions_for_referenced_types() {
+    let schema = ParamsMapSchemaBuilder::<String>::new("ProfileParams")
+        .with_param::<Option<Vec<u8>>>(String::from("bytes"))
+        .with_param::<peace::params::ValueSpec<u8>>(String::from("value_spec"))
+        .build()
+        .to_value();
+
+    assert!(schema["$schema"].is_string());
+    assert!(schema["$defs"]["ValueSpec_for_uint8"].is_object());
+    assert_eq!(
+        Some("#/$defs/ValueSpec_for_uint8"),
+        schema["properties"]["value_spec"]["$ref"].as_str()
+    );
+}
+
+#[test]
+fn with_param_uses_serialized_key() {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    enum Key {
+        ServerPort,
+    }
+
+    let schema = ParamsMapSchemaBuilder::<Key>::new("FlowParams")
+        .with_param::<u32>(Key::ServerPort)
+        .build()
+        .to_value();
+
+    assert!(schema["properties"]["server_port"].is_object());
+}
+
+#[test]
+fn with_param_skips_keys_that_are_not_plain_values() {
+    let schema = ParamsMapSchemaBuilder::<Vec<u8>>::new("FlowParams")
+        .with_param::<u32>(vec![1, 2])
+        .build()
+        .to_value();
+
+    assert_eq!(json!({}), schema["properties"]);
+}
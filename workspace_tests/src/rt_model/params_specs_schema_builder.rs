@@ -0,0 +1,145 @@
+use std::collections::BTreeMap;
+
+use peace::{
+    cfg::{flow_id, item_id},
+    params::{Params, ParamsSpec, ParamsSpecSchemaReg},
+    rt_model::{Flow, ItemGraphBuilder, ParamsSpecsSchemaBuilder},
+};
+use peace_items::blank::{BlankDest, BlankItem, BlankParams, BlankSrc};
+
+use crate::{
+    mock_item::{MockItem, MockSrc},
+    vec_copy_item::{VecA, VecCopyItem},
+    PeaceTestError,
+};
+
+#[test]
+fn with_flow_includes_params_spec_for_each_registered_item() {
+    let flow = {
+        let mut item_graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        item_graph_builder.add_fn(VecCopyItem::new(item_id!("vec_copy_0")).into());
+        item_graph_builder.add_fn(VecCopyItem::new(item_id!("vec_copy_1")).into());
+        item_graph_builder.add_fn(MockItem::<()>::default().into());
+        item_graph_builder.add_fn(BlankItem::<()>::new(item_id!("blank")).into());
+        let mut params_spec_schema_reg = ParamsSpecSchemaReg::new();
+        params_spec_schema_reg.register::<VecA>(item_id!("vec_copy_0"));
+        params_spec_schema_reg.register::<VecA>(item_id!("vec_copy_1"));
+        params_spec_schema_reg.register::<MockSrc>(MockItem::<()>::ID_DEFAULT.clone());
+        params_spec_schema_reg.register::<BlankParams<()>>(item_id!("blank"));
+        Flow::new(flow_id!("schema_flow"), item_graph_builder.build())
+            .with_params_spec_schema_reg(params_spec_schema_reg)
+    };
+
+    let schema = ParamsSpecsSchemaBuilder::new()
+        .with_flow(&flow)
+        .build()
+        .to_value();
+
+    assert_eq!(Some("ParamsSpecs"), schema["title"].as_str());
+    assert_eq!(
+        Some("#/$defs/ParamsSpec_for_VecA"),
+        schema["properties"]["vec_copy_0"]["$ref"].as_str()
+    );
+    assert_eq!(
+        Some("#/$defs/ParamsSpec_for_VecA"),
+        schema["properties"]["vec_copy_1"]["$ref"].as_str()
+    );
+    assert_eq!(
+        Some("#/$defs/ParamsSpec_for_MockSrc"),
+        schema["properties"][MockItem::<()>::ID_DEFAULT.as_str()]["$ref"].as_str()
+    );
+    assert_eq!(
+        Some("#/$defs/ParamsSpec_for_BlankParams"),
+        schema["properties"]["blank"]["$ref"].as_str()
+    );
+    assert!(schema["$defs"]["VecAFieldWise"].is_object());
+    assert!(schema["$defs"]["BlankParamsFieldWise"].is_object());
+}
+
+#[test]
+fn with_flow_skips_items_that_are_not_registered() {
+    let flow = {
+        let mut item_graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        item_graph_builder.add_fn(VecCopyItem::new(item_id!("vec_copy_0")).into());
+        item_graph_builder.add_fn(BlankItem::<()>::new(item_id!("blank")).into());
+        let mut params_spec_schema_reg = ParamsSpecSchemaReg::new();
+        params_spec_schema_reg.register::<BlankParams<()>>(item_id!("blank"));
+        Flow::new(flow_id!("schema_flow"), item_graph_builder.build())
+            .with_params_spec_schema_reg(params_spec_schema_reg)
+    };
+
+    let schema = ParamsSpecsSchemaBuilder::new()
+        .with_flow(&flow)
+        .build()
+        .to_value();
+
+    assert!(schema["properties"]["vec_copy_0"].is_null());
+    assert_eq!(
+        Some("#/$defs/ParamsSpec_for_BlankParams"),
+        schema["properties"]["blank"]["$ref"].as_str()
+    );
+}
+
+#[test]
+fn default_has_no_items() {
+    let schema = ParamsSpecsSchemaBuilder::default().build().to_value();
+
+    assert_eq!(serde_json::json!({}), schema["properties"]);
+}
+
+#[test]
+fn schema_validates_json_params_specs() -> Result<(), Box<dyn std::error::Error>> {
+    let validator = jsonschema::validator_for(&blank_flow_schema())?;
+    let params_specs = serde_json::to_value(blank_params_specs())?;
+
+    assert!(
+        validator.is_valid(&params_specs),
+        "Expected JSON params specs to be valid: {params_specs:#}"
+    );
+    Ok(())
+}
+
+#[test]
+fn schema_does_not_validate_tagged_yaml_params_specs() -> Result<(), Box<dyn std::error::Error>> {
+    let validator = jsonschema::validator_for(&blank_flow_schema())?;
+    let params_specs_yaml = serde_yaml::to_string(&blank_params_specs())?;
+    assert!(
+        params_specs_yaml.contains("!FieldWise"),
+        "Expected YAML params specs to be tagged:\n{params_specs_yaml}"
+    );
+
+    let params_specs = serde_json::to_value(serde_yaml::from_str::<serde_yaml::Value>(
+        &params_specs_yaml,
+    )?)?;
+
+    assert!(
+        !validator.is_valid(&params_specs),
+        "Expected tagged YAML params specs to be unsupported: {params_specs:#}"
+    );
+    Ok(())
+}
+
+fn blank_flow_schema() -> serde_json::Value {
+    let flow = {
+        let mut item_graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        item_graph_builder.add_fn(BlankItem::<()>::new(item_id!("blank")).into());
+        let mut params_spec_schema_reg = ParamsSpecSchemaReg::new();
+        params_spec_schema_reg.register::<BlankParams<()>>(item_id!("blank"));
+        Flow::new(flow_id!("schema_flow"), item_graph_builder.build())
+            .with_params_spec_schema_reg(params_spec_schema_reg)
+    };
+
+    ParamsSpecsSchemaBuilder::new()
+        .with_flow(&flow)
+        .build()
+        .to_value()
+}
+
+fn blank_params_specs() -> BTreeMap<&'static str, ParamsSpec<BlankParams<()>>> {
+    let params_spec = BlankParams::<()>::field_wise_spec()
+        .with_src(BlankSrc(1))
+        .with_dest(BlankDest(Some(2)))
+        .build();
+
+    BTreeMap::from([("blank", params_spec)])
+}
@@ -0,0 +1,199 @@
+use std::{collections::BTreeMap, path::Path};
+
+use peace::{
+    resource_rt::type_reg::untagged::{BoxDt, TypeReg},
+    rt_model::{SerializationError, SerializationFormat},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+struct TestStruct {
+    a: u32,
+    b: Vec<String>,
+}
+
+#[test]
+fn file_path_replaces_extension_with_format_extension() {
+    let path = Path::new("flow/states_current.yaml");
+
+    assert_eq!(
+        Path::new("flow/states_current.yaml"),
+        SerializationFormat::Yaml.file_path(path)
+    );
+    assert_eq!(
+        Path::new("flow/states_current.json"),
+        SerializationFormat::Json.file_path(path)
+    );
+    assert_eq!(
+        Path::new("flow/states_current.msgpack"),
+        SerializationFormat::MessagePack.file_path(path)
+    );
+}
+
+#[test]
+fn from_file_path_returns_format_of_extension() {
+    assert_eq!(
+        Some(SerializationFormat::Yaml),
+        SerializationFormat::from_file_path(Path::new("states_current.yaml"))
+    );
+    assert_eq!(
+        Some(SerializationFormat::Json),
+        SerializationFormat::from_file_path(Path::new("states_current.json"))
+    );
+    assert_eq!(
+        Some(SerializationFormat::MessagePack),
+        SerializationFormat::from_file_path(Path::new("states_current.msgpack"))
+    );
+    assert_eq!(
+        None,
+        SerializationFormat::from_file_path(Path::new("states_current.txt"))
+    );
+    assert_eq!(
+        None,
+        SerializationFormat::from_file_path(Path::new("states_current"))
+    );
+}
+
+#[test]
+fn read_order_starts_with_format() {
+    assert_eq!(
+        vec![
+            SerializationFormat::Json,
+            SerializationFormat::Yaml,
+            SerializationFormat::MessagePack,
+        ],
+        SerializationFormat::Json.read_order().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn is_file_path_of_matches_file_in_any_format() {
+    let file_path = Path::new("flow/states_current.yaml");
+
+    assert!(SerializationFormat::is_file_path_of(
+        Path::new("flow/states_current.yaml"),
+        file_path
+    ));
+    assert!(SerializationFormat::is_file_path_of(
+        Path::new("flow/states_current.msgpack"),
+        file_path
+    ));
+    assert!(!SerializationFormat::is_file_path_of(
+        Path::new("flow/states_current.txt"),
+        file_path
+    ));
+    assert!(!SerializationFormat::is_file_path_of(
+        Path::new("flow/states_goal.json"),
+        file_path
+    ));
+}
+
+#[test]
+fn serialize_yaml() -> Result<(), SerializationError> {
+    let bytes = SerializationFormat::Yaml.serialize(&test_struct())?;
+
+    assert_eq!("a: 1\nb:\n- x\n", String::from_utf8(bytes).unwrap());
+    Ok(())
+}
+
+#[test]
+fn serialize_json() -> Result<(), SerializationError> {
+    let bytes = SerializationFormat::Json.serialize(&test_struct())?;
+
+    assert_eq!(
+        r#"{
+  "a": 1,
+  "b": [
+    "x"
+  ]
+}
+"#,
+        String::from_utf8(bytes).unwrap()
+    );
+    Ok(())
+}
+
+#[test]
+fn serialize_then_deserialize_round_trips_each_format() -> Result<(), SerializationError> {
+    [
+        SerializationFormat::Yaml,
+        SerializationFormat::Json,
+        SerializationFormat::MessagePack,
+    ]
+    .into_iter()
+    .try_for_each(|serialization_format| {
+        let bytes = serialization_format.serialize(&test_struct())?;
+        let test_struct_deserialized = serialization_format.deserialize::<TestStruct>(&bytes)?;
+
+        assert_eq!(test_struct(), test_struct_deserialized);
+        Ok(())
+    })
+}
+
+#[test]
+fn deserialize_typemap_opt_returns_unknown_entries_for_each_format(
+) -> Result<(), SerializationError> {
+    let mut type_reg = TypeReg::<String, BoxDt>::new_typed();
+    type_reg.register::<u32>(String::from("a"));
+    let map = BTreeMap::from([
+        (String::from("a"), serde_yaml::Value::from(1u32)),
+        (String::from("b"), serde_yaml::Value::from("unknown")),
+    ]);
+
+    [
+        SerializationFormat::Yaml,
+        SerializationFormat::Json,
+        SerializationFormat::MessagePack,
+    ]
+    .into_iter()
+    .try_for_each(|serialization_format| {
+        let bytes = serialization_format.serialize(&map)?;
+        let type_map_opt = serialization_format.deserialize_typemap_opt(&type_reg, &bytes)?;
+
+        assert_eq!(Some(Some(&1u32)), type_map_opt.get::<u32, _>("a"));
+        assert_eq!(
+            Some(&Some(serde_yaml::Value::from("unknown"))),
+            type_map_opt.unknown_entries().get("b")
+        );
+        Ok(())
+    })
+}
+
+#[test]
+fn deserialize_returns_error_for_format() {
+    assert!(matches!(
+        SerializationFormat::Yaml.deserialize::<TestStruct>(b"a: [1]\n"),
+        Err(SerializationError::Yaml(_))
+    ));
+    assert!(matches!(
+        SerializationFormat::Json.deserialize::<TestStruct>(b"{\"a\": [1]}"),
+        Err(SerializationError::Json(_))
+    ));
+    assert!(matches!(
+        SerializationFormat::MessagePack.deserialize::<TestStruct>(b"\xc1"),
+        Err(SerializationError::MessagePackDeserialize(_))
+    ));
+}
+
+#[test]
+fn display() {
+    assert_eq!("yaml", SerializationFormat::Yaml.to_string());
+    assert_eq!("json", SerializationFormat::Json.to_string());
+    assert_eq!("message_pack", SerializationFormat::MessagePack.to_string());
+}
+
+#[test]
+fn deserialize_serialization_format() -> Result<(), serde_yaml::Error> {
+    assert_eq!(
+        SerializationFormat::MessagePack,
+        serde_yaml::from_str("message_pack")?
+    );
+    Ok(())
+}
+
+fn test_struct() -> TestStruct {
+    TestStruct {
+        a: 1,
+        b: vec![String::from("x")],
+    }
+}
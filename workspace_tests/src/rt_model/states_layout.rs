@@ -0,0 +1,44 @@
+use std::path::Path;
+
+use peace::{cfg::item_id, rt_model::StatesLayout};
+
+#[test]
+fn default_is_single_file() {
+    assert_eq!(StatesLayout::SingleFile, StatesLayout::default());
+}
+
+#[test]
+fn item_states_dir_is_states_file_path_without_extension() {
+    assert_eq!(
+        Path::new("flow_dir/states_current"),
+        StatesLayout::item_states_dir(Path::new("flow_dir/states_current.yaml"))
+    );
+}
+
+#[test]
+fn item_states_file_path_is_in_directory_named_after_states_file() {
+    let item_states_file_path = StatesLayout::item_states_file_path(
+        Path::new("flow_dir/states_current.yaml"),
+        &item_id!("item_one"),
+    );
+
+    assert_eq!(
+        Path::new("flow_dir/states_current/item_one.yaml"),
+        item_states_file_path
+    );
+}
+
+#[test]
+fn serialize() -> Result<(), serde_yaml::Error> {
+    assert_eq!("per_item\n", serde_yaml::to_string(&StatesLayout::PerItem)?);
+    Ok(())
+}
+
+#[test]
+fn deserialize() -> Result<(), serde_yaml::Error> {
+    assert_eq!(
+        StatesLayout::SingleFile,
+        serde_yaml::from_str("single_file\n")?
+    );
+    Ok(())
+}
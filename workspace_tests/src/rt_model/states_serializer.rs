@@ -1,10 +1,18 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
 use peace::{
     cfg::{flow_id, item_id},
     resource_rt::{
         internal::StatesMut, paths::StatesCurrentFile, states::StatesCurrentStored,
         type_reg::untagged::TypeReg,
     },
-    rt_model::{Error, ItemGraphBuilder, StatesSerializer, Storage},
+    rt_model::{
+        async_trait, Error, InMemoryStorageBackend, ItemGraph, ItemGraphBuilder, StatesLayout,
+        StatesSerializer, Storage, StorageBackend,
+    },
 };
 use pretty_assertions::assert_eq;
 
@@ -17,7 +25,7 @@ use crate::{
 #[tokio::test]
 async fn serialize() -> Result<(), Box<dyn std::error::Error>> {
     let tempdir = tempfile::tempdir()?;
-    let storage = Storage;
+    let storage = Storage::default();
     let states_current_file = StatesCurrentFile::new(tempdir.path().join("states_current.yaml"));
 
     let item_one = item_id!("one");
@@ -64,7 +72,7 @@ async fn serialize() -> Result<(), Box<dyn std::error::Error>> {
 async fn deserialize_stored() -> Result<(), Box<dyn std::error::Error>> {
     let tempdir = tempfile::tempdir()?;
     let flow_id = flow_id!("test_flow");
-    let storage = Storage;
+    let storage = Storage::default();
     let states_current_file = StatesCurrentFile::new(tempdir.path().join("states_current.yaml"));
 
     let item_one = item_id!("one");
@@ -129,7 +137,7 @@ async fn deserialize_stored() -> Result<(), Box<dyn std::error::Error>> {
 async fn deserialize_stored_error_maps_byte_indices() -> Result<(), Box<dyn std::error::Error>> {
     let tempdir = tempfile::tempdir()?;
     let flow_id = flow_id!("test_flow");
-    let storage = Storage;
+    let storage = Storage::default();
     let item_id = item_id!("a");
     let mut states_type_reg = TypeReg::new_typed();
     states_type_reg.register::<u32>(item_id.clone());
@@ -187,3 +195,432 @@ async fn deserialize_stored_error_maps_byte_indices() -> Result<(), Box<dyn std:
 
     Ok(())
 }
+
+#[tokio::test]
+async fn deserialize_stored_error_includes_contents_from_storage_backend(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let flow_id = flow_id!("test_flow");
+    let storage_backend = InMemoryStorageBackend::new();
+    let storage = Storage::new(storage_backend.clone());
+    let item_id = item_id!("a");
+    let mut states_type_reg = TypeReg::new_typed();
+    states_type_reg.register::<u32>(item_id.clone());
+    let states_current_file = StatesCurrentFile::new("in_memory/states_current.yaml".into());
+
+    let contents = "a: [123]\n";
+    storage_backend
+        .write(&states_current_file, contents.as_bytes())
+        .await?;
+
+    let error = StatesSerializer::<PeaceTestError>::deserialize_stored(
+        &flow_id,
+        &storage,
+        &states_type_reg,
+        &states_current_file,
+    )
+    .await
+    .unwrap_err();
+
+    #[cfg(feature = "error_reporting")]
+    {
+        if let PeaceTestError::PeaceRt(Error::StatesDeserialize {
+            states_file_source, ..
+        }) = error
+        {
+            assert_eq!(contents, states_file_source.inner());
+        } else {
+            panic!("Expected error to be `Error::StatesDeserialize {{ .. }}`, but was {error:?}");
+        }
+    }
+    #[cfg(not(feature = "error_reporting"))]
+    {
+        assert!(matches!(
+            error,
+            PeaceTestError::PeaceRt(Error::StatesDeserialize { .. })
+        ));
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn serialize_per_item_writes_one_file_per_item() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let storage = Storage::default().with_states_layout(StatesLayout::PerItem);
+    let states_current_file = StatesCurrentFile::new(tempdir.path().join("states_current.yaml"));
+    tokio::fs::write(&states_current_file, "one: [0]\n").await?;
+
+    let (item_graph, states) = item_graph_and_states(2u8);
+    StatesSerializer::<PeaceTestError>::serialize(
+        &storage,
+        &item_graph,
+        &states,
+        &states_current_file,
+    )
+    .await?;
+
+    let states_dir = tempdir.path().join("states_current");
+    assert_eq!(
+        "one:\n- 1\n",
+        tokio::fs::read_to_string(states_dir.join("one.yaml")).await?
+    );
+    assert_eq!(
+        "two: 2\n",
+        tokio::fs::read_to_string(states_dir.join("two.yaml")).await?
+    );
+    assert_eq!(
+        "three: null\n",
+        tokio::fs::read_to_string(states_dir.join("three.yaml")).await?
+    );
+    assert!(!states_current_file.exists());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn serialize_per_item_only_writes_changed_items() -> Result<(), Box<dyn std::error::Error>> {
+    let storage_backend = WriteRecordStorageBackend::default();
+    let storage = Storage::new(storage_backend.clone()).with_states_layout(StatesLayout::PerItem);
+    let states_current_file = StatesCurrentFile::new(PathBuf::from("states_current.yaml"));
+
+    let (item_graph, states) = item_graph_and_states(2u8);
+    StatesSerializer::<PeaceTestError>::serialize(
+        &storage,
+        &item_graph,
+        &states,
+        &states_current_file,
+    )
+    .await?;
+    storage_backend.written.lock().unwrap().clear();
+
+    let (item_graph, states) = item_graph_and_states(3u8);
+    StatesSerializer::<PeaceTestError>::serialize(
+        &storage,
+        &item_graph,
+        &states,
+        &states_current_file,
+    )
+    .await?;
+
+    assert_eq!(
+        vec![Path::new("states_current/two.yaml").to_path_buf()],
+        *storage_backend.written.lock().unwrap()
+    );
+    assert!(storage_backend.read.lock().unwrap().is_empty());
+    assert!(storage_backend.removed.lock().unwrap().is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn serialize_per_item_writes_items_changed_since_read(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let storage_backend = WriteRecordStorageBackend::default();
+    let storage = Storage::new(storage_backend.clone()).with_states_layout(StatesLayout::PerItem);
+    let states_current_file = StatesCurrentFile::new(PathBuf::from("states_current.yaml"));
+
+    let (item_graph, states) = item_graph_and_states(2u8);
+    StatesSerializer::<PeaceTestError>::serialize(
+        &storage,
+        &item_graph,
+        &states,
+        &states_current_file,
+    )
+    .await?;
+
+    // Another process changes the stored state after it was written, and the
+    // changed state is read.
+    let item_one_file = Path::new("states_current/one.yaml");
+    storage_backend.write(item_one_file, b"one:\n- 9\n").await?;
+    let mut states_type_reg = TypeReg::new_typed();
+    states_type_reg.register::<VecCopyState>(item_id!("one"));
+    states_type_reg.register::<MockState>(item_id!("two"));
+    states_type_reg.register::<MockState>(item_id!("three"));
+    StatesSerializer::<PeaceTestError>::deserialize_stored(
+        &flow_id!("test_flow"),
+        &storage,
+        &states_type_reg,
+        &states_current_file,
+    )
+    .await?;
+    storage_backend.written.lock().unwrap().clear();
+
+    StatesSerializer::<PeaceTestError>::serialize(
+        &storage,
+        &item_graph,
+        &states,
+        &states_current_file,
+    )
+    .await?;
+
+    assert_eq!(
+        vec![item_one_file.to_path_buf()],
+        *storage_backend.written.lock().unwrap()
+    );
+    assert_eq!(
+        Some(b"one:\n- 1\n".to_vec()),
+        storage_backend.backend.get(item_one_file)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn serialize_per_item_removes_files_of_items_not_in_flow(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let storage_backend = InMemoryStorageBackend::new();
+    let storage = Storage::new(storage_backend.clone()).with_states_layout(StatesLayout::PerItem);
+    let states_current_file = StatesCurrentFile::new(PathBuf::from("states_current.yaml"));
+
+    let (item_graph, states) = item_graph_and_states(2u8);
+    StatesSerializer::<PeaceTestError>::serialize(
+        &storage,
+        &item_graph,
+        &states,
+        &states_current_file,
+    )
+    .await?;
+
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        item_graph_builder.add_fns([
+            VecCopyItem::new(item_id!("one")).into(),
+            MockItem::<()>::new(item_id!("two")).into(),
+        ]);
+        item_graph_builder.build()
+    };
+    StatesSerializer::<PeaceTestError>::serialize(
+        &storage,
+        &item_graph,
+        &states,
+        &states_current_file,
+    )
+    .await?;
+
+    assert_eq!(
+        vec![
+            PathBuf::from("states_current/one.yaml"),
+            PathBuf::from("states_current/two.yaml"),
+        ],
+        storage_backend.paths()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn deserialize_stored_per_item_reads_registered_items_only(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let flow_id = flow_id!("test_flow");
+    let storage = Storage::default().with_states_layout(StatesLayout::PerItem);
+    let states_current_file = StatesCurrentFile::new(tempdir.path().join("states_current.yaml"));
+    let states_dir = tempdir.path().join("states_current");
+    tokio::fs::create_dir_all(&states_dir).await?;
+    tokio::fs::write(states_dir.join("one.yaml"), "one:\n- 1\n").await?;
+    tokio::fs::write(states_dir.join("two.yaml"), "two: 2\n").await?;
+    // Would fail to deserialize if it were read.
+    tokio::fs::write(states_dir.join("three.yaml"), "three: [not_a_u8]\n").await?;
+
+    let item_one = item_id!("one");
+    let item_two = item_id!("two");
+    let item_three = item_id!("three");
+    let mut states_type_reg = TypeReg::new_typed();
+    states_type_reg.register::<VecCopyState>(item_one.clone());
+    states_type_reg.register::<MockState>(item_two.clone());
+
+    let states_deserialized = StatesSerializer::<PeaceTestError>::deserialize_stored(
+        &flow_id,
+        &storage,
+        &states_type_reg,
+        &states_current_file,
+    )
+    .await?;
+
+    assert_eq!(
+        Some(VecCopyState::from(vec![1u8])),
+        states_deserialized
+            .get::<VecCopyState, _>(&item_one)
+            .cloned()
+    );
+    assert_eq!(
+        Some(MockState(2u8)),
+        states_deserialized.get::<MockState, _>(&item_two).cloned()
+    );
+    assert!(states_deserialized.get_raw(&item_three).is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn deserialize_stored_per_item_falls_back_to_single_file(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let flow_id = flow_id!("test_flow");
+    let states_current_file = StatesCurrentFile::new(tempdir.path().join("states_current.yaml"));
+
+    let (item_graph, states) = item_graph_and_states(2u8);
+    StatesSerializer::<PeaceTestError>::serialize(
+        &Storage::default(),
+        &item_graph,
+        &states,
+        &states_current_file,
+    )
+    .await?;
+
+    let item_two = item_id!("two");
+    let mut states_type_reg = TypeReg::new_typed();
+    states_type_reg.register::<VecCopyState>(item_id!("one"));
+    states_type_reg.register::<MockState>(item_two.clone());
+    states_type_reg.register::<MockState>(item_id!("three"));
+
+    let storage = Storage::default().with_states_layout(StatesLayout::PerItem);
+    let states_deserialized = StatesSerializer::<PeaceTestError>::deserialize_stored(
+        &flow_id,
+        &storage,
+        &states_type_reg,
+        &states_current_file,
+    )
+    .await?;
+
+    assert_eq!(
+        Some(MockState(2u8)),
+        states_deserialized.get::<MockState, _>(&item_two).cloned()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn deserialize_stored_items_per_item_reads_given_items_only(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let flow_id = flow_id!("test_flow");
+    let storage = Storage::default().with_states_layout(StatesLayout::PerItem);
+    let states_current_file = StatesCurrentFile::new(tempdir.path().join("states_current.yaml"));
+    let states_dir = tempdir.path().join("states_current");
+    tokio::fs::create_dir_all(&states_dir).await?;
+    // Would fail to deserialize if it were read.
+    tokio::fs::write(states_dir.join("one.yaml"), "one: [not_a_u8]\n").await?;
+    tokio::fs::write(states_dir.join("two.yaml"), "two: 2\n").await?;
+
+    let item_one = item_id!("one");
+    let item_two = item_id!("two");
+    let mut states_type_reg = TypeReg::new_typed();
+    states_type_reg.register::<VecCopyState>(item_one.clone());
+    states_type_reg.register::<MockState>(item_two.clone());
+
+    let states_deserialized = StatesSerializer::<PeaceTestError>::deserialize_stored_items(
+        &flow_id,
+        &storage,
+        &states_type_reg,
+        &states_current_file,
+        std::slice::from_ref(&item_two),
+    )
+    .await?;
+
+    assert!(states_deserialized.get_raw(&item_one).is_none());
+    assert_eq!(
+        Some(MockState(2u8)),
+        states_deserialized.get::<MockState, _>(&item_two).cloned()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn deserialize_stored_items_single_file_returns_given_items_only(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let flow_id = flow_id!("test_flow");
+    let storage = Storage::default();
+    let states_current_file = StatesCurrentFile::new(tempdir.path().join("states_current.yaml"));
+
+    let (item_graph, states) = item_graph_and_states(2u8);
+    StatesSerializer::<PeaceTestError>::serialize(
+        &storage,
+        &item_graph,
+        &states,
+        &states_current_file,
+    )
+    .await?;
+
+    let item_one = item_id!("one");
+    let item_two = item_id!("two");
+    let mut states_type_reg = TypeReg::new_typed();
+    states_type_reg.register::<VecCopyState>(item_one.clone());
+    states_type_reg.register::<MockState>(item_two.clone());
+
+    let states_deserialized = StatesSerializer::<PeaceTestError>::deserialize_stored_items(
+        &flow_id,
+        &storage,
+        &states_type_reg,
+        &states_current_file,
+        std::slice::from_ref(&item_two),
+    )
+    .await?;
+
+    assert!(states_deserialized.get_raw(&item_one).is_none());
+    assert_eq!(
+        Some(MockState(2u8)),
+        states_deserialized.get::<MockState, _>(&item_two).cloned()
+    );
+
+    Ok(())
+}
+
+fn item_graph_and_states(state_two: u8) -> (ItemGraph<PeaceTestError>, StatesCurrentStored) {
+    let item_graph = {
+        let mut item_graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        item_graph_builder.add_fns([
+            VecCopyItem::new(item_id!("one")).into(),
+            MockItem::<()>::new(item_id!("two")).into(),
+            MockItem::<()>::new(item_id!("three")).into(),
+        ]);
+        item_graph_builder.build()
+    };
+    let states = {
+        let mut states_mut = StatesMut::new();
+        states_mut.insert(item_id!("one"), VecCopyState::from(vec![1u8]));
+        states_mut.insert(item_id!("two"), MockState(state_two));
+        StatesCurrentStored::from(states_mut)
+    };
+
+    (item_graph, states)
+}
+
+/// Records the paths read from, written to, and removed from an
+/// [`InMemoryStorageBackend`].
+#[derive(Clone, Debug, Default)]
+struct WriteRecordStorageBackend {
+    backend: InMemoryStorageBackend,
+    read: Arc<Mutex<Vec<PathBuf>>>,
+    written: Arc<Mutex<Vec<PathBuf>>>,
+    removed: Arc<Mutex<Vec<PathBuf>>>,
+}
+
+#[async_trait(?Send)]
+impl StorageBackend for WriteRecordStorageBackend {
+    async fn contains_item(&self, path: &Path) -> Result<bool, Error> {
+        self.backend.contains_item(path).await
+    }
+
+    async fn read_opt(&self, path: &Path) -> Result<Option<Vec<u8>>, Error> {
+        self.read.lock().unwrap().push(path.to_path_buf());
+        self.backend.read_opt(path).await
+    }
+
+    async fn write(&self, path: &Path, bytes: &[u8]) -> Result<(), Error> {
+        self.written.lock().unwrap().push(path.to_path_buf());
+        self.backend.write(path, bytes).await
+    }
+
+    async fn remove(&self, path: &Path) -> Result<(), Error> {
+        self.removed.lock().unwrap().push(path.to_path_buf());
+        self.backend.remove(path).await
+    }
+
+    async fn paths_under(&self, dir: &Path) -> Result<Vec<PathBuf>, Error> {
+        self.backend.paths_under(dir).await
+    }
+}
@@ -1,6 +1,15 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
 use peace::{
     resource_rt::type_reg::untagged::{TypeMapOpt, TypeReg},
-    rt_model::{params::WorkspaceParams, Error, Storage},
+    rt_model::{
+        async_trait, params::WorkspaceParams, EncryptionError, EncryptionKey, Error,
+        SerializationFormat, Storage, StorageBackend, StorageEncryption,
+    },
 };
 use serde::{Deserialize, Serialize};
 
@@ -11,13 +20,16 @@ struct TestStruct {
 
 #[test]
 fn clone() {
-    let _ = Clone::clone(&Storage);
+    let _ = Clone::clone(&Storage::default());
     let _ = Clone::clone(&TestStruct { a: 1 });
 }
 
 #[test]
 fn debug() {
-    assert_eq!("Storage", format!("{Storage:?}"));
+    assert_eq!(
+        "Storage { backend: FileStorageBackend, serialization_format: Yaml, states_layout: SingleFile, encryption: None, .. }",
+        format!("{:?}", Storage::default())
+    );
     assert_eq!("TestStruct { a: 1 }", format!("{:?}", TestStruct { a: 1 }));
 }
 
@@ -39,7 +51,7 @@ async fn serialized_read_returns_t_when_path_exists() -> Result<(), Box<dyn std:
     let file_path = tempdir.path().join("t.yaml");
     tokio::fs::write(&file_path, br#"a: 1"#).await?;
 
-    let test_struct = Storage
+    let test_struct = Storage::default()
         .serialized_read::<TestStruct, _>(
             crate::fn_name_short!().to_string(),
             &file_path,
@@ -59,7 +71,7 @@ async fn serialized_read_returns_error_when_path_not_exists(
     let tempdir = tempfile::tempdir()?;
     let file_path = tempdir.path().join("t.yaml");
 
-    let error = Storage
+    let error = Storage::default()
         .serialized_read::<TestStruct, _>(
             crate::fn_name_short!().to_string(),
             &file_path,
@@ -81,7 +93,7 @@ async fn serialized_read_opt_returns_t_when_path_exists() -> Result<(), Box<dyn
     let file_path = tempdir.path().join("t.yaml");
     tokio::fs::write(&file_path, br#"a: 1"#).await?;
 
-    let test_struct = Storage
+    let test_struct = Storage::default()
         .serialized_read_opt::<TestStruct, _>(
             crate::fn_name_short!().to_string(),
             &file_path,
@@ -101,7 +113,7 @@ async fn serialized_read_opt_returns_none_when_path_not_exists(
     let tempdir = tempfile::tempdir()?;
     let file_path = tempdir.path().join("t.yaml");
 
-    let test_struct = Storage
+    let test_struct = Storage::default()
         .serialized_read_opt::<TestStruct, _>(
             crate::fn_name_short!().to_string(),
             &file_path,
@@ -124,7 +136,7 @@ async fn serialized_typemap_read_opt_returns_typemap_when_path_exists(
     let mut type_reg = TypeReg::new();
     type_reg.register::<TestStruct>(0);
 
-    let workspace_params: WorkspaceParams<u32> = Storage
+    let workspace_params: WorkspaceParams<u32> = Storage::default()
         .serialized_typemap_read_opt(
             crate::fn_name_short!().to_string(),
             &type_reg,
@@ -150,7 +162,7 @@ async fn serialized_typemap_read_opt_returns_none_when_path_not_exists(
     let mut type_reg = TypeReg::new();
     type_reg.register::<TestStruct>(0);
 
-    let workspace_params: Option<WorkspaceParams<u32>> = Storage
+    let workspace_params: Option<WorkspaceParams<u32>> = Storage::default()
         .serialized_typemap_read_opt(
             crate::fn_name_short!().to_string(),
             &type_reg,
@@ -173,7 +185,7 @@ async fn serialized_write_serializes_t() -> Result<(), Box<dyn std::error::Error
     let file_path = tempdir.path().join("t.yaml");
 
     let test_struct = TestStruct { a: 1 };
-    Storage
+    Storage::default()
         .serialized_write(
             crate::fn_name_short!().to_string(),
             &file_path,
@@ -189,3 +201,389 @@ async fn serialized_write_serializes_t() -> Result<(), Box<dyn std::error::Error
 
     Ok(())
 }
+
+#[tokio::test]
+async fn serialized_write_encrypted_if_changed_skips_unchanged_t(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let storage = Storage::new(MapStorageBackend::default());
+    let file_path = Path::new("not_on_disk/t.yaml");
+
+    let mut written = Vec::new();
+    for a in [1, 1, 2] {
+        let written_this = storage
+            .serialized_write_encrypted_if_changed(
+                crate::fn_name_short!().to_string(),
+                file_path,
+                &TestStruct { a },
+                #[cfg_attr(coverage_nightly, coverage(off))]
+                |_error| panic!("Expected `test_struct` to be serialized."),
+            )
+            .await?;
+        written.push(written_this);
+    }
+
+    assert_eq!(vec![true, false, true], written);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn serialized_write_encrypted_if_changed_writes_t_changed_since_read(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let storage_backend = MapStorageBackend::default();
+    let storage = Storage::new(storage_backend.clone());
+    let file_path = Path::new("not_on_disk/t.yaml");
+
+    let written_first = storage
+        .serialized_write_encrypted_if_changed(
+            crate::fn_name_short!().to_string(),
+            file_path,
+            &TestStruct { a: 1 },
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            |_error| panic!("Expected `test_struct` to be serialized."),
+        )
+        .await?;
+    // Another process changes the stored item after it was written, and the
+    // changed item is read.
+    storage_backend.write(file_path, b"a: 2\n").await?;
+    storage
+        .serialized_read_opt::<TestStruct, _>(
+            crate::fn_name_short!().to_string(),
+            file_path,
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            |_error| panic!("Expected `test_struct` to be deserialized."),
+        )
+        .await?;
+    let written_second = storage
+        .serialized_write_encrypted_if_changed(
+            crate::fn_name_short!().to_string(),
+            file_path,
+            &TestStruct { a: 1 },
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            |_error| panic!("Expected `test_struct` to be serialized."),
+        )
+        .await?;
+
+    assert!(written_first);
+    assert!(written_second);
+    assert_eq!(
+        Some(b"a: 1\n".to_vec()),
+        storage_backend.0.lock().unwrap().get(file_path).cloned()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn serialized_write_encrypted_if_changed_writes_t_not_read_or_written(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let storage_backend = MapStorageBackend::default();
+    let file_path = Path::new("not_on_disk/t.yaml");
+    storage_backend.write(file_path, b"a: 1\n").await?;
+
+    let written = Storage::new(storage_backend.clone())
+        .serialized_write_encrypted_if_changed(
+            crate::fn_name_short!().to_string(),
+            file_path,
+            &TestStruct { a: 1 },
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            |_error| panic!("Expected `test_struct` to be serialized."),
+        )
+        .await?;
+
+    assert!(written);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn serialized_write_and_read_use_storage_backend() -> Result<(), Box<dyn std::error::Error>> {
+    let storage_backend = MapStorageBackend::default();
+    let storage = Storage::new(storage_backend.clone());
+    let file_path = Path::new("not_on_disk/t.yaml");
+
+    storage
+        .serialized_write(
+            crate::fn_name_short!().to_string(),
+            file_path,
+            &TestStruct { a: 1 },
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            |_error| panic!("Expected `test_struct` to be serialized."),
+        )
+        .await?;
+    let test_struct = storage
+        .serialized_read::<TestStruct, _>(
+            crate::fn_name_short!().to_string(),
+            file_path,
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            |_error| panic!("Expected `test_struct` to be deserialized."),
+        )
+        .await?;
+
+    assert!(!file_path.exists());
+    assert_eq!(
+        Some(b"a: 1\n".to_vec()),
+        storage_backend.0.lock().unwrap().get(file_path).cloned()
+    );
+    assert_eq!(TestStruct { a: 1 }, test_struct);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn serialized_write_removes_file_in_other_format_when_format_changed(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let storage_backend = RemoveRecordStorageBackend::default();
+    let file_path = Path::new("not_on_disk/t.yaml");
+    Storage::new(storage_backend.clone())
+        .serialized_write(
+            crate::fn_name_short!().to_string(),
+            file_path,
+            &TestStruct { a: 1 },
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            |_error| panic!("Expected `test_struct` to be serialized."),
+        )
+        .await?;
+
+    Storage::new(storage_backend.clone())
+        .with_serialization_format(SerializationFormat::Json)
+        .serialized_write(
+            crate::fn_name_short!().to_string(),
+            file_path,
+            &TestStruct { a: 2 },
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            |_error| panic!("Expected `test_struct` to be serialized."),
+        )
+        .await?;
+
+    assert!(!storage_backend.contains_item(file_path).await?);
+    assert!(
+        storage_backend
+            .contains_item(&file_path.with_extension("json"))
+            .await?
+    );
+    assert_eq!(
+        vec![file_path.to_path_buf()],
+        *storage_backend.removed.lock().unwrap()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn serialized_write_does_not_remove_files_when_format_unchanged(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let storage_backend = RemoveRecordStorageBackend::default();
+    let storage = Storage::new(storage_backend.clone());
+    let file_path = Path::new("not_on_disk/t.yaml");
+
+    for a in 1..=3 {
+        storage
+            .serialized_write(
+                crate::fn_name_short!().to_string(),
+                file_path,
+                &TestStruct { a },
+                #[cfg_attr(coverage_nightly, coverage(off))]
+                |_error| panic!("Expected `test_struct` to be serialized."),
+            )
+            .await?;
+    }
+
+    assert!(storage_backend.contains_item(file_path).await?);
+    assert!(storage_backend.removed.lock().unwrap().is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn serialized_write_encrypted_writes_plaintext_when_encryption_not_set(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let storage_backend = MapStorageBackend::default();
+    let storage = Storage::new(storage_backend.clone());
+    let file_path = Path::new("not_on_disk/t.yaml");
+
+    storage
+        .serialized_write_encrypted(
+            crate::fn_name_short!().to_string(),
+            file_path,
+            &TestStruct { a: 1 },
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            |_error| panic!("Expected `test_struct` to be serialized."),
+        )
+        .await?;
+
+    assert_eq!(
+        Some(b"a: 1\n".to_vec()),
+        storage_backend.0.lock().unwrap().get(file_path).cloned()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn serialized_write_encrypted_and_read_round_trip() -> Result<(), Box<dyn std::error::Error>>
+{
+    let storage_backend = MapStorageBackend::default();
+    let storage = Storage::new(storage_backend.clone())
+        .with_encryption(StorageEncryption::new(EncryptionKey::generate()));
+    let file_path = Path::new("not_on_disk/t.yaml");
+
+    storage
+        .serialized_write_encrypted(
+            crate::fn_name_short!().to_string(),
+            file_path,
+            &TestStruct { a: 1 },
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            |_error| panic!("Expected `test_struct` to be serialized."),
+        )
+        .await?;
+    let test_struct = storage
+        .serialized_read::<TestStruct, _>(
+            crate::fn_name_short!().to_string(),
+            file_path,
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            |_error| panic!("Expected `test_struct` to be deserialized."),
+        )
+        .await?;
+
+    let bytes = storage_backend
+        .0
+        .lock()
+        .unwrap()
+        .get(file_path)
+        .cloned()
+        .expect("Expected item to be stored.");
+    assert!(StorageEncryption::is_encrypted(&bytes));
+    assert_eq!(TestStruct { a: 1 }, test_struct);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn serialized_read_returns_error_when_encrypted_and_encryption_not_set(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let storage_backend = MapStorageBackend::default();
+    let file_path = Path::new("not_on_disk/t.yaml");
+    Storage::new(storage_backend.clone())
+        .with_encryption(StorageEncryption::new(EncryptionKey::generate()))
+        .serialized_write_encrypted(
+            crate::fn_name_short!().to_string(),
+            file_path,
+            &TestStruct { a: 1 },
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            |_error| panic!("Expected `test_struct` to be serialized."),
+        )
+        .await?;
+
+    let result = Storage::new(storage_backend)
+        .serialized_read_opt::<TestStruct, _>(
+            crate::fn_name_short!().to_string(),
+            file_path,
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            |_error| panic!("Expected deserialization to not be attempted."),
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(Error::Encryption(EncryptionError::KeyMissing { path })) if path == file_path
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn remove_removes_item_from_storage_backend() -> Result<(), Box<dyn std::error::Error>> {
+    let storage_backend = MapStorageBackend::default();
+    let storage = Storage::new(storage_backend.clone());
+    let file_path = Path::new("not_on_disk/t.yaml");
+    storage_backend.write(file_path, b"a: 1").await?;
+
+    storage.remove(file_path).await?;
+
+    assert!(!storage.backend().contains_item(file_path).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn remove_succeeds_when_file_not_exists() -> Result<(), Box<dyn std::error::Error>> {
+    let tempdir = tempfile::tempdir()?;
+    let file_path = tempdir.path().join("t.yaml");
+
+    Storage::default().remove(&file_path).await?;
+
+    Ok(())
+}
+
+/// Stores items in a map, to check `Storage` does not use the file system.
+#[derive(Clone, Debug, Default)]
+struct MapStorageBackend(Arc<Mutex<HashMap<PathBuf, Vec<u8>>>>);
+
+#[async_trait(?Send)]
+impl StorageBackend for MapStorageBackend {
+    async fn contains_item(&self, path: &Path) -> Result<bool, Error> {
+        Ok(self.0.lock().unwrap().contains_key(path))
+    }
+
+    async fn read_opt(&self, path: &Path) -> Result<Option<Vec<u8>>, Error> {
+        Ok(self.0.lock().unwrap().get(path).cloned())
+    }
+
+    async fn write(&self, path: &Path, bytes: &[u8]) -> Result<(), Error> {
+        self.0
+            .lock()
+            .unwrap()
+            .insert(path.to_path_buf(), bytes.to_vec());
+        Ok(())
+    }
+
+    async fn remove(&self, path: &Path) -> Result<(), Error> {
+        self.0.lock().unwrap().remove(path);
+        Ok(())
+    }
+
+    async fn paths_under(&self, dir: &Path) -> Result<Vec<PathBuf>, Error> {
+        let mut paths = self
+            .0
+            .lock()
+            .unwrap()
+            .keys()
+            .filter(|path| path.starts_with(dir) && *path != dir)
+            .cloned()
+            .collect::<Vec<PathBuf>>();
+        paths.sort();
+        Ok(paths)
+    }
+}
+
+/// Records the paths removed from a [`MapStorageBackend`].
+#[derive(Clone, Debug, Default)]
+struct RemoveRecordStorageBackend {
+    map: MapStorageBackend,
+    removed: Arc<Mutex<Vec<PathBuf>>>,
+}
+
+#[async_trait(?Send)]
+impl StorageBackend for RemoveRecordStorageBackend {
+    async fn contains_item(&self, path: &Path) -> Result<bool, Error> {
+        self.map.contains_item(path).await
+    }
+
+    async fn read_opt(&self, path: &Path) -> Result<Option<Vec<u8>>, Error> {
+        self.map.read_opt(path).await
+    }
+
+    async fn write(&self, path: &Path, bytes: &[u8]) -> Result<(), Error> {
+        self.map.write(path, bytes).await
+    }
+
+    async fn remove(&self, path: &Path) -> Result<(), Error> {
+        self.removed.lock().unwrap().push(path.to_path_buf());
+        self.map.remove(path).await
+    }
+
+    async fn paths_under(&self, dir: &Path) -> Result<Vec<PathBuf>, Error> {
+        self.map.paths_under(dir).await
+    }
+}
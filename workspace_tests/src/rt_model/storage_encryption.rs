@@ -0,0 +1,145 @@
+use std::path::Path;
+
+use peace::rt_model::{EncryptionError, EncryptionKey, StorageEncryption};
+
+#[test]
+fn encrypt_then_decrypt_returns_plaintext() -> Result<(), EncryptionError> {
+    let storage_encryption = StorageEncryption::new(EncryptionKey::generate());
+    let path = Path::new("states_current.yaml");
+
+    let encrypted = storage_encryption.encrypt(path, b"vec_copy: [1, 2]\n")?;
+    let plaintext = storage_encryption.decrypt(path, &encrypted)?;
+
+    assert!(encrypted.starts_with(StorageEncryption::HEADER));
+    assert!(std::str::from_utf8(&encrypted).is_ok());
+    assert_eq!(b"vec_copy: [1, 2]\n".to_vec(), plaintext);
+    Ok(())
+}
+
+#[test]
+fn encrypt_uses_different_nonce_each_time() -> Result<(), EncryptionError> {
+    let storage_encryption = StorageEncryption::new(EncryptionKey::generate());
+    let path = Path::new("states_current.yaml");
+
+    assert_ne!(
+        storage_encryption.encrypt(path, b"a: 1\n")?,
+        storage_encryption.encrypt(path, b"a: 1\n")?
+    );
+    Ok(())
+}
+
+#[test]
+fn decrypt_returns_error_when_key_is_wrong() -> Result<(), EncryptionError> {
+    let path = Path::new("states_current.yaml");
+    let encrypted = StorageEncryption::new(EncryptionKey::generate()).encrypt(path, b"a: 1\n")?;
+
+    let result = StorageEncryption::new(EncryptionKey::generate()).decrypt(path, &encrypted);
+
+    assert!(matches!(
+        result,
+        Err(EncryptionError::Decrypt { path: error_path }) if error_path == path
+    ));
+    Ok(())
+}
+
+#[test]
+fn decrypt_returns_error_when_content_is_modified() -> Result<(), EncryptionError> {
+    let storage_encryption = StorageEncryption::new(EncryptionKey::generate());
+    let path = Path::new("states_current.yaml");
+    let mut encrypted = storage_encryption.encrypt(path, b"a: 1\n")?;
+    let header_len = StorageEncryption::HEADER.len();
+    encrypted[header_len] = if encrypted[header_len] == b'A' {
+        b'B'
+    } else {
+        b'A'
+    };
+
+    let result = storage_encryption.decrypt(path, &encrypted);
+
+    assert!(matches!(result, Err(EncryptionError::Decrypt { .. })));
+    Ok(())
+}
+
+#[test]
+fn decrypt_returns_error_when_path_is_different() -> Result<(), EncryptionError> {
+    let storage_encryption = StorageEncryption::new(EncryptionKey::generate());
+    let path = Path::new(".peace/app/profile_a/flow/states_current.yaml");
+    let path_other = Path::new(".peace/app/profile_b/flow/states_current.yaml");
+    let encrypted = storage_encryption.encrypt(path, b"a: 1\n")?;
+
+    let result = storage_encryption.decrypt(path_other, &encrypted);
+
+    assert!(matches!(
+        result,
+        Err(EncryptionError::Decrypt { path: error_path }) if error_path == path_other
+    ));
+    Ok(())
+}
+
+#[test]
+fn decrypt_returns_plaintext_when_workspace_dir_is_different() -> Result<(), EncryptionError> {
+    let storage_encryption = StorageEncryption::new(EncryptionKey::generate());
+    let path = Path::new("/workspace_a/.peace/app/profile/flow/states_current.yaml");
+    let path_moved = Path::new("/workspace_b/.peace/app/profile/flow/states_current.yaml");
+    let encrypted = storage_encryption.encrypt(path, b"a: 1\n")?;
+
+    let plaintext = storage_encryption.decrypt(path_moved, &encrypted)?;
+
+    assert_eq!(b"a: 1\n".to_vec(), plaintext);
+    Ok(())
+}
+
+#[test]
+fn decrypt_if_encrypted_returns_bytes_when_not_encrypted() -> Result<(), EncryptionError> {
+    let path = Path::new("states_current.yaml");
+
+    let plaintext = StorageEncryption::decrypt_if_encrypted(None, path, b"a: 1\n".to_vec())?;
+
+    assert_eq!(b"a: 1\n".to_vec(), plaintext);
+    Ok(())
+}
+
+#[test]
+fn decrypt_if_encrypted_returns_error_when_encryption_not_set() -> Result<(), EncryptionError> {
+    let path = Path::new("states_current.yaml");
+    let encrypted = StorageEncryption::new(EncryptionKey::generate()).encrypt(path, b"a: 1\n")?;
+
+    let result = StorageEncryption::decrypt_if_encrypted(None, path, encrypted);
+
+    assert!(matches!(
+        result,
+        Err(EncryptionError::KeyMissing { path: error_path }) if error_path == path
+    ));
+    Ok(())
+}
+
+#[test]
+fn decrypt_if_encrypted_returns_error_when_encryption_set_and_not_encrypted() {
+    let path = Path::new("states_current.yaml");
+
+    let result = StorageEncryption::decrypt_if_encrypted(
+        Some(&StorageEncryption::new(EncryptionKey::generate())),
+        path,
+        b"a: 1\n".to_vec(),
+    );
+
+    assert!(matches!(
+        result,
+        Err(EncryptionError::NotEncrypted { path: error_path }) if error_path == path
+    ));
+}
+
+#[test]
+fn decrypt_if_has_header_returns_bytes_when_encryption_set_and_not_encrypted(
+) -> Result<(), EncryptionError> {
+    let path = Path::new("workspace_params.yaml");
+
+    let plaintext = StorageEncryption::decrypt_if_has_header(
+        Some(&StorageEncryption::new(EncryptionKey::generate())),
+        path,
+        b"a: 1\n".to_vec(),
+    )?;
+
+    assert_eq!(b"a: 1\n".to_vec(), plaintext);
+    Ok(())
+}
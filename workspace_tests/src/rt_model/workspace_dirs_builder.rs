@@ -2,7 +2,7 @@ use std::path::Path;
 
 use peace::{
     cfg::app_name,
-    rt_model::{Error, NativeError, WorkspaceDirsBuilder, WorkspaceSpec},
+    rt_model::{Error, InMemoryStorageBackend, NativeError, WorkspaceDirsBuilder, WorkspaceSpec},
 };
 
 #[test]
@@ -24,6 +24,22 @@ fn returns_workspace_dir_from_working_directory() -> Result<(), Box<dyn std::err
     Ok(())
 }
 
+#[test]
+fn returns_empty_workspace_dir_for_in_memory_workspace() -> Result<(), Box<dyn std::error::Error>> {
+    let workspace_dirs = WorkspaceDirsBuilder::build(
+        &app_name!(),
+        WorkspaceSpec::InMemory(InMemoryStorageBackend::new()),
+    )?;
+
+    assert_eq!(Path::new(""), &**workspace_dirs.workspace_dir());
+    assert_eq!(Path::new(".peace"), &**workspace_dirs.peace_dir());
+    assert_eq!(
+        Path::new(".peace/workspace_tests"),
+        &**workspace_dirs.peace_app_dir()
+    );
+    Ok(())
+}
+
 #[test]
 fn returns_workspace_dir_from_first_dir_with_file() -> Result<(), Box<dyn std::error::Error>> {
     let workspace_dirs = WorkspaceDirsBuilder::build(
@@ -0,0 +1,2 @@
+mod remote_storage_backend;
+mod remote_storage_server;
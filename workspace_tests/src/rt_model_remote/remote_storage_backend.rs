@@ -0,0 +1,477 @@
+use std::{path::Path, time::Duration};
+
+use peace::{
+    cfg::{app_name, profile, FlowId},
+    cmd::ctx::CmdCtx,
+    rt::cmds::StatesDiscoverCmd,
+    rt_model::{Error, Flow, ItemGraphBuilder, StorageBackend, Workspace, WorkspaceSpec},
+};
+use peace_rt_model_remote::{
+    RemoteError, RemoteLeaseRequest, RemoteStorageBackend, RemoteStorageServer, Url,
+};
+use tokio::net::TcpListener;
+
+use crate::{NoOpOutput, PeaceTestError, VecA, VecCopyItem};
+
+#[test]
+fn new_returns_err_when_base_url_cannot_be_a_base() -> Result<(), Box<dyn std::error::Error>> {
+    let error = RemoteStorageBackend::new(Url::parse("data:text/plain,abc")?).unwrap_err();
+
+    assert!(
+        matches!(&error, RemoteError::BaseUrlCannotBeABase { base_url } if base_url == "data:text/plain,abc"),
+        "Expected `RemoteError::BaseUrlCannotBeABase`, but was {error:?}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn write_then_read_opt_shares_items_from_peace_dir() -> Result<(), Box<dyn std::error::Error>>
+{
+    let base_url = server_spawn().await?;
+    let storage_backend_0 = RemoteStorageBackend::new(base_url.clone())?;
+    let storage_backend_1 = RemoteStorageBackend::new(base_url)?;
+
+    storage_backend_0
+        .write(
+            Path::new("/home/user_0/project/.peace/app/dev/deploy/states_current.yaml"),
+            b"a: 1\n",
+        )
+        .await?;
+    let bytes = storage_backend_1
+        .read_opt(Path::new(
+            "/home/user_1/code/project/.peace/app/dev/deploy/states_current.yaml",
+        ))
+        .await?;
+
+    assert_eq!(Some(b"a: 1\n".to_vec()), bytes);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_opt_returns_none_when_item_not_exists() -> Result<(), Box<dyn std::error::Error>> {
+    let base_url = server_spawn().await?;
+    let storage_backend = RemoteStorageBackend::new(base_url)?;
+    let path = Path::new(".peace/app/dev/deploy/states_current.yaml");
+
+    assert_eq!(None, storage_backend.read_opt(path).await?);
+    assert!(!storage_backend.contains_item(path).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn remove_removes_item() -> Result<(), Box<dyn std::error::Error>> {
+    let base_url = server_spawn().await?;
+    let storage_backend = RemoteStorageBackend::new(base_url)?;
+    let path = Path::new(".peace/app/dev/deploy/states_current.yaml");
+    storage_backend.write(path, b"a: 1\n").await?;
+
+    storage_backend.remove(path).await?;
+
+    assert!(!storage_backend.contains_item(path).await?);
+    storage_backend.remove(path).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn write_returns_version_conflict_when_item_changed_since_read(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let base_url = server_spawn().await?;
+    let storage_backend_0 = RemoteStorageBackend::new(base_url.clone())?;
+    let storage_backend_1 = RemoteStorageBackend::new(base_url)?;
+    let path = Path::new(".peace/app/dev/deploy/states_current.yaml");
+    storage_backend_0.write(path, b"a: 1\n").await?;
+
+    storage_backend_1.read_opt(path).await?;
+    storage_backend_0.write(path, b"a: 2\n").await?;
+    let error = storage_backend_1.write(path, b"a: 3\n").await.unwrap_err();
+
+    assert_remote_error(
+        &error,
+        |remote_error| matches!(remote_error, RemoteError::VersionConflict { path: error_path } if error_path == path),
+    );
+    assert_eq!(
+        Some(b"a: 2\n".to_vec()),
+        storage_backend_0.read_opt(path).await?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn write_returns_version_conflict_when_item_created_since_read(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let base_url = server_spawn().await?;
+    let storage_backend_0 = RemoteStorageBackend::new(base_url.clone())?;
+    let storage_backend_1 = RemoteStorageBackend::new(base_url)?;
+    let path = Path::new(".peace/app/dev/deploy/states_current.yaml");
+
+    assert_eq!(None, storage_backend_1.read_opt(path).await?);
+    storage_backend_0.write(path, b"a: 1\n").await?;
+    let error = storage_backend_1.write(path, b"a: 2\n").await.unwrap_err();
+
+    assert_remote_error(&error, |remote_error| {
+        matches!(remote_error, RemoteError::VersionConflict { .. })
+    });
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn write_returns_version_conflict_when_item_exists_and_not_read(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let base_url = server_spawn().await?;
+    let storage_backend_0 = RemoteStorageBackend::new(base_url.clone())?;
+    let storage_backend_1 = RemoteStorageBackend::new(base_url)?;
+    let path = Path::new(".peace/app/dev/deploy/states_current.yaml");
+    storage_backend_0.write(path, b"a: 1\n").await?;
+
+    let error = storage_backend_1.write(path, b"a: 2\n").await.unwrap_err();
+
+    assert_remote_error(&error, |remote_error| {
+        matches!(remote_error, RemoteError::VersionConflict { .. })
+    });
+    assert_eq!(
+        Some(b"a: 1\n".to_vec()),
+        storage_backend_0.read_opt(path).await?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn paths_under_returns_paths_of_items_under_dir() -> Result<(), Box<dyn std::error::Error>> {
+    let base_url = server_spawn().await?;
+    let storage_backend_0 = RemoteStorageBackend::new(base_url.clone())?;
+    let storage_backend_1 = RemoteStorageBackend::new(base_url)?;
+    storage_backend_0
+        .write(
+            Path::new("/home/user_0/.peace/app/dev/deploy/states_current.yaml"),
+            b"a: 1\n",
+        )
+        .await?;
+    storage_backend_0
+        .write(
+            Path::new("/home/user_0/.peace/app/dev/profile_params.yaml"),
+            b"a: 1\n",
+        )
+        .await?;
+    storage_backend_0
+        .write(
+            Path::new("/home/user_0/.peace/app/dev_other/profile_params.yaml"),
+            b"a: 1\n",
+        )
+        .await?;
+
+    let paths = storage_backend_1
+        .paths_under(Path::new("/home/user_1/.peace/app/dev"))
+        .await?;
+
+    assert_eq!(
+        vec![
+            Path::new("/home/user_1/.peace/app/dev/deploy/states_current.yaml").to_path_buf(),
+            Path::new("/home/user_1/.peace/app/dev/profile_params.yaml").to_path_buf(),
+        ],
+        paths
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn dir_remove_removes_items_under_dir() -> Result<(), Box<dyn std::error::Error>> {
+    let base_url = server_spawn().await?;
+    let storage_backend = RemoteStorageBackend::new(base_url)?;
+    let dir = Path::new(".peace/app/dev");
+    let path_other = Path::new(".peace/app/dev_other/profile_params.yaml");
+    storage_backend
+        .write(&dir.join("deploy/states_current.yaml"), b"a: 1\n")
+        .await?;
+    storage_backend
+        .write(&dir.join("profile_params.yaml"), b"a: 1\n")
+        .await?;
+    storage_backend.write(path_other, b"a: 1\n").await?;
+
+    storage_backend.dir_remove(dir).await?;
+
+    assert!(storage_backend.paths_under(dir).await?.is_empty());
+    assert!(storage_backend.contains_item(path_other).await?);
+    // Items may be written again after the directory is removed.
+    storage_backend
+        .write(&dir.join("profile_params.yaml"), b"a: 2\n")
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn write_succeeds_after_own_write() -> Result<(), Box<dyn std::error::Error>> {
+    let base_url = server_spawn().await?;
+    let storage_backend = RemoteStorageBackend::new(base_url)?;
+    let path = Path::new(".peace/app/dev/deploy/states_current.yaml");
+
+    assert_eq!(None, storage_backend.read_opt(path).await?);
+    storage_backend.write(path, b"a: 1\n").await?;
+    storage_backend.write(path, b"a: 2\n").await?;
+
+    assert_eq!(
+        Some(b"a: 2\n".to_vec()),
+        storage_backend.read_opt(path).await?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn lock_acquire_returns_locked_when_held_by_someone_else(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let base_url = server_spawn().await?;
+    let storage_backend_0 = RemoteStorageBackend::new(base_url.clone())?;
+    let storage_backend_1 = RemoteStorageBackend::new(base_url)?;
+
+    let lease = storage_backend_0
+        .lock_acquire(String::from("user_0"), Duration::from_secs(60))
+        .await?;
+    let error = storage_backend_1
+        .lock_acquire(String::from("user_1"), Duration::from_secs(60))
+        .await
+        .unwrap_err();
+
+    assert_eq!("user_0", lease.holder);
+    assert!(
+        matches!(
+            &error,
+            RemoteError::Locked { lease_held }
+            if lease_held.holder == "user_0"
+                && lease_held.ttl_remaining_ms > 0
+                && lease_held.ttl_remaining_ms <= 60_000
+        ),
+        "Expected `RemoteError::Locked`, but was {error:?}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn lock_acquire_renews_lease_for_lease_holder() -> Result<(), Box<dyn std::error::Error>> {
+    let base_url = server_spawn().await?;
+    let storage_backend = RemoteStorageBackend::new(base_url)?;
+
+    let lease_0 = storage_backend
+        .lock_acquire(String::from("user_0"), Duration::from_secs(60))
+        .await?;
+    let lease_1 = storage_backend
+        .lock_acquire(String::from("user_0"), Duration::from_secs(120))
+        .await?;
+
+    assert_eq!(lease_0.lease_id, lease_1.lease_id);
+    assert_eq!(120_000, lease_1.ttl_ms);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn lock_acquire_returns_locked_for_same_holder_without_lease_id(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let base_url = server_spawn().await?;
+    let storage_backend_0 = RemoteStorageBackend::new(base_url.clone())?;
+    let storage_backend_1 = RemoteStorageBackend::new(base_url)?;
+
+    storage_backend_0
+        .lock_acquire(String::from("user_0"), Duration::from_secs(60))
+        .await?;
+    let error = storage_backend_1
+        .lock_acquire(String::from("user_0"), Duration::from_secs(60))
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(&error, RemoteError::Locked { lease_held } if lease_held.holder == "user_0"),
+        "Expected `RemoteError::Locked`, but was {error:?}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn write_returns_locked_when_lease_held_by_someone_else(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let base_url = server_spawn().await?;
+    let storage_backend_0 = RemoteStorageBackend::new(base_url.clone())?;
+    let storage_backend_1 = RemoteStorageBackend::new(base_url)?;
+    let path = Path::new(".peace/app/dev/deploy/states_current.yaml");
+
+    storage_backend_0
+        .lock_acquire(String::from("user_0"), Duration::from_secs(60))
+        .await?;
+    storage_backend_0.write(path, b"a: 1\n").await?;
+    let error = storage_backend_1.write(path, b"a: 2\n").await.unwrap_err();
+
+    assert_remote_error(
+        &error,
+        |remote_error| matches!(remote_error, RemoteError::Locked { lease_held } if lease_held.holder == "user_0"),
+    );
+
+    storage_backend_0.lock_release().await?;
+    storage_backend_1.read_opt(path).await?;
+    storage_backend_1.write(path, b"a: 2\n").await?;
+
+    assert_eq!(
+        Some(b"a: 2\n".to_vec()),
+        storage_backend_0.read_opt(path).await?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn locked_response_cannot_be_used_to_write_or_release(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let base_url = server_spawn().await?;
+    let storage_backend = RemoteStorageBackend::new(base_url.clone())?;
+    let client = reqwest::Client::new();
+    let item_url = base_url.join("items/app/dev/deploy/states_current.yaml")?;
+
+    let lease = storage_backend
+        .lock_acquire(String::from("user_0"), Duration::from_secs(60))
+        .await?;
+    let response = client
+        .post(base_url.join("lock")?)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .body(serde_json::to_vec(&RemoteLeaseRequest {
+            holder: String::from("user_1"),
+            ttl_ms: 60_000,
+        })?)
+        .send()
+        .await?;
+    assert_eq!(reqwest::StatusCode::LOCKED, response.status());
+    let locked_body = response.text().await?;
+    let locked_json = serde_json::from_str::<serde_json::Value>(&locked_body)?;
+
+    assert!(!locked_body.contains(&lease.lease_id));
+    assert_eq!(
+        vec!["holder", "ttl_remaining_ms"],
+        locked_json
+            .as_object()
+            .map(|fields| fields.keys().map(String::as_str).collect::<Vec<_>>())
+            .unwrap_or_default()
+    );
+
+    // Neither field of the `423` response is accepted as the lease ID.
+    for value in [
+        String::from("user_0"),
+        locked_json["ttl_remaining_ms"].to_string(),
+    ] {
+        let write_status = client
+            .put(item_url.clone())
+            .header(RemoteStorageBackend::LEASE_HEADER, &value)
+            .body("a: 1\n")
+            .send()
+            .await?
+            .status();
+        let release_status = client
+            .delete(base_url.join(&format!("lock/{value}"))?)
+            .send()
+            .await?
+            .status();
+
+        assert_eq!(reqwest::StatusCode::LOCKED, write_status);
+        assert_eq!(reqwest::StatusCode::NOT_FOUND, release_status);
+    }
+
+    // The lease is still held by its holder.
+    storage_backend
+        .write(
+            Path::new(".peace/app/dev/deploy/states_current.yaml"),
+            b"a: 2\n",
+        )
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn lock_acquire_succeeds_when_lease_expired() -> Result<(), Box<dyn std::error::Error>> {
+    let base_url = server_spawn().await?;
+    let storage_backend_0 = RemoteStorageBackend::new(base_url.clone())?;
+    let storage_backend_1 = RemoteStorageBackend::new(base_url)?;
+
+    storage_backend_0
+        .lock_acquire(String::from("user_0"), Duration::from_millis(1))
+        .await?;
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    let lease = storage_backend_1
+        .lock_acquire(String::from("user_1"), Duration::from_secs(60))
+        .await?;
+
+    assert_eq!("user_1", lease.holder);
+    storage_backend_0.lock_release().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn states_discover_cmd_stores_states_in_remote_workspace(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let base_url = server_spawn().await?;
+    let tempdir = tempfile::tempdir()?;
+    let workspace = Workspace::new(
+        app_name!(),
+        WorkspaceSpec::Path(tempdir.path().to_path_buf()),
+    )?
+    .with_storage_backend(RemoteStorageBackend::new(base_url.clone())?);
+    let graph = {
+        let mut graph_builder = ItemGraphBuilder::<PeaceTestError>::new();
+        graph_builder.add_fn(VecCopyItem::default().into());
+        graph_builder.build()
+    };
+    let flow = Flow::new(FlowId::new(crate::fn_name_short!())?, graph);
+    let output = &mut NoOpOutput;
+    let mut cmd_ctx = CmdCtx::builder_single_profile_single_flow::<PeaceTestError, NoOpOutput>(
+        output.into(),
+        (&workspace).into(),
+    )
+    .with_profile(profile!("test_profile"))
+    .with_flow((&flow).into())
+    .with_item_params::<VecCopyItem>(
+        VecCopyItem::ID_DEFAULT.clone(),
+        VecA(vec![0, 1, 2, 3, 4, 5, 6, 7]).into(),
+    )
+    .await?;
+
+    StatesDiscoverCmd::current(&mut cmd_ctx).await?;
+
+    let states_current_path = Path::new(".peace/workspace_tests/test_profile")
+        .join(flow.flow_id().as_str())
+        .join("states_current.yaml");
+    let states_current_yaml = RemoteStorageBackend::new(base_url)?
+        .read_opt(&states_current_path)
+        .await?;
+    assert_eq!(Some(b"vec_copy: []\n".to_vec()), states_current_yaml);
+    assert!(!tempdir.path().join(&states_current_path).exists());
+
+    Ok(())
+}
+
+/// Starts a `RemoteStorageServer` on a random port, returning its URL.
+async fn server_spawn() -> Result<Url, Box<dyn std::error::Error>> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let base_url = Url::parse(&format!("http://{}/", listener.local_addr()?))?;
+    tokio::spawn(RemoteStorageServer::new().serve(listener));
+
+    Ok(base_url)
+}
+
+fn assert_remote_error(error: &Error, f: impl Fn(&RemoteError) -> bool) {
+    let remote_error = match error {
+        Error::StorageBackend(error) => error.downcast_ref::<RemoteError>(),
+        _ => None,
+    };
+    assert!(
+        remote_error.is_some_and(f),
+        "Expected matching `RemoteError`, but was {error:?}"
+    );
+}
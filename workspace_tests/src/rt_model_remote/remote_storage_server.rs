@@ -0,0 +1,128 @@
+use peace_rt_model_remote::{RemoteLease, RemoteLeaseRequest, RemoteStorageServer, Url};
+use tokio::net::TcpListener;
+
+#[tokio::test]
+async fn put_returns_precondition_failed_when_if_match_differs(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let base_url = server_spawn().await?;
+    let client = reqwest::Client::new();
+    let item_url = base_url.join("items/.peace/app/a.yaml")?;
+
+    let response = client.put(item_url.clone()).body("a: 1").send().await?;
+    assert_eq!(201, response.status().as_u16());
+    let etag = response.headers()["etag"].to_str()?.to_string();
+
+    let response = client
+        .put(item_url.clone())
+        .header("if-match", "\"stale\"")
+        .body("a: 2")
+        .send()
+        .await?;
+    assert_eq!(412, response.status().as_u16());
+
+    let response = client
+        .put(item_url.clone())
+        .header("if-match", &etag)
+        .body("a: 2")
+        .send()
+        .await?;
+    assert_eq!(200, response.status().as_u16());
+    assert_ne!(etag, response.headers()["etag"].to_str()?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn put_returns_precondition_failed_when_if_none_match_and_item_exists(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let base_url = server_spawn().await?;
+    let client = reqwest::Client::new();
+    let item_url = base_url.join("items/.peace/app/a.yaml")?;
+    client.put(item_url.clone()).body("a: 1").send().await?;
+
+    let response = client
+        .put(item_url)
+        .header("if-none-match", "*")
+        .body("a: 2")
+        .send()
+        .await?;
+
+    assert_eq!(412, response.status().as_u16());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn lock_release_returns_not_found_for_other_lease() -> Result<(), Box<dyn std::error::Error>>
+{
+    let base_url = server_spawn().await?;
+    let client = reqwest::Client::new();
+    let lease_request = RemoteLeaseRequest {
+        holder: String::from("user_0"),
+        ttl_ms: 60_000,
+    };
+
+    let response = client
+        .post(base_url.join("lock")?)
+        .body(serde_json::to_vec(&lease_request)?)
+        .header("content-type", "application/json")
+        .send()
+        .await?;
+    let lease = serde_json::from_slice::<RemoteLease>(&response.bytes().await?)?;
+    let response = client
+        .delete(base_url.join(&format!("lock/{}0", lease.lease_id))?)
+        .send()
+        .await?;
+    assert_eq!(404, response.status().as_u16());
+
+    let response = client
+        .delete(base_url.join(&format!("lock/{}", lease.lease_id))?)
+        .send()
+        .await?;
+    assert_eq!(204, response.status().as_u16());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn lock_acquire_returns_unguessable_lease_ids() -> Result<(), Box<dyn std::error::Error>> {
+    let base_url = server_spawn().await?;
+    let client = reqwest::Client::new();
+    let lease_request = RemoteLeaseRequest {
+        holder: String::from("user_0"),
+        ttl_ms: 60_000,
+    };
+
+    let mut lease_ids = Vec::new();
+    for _ in 0..2 {
+        let response = client
+            .post(base_url.join("lock")?)
+            .body(serde_json::to_vec(&lease_request)?)
+            .header("content-type", "application/json")
+            .send()
+            .await?;
+        let lease = serde_json::from_slice::<RemoteLease>(&response.bytes().await?)?;
+        let response = client
+            .delete(base_url.join(&format!("lock/{}", lease.lease_id))?)
+            .send()
+            .await?;
+        assert_eq!(204, response.status().as_u16());
+        lease_ids.push(lease.lease_id);
+    }
+
+    lease_ids.iter().for_each(|lease_id| {
+        assert_eq!(32, lease_id.len());
+        assert!(lease_id.chars().all(|c| c.is_ascii_hexdigit()));
+    });
+    assert_ne!(lease_ids[0], lease_ids[1]);
+
+    Ok(())
+}
+
+async fn server_spawn() -> Result<Url, Box<dyn std::error::Error>> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let base_url = Url::parse(&format!("http://{}/", listener.local_addr()?))?;
+    tokio::spawn(RemoteStorageServer::new().serve(listener));
+
+    Ok(base_url)
+}
@@ -290,6 +290,7 @@ impl VecCopyData<'_> {
 }
 
 #[derive(Clone, Debug, Default, Params, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema), json_schema)]
 pub struct VecA(pub Vec<u8>);
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]